@@ -0,0 +1,87 @@
+//! Benchmarks of deserializing representative ESI payloads, generated to match the size of a full
+//! page of regional market orders & a large character's assets.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use eve_esi::model::asset::Asset;
+use eve_esi::model::market::MarketRegionOrder;
+use serde_json::json;
+
+/// Number of market orders in the market order fixture, a full page of regional orders
+const MARKET_ORDER_COUNT: i64 = 1000;
+/// Number of assets in the asset fixture
+const ASSET_COUNT: i64 = 5000;
+
+/// Creates a JSON array of regional market orders as returned by ESI
+fn market_orders_fixture() -> String {
+    let orders: Vec<_> = (0..MARKET_ORDER_COUNT)
+        .map(|i| {
+            json!({
+                "duration": 90,
+                "is_buy_order": i % 2 == 0,
+                "issued": "2026-01-01T12:00:00Z",
+                "location_id": 60003760,
+                "min_volume": 1,
+                "order_id": 6000000000 + i,
+                "price": 5.25 + i as f64 / 100.0,
+                "range": if i % 2 == 0 { "station" } else { "region" },
+                "system_id": 30000142,
+                "type_id": 34 + i % 50,
+                "volume_remain": 100000 - i,
+                "volume_total": 100000
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&orders).unwrap()
+}
+
+/// Creates a JSON array of assets as returned by ESI
+fn assets_fixture() -> String {
+    let assets: Vec<_> = (0..ASSET_COUNT)
+        .map(|i| {
+            json!({
+                "is_blueprint_copy": (i % 10 == 0).then_some(true),
+                "is_singleton": i % 3 == 0,
+                "item_id": 1000000000000 + i,
+                "location_flag": if i % 4 == 0 { "Cargo" } else { "Hangar" },
+                "location_id": 60003760,
+                "location_type": "station",
+                "quantity": 1 + i % 1000,
+                "type_id": 587 + i % 200
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&assets).unwrap()
+}
+
+/// Benchmarks deserializing a page of regional market orders
+fn bench_market_orders(c: &mut Criterion) {
+    let fixture = market_orders_fixture();
+    let mut group = c.benchmark_group("deserialize");
+    group.throughput(Throughput::Elements(MARKET_ORDER_COUNT as u64));
+
+    group.bench_function("market_orders", |b| {
+        b.iter(|| serde_json::from_str::<Vec<MarketRegionOrder>>(black_box(&fixture)).unwrap())
+    });
+
+    group.finish();
+}
+
+/// Benchmarks deserializing a character's assets
+fn bench_assets(c: &mut Criterion) {
+    let fixture = assets_fixture();
+    let mut group = c.benchmark_group("deserialize");
+    group.throughput(Throughput::Elements(ASSET_COUNT as u64));
+
+    group.bench_function("assets", |b| {
+        b.iter(|| serde_json::from_str::<Vec<Asset>>(black_box(&fixture)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_market_orders, bench_assets);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+//! Benchmarks of building ESI requests, from the endpoint functions generated by
+//! `define_esi_endpoint!` formatting their path & query parameters to requests built manually.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use eve_esi::esi::EsiRequest;
+use eve_esi::model::enums::market::OrderType;
+
+/// Creates a client without OAuth2, requests are only built & never sent
+fn bench_client() -> eve_esi::Client {
+    let config = eve_esi::Config::builder()
+        .esi_route_version("/markets/{}/history", "v2")
+        .build()
+        .expect("Failed to build Config");
+
+    eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .build()
+        .expect("Failed to build Client")
+}
+
+/// Benchmarks building a request with [`EsiRequest`]'s builder methods
+fn bench_esi_request_new(c: &mut Criterion) {
+    let client = bench_client();
+
+    c.bench_function("esi_request_new", |b| {
+        b.iter(|| {
+            EsiRequest::<Vec<i64>>::new(&client, black_box("/markets/10000002/types"))
+                .with_query("page", black_box(1))
+                .with_header("X-Tenant", "tranquility")
+        })
+    });
+}
+
+/// Benchmarks the endpoint functions generated by `define_esi_endpoint!`
+///
+/// Covers a public endpoint with path & query parameters, an authenticated endpoint with
+/// required scopes & an endpoint whose route version is pinned by the client.
+fn bench_endpoint_request(c: &mut Criterion) {
+    let client = bench_client();
+    let mut group = c.benchmark_group("endpoint_request");
+
+    group.bench_function("public", |b| {
+        b.iter(|| {
+            client.market().list_orders_in_a_region(
+                black_box(10000002),
+                OrderType::All,
+                black_box(1),
+            )
+        })
+    });
+
+    group.bench_function("authenticated", |b| {
+        b.iter(|| {
+            client.assets().get_character_assets(
+                "access_token",
+                black_box(2114794365),
+                black_box(1),
+            )
+        })
+    });
+
+    group.bench_function("versioned", |b| {
+        b.iter(|| {
+            client
+                .market()
+                .list_historical_market_statistics_in_a_region(black_box(10000002), black_box(34))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_esi_request_new, bench_endpoint_request);
+criterion_main!(benches);
@@ -0,0 +1,82 @@
+//! EVE ESI Record Goldens Example
+//!
+//! This example re-records the golden files used by the golden-file tests in `tests/golden`
+//! from ESI. Run it when ESI changes a response schema & review the diff of the golden files
+//! before committing them, a model which no longer round-trips a golden file fails its test.
+//!
+//! ```sh
+//! cargo run --example record_goldens --features golden
+//! ```
+
+use eve_esi::golden::record_golden;
+
+#[tokio::main]
+async fn main() -> Result<(), eve_esi::Error> {
+    // Always set a user agent for your ESI client
+    let user_agent: String = format!(
+        "{}/{} (+{})",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_REPOSITORY")
+    );
+    let esi_client = eve_esi::Client::new(&user_agent)?;
+
+    // Golden files are written relative to the crate rather than the working directory
+    let golden = |name: &str| format!("{}/tests/golden/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+
+    record_golden(
+        esi_client.alliance().get_alliance_information(99013534),
+        golden("alliance"),
+    )
+    .await?;
+    record_golden(
+        esi_client
+            .character()
+            .get_character_public_information(2114794365),
+        golden("character"),
+    )
+    .await?;
+    record_golden(
+        esi_client
+            .corporation()
+            .get_corporation_information(98785281),
+        golden("corporation"),
+    )
+    .await?;
+    record_golden(
+        esi_client.universe().get_solar_system_information(30000142),
+        golden("solar_system"),
+    )
+    .await?;
+    record_golden(
+        esi_client.universe().get_item_type_information(587),
+        golden("item_type"),
+    )
+    .await?;
+
+    record_golden(
+        esi_client.universe().get_stargate_information(50001248),
+        golden("stargate"),
+    )
+    .await?;
+    record_golden(
+        esi_client.universe().get_station_information(60003760),
+        golden("station"),
+    )
+    .await?;
+    record_golden(esi_client.universe().get_factions(), golden("factions")).await?;
+    record_golden(
+        esi_client.market().list_market_prices(),
+        golden("market_prices"),
+    )
+    .await?;
+    record_golden(
+        esi_client.alliance().get_alliance_icon(99013534),
+        golden("alliance_icons"),
+    )
+    .await?;
+
+    println!("Recorded golden files to tests/golden");
+
+    Ok(())
+}
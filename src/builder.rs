@@ -9,9 +9,12 @@
 //! - [EVE SSO Documentation](https://developers.eveonline.com/docs/services/sso/)
 //!
 //! ## Features
-//! - Set a user agent to identify your application's requests
+//! - Set a user agent to identify your application's requests, optionally formatted with [`UserAgent`]
+//!   & validated to contain contact information with [`ClientBuilder::strict_user_agent`]
 //! - Configure [`Client`] for OAuth2 using `client_id`, `client_secret`, and `callback_url` methods
 //! - Share a reqwest Client with the ESI client for optimal performance by using the same connection pool
+//! - Configure default headers, proxies & TLS settings of the default reqwest Client without losing
+//!   the user agent set with [`ClientBuilder::user_agent`]
 //! - Override the default JWT key cache & refresh settings used to validate OAuth2 tokens & override
 //!   the default endpoint URLs with a custom [`Config`] using the [`ClientBuilder::config`] method.
 //!
@@ -50,12 +53,25 @@
 //!
 //! Example: `"MyApp/1.0 (contact@example.com; +https://github.com/your/repo)"`
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use reqwest::header::HeaderMap;
+use reqwest::tls::Version;
+use reqwest::{Certificate, Proxy};
+#[cfg(feature = "universe")]
+use tokio::sync::OnceCell;
 
 use crate::client::ClientRef;
 use crate::config::Config;
-use crate::error::Error;
+use crate::error::{ConfigError, Error};
+#[cfg(feature = "universe")]
+use crate::esi::NameCache;
+use crate::esi::{DeprecationRegistry, RequestLimiter, SimulatedErrorLimit};
+use crate::oauth2::discovery::OAuth2Discovery;
 use crate::oauth2::jwk::cache::JwtKeyCache;
+use crate::oauth2::metadata::server_metadata_url;
 use crate::Client;
 
 /// Builder for configuring and constructing an [`Client`].
@@ -69,6 +85,13 @@ pub struct ClientBuilder {
     pub(crate) reqwest_client: Option<reqwest::Client>,
     /// User agent used for default reqwest client if no client is provided
     pub(crate) user_agent: Option<String>,
+    /// Enable/disable validating that the user agent contains contact information
+    pub(crate) strict_user_agent: bool,
+    /// Headers, proxies & TLS settings applied to the default reqwest client
+    pub(crate) http_options: HttpOptions,
+    /// Cassette ESI responses are recorded to or replayed from
+    #[cfg(feature = "record-replay")]
+    pub(crate) cassette: Option<crate::esi::Cassette>,
 
     // OAuth2 Settings
     /// Client ID used to identify an EVE Online application
@@ -98,7 +121,11 @@ impl ClientBuilder {
             // Base settings
             config: None,
             user_agent: None,
+            strict_user_agent: false,
             reqwest_client: None,
+            http_options: HttpOptions::default(),
+            #[cfg(feature = "record-replay")]
+            cassette: None,
 
             // OAuth2 settings
             client_id: None,
@@ -120,9 +147,21 @@ impl ClientBuilder {
     ///   missing OAuth2 settings on [`ClientBuilder`] or invalid URLs configured by a custom [`Config`].
     /// - There is an internal issue building a default [`reqwest::Client`]
     /// - There is an internal issue building a default [`Config`]
+    /// - [`Self::strict_user_agent`] is enabled & the user agent does not contain contact information
     pub fn build(self) -> Result<Client, Error> {
         let mut builder = self;
 
+        // The user agent of a provided reqwest client can't be inspected so only validate our own
+        if builder.strict_user_agent
+            && (builder.user_agent.is_some() || builder.reqwest_client.is_none())
+            && !builder
+                .user_agent
+                .as_deref()
+                .is_some_and(UserAgent::has_contact_info)
+        {
+            return Err(Error::ConfigError(ConfigError::InvalidUserAgent));
+        }
+
         // Create a default Config if one is not provided
         let config = match builder.config.take() {
             Some(config) => config,
@@ -130,9 +169,15 @@ impl ClientBuilder {
         };
 
         // Setup a reqwest client
-        // Will create a reqwest client with default settings & provided user_agent if builder.reqwest_client is none
-        let reqwest_client =
-            get_or_default_reqwest_client(builder.reqwest_client.take(), &builder.user_agent)?;
+        // Will create a reqwest client with default settings, provided user_agent & HTTP options if builder.reqwest_client is none
+        let reqwest_client = get_or_default_reqwest_client(
+            builder.reqwest_client.take(),
+            &builder.user_agent,
+            std::mem::take(&mut builder.http_options),
+        )?;
+
+        #[cfg(feature = "record-replay")]
+        let cassette = builder.cassette.take();
 
         // Build an OAuth2 client if any OAuth2 settings are configured
         //
@@ -147,6 +192,15 @@ impl ClientBuilder {
             None
         };
 
+        // Discover OAuth2 endpoints from the server metadata at the origin of the auth URL
+        let oauth2_discovery = if config.oauth2_discovery {
+            let metadata_url = server_metadata_url(config.auth_url.as_str())?;
+
+            Some(OAuth2Discovery::new(metadata_url))
+        } else {
+            None
+        };
+
         // Setup JWT key cache
         let jwt_key_cache = JwtKeyCache::new(&config);
 
@@ -157,9 +211,29 @@ impl ClientBuilder {
             esi_validate_token_before_request: config.esi_validate_token_before_request,
             esi_max_retries: config.esi_max_retries,
             esi_retry_backoff: config.esi_retry_backoff,
+            esi_max_retry_after: config.esi_max_retry_after,
+            esi_error_limit_cool_off: config.esi_error_limit_cool_off,
+            esi_error_limit_threshold: config.esi_error_limit_threshold,
+            esi_max_response_size: config.esi_max_response_size,
+            esi_error_limit_simulation: config
+                .esi_error_limit_simulation
+                .map(SimulatedErrorLimit::new),
+            esi_request_limiter: RequestLimiter::new(config.esi_max_concurrent_requests),
+            esi_route_versions: config.esi_route_versions,
+            esi_error_limit_reset: Mutex::new(None),
+            esi_error_limit_remain: Mutex::new(None),
+            esi_rate_limits: Mutex::new(HashMap::new()),
+            deprecated_routes: DeprecationRegistry::new(),
+            #[cfg(feature = "universe")]
+            name_cache: NameCache::new(config.name_cache_max_size, config.name_cache_ttl),
+            #[cfg(feature = "universe")]
+            factions: OnceCell::new(),
+            #[cfg(feature = "record-replay")]
+            cassette,
 
             // OAuth2
             oauth2_client: oauth_client,
+            oauth2_discovery,
             jwt_key_cache,
             jwt_issuers: config.jwt_issuers,
             jwt_audience: config.jwt_audience,
@@ -240,6 +314,116 @@ impl ClientBuilder {
         self
     }
 
+    /// Enables strict validation of the user agent when building the Client
+    ///
+    /// When enabled, [`Self::build`] returns [`ConfigError::InvalidUserAgent`] if the user agent set
+    /// with [`Self::user_agent`] is missing or does not contain an email address or URL to contact
+    /// the application's developer. The user agent of a client provided with [`Self::reqwest_client`]
+    /// is not validated.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Arguments
+    /// - `enabled` (`bool`): indicates whether or not the user agent is validated
+    ///
+    /// # Returns
+    /// - [`ClientBuilder`]: instance with updated user agent validation configuration.
+    pub fn strict_user_agent(mut self, enabled: bool) -> Self {
+        self.strict_user_agent = enabled;
+        self
+    }
+
+    /// Sets headers sent with every request made by the default reqwest client
+    ///
+    /// Headers set by individual requests, such as the `Authorization` header of authenticated ESI
+    /// routes, take precedence over default headers with the same name. Calling this method again
+    /// adds to the previously set headers.
+    ///
+    /// # Warning
+    /// Default headers will not be applied to the reqwest client provided by the
+    /// [`Self::reqwest_client`] method, instead you should set them on the reqwest client you provide prior.
+    ///
+    /// # Arguments
+    /// - `headers` ([`HeaderMap`]): Headers to send with every request, e.g. `X-Compatibility-Date`
+    ///
+    /// # Returns
+    /// - [`ClientBuilder`]: instance with updated default headers configuration.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.http_options.default_headers.extend(headers);
+        self
+    }
+
+    /// Adds a proxy used by the default reqwest client
+    ///
+    /// Proxies are checked in the order they are added, the first proxy which intercepts a request's
+    /// URL is used. See [`reqwest::Proxy`] for creating HTTP, HTTPS, & SOCKS proxies.
+    ///
+    /// # Warning
+    /// Proxies will not be applied to the reqwest client provided by the [`Self::reqwest_client`]
+    /// method, instead you should set them on the reqwest client you provide prior.
+    ///
+    /// # Arguments
+    /// - `proxy` ([`Proxy`]): Proxy requests are sent through, e.g. `Proxy::https("http://proxy:8080")`
+    ///
+    /// # Returns
+    /// - [`ClientBuilder`]: instance with updated proxy configuration.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.http_options.proxies.push(proxy);
+        self
+    }
+
+    /// Adds a trusted root certificate used by the default reqwest client
+    ///
+    /// Used to trust the certificate of a TLS-intercepting proxy or a mock ESI server in addition
+    /// to the system's trusted certificates.
+    ///
+    /// # Warning
+    /// Root certificates will not be applied to the reqwest client provided by the
+    /// [`Self::reqwest_client`] method, instead you should add them to the reqwest client you provide prior.
+    ///
+    /// # Arguments
+    /// - `certificate` ([`Certificate`]): Root certificate to trust, see [`Certificate::from_pem`]
+    ///
+    /// # Returns
+    /// - [`ClientBuilder`]: instance with updated TLS configuration.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.http_options.root_certificates.push(certificate);
+        self
+    }
+
+    /// Sets the minimum TLS version accepted by the default reqwest client
+    ///
+    /// # Warning
+    /// The minimum TLS version will not be applied to the reqwest client provided by the
+    /// [`Self::reqwest_client`] method, instead you should set it on the reqwest client you provide prior.
+    ///
+    /// # Arguments
+    /// - `version` ([`Version`]): The minimum TLS version, e.g. [`Version::TLS_1_2`]
+    ///
+    /// # Returns
+    /// - [`ClientBuilder`]: instance with updated TLS configuration.
+    pub fn min_tls_version(mut self, version: Version) -> Self {
+        self.http_options.min_tls_version = Some(version);
+        self
+    }
+
+    /// Records ESI responses to or replays them from the provided cassette
+    ///
+    /// See the [cassette module documentation](crate::esi::cassette) for details & usage. Requires
+    /// the `record-replay` feature.
+    ///
+    /// # Arguments
+    /// - `cassette` ([`Cassette`](crate::esi::Cassette)): Cassette created with
+    ///   [`Cassette::record`](crate::esi::Cassette::record) or [`Cassette::replay`](crate::esi::Cassette::replay)
+    ///
+    /// # Returns
+    /// - [`ClientBuilder`]: instance with the configured cassette
+    #[cfg(feature = "record-replay")]
+    pub fn cassette(mut self, cassette: crate::esi::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
     /// Sets the OAuth2 client ID for authentication with EVE Online SSO.
     ///
     /// This method configures the client ID required for OAuth2 authentication.
@@ -298,13 +482,129 @@ impl ClientBuilder {
     }
 }
 
+/// A user agent identifying an application to EVE Online's ESI
+///
+/// Formats the application name, version, & contact information in the format recommended by
+/// EVE Online, e.g. `"MyApp/1.0 (contact@example.com; +https://github.com/your/repository)"`.
+///
+/// ```
+/// use eve_esi::builder::UserAgent;
+///
+/// let user_agent = UserAgent::new("MyApp", "1.0", "contact@example.com")
+///     .repository("https://github.com/your/repository");
+///
+/// let esi_client = eve_esi::Client::builder()
+///     .user_agent(user_agent.as_str())
+///     .strict_user_agent(true)
+///     .build()
+///     .expect("Failed to build ESI Client");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAgent(String);
+
+impl UserAgent {
+    /// Creates a new [`UserAgent`] from an application name, version, & contact
+    ///
+    /// # Arguments
+    /// - `app` (`&str`): Name of the application, e.g. `"MyApp"`
+    /// - `version` (`&str`): Version of the application, e.g. `"1.0"`
+    /// - `contact` (`&str`): Email address or URL used to contact the application's developer
+    ///
+    /// # Returns
+    /// - [`UserAgent`]: Formatted as `"{app}/{version} ({contact})"`
+    pub fn new(app: &str, version: &str, contact: &str) -> Self {
+        Self(format!("{}/{} ({})", app, version, contact))
+    }
+
+    /// Adds the repository of the application to the user agent
+    ///
+    /// # Arguments
+    /// - `url` (`&str`): URL of the application's repository
+    ///
+    /// # Returns
+    /// - [`UserAgent`]: Formatted as `"{app}/{version} ({contact}; +{url})"`
+    pub fn repository(mut self, url: &str) -> Self {
+        self.0.pop();
+        self.0 = format!("{}; +{})", self.0, url);
+        self
+    }
+
+    /// Returns the user agent as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether a user agent contains an email address or URL
+    ///
+    /// # Arguments
+    /// - `user_agent` (`&str`): The user agent to validate
+    pub fn has_contact_info(user_agent: &str) -> bool {
+        user_agent
+            .split(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | ';' | ',' | '<' | '>'))
+            .map(|token| token.trim_start_matches('+'))
+            .any(|token| {
+                if token.starts_with("http://") || token.starts_with("https://") {
+                    return token
+                        .split("://")
+                        .nth(1)
+                        .is_some_and(|host| !host.is_empty());
+                }
+
+                match token.split_once('@') {
+                    Some((local, domain)) => {
+                        !local.is_empty()
+                            && domain
+                                .split_once('.')
+                                .is_some_and(|(name, tld)| !name.is_empty() && !tld.is_empty())
+                    }
+                    None => false,
+                }
+            })
+    }
+}
+
+impl fmt::Display for UserAgent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<UserAgent> for String {
+    fn from(user_agent: UserAgent) -> Self {
+        user_agent.0
+    }
+}
+
+/// Settings applied to the default [`reqwest::Client`] created by [`ClientBuilder::build`]
+#[derive(Default)]
+pub(crate) struct HttpOptions {
+    /// Headers sent with every request
+    pub(crate) default_headers: HeaderMap,
+    /// Proxies requests are sent through
+    pub(crate) proxies: Vec<Proxy>,
+    /// Root certificates trusted in addition to the system's trusted certificates
+    pub(crate) root_certificates: Vec<Certificate>,
+    /// Minimum TLS version accepted
+    pub(crate) min_tls_version: Option<Version>,
+}
+
+impl HttpOptions {
+    /// Returns `true` if no settings differ from the reqwest client defaults
+    fn is_empty(&self) -> bool {
+        self.default_headers.is_empty()
+            && self.proxies.is_empty()
+            && self.root_certificates.is_empty()
+            && self.min_tls_version.is_none()
+    }
+}
+
 /// Utility function that creates a default [`reqwest::Client`] if no client is provided
 ///
 /// Used with the [`ClientBuilder::build`] method to create a default [`reqwest::Client`] with
-/// provided user agent if a reqwest custom client has not been provided.
+/// provided user agent & HTTP options if a reqwest custom client has not been provided.
 ///
-/// Provides a warning if both a custom agent and user agent has been provided as the user agent
-/// cannot be set on the provided client, the user agent should be set on the provided client prior
+/// Provides a warning if both a custom client and user agent or HTTP options have been provided as
+/// they cannot be set on the provided client, they should be set on the provided client prior
 /// instead.
 ///
 /// # Arguments
@@ -312,6 +612,8 @@ impl ClientBuilder {
 ///   should be created and returned.
 /// - `user_agent` (&Option<[`reqwest::Client`]): Option of a user agent that will be applied to the
 ///   default reqwest::Client if no `client` is provided.
+/// - `http_options` ([`HttpOptions`]): Default headers, proxies, & TLS settings that will be applied
+///   to the default reqwest::Client if no `client` is provided.
 ///
 /// # Returns
 /// - [`reqwest::Client`]: Either a default reqwest client or the provided one.
@@ -321,6 +623,7 @@ impl ClientBuilder {
 fn get_or_default_reqwest_client(
     client: Option<reqwest::Client>,
     user_agent: &Option<String>,
+    http_options: HttpOptions,
 ) -> Result<reqwest::Client, Error> {
     if user_agent.is_some() && client.is_some() {
         log::warn!(
@@ -328,6 +631,12 @@ fn get_or_default_reqwest_client(
         );
     }
 
+    if !http_options.is_empty() && client.is_some() {
+        log::warn!(
+            "Default headers, proxies, or TLS settings are set on `ClientBuilder` but so is reqwest_client. They will not be applied and should be instead applied to the provided reqwest client if not done so already."
+        );
+    }
+
     match client {
         Some(client) => Ok(client),
         None => {
@@ -336,6 +645,17 @@ fn get_or_default_reqwest_client(
                 client_builder = client_builder.user_agent(agent.clone());
             }
 
+            client_builder = client_builder.default_headers(http_options.default_headers);
+            for proxy in http_options.proxies {
+                client_builder = client_builder.proxy(proxy);
+            }
+            for certificate in http_options.root_certificates {
+                client_builder = client_builder.add_root_certificate(certificate);
+            }
+            if let Some(version) = http_options.min_tls_version {
+                client_builder = client_builder.min_tls_version(version);
+            }
+
             Ok(client_builder.build()?)
         }
     }
@@ -362,6 +682,8 @@ mod tests {
         assert!(builder.config.is_none());
         assert!(builder.reqwest_client.is_none());
         assert!(builder.user_agent.is_none());
+        assert!(!builder.strict_user_agent);
+        assert!(builder.http_options.is_empty());
         assert!(builder.client_id.is_none());
         assert!(builder.client_secret.is_none());
         assert!(builder.callback_url.is_none());
@@ -511,9 +833,93 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod user_agent_tests {
+    use super::*;
+    use crate::ConfigError;
+
+    /// Test formatting a user agent with & without a repository
+    ///
+    /// # Assertions
+    /// - Assert the user agent is formatted as recommended by EVE Online
+    #[test]
+    fn test_user_agent_format() {
+        let user_agent = UserAgent::new("MyApp", "1.0", "contact@example.com");
+        assert_eq!(user_agent.as_str(), "MyApp/1.0 (contact@example.com)");
+
+        let user_agent = user_agent.repository("https://github.com/your/repository");
+        assert_eq!(
+            user_agent.to_string(),
+            "MyApp/1.0 (contact@example.com; +https://github.com/your/repository)"
+        );
+    }
+
+    /// Test detecting contact information within user agents
+    ///
+    /// # Assertions
+    /// - Assert user agents with an email address or URL contain contact information
+    /// - Assert user agents without either do not
+    #[test]
+    fn test_user_agent_has_contact_info() {
+        assert!(UserAgent::has_contact_info(
+            "MyApp/1.0 (contact@example.com)"
+        ));
+        assert!(UserAgent::has_contact_info(
+            "MyApp/1.0 (+https://github.com/your/repository)"
+        ));
+        assert!(UserAgent::has_contact_info("MyApp/1.0 <dev@example.org>"));
+
+        assert!(!UserAgent::has_contact_info("MyApp/1.0"));
+        assert!(!UserAgent::has_contact_info("MyApp/1.0 (@character)"));
+        assert!(!UserAgent::has_contact_info(
+            "MyApp/1.0 (contact@localhost)"
+        ));
+        assert!(!UserAgent::has_contact_info("MyApp/1.0 (https://)"));
+    }
+
+    /// Test strict user agent validation when building the Client
+    ///
+    /// # Setup
+    /// - Build clients with strict user agent validation enabled
+    ///
+    /// # Assertions
+    /// - Assert a user agent with contact information builds successfully
+    /// - Assert a missing or invalid user agent returns [`ConfigError::InvalidUserAgent`]
+    /// - Assert a provided reqwest client without a user agent is not validated
+    #[test]
+    fn test_strict_user_agent() {
+        let result = ClientBuilder::new()
+            .user_agent("MyApp/1.0 (contact@example.com)")
+            .strict_user_agent(true)
+            .build();
+        assert!(result.is_ok());
+
+        let result = ClientBuilder::new()
+            .user_agent("MyApp/1.0")
+            .strict_user_agent(true)
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::ConfigError(ConfigError::InvalidUserAgent))
+        ));
+
+        let result = ClientBuilder::new().strict_user_agent(true).build();
+        assert!(matches!(
+            result,
+            Err(Error::ConfigError(ConfigError::InvalidUserAgent))
+        ));
+
+        let result = ClientBuilder::new()
+            .reqwest_client(reqwest::Client::new())
+            .strict_user_agent(true)
+            .build();
+        assert!(result.is_ok());
+    }
+}
+
 #[cfg(test)]
 mod get_or_default_reqwest_client_tests {
-    use crate::builder::get_or_default_reqwest_client;
+    use crate::builder::{get_or_default_reqwest_client, HttpOptions};
 
     /// Ensures a [`reqwest::Client`] is returned when a reqwest client & user agent is provided
     ///
@@ -540,7 +946,8 @@ mod get_or_default_reqwest_client_tests {
         // Call function
         //
         // The provided agent won't be used but we'll add it to make sure the warning execution path is called
-        let result = get_or_default_reqwest_client(Some(client), &Some(user_agent));
+        let result =
+            get_or_default_reqwest_client(Some(client), &Some(user_agent), HttpOptions::default());
 
         // Assert result is Ok
         assert!(result.is_ok());
@@ -555,7 +962,8 @@ mod get_or_default_reqwest_client_tests {
     /// - Assert result is Ok indicating a default reqwest client with default settings has been returned
     #[test]
     fn test_default_with_agent() {
-        let result = get_or_default_reqwest_client(None, &Some("Agent".to_string()));
+        let result =
+            get_or_default_reqwest_client(None, &Some("Agent".to_string()), HttpOptions::default());
 
         // Assert result is Ok
         assert!(result.is_ok());
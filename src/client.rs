@@ -27,11 +27,31 @@
 //!
 //! Example: `"MyApp/1.0 (contact@example.com; +https://github.com/your/repo)"`
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+#[cfg(feature = "universe")]
+use tokio::sync::OnceCell;
+
 use crate::builder::ClientBuilder;
+#[cfg(feature = "universe")]
+use crate::esi::NameCache;
+use crate::esi::{
+    DeprecatedRoute, DeprecationRegistry, RateLimitHeaders, RequestLimiter, SimulatedErrorLimit,
+};
+#[cfg(feature = "universe")]
+use crate::model::universe::Faction;
+#[cfg(all(
+    feature = "alliance",
+    feature = "character",
+    feature = "corporation",
+    feature = "universe"
+))]
+use crate::model::{enums::universe::UniverseNameCategory, universe::Entity};
 use crate::oauth2::client::OAuth2Client;
+use crate::oauth2::discovery::OAuth2Discovery;
 use crate::oauth2::jwk::cache::JwtKeyCache;
 use crate::Error;
 
@@ -64,6 +84,37 @@ pub(crate) struct ClientRef {
     pub(crate) esi_max_retries: u32,
     /// Backoff period between ESI request retries
     pub(crate) esi_retry_backoff: Duration,
+    /// Maximum time to wait for a `Retry-After` header before retrying an ESI request
+    pub(crate) esi_max_retry_after: Duration,
+    /// Enable/disable parking ESI requests until the error limit resets after a 420 response
+    pub(crate) esi_error_limit_cool_off: bool,
+    /// Remaining ESI errors below which bounded concurrency helpers stop starting requests
+    pub(crate) esi_error_limit_threshold: u32,
+    /// Maximum size in bytes of an ESI response body, `None` for no limit
+    pub(crate) esi_max_response_size: Option<u64>,
+    /// Simulated ESI error limit enforced by the client, `None` to rely on ESI's error limit
+    pub(crate) esi_error_limit_simulation: Option<SimulatedErrorLimit>,
+    /// Limit & count of ESI requests in flight
+    pub(crate) esi_request_limiter: RequestLimiter,
+    /// Version pinned for ESI routes by path template, overriding the version of the endpoint
+    pub(crate) esi_route_versions: HashMap<String, String>,
+    /// Time at which the ESI error limit resets after the most recent 420 response
+    pub(crate) esi_error_limit_reset: Mutex<Option<DateTime<Utc>>>,
+    /// Remaining errors & reset time of the ESI error limit from the most recent response
+    pub(crate) esi_error_limit_remain: Mutex<Option<(u32, DateTime<Utc>)>>,
+    /// Rate limit headers of the most recent ESI response of each rate limit group
+    pub(crate) esi_rate_limits: Mutex<HashMap<String, RateLimitHeaders>>,
+    /// Registry of deprecated ESI routes which have been used by the client
+    pub(crate) deprecated_routes: DeprecationRegistry,
+    /// Cache of names resolved from IDs
+    #[cfg(feature = "universe")]
+    pub(crate) name_cache: NameCache,
+    /// NPC factions by ID, retrieved once by [`UniverseEndpoints::get_faction_lookup`](crate::endpoints::universe::UniverseEndpoints::get_faction_lookup)
+    #[cfg(feature = "universe")]
+    pub(crate) factions: OnceCell<Arc<HashMap<i64, Faction>>>,
+    /// Cassette ESI responses are recorded to or replayed from
+    #[cfg(feature = "record-replay")]
+    pub(crate) cassette: Option<crate::esi::Cassette>,
 
     // OAuth2 Settings
     /// OAuth2 client used for accessing EVE Online OAuth2 endpoints
@@ -71,6 +122,8 @@ pub(crate) struct ClientRef {
     /// Will be None if `client_id`, `client_secret`, and `callback_url` have not been
     /// set on the [`Client`] which will result in errors if trying to use OAuth2-related endpoints.
     pub(crate) oauth2_client: Option<OAuth2Client>,
+    /// Endpoints discovered from the SSO's server metadata, `None` if discovery is disabled
+    pub(crate) oauth2_discovery: Option<OAuth2Discovery>,
     /// Cache containing JWT keys for validating OAuth2 tokens and fields for coordinating
     /// cache usage & refreshes across threads.
     pub(crate) jwt_key_cache: JwtKeyCache,
@@ -106,6 +159,136 @@ impl Client {
     pub fn builder() -> ClientBuilder {
         ClientBuilder::new()
     }
+
+    /// Shuts down the [`Client`]'s background tasks
+    ///
+    /// Aborts any outstanding background JWT key refresh tasks and prevents new ones from being
+    /// spawned, allowing applications to drain cleanly during a graceful shutdown. As the [`Client`]
+    /// uses an [`Arc`] internally, this affects all clones of the client.
+    ///
+    /// The client remains usable for ESI & OAuth2 requests after shutdown, JWT keys will still be
+    /// fetched when the cache is empty or expired but will no longer be refreshed proactively.
+    ///
+    /// # Returns
+    /// - [`usize`]: The number of outstanding background tasks which were aborted
+    pub async fn shutdown(&self) -> usize {
+        self.inner.jwt_key_cache.shutdown().await
+    }
+
+    /// Returns the number of ESI requests the [`Client`] currently has in flight
+    ///
+    /// A request is in flight from being sent until its response body has been read, including
+    /// retries. Requests queued by
+    /// [`ConfigBuilder::esi_max_concurrent_requests`](crate::ConfigBuilder::esi_max_concurrent_requests)
+    /// are not counted until they are sent.
+    pub fn in_flight_requests(&self) -> usize {
+        self.inner.esi_request_limiter.in_flight()
+    }
+
+    /// Returns a report of deprecated ESI routes which have been used by the [`Client`]
+    ///
+    /// Endpoints for ESI routes which CCP has deprecated or announced for removal are marked
+    /// as deprecated, a warning is logged the first time one is used & the route is recorded
+    /// for this report. Check the report during development or testing to find routes your
+    /// application relies on before they are removed.
+    ///
+    /// # Returns
+    /// - `Vec<`[`DeprecatedRoute`]`>`: The deprecated routes used by the client, sorted by path
+    pub fn deprecated_routes(&self) -> Vec<DeprecatedRoute> {
+        self.inner.deprecated_routes.report()
+    }
+
+    /// Returns the [`Client`]'s cache of names resolved from IDs
+    ///
+    /// Names resolved by [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names)
+    /// & [`Self::entity`] are cached & reused until they expire, see the
+    /// [name cache module documentation](crate::esi::name_cache). As the [`Client`] uses an [`Arc`]
+    /// internally, the cache is shared between all clones of the client.
+    #[cfg(feature = "universe")]
+    pub fn name_cache(&self) -> &NameCache {
+        &self.inner.name_cache
+    }
+
+    /// Determines whether an ID belongs to a character, corporation or alliance & retrieves its public information
+    ///
+    /// Resolves the ID's category with
+    /// [`UniverseEndpoints::get_names_and_categories`](crate::endpoints::universe::UniverseEndpoints::get_names_and_categories)
+    /// unless it is held by the [`Self::name_cache`], then requests the public information of the
+    /// character, corporation or alliance.
+    ///
+    /// Requires the `alliance`, `character`, `corporation` & `universe` features.
+    ///
+    /// # Arguments
+    /// - `id` (`i64`): The ID of a character, corporation or alliance
+    ///
+    /// # Returns
+    /// - `Ok(Some(`[`Entity`]`))`: The public information of the character, corporation or alliance
+    /// - `Ok(None)`: If the ID belongs to another category, such as a solar system or type
+    /// - `Err(`[`Error`]`)`: If either request fails, such as when the ID is invalid
+    #[cfg(all(
+        feature = "alliance",
+        feature = "character",
+        feature = "corporation",
+        feature = "universe"
+    ))]
+    pub async fn entity(&self, id: i64) -> Result<Option<Entity>, Error> {
+        let category = match self.name_cache().get(id) {
+            Some(name) => Some(name.category),
+            None => {
+                let names = self
+                    .universe()
+                    .get_names_and_categories(vec![id])
+                    .send()
+                    .await?
+                    .data;
+                let category = names
+                    .iter()
+                    .find(|name| name.id == id)
+                    .map(|name| name.category);
+
+                self.name_cache().insert(names);
+
+                category
+            }
+        };
+
+        let Some(category) = category else {
+            return Ok(None);
+        };
+
+        let entity = match category {
+            UniverseNameCategory::Alliance => Entity::Alliance {
+                alliance_id: id,
+                alliance: self
+                    .alliance()
+                    .get_alliance_information(id)
+                    .send()
+                    .await?
+                    .data,
+            },
+            UniverseNameCategory::Character => Entity::Character {
+                character_id: id,
+                character: self
+                    .character()
+                    .get_character_public_information(id)
+                    .send()
+                    .await?
+                    .data,
+            },
+            UniverseNameCategory::Corporation => Entity::Corporation {
+                corporation_id: id,
+                corporation: self
+                    .corporation()
+                    .get_corporation_information(id)
+                    .send()
+                    .await?
+                    .data,
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(entity))
+    }
 }
 
 #[cfg(test)]
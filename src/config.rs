@@ -6,12 +6,17 @@
 //!
 //! ## Features
 //!
+//! - Target an [`Environment`] preset which sets all ESI & OAuth2 URLs together
 //! - Override the base ESI URL
 //! - Override EVE Online OAuth2 authorization, JWT key, and token endpoint URLs
+//! - Discover the OAuth2 endpoint URLs from the SSO's server metadata rather than using the configured URLs
 //! - Adjust expiration time & threshold for a proactive refresh for the JWT key cache used to validate tokens
 //! - Adjust the timeout between sets of JWT key refresh attempts
 //! - Adjust backoff period (wait time) beteween attempts and how many retries should be made to refresh JWT keys
 //! - Enable/disable the proactive background JWT key refresh
+//! - Adjust the maximum size & time to live of the name cache used when resolving IDs to names
+//! - Simulate ESI's error limit to load test how an application handles 420 responses
+//! - Pin the version of individual ESI routes
 //!
 //! ## Usage
 //!
@@ -36,19 +41,94 @@
 //!     .expect("Failed to build ESI Client");
 //! ```
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use oauth2::{AuthUrl, TokenUrl};
 
+#[cfg(feature = "universe")]
+use crate::constant::{DEFAULT_NAME_CACHE_MAX_SIZE, DEFAULT_NAME_CACHE_TTL};
 use crate::{
     constant::{
-        DEFAULT_AUTH_URL, DEFAULT_ESI_MAX_RETRIES, DEFAULT_ESI_RETRY_BACKOFF, DEFAULT_ESI_URL,
-        DEFAULT_JWT_AUDIENCE, DEFAULT_JWT_ISSUERS, DEFAULT_TOKEN_URL,
+        DEFAULT_AUTH_URL, DEFAULT_ESI_ERROR_LIMIT_THRESHOLD, DEFAULT_ESI_MAX_RETRIES,
+        DEFAULT_ESI_MAX_RETRY_AFTER, DEFAULT_ESI_RETRY_BACKOFF, DEFAULT_ESI_URL, DEFAULT_JWK_URL,
+        DEFAULT_JWT_AUDIENCE, DEFAULT_JWT_ISSUERS, DEFAULT_TOKEN_URL, SERENITY_AUTH_URL,
+        SERENITY_ESI_URL, SERENITY_JWK_URL, SERENITY_JWT_ISSUERS, SERENITY_TOKEN_URL,
     },
     error::{ConfigError, Error},
+    esi::ErrorLimitSimulation,
     oauth2::jwk::cache::JwtKeyCacheConfig,
 };
 
+/// EVE Online server environment targeted by the [`Client`](crate::Client)
+///
+/// Each environment provides a consistent set of ESI, OAuth2 authorization, token, & JWK URLs
+/// alongside the expected JWT issuers, applied together with [`ConfigBuilder::environment`] to
+/// prevent mismatched combinations, such as validating tokens against the keys of another server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Environment {
+    /// The Tranquility (global) server, used by default
+    Tranquility,
+    /// The Serenity (China) server
+    Serenity,
+    /// A custom deployment, such as a mock server, with the provided base URL
+    ///
+    /// All endpoints are derived from the base URL using the same paths as Tranquility:
+    /// - ESI: `{base_url}`
+    /// - Authorization: `{base_url}/v2/oauth/authorize`
+    /// - Token: `{base_url}/v2/oauth/token`
+    /// - JWK: `{base_url}/oauth/jwks`
+    /// - JWT issuer: `{base_url}`
+    Custom(String),
+}
+
+impl Environment {
+    /// Returns the base ESI URL of the environment
+    pub fn esi_url(&self) -> String {
+        match self {
+            Environment::Tranquility => DEFAULT_ESI_URL.to_string(),
+            Environment::Serenity => SERENITY_ESI_URL.to_string(),
+            Environment::Custom(base_url) => base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Returns the OAuth2 authorization URL of the environment
+    pub fn auth_url(&self) -> String {
+        match self {
+            Environment::Tranquility => DEFAULT_AUTH_URL.to_string(),
+            Environment::Serenity => SERENITY_AUTH_URL.to_string(),
+            Environment::Custom(_) => format!("{}/v2/oauth/authorize", self.esi_url()),
+        }
+    }
+
+    /// Returns the OAuth2 token URL of the environment
+    pub fn token_url(&self) -> String {
+        match self {
+            Environment::Tranquility => DEFAULT_TOKEN_URL.to_string(),
+            Environment::Serenity => SERENITY_TOKEN_URL.to_string(),
+            Environment::Custom(_) => format!("{}/v2/oauth/token", self.esi_url()),
+        }
+    }
+
+    /// Returns the OAuth2 JWK URL of the environment
+    pub fn jwk_url(&self) -> String {
+        match self {
+            Environment::Tranquility => DEFAULT_JWK_URL.to_string(),
+            Environment::Serenity => SERENITY_JWK_URL.to_string(),
+            Environment::Custom(_) => format!("{}/oauth/jwks", self.esi_url()),
+        }
+    }
+
+    /// Returns the expected issuers of JWT tokens for the environment
+    pub fn jwt_issuers(&self) -> Vec<String> {
+        match self {
+            Environment::Tranquility => DEFAULT_JWT_ISSUERS.iter().map(|i| i.to_string()).collect(),
+            Environment::Serenity => SERENITY_JWT_ISSUERS.iter().map(|i| i.to_string()).collect(),
+            Environment::Custom(_) => vec![self.esi_url()],
+        }
+    }
+}
+
 /// Configuration settings for the [`Client`](crate::Client)
 ///
 /// For a full overview, features, and usage examples, see the [module-level documentation](self).
@@ -60,6 +140,8 @@ pub struct Config {
     pub(crate) auth_url: AuthUrl,
     /// Token URL which provides an access token for authenticated ESI endpoints
     pub(crate) token_url: TokenUrl,
+    /// Enable/disable discovering the OAuth2 endpoint URLs from the SSO's server metadata
+    pub(crate) oauth2_discovery: bool,
 
     // JWT Key Settings
     /// Config for JWT key caching & refreshing
@@ -76,6 +158,28 @@ pub struct Config {
     pub(crate) esi_max_retries: u32,
     /// Backoff period between ESI request retries
     pub(crate) esi_retry_backoff: Duration,
+    /// Maximum time to wait for a `Retry-After` header before retrying an ESI request
+    pub(crate) esi_max_retry_after: Duration,
+    /// Enable/disable parking ESI requests until the error limit resets after a 420 response
+    pub(crate) esi_error_limit_cool_off: bool,
+    /// Remaining ESI errors below which bounded concurrency helpers stop starting requests
+    pub(crate) esi_error_limit_threshold: u32,
+    /// Maximum size in bytes of an ESI response body, `None` for no limit
+    pub(crate) esi_max_response_size: Option<u64>,
+    /// Simulated ESI error limit enforced by the client, `None` to rely on ESI's error limit
+    pub(crate) esi_error_limit_simulation: Option<ErrorLimitSimulation>,
+    /// Maximum number of ESI requests in flight at once, `None` for no limit
+    pub(crate) esi_max_concurrent_requests: Option<usize>,
+    /// Version pinned for ESI routes by path template, overriding the version of the endpoint
+    pub(crate) esi_route_versions: HashMap<String, String>,
+
+    // Name Cache Settings
+    /// Maximum number of names held by the client's name cache
+    #[cfg(feature = "universe")]
+    pub(crate) name_cache_max_size: usize,
+    /// Time after which names held by the client's name cache expire
+    #[cfg(feature = "universe")]
+    pub(crate) name_cache_ttl: Duration,
 }
 
 /// Builder struct for configuring & constructing an [`Config`] to override default [`Client`](crate::Client) settings
@@ -89,6 +193,8 @@ pub struct ConfigBuilder {
     pub(crate) auth_url: String,
     /// Token URL which provides an access token for authenticated ESI endpoints
     pub(crate) token_url: String,
+    /// Enable/disable discovering the OAuth2 endpoint URLs from the SSO's server metadata
+    pub(crate) oauth2_discovery: bool,
 
     // OAuth2 JWT key config
     /// Config for OAuth2 JWT key caching & refreshing
@@ -105,6 +211,28 @@ pub struct ConfigBuilder {
     pub(crate) esi_max_retries: u32,
     /// Backoff period between ESI request retries
     pub(crate) esi_retry_backoff: Duration,
+    /// Maximum time to wait for a `Retry-After` header before retrying an ESI request
+    pub(crate) esi_max_retry_after: Duration,
+    /// Enable/disable parking ESI requests until the error limit resets after a 420 response
+    pub(crate) esi_error_limit_cool_off: bool,
+    /// Remaining ESI errors below which bounded concurrency helpers stop starting requests
+    pub(crate) esi_error_limit_threshold: u32,
+    /// Maximum size in bytes of an ESI response body, `None` for no limit
+    pub(crate) esi_max_response_size: Option<u64>,
+    /// Simulated ESI error limit enforced by the client, `None` to rely on ESI's error limit
+    pub(crate) esi_error_limit_simulation: Option<ErrorLimitSimulation>,
+    /// Maximum number of ESI requests in flight at once, `None` for no limit
+    pub(crate) esi_max_concurrent_requests: Option<usize>,
+    /// Version pinned for ESI routes by path template, overriding the version of the endpoint
+    pub(crate) esi_route_versions: HashMap<String, String>,
+
+    // Name Cache Settings
+    /// Maximum number of names held by the client's name cache
+    #[cfg(feature = "universe")]
+    pub(crate) name_cache_max_size: usize,
+    /// Time after which names held by the client's name cache expire
+    #[cfg(feature = "universe")]
+    pub(crate) name_cache_ttl: Duration,
 }
 
 impl Config {
@@ -162,6 +290,7 @@ impl ConfigBuilder {
             esi_url: DEFAULT_ESI_URL.to_string(),
             auth_url: DEFAULT_AUTH_URL.to_string(),
             token_url: DEFAULT_TOKEN_URL.to_string(),
+            oauth2_discovery: false,
 
             // OAuth2 JWT key config
             jwt_key_cache_config: JwtKeyCacheConfig::new(),
@@ -172,6 +301,19 @@ impl ConfigBuilder {
             esi_validate_token_before_request: true,
             esi_max_retries: DEFAULT_ESI_MAX_RETRIES,
             esi_retry_backoff: DEFAULT_ESI_RETRY_BACKOFF,
+            esi_max_retry_after: DEFAULT_ESI_MAX_RETRY_AFTER,
+            esi_error_limit_cool_off: false,
+            esi_error_limit_threshold: DEFAULT_ESI_ERROR_LIMIT_THRESHOLD,
+            esi_max_response_size: None,
+            esi_error_limit_simulation: None,
+            esi_max_concurrent_requests: None,
+            esi_route_versions: HashMap::new(),
+
+            // Name Cache Settings
+            #[cfg(feature = "universe")]
+            name_cache_max_size: DEFAULT_NAME_CACHE_MAX_SIZE,
+            #[cfg(feature = "universe")]
+            name_cache_ttl: DEFAULT_NAME_CACHE_TTL,
         }
     }
 
@@ -190,6 +332,7 @@ impl ConfigBuilder {
     /// - The [`Self::jwk_background_refresh_threshold`] method is given a value less than 1 or over 99
     /// - The [`Self::auth_url`] method is given an invalid URL
     /// - The [`Self::token_url`] method is given an invalid URL
+    /// - The [`Self::esi_max_concurrent_requests`] method is given `0`
     pub fn build(self) -> Result<Config, Error> {
         // Ensure background refresh percentage is set properly
         if self.jwt_key_cache_config.background_refresh_threshold == 0 {
@@ -203,6 +346,13 @@ impl ConfigBuilder {
             ));
         }
 
+        // Ensure at least 1 request can be in flight
+        if self.esi_max_concurrent_requests == Some(0) {
+            return Err(Error::ConfigError(
+                ConfigError::InvalidMaxConcurrentRequests,
+            ));
+        }
+
         // Parse URLs
         let auth_url = match AuthUrl::new(self.auth_url.clone()) {
             Ok(url) => url,
@@ -220,6 +370,7 @@ impl ConfigBuilder {
             esi_url: self.esi_url,
             auth_url,
             token_url,
+            oauth2_discovery: self.oauth2_discovery,
 
             // JWT key cache settings
             jwt_key_cache_config: self.jwt_key_cache_config,
@@ -230,9 +381,42 @@ impl ConfigBuilder {
             esi_validate_token_before_request: self.esi_validate_token_before_request,
             esi_max_retries: self.esi_max_retries,
             esi_retry_backoff: self.esi_retry_backoff,
+            esi_max_retry_after: self.esi_max_retry_after,
+            esi_error_limit_cool_off: self.esi_error_limit_cool_off,
+            esi_error_limit_threshold: self.esi_error_limit_threshold,
+            esi_max_response_size: self.esi_max_response_size,
+            esi_error_limit_simulation: self.esi_error_limit_simulation,
+            esi_max_concurrent_requests: self.esi_max_concurrent_requests,
+            esi_route_versions: self.esi_route_versions,
+
+            // Name Cache Settings
+            #[cfg(feature = "universe")]
+            name_cache_max_size: self.name_cache_max_size,
+            #[cfg(feature = "universe")]
+            name_cache_ttl: self.name_cache_ttl,
         })
     }
 
+    /// Sets the ESI & OAuth2 URLs to those of an EVE Online server environment
+    ///
+    /// Overrides the [`Self::esi_url`], [`Self::auth_url`], [`Self::token_url`], [`Self::jwk_url`], &
+    /// [`Self::jwt_issuers`] settings together. Individual URLs can still be overridden afterwards.
+    ///
+    /// # Arguments
+    /// - `environment` ([`Environment`]): The environment to target, e.g. [`Environment::Serenity`] or
+    ///   [`Environment::Custom`] with the URL of a mock server.
+    ///
+    /// # Returns
+    /// - [`ConfigBuilder`]: Instance with the URLs of the environment.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.esi_url = environment.esi_url();
+        self.auth_url = environment.auth_url();
+        self.token_url = environment.token_url();
+        self.jwt_key_cache_config.jwk_url = environment.jwk_url();
+        self.jwt_issuers = environment.jwt_issuers();
+        self
+    }
+
     /// Sets the EVE Online ESI base URL
     ///
     /// This method configures the base URL for EVE Online ESI.
@@ -297,6 +481,23 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enable/disable discovering the OAuth2 endpoint URLs from the SSO's server metadata
+    ///
+    /// When enabled, the authorization, token & JWK URLs are retrieved from the
+    /// `/.well-known/oauth-authorization-server` metadata at the origin of the [`Self::auth_url`]
+    /// on first use, falling back to the configured URLs until the metadata can be retrieved.
+    /// Disabled by default, see the [discovery module documentation](crate::oauth2::discovery).
+    ///
+    /// # Arguments
+    /// - `enabled` (`bool`): indicates whether the OAuth2 endpoint URLs are discovered
+    ///
+    /// # Returns
+    /// - [`ConfigBuilder`]: Instance with updated OAuth2 discovery setting.
+    pub fn oauth2_discovery(mut self, enabled: bool) -> Self {
+        self.oauth2_discovery = enabled;
+        self
+    }
+
     /// Modifies the default lifetime of the JWT keys stored in cache
     ///
     /// By default, JWT keys are stored in cache for 3600 seconds (1 hour)
@@ -460,6 +661,33 @@ impl ConfigBuilder {
         self
     }
 
+    /// Adds a JWT issuer validated using its own set of JWT keys
+    ///
+    /// EVE Online has changed its SSO hostnames before, during such transitions tokens
+    /// may be issued by either hostname with each providing its own JWT keys. Tokens with
+    /// this issuer will be validated using keys fetched from the provided JWK URL which are
+    /// cached separately from the keys of the default [`Self::jwk_url`].
+    ///
+    /// The issuer is added to the expected issuers if not already present, as such this
+    /// should be called after [`Self::jwt_issuers`] if both are used.
+    ///
+    /// # Arguments
+    /// - `issuer` (&[`str`]): The issuer of the JWT tokens, e.g. `"https://login.eveonline.com"`
+    /// - `jwk_url` (&[`str`]): The JWK endpoint URL providing the keys for the issuer
+    ///
+    /// # Returns
+    /// - [`ConfigBuilder`]: Instance with the additional issuer & its JWK URL.
+    pub fn jwt_issuer_jwk_url(mut self, issuer: &str, jwk_url: &str) -> Self {
+        if !self.jwt_issuers.iter().any(|i| i == issuer) {
+            self.jwt_issuers.push(issuer.to_string());
+        }
+
+        self.jwt_key_cache_config
+            .issuer_jwk_urls
+            .insert(issuer.to_string(), jwk_url.to_string());
+        self
+    }
+
     /// Intended audience JWT tokens are to be used with
     ///
     /// The intended audience which the JSON web tokens (JWTs) used to access authenticated
@@ -493,8 +721,12 @@ impl ConfigBuilder {
     /// Set the maximum number of retries for ESI requests on 5xx errors
     ///
     /// When an ESI request receives a 5xx server error, it will be retried up to this many times
-    /// with exponential backoff. Requests with 4xx errors or successful responses return immediately
-    /// without retrying. Default is 2 retries.
+    /// with exponential backoff, or after its `Retry-After` header if present, see
+    /// [`Self::esi_max_retry_after`]. Requests with 4xx errors or successful responses return
+    /// immediately without retrying. Default is 2 retries.
+    ///
+    /// Requests which may duplicate their effect when repeated, such as sending a mail, are never
+    /// retried, see [`Idempotency`](crate::esi::Idempotency).
     ///
     /// # Arguments
     /// - `max_retries` - Maximum number of retry attempts for 5xx errors
@@ -518,6 +750,132 @@ impl ConfigBuilder {
         self.esi_retry_backoff = backoff;
         self
     }
+
+    /// Set the maximum time to wait before retrying an ESI request which returned a `Retry-After` header
+    ///
+    /// When a 5xx response includes a `Retry-After` header, such as the 503 responses returned during
+    /// ESI's daily downtime, the retry waits for the duration of the header rather than the exponential
+    /// backoff, capped to this maximum. Default is 30 seconds.
+    ///
+    /// If the request still fails, 503 responses with a `Retry-After` header return [`Error::Downtime`].
+    ///
+    /// # Arguments
+    /// - `max_retry_after` - Maximum time to wait for a `Retry-After` header between retry attempts
+    pub fn esi_max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.esi_max_retry_after = max_retry_after;
+        self
+    }
+
+    /// Enable/disable automatic cool-off after ESI returns a 420 error limited response
+    ///
+    /// When ESI returns a 420 the request fails with [`Error::ErrorLimited`] regardless of this setting.
+    /// If enabled, subsequent ESI requests made by the client are parked until the `X-ESI-Error-Limit-Reset`
+    /// window has passed rather than being sent, as further requests while error limited extend the ban.
+    /// Disabled by default.
+    ///
+    /// # Arguments
+    /// - `enabled` (`bool`): indicates whether or not ESI requests wait for the error limit to reset after a 420
+    pub fn esi_error_limit_cool_off(mut self, enabled: bool) -> Self {
+        self.esi_error_limit_cool_off = enabled;
+        self
+    }
+
+    /// Set the number of remaining ESI errors below which bounded concurrency helpers stop starting requests
+    ///
+    /// [`try_join_limited`](crate::esi::concurrency::try_join_limited),
+    /// [`join_limited_partial`](crate::esi::concurrency::join_limited_partial) & the composite helpers
+    /// built on them check the `X-ESI-Error-Limit-Remain` header of the client's most recent response
+    /// before starting each request. Below the threshold they wait for the error limit to reset if
+    /// [`Self::esi_error_limit_cool_off`] is enabled, otherwise requests fail with
+    /// [`Error::ErrorLimitThreshold`] without being sent. Defaults to 10 errors, `0` disables the check.
+    ///
+    /// # Arguments
+    /// - `remaining` (`u32`): Remaining errors below which no further requests are started
+    pub fn esi_error_limit_threshold(mut self, remaining: u32) -> Self {
+        self.esi_error_limit_threshold = remaining;
+        self
+    }
+
+    /// Set the maximum size of an ESI response body
+    ///
+    /// Requests fail with [`Error::ResponseTooLarge`] once a response body exceeds the limit rather than
+    /// buffering it entirely into memory. Can be overridden per request with
+    /// [`EsiRequest::with_max_response_size`](crate::EsiRequest::with_max_response_size). No limit by default.
+    ///
+    /// # Arguments
+    /// - `bytes` (`u64`): Maximum size of a response body in bytes
+    pub fn esi_max_response_size(mut self, bytes: u64) -> Self {
+        self.esi_max_response_size = Some(bytes);
+        self
+    }
+
+    /// Enforce a simulated ESI error limit to load test how an application handles 420 responses
+    ///
+    /// Intended for tests against a mock server: error responses count against the simulation's budget
+    /// & once it is spent requests fail with [`Error::ErrorLimited`] without being sent until the window
+    /// resets, see the [error limit module documentation](crate::esi::error_limit). Disabled by default.
+    ///
+    /// # Arguments
+    /// - `simulation` ([`ErrorLimitSimulation`]): Budget & window of the simulated error limit
+    pub fn esi_error_limit_simulation(mut self, simulation: ErrorLimitSimulation) -> Self {
+        self.esi_error_limit_simulation = Some(simulation);
+        self
+    }
+
+    /// Set the maximum number of ESI requests the client has in flight at once
+    ///
+    /// Further requests queue until a request in flight completes, preventing application code which
+    /// sends thousands of requests at once from opening a connection to ESI for each, see the
+    /// [concurrency module documentation](crate::esi::concurrency#client-request-limit). No limit by default.
+    ///
+    /// # Arguments
+    /// - `max` (`usize`): Maximum number of requests in flight, must be at least `1`
+    pub fn esi_max_concurrent_requests(mut self, max: usize) -> Self {
+        self.esi_max_concurrent_requests = Some(max);
+        self
+    }
+
+    /// Pin the version of an ESI route
+    ///
+    /// Requests for the route are sent to `/{version}{route}` rather than the version declared by the
+    /// endpoint, or the unversioned route if it declares none. Use to keep a route on a version whose
+    /// schema matches the crate's models when CCP changes the default version of a route before the
+    /// crate is updated, or `"latest"` to follow the default version. Can be called once per route.
+    ///
+    /// # Arguments
+    /// - `route` (&[`str`]): Path template of the route as documented on its endpoint, e.g.
+    ///   `"/characters/{}/assets"`
+    /// - `version` (&[`str`]): Version to request, e.g. `"v5"`
+    pub fn esi_route_version(mut self, route: &str, version: &str) -> Self {
+        self.esi_route_versions
+            .insert(route.to_string(), version.to_string());
+        self
+    }
+
+    /// Set the maximum number of names held by the client's name cache
+    ///
+    /// Once the cache is full the oldest names are evicted, see [`NameCache`](crate::esi::NameCache).
+    /// Set to `0` to disable caching names. Default is 100,000 names.
+    ///
+    /// # Arguments
+    /// - `max_size` (`usize`): Maximum number of names held by the cache
+    #[cfg(feature = "universe")]
+    pub fn name_cache_max_size(mut self, max_size: usize) -> Self {
+        self.name_cache_max_size = max_size;
+        self
+    }
+
+    /// Set the time after which names held by the client's name cache expire
+    ///
+    /// Expired names are resolved from ESI again the next time they are requested. Default is 24 hours.
+    ///
+    /// # Arguments
+    /// - `ttl` (`Duration`): Time after which a cached name expires
+    #[cfg(feature = "universe")]
+    pub fn name_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.name_cache_ttl = ttl;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -543,6 +901,7 @@ mod tests {
             .auth_url("https://example.com")
             .token_url("https://example.com")
             .jwk_url("https://example.com")
+            .oauth2_discovery(true)
             // JWT key settings
             .jwk_cache_ttl(zero_seconds)
             .jwk_refresh_backoff(zero_seconds)
@@ -557,6 +916,13 @@ mod tests {
             .jwt_audience("example")
             // ESI Request Settings
             .esi_validate_token_before_request(false)
+            .esi_error_limit_cool_off(true)
+            .esi_error_limit_threshold(5)
+            .esi_max_retry_after(zero_seconds)
+            .esi_max_response_size(1024)
+            .esi_error_limit_simulation(ErrorLimitSimulation::new(5, zero_seconds))
+            .esi_max_concurrent_requests(8)
+            .esi_route_version("/characters/{}/assets", "v5")
             .build()
             .expect("Failed to build Config");
 
@@ -567,6 +933,7 @@ mod tests {
         assert_eq!(config.auth_url, auth_url);
         assert_eq!(config.token_url, token_url);
         assert_eq!(config.jwt_key_cache_config.jwk_url, "https://example.com");
+        assert!(config.oauth2_discovery);
 
         // Assert JWT key settings were set
         assert_eq!(config.jwt_key_cache_config.cache_ttl, zero_seconds);
@@ -584,7 +951,119 @@ mod tests {
         assert_eq!(config.jwt_audience, "example");
 
         // Assert ESI request settings was set
-        assert!(!config.esi_validate_token_before_request)
+        assert!(!config.esi_validate_token_before_request);
+        assert!(config.esi_error_limit_cool_off);
+        assert_eq!(config.esi_error_limit_threshold, 5);
+        assert_eq!(config.esi_max_retry_after, zero_seconds);
+        assert_eq!(config.esi_max_response_size, Some(1024));
+        assert_eq!(
+            config.esi_error_limit_simulation,
+            Some(ErrorLimitSimulation::new(5, zero_seconds))
+        );
+        assert_eq!(config.esi_max_concurrent_requests, Some(8));
+        assert_eq!(config.esi_route_versions["/characters/{}/assets"], "v5");
+    }
+
+    /// Ensures the name cache setter methods for [`ConfigBuilder`] work as expected
+    ///
+    /// Test Setup
+    /// - Create a new instance of [`ConfigBuilder`] and use each name cache setter method
+    /// - Build the [`ConfigBuilder`] returning an [`Config`]
+    ///
+    /// Assertions
+    /// - Assert name cache settings were set as expected
+    #[cfg(feature = "universe")]
+    #[test]
+    fn test_name_cache_config_setter_methods() {
+        let zero_seconds = Duration::from_secs(0);
+
+        let config = ConfigBuilder::default()
+            .name_cache_max_size(10)
+            .name_cache_ttl(zero_seconds)
+            .build()
+            .expect("Failed to build Config");
+
+        // Assert name cache settings were set
+        assert_eq!(config.name_cache_max_size, 10);
+        assert_eq!(config.name_cache_ttl, zero_seconds);
+    }
+
+    /// Environment presets set all ESI & OAuth2 URLs together
+    ///
+    /// # Test Setup
+    /// - Build a [`Config`] for the Serenity & a custom environment
+    ///
+    /// # Assertions
+    /// - Assert Serenity URLs & issuers were set
+    /// - Assert custom environment URLs were derived from the base URL
+    #[test]
+    fn test_environment_presets() {
+        let config = Config::builder()
+            .environment(Environment::Serenity)
+            .build()
+            .expect("Failed to build Config");
+
+        // Assert Serenity URLs & issuers were set
+        assert_eq!(config.esi_url, "https://esi.evepc.163.com");
+        assert_eq!(
+            config.token_url.as_str(),
+            "https://login.evepc.163.com/v2/oauth/token"
+        );
+        assert_eq!(
+            config.jwt_key_cache_config.jwk_url,
+            "https://login.evepc.163.com/oauth/jwks"
+        );
+        assert!(config
+            .jwt_issuers
+            .contains(&"https://login.evepc.163.com".to_string()));
+
+        let config = Config::builder()
+            .environment(Environment::Custom("http://127.0.0.1:8080/".to_string()))
+            .build()
+            .expect("Failed to build Config");
+
+        // Assert custom environment URLs were derived from the base URL
+        assert_eq!(config.esi_url, "http://127.0.0.1:8080");
+        assert_eq!(
+            config.auth_url.as_str(),
+            "http://127.0.0.1:8080/v2/oauth/authorize"
+        );
+        assert_eq!(
+            config.token_url.as_str(),
+            "http://127.0.0.1:8080/v2/oauth/token"
+        );
+        assert_eq!(
+            config.jwt_key_cache_config.jwk_url,
+            "http://127.0.0.1:8080/oauth/jwks"
+        );
+        assert_eq!(config.jwt_issuers, vec!["http://127.0.0.1:8080"]);
+    }
+
+    /// Additional JWT issuer is added with its own JWK URL
+    ///
+    /// # Test Setup
+    /// - Build a [`Config`] with an additional issuer & JWK URL
+    ///
+    /// # Assertions
+    /// - Assert issuer was added to the expected issuers once
+    /// - Assert JWK URL was mapped to the issuer
+    #[test]
+    fn test_jwt_issuer_jwk_url() {
+        let config = Config::builder()
+            .jwt_issuers(vec!["example".to_string()])
+            .jwt_issuer_jwk_url("issuer", "https://example.com/jwks")
+            .jwt_issuer_jwk_url("issuer", "https://example.com/jwks")
+            .build()
+            .expect("Failed to build Config");
+
+        // Assert issuer was added to the expected issuers once
+        assert_eq!(config.jwt_issuers, vec!["example", "issuer"]);
+
+        // Assert JWK URL was mapped to the issuer
+        assert_eq!(
+            config.jwt_key_cache_config.issuer_jwk_urls.get("issuer"),
+            Some(&"https://example.com/jwks".to_string())
+        );
     }
 
     /// Expect an error setting the JWK background refresh threshold to 0
@@ -641,6 +1120,26 @@ mod tests {
         ))
     }
 
+    /// Expect an error setting the maximum concurrent ESI requests to 0
+    ///
+    /// # Test Setup
+    /// - Attempt to build an [`Config`] with esi_max_concurrent_requests set to 0
+    ///
+    /// # Assertions
+    /// - Assert error is of type [`ConfigError::InvalidMaxConcurrentRequests`]
+    #[test]
+    fn test_invalid_max_concurrent_requests() {
+        let result = Config::builder().esi_max_concurrent_requests(0).build();
+
+        // Assert error is of type ConfigError::InvalidMaxConcurrentRequests
+        assert!(matches!(
+            result,
+            Err(Error::ConfigError(
+                ConfigError::InvalidMaxConcurrentRequests
+            ))
+        ))
+    }
+
     /// Tests the attempting initialize an Config with an invalid auth_url
     ///
     /// # Test Setup
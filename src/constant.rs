@@ -9,12 +9,32 @@ pub static DEFAULT_AUTH_URL: &str = "https://login.eveonline.com/v2/oauth/author
 pub static DEFAULT_TOKEN_URL: &str = "https://login.eveonline.com/v2/oauth/token";
 /// Default EVE Online OAuth2 JWK URL used for validating access tokens
 pub static DEFAULT_JWK_URL: &str = "https://login.eveonline.com/oauth/jwks";
+/// Path of the OAuth2 authorization server metadata relative to the SSO's origin
+pub static OAUTH_SERVER_METADATA_PATH: &str = "/.well-known/oauth-authorization-server";
+
+// Serenity (China) EVE Online API URLs
+/// Serenity ESI URL
+pub static SERENITY_ESI_URL: &str = "https://esi.evepc.163.com";
+/// Serenity authorization URL for single sign-on
+pub static SERENITY_AUTH_URL: &str = "https://login.evepc.163.com/v2/oauth/authorize";
+/// Serenity OAuth2 token URL used for retrieving access tokens
+pub static SERENITY_TOKEN_URL: &str = "https://login.evepc.163.com/v2/oauth/token";
+/// Serenity OAuth2 JWK URL used for validating access tokens
+pub static SERENITY_JWK_URL: &str = "https://login.evepc.163.com/oauth/jwks";
+/// Serenity JWT issuers, defined with & without the scheme as with [`DEFAULT_JWT_ISSUERS`]
+pub static SERENITY_JWT_ISSUERS: [&str; 2] = ["https://login.evepc.163.com", "login.evepc.163.com"];
 
 // Default ESI request settings
 /// Default maximum number of retries for ESI requests on 5xx errors (2 retries)
 pub static DEFAULT_ESI_MAX_RETRIES: u32 = 2;
 /// Default backoff period for ESI request retries (200 milliseconds)
 pub static DEFAULT_ESI_RETRY_BACKOFF: Duration = Duration::from_millis(200);
+/// Default maximum time to wait for a `Retry-After` header between ESI request retries (30 seconds)
+pub static DEFAULT_ESI_MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+/// Default error limit reset window used when a 420 response lacks the `X-ESI-Error-Limit-Reset` header (60 seconds)
+pub static DEFAULT_ESI_ERROR_LIMIT_RESET: Duration = Duration::from_secs(60);
+/// Default number of remaining ESI errors below which bounded concurrency helpers stop starting requests (10 errors)
+pub static DEFAULT_ESI_ERROR_LIMIT_THRESHOLD: u32 = 10;
 
 // Default JWT key cache settings
 /// Default JWT key cache lifetime before expiration (3600 seconds representing 1 hour)
@@ -40,3 +60,21 @@ pub static DEFAULT_JWK_BACKGROUND_REFRESH_THRESHOLD_PERCENT: u64 = 80;
 pub static DEFAULT_JWT_ISSUERS: [&str; 2] = ["https://login.eveonline.com", "login.eveonline.com"];
 /// Default intended audience which JWT tokens will be used with
 pub static DEFAULT_JWT_AUDIENCE: &str = "EVE Online";
+
+// Default composite endpoint settings
+/// Default maximum number of requests composite endpoints send concurrently (10)
+#[cfg(any(feature = "market", feature = "universe", feature = "wallet"))]
+pub static DEFAULT_COMPOSITE_CONCURRENCY: usize = 10;
+
+// Default name cache settings
+/// Default maximum number of names held by the client's name cache (100,000)
+#[cfg(feature = "universe")]
+pub static DEFAULT_NAME_CACHE_MAX_SIZE: usize = 100_000;
+/// Default time after which names held by the client's name cache expire (86400 seconds representing 24 hours)
+#[cfg(feature = "universe")]
+pub static DEFAULT_NAME_CACHE_TTL: Duration = Duration::from_secs(86400);
+
+// Default killmail feed settings
+/// Default number of killmail IDs remembered by the killmail feed to skip killmails which were already yielded (10,000)
+#[cfg(feature = "killmails")]
+pub static DEFAULT_KILLMAIL_FEED_SEEN_CAPACITY: usize = 10_000;
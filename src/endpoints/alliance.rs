@@ -4,10 +4,12 @@
 //! alliance-related ESI endpoints. All endpoints in this module are public and do not require
 //! authentication.
 
+#[cfg(feature = "universe")]
+use crate::model::alliance::{AllianceCorporation, AllianceWithCorporations};
 use crate::{
-    esi::EsiRequest,
+    esi::{EsiRequest, IconSize, Image},
     model::alliance::{Alliance, AllianceIcons},
-    Client,
+    Client, Error,
 };
 use reqwest::Method;
 
@@ -110,4 +112,99 @@ impl<'a> AllianceEndpoints<'a> {
         method = Method::GET;
         path = "/alliances/{}/icons";
     }
+
+    /// Downloads the icon of an alliance
+    ///
+    /// Retrieves the icon URLs with [`Self::get_alliance_icon`] & downloads the image of the provided
+    /// size with [`EsiApi::download_image`](crate::esi::EsiApi::download_image). Alliances have no
+    /// 256x256 icon, the 128x128 icon is downloaded for [`IconSize::Px256`].
+    ///
+    /// For an overview & usage examples, see the [image module documentation](crate::esi::image)
+    ///
+    /// # Arguments
+    /// - `alliance_id` (`i64`): ID of the alliance to download the icon of
+    /// - `size` ([`IconSize`]): Size of the icon to download
+    ///
+    /// # Returns
+    /// The icon's bytes, content type & cache headers, or an [`Error`] if either request fails.
+    pub async fn get_alliance_icon_image(
+        &self,
+        alliance_id: i64,
+        size: IconSize,
+    ) -> Result<Image, Error> {
+        let icons = self.get_alliance_icon(alliance_id).send().await?;
+
+        self.client
+            .esi()
+            .download_fresh_image(icons.url(size))
+            .await
+    }
+
+    /// Retrieves the number of member corporations within an alliance
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `alliance_id`: ID of the alliance to count the member corporations of
+    ///
+    /// # Returns
+    /// The number of member corporations within the alliance, or an [`Error`] if the request fails.
+    pub async fn get_alliance_corporation_count(&self, alliance_id: i64) -> Result<usize, Error> {
+        let corporation_ids = self.list_alliance_corporations(alliance_id).send().await?;
+
+        Ok(corporation_ids.len())
+    }
+
+    /// Retrieves information on an alliance along with its member corporations & their names
+    ///
+    /// Combines [`Self::get_alliance_information`] & [`Self::list_alliance_corporations`], resolving the
+    /// names of all member corporations in bulk using
+    /// [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names).
+    /// Member corporations are sorted by name.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `alliance_id`: ID of the alliance to fetch information & member corporations for
+    ///
+    /// # Returns
+    /// The alliance & its member corporations, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "universe")]
+    pub async fn get_alliance_with_corporations(
+        &self,
+        alliance_id: i64,
+    ) -> Result<AllianceWithCorporations, Error> {
+        let alliance = self
+            .get_alliance_information(alliance_id)
+            .send()
+            .await?
+            .data;
+        let corporation_ids = self
+            .list_alliance_corporations(alliance_id)
+            .send()
+            .await?
+            .data;
+
+        let names = self
+            .client
+            .universe()
+            .resolve_names(corporation_ids.clone())
+            .await?;
+
+        let mut corporations: Vec<AllianceCorporation> = corporation_ids
+            .into_iter()
+            .map(|corporation_id| AllianceCorporation {
+                corporation_id,
+                name: names.get(&corporation_id).cloned().unwrap_or_default(),
+            })
+            .collect();
+        corporations.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(AllianceWithCorporations {
+            alliance,
+            corporations,
+        })
+    }
 }
@@ -4,10 +4,10 @@
 //! asset-related ESI endpoints.
 
 use crate::{
-    esi::EsiRequest,
-    model::asset::{Asset, AssetLocation, AssetName},
+    esi::{AccessToken, EsiRequest, Idempotency},
+    model::asset::{Asset, AssetLocation, AssetName, AssetTree},
     scope::AssetsScopes,
-    Client, ScopeBuilder,
+    Client, Error, ScopeBuilder,
 };
 use reqwest::Method;
 
@@ -40,14 +40,14 @@ impl<'a> AssetsEndpoints<'a> {
         ///   `esi-assets.read_assets.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve assets for.
         /// - `page`          (`i32`): The page of assets to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of assets for the character when sent.
         auth fn get_character_assets(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64;
             page: i32
         ) -> EsiRequest<Vec<Asset>>
@@ -73,14 +73,14 @@ impl<'a> AssetsEndpoints<'a> {
         ///   `esi-assets.read_assets.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve asset locations for.
         /// - `item_ids`     (`Vec<i64>`): Vec of item IDs to get coordinates for (Limit of 1000 IDs per request)
         ///
         /// # Returns
         /// An ESI request builder that returns a list of asset locations with coordinates when sent.
         auth fn get_character_asset_locations(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<AssetLocation>>
         method = Method::POST;
@@ -89,6 +89,7 @@ impl<'a> AssetsEndpoints<'a> {
             .assets(AssetsScopes::new().read_assets())
             .build();
         body = item_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -109,14 +110,14 @@ impl<'a> AssetsEndpoints<'a> {
         ///   `esi-assets.read_assets.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve asset names for.
         /// - `item_ids`     (`Vec<i64>`): Vec of item IDs to get names for (Limit of 1000 IDs per request)
         ///
         /// # Returns
         /// An ESI request builder that returns a list of asset names for items with customizable names when sent.
         auth fn get_character_asset_names(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<AssetName>>
         method = Method::POST;
@@ -125,6 +126,7 @@ impl<'a> AssetsEndpoints<'a> {
             .assets(AssetsScopes::new().read_assets())
             .build();
         body = item_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -140,14 +142,14 @@ impl<'a> AssetsEndpoints<'a> {
         ///   `esi-assets.read_corporation_assets.v1`
         ///
         /// # Arguments
-        /// - `access_token`    (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`    (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve assets for.
         /// - `page`            (`i32`): The page of assets to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of assets for the corporation when sent.
         auth fn get_corporation_assets(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<Asset>>
@@ -173,14 +175,14 @@ impl<'a> AssetsEndpoints<'a> {
         ///   `esi-assets.read_corporation_assets.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id` (`i64`): The ID of the corporation to retrieve asset locations for.
         /// - `item_ids`       (`Vec<i64>`): Vec of item IDs to get coordinates for (Limit of 1000 IDs per request)
         ///
         /// # Returns
         /// An ESI request builder that returns a list of corporation asset locations with coordinates when sent.
         auth fn get_corporation_asset_locations(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<AssetLocation>>
         method = Method::POST;
@@ -189,6 +191,7 @@ impl<'a> AssetsEndpoints<'a> {
             .assets(AssetsScopes::new().read_corporation_assets())
             .build();
         body = item_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -209,14 +212,14 @@ impl<'a> AssetsEndpoints<'a> {
         ///   `esi-assets.read_corporation_assets.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id` (`i64`): The ID of the corporation to retrieve asset names for.
         /// - `item_ids`       (`Vec<i64>`): Vec of item IDs to get names for (Limit of 1000 IDs per request)
         ///
         /// # Returns
         /// An ESI request builder that returns a list of corporation asset names for items with customizable names when sent.
         auth fn get_corporation_asset_names(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<AssetName>>
         method = Method::POST;
@@ -225,5 +228,34 @@ impl<'a> AssetsEndpoints<'a> {
             .assets(AssetsScopes::new().read_corporation_assets())
             .build();
         body = item_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
+    }
+
+    /// Retrieves every page of a character's assets arranged as an [`AssetTree`]
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`AssetsScopes::read_assets`](crate::scope::AssetsScopes::read_assets):
+    ///   `esi-assets.read_assets.v1`
+    ///
+    /// # Arguments
+    /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id`  (`i64`): The ID of the character to retrieve assets for.
+    ///
+    /// # Returns
+    /// The character's assets as a tree, or an [`Error`] if any page could not be retrieved.
+    pub async fn get_character_asset_tree(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+    ) -> Result<AssetTree, Error> {
+        let assets = self
+            .get_character_assets(access_token, character_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+
+        Ok(AssetTree::new(assets))
     }
 }
@@ -4,7 +4,7 @@
 //! calendar-related ESI endpoints.
 
 use crate::{
-    esi::EsiRequest,
+    esi::{EsiRequest, Idempotency},
     model::{
         calendar::{CalendarEvent, CalendarEventAttendee, CalendarEventSummary},
         enums::calendar::PutCalendarEventResponse,
@@ -50,13 +50,13 @@ impl<'a> CalendarEndpoints<'a> {
         ///   `esi-calendar.read_calendar_events.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve calendar event summaries for.
         ///
         /// # Returns
         /// An ESI request builder that returns a list of calendar event summaries for the character when sent.
         auth fn list_calendar_event_summaries(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CalendarEventSummary>>
         method = Method::GET;
@@ -79,14 +79,14 @@ impl<'a> CalendarEndpoints<'a> {
         ///   `esi-calendar.read_calendar_events.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve calendar event for.
         /// - `event_id`      (`i64`): The ID of the calendar event to retrieve information for
         ///
         /// # Returns
         /// An ESI request builder that returns detailed information about a specific calendar event when sent.
         auth fn get_an_event(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64,
             event_id: i64
         ) -> EsiRequest<CalendarEvent>
@@ -110,7 +110,7 @@ impl<'a> CalendarEndpoints<'a> {
         ///   `esi-calendar.respond_calendar_events.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to respond to the event on behalf of.
         /// - `event_id`      (`i64`): The ID of the calendar event to respond to
         /// - `event_response` ([`PutCalendarEventResponse`]): The response to send for the character
@@ -118,7 +118,7 @@ impl<'a> CalendarEndpoints<'a> {
         /// # Returns
         /// An ESI request builder that submits a response to a calendar event when sent.
         auth fn respond_to_an_event(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64,
             event_id: i64
         ) -> EsiRequest<()>
@@ -128,6 +128,7 @@ impl<'a> CalendarEndpoints<'a> {
             .calendar(CalendarScopes::new().respond_calendar_events())
             .build();
         body = event_response: PutCalendarEventResponse;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -143,14 +144,14 @@ impl<'a> CalendarEndpoints<'a> {
         ///   `esi-calendar.read_calendar_events.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve calendar event attendees for.
         /// - `event_id`      (`i64`): The ID of the calendar event to retrieve attendees for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of attendees and their responses for a calendar event when sent.
         auth fn get_attendees(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64,
             event_id: i64
         ) -> EsiRequest<Vec<CalendarEventAttendee>>
@@ -3,10 +3,11 @@
 //! This module provides the [`CharacterEndpoints`] struct and associated methods for accessing
 //! character-related ESI endpoints.
 
-use crate::esi::EsiRequest;
+use crate::esi::{AccessToken, EsiRequest, Idempotency};
+use crate::model::enums::notification::NotificationType;
 use crate::model::standing::Standing;
 use crate::scope::CharactersScopes;
-use crate::{Client, ScopeBuilder};
+use crate::{Client, Error, ScopeBuilder};
 
 use crate::model::asset::Blueprint;
 use crate::model::character::{
@@ -73,6 +74,7 @@ impl<'a> CharacterEndpoints<'a> {
         method = Method::POST;
         path = "/characters/affiliation";
         body = character_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -88,13 +90,17 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_agents_research.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve research agent information for.
         ///
         /// # Returns
         /// An ESI request builder that returns a list of the character's research agents when sent.
+        ///
+        /// # Deprecation
+        /// Research agents were retired from the game, the route is deprecated & recorded in
+        /// [`Client::deprecated_routes`](crate::Client::deprecated_routes) when used.
         auth fn get_agents_research(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterResearchAgent>>
         method = Method::GET;
@@ -102,6 +108,7 @@ impl<'a> CharacterEndpoints<'a> {
         required_scopes = ScopeBuilder::new()
             .characters(CharactersScopes::new().read_agents_research())
             .build();
+        deprecated_since = "2025-01-01";
     }
 
     define_esi_endpoint! {
@@ -117,14 +124,14 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_blueprints.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve blueprints for
         /// - `page`         (`i32`): The page of blueprints to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of the character's blueprints when sent.
         auth fn get_blueprints(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64;
             page: i32
         ) -> EsiRequest<Vec<Blueprint>>
@@ -165,7 +172,7 @@ impl<'a> CharacterEndpoints<'a> {
         /// - <developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdCspa>
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): ID of the character who would be sending the evemails
         /// - `character_ids` (`Vec<i64>`): List of character IDs to calculate the CSPA cost to
         ///   evemail.
@@ -173,13 +180,14 @@ impl<'a> CharacterEndpoints<'a> {
         /// # Returns
         /// An ESI request builder that returns the calculated CSPA charge cost for evemailing the provided characters when sent.
         auth fn calculate_a_cspa_charge_cost(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<f64>
         method = Method::POST;
         path = "/characters/{}/cspa";
         required_scopes = ScopeBuilder::new().characters(CharactersScopes::new().read_contacts()).build();
         body = character_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -195,13 +203,13 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_fatigue.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve jump fatigue for
         ///
         /// # Returns
         /// An ESI request builder that returns the character's jump fatigue information when sent.
         auth fn get_jump_fatigue(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<CharacterJumpFatigue>
         method = Method::GET;
@@ -222,13 +230,13 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_medals.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve medals for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of medals awarded to the character when sent.
         auth fn get_medals(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterMedal>>
         method = Method::GET;
@@ -249,13 +257,13 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_notifications.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve notifications for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of the character's notifications when sent.
         auth fn get_character_notifications(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterNotification>>
         method = Method::GET;
@@ -276,13 +284,13 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_notifications.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve added as contact notifications
         ///
         /// # Returns
         /// An ESI request builder that returns a list of notifications about being added to someone's contact list when sent.
         auth fn get_new_contact_notifications(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterNewContactNotification>>
         method = Method::GET;
@@ -323,13 +331,13 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_corporation_roles.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve corporation roles for
         ///
         /// # Returns
         /// An ESI request builder that returns the character's corporation roles when sent.
         auth fn get_character_corporation_roles(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<CharacterCorporationRole>
         method = Method::GET;
@@ -350,13 +358,13 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_standings.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve standings for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of the character's NPC standings when sent.
         auth fn get_standings(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<Standing>>
         method = Method::GET;
@@ -377,17 +385,76 @@ impl<'a> CharacterEndpoints<'a> {
         ///   `esi-characters.read_titles.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id` (`i64`): The ID of the character to retrieve corporation titles for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of the character's corporation titles when sent.
         auth fn get_character_corporation_titles(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterCorporationTitle>>
         method = Method::GET;
         path = "/characters/{}/titles";
         required_scopes = ScopeBuilder::new().characters(CharactersScopes::new().read_titles()).build();
     }
+
+    /// Retrieves the character's notifications received since the last seen notification ID
+    ///
+    /// Requests [`Self::get_character_notifications`] & returns only notifications with an ID greater
+    /// than `last_notification_id`, optionally filtered to the provided notification types. ESI
+    /// returns notifications in no guaranteed order, the returned notifications are ordered from
+    /// oldest to newest by timestamp & then ID so they can be relayed in the order they occurred.
+    ///
+    /// Store the `notification_id` of the last returned notification as the `last_notification_id`
+    /// for the next poll. ESI caches notifications for 10 minutes, polling more frequently has no
+    /// benefit.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CharactersScopes::read_notifications`](crate::scope::CharactersScopes::read_notifications):
+    ///   `esi-characters.read_notifications.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`         (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id`         (`i64`): The ID of the character to retrieve notifications for
+    /// - `last_notification_id` (`Option<i64>`): The highest notification ID previously seen, `None` to return all notifications
+    /// - `types`                (`&[NotificationType]`): Notification types to return, an empty slice returns all types,
+    ///   e.g. [`NotificationType::STRUCTURE_ATTACKS`]
+    ///
+    /// # Returns
+    /// The new notifications ordered from oldest to newest, or an [`Error`] if the request fails.
+    pub async fn get_new_notifications(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+        last_notification_id: Option<i64>,
+        types: &[NotificationType],
+    ) -> Result<Vec<CharacterNotification>, Error> {
+        let notifications = self
+            .get_character_notifications(access_token, character_id)
+            .send()
+            .await?
+            .data;
+
+        let mut notifications: Vec<CharacterNotification> = notifications
+            .into_iter()
+            .filter(|notification| {
+                last_notification_id.is_none_or(|last| notification.notification_id > last)
+            })
+            .filter(|notification| types.is_empty() || types.contains(&notification.r#type))
+            .collect();
+
+        log::debug!(
+            "Found {} new notifications for character {}",
+            notifications.len(),
+            character_id
+        );
+
+        notifications
+            .sort_by_key(|notification| (notification.timestamp, notification.notification_id));
+
+        Ok(notifications)
+    }
 }
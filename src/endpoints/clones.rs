@@ -3,6 +3,8 @@
 //! This module provides the [`ClonesEndpoints`] struct and associated methods for accessing
 //! clone-related ESI endpoints.
 
+#[cfg(feature = "universe")]
+use crate::{esi::AccessToken, model::clones::ImplantSet, Error};
 use crate::{
     esi::EsiRequest, model::clones::CharacterClones, scope::ClonesScopes, Client, ScopeBuilder,
 };
@@ -37,13 +39,13 @@ impl<'a> ClonesEndpoints<'a> {
         ///   `esi-clones.read_clones.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve clones for
         ///
         /// # Returns
         /// An ESI request builder that returns clone information including home location and jump clones when sent.
         auth fn get_clones(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<CharacterClones>
         method = Method::GET;
@@ -66,13 +68,13 @@ impl<'a> ClonesEndpoints<'a> {
         ///   `esi-clones.read_implants.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve implants for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of implant type IDs installed in the character's active clone when sent.
         auth fn get_active_implants(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<i64>>
         method = Method::GET;
@@ -81,4 +83,49 @@ impl<'a> ClonesEndpoints<'a> {
             .clones(ClonesScopes::new().read_implants())
             .build();
     }
+
+    /// Retrieves & classifies the implants of a character's active clone
+    ///
+    /// Requests the implant type IDs with [`Self::get_active_implants`] & their item types with
+    /// [`UniverseEndpoints::get_item_types`](crate::endpoints::universe::UniverseEndpoints::get_item_types)
+    /// to read each implant's slot, attribute bonuses & pirate implant set.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`ClonesScopes::read_implants`](crate::scope::ClonesScopes::read_implants):
+    ///   `esi-clones.read_implants.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id`  (`i64`): The ID of the character to retrieve implants for
+    ///
+    /// # Returns
+    /// The implants of the character's active clone, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "universe")]
+    pub async fn get_active_implant_set(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+    ) -> Result<ImplantSet, Error> {
+        let type_ids = self
+            .get_active_implants(access_token, character_id)
+            .send()
+            .await?
+            .data;
+
+        let item_types = self
+            .client
+            .universe()
+            .get_item_types(type_ids.clone())
+            .await?;
+
+        Ok(ImplantSet::from_item_types(
+            type_ids
+                .iter()
+                .filter_map(|type_id| item_types.get(type_id)),
+        ))
+    }
 }
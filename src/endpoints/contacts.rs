@@ -4,10 +4,13 @@
 //! contact-related ESI endpoints.
 
 use crate::{
-    esi::EsiRequest,
-    model::contacts::{AllianceContact, CharacterContact, ContactLabel, CorporationContact},
+    esi::{AccessToken, EsiRequest, Idempotency},
+    model::contacts::{
+        AllianceContact, CharacterContact, ContactLabel, ContactSyncPlan, CorporationContact,
+        DesiredContact, LabelSet,
+    },
     scope::{AlliancesScopes, CharactersScopes, CorporationsScopes},
-    Client, ScopeBuilder,
+    Client, Error, ScopeBuilder,
 };
 use reqwest::Method;
 
@@ -40,13 +43,13 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-alliances.read_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `alliance_id`  (`i64`): The ID of the alliance to retrieve contacts for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of alliance contacts when sent.
         auth fn get_alliance_contacts(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             alliance_id: i64
         ) -> EsiRequest<Vec<AllianceContact>>
         method = Method::GET;
@@ -69,13 +72,13 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-alliances.read_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `alliance_id`  (`i64`): The ID of the alliance to retrieve contacts labels for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of contact labels for the alliance when sent.
         auth fn get_alliance_contact_labels(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             alliance_id: i64
         ) -> EsiRequest<Vec<ContactLabel>>
         method = Method::GET;
@@ -98,14 +101,14 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-characters.write_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to delete contacts for
         /// - `contact_ids`   (`Vec<i64>`): List of contact IDs to delete (up to 20 per request)
         ///
         /// # Returns
         /// An ESI request builder that deletes the specified contacts when sent.
         auth fn delete_contacts(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64;
             contact_ids: Vec<i64>
         ) -> EsiRequest<()>
@@ -114,6 +117,7 @@ impl<'a> ContactsEndpoints<'a> {
         required_scopes = ScopeBuilder::new()
             .characters(CharactersScopes::new().write_contacts())
             .build();
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -129,13 +133,13 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-characters.read_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve contacts for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of character contacts when sent.
         auth fn get_contacts(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterContact>>
         method = Method::GET;
@@ -158,7 +162,7 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-characters.write_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`    (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`    (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `contact_ids` (`Vec<i64>`): List of contact IDs to add for the provided character ID
         /// - `character_id`     (`i64`): The ID of the character to add contacts for
         /// - `standing`         (`f64`): The standing to set for the provided contact IDs
@@ -169,7 +173,7 @@ impl<'a> ContactsEndpoints<'a> {
         /// # Returns
         /// An ESI request builder that adds contacts and returns a list of the created contact IDs when sent.
         auth fn add_contacts(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64;
             standing: f64,
             label_ids: Vec<i64>,
@@ -181,6 +185,7 @@ impl<'a> ContactsEndpoints<'a> {
             .characters(CharactersScopes::new().write_contacts())
             .build();
         body = contact_ids: Vec<i64>;
+        idempotency = Idempotency::Unsafe;
     }
 
     define_esi_endpoint! {
@@ -196,7 +201,7 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-characters.write_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`    (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`    (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `contact_ids` (`Vec<i64>`): List of contact IDs to edit for the provided character ID
         /// - `character_id`     (`i64`): The ID of the character to edit contacts for
         /// - `standing`         (`f64`): The standing to set for the provided contact IDs
@@ -207,7 +212,7 @@ impl<'a> ContactsEndpoints<'a> {
         /// # Returns
         /// An ESI request builder that updates contacts and returns a list of the edited contact IDs when sent.
         auth fn edit_contacts(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64;
             standing: f64,
             label_ids: Vec<i64>,
@@ -219,6 +224,7 @@ impl<'a> ContactsEndpoints<'a> {
             .characters(CharactersScopes::new().write_contacts())
             .build();
         body = contact_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
     }
 
     define_esi_endpoint! {
@@ -234,13 +240,13 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-characters.read_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`  (`i64`): The ID of the character to retrieve contacts labels for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of contact labels for the character when sent.
         auth fn get_contact_labels(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<ContactLabel>>
         method = Method::GET;
@@ -263,13 +269,13 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-corporations.read_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token`  (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve contacts for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of corporation contacts when sent.
         auth fn get_corporation_contacts(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationContact>>
         method = Method::GET;
@@ -292,13 +298,13 @@ impl<'a> ContactsEndpoints<'a> {
         ///   `esi-corporations.read_contacts.v1`
         ///
         /// # Arguments
-        /// - `access_token` (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id` (`i64`): The ID of the corporation to retrieve contacts labels for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of contact labels for the corporation when sent.
         auth fn get_corporation_contact_labels(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<ContactLabel>>
         method = Method::GET;
@@ -307,4 +313,168 @@ impl<'a> ContactsEndpoints<'a> {
             .corporations(CorporationsScopes::new().read_contacts())
             .build();
     }
+
+    /// Syncs a character's contacts to the provided list of desired contacts
+    ///
+    /// Retrieves every page of the character's current contacts, computes the minimal set of add,
+    /// edit, & delete requests with [`ContactSyncPlan::new`], then executes them. Current contacts
+    /// missing from the desired contacts are deleted. Requests are split to respect ESI's per request
+    /// contact limits.
+    ///
+    /// Deletes are executed first to free space on the contact list, followed by edits & then adds.
+    /// If a request fails the sync stops & the error is returned, requests which already succeeded are
+    /// not reverted, calling this method again will resume the sync.
+    ///
+    /// Note: ESI only provides write endpoints for character contacts, corporation & alliance contacts
+    /// can only be managed in-game.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CharactersScopes::read_contacts`](crate::scope::CharactersScopes::read_contacts):
+    ///   `esi-characters.read_contacts.v1`
+    /// - [`CharactersScopes::write_contacts`](crate::scope::CharactersScopes::write_contacts):
+    ///   `esi-characters.write_contacts.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`               (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id`                (`i64`): The ID of the character to sync contacts for
+    /// - `desired` (`&[`[`DesiredContact`]`]`): The contacts which should exist after the sync
+    ///
+    /// # Returns
+    /// The [`ContactSyncPlan`] which was executed, or an [`Error`] if any of the requests fail.
+    pub async fn sync_contacts(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+        desired: &[DesiredContact],
+    ) -> Result<ContactSyncPlan, Error> {
+        let access_token = access_token.into();
+
+        let current = self
+            .get_contacts(&access_token, character_id)
+            .send_all_pages()
+            .await?
+            .data;
+
+        let plan = ContactSyncPlan::new(&current, desired);
+
+        for contact_ids in &plan.delete {
+            self.delete_contacts(&access_token, character_id, contact_ids.clone())
+                .send()
+                .await?;
+        }
+
+        for batch in &plan.edit {
+            self.edit_contacts(
+                &access_token,
+                character_id,
+                batch.standing,
+                batch.label_ids.clone(),
+                batch.watched,
+                batch.contact_ids.clone(),
+            )
+            .send()
+            .await?;
+        }
+
+        for batch in &plan.add {
+            self.add_contacts(
+                &access_token,
+                character_id,
+                batch.standing,
+                batch.label_ids.clone(),
+                batch.watched,
+                batch.contact_ids.clone(),
+            )
+            .send()
+            .await?;
+        }
+
+        Ok(plan)
+    }
+
+    /// Retrieves the contact labels of a character as a [`LabelSet`] to resolve label IDs to names
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CharactersScopes::read_contacts`](crate::scope::CharactersScopes::read_contacts):
+    ///   `esi-characters.read_contacts.v1`
+    ///
+    /// # Arguments
+    /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id`  (`i64`): The ID of the character to retrieve contact labels for
+    ///
+    /// # Returns
+    /// The character's contact labels, or an [`Error`] if the request fails.
+    pub async fn get_contact_label_set(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+    ) -> Result<LabelSet, Error> {
+        let labels = self
+            .get_contact_labels(access_token, character_id)
+            .send()
+            .await?
+            .data;
+
+        Ok(LabelSet::new(labels))
+    }
+
+    /// Retrieves the contact labels of a corporation as a [`LabelSet`] to resolve label IDs to names
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CorporationsScopes::read_contacts`](crate::scope::CorporationsScopes::read_contacts):
+    ///   `esi-corporations.read_contacts.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve contact labels for
+    ///
+    /// # Returns
+    /// The corporation's contact labels, or an [`Error`] if the request fails.
+    pub async fn get_corporation_contact_label_set(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<LabelSet, Error> {
+        let labels = self
+            .get_corporation_contact_labels(access_token, corporation_id)
+            .send()
+            .await?
+            .data;
+
+        Ok(LabelSet::new(labels))
+    }
+
+    /// Retrieves the contact labels of an alliance as a [`LabelSet`] to resolve label IDs to names
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`AlliancesScopes::read_contacts`](crate::scope::AlliancesScopes::read_contacts):
+    ///   `esi-alliances.read_contacts.v1`
+    ///
+    /// # Arguments
+    /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `alliance_id`   (`i64`): The ID of the alliance to retrieve contact labels for
+    ///
+    /// # Returns
+    /// The alliance's contact labels, or an [`Error`] if the request fails.
+    pub async fn get_alliance_contact_label_set(
+        &self,
+        access_token: impl Into<AccessToken>,
+        alliance_id: i64,
+    ) -> Result<LabelSet, Error> {
+        let labels = self
+            .get_alliance_contact_labels(access_token, alliance_id)
+            .send()
+            .await?
+            .data;
+
+        Ok(LabelSet::new(labels))
+    }
 }
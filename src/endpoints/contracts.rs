@@ -3,12 +3,33 @@
 //! This module provides the [`ContractsEndpoints`] struct and associated methods for accessing
 //! contract-related ESI endpoints.
 
+use std::ops::Deref;
+
+use reqwest::Method;
+use tokio::sync::OnceCell;
+
+#[cfg(all(feature = "routes", feature = "universe"))]
+use crate::{
+    constant::DEFAULT_COMPOSITE_CONCURRENCY,
+    esi::{join_limited_partial, AccessToken},
+    model::{
+        contract::{CourierRoute, CourierRouteSystem},
+        enums::routes::RouteFlag,
+    },
+    CompositeError,
+};
 use crate::{
     esi::EsiRequest,
-    model::contract::{PublicContract, PublicContractItem},
-    Client,
+    model::{
+        contract::{PublicContract, PublicContractBid, PublicContractItem},
+        enums::contract::ContractType,
+    },
+    Client, Error,
 };
-use reqwest::Method;
+
+/// Range of IDs assigned to NPC stations, other location IDs of contracts are player structures
+#[cfg(all(feature = "routes", feature = "universe"))]
+const NPC_STATION_IDS: std::ops::Range<i64> = 60_000_000..64_000_000;
 
 /// Provides methods for accessing contract-related endpoints of the EVE Online ESI API.
 ///
@@ -65,4 +86,292 @@ impl<'a> ContractsEndpoints<'a> {
         method = Method::GET;
         path = "/contracts/public/items/{}";
     }
+
+    define_esi_endpoint! {
+        /// Retrieves a paginated list of bids for the provided auction contract ID
+        ///
+        /// # ESI Documentation
+        /// <https://developers.eveonline.com/api-explorer#/operations/GetContractsPublicBidsContractId>
+        ///
+        /// # Arguments
+        /// - `contract_id`   (`i64`): The ID of the contract to retrieve bids.
+        /// - `page`        (`i32`): The page of contract bids to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated vector of contract bids when sent.
+        pub fn get_public_contract_bids(
+            contract_id: i64;
+            page: i32
+        ) -> EsiRequest<Vec<PublicContractBid>>
+        method = Method::GET;
+        path = "/contracts/public/bids/{}";
+    }
+
+    /// Retrieves every page of public contracts in the provided region as [`Contract`]s
+    ///
+    /// The items & bids of each contract are not requested until [`Contract::items`] or
+    /// [`Contract::bids`] is called, see [`Contract`].
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `region_id` (`i64`): The ID of the region to retrieve public contracts for.
+    ///
+    /// # Returns
+    /// The public contracts of the region, or an [`Error`] if any page could not be retrieved.
+    pub async fn get_all_public_contracts(&self, region_id: i64) -> Result<Vec<Contract>, Error> {
+        let contracts = self
+            .get_public_contracts(region_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+
+        Ok(contracts.into_iter().map(Contract::new).collect())
+    }
+
+    /// Resolves the jump route of a courier contract & the security status of each solar system along it
+    ///
+    /// Resolves the solar systems of the contract's start & end locations using
+    /// [`UniverseEndpoints::get_station_information`](crate::endpoints::universe::UniverseEndpoints::get_station_information)
+    /// for NPC stations &
+    /// [`UniverseEndpoints::get_structure_information`](crate::endpoints::universe::UniverseEndpoints::get_structure_information)
+    /// for player structures, then requests the route between them with
+    /// [`RoutesEndpoints::get_route`](crate::endpoints::routes::RoutesEndpoints::get_route) &
+    /// the security status of each solar system along it. See [`CourierRoute`] for the lowsec &
+    /// nullsec segments and the reward & collateral per jump.
+    ///
+    /// A failed solar system request doesn't stop the remaining systems from being requested, the
+    /// route is returned alongside the failures without the systems which failed, see
+    /// [`CompositeError`]. If the route itself can't be resolved the failure is keyed by the
+    /// contract ID.
+    ///
+    /// Requires the `routes` & `universe` features.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`UniverseScopes::read_structures`](crate::scope::UniverseScopes::read_structures):
+    ///   `esi-universe.read_structures.v1`, only used if a location is a player structure
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `contract`       (&[`PublicContract`]): The courier contract to resolve the route of
+    /// - `flag`           ([`RouteFlag`]): The route preference, e.g. shortest or safest
+    ///
+    /// # Returns
+    /// The annotated route, `None` if the contract is not a courier contract or lacks a start or
+    /// end location, or a [`CompositeError`] with the route & the error of each solar system ID
+    /// which failed if any of the requests fail.
+    #[cfg(all(feature = "routes", feature = "universe"))]
+    pub async fn get_courier_route(
+        &self,
+        access_token: impl Into<AccessToken>,
+        contract: &PublicContract,
+        flag: RouteFlag,
+    ) -> Result<Option<CourierRoute>, CompositeError<Option<CourierRoute>>> {
+        let (start_location_id, end_location_id) = match (
+            &contract.r#type,
+            contract.start_location_id,
+            contract.end_location_id,
+        ) {
+            (ContractType::Courier, Some(start), Some(end)) => (start, end),
+            _ => return Ok(None),
+        };
+
+        let route = match self
+            .get_route_system_ids(
+                access_token.into(),
+                start_location_id,
+                end_location_id,
+                flag,
+            )
+            .await
+        {
+            Ok(route) => route,
+            Err(error) => return Err(CompositeError::failed(None, contract.contract_id, error)),
+        };
+
+        let universe = self.client.universe();
+        let systems = join_limited_partial(
+            self.client,
+            route,
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |system_id| *system_id,
+            |system_id| {
+                let request = universe.get_solar_system_information(system_id);
+
+                async move {
+                    request.send().await.map(|response| CourierRouteSystem {
+                        system_id,
+                        security_status: response.data.security_status,
+                    })
+                }
+            },
+        )
+        .await;
+
+        let into_route = |systems| {
+            Some(CourierRoute {
+                contract_id: contract.contract_id,
+                start_location_id,
+                end_location_id,
+                systems,
+                reward: contract.reward.unwrap_or_default(),
+                collateral: contract.collateral.unwrap_or_default(),
+                volume: contract.volume,
+            })
+        };
+
+        systems
+            .map(into_route)
+            .map_err(|partial| partial.map(into_route))
+    }
+
+    /// Returns the IDs of the solar systems along the route between two stations or structures
+    #[cfg(all(feature = "routes", feature = "universe"))]
+    async fn get_route_system_ids(
+        &self,
+        access_token: AccessToken,
+        start_location_id: i64,
+        end_location_id: i64,
+        flag: RouteFlag,
+    ) -> Result<Vec<i64>, Error> {
+        let origin = self
+            .get_location_system_id(&access_token, start_location_id)
+            .await?;
+        let destination = self
+            .get_location_system_id(&access_token, end_location_id)
+            .await?;
+
+        let route = self
+            .client
+            .routes()
+            .get_route(origin, destination, flag)
+            .send()
+            .await?
+            .data;
+
+        Ok(route)
+    }
+
+    /// Returns the ID of the solar system an NPC station or player structure is in
+    #[cfg(all(feature = "routes", feature = "universe"))]
+    async fn get_location_system_id(
+        &self,
+        access_token: &AccessToken,
+        location_id: i64,
+    ) -> Result<i64, Error> {
+        let universe = self.client.universe();
+
+        if NPC_STATION_IDS.contains(&location_id) {
+            let station = universe.get_station_information(location_id).send().await?;
+
+            Ok(station.data.system_id)
+        } else {
+            let structure = universe
+                .get_structure_information(access_token, location_id)
+                .send()
+                .await?;
+
+            Ok(structure.data.solar_system_id)
+        }
+    }
+}
+
+/// A public contract which retrieves its items & bids on demand
+///
+/// Dereferences to the [`PublicContract`] it wraps. The items & bids are requested with every
+/// page of [`ContractsEndpoints::get_public_contract_items`] & [`ContractsEndpoints::get_public_contract_bids`]
+/// the first time [`Self::items`] or [`Self::bids`] is called, later calls return the retrieved
+/// entries without making requests. A request which fails is retried the next time it is called.
+#[derive(Debug)]
+pub struct Contract {
+    contract: PublicContract,
+    items: OnceCell<Vec<PublicContractItem>>,
+    bids: OnceCell<Vec<PublicContractBid>>,
+}
+
+impl Contract {
+    /// Creates a new [`Contract`] from a public contract, no items or bids are requested
+    pub fn new(contract: PublicContract) -> Self {
+        Self {
+            contract,
+            items: OnceCell::new(),
+            bids: OnceCell::new(),
+        }
+    }
+
+    /// Returns the public contract, dropping any retrieved items & bids
+    pub fn into_inner(self) -> PublicContract {
+        self.contract
+    }
+
+    /// Retrieves the contract's items, requesting them only the first time this is called
+    ///
+    /// # Arguments
+    /// - `client` (&[`Client`]): ESI client used to request the items
+    ///
+    /// # Returns
+    /// The items of the contract, or an [`Error`] if they could not be retrieved
+    pub async fn items(&self, client: &Client) -> Result<&[PublicContractItem], Error> {
+        let items = self
+            .items
+            .get_or_try_init(|| async {
+                let response = client
+                    .contracts()
+                    .get_public_contract_items(self.contract.contract_id, 1)
+                    .send_all_pages()
+                    .await?;
+
+                Ok::<_, Error>(response.data)
+            })
+            .await?;
+
+        Ok(items)
+    }
+
+    /// Retrieves the contract's bids, requesting them only the first time this is called
+    ///
+    /// Only auctions have bids, an empty slice is returned for other contract types without
+    /// making a request.
+    ///
+    /// # Arguments
+    /// - `client` (&[`Client`]): ESI client used to request the bids
+    ///
+    /// # Returns
+    /// The bids of the contract, or an [`Error`] if they could not be retrieved
+    pub async fn bids(&self, client: &Client) -> Result<&[PublicContractBid], Error> {
+        if self.contract.r#type != ContractType::Auction {
+            return Ok(&[]);
+        }
+
+        let bids = self
+            .bids
+            .get_or_try_init(|| async {
+                let response = client
+                    .contracts()
+                    .get_public_contract_bids(self.contract.contract_id, 1)
+                    .send_all_pages()
+                    .await?;
+
+                Ok::<_, Error>(response.data)
+            })
+            .await?;
+
+        Ok(bids)
+    }
+}
+
+impl From<PublicContract> for Contract {
+    fn from(contract: PublicContract) -> Self {
+        Self::new(contract)
+    }
+}
+
+impl Deref for Contract {
+    type Target = PublicContract;
+
+    fn deref(&self) -> &Self::Target {
+        &self.contract
+    }
 }
@@ -3,18 +3,28 @@
 //! This module provides the [`CorporationEndpoints`] struct and associated methods for accessing
 //! corporation-related ESI endpoints.
 
-use crate::esi::EsiRequest;
+use crate::esi::{AccessToken, EsiRequest, IconSize, Image};
 use crate::model::asset::Blueprint;
+#[cfg(feature = "assets")]
+use crate::model::corporation::CorporationHangarDivision;
 use crate::model::corporation::{
     Corporation, CorporationAllianceHistory, CorporationDivisions, CorporationFacilities,
-    CorporationIcon, CorporationIssuedMedal, CorporationMedal, CorporationMemberRoles,
-    CorporationMemberRolesHistory, CorporationMemberTitles, CorporationMemberTracking,
-    CorporationSecureContainerLog, CorporationShareholder, CorporationStarbase,
-    CorporationStarbaseDetails, CorporationStructure, CorporationTitle,
+    CorporationIcon, CorporationIssuedMedal, CorporationMedal, CorporationMemberAccessSnapshot,
+    CorporationMemberRoles, CorporationMemberRolesHistory, CorporationMemberTitles,
+    CorporationMemberTracking, CorporationSecureContainerLog, CorporationShareholder,
+    CorporationStarbase, CorporationStarbaseDetails, CorporationStructure, CorporationTitle,
+    DividendReport, DividendRounding,
+};
+#[cfg(feature = "universe")]
+use crate::model::corporation::{
+    CorporationStructureDashboardEntry, SecureContainerAuditEntry, SecureContainerLogFilter,
+    StructureState,
 };
 use crate::model::standing::Standing;
+#[cfg(all(feature = "assets", feature = "universe"))]
+use crate::model::{corporation::CorporationOffice, enums::asset::LocationType};
 use crate::scope::{CorporationsScopes, WalletScopes};
-use crate::{Client, ScopeBuilder};
+use crate::{Client, Error, ScopeBuilder};
 use reqwest::Method;
 
 /// Provides methods for accessing corporation-related endpoints of the EVE Online ESI API.
@@ -104,14 +114,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_blueprints.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve blueprints for.
         /// - `page`            (`i32`): The page of blueprints to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of blueprint entries for the corporation when sent.
         auth fn get_corporation_blueprints(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<Blueprint>>
@@ -138,14 +148,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_container_logs.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve ALSC logs for.
         /// - `page`            (`i32`): The page of ALSC logs to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of audit log secure container entries for the corporation when sent.
         auth fn get_all_corporation_alsc_logs(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationSecureContainerLog>>
@@ -170,13 +180,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_divisions.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve divisions for
         ///
         /// # Returns
         /// An ESI request builder that returns hangar and wallet divisions for the corporation when sent.
         auth fn get_corporation_divisions(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<CorporationDivisions>
         method = Method::GET;
@@ -200,13 +210,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_facilities.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve facilities for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of industry facilities for the corporation when sent.
         auth fn get_corporation_facilities(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationFacilities>>
         method = Method::GET;
@@ -250,14 +260,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_medals.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve medals for
         /// - `page`            (`i32`): The page of medals to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of medal definitions for the corporation when sent.
         auth fn get_corporation_medals(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationMedal>>
@@ -285,14 +295,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_medals.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve issued medals for
         /// - `page`            (`i32`): The page of issued medals to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of issued medal records for the corporation when sent.
         auth fn get_corporation_issued_medals(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationIssuedMedal>>
@@ -317,13 +327,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_corporation_membership.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve members for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of character IDs of corporation members when sent.
         auth fn get_corporation_members(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<i64>>
         method = Method::GET;
@@ -351,13 +361,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_corporation_membership.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve the member limit for
         ///
         /// # Returns
         /// An ESI request builder that returns the maximum number of members the corporation can have when sent.
         auth fn get_corporation_member_limit(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<i32>
         method = Method::GET;
@@ -385,13 +395,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_titles.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve member titles for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of member character IDs and their associated title IDs when sent.
         auth fn get_corporation_members_titles(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationMemberTitles>>
         method = Method::GET;
@@ -405,6 +415,8 @@ impl<'a> CorporationEndpoints<'a> {
         /// Fetches a list of tracking information for each character part of the provided corporation ID
         ///
         /// Returns data such as member start dates, logon/logoff timestamps, location, and ship type.
+        /// [`CorporationMemberTracking`] derives activity such as [`CorporationMemberTracking::is_inactive`]
+        /// & groups members with [`CorporationMemberTracking::group_by_location`].
         ///
         /// Additional permissions required: the owner of the access token must hold the `director` role within
         /// the corporation to access this information.
@@ -419,13 +431,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.track_members.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve member tracking for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of tracking information for each corporation member when sent.
+        /// Fields the character can't view are omitted, see [`RoleRestricted`](crate::model::RoleRestricted).
         auth fn track_corporation_members(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationMemberTracking>>
         method = Method::GET;
@@ -453,13 +466,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_corporation_membership.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve member roles for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of roles for each corporation member when sent.
         auth fn get_corporation_member_roles(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationMemberRoles>>
         method = Method::GET;
@@ -487,14 +500,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_corporation_membership.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve role history for
         /// - `page`            (`i32`): The page of role history to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of role change history for the corporation when sent.
         auth fn get_corporation_member_roles_history(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationMemberRolesHistory>>
@@ -523,14 +536,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-wallet.read_corporation_wallets.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve shareholders for
         /// - `page`            (`i32`): The page of shareholders to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of shareholders for the corporation when sent.
         auth fn get_corporation_shareholders(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationShareholder>>
@@ -556,14 +569,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_standings.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve standings for
         /// - `page`            (`i32`): The page of standings to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of NPC standings for the corporation when sent.
         auth fn get_corporation_standings(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<Standing>>
@@ -592,14 +605,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_starbases.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve starbases for
         /// - `page`            (`i32`): The page of starbases to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of starbases owned by the corporation when sent.
         auth fn get_corporation_starbases(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationStarbase>>
@@ -628,15 +641,16 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_starbases.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation that owns the starbase
         /// - `starbase_id`     (`i64`): The ID of the starbase to retrieve details for
         /// - `system_id`       (`i64`): The ID of the solar system where the starbase is located
         ///
         /// # Returns
         /// An ESI request builder that returns detailed information about the specified starbase when sent.
+        /// The fuel bay contents are omitted if the character can't view them, see [`RoleRestricted`](crate::model::RoleRestricted).
         auth fn get_starbase_detail(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64,
             starbase_id: i64;
             system_id: i64
@@ -667,14 +681,14 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_structures.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve structures for
         /// - `page`            (`i32`): The page of structures to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a paginated list of structures owned by the corporation when sent.
         auth fn get_corporation_structures(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationStructure>>
@@ -703,13 +717,13 @@ impl<'a> CorporationEndpoints<'a> {
         ///   `esi-corporations.read_titles.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve titles for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of corporation titles and their associated roles when sent.
         auth fn get_corporation_titles(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationTitle>>
         method = Method::GET;
@@ -718,4 +732,334 @@ impl<'a> CorporationEndpoints<'a> {
             .corporations(CorporationsScopes::new().read_titles())
             .build();
     }
+
+    /// Downloads the icon of a corporation
+    ///
+    /// Retrieves the icon URLs with [`Self::get_corporation_icon`] & downloads the image of the
+    /// provided size with [`EsiApi::download_image`](crate::esi::EsiApi::download_image).
+    ///
+    /// For an overview & usage examples, see the [image module documentation](crate::esi::image)
+    ///
+    /// # Arguments
+    /// - `corporation_id` (`i64`): The ID of the corporation to download the icon of
+    /// - `size`           ([`IconSize`]): Size of the icon to download
+    ///
+    /// # Returns
+    /// The icon's bytes, content type & cache headers, or an [`Error`] if either request fails.
+    pub async fn get_corporation_icon_image(
+        &self,
+        corporation_id: i64,
+        size: IconSize,
+    ) -> Result<Image, Error> {
+        let icons = self.get_corporation_icon(corporation_id).send().await?;
+
+        self.client
+            .esi()
+            .download_fresh_image(icons.url(size))
+            .await
+    }
+
+    /// Retrieves a dashboard of all structures owned by a corporation sorted by fuel expiration
+    ///
+    /// Fetches all pages of [`Self::get_corporation_structures`], resolves the names of the structure
+    /// types & solar systems in bulk, and normalizes each structure's state & timers into a
+    /// [`StructureState`].
+    ///
+    /// Structures are sorted by [`CorporationStructure::fuel_expires`] with the soonest first, structures
+    /// without fuel (low power) are listed before all others.
+    ///
+    /// Additional permissions required: the owner of the access token must be a director in the
+    /// corporation or have the `Station_Manager` role.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CorporationsScopes::read_structures`](crate::scope::CorporationsScopes::read_structures):
+    ///   `esi-corporations.read_structures.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve the structure dashboard for
+    ///
+    /// # Returns
+    /// A list of dashboard entries for each structure, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "universe")]
+    pub async fn get_structure_dashboard(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<Vec<CorporationStructureDashboardEntry>, Error> {
+        let structures = self
+            .get_corporation_structures(access_token, corporation_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+
+        // Resolve type & solar system names in bulk
+        let ids: Vec<i64> = structures
+            .iter()
+            .flat_map(|structure| [structure.type_id, structure.system_id])
+            .collect();
+        let names = self.client.universe().resolve_names(ids).await?;
+
+        let mut dashboard: Vec<CorporationStructureDashboardEntry> = structures
+            .into_iter()
+            .map(|structure| CorporationStructureDashboardEntry {
+                type_name: names.get(&structure.type_id).cloned(),
+                system_name: names.get(&structure.system_id).cloned(),
+                state: StructureState::from_structure(&structure),
+                structure,
+            })
+            .collect();
+
+        // Sort by fuel expiration, structures without fuel first as `None` orders before `Some`
+        dashboard.sort_by_key(|entry| entry.structure.fuel_expires);
+
+        Ok(dashboard)
+    }
+
+    /// Lists every station & structure where a corporation rents an office
+    ///
+    /// Fetches all pages of [`AssetsEndpoints::get_corporation_assets`](crate::endpoints::assets::AssetsEndpoints::get_corporation_assets),
+    /// finds the offices within the corporation's assets with [`CorporationOffice::from_assets`] & resolves
+    /// the names of stations in bulk. Structure names are not resolved as they require docking access.
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `director` role within
+    /// the corporation to access this information.
+    ///
+    /// Requires the `assets` & `universe` features.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`AssetsScopes::read_corporation_assets`](crate::scope::AssetsScopes::read_corporation_assets):
+    ///   `esi-assets.read_corporation_assets.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to list offices for
+    ///
+    /// # Returns
+    /// A list of the corporation's offices, or an [`Error`] if any of the requests fail.
+    #[cfg(all(feature = "assets", feature = "universe"))]
+    pub async fn get_corporation_offices(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<Vec<CorporationOffice>, Error> {
+        let assets = self
+            .client
+            .assets()
+            .get_corporation_assets(access_token, corporation_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+
+        let mut offices = CorporationOffice::from_assets(&assets);
+
+        // Resolve station names in bulk, structure IDs can't be resolved via universe names
+        let station_ids: Vec<i64> = offices
+            .iter()
+            .filter(|office| office.location_type == LocationType::Station)
+            .map(|office| office.location_id)
+            .collect();
+
+        if !station_ids.is_empty() {
+            let names = self.client.universe().resolve_names(station_ids).await?;
+
+            for office in &mut offices {
+                office.location_name = names.get(&office.location_id).cloned();
+            }
+        }
+
+        Ok(offices)
+    }
+
+    /// Groups a corporation's assets by the hangar division they are stored in
+    ///
+    /// Fetches all pages of [`AssetsEndpoints::get_corporation_assets`](crate::endpoints::assets::AssetsEndpoints::get_corporation_assets)
+    /// & [`Self::get_corporation_divisions`], see [`CorporationHangarDivision::from_assets`].
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `director` role within
+    /// the corporation to access this information.
+    ///
+    /// Requires the `assets` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`AssetsScopes::read_corporation_assets`](crate::scope::AssetsScopes::read_corporation_assets):
+    ///   `esi-assets.read_corporation_assets.v1`
+    /// - [`CorporationsScopes::read_divisions`](crate::scope::CorporationsScopes::read_divisions):
+    ///   `esi-corporations.read_divisions.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to group assets for
+    ///
+    /// # Returns
+    /// Hangar divisions 1 through 7 with their names & assets, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "assets")]
+    pub async fn get_corporation_hangar_divisions(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<Vec<CorporationHangarDivision>, Error> {
+        let access_token = access_token.into();
+
+        let assets = self
+            .client
+            .assets()
+            .get_corporation_assets(&access_token, corporation_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+        let divisions = self
+            .get_corporation_divisions(&access_token, corporation_id)
+            .send()
+            .await?
+            .data;
+
+        Ok(CorporationHangarDivision::from_assets(assets, &divisions))
+    }
+
+    /// Calculates the dividend payout for each shareholder of a corporation
+    ///
+    /// Fetches all pages of [`Self::get_corporation_shareholders`] & splits the provided amount of ISK
+    /// between the shareholders proportionally to the shares they hold, see [`DividendReport::new`].
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `director` role within
+    /// the corporation to access this information.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+    ///   `esi-wallet.read_corporation_wallets.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to calculate dividends for
+    /// - `amount`          (`f64`): Amount of ISK to pay out as dividends
+    /// - `rounding` ([`DividendRounding`]): Rounding rule applied to each payout
+    ///
+    /// # Returns
+    /// A [`DividendReport`] with the payout for each shareholder, or an [`Error`] if any of the requests fail.
+    pub async fn calculate_dividends(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+        amount: f64,
+        rounding: DividendRounding,
+    ) -> Result<DividendReport, Error> {
+        let shareholders = self
+            .get_corporation_shareholders(access_token, corporation_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+
+        Ok(DividendReport::new(&shareholders, amount, rounding))
+    }
+
+    /// Retrieves a snapshot of the roles & titles of each member of a corporation
+    ///
+    /// Combines [`Self::get_corporation_member_roles`] & [`Self::get_corporation_members_titles`], successive
+    /// snapshots can be compared with [`CorporationMemberAccessSnapshot::diff`] to audit role & title changes.
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `director` role within
+    /// the corporation to access this information.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CorporationsScopes::read_corporation_membership`](crate::scope::CorporationsScopes::read_corporation_membership):
+    ///   `esi-corporations.read_corporation_membership.v1`
+    /// - [`CorporationsScopes::read_titles`](crate::scope::CorporationsScopes::read_titles):
+    ///   `esi-corporations.read_titles.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve the snapshot for
+    ///
+    /// # Returns
+    /// A [`CorporationMemberAccessSnapshot`] of the corporation's members, or an [`Error`] if either request fails.
+    pub async fn get_member_access_snapshot(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<CorporationMemberAccessSnapshot, Error> {
+        let access_token = access_token.into();
+
+        let roles = self
+            .get_corporation_member_roles(&access_token, corporation_id)
+            .send()
+            .await?
+            .data;
+        let titles = self
+            .get_corporation_members_titles(&access_token, corporation_id)
+            .send()
+            .await?
+            .data;
+
+        Ok(CorporationMemberAccessSnapshot { roles, titles })
+    }
+
+    /// Retrieves a corporation's audit log secure container entries matching the filter with the
+    /// names of the characters & item types involved
+    ///
+    /// Requests every page of [`Self::get_all_corporation_alsc_logs`], keeps the entries matching
+    /// `filter` & resolves the character & item type IDs of those entries in bulk with
+    /// [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names).
+    /// Each [`SecureContainerAuditEntry`] can be displayed as a single line description of the entry.
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `director` role within
+    /// the corporation to access this information.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CorporationsScopes::read_container_logs`](crate::scope::CorporationsScopes::read_container_logs):
+    ///   `esi-corporations.read_container_logs.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve ALSC logs for
+    /// - `filter`          (&[`SecureContainerLogFilter`]): The entries to include
+    ///
+    /// # Returns
+    /// The matching entries ordered from newest to oldest, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "universe")]
+    pub async fn get_corporation_alsc_audit_trail(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+        filter: &SecureContainerLogFilter,
+    ) -> Result<Vec<SecureContainerAuditEntry>, Error> {
+        let mut logs = self
+            .get_all_corporation_alsc_logs(access_token, corporation_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+        logs.retain(|log| filter.matches(log));
+        logs.sort_by_key(|log| std::cmp::Reverse(log.logged_at));
+
+        let ids = logs
+            .iter()
+            .flat_map(|log| [log.character_id, log.type_id])
+            .collect();
+        let names = self.client.universe().resolve_names(ids).await?;
+
+        Ok(logs
+            .into_iter()
+            .map(|log| SecureContainerAuditEntry {
+                character_name: names.get(&log.character_id).cloned(),
+                type_name: names.get(&log.type_id).cloned(),
+                log,
+            })
+            .collect())
+    }
 }
@@ -2,8 +2,24 @@
 //!
 //! This module provides the [`CorporationProjectsEndpoints`] struct and associated methods for accessing
 //! corporation project-related ESI endpoints.
+//!
+//! The projects & contributors lists are paginated with cursors rather than page numbers, iterate
+//! them with a [`Cursor`](crate::esi::Cursor), see the [cursor module documentation](crate::esi::cursor).
+
+use reqwest::Method;
 
-use crate::Client;
+use crate::{
+    esi::EsiRequest,
+    model::{
+        corporation_projects::{
+            CorporationProjectContribution, CorporationProjectContributorsPage,
+            CorporationProjectsPage,
+        },
+        enums::corporation_projects::CorporationProjectState,
+    },
+    scope::CorporationsScopes,
+    Client, ScopeBuilder,
+};
 
 /// Provides methods for accessing corporation project-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +36,117 @@ impl<'a> CorporationProjectsEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves a page of the projects of the provided corporation, newest first
+        ///
+        /// Iterate every page with a [`Cursor`](crate::esi::Cursor) rather than setting the
+        /// `before` & `after` tokens manually.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdProjects>
+        ///
+        /// # Required Scopes
+        /// - [`CorporationsScopes::read_projects`](crate::scope::CorporationsScopes::read_projects):
+        ///   `esi-corporations.read_projects.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation to retrieve projects for
+        /// - `after`          (`Option<&str>`): Token to retrieve the projects modified since a previous page
+        /// - `before`         (`Option<&str>`): Token to retrieve the projects older than a previous page
+        /// - `limit`          (`Option<i32>`): Maximum number of projects per page
+        /// - `state`          (`Option<`[`CorporationProjectState`]`>`): Only retrieve projects in the provided state
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a page of the corporation's projects when sent.
+        auth fn list_corporation_projects(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64;
+            after: Option<&str>,
+            before: Option<&str>,
+            limit: Option<i32>,
+            state: Option<CorporationProjectState>
+        ) -> EsiRequest<CorporationProjectsPage>
+        method = Method::GET;
+        path = "/corporations/{}/projects";
+        required_scopes = ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_projects())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves a page of the characters who contributed to the provided corporation project
+        ///
+        /// Iterate every page with a [`Cursor`](crate::esi::Cursor) rather than setting the
+        /// `before` & `after` tokens manually.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdProjectsProjectIdContributors>
+        ///
+        /// # Required Scopes
+        /// - [`CorporationsScopes::read_projects`](crate::scope::CorporationsScopes::read_projects):
+        ///   `esi-corporations.read_projects.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation which created the project
+        /// - `project_id`     (`&str`): The UUID of the project
+        /// - `after`          (`Option<&str>`): Token to retrieve the contributors modified since a previous page
+        /// - `before`         (`Option<&str>`): Token to retrieve the contributors older than a previous page
+        /// - `limit`          (`Option<i32>`): Maximum number of contributors per page
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a page of the project's contributors when sent.
+        auth fn list_corporation_project_contributors(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64,
+            project_id: &str;
+            after: Option<&str>,
+            before: Option<&str>,
+            limit: Option<i32>
+        ) -> EsiRequest<CorporationProjectContributorsPage>
+        method = Method::GET;
+        path = "/corporations/{}/projects/{}/contributors";
+        required_scopes = ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_projects())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the contribution of the provided character to a corporation project
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdProjectsProjectIdContributionCharacterId>
+        ///
+        /// # Required Scopes
+        /// - [`CorporationsScopes::read_projects`](crate::scope::CorporationsScopes::read_projects):
+        ///   `esi-corporations.read_projects.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation which created the project
+        /// - `project_id`     (`&str`): The UUID of the project
+        /// - `character_id`   (`i64`): The ID of the character to retrieve the contribution of
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the character's contribution to the project when sent.
+        auth fn get_corporation_project_contribution(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64,
+            project_id: &str,
+            character_id: i64
+        ) -> EsiRequest<CorporationProjectContribution>
+        method = Method::GET;
+        path = "/corporations/{}/projects/{}/contribution/{}";
+        required_scopes = ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_projects())
+            .build();
+    }
 }
@@ -3,7 +3,21 @@
 //! This module provides the [`DogmaEndpoints`] struct and associated methods for accessing
 //! dogma-related ESI endpoints.
 
+use reqwest::Method;
+
+#[cfg(feature = "universe")]
+use crate::constant::DEFAULT_COMPOSITE_CONCURRENCY;
+#[cfg(feature = "universe")]
+use crate::esi::concurrency::SharedRequests;
+#[cfg(feature = "universe")]
+use crate::esi::join_limited_partial;
+use crate::esi::EsiRequest;
+use crate::model::dogma::DynamicItem;
+#[cfg(feature = "universe")]
+use crate::model::dogma::DynamicItemDetails;
 use crate::Client;
+#[cfg(feature = "universe")]
+use crate::CompositeError;
 
 /// Provides methods for accessing dogma-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +34,90 @@ impl<'a> DogmaEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the dogma attributes & effects of a mutated item
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetDogmaDynamicItemsTypeIdItemId>
+        ///
+        /// # Arguments
+        /// - `type_id` (`i64`): The type ID of the mutated item
+        /// - `item_id` (`i64`): The ID of the mutated item
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the mutated item's dogma attributes & effects when sent.
+        pub fn get_dynamic_item_information(
+            type_id: i64,
+            item_id: i64
+        ) -> EsiRequest<DynamicItem>
+        method = Method::GET;
+        path = "/dogma/dynamic/items/{}/{}";
+    }
+
+    /// Retrieves mutated items' attributes merged with the attributes of the types they were mutated from
+    ///
+    /// Requests [`Self::get_dynamic_item_information`] for every item & the type information of its
+    /// source type concurrently, up to 10 items at a time, then merges the mutated values with the
+    /// base values, see [`DynamicItemDetails`]. Each distinct source type is only requested once.
+    /// Type & item ID pairs can be taken from assets where the item is a mutated type or from
+    /// killmail items & victims.
+    ///
+    /// A failed request doesn't stop the remaining items from being requested, the details retrieved
+    /// are returned alongside the failures, see [`CompositeError`]. An item whose source type can't
+    /// be retrieved is a failure, its source type is requested again by the next item needing it.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `items` (`Vec<(i64, i64)>`): Type ID & item ID pairs of the mutated items
+    ///
+    /// # Returns
+    /// The details of each mutated item in the order provided, or a [`CompositeError`] with the
+    /// details retrieved & the error of each type & item ID pair which failed if any of the requests
+    /// fail.
+    #[cfg(feature = "universe")]
+    pub async fn get_dynamic_item_details(
+        &self,
+        items: Vec<(i64, i64)>,
+    ) -> Result<Vec<DynamicItemDetails>, CompositeError<Vec<DynamicItemDetails>, (i64, i64)>> {
+        let universe = self.client.universe();
+        let source_types = SharedRequests::default();
+
+        join_limited_partial(
+            self.client,
+            items,
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |item| *item,
+            |(type_id, item_id)| {
+                let request = self.get_dynamic_item_information(type_id, item_id);
+                let (universe, source_types) = (&universe, &source_types);
+
+                async move {
+                    let item = request.send().await?.data;
+                    let source_type = source_types
+                        .get_or_send(item.source_type_id, || async {
+                            universe
+                                .get_item_type_information(item.source_type_id)
+                                .send()
+                                .await
+                                .map(|response| response.data)
+                        })
+                        .await?;
+
+                    Ok(DynamicItemDetails::new(
+                        type_id,
+                        item_id,
+                        item,
+                        &source_type.dogma_attributes,
+                    ))
+                }
+            },
+        )
+        .await
+    }
 }
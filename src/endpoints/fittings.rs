@@ -3,7 +3,19 @@
 //! This module provides the [`FittingsEndpoints`] struct and associated methods for accessing
 //! fitting-related ESI endpoints.
 
-use crate::Client;
+#[cfg(feature = "universe")]
+use std::collections::HashMap;
+
+use crate::esi::{EsiRequest, Idempotency};
+#[cfg(feature = "universe")]
+use crate::model::fitting::FittingStats;
+use crate::model::fitting::{CreatedFitting, Fitting};
+use crate::model::requests::NewFitting;
+use crate::scope::FittingsScopes;
+#[cfg(feature = "universe")]
+use crate::Error;
+use crate::{Client, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing fitting-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +32,116 @@ impl<'a> FittingsEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the fittings saved by the provided character ID
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdFittings>
+        ///
+        /// # Required Scopes
+        /// - [`FittingsScopes::read_fittings`](crate::scope::FittingsScopes::read_fittings):
+        ///   `esi-fittings.read_fittings.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`     (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`      (`i64`): The ID of the character to retrieve fittings for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the character's saved fittings when sent.
+        auth fn get_fittings(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<Vec<Fitting>>
+        method = Method::GET;
+        path = "/characters/{}/fittings";
+        required_scopes = ScopeBuilder::new()
+            .fittings(FittingsScopes::new().read_fittings())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Saves a new fitting for the provided character ID
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdFittings>
+        ///
+        /// # Required Scopes
+        /// - [`FittingsScopes::write_fittings`](crate::scope::FittingsScopes::write_fittings):
+        ///   `esi-fittings.write_fittings.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`     (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`      (`i64`): The ID of the character to save the fitting for
+        /// - `fitting`  ([`NewFitting`]): The name, description, ship, & items of the fitting
+        ///
+        /// # Returns
+        /// An ESI request builder that saves the fitting and returns the ID of the new fitting when sent.
+        auth fn create_fitting(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<CreatedFitting>
+        method = Method::POST;
+        path = "/characters/{}/fittings";
+        required_scopes = ScopeBuilder::new()
+            .fittings(FittingsScopes::new().write_fittings())
+            .build();
+        body = fitting: NewFitting;
+        idempotency = Idempotency::Unsafe;
+    }
+
+    /// Calculates the CPU, powergrid, & calibration usage of a fitting from dogma attributes
+    ///
+    /// Retrieves the item type information of the ship & each unique item in a fitting slot with
+    /// [`UniverseEndpoints::get_item_type_information`](crate::endpoints::universe::UniverseEndpoints::get_item_type_information),
+    /// making 1 request per item type, then calculates the usage with [`FittingStats::calculate`].
+    ///
+    /// Values are the base values of the hull & modules without skills or modifiers applied.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `fitting` (&[`Fitting`]): The fitting to calculate the stats of
+    ///
+    /// # Returns
+    /// The [`FittingStats`] of the fitting, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "universe")]
+    pub async fn calculate_fitting_stats(&self, fitting: &Fitting) -> Result<FittingStats, Error> {
+        let universe = self.client.universe();
+
+        let ship = universe
+            .get_item_type_information(fitting.ship_type_id)
+            .send()
+            .await?
+            .data;
+
+        let mut type_ids: Vec<i64> = fitting
+            .items
+            .iter()
+            .filter(|item| item.flag.is_fitting_slot())
+            .map(|item| item.type_id)
+            .collect();
+        type_ids.sort_unstable();
+        type_ids.dedup();
+
+        let mut types = HashMap::with_capacity(type_ids.len());
+
+        for type_id in type_ids {
+            let item_type = universe
+                .get_item_type_information(type_id)
+                .send()
+                .await?
+                .data;
+
+            types.insert(type_id, item_type);
+        }
+
+        Ok(FittingStats::calculate(&ship, &fitting.items, &types))
+    }
 }
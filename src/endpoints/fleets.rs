@@ -3,7 +3,11 @@
 //! This module provides the [`FleetsEndpoints`] struct and associated methods for accessing
 //! fleet-related ESI endpoints.
 
-use crate::Client;
+use crate::esi::{EsiRequest, Idempotency};
+use crate::model::requests::FleetInvitation;
+use crate::scope::FleetsScopes;
+use crate::{Client, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing fleet-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +24,38 @@ impl<'a> FleetsEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Invites a character to the provided fleet ID
+        ///
+        /// Additional permissions required: the owner of the access token must be the fleet boss.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/PostFleetsFleetIdMembers>
+        ///
+        /// # Required Scopes
+        /// - [`FleetsScopes::write_fleet`](crate::scope::FleetsScopes::write_fleet):
+        ///   `esi-fleets.write_fleet.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`             (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `fleet_id`                  (`i64`): The ID of the fleet to invite the character to
+        /// - `invitation` ([`FleetInvitation`]): The character to invite & the role, wing, & squad to invite them to
+        ///
+        /// # Returns
+        /// An ESI request builder that sends the fleet invitation when sent.
+        auth fn create_fleet_invitation(
+            access_token: impl Into<AccessToken>,
+            fleet_id: i64
+        ) -> EsiRequest<()>
+        method = Method::POST;
+        path = "/fleets/{}/members";
+        required_scopes = ScopeBuilder::new()
+            .fleets(FleetsScopes::new().write_fleet())
+            .build();
+        body = invitation: FleetInvitation;
+        idempotency = Idempotency::Safe;
+    }
 }
@@ -3,7 +3,17 @@
 //! This module provides the [`IndustryEndpoints`] struct and associated methods for accessing
 //! industry-related ESI endpoints.
 
-use crate::Client;
+use crate::esi::EsiRequest;
+#[cfg(feature = "market")]
+use crate::model::enums::industry::IndustryActivity;
+#[cfg(feature = "market")]
+use crate::model::industry::{estimate_job_cost, BlueprintMaterial, JobCostEstimate};
+use crate::model::industry::{IndustrySystem, MiningObserver, MiningObserverEntry, MoonExtraction};
+use crate::scope::IndustryScopes;
+#[cfg(feature = "market")]
+use crate::Error;
+use crate::{Client, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing industry-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +30,180 @@ impl<'a> IndustryEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves moon mining extraction timers for the provided corporation ID
+        ///
+        /// Returns extractions which are currently in progress or have a chunk which has arrived
+        /// but not yet been fractured, including when the chunk arrives & when it will auto-fracture.
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Station_Manager` role within
+        /// the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationCorporationIdMiningExtractions>
+        ///
+        /// # Required Scopes
+        /// - [`IndustryScopes::read_corporation_mining`](crate::scope::IndustryScopes::read_corporation_mining):
+        ///   `esi-industry.read_corporation_mining.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve extraction timers for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of moon extraction timers for the corporation when sent.
+        auth fn moon_extraction_timers(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64
+        ) -> EsiRequest<Vec<MoonExtraction>>
+        method = Method::GET;
+        path = "/corporation/{}/mining/extractions";
+        required_scopes = ScopeBuilder::new()
+            .industry(IndustryScopes::new().read_corporation_mining())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves a paginated list of mining observers for the provided corporation ID
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Accountant` role within
+        /// the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationCorporationIdMiningObservers>
+        ///
+        /// # Required Scopes
+        /// - [`IndustryScopes::read_corporation_mining`](crate::scope::IndustryScopes::read_corporation_mining):
+        ///   `esi-industry.read_corporation_mining.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve mining observers for
+        /// - `page`            (`i32`): The page of mining observers to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated list of the corporation's mining observers when sent.
+        auth fn corporation_mining_observers(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64;
+            page: i32
+        ) -> EsiRequest<Vec<MiningObserver>>
+        method = Method::GET;
+        path = "/corporation/{}/mining/observers";
+        required_scopes = ScopeBuilder::new()
+            .industry(IndustryScopes::new().read_corporation_mining())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves a paginated list of mining recorded by a corporation's mining observer
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Accountant` role within
+        /// the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationCorporationIdMiningObserversObserverId>
+        ///
+        /// # Required Scopes
+        /// - [`IndustryScopes::read_corporation_mining`](crate::scope::IndustryScopes::read_corporation_mining):
+        ///   `esi-industry.read_corporation_mining.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id`  (`i64`): The ID of the corporation which owns the observer
+        /// - `observer_id`     (`i64`): The ID of the mining observer to retrieve mining entries for
+        /// - `page`            (`i32`): The page of mining entries to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated list of mining entries recorded by the observer when sent.
+        auth fn observed_corporation_mining(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64,
+            observer_id: i64;
+            page: i32
+        ) -> EsiRequest<Vec<MiningObserverEntry>>
+        method = Method::GET;
+        path = "/corporation/{}/mining/observers/{}";
+        required_scopes = ScopeBuilder::new()
+            .industry(IndustryScopes::new().read_corporation_mining())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the industry cost indices of every solar system with industry activity
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetIndustrySystems>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the cost indices of each solar system when sent.
+        pub fn list_solar_system_cost_indices(
+        ) -> EsiRequest<Vec<IndustrySystem>>
+        method = Method::GET;
+        path = "/industry/systems";
+    }
+
+    /// Estimates the install cost of a manufacturing or reaction job in the provided solar system
+    ///
+    /// Requests the solar system cost indices with [`Self::list_solar_system_cost_indices`] & the
+    /// adjusted prices with
+    /// [`MarketEndpoints::list_market_prices`](crate::endpoints::market::MarketEndpoints::list_market_prices),
+    /// then estimates the cost with [`estimate_job_cost`]. Both are cached by ESI for an hour, prefer
+    /// requesting them once & calling [`estimate_job_cost`] directly when estimating many jobs.
+    ///
+    /// Blueprint materials can be loaded from the Static Data Export with the `sde` feature, see
+    /// `StaticData::blueprint_materials` in the `sde` module.
+    ///
+    /// Requires the `market` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `solar_system_id`   (`i64`): The ID of the solar system the job is installed in
+    /// - `activity`          ([`IndustryActivity`]): The activity of the job, e.g. manufacturing or reaction
+    /// - `materials`         (`&[BlueprintMaterial]`): Materials required per run of the blueprint or formula
+    /// - `runs`              (`u32`): The number of runs of the job
+    /// - `facility_tax_rate` (`f64`): The facility's tax rate, e.g. `0.0025` for the 0.25% of NPC stations
+    ///
+    /// # Returns
+    /// The estimated install cost, `None` if the solar system has no cost index for the activity, or
+    /// an [`Error`] if any of the requests fail.
+    #[cfg(feature = "market")]
+    pub async fn estimate_job_cost(
+        &self,
+        solar_system_id: i64,
+        activity: IndustryActivity,
+        materials: &[BlueprintMaterial],
+        runs: u32,
+        facility_tax_rate: f64,
+    ) -> Result<Option<JobCostEstimate>, Error> {
+        let systems = self.list_solar_system_cost_indices().send().await?.data;
+
+        let Some(cost_index) = systems
+            .iter()
+            .find(|system| system.solar_system_id == solar_system_id)
+            .and_then(|system| system.cost_index(activity))
+        else {
+            return Ok(None);
+        };
+
+        let prices = self.client.market().list_market_prices().send().await?.data;
+
+        Ok(Some(estimate_job_cost(
+            materials,
+            runs,
+            &prices,
+            cost_index,
+            facility_tax_rate,
+        )))
+    }
 }
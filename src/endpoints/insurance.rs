@@ -3,7 +3,20 @@
 //! This module provides the [`InsuranceEndpoints`] struct and associated methods for accessing
 //! insurance-related ESI endpoints.
 
+use reqwest::Method;
+
+use crate::esi::EsiRequest;
+use crate::model::insurance::InsurancePrice;
 use crate::Client;
+#[cfg(feature = "killmails")]
+use crate::{
+    model::{
+        enums::insurance::InsuranceLevel,
+        insurance::{estimate_payout, InsurancePayoutEstimate},
+        killmail::Killmail,
+    },
+    Error,
+};
 
 /// Provides methods for accessing insurance-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +33,49 @@ impl<'a> InsuranceEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the cost & payout of each insurance level for all insurable ship types
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetInsurancePrices>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the insurance levels of all insurable ship types when sent.
+        pub fn list_insurance_levels(
+        ) -> EsiRequest<Vec<InsurancePrice>>
+        method = Method::GET;
+        path = "/insurance/prices";
+    }
+
+    /// Estimates the insurance payout for the ship lost in a killmail
+    ///
+    /// Requests [`Self::list_insurance_levels`] & looks up the cost & payout of the provided level
+    /// for the victim's ship type, see [`estimate_payout`]. Use [`estimate_payout`] directly with
+    /// prices retrieved once when estimating payouts for many killmails.
+    ///
+    /// Requires the `killmails` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `killmail` (`&Killmail`): The killmail of the lost ship, see
+    ///   [`KillmailsEndpoints::get_killmail`](crate::endpoints::killmails::KillmailsEndpoints::get_killmail)
+    /// - `level` ([`InsuranceLevel`]): The insurance level the ship was insured at
+    ///
+    /// # Returns
+    /// The estimated payout, `None` if the victim's ship type can't be insured at the provided
+    /// level, or an [`Error`] if the request fails.
+    #[cfg(feature = "killmails")]
+    pub async fn estimate_insurance_payout(
+        &self,
+        killmail: &Killmail,
+        level: InsuranceLevel,
+    ) -> Result<Option<InsurancePayoutEstimate>, Error> {
+        let prices = self.list_insurance_levels().send().await?.data;
+
+        Ok(estimate_payout(&prices, killmail, &level))
+    }
 }
@@ -1,9 +1,23 @@
 //! # EVE ESI Killmails Endpoints
 //!
 //! This module provides the [`KillmailsEndpoints`] struct and associated methods for accessing
-//! killmail-related ESI endpoints.
+//! killmail-related ESI endpoints, along with the [`SeenStore`] trait used by killmail feeds to skip
+//! killmails which were already yielded.
 
-use crate::Client;
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::Stream;
+use reqwest::Method;
+
+use crate::constant::DEFAULT_KILLMAIL_FEED_SEEN_CAPACITY;
+use crate::esi::{AccessToken, EsiRequest, MemoryResponseCache};
+use crate::model::killmail::{Killmail, KillmailReference};
+use crate::scope::KillmailsScopes;
+use crate::{Client, Error, ScopeBuilder};
 
 /// Provides methods for accessing killmail-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +34,623 @@ impl<'a> KillmailsEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves a killmail's details using its ID & hash
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetKillmailsKillmailIdKillmailHash>
+        ///
+        /// # Arguments
+        /// - `killmail_id`   (`i64`): The ID of the killmail to retrieve
+        /// - `killmail_hash` (`&str`): The hash of the killmail, see [`KillmailReference`]
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the killmail's details when sent.
+        pub fn get_killmail(
+            killmail_id: i64,
+            killmail_hash: &str
+        ) -> EsiRequest<Killmail>
+        method = Method::GET;
+        path = "/killmails/{}/{}";
+    }
+
+    define_esi_endpoint! {
+        /// Get paginated list of a character's recent kills & losses
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdKillmailsRecent>
+        ///
+        /// # Required Scopes
+        /// - [`KillmailsScopes::read_killmails`](crate::scope::KillmailsScopes::read_killmails):
+        ///   `esi-killmails.read_killmails.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to retrieve recent killmails for
+        /// - `page`         (`i32`): The page of killmails to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated list of killmail IDs & hashes when sent.
+        auth fn get_character_recent_killmails(
+            access_token: impl Into<AccessToken>,
+            character_id: i64;
+            page: i32
+        ) -> EsiRequest<Vec<KillmailReference>>
+        method = Method::GET;
+        path = "/characters/{}/killmails/recent";
+        required_scopes = ScopeBuilder::new()
+            .killmails(KillmailsScopes::new().read_killmails())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Get paginated list of a corporation's recent kills & losses
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Director`
+        /// role within the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdKillmailsRecent>
+        ///
+        /// # Required Scopes
+        /// - [`KillmailsScopes::read_corporation_killmails`](crate::scope::KillmailsScopes::read_corporation_killmails):
+        ///   `esi-killmails.read_corporation_killmails.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation to retrieve recent killmails for
+        /// - `page`           (`i32`): The page of killmails to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated list of killmail IDs & hashes when sent.
+        auth fn get_corporation_recent_killmails(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64;
+            page: i32
+        ) -> EsiRequest<Vec<KillmailReference>>
+        method = Method::GET;
+        path = "/corporations/{}/killmails/recent";
+        required_scopes = ScopeBuilder::new()
+            .killmails(KillmailsScopes::new().read_corporation_killmails())
+            .build();
+    }
+
+    /// Polls a corporation's recent killmails, yielding the details of each new killmail as a [`Stream`]
+    ///
+    /// The first page of [`Self::get_corporation_recent_killmails`] is requested every `poll_interval`
+    /// with the `If-None-Match` header so unchanged responses are not downloaded again. Killmails which
+    /// were not yet yielded are retrieved one at a time with [`Self::get_killmail`] & yielded from oldest
+    /// to newest. ESI caches recent killmails for 5 minutes, polling more frequently has no benefit.
+    ///
+    /// Killmail IDs provided with `seen` are never yielded, e.g. the IDs already stored by a killboard.
+    /// The feed remembers the most recent 10,000 yielded killmail IDs, or the number of provided IDs
+    /// if greater, forgetting the oldest first. Use [`Self::corporation_killmail_feed_with_store`]
+    /// with a [`FileSeenStore`] to remember yielded killmails across restarts.
+    ///
+    /// Errors are yielded without ending the stream; a killmail which failed to be retrieved is
+    /// retried on the next poll. Drop the stream to stop polling, e.g. to create a new feed once the
+    /// access token expires.
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `Director`
+    /// role within the corporation to access this information.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`KillmailsScopes::read_corporation_killmails`](crate::scope::KillmailsScopes::read_corporation_killmails):
+    ///   `esi-killmails.read_corporation_killmails.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id` (`i64`): The ID of the corporation to poll recent killmails for
+    /// - `poll_interval`  (`Duration`): Time to wait between polls once all new killmails were yielded
+    /// - `seen`           (`impl IntoIterator<Item = i64>`): IDs of killmails which should not be yielded
+    ///
+    /// # Returns
+    /// A stream of new killmails, or an [`Error`] for each request which failed.
+    pub fn corporation_killmail_feed(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+        poll_interval: Duration,
+        seen: impl IntoIterator<Item = i64>,
+    ) -> impl Stream<Item = Result<Killmail, Error>> + 'static {
+        self.corporation_killmail_feed_with_store(
+            access_token,
+            corporation_id,
+            poll_interval,
+            MemorySeenStore::from_ids(seen),
+        )
+    }
+
+    /// Polls a corporation's recent killmails, skipping killmails recorded in the provided [`SeenStore`]
+    ///
+    /// Behaves like [`Self::corporation_killmail_feed`], but killmails are checked against & recorded
+    /// in `store` before being yielded. A persistent store such as [`FileSeenStore`] allows long-running
+    /// ingestors to restart without yielding killmails which were already processed. A killmail which
+    /// could not be recorded in the store is yielded as an error & retried on the next poll.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`KillmailsScopes::read_corporation_killmails`](crate::scope::KillmailsScopes::read_corporation_killmails):
+    ///   `esi-killmails.read_corporation_killmails.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id` (`i64`): The ID of the corporation to poll recent killmails for
+    /// - `poll_interval`  (`Duration`): Time to wait between polls once all new killmails were yielded
+    /// - `store`          (`impl SeenStore`): Storage of the IDs of killmails which should not be yielded
+    ///
+    /// # Returns
+    /// A stream of new killmails, or an [`Error`] for each request which failed.
+    pub fn corporation_killmail_feed_with_store<S: SeenStore + 'static>(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+        poll_interval: Duration,
+        store: S,
+    ) -> impl Stream<Item = Result<Killmail, Error>> + 'static {
+        let feed = KillmailFeed {
+            client: self.client.clone(),
+            access_token: access_token.into(),
+            corporation_id,
+            poll_interval,
+            cache: MemoryResponseCache::new(),
+            seen: store,
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        feed.into_stream()
+    }
+}
+
+/// Storage of the IDs of killmails already yielded by a killmail feed
+///
+/// Implement this trait to store seen killmails in a custom backend, e.g. the database of a
+/// killboard. Two implementations are provided:
+/// - [`MemorySeenStore`]: Remembers killmail IDs for the lifetime of the process
+/// - [`FileSeenStore`]: Remembers killmail IDs in a file, surviving process restarts
+///
+/// Wrap a store in an [`Arc`] to share it between feeds or inspect it while a feed is running.
+///
+/// See [`KillmailsEndpoints::corporation_killmail_feed_with_store`] for usage.
+pub trait SeenStore: Send + Sync {
+    /// Returns `true` if the killmail was recorded as seen
+    ///
+    /// # Arguments
+    /// - `killmail_id` (`i64`): The ID of the killmail
+    fn contains(&self, killmail_id: i64) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Records the killmail as seen
+    ///
+    /// # Arguments
+    /// - `killmail_id` (`i64`): The ID of the killmail
+    fn insert(&self, killmail_id: i64) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+impl<T: SeenStore> SeenStore for Arc<T> {
+    fn contains(&self, killmail_id: i64) -> impl Future<Output = Result<bool, Error>> + Send {
+        (**self).contains(killmail_id)
+    }
+
+    fn insert(&self, killmail_id: i64) -> impl Future<Output = Result<(), Error>> + Send {
+        (**self).insert(killmail_id)
+    }
+}
+
+/// In-memory [`SeenStore`] which forgets the oldest killmail ID once its capacity is reached
+pub struct MemorySeenStore {
+    seen: Mutex<SeenKillmails>,
+}
+
+impl MemorySeenStore {
+    /// Creates a new empty [`MemorySeenStore`] remembering up to 10,000 killmail IDs
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_KILLMAIL_FEED_SEEN_CAPACITY)
+    }
+
+    /// Creates a new empty [`MemorySeenStore`] remembering up to `capacity` killmail IDs
+    ///
+    /// # Arguments
+    /// - `capacity` (`usize`): The number of killmail IDs remembered before the oldest are forgotten
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            seen: Mutex::new(SeenKillmails::with_capacity(capacity)),
+        }
+    }
+
+    /// Creates a [`MemorySeenStore`] containing the provided killmail IDs
+    ///
+    /// The store remembers up to 10,000 killmail IDs, or the number of provided IDs if greater.
+    ///
+    /// # Arguments
+    /// - `ids` (`impl IntoIterator<Item = i64>`): IDs of killmails which were already seen
+    pub fn from_ids(ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            seen: Mutex::new(SeenKillmails::new(ids)),
+        }
+    }
+
+    /// Returns the number of remembered killmail IDs
+    pub fn len(&self) -> usize {
+        self.seen.lock().map(|seen| seen.order.len()).unwrap_or(0)
+    }
+
+    /// Returns `true` if no killmail IDs are remembered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for MemorySeenStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SeenStore for MemorySeenStore {
+    async fn contains(&self, killmail_id: i64) -> Result<bool, Error> {
+        Ok(self
+            .seen
+            .lock()
+            .map(|seen| seen.contains(killmail_id))
+            .unwrap_or(false))
+    }
+
+    async fn insert(&self, killmail_id: i64) -> Result<(), Error> {
+        if let Ok(mut seen) = self.seen.lock() {
+            seen.insert(killmail_id);
+        }
+
+        Ok(())
+    }
+}
+
+/// Filesystem [`SeenStore`] which appends each killmail ID to a file
+///
+/// The file is read on first use & contains one killmail ID per line, lines which are not IDs are
+/// ignored. The parent directory & file are created on the first write if they do not exist. Like
+/// [`MemorySeenStore`], the oldest IDs are forgotten once the capacity is reached; the file is
+/// rewritten with only the remembered IDs once it holds twice the capacity, using a temporary file
+/// which is renamed so an interrupted process never leaves a partially written file behind.
+pub struct FileSeenStore {
+    path: PathBuf,
+    capacity: usize,
+    state: tokio::sync::Mutex<Option<FileSeenState>>,
+}
+
+/// Killmail IDs loaded from the file of a [`FileSeenStore`]
+struct FileSeenState {
+    seen: SeenKillmails,
+    /// Number of lines in the file, including IDs which were since forgotten
+    lines: usize,
+}
+
+impl FileSeenStore {
+    /// Creates a new [`FileSeenStore`] storing killmail IDs in the provided file, remembering up to
+    /// 10,000 killmail IDs
+    ///
+    /// # Arguments
+    /// - `path` (`impl Into<PathBuf>`): The file to store killmail IDs in
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            capacity: DEFAULT_KILLMAIL_FEED_SEEN_CAPACITY,
+            state: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Sets the number of killmail IDs remembered before the oldest are forgotten
+    ///
+    /// # Arguments
+    /// - `capacity` (`usize`): The number of remembered killmail IDs
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Returns the file killmail IDs are stored in
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the killmail IDs stored in the file, returning an empty state if it does not exist
+    async fn load(&self) -> Result<FileSeenState, Error> {
+        let contents = match tokio::fs::read_to_string(&self.path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut state = FileSeenState {
+            seen: SeenKillmails::with_capacity(self.capacity),
+            lines: 0,
+        };
+
+        for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+            state.lines += 1;
+
+            match line.trim().parse() {
+                Ok(killmail_id) => {
+                    state.seen.insert(killmail_id);
+                }
+                Err(_) => log::warn!(
+                    "Ignoring malformed killmail ID {:?} in {}",
+                    line,
+                    self.path.display()
+                ),
+            }
+        }
+
+        Ok(state)
+    }
+
+    /// Rewrites the file with only the remembered killmail IDs, oldest first
+    async fn compact(&self, state: &mut FileSeenState) -> Result<(), Error> {
+        let contents: String = state
+            .seen
+            .order
+            .iter()
+            .map(|killmail_id| format!("{}\n", killmail_id))
+            .collect();
+        let temp_path = self.path.with_extension("tmp");
+
+        tokio::fs::write(&temp_path, contents).await?;
+        tokio::fs::rename(&temp_path, &self.path).await?;
+
+        state.lines = state.seen.order.len();
+
+        Ok(())
+    }
+}
+
+impl SeenStore for FileSeenStore {
+    async fn contains(&self, killmail_id: i64) -> Result<bool, Error> {
+        let mut state = self.state.lock().await;
+
+        if state.is_none() {
+            *state = Some(self.load().await?);
+        }
+
+        Ok(state
+            .as_ref()
+            .is_some_and(|state| state.seen.contains(killmail_id)))
+    }
+
+    async fn insert(&self, killmail_id: i64) -> Result<(), Error> {
+        use tokio::io::AsyncWriteExt;
+
+        let mut guard = self.state.lock().await;
+        let state = match guard.as_mut() {
+            Some(state) => state,
+            None => guard.insert(self.load().await?),
+        };
+
+        if state.seen.contains(killmail_id) {
+            return Ok(());
+        }
+
+        if let Some(directory) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(directory).await?;
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(format!("{}\n", killmail_id).as_bytes())
+            .await?;
+        file.flush().await?;
+
+        state.seen.insert(killmail_id);
+        state.lines += 1;
+
+        if state.lines >= self.capacity.saturating_mul(2).max(1) {
+            self.compact(state).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// State of a stream created by [`KillmailsEndpoints::corporation_killmail_feed_with_store`]
+struct KillmailFeed<S> {
+    client: Client,
+    access_token: AccessToken,
+    corporation_id: i64,
+    poll_interval: Duration,
+    /// Cache of the recent killmails response used to send the `If-None-Match` header
+    cache: MemoryResponseCache,
+    seen: S,
+    /// Killmails found by the last poll which have not been yielded yet, oldest first
+    pending: VecDeque<KillmailReference>,
+    /// Whether the recent killmails were polled at least once
+    polled: bool,
+}
+
+impl<S: SeenStore> KillmailFeed<S> {
+    /// Converts the feed into a stream which never ends
+    fn into_stream(self) -> impl Stream<Item = Result<Killmail, Error>> {
+        futures_util::stream::unfold(self, |mut feed| async move {
+            let item = feed.next().await;
+
+            Some((item, feed))
+        })
+    }
+
+    /// Retrieves the next new killmail, polling recent killmails until one is found
+    async fn next(&mut self) -> Result<Killmail, Error> {
+        loop {
+            if let Some(reference) = self.pending.pop_front() {
+                let killmail = self
+                    .client
+                    .killmails()
+                    .get_killmail(reference.killmail_id, &reference.killmail_hash)
+                    .send()
+                    .await?
+                    .data;
+
+                self.seen.insert(killmail.killmail_id).await?;
+
+                return Ok(killmail);
+            }
+
+            if self.polled {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+            self.polled = true;
+
+            let recent = self
+                .client
+                .killmails()
+                .get_corporation_recent_killmails(&self.access_token, self.corporation_id, 1)
+                .send_with_cache(&self.cache)
+                .await?
+                .data;
+
+            let mut new: Vec<KillmailReference> = Vec::new();
+            for reference in recent {
+                if !self.seen.contains(reference.killmail_id).await? {
+                    new.push(reference);
+                }
+            }
+
+            // Killmail IDs increase over time, yield the oldest new killmail first
+            new.sort_by_key(|reference| reference.killmail_id);
+
+            log::debug!(
+                "Found {} new killmails for corporation {}",
+                new.len(),
+                self.corporation_id
+            );
+
+            self.pending.extend(new);
+        }
+    }
+}
+
+/// Set of killmail IDs which forgets the oldest inserted ID once its capacity is reached
+struct SeenKillmails {
+    ids: HashSet<i64>,
+    order: VecDeque<i64>,
+    capacity: usize,
+}
+
+impl SeenKillmails {
+    /// Creates a set containing the provided IDs with a capacity of at least
+    /// [`DEFAULT_KILLMAIL_FEED_SEEN_CAPACITY`]
+    fn new(ids: impl IntoIterator<Item = i64>) -> Self {
+        let order: VecDeque<i64> = ids.into_iter().collect();
+
+        Self {
+            ids: order.iter().copied().collect(),
+            capacity: order.len().max(DEFAULT_KILLMAIL_FEED_SEEN_CAPACITY),
+            order,
+        }
+    }
+
+    /// Creates an empty set with the provided capacity
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: HashSet::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    fn contains(&self, id: i64) -> bool {
+        self.ids.contains(&id)
+    }
+
+    fn insert(&mut self, id: i64) {
+        if !self.ids.insert(id) {
+            return;
+        }
+
+        self.order.push_back(id);
+
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod seen_killmails_tests {
+    use super::*;
+
+    /// Tests that the oldest inserted killmail ID is forgotten once capacity is reached
+    ///
+    /// # Test Setup
+    /// - Create a set with a capacity of 2
+    ///
+    /// # Assertions
+    /// - Assert the first inserted ID is forgotten after inserting a third ID
+    /// - Assert inserting an existing ID does not evict another ID
+    #[test]
+    fn test_seen_killmails_capacity() {
+        let mut seen = SeenKillmails::new([1, 2]);
+        seen.capacity = 2;
+
+        seen.insert(2);
+        assert!(seen.contains(1));
+
+        seen.insert(3);
+        assert!(!seen.contains(1));
+        assert!(seen.contains(2));
+        assert!(seen.contains(3));
+    }
+
+    /// Tests that killmail IDs persist between file stores using the same file & the file is compacted
+    ///
+    /// # Test Setup
+    /// - Create a file store with a capacity of 2 in a temporary directory & insert 4 IDs
+    ///
+    /// # Assertions
+    /// - Assert a new store for the same file remembers only the 2 most recent IDs
+    /// - Assert the file was compacted to the remembered IDs
+    /// - Assert malformed lines are ignored
+    #[tokio::test]
+    async fn test_file_seen_store_persists() {
+        let directory =
+            std::env::temp_dir().join(format!("eve_esi_seen_store_test_{}", std::process::id()));
+        let path = directory.join("seen.txt");
+
+        let store = FileSeenStore::new(&path).with_capacity(2);
+        for killmail_id in [1, 2, 3, 4] {
+            store.insert(killmail_id).await.unwrap();
+        }
+        store.insert(4).await.unwrap();
+
+        // Assert the file was compacted to the remembered IDs
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "3\n4\n");
+
+        // Assert malformed lines are ignored
+        std::fs::write(&path, format!("{}not an id\n", contents)).unwrap();
+
+        // Assert a new store for the same file remembers only the 2 most recent IDs
+        let store = FileSeenStore::new(&path).with_capacity(2);
+        let remembered = [
+            store.contains(2).await.unwrap(),
+            store.contains(3).await.unwrap(),
+            store.contains(4).await.unwrap(),
+        ];
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(remembered, [false, true, true]);
+    }
 }
@@ -3,7 +3,24 @@
 //! This module provides the [`LocationEndpoints`] struct and associated methods for accessing
 //! location-related ESI endpoints.
 
-use crate::Client;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::time::Duration;
+
+#[cfg(feature = "universe")]
+use futures_util::future::try_join3;
+use futures_util::Stream;
+use reqwest::Method;
+use tokio::time::Instant;
+
+use crate::esi::{AccessToken, EsiRequest};
+#[cfg(feature = "universe")]
+use crate::model::location::CharacterPresence;
+use crate::model::location::{
+    CharacterLocation, CharacterOnlineStatus, CharacterShip, OnlineStatusEvent,
+};
+use crate::scope::LocationScopes;
+use crate::{Client, Error, ScopeBuilder};
 
 /// Provides methods for accessing location-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +37,310 @@ impl<'a> LocationEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the solar system & the station or structure the provided character ID is in
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdLocation>
+        ///
+        /// # Required Scopes
+        /// - [`LocationScopes::read_location`](crate::scope::LocationScopes::read_location):
+        ///   `esi-location.read_location.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to retrieve the location for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the location of the character when sent.
+        auth fn get_character_location(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<CharacterLocation>
+        method = Method::GET;
+        path = "/characters/{}/location";
+        required_scopes = ScopeBuilder::new()
+            .location(LocationScopes::new().read_location())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the online status, last login & last logout of the provided character ID
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdOnline>
+        ///
+        /// # Required Scopes
+        /// - [`LocationScopes::read_online`](crate::scope::LocationScopes::read_online):
+        ///   `esi-location.read_online.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to retrieve the online status for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the online status of the character when sent.
+        auth fn get_character_online(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<CharacterOnlineStatus>
+        method = Method::GET;
+        path = "/characters/{}/online";
+        required_scopes = ScopeBuilder::new()
+            .location(LocationScopes::new().read_online())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the ship the provided character ID is currently flying
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdShip>
+        ///
+        /// # Required Scopes
+        /// - [`LocationScopes::read_ship_type`](crate::scope::LocationScopes::read_ship_type):
+        ///   `esi-location.read_ship_type.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to retrieve the current ship for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the character's current ship when sent.
+        auth fn get_character_ship(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<CharacterShip>
+        method = Method::GET;
+        path = "/characters/{}/ship";
+        required_scopes = ScopeBuilder::new()
+            .location(LocationScopes::new().read_ship_type())
+            .build();
+    }
+
+    /// Retrieves the location, ship & online status of the provided character ID with resolved names
+    ///
+    /// Sends [`Self::get_character_location`], [`Self::get_character_ship`] &
+    /// [`Self::get_character_online`] concurrently, then resolves the names of the solar system,
+    /// station & ship type with
+    /// [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names).
+    /// Names are held by the client's [`NameCache`](crate::esi::NameCache), so polling the same
+    /// characters usually only makes the 3 character requests.
+    ///
+    /// Structure names are not resolved as they require the `esi-universe.read_structures.v1` scope,
+    /// resolve them with
+    /// [`UniverseEndpoints::resolve_structure_names`](crate::endpoints::universe::UniverseEndpoints::resolve_structure_names)
+    /// if required.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`LocationScopes::read_location`](crate::scope::LocationScopes::read_location):
+    ///   `esi-location.read_location.v1`
+    /// - [`LocationScopes::read_ship_type`](crate::scope::LocationScopes::read_ship_type):
+    ///   `esi-location.read_ship_type.v1`
+    /// - [`LocationScopes::read_online`](crate::scope::LocationScopes::read_online):
+    ///   `esi-location.read_online.v1`
+    ///
+    /// # Arguments
+    /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id` (`i64`): The ID of the character to retrieve the presence of
+    ///
+    /// # Returns
+    /// The character's location, ship & online status, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "universe")]
+    pub async fn get_character_presence(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+    ) -> Result<CharacterPresence, Error> {
+        let access_token = access_token.into();
+
+        let (location, ship, online) = try_join3(
+            self.get_character_location(&access_token, character_id)
+                .send(),
+            self.get_character_ship(&access_token, character_id).send(),
+            self.get_character_online(&access_token, character_id)
+                .send(),
+        )
+        .await?;
+        let (location, ship, online) = (location.data, ship.data, online.data);
+
+        let mut ids = vec![location.solar_system_id, ship.ship_type_id];
+        ids.extend(location.station_id);
+
+        let mut names = self.client.universe().resolve_names(ids).await?;
+
+        Ok(CharacterPresence {
+            character_id,
+            solar_system_name: names.remove(&location.solar_system_id).unwrap_or_default(),
+            station_name: location
+                .station_id
+                .and_then(|station_id| names.remove(&station_id)),
+            ship_type_name: names.remove(&ship.ship_type_id).unwrap_or_default(),
+            location,
+            ship,
+            online,
+        })
+    }
+
+    /// Polls the online status of multiple characters, yielding an event whenever one logs in or out
+    ///
+    /// Each character is polled with [`Self::get_character_online`] using its own access token,
+    /// every `online_interval` while the character is online & every `offline_interval` while it is
+    /// offline. Requests are sent one at a time in the order they are due, so polling many
+    /// characters spreads requests out rather than sending them in bursts. ESI caches the online
+    /// status for 60 seconds, polling more frequently has no benefit.
+    ///
+    /// The first poll of each character yields an event of its current status, later polls only
+    /// yield an event when the status changed.
+    ///
+    /// Errors are yielded without ending the stream; a character which failed to be polled is
+    /// polled again after its current interval. Drop the stream to stop polling, e.g. to create a
+    /// new poller once the access tokens expire. The stream ends immediately if no characters are
+    /// provided.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`LocationScopes::read_online`](crate::scope::LocationScopes::read_online):
+    ///   `esi-location.read_online.v1`
+    ///
+    /// # Arguments
+    /// - `characters`       (`impl IntoIterator<Item = (i64, impl Into<AccessToken>)>`): IDs of
+    ///   the characters to poll with an access token of each character
+    /// - `online_interval`  (`Duration`): Time between polls of a character which is online
+    /// - `offline_interval` (`Duration`): Time between polls of a character which is offline or
+    ///   whose status is not known yet
+    ///
+    /// # Returns
+    /// A stream of online status changes, or an [`Error`] for each request which failed.
+    pub fn online_status_feed<T: Into<AccessToken>>(
+        &self,
+        characters: impl IntoIterator<Item = (i64, T)>,
+        online_interval: Duration,
+        offline_interval: Duration,
+    ) -> impl Stream<Item = Result<OnlineStatusEvent, Error>> + 'static {
+        let now = Instant::now();
+        let mut poller = OnlineStatusPoller {
+            client: self.client.clone(),
+            characters: HashMap::new(),
+            schedule: BinaryHeap::new(),
+            online_interval,
+            offline_interval,
+        };
+
+        for (character_id, access_token) in characters {
+            let character = PolledCharacter {
+                access_token: access_token.into(),
+                online: None,
+            };
+
+            if poller.characters.insert(character_id, character).is_none() {
+                poller.schedule.push(Reverse((now, character_id)));
+            }
+        }
+
+        poller.into_stream()
+    }
+}
+
+/// State of a stream created by [`LocationEndpoints::online_status_feed`]
+struct OnlineStatusPoller {
+    client: Client,
+    characters: HashMap<i64, PolledCharacter>,
+    /// Time of the next poll of each character, earliest first
+    schedule: BinaryHeap<Reverse<(Instant, i64)>>,
+    online_interval: Duration,
+    offline_interval: Duration,
+}
+
+/// A character polled by an [`OnlineStatusPoller`]
+struct PolledCharacter {
+    access_token: AccessToken,
+    /// The online status of the last successful poll, `None` if not polled successfully yet
+    online: Option<bool>,
+}
+
+impl OnlineStatusPoller {
+    /// Converts the poller into a stream which only ends if no characters are polled
+    fn into_stream(self) -> impl Stream<Item = Result<OnlineStatusEvent, Error>> {
+        futures_util::stream::unfold(self, |mut poller| async move {
+            let item = poller.next().await?;
+
+            Some((item, poller))
+        })
+    }
+
+    /// Polls characters as they become due until one changed status
+    async fn next(&mut self) -> Option<Result<OnlineStatusEvent, Error>> {
+        loop {
+            let Reverse((due, character_id)) = self.schedule.pop()?;
+            tokio::time::sleep_until(due).await;
+
+            let character = self.characters.get_mut(&character_id)?;
+            let result = self
+                .client
+                .location()
+                .get_character_online(&character.access_token, character_id)
+                .send()
+                .await;
+
+            let interval = match character.online {
+                Some(true) => self.online_interval,
+                _ => self.offline_interval,
+            };
+
+            let status = match result {
+                Ok(response) => response.data,
+                Err(error) => {
+                    self.schedule
+                        .push(Reverse((Instant::now() + interval, character_id)));
+
+                    return Some(Err(error));
+                }
+            };
+
+            let interval = if status.online {
+                self.online_interval
+            } else {
+                self.offline_interval
+            };
+            self.schedule
+                .push(Reverse((Instant::now() + interval, character_id)));
+
+            if character.online.replace(status.online) == Some(status.online) {
+                continue;
+            }
+
+            log::debug!(
+                "Character {} is now {}",
+                character_id,
+                if status.online { "online" } else { "offline" }
+            );
+
+            let event = if status.online {
+                OnlineStatusEvent::Online {
+                    character_id,
+                    status,
+                }
+            } else {
+                OnlineStatusEvent::Offline {
+                    character_id,
+                    status,
+                }
+            };
+
+            return Some(Ok(event));
+        }
+    }
 }
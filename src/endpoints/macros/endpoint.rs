@@ -1,38 +1,13 @@
-/// Internal macro for constructing an ESI endpoint request with path and query parameters.
+/// Internal macro for constructing an ESI endpoint path from its path parameters.
 ///
-/// This macro handles path construction for endpoints with various parameter combinations:
-/// - Path parameters only
-/// - Path and query parameters
-///
-/// Query parameters are automatically serialized to JSON and URL-encoded.
+/// Query parameters are appended to the built request with
+/// [`EsiRequest::with_query`](crate::esi::EsiRequest::with_query).
+#[doc(hidden)]
+#[macro_export]
 macro_rules! build_endpoint_path {
-    // No query params
     ($fmt:expr, ($($path:ident),* $(,)?)) => {{
         format!($fmt, $($path),* )
     }};
-
-    // One or more query params
-    ($fmt:expr, ($($path:ident),* $(,)?), ($($query:ident),+ $(,)?)) => {{
-        let mut path = format!($fmt, $($path),* );
-
-        let mut ser = url::form_urlencoded::Serializer::new(String::new());
-
-        $(
-            // Serialize to JSON and add to query string
-            // If serialization fails, we use a placeholder value
-            // Real errors will be caught when the request is sent
-            let val = serde_json::to_string(&$query).unwrap_or_else(|_| String::from("null"));
-            ser.append_pair(stringify!($query), &val);
-        )*
-
-        let query_string = ser.finish();
-        if !query_string.is_empty() {
-            path.push('?');
-            path.push_str(&query_string);
-        }
-
-        path
-    }};
 }
 
 /// Internal macro for building the `EsiRequest<T>` with common configuration.
@@ -42,8 +17,10 @@ macro_rules! build_endpoint_path {
 /// - Authenticated endpoints: path, HTTP method, access token, and required scopes
 /// - Endpoints with body: Serializes the body to JSON and includes it in the request
 ///
-/// The macro automatically handles body serialization, using a null value as a fallback
-/// if serialization fails (actual errors are handled when `.send()` is called).
+/// The body is serialized with [`EsiRequest::with_body`](crate::esi::EsiRequest::with_body), a
+/// serialization error is returned when the request is sent.
+#[doc(hidden)]
+#[macro_export]
 macro_rules! build_esi_request_internal {
     // Public endpoint with body
     (
@@ -53,13 +30,11 @@ macro_rules! build_esi_request_internal {
         return_type = $return_type:ty,
         body = $body_name:ident
     ) => {{
-        // Serialize body - if it fails, store null and let send() handle the error
-        let body_value = serde_json::to_value(&$body_name).unwrap_or(serde_json::Value::Null);
         $client
             .esi()
             .new_request::<$return_type>($path)
             .with_method($method)
-            .with_body_json(body_value)
+            .with_body(&$body_name)
     }};
 
     // Public endpoint without body
@@ -85,15 +60,13 @@ macro_rules! build_esi_request_internal {
         access_token = $access_token:ident,
         required_scopes = $required_scopes:expr
     ) => {{
-        // Serialize body - if it fails, store null and let send() handle the error
-        let body_value = serde_json::to_value(&$body_name).unwrap_or(serde_json::Value::Null);
         $client
             .esi()
             .new_request::<$return_type>($path)
             .with_method($method)
             .with_access_token($access_token)
             .with_required_scopes($required_scopes)
-            .with_body_json(body_value)
+            .with_body(&$body_name)
     }};
 
     // Authenticated endpoint without body
@@ -114,6 +87,32 @@ macro_rules! build_esi_request_internal {
     }};
 }
 
+/// Internal macro for recording the use of a deprecated ESI route with the client's deprecation registry.
+///
+/// Expands to nothing for endpoints without deprecation metadata.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! record_deprecated_route {
+    (
+        client = $client:expr,
+        function = $fn_name:ident,
+        method = $method:expr,
+        path = $path:expr
+        $(, deprecated_since = $since:expr $(, removal_date = $removal:expr)?)?
+    ) => {
+        $(
+            $crate::__private::record_deprecated_route(
+                &$client,
+                stringify!($fn_name),
+                $method.as_str(),
+                $path,
+                $since,
+                None $(.or(Some($removal)))?,
+            );
+        )?
+    };
+}
+
 /// Macro for defining ESI endpoints that return `EsiRequest<T>` builders.
 ///
 /// This macro generates endpoint methods that return `EsiRequest<T>` structs, allowing users
@@ -124,9 +123,16 @@ macro_rules! build_esi_request_internal {
 ///
 /// - **Path parameters**: Embedded directly in the path template with `{}`
 /// - **Query parameters**: Automatically serialized and appended to the path
-/// - **Body parameters**: Serialized to JSON for POST/PUT/DELETE requests
+/// - **Body parameters**: Serialized to JSON for POST/PUT/DELETE requests, either a simple type such as
+///   `Vec<i64>` or a typed request struct from [`crate::model::requests`] for object bodies
 /// - **Authentication**: Automatic access token and scope validation for authenticated endpoints
-/// - **Flexible HTTP methods**: Supports GET, POST, PUT, DELETE via `reqwest::Method`
+/// - **Flexible HTTP methods**: Supports GET, POST, PUT, DELETE via [`Method`](crate::Method)
+/// - **Idempotency**: Write endpoints declare whether they are safe to retry, see
+///   [`Idempotency`](crate::esi::Idempotency)
+/// - **Deprecation**: Routes deprecated by CCP log a one-time warning & are recorded for
+///   [`Client::deprecated_routes`](crate::Client::deprecated_routes)
+/// - **Route versions**: Endpoints may pin the version of their route, which applications can
+///   override per route with [`ConfigBuilder::esi_route_version`](crate::ConfigBuilder::esi_route_version)
 ///
 /// # Syntax Variants
 ///
@@ -146,7 +152,7 @@ macro_rules! build_esi_request_internal {
 /// ```ignore
 /// define_esi_endpoint! {
 ///     auth fn function_name(
-///         access_token: &str,
+///         access_token: impl Into<AccessToken>,
 ///         path_param: Type;
 ///         query_param: Type
 ///     ) -> EsiRequest<ReturnType>
@@ -160,7 +166,7 @@ macro_rules! build_esi_request_internal {
 /// ```ignore
 /// define_esi_endpoint! {
 ///     auth fn function_name(
-///         access_token: &str,
+///         access_token: impl Into<AccessToken>,
 ///         path_param: Type;
 ///         query_param: Type
 ///     ) -> EsiRequest<ReturnType>
@@ -171,7 +177,130 @@ macro_rules! build_esi_request_internal {
 /// }
 /// ```
 ///
-/// For detailed examples and usage patterns, see the [module-level documentation](super)
+/// Object bodies use a typed request struct implementing `Serialize`, which becomes the final
+/// argument of the generated method:
+/// ```ignore
+/// define_esi_endpoint! {
+///     auth fn send_mail(
+///         access_token: impl Into<AccessToken>,
+///         character_id: i64
+///     ) -> EsiRequest<i64>
+///     method = Method::POST;
+///     path = "/characters/{}/mail";
+///     required_scopes = ScopeBuilder::new().mail(MailScopes::new().send_mail()).build();
+///     body = mail: NewMail;
+///     idempotency = Idempotency::Unsafe;
+/// }
+/// ```
+///
+/// ## Write Endpoint
+///
+/// Endpoints which write data declare whether repeating the request has the same effect as sending
+/// it once with `idempotency`, following `body` if present. Only [`Idempotency::Safe`](crate::esi::Idempotency::Safe)
+/// requests are retried after a network error or 5xx response. Endpoints without the clause are
+/// classed by their HTTP method, treating `POST` as unsafe, so read-only `POST` routes such as
+/// bulk name lookups declare themselves safe:
+/// ```ignore
+/// define_esi_endpoint! {
+///     pub fn get_names(
+///     ) -> EsiRequest<Vec<Name>>
+///     method = Method::POST;
+///     path = "/universe/names";
+///     body = ids: Vec<i64>;
+///     idempotency = Idempotency::Safe;
+/// }
+/// ```
+///
+/// ## Versioned Endpoint
+///
+/// Routes are requested without a version, following ESI's default version of the route, unless
+/// the endpoint pins one with `version` following `path`. The version prefixes the path, so the
+/// definition below requests `/v5/characters/{}/assets`. A version configured for the path template
+/// with [`ConfigBuilder::esi_route_version`](crate::ConfigBuilder::esi_route_version) takes
+/// precedence over the version of the endpoint.
+/// ```ignore
+/// define_esi_endpoint! {
+///     auth fn get_character_assets(
+///         access_token: impl Into<AccessToken>,
+///         character_id: i64;
+///         page: i32
+///     ) -> EsiRequest<Vec<Asset>>
+///     method = Method::GET;
+///     path = "/characters/{}/assets";
+///     version = "v5";
+///     required_scopes = ScopeBuilder::new().assets(AssetsScopes::new().read_assets()).build();
+/// }
+/// ```
+///
+/// ## Deprecated Endpoint
+///
+/// Any variant may end with deprecation metadata, the removal date is optional. Dates use
+/// the `YYYY-MM-DD` format.
+/// ```ignore
+/// define_esi_endpoint! {
+///     pub fn function_name(
+///         path_param: Type
+///     ) -> EsiRequest<ReturnType>
+///     method = Method::GET;
+///     path = "/path/{}";
+///     deprecated_since = "2025-01-01";
+///     removal_date = "2025-06-01";
+/// }
+/// ```
+///
+/// # Usage Outside of the Crate
+///
+/// The macro is exported so downstream crates can define endpoints the crate does not provide in
+/// the same style as the built-in endpoints. It must be used within an `impl` block of a struct with
+/// a `client` field holding a [`Client`](crate::Client) or `&Client`, & the types named in the
+/// definition such as [`EsiRequest`](crate::EsiRequest), [`Method`](crate::Method) & [`ScopeBuilder`](crate::ScopeBuilder) must
+/// be in scope.
+///
+/// ```no_run
+/// use eve_esi::{define_esi_endpoint, Client, EsiRequest, Method, ScopeBuilder};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// pub struct Application {
+///     pub application_id: i64,
+///     pub character_id: i64,
+/// }
+///
+/// pub struct RecruitmentEndpoints<'a> {
+///     client: &'a Client,
+/// }
+///
+/// impl RecruitmentEndpoints<'_> {
+///     define_esi_endpoint! {
+///         /// Retrieves the applications to join a corporation
+///         auth fn get_applications(
+///             access_token: impl Into<AccessToken>,
+///             corporation_id: i64;
+///             page: Option<i32>
+///         ) -> EsiRequest<Vec<Application>>
+///         method = Method::GET;
+///         path = "/corporations/{}/applications";
+///         required_scopes = ScopeBuilder::new()
+///             .custom("esi-corporations.read_applications.v1")
+///             .build();
+///     }
+/// }
+///
+/// # async fn example(esi_client: Client, access_token: &str) -> Result<(), eve_esi::Error> {
+/// let recruitment = RecruitmentEndpoints { client: &esi_client };
+/// let applications = recruitment
+///     .get_applications(access_token, 98785281, None)
+///     .send()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// **Stability:** the macro's syntax follows the needs of the crate's own endpoints & may change in
+/// a minor release before `1.0`. Routes which should keep compiling across releases can instead
+/// be built with the [`EsiRequest`](crate::EsiRequest) builder methods the macro expands to, see
+/// [custom routes](crate::esi#custom-routes).
+#[macro_export]
 macro_rules! define_esi_endpoint {
     // Public endpoint with body but no path parameters (e.g., character_affiliation)
     (
@@ -181,17 +310,37 @@ macro_rules! define_esi_endpoint {
         ) -> EsiRequest<$return_type:ty>
         method = $method:expr;
         path = $path:expr;
+        $(version = $version:expr;)?
         body = $body_name:ident: $body_type:ty;
+        $(idempotency = $idempotency:expr;)?
+        $(deprecated_since = $since:expr; $(removal_date = $removal:expr;)?)?
     ) => {
         $(#[$attr])*
         pub fn $fn_name(&self, $body_name: $body_type) -> EsiRequest<$return_type> {
-            build_esi_request_internal!(
+            $crate::record_deprecated_route!(
+                client = self.client,
+                function = $fn_name,
+                method = $method,
+                path = $path
+                $(, deprecated_since = $since $(, removal_date = $removal)?)?
+            );
+
+            let path = $crate::__private::versioned_path(
+                &self.client,
+                $path,
+                None $(.or(Some($version)))?,
+                $path.to_string(),
+            );
+
+            let request = $crate::build_esi_request_internal!(
                 client = self.client,
-                path = $path,
+                path = path,
                 method = $method,
                 return_type = $return_type,
                 body = $body_name
-            )
+            );
+
+            request $(.with_idempotency($idempotency))?
         }
     };
 
@@ -205,19 +354,41 @@ macro_rules! define_esi_endpoint {
         ) -> EsiRequest<$return_type:ty>
         method = $method:expr;
         path = $path:expr;
+        $(version = $version:expr;)?
         $(body = $body_name:ident: $body_type:ty;)?
+        $(idempotency = $idempotency:expr;)?
+        $(deprecated_since = $since:expr; $(removal_date = $removal:expr;)?)?
     ) => {
         $(#[$attr])*
         pub fn $fn_name(&self, $($path_name: $path_ty),* $(, $($query_name: $query_ty),* )? $( , $body_name: $body_type )? ) -> EsiRequest<$return_type> {
-            let path = build_endpoint_path!($path, ($($path_name),*) $(, ($($query_name),*) )? );
+            $crate::record_deprecated_route!(
+                client = self.client,
+                function = $fn_name,
+                method = $method,
+                path = $path
+                $(, deprecated_since = $since $(, removal_date = $removal)?)?
+            );
 
-            build_esi_request_internal!(
+            let path = $crate::build_endpoint_path!($path, ($($path_name),*));
+            let path = $crate::__private::versioned_path(
+                &self.client,
+                $path,
+                None $(.or(Some($version)))?,
+                path,
+            );
+
+            let request = $crate::build_esi_request_internal!(
                 client = self.client,
                 path = path,
                 method = $method,
                 return_type = $return_type
                 $(, body = $body_name)?
-            )
+            );
+
+            // Optional parameters which are `None` are omitted from the query string
+            request
+                $($(.with_query(stringify!($query_name), &$query_name))*)?
+                $(.with_idempotency($idempotency))?
         }
     };
 
@@ -226,20 +397,37 @@ macro_rules! define_esi_endpoint {
         $(#[$attr:meta])*
         auth fn $fn_name:ident(
             $(&self,)?
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             $($path_name:ident: $path_ty:ty),* $(,)?
             $(; $($query_name:ident: $query_ty:ty),* $(,)?)?
         ) -> EsiRequest<$return_type:ty>
         method = $method:expr;
         path = $path:expr;
+        $(version = $version:expr;)?
         required_scopes = $required_scopes:expr;
         $(body = $body_name:ident: $body_type:ty;)?
+        $(idempotency = $idempotency:expr;)?
+        $(deprecated_since = $since:expr; $(removal_date = $removal:expr;)?)?
     ) => {
         $(#[$attr])*
-        pub fn $fn_name(&self, access_token: &str, $($path_name: $path_ty),* $(, $($query_name: $query_ty),* )? $( , $body_name: $body_type )? ) -> EsiRequest<$return_type> {
-            let path = build_endpoint_path!($path, ($($path_name),*) $(, ($($query_name),*) )? );
+        pub fn $fn_name(&self, access_token: impl Into<$crate::esi::AccessToken>, $($path_name: $path_ty),* $(, $($query_name: $query_ty),* )? $( , $body_name: $body_type )? ) -> EsiRequest<$return_type> {
+            $crate::record_deprecated_route!(
+                client = self.client,
+                function = $fn_name,
+                method = $method,
+                path = $path
+                $(, deprecated_since = $since $(, removal_date = $removal)?)?
+            );
+
+            let path = $crate::build_endpoint_path!($path, ($($path_name),*));
+            let path = $crate::__private::versioned_path(
+                &self.client,
+                $path,
+                None $(.or(Some($version)))?,
+                path,
+            );
 
-            build_esi_request_internal!(
+            let request = $crate::build_esi_request_internal!(
                 client = self.client,
                 path = path,
                 method = $method,
@@ -247,7 +435,162 @@ macro_rules! define_esi_endpoint {
                 $(, body = $body_name)?
                 , access_token = access_token
                 , required_scopes = $required_scopes
-            )
+            );
+
+            // Optional parameters which are `None` are omitted from the query string
+            request
+                $($(.with_query(stringify!($query_name), &$query_name))*)?
+                $(.with_idempotency($idempotency))?
         }
     };
 }
+
+#[cfg(test)]
+mod define_esi_endpoint_tests {
+    use reqwest::Method;
+
+    use crate::esi::EsiRequest;
+    use crate::Client;
+
+    /// Endpoints used to test deprecation metadata of the [`define_esi_endpoint`] macro
+    struct TestEndpoints<'a> {
+        client: &'a Client,
+    }
+
+    impl TestEndpoints<'_> {
+        define_esi_endpoint! {
+            /// Deprecated route with an announced removal date
+            pub fn get_opportunities_group(
+                group_id: i64
+            ) -> EsiRequest<serde_json::Value>
+            method = Method::GET;
+            path = "/opportunities/groups/{}";
+            deprecated_since = "2024-01-01";
+            removal_date = "2024-06-01";
+        }
+
+        define_esi_endpoint! {
+            /// Deprecated route without an announced removal date
+            auth fn get_agents_research(
+                access_token: impl Into<AccessToken>,
+                character_id: i64
+            ) -> EsiRequest<serde_json::Value>
+            method = Method::GET;
+            path = "/characters/{}/agents_research";
+            required_scopes = Vec::new();
+            deprecated_since = "2025-01-01";
+        }
+
+        define_esi_endpoint! {
+            /// Route which is not deprecated
+            pub fn get_status(
+            ) -> EsiRequest<serde_json::Value>
+            method = Method::GET;
+            path = "/status";
+        }
+
+        define_esi_endpoint! {
+            /// Route pinned to a version
+            pub fn get_alliance(
+                alliance_id: i64
+            ) -> EsiRequest<serde_json::Value>
+            method = Method::GET;
+            path = "/alliances/{}";
+            version = "v4";
+        }
+
+        define_esi_endpoint! {
+            /// Authenticated route pinned to a version
+            auth fn get_character_assets(
+                access_token: impl Into<AccessToken>,
+                character_id: i64;
+                page: Option<i32>
+            ) -> EsiRequest<serde_json::Value>
+            method = Method::GET;
+            path = "/characters/{}/assets";
+            version = "v5";
+            required_scopes = Vec::new();
+        }
+    }
+
+    /// Tests the version of a route prefixes its path, preferring the version pinned on the client
+    ///
+    /// # Test Setup
+    /// - Create a client pinning `/characters/{}/assets` to `v4` & the unversioned `/status` to `v2`
+    ///
+    /// # Assertions
+    /// - Assert the version declared by the endpoint is used when not pinned
+    /// - Assert the version pinned on the client overrides the endpoint's version
+    /// - Assert unversioned routes can be pinned to a version
+    #[test]
+    fn test_versioned_endpoint() {
+        let config = crate::Config::builder()
+            .esi_url("https://esi.evetech.net")
+            .esi_route_version("/characters/{}/assets", "v4")
+            .esi_route_version("/status", "v2")
+            .build()
+            .unwrap();
+        let client = Client::builder()
+            .user_agent("MyApp/1.0 (contact@example.com)")
+            .config(config)
+            .build()
+            .unwrap();
+        let endpoints = TestEndpoints { client: &client };
+
+        // Assert the version declared by the endpoint is used when not pinned
+        assert_eq!(
+            endpoints.get_alliance(99013534).endpoint(),
+            "https://esi.evetech.net/v4/alliances/99013534"
+        );
+
+        // Assert the version pinned on the client overrides the endpoint's version
+        assert_eq!(
+            endpoints
+                .get_character_assets("token", 2114794365, Some(2))
+                .endpoint(),
+            "https://esi.evetech.net/v4/characters/2114794365/assets?page=2"
+        );
+
+        // Assert unversioned routes can be pinned to a version
+        assert_eq!(
+            endpoints.get_status().endpoint(),
+            "https://esi.evetech.net/v2/status"
+        );
+    }
+
+    /// Tests that endpoints with deprecation metadata are recorded in the client's report
+    ///
+    /// # Test Setup
+    /// - Create a basic client
+    /// - Build requests for 2 deprecated endpoints, one of them twice, & 1 non-deprecated endpoint
+    ///
+    /// # Assertions
+    /// - Assert only the deprecated routes are reported, sorted by path
+    /// - Assert call counts & deprecation metadata match the endpoint definitions
+    #[test]
+    fn test_deprecated_endpoint_recorded() {
+        let client = Client::new("MyApp/1.0 (contact@example.com)").unwrap();
+        let endpoints = TestEndpoints { client: &client };
+
+        let _ = endpoints.get_opportunities_group(100);
+        let _ = endpoints.get_opportunities_group(101);
+        let _ = endpoints.get_agents_research("token", 2114794365);
+        let _ = endpoints.get_status();
+
+        let report = client.deprecated_routes();
+
+        assert_eq!(report.len(), 2);
+
+        assert_eq!(report[0].function, "get_agents_research");
+        assert_eq!(report[0].path, "/characters/{}/agents_research");
+        assert_eq!(report[0].method, "GET");
+        assert_eq!(report[0].deprecated_since, "2025-01-01");
+        assert_eq!(report[0].removal_date, None);
+        assert_eq!(report[0].calls, 1);
+
+        assert_eq!(report[1].function, "get_opportunities_group");
+        assert_eq!(report[1].path, "/opportunities/groups/{}");
+        assert_eq!(report[1].removal_date.as_deref(), Some("2024-06-01"));
+        assert_eq!(report[1].calls, 2);
+    }
+}
@@ -10,10 +10,10 @@
 //!
 //! ## Endpoint Variants
 //!
-//! | Variant  | Required Arguments                      |
-//! | -------- | --------------------------------------- |
-//! | `pub fn` | None (public endpoints)                 |
-//! | `auth fn`| `access_token: &str` (authenticated)    |
+//! | Variant  | Required Arguments                                     |
+//! | -------- | ------------------------------------------------------ |
+//! | `pub fn` | None (public endpoints)                                |
+//! | `auth fn`| `access_token: impl Into<AccessToken>` (authenticated) |
 //!
 //! ## HTTP Methods
 //!
@@ -28,6 +28,9 @@
 //! Use existing endpoints defined with `define_esi_endpoint!` as a guideline. The macro must be used
 //! within an `impl` block of a struct that contains a `client` field of type `&'a Client` (the [`crate::Client`]).
 //!
+//! The macro is exported as [`eve_esi::define_esi_endpoint!`](crate::define_esi_endpoint) for
+//! downstream crates, see its documentation for usage outside of the crate & stability caveats.
+//!
 //! ### URL Path & Query Parameters
 //!
 //! The macro is flexible with URL parameters:
@@ -42,6 +45,9 @@
 //! - `url = "...";` - The URL template with `{}` for path parameters
 //! - `required_scopes = ...;` - For authenticated endpoints only
 //!
+//! Endpoints may pin the route version with `version = "v2";` following the URL, see
+//! [`define_esi_endpoint!`](crate::define_esi_endpoint).
+//!
 //! ### Example: Public Endpoint
 //!
 //! ```ignore
@@ -68,14 +74,14 @@
 //!     /// Fetches a character's contacts
 //!     ///
 //!     /// # Arguments
-//!     /// - `access_token` (`&str`): Access token for authentication
+//!     /// - `access_token` (`impl Into<AccessToken>`): Access token for authentication
 //!     /// - `character_id` (`i64`): The character's ID
 //!     /// - `page` (`i32`): Page number for pagination
 //!     ///
 //!     /// # Returns
 //!     /// An ESI request builder that returns a list of contacts when sent.
 //!     auth fn get_contacts(
-//!         access_token: &str,
+//!         access_token: impl Into<AccessToken>,
 //!         character_id: i64;
 //!         page: i32
 //!     ) -> EsiRequest<Vec<Contact>>
@@ -94,7 +100,7 @@
 //!     /// Adds contacts for a character
 //!     ///
 //!     /// # Arguments
-//!     /// - `access_token` (`&str`): Access token for authentication
+//!     /// - `access_token` (`impl Into<AccessToken>`): Access token for authentication
 //!     /// - `character_id` (`i64`): The character's ID
 //!     /// - `contact_ids` (`Vec<i64>`): List of contact IDs to add
 //!     /// - `standing` (`f64`): Standing value for the contacts
@@ -102,7 +108,7 @@
 //!     /// # Returns
 //!     /// An ESI request builder that returns a list of added contact IDs when sent.
 //!     auth fn add_contacts(
-//!         access_token: &str,
+//!         access_token: impl Into<AccessToken>,
 //!         character_id: i64;
 //!         standing: f64
 //!     ) -> EsiRequest<Vec<i64>>
@@ -122,3 +128,52 @@
 
 #[macro_use]
 mod endpoint;
+
+/// Functions called by the expansion of [`define_esi_endpoint!`](crate::define_esi_endpoint),
+/// re-exported as `eve_esi::__private` for use by downstream crates & not part of the public API
+pub mod support {
+    use crate::Client;
+
+    /// Prefixes the path with the version pinned for the route on the client, otherwise the
+    /// version declared by the endpoint
+    pub fn versioned_path(
+        client: &Client,
+        route: &str,
+        version: Option<&str>,
+        path: String,
+    ) -> String {
+        let version = client
+            .inner
+            .esi_route_versions
+            .get(route)
+            .map(String::as_str)
+            .or(version);
+
+        match version {
+            Some(version) => format!(
+                "/{}/{}",
+                version.trim_matches('/'),
+                path.trim_start_matches('/')
+            ),
+            None => path,
+        }
+    }
+
+    /// Records the use of a deprecated route with the client's deprecation registry
+    pub fn record_deprecated_route(
+        client: &Client,
+        function: &str,
+        method: &str,
+        path: &str,
+        deprecated_since: &str,
+        removal_date: Option<&str>,
+    ) {
+        client.inner.deprecated_routes.record(
+            function,
+            method,
+            path,
+            deprecated_since,
+            removal_date,
+        );
+    }
+}
@@ -3,7 +3,11 @@
 //! This module provides the [`MailEndpoints`] struct and associated methods for accessing
 //! mail-related ESI endpoints.
 
-use crate::Client;
+use crate::esi::{EsiRequest, Idempotency};
+use crate::model::requests::NewMail;
+use crate::scope::MailScopes;
+use crate::{Client, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing mail-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +24,36 @@ impl<'a> MailEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Sends a new EVE mail on behalf of the provided character ID
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdMail>
+        ///
+        /// # Required Scopes
+        /// - [`MailScopes::send_mail`](crate::scope::MailScopes::send_mail):
+        ///   `esi-mail.send_mail.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`   (`i64`): The ID of the character to send the mail from
+        /// - `mail`      ([`NewMail`]): The subject, body, & recipients of the mail to send
+        ///
+        /// # Returns
+        /// An ESI request builder that sends the mail and returns the ID of the sent mail when sent.
+        auth fn send_mail(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<i64>
+        method = Method::POST;
+        path = "/characters/{}/mail";
+        required_scopes = ScopeBuilder::new()
+            .mail(MailScopes::new().send_mail())
+            .build();
+        body = mail: NewMail;
+        idempotency = Idempotency::Unsafe;
+    }
 }
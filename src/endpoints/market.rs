@@ -3,17 +3,21 @@
 //! This module provides the [`MarketEndpoints`] struct and associated methods for accessing
 //! market-related ESI endpoints.
 
+use std::sync::Arc;
+
 use crate::{
-    esi::EsiRequest,
+    constant::DEFAULT_COMPOSITE_CONCURRENCY,
+    esi::{concurrency::SharedRequests, join_limited_partial, AccessToken, EsiRequest},
     model::{
         enums::market::OrderType,
         market::{
             CharacterMarketOrder, CorporationMarketOrder, MarketItemGroupInformation,
             MarketItemPrices, MarketItemRegionStatistics, MarketRegionOrder, StructureMarketOrder,
+            UndercutOrder,
         },
     },
     scope::MarketsScopes,
-    Client, ScopeBuilder,
+    Client, CompositeError, ScopeBuilder,
 };
 use reqwest::Method;
 
@@ -48,13 +52,13 @@ impl<'a> MarketEndpoints<'a> {
         ///   `esi-markets.read_character_orders.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`    (`i64`): The ID of the character to retrieve open market orders for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of open market orders for the provided character ID when sent.
         auth fn list_open_orders_from_a_character(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64
         ) -> EsiRequest<Vec<CharacterMarketOrder>>
         method = Method::GET;
@@ -77,14 +81,14 @@ impl<'a> MarketEndpoints<'a> {
         ///   `esi-markets.read_character_orders.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `character_id`    (`i64`): The ID of the character to retrieve historical market orders for
         /// - `page`            (`i32`): The page of market orders to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a list of historical market orders for the provided character ID when sent.
         auth fn list_historical_orders_by_a_character(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             character_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CharacterMarketOrder>>
@@ -111,13 +115,13 @@ impl<'a> MarketEndpoints<'a> {
         ///   `esi-markets.read_corporation_orders.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve open market orders for
         ///
         /// # Returns
         /// An ESI request builder that returns a list of open market orders for the provided corporation ID when sent.
         auth fn list_open_orders_from_a_corporation(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64
         ) -> EsiRequest<Vec<CorporationMarketOrder>>
         method = Method::GET;
@@ -143,14 +147,14 @@ impl<'a> MarketEndpoints<'a> {
         ///   `esi-markets.read_corporation_orders.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `corporation_id`  (`i64`): The ID of the corporation to retrieve historical market orders for
         /// - `page`            (`i32`): The page of market orders to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a list of historical market orders for the provided corporation ID when sent.
         auth fn list_historical_orders_from_a_corporation(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             corporation_id: i64;
             page: i32
         ) -> EsiRequest<Vec<CorporationMarketOrder>>
@@ -226,14 +230,14 @@ impl<'a> MarketEndpoints<'a> {
         ///   `esi-markets.structure_markets.v1`
         ///
         /// # Arguments
-        /// - `access_token`   (`&str`): Access token used for authenticated ESI routes in string format.
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
         /// - `structure_id`    (`i64`): The ID of the structure to retrieve market orders for
         /// - `page`            (`i32`): The page of market orders to retrieve, page numbers start at `1`
         ///
         /// # Returns
         /// An ESI request builder that returns a list of market orders for the provided structure ID when sent.
         auth fn list_orders_in_a_structure(
-            access_token: &str,
+            access_token: impl Into<AccessToken>,
             structure_id: i64;
             page: i32
         ) -> EsiRequest<Vec<StructureMarketOrder>>
@@ -312,4 +316,143 @@ impl<'a> MarketEndpoints<'a> {
         method = Method::GET;
         path = "/markets/{}/types";
     }
+
+    /// Retrieves the open market orders of a character which have been undercut
+    ///
+    /// Requests the character's open orders, then the current orders of each item type in the
+    /// regions the orders were placed in with
+    /// [`Self::list_orders_in_a_region`], see [`UndercutOrder`] for which orders compete.
+    ///
+    /// A failed request doesn't stop the remaining orders from being checked, the undercut orders
+    /// found are returned alongside the failures, see [`CompositeError`]. If the character's orders
+    /// can't be retrieved the failure is keyed by the character ID.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`MarketsScopes::read_character_orders`](crate::scope::MarketsScopes::read_character_orders):
+    ///   `esi-markets.read_character_orders.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id`    (`i64`): The ID of the character to check the open market orders of
+    ///
+    /// # Returns
+    /// The undercut orders in the order ESI returned the character's orders, or a
+    /// [`CompositeError`] with the undercut orders found & the error of each order ID which failed if
+    /// any of the requests fail.
+    pub async fn get_undercut_character_orders(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+    ) -> Result<Vec<UndercutOrder>, CompositeError<Vec<UndercutOrder>>> {
+        let orders = match self
+            .list_open_orders_from_a_character(access_token, character_id)
+            .send()
+            .await
+        {
+            Ok(response) => response.data,
+            Err(error) => return Err(CompositeError::failed(Vec::new(), character_id, error)),
+        };
+
+        self.find_undercut_orders(
+            orders,
+            |order| (order.order_id, order.region_id, order.type_id),
+            CharacterMarketOrder::undercut_by,
+        )
+        .await
+    }
+
+    /// Retrieves the open market orders of a corporation which have been undercut
+    ///
+    /// Requests the corporation's open orders, then the current orders of each item type in the
+    /// regions the orders were placed in with
+    /// [`Self::list_orders_in_a_region`], see [`UndercutOrder`] for which orders compete.
+    ///
+    /// A failed request doesn't stop the remaining orders from being checked, the undercut orders
+    /// found are returned alongside the failures, see [`CompositeError`]. If the corporation's
+    /// orders can't be retrieved the failure is keyed by the corporation ID.
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `Accountant` or
+    /// `Trader` role within the corporation to access this information.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`MarketsScopes::read_corporation_orders`](crate::scope::MarketsScopes::read_corporation_orders):
+    ///   `esi-markets.read_corporation_orders.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to check the open market orders of
+    ///
+    /// # Returns
+    /// The undercut orders in the order ESI returned the corporation's orders, or a
+    /// [`CompositeError`] with the undercut orders found & the error of each order ID which failed if
+    /// any of the requests fail.
+    pub async fn get_undercut_corporation_orders(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<Vec<UndercutOrder>, CompositeError<Vec<UndercutOrder>>> {
+        let orders = match self
+            .list_open_orders_from_a_corporation(access_token, corporation_id)
+            .send()
+            .await
+        {
+            Ok(response) => response.data,
+            Err(error) => return Err(CompositeError::failed(Vec::new(), corporation_id, error)),
+        };
+
+        self.find_undercut_orders(
+            orders,
+            |order| (order.order_id, order.region_id, order.type_id),
+            CorporationMarketOrder::undercut_by,
+        )
+        .await
+    }
+
+    /// Checks each order against every page of the current orders of its region & item type
+    ///
+    /// `ids` returns the order, region & type ID of an order. The current orders of each distinct
+    /// region & item type pair are only requested once.
+    async fn find_undercut_orders<O>(
+        &self,
+        orders: Vec<O>,
+        ids: impl Fn(&O) -> (i64, i64, i64),
+        undercut_by: impl Fn(&O, &[MarketRegionOrder]) -> Option<UndercutOrder>,
+    ) -> Result<Vec<UndercutOrder>, CompositeError<Vec<UndercutOrder>>> {
+        let region_orders = SharedRequests::default();
+
+        let result = join_limited_partial(
+            self.client,
+            orders,
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |order| ids(order).0,
+            |order| {
+                let (_, region_id, type_id) = ids(&order);
+                let (region_orders, undercut_by) = (&region_orders, &undercut_by);
+
+                async move {
+                    let competing = region_orders
+                        .get_or_send((region_id, type_id), || async move {
+                            self.list_orders_in_a_region(region_id, OrderType::All, 1)
+                                .with_query("type_id", type_id)
+                                .send_all_pages()
+                                .await
+                                .map(|response| Arc::new(response.data))
+                        })
+                        .await?;
+
+                    Ok(undercut_by(&order, &competing))
+                }
+            },
+        )
+        .await;
+
+        let flatten =
+            |undercut: Vec<Option<UndercutOrder>>| undercut.into_iter().flatten().collect();
+
+        result.map(flatten).map_err(|partial| partial.map(flatten))
+    }
 }
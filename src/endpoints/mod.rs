@@ -54,7 +54,7 @@
 //!     // Get character notifications for character_id using the access_token
 //!     let notifications = esi_client
 //!         .character()
-//!         .get_character_notifications(&access_token, character_id)
+//!         .get_character_notifications(access_token, character_id)
 //!         .send()
 //!         .await
 //!         .unwrap();
@@ -62,71 +62,134 @@
 //! ```
 
 #[macro_use]
-mod macros;
+pub(crate) mod macros;
 
+#[cfg(feature = "alliance")]
 pub mod alliance;
+#[cfg(feature = "assets")]
 pub mod assets;
+#[cfg(feature = "calendar")]
 pub mod calendar;
+#[cfg(feature = "character")]
 pub mod character;
+#[cfg(feature = "clones")]
 pub mod clones;
+#[cfg(feature = "contacts")]
 pub mod contacts;
+#[cfg(feature = "contracts")]
 pub mod contracts;
+#[cfg(feature = "corporation")]
 pub mod corporation;
+#[cfg(feature = "corporation-projects")]
 pub mod corporation_projects;
+#[cfg(feature = "dogma")]
 pub mod dogma;
+#[cfg(feature = "faction-warfare")]
 pub mod faction_warfare;
+#[cfg(feature = "fittings")]
 pub mod fittings;
+#[cfg(feature = "fleets")]
 pub mod fleets;
+#[cfg(feature = "incursions")]
 pub mod incursions;
+#[cfg(feature = "industry")]
 pub mod industry;
+#[cfg(feature = "insurance")]
 pub mod insurance;
+#[cfg(feature = "killmails")]
 pub mod killmails;
+#[cfg(feature = "location")]
 pub mod location;
+#[cfg(feature = "loyalty")]
 pub mod loyalty;
+#[cfg(feature = "mail")]
 pub mod mail;
+#[cfg(feature = "market")]
 pub mod market;
+#[cfg(feature = "meta")]
 pub mod meta;
+#[cfg(feature = "planetary-interaction")]
 pub mod planetary_interaction;
+#[cfg(feature = "routes")]
 pub mod routes;
+#[cfg(feature = "search")]
 pub mod search;
+#[cfg(feature = "skills")]
 pub mod skills;
+#[cfg(feature = "sovereignty")]
 pub mod sovereignty;
+#[cfg(feature = "status")]
 pub mod status;
+#[cfg(feature = "universe")]
 pub mod universe;
+#[cfg(feature = "user-interface")]
 pub mod user_interface;
+#[cfg(feature = "wallet")]
 pub mod wallet;
 
-use crate::{endpoints::contracts::ContractsEndpoints, Client};
+use crate::Client;
 
+#[cfg(feature = "alliance")]
 use alliance::AllianceEndpoints;
+#[cfg(feature = "assets")]
 use assets::AssetsEndpoints;
+#[cfg(feature = "calendar")]
 use calendar::CalendarEndpoints;
+#[cfg(feature = "character")]
 use character::CharacterEndpoints;
+#[cfg(feature = "clones")]
 use clones::ClonesEndpoints;
+#[cfg(feature = "contacts")]
 use contacts::ContactsEndpoints;
+#[cfg(feature = "contracts")]
+use contracts::ContractsEndpoints;
+#[cfg(feature = "corporation")]
 use corporation::CorporationEndpoints;
+#[cfg(feature = "corporation-projects")]
 use corporation_projects::CorporationProjectsEndpoints;
+#[cfg(feature = "dogma")]
 use dogma::DogmaEndpoints;
+#[cfg(feature = "faction-warfare")]
 use faction_warfare::FactionWarfareEndpoints;
+#[cfg(feature = "fittings")]
 use fittings::FittingsEndpoints;
+#[cfg(feature = "fleets")]
 use fleets::FleetsEndpoints;
+#[cfg(feature = "incursions")]
 use incursions::IncursionsEndpoints;
+#[cfg(feature = "industry")]
 use industry::IndustryEndpoints;
+#[cfg(feature = "insurance")]
 use insurance::InsuranceEndpoints;
+#[cfg(feature = "killmails")]
 use killmails::KillmailsEndpoints;
+#[cfg(feature = "location")]
 use location::LocationEndpoints;
+#[cfg(feature = "loyalty")]
 use loyalty::LoyaltyEndpoints;
+#[cfg(feature = "mail")]
 use mail::MailEndpoints;
+#[cfg(feature = "market")]
 use market::MarketEndpoints;
+#[cfg(feature = "meta")]
 use meta::MetaEndpoints;
+#[cfg(feature = "planetary-interaction")]
 use planetary_interaction::PlanetaryInteractionEndpoints;
+#[cfg(feature = "routes")]
 use routes::RoutesEndpoints;
+#[cfg(feature = "search")]
 use search::SearchEndpoints;
+#[cfg(feature = "skills")]
 use skills::SkillsEndpoints;
+#[cfg(feature = "sovereignty")]
 use sovereignty::SovereigntyEndpoints;
+#[cfg(feature = "status")]
 use status::StatusEndpoints;
+#[cfg(feature = "universe")]
 use universe::UniverseEndpoints;
+#[cfg(feature = "user-interface")]
 use user_interface::UserInterfaceEndpoints;
+#[cfg(feature = "wallet")]
 use wallet::WalletEndpoints;
 
 impl Client {
@@ -135,6 +198,7 @@ impl Client {
     /// For an overview & usage example, see the [endpoints module documentation](super)
     ///
     /// Returns an API client for interacting with alliance-related endpoints.
+    #[cfg(feature = "alliance")]
     pub fn alliance(&self) -> AllianceEndpoints<'_> {
         AllianceEndpoints::new(self)
     }
@@ -142,6 +206,7 @@ impl Client {
     /// Access to assets ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "assets")]
     pub fn assets(&self) -> AssetsEndpoints<'_> {
         AssetsEndpoints::new(self)
     }
@@ -149,6 +214,7 @@ impl Client {
     /// Access to calendar ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "calendar")]
     pub fn calendar(&self) -> CalendarEndpoints<'_> {
         CalendarEndpoints::new(self)
     }
@@ -158,6 +224,7 @@ impl Client {
     /// For an overview & usage example, see the [endpoints module documentation](super)
     ///
     /// Returns an API client for interacting with character-related endpoints.
+    #[cfg(feature = "character")]
     pub fn character(&self) -> CharacterEndpoints<'_> {
         CharacterEndpoints::new(self)
     }
@@ -165,6 +232,7 @@ impl Client {
     /// Access to clones ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "clones")]
     pub fn clones(&self) -> ClonesEndpoints<'_> {
         ClonesEndpoints::new(self)
     }
@@ -172,6 +240,7 @@ impl Client {
     /// Access to contacts ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "contacts")]
     pub fn contacts(&self) -> ContactsEndpoints<'_> {
         ContactsEndpoints::new(self)
     }
@@ -179,6 +248,7 @@ impl Client {
     /// Access to contracts ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "contracts")]
     pub fn contracts(&self) -> ContractsEndpoints<'_> {
         ContractsEndpoints::new(self)
     }
@@ -188,6 +258,7 @@ impl Client {
     /// For an overview & usage example, see the [endpoints module documentation](super)
     ///
     /// Returns an API client for interacting with corporation-related endpoints.
+    #[cfg(feature = "corporation")]
     pub fn corporation(&self) -> CorporationEndpoints<'_> {
         CorporationEndpoints::new(self)
     }
@@ -195,20 +266,23 @@ impl Client {
     /// Access to corporation projects ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn corporation_projects(&self) -> CorporationProjectsEndpoints<'_> {
+    #[cfg(feature = "corporation-projects")]
+    pub fn corporation_projects(&self) -> CorporationProjectsEndpoints<'_> {
         CorporationProjectsEndpoints::new(self)
     }
 
     /// Access to dogma ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn dogma(&self) -> DogmaEndpoints<'_> {
+    #[cfg(feature = "dogma")]
+    pub fn dogma(&self) -> DogmaEndpoints<'_> {
         DogmaEndpoints::new(self)
     }
 
     /// Access to faction warfare ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "faction-warfare")]
     fn faction_warfare(&self) -> FactionWarfareEndpoints<'_> {
         FactionWarfareEndpoints::new(self)
     }
@@ -216,20 +290,23 @@ impl Client {
     /// Access to fittings ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn fittings(&self) -> FittingsEndpoints<'_> {
+    #[cfg(feature = "fittings")]
+    pub fn fittings(&self) -> FittingsEndpoints<'_> {
         FittingsEndpoints::new(self)
     }
 
     /// Access to fleets ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn fleets(&self) -> FleetsEndpoints<'_> {
+    #[cfg(feature = "fleets")]
+    pub fn fleets(&self) -> FleetsEndpoints<'_> {
         FleetsEndpoints::new(self)
     }
 
     /// Access to incursions ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "incursions")]
     fn incursions(&self) -> IncursionsEndpoints<'_> {
         IncursionsEndpoints::new(self)
     }
@@ -237,34 +314,39 @@ impl Client {
     /// Access to industry ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn industry(&self) -> IndustryEndpoints<'_> {
+    #[cfg(feature = "industry")]
+    pub fn industry(&self) -> IndustryEndpoints<'_> {
         IndustryEndpoints::new(self)
     }
 
     /// Access to insurance ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn insurance(&self) -> InsuranceEndpoints<'_> {
+    #[cfg(feature = "insurance")]
+    pub fn insurance(&self) -> InsuranceEndpoints<'_> {
         InsuranceEndpoints::new(self)
     }
 
     /// Access to killmails ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn killmails(&self) -> KillmailsEndpoints<'_> {
+    #[cfg(feature = "killmails")]
+    pub fn killmails(&self) -> KillmailsEndpoints<'_> {
         KillmailsEndpoints::new(self)
     }
 
     /// Access to location ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn location(&self) -> LocationEndpoints<'_> {
+    #[cfg(feature = "location")]
+    pub fn location(&self) -> LocationEndpoints<'_> {
         LocationEndpoints::new(self)
     }
 
     /// Access to loyalty ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "loyalty")]
     fn loyalty(&self) -> LoyaltyEndpoints<'_> {
         LoyaltyEndpoints::new(self)
     }
@@ -272,13 +354,15 @@ impl Client {
     /// Access to mail ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn mail(&self) -> MailEndpoints<'_> {
+    #[cfg(feature = "mail")]
+    pub fn mail(&self) -> MailEndpoints<'_> {
         MailEndpoints::new(self)
     }
 
     /// Access to market ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "market")]
     pub fn market(&self) -> MarketEndpoints<'_> {
         MarketEndpoints::new(self)
     }
@@ -286,6 +370,7 @@ impl Client {
     /// Access to meta ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "meta")]
     fn meta(&self) -> MetaEndpoints<'_> {
         MetaEndpoints::new(self)
     }
@@ -293,41 +378,47 @@ impl Client {
     /// Access to planetary interaction ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn planetary_interaction(&self) -> PlanetaryInteractionEndpoints<'_> {
+    #[cfg(feature = "planetary-interaction")]
+    pub fn planetary_interaction(&self) -> PlanetaryInteractionEndpoints<'_> {
         PlanetaryInteractionEndpoints::new(self)
     }
 
     /// Access to routes ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn routes(&self) -> RoutesEndpoints<'_> {
+    #[cfg(feature = "routes")]
+    pub fn routes(&self) -> RoutesEndpoints<'_> {
         RoutesEndpoints::new(self)
     }
 
     /// Access to search ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn search(&self) -> SearchEndpoints<'_> {
+    #[cfg(feature = "search")]
+    pub fn search(&self) -> SearchEndpoints<'_> {
         SearchEndpoints::new(self)
     }
 
     /// Access to skills ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn skills(&self) -> SkillsEndpoints<'_> {
+    #[cfg(feature = "skills")]
+    pub fn skills(&self) -> SkillsEndpoints<'_> {
         SkillsEndpoints::new(self)
     }
 
     /// Access to sovereignty ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn sovereignty(&self) -> SovereigntyEndpoints<'_> {
+    #[cfg(feature = "sovereignty")]
+    pub fn sovereignty(&self) -> SovereigntyEndpoints<'_> {
         SovereigntyEndpoints::new(self)
     }
 
     /// Access to status ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "status")]
     fn status(&self) -> StatusEndpoints<'_> {
         StatusEndpoints::new(self)
     }
@@ -335,6 +426,7 @@ impl Client {
     /// Access to universe ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "universe")]
     pub fn universe(&self) -> UniverseEndpoints<'_> {
         UniverseEndpoints::new(self)
     }
@@ -342,6 +434,7 @@ impl Client {
     /// Access to user interface ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
+    #[cfg(feature = "user-interface")]
     fn user_interface(&self) -> UserInterfaceEndpoints<'_> {
         UserInterfaceEndpoints::new(self)
     }
@@ -349,7 +442,8 @@ impl Client {
     /// Access to wallet ESI endpoints
     ///
     /// For an overview & usage example, see the [endpoints module documentation](super)
-    fn wallet(&self) -> WalletEndpoints<'_> {
+    #[cfg(feature = "wallet")]
+    pub fn wallet(&self) -> WalletEndpoints<'_> {
         WalletEndpoints::new(self)
     }
 }
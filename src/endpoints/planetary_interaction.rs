@@ -3,7 +3,11 @@
 //! This module provides the [`PlanetaryInteractionEndpoints`] struct and associated methods for accessing
 //! planetary interaction-related ESI endpoints.
 
-use crate::Client;
+use crate::esi::EsiRequest;
+use crate::model::planetary_interaction::{Colony, ColonyLayout};
+use crate::scope::PlanetsScopes;
+use crate::{Client, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing planetary interaction-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +24,67 @@ impl<'a> PlanetaryInteractionEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves a list of planetary colonies owned by the provided character ID
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdPlanets>
+        ///
+        /// # Required Scopes
+        /// - [`PlanetsScopes::manage_planets`](crate::scope::PlanetsScopes::manage_planets):
+        ///   `esi-planets.manage_planets.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`  (`i64`): The ID of the character to retrieve colonies for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of the character's planetary colonies when sent.
+        auth fn get_colonies(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<Vec<Colony>>
+        method = Method::GET;
+        path = "/characters/{}/planets";
+        required_scopes = ScopeBuilder::new()
+            .planets(PlanetsScopes::new().manage_planets())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the full layout of a character's planetary colony including extractor programs
+        ///
+        /// For extractor pins, see [`PlanetPin::extraction_remaining`](crate::model::planetary_interaction::PlanetPin::extraction_remaining)
+        /// & related methods for the remaining duration & estimated yield of the extractor program.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdPlanetsPlanetId>
+        ///
+        /// # Required Scopes
+        /// - [`PlanetsScopes::manage_planets`](crate::scope::PlanetsScopes::manage_planets):
+        ///   `esi-planets.manage_planets.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`  (`i64`): The ID of the character which owns the colony
+        /// - `planet_id`     (`i64`): The ID of the planet the colony is on
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the layout of the colony when sent.
+        auth fn get_colony_layout(
+            access_token: impl Into<AccessToken>,
+            character_id: i64,
+            planet_id: i64
+        ) -> EsiRequest<ColonyLayout>
+        method = Method::GET;
+        path = "/characters/{}/planets/{}";
+        required_scopes = ScopeBuilder::new()
+            .planets(PlanetsScopes::new().manage_planets())
+            .build();
+    }
 }
@@ -3,7 +3,17 @@
 //! This module provides the [`RoutesEndpoints`] struct and associated methods for accessing
 //! route-related ESI endpoints.
 
+#[cfg(all(feature = "sovereignty", feature = "universe"))]
+use std::collections::HashMap;
+
+use crate::esi::EsiRequest;
+use crate::model::enums::routes::RouteFlag;
+#[cfg(all(feature = "sovereignty", feature = "universe"))]
+use crate::model::routes::{RouteReport, RouteSystem};
 use crate::Client;
+#[cfg(all(feature = "sovereignty", feature = "universe"))]
+use crate::Error;
+use reqwest::Method;
 
 /// Provides methods for accessing route-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +30,102 @@ impl<'a> RoutesEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the route between 2 solar systems as a list of solar system IDs
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetRouteOriginDestination>
+        ///
+        /// # Arguments
+        /// - `origin`          (`i64`): The ID of the solar system to start the route at
+        /// - `destination`     (`i64`): The ID of the solar system to end the route at
+        /// - `flag`  ([`RouteFlag`]): The route preference, either [`RouteFlag::Shortest`],
+        ///   [`RouteFlag::Secure`], or [`RouteFlag::Insecure`]
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the solar system IDs along the route, including the origin
+        /// & destination, when sent.
+        pub fn get_route(
+            origin: i64,
+            destination: i64;
+            flag: RouteFlag
+        ) -> EsiRequest<Vec<i64>>
+        method = Method::GET;
+        path = "/route/{}/{}";
+    }
+
+    /// Retrieves a route between 2 solar systems with each system annotated with recent kills & sovereignty
+    ///
+    /// Combines [`Self::get_route`] with
+    /// [`UniverseEndpoints::get_system_kills`](crate::endpoints::universe::UniverseEndpoints::get_system_kills) &
+    /// [`SovereigntyEndpoints::list_sovereignty_of_systems`](crate::endpoints::sovereignty::SovereigntyEndpoints::list_sovereignty_of_systems)
+    /// to evaluate the safety of a route, making 3 requests in total.
+    ///
+    /// Requires the `sovereignty` & `universe` features.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `origin`          (`i64`): The ID of the solar system to start the route at
+    /// - `destination`     (`i64`): The ID of the solar system to end the route at
+    /// - `flag`  ([`RouteFlag`]): The route preference
+    ///
+    /// # Returns
+    /// A [`RouteReport`] for the route, or an [`Error`] if any of the requests fail.
+    #[cfg(all(feature = "sovereignty", feature = "universe"))]
+    pub async fn get_route_report(
+        &self,
+        origin: i64,
+        destination: i64,
+        flag: RouteFlag,
+    ) -> Result<RouteReport, Error> {
+        let route = self.get_route(origin, destination, flag).send().await?.data;
+
+        let kills: HashMap<i64, (i64, i64)> = self
+            .client
+            .universe()
+            .get_system_kills()
+            .send()
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| (entry.system_id, (entry.ship_kills, entry.pod_kills)))
+            .collect();
+        let mut sovereignty: HashMap<i64, _> = self
+            .client
+            .sovereignty()
+            .list_sovereignty_of_systems()
+            .send()
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| (entry.system_id, entry))
+            .collect();
+
+        let systems = route
+            .into_iter()
+            .map(|system_id| {
+                let (ship_kills, pod_kills) = kills.get(&system_id).copied().unwrap_or((0, 0));
+                let holder = sovereignty.remove(&system_id);
+
+                RouteSystem {
+                    system_id,
+                    ship_kills,
+                    pod_kills,
+                    alliance_id: holder.as_ref().and_then(|holder| holder.alliance_id),
+                    corporation_id: holder.as_ref().and_then(|holder| holder.corporation_id),
+                    faction_id: holder.and_then(|holder| holder.faction_id),
+                }
+            })
+            .collect();
+
+        Ok(RouteReport {
+            origin,
+            destination,
+            systems,
+        })
+    }
 }
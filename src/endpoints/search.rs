@@ -3,7 +3,26 @@
 //! This module provides the [`SearchEndpoints`] struct and associated methods for accessing
 //! search-related ESI endpoints.
 
-use crate::Client;
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+use std::collections::HashMap;
+
+use reqwest::Method;
+
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+use crate::{
+    constant::DEFAULT_COMPOSITE_CONCURRENCY,
+    esi::join_limited_partial,
+    model::enums::search::SearchCategory,
+    model::search::{ResolvedSearchResult, SearchCharacter},
+    CompositeError, Error,
+};
+use crate::{
+    esi::EsiRequest, model::search::SearchResult, scope::SearchScopes, Client, ScopeBuilder,
+};
+
+/// Maximum number of character IDs which can be resolved per character affiliation request
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+const MAX_AFFILIATIONS_PER_REQUEST: usize = 1000;
 
 /// Provides methods for accessing search-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +39,197 @@ impl<'a> SearchEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Searches for entities matching a string, grouped by category
+        ///
+        /// Use [`Self::resolve_search_result`] to retrieve the public information of the matched
+        /// characters, corporations & solar systems.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdSearch>
+        ///
+        /// # Required Scopes
+        /// - [`SearchScopes::search_structures`](crate::scope::SearchScopes::search_structures):
+        ///   `esi-search.search_structures.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to search as
+        /// - `categories`   (`&str`): Comma separated categories to search, see
+        ///   [`SearchCategory::join`](crate::model::enums::search::SearchCategory::join)
+        /// - `search`       (`&str`): The string to search for, at least 3 characters
+        /// - `strict`       (`Option<bool>`): Whether to only match the whole search string, defaults to `false`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the IDs matching the search string per category when sent.
+        auth fn character_search(
+            access_token: impl Into<AccessToken>,
+            character_id: i64;
+            categories: &str,
+            search: &str,
+            strict: Option<bool>
+        ) -> EsiRequest<SearchResult>
+        method = Method::GET;
+        path = "/characters/{}/search";
+        required_scopes = ScopeBuilder::new()
+            .search(SearchScopes::new().search_structures())
+            .build();
+    }
+
+    /// Retrieves the public information of the characters, corporations & solar systems matched by a search
+    ///
+    /// Character names are resolved with
+    /// [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names)
+    /// & affiliations with
+    /// [`CharacterEndpoints::character_affiliation`](crate::endpoints::character::CharacterEndpoints::character_affiliation)
+    /// in bulk. Corporations & solar systems are requested one at a time with up to 10 requests in
+    /// flight per category. IDs in other categories are ignored.
+    ///
+    /// A failed request doesn't stop the remaining IDs from being requested, the matches resolved are
+    /// returned alongside the failures, see [`CompositeError`]. Characters are resolved together, if
+    /// that fails no characters are returned & the failure is keyed by the first character ID.
+    ///
+    /// Requires the `character`, `corporation` & `universe` features.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `result` (&[`SearchResult`]): The result returned by [`Self::character_search`]
+    ///
+    /// # Returns
+    /// The matched characters, corporations & solar systems keyed by ID, or a [`CompositeError`] with
+    /// the matches resolved & the error of each category & ID which failed if any of the requests
+    /// fail.
+    #[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+    pub async fn resolve_search_result(
+        &self,
+        result: &SearchResult,
+    ) -> Result<ResolvedSearchResult, CompositeError<ResolvedSearchResult, (SearchCategory, i64)>>
+    {
+        let mut failures = Vec::new();
+        let mut attempted = 0;
+
+        let mut characters = HashMap::new();
+        if let Some(&character_id) = result.character.first() {
+            attempted += 1;
+
+            match self.resolve_characters(&result.character).await {
+                Ok(resolved) => characters = resolved,
+                Err(error) => failures.push(((SearchCategory::Character, character_id), error)),
+            }
+        }
+
+        let corporation = self.client.corporation();
+        let corporations = join_limited_partial(
+            self.client,
+            result.corporation.clone(),
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |corporation_id| (SearchCategory::Corporation, *corporation_id),
+            |corporation_id| {
+                let request = corporation.get_corporation_information(corporation_id);
+
+                async move {
+                    request
+                        .send()
+                        .await
+                        .map(|response| (corporation_id, response.data))
+                }
+            },
+        )
+        .await;
+        let corporations = collect_partial(corporations, &mut failures, &mut attempted);
+
+        let universe = self.client.universe();
+        let solar_systems = join_limited_partial(
+            self.client,
+            result.solar_system.clone(),
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |system_id| (SearchCategory::SolarSystem, *system_id),
+            |system_id| {
+                let request = universe.get_solar_system_information(system_id);
+
+                async move {
+                    request
+                        .send()
+                        .await
+                        .map(|response| (system_id, response.data))
+                }
+            },
+        )
+        .await;
+        let solar_systems = collect_partial(solar_systems, &mut failures, &mut attempted);
+
+        let resolved = ResolvedSearchResult {
+            characters,
+            corporations: corporations.into_iter().collect(),
+            solar_systems: solar_systems.into_iter().collect(),
+        };
+
+        CompositeError::check(resolved, failures, attempted)
+    }
+
+    /// Resolves the names & affiliations of the provided characters in bulk
+    #[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+    async fn resolve_characters(
+        &self,
+        character_ids: &[i64],
+    ) -> Result<HashMap<i64, SearchCharacter>, Error> {
+        if character_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut names = self
+            .client
+            .universe()
+            .resolve_names(character_ids.to_vec())
+            .await?;
+
+        let mut characters = HashMap::with_capacity(character_ids.len());
+        for chunk in character_ids.chunks(MAX_AFFILIATIONS_PER_REQUEST) {
+            let affiliations = self
+                .client
+                .character()
+                .character_affiliation(chunk.to_vec())
+                .send()
+                .await?
+                .data;
+
+            for affiliation in affiliations {
+                let name = names.remove(&affiliation.character_id).unwrap_or_default();
+
+                characters.insert(
+                    affiliation.character_id,
+                    SearchCharacter { name, affiliation },
+                );
+            }
+        }
+
+        Ok(characters)
+    }
+}
+
+/// Returns the data of a partial request of one category, adding its failures & attempts to the totals
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+fn collect_partial<T, K>(
+    result: Result<Vec<T>, CompositeError<Vec<T>, K>>,
+    failures: &mut Vec<(K, Error)>,
+    attempted: &mut usize,
+) -> Vec<T> {
+    match result {
+        Ok(successes) => {
+            *attempted += successes.len();
+
+            successes
+        }
+        Err(partial) => {
+            let (successes, category_failures, category_attempted) = partial.into_parts();
+            failures.extend(category_failures);
+            *attempted += category_attempted;
+
+            successes
+        }
+    }
 }
@@ -3,7 +3,11 @@
 //! This module provides the [`SkillsEndpoints`] struct and associated methods for accessing
 //! skill-related ESI endpoints.
 
-use crate::Client;
+use crate::esi::EsiRequest;
+use crate::model::skills::{CharacterAttributes, SkillQueueEntry};
+use crate::scope::SkillsScopes;
+use crate::{Client, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing skill-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +24,69 @@ impl<'a> SkillsEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the attributes of the provided character ID
+        ///
+        /// Attributes do not include bonuses from implants, see
+        /// [`CharacterAttributes::sp_per_hour`](crate::model::skills::CharacterAttributes::sp_per_hour)
+        /// to calculate the training rate of a skill including implant bonuses.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdAttributes>
+        ///
+        /// # Required Scopes
+        /// - [`SkillsScopes::read_skills`](crate::scope::SkillsScopes::read_skills):
+        ///   `esi-skills.read_skills.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`  (`i64`): The ID of the character to retrieve attributes for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the attributes of the character when sent.
+        auth fn get_character_attributes(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<CharacterAttributes>
+        method = Method::GET;
+        path = "/characters/{}/attributes";
+        required_scopes = ScopeBuilder::new()
+            .skills(SkillsScopes::new().read_skills())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the skill queue of the provided character ID
+        ///
+        /// See [`SkillQueue`](crate::model::skills::SkillQueue) to calculate the remaining training time of
+        /// the queue including when it is paused.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdSkillqueue>
+        ///
+        /// # Required Scopes
+        /// - [`SkillsScopes::read_skillqueue`](crate::scope::SkillsScopes::read_skillqueue):
+        ///   `esi-skills.read_skillqueue.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id`  (`i64`): The ID of the character to retrieve the skill queue for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the entries of the character's skill queue when sent.
+        auth fn get_character_skill_queue(
+            access_token: impl Into<AccessToken>,
+            character_id: i64
+        ) -> EsiRequest<Vec<SkillQueueEntry>>
+        method = Method::GET;
+        path = "/characters/{}/skillqueue";
+        required_scopes = ScopeBuilder::new()
+            .skills(SkillsScopes::new().read_skillqueue())
+            .build();
+    }
 }
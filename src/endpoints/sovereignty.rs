@@ -3,7 +3,16 @@
 //! This module provides the [`SovereigntyEndpoints`] struct and associated methods for accessing
 //! sovereignty-related ESI endpoints.
 
+use crate::esi::EsiRequest;
+use crate::model::sovereignty::{SovereigntyMapSystem, SovereigntyStructure};
 use crate::Client;
+#[cfg(feature = "corporation")]
+use crate::{
+    esi::AccessToken,
+    model::sovereignty::{upcoming_timers, Timer},
+    Error,
+};
+use reqwest::Method;
 
 /// Provides methods for accessing sovereignty-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +29,100 @@ impl<'a> SovereigntyEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the sovereignty holder of each solar system
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetSovereigntyMap>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the sovereignty holder of each solar system when sent.
+        pub fn list_sovereignty_of_systems() -> EsiRequest<Vec<SovereigntyMapSystem>>
+        method = Method::GET;
+        path = "/sovereignty/map";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves every sovereignty structure & its vulnerability window
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetSovereigntyStructures>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of sovereignty structures when sent.
+        pub fn list_sovereignty_structures() -> EsiRequest<Vec<SovereigntyStructure>>
+        method = Method::GET;
+        path = "/sovereignty/structures";
+    }
+
+    /// Lists the upcoming sovereignty & structure timers of a corporation
+    ///
+    /// Fetches the corporation's public information for its alliance, the vulnerability windows of
+    /// the alliance's sovereignty structures & all pages of
+    /// [`CorporationEndpoints::get_corporation_structures`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_structures),
+    /// then combines them into a single chronological list with [`upcoming_timers`]. Corporations
+    /// outside of an alliance only have the timers of their own structures.
+    ///
+    /// Additional permissions required: the owner of the access token must be a director in the
+    /// corporation or have the `Station_Manager` role.
+    ///
+    /// Requires the `corporation` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`CorporationsScopes::read_structures`](crate::scope::CorporationsScopes::read_structures):
+    ///   `esi-corporations.read_structures.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id`  (`i64`): The ID of the corporation to list upcoming timers for
+    ///
+    /// # Returns
+    /// The corporation's upcoming timers sorted chronologically, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "corporation")]
+    pub async fn get_upcoming_timers(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<Vec<Timer>, Error> {
+        let corporation = self
+            .client
+            .corporation()
+            .get_corporation_information(corporation_id)
+            .send()
+            .await?
+            .data;
+
+        let sovereignty_structures = match corporation.alliance_id {
+            Some(alliance_id) => self
+                .list_sovereignty_structures()
+                .send()
+                .await?
+                .data
+                .into_iter()
+                .filter(|structure| structure.alliance_id == alliance_id)
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let structures = self
+            .client
+            .corporation()
+            .get_corporation_structures(access_token, corporation_id, 1)
+            .send_all_pages()
+            .await?
+            .data;
+
+        Ok(upcoming_timers(
+            &sovereignty_structures,
+            &structures,
+            chrono::Utc::now(),
+        ))
+    }
 }
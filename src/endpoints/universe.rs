@@ -3,9 +3,34 @@
 //! This module provides the [`UniverseEndpoints`] struct and associated methods for accessing
 //! universe-related ESI endpoints.
 
-use crate::{esi::EsiRequest, model::universe::Faction, Client};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    constant::DEFAULT_COMPOSITE_CONCURRENCY,
+    error::CompositeError,
+    esi::{join_limited_partial, AccessToken, EsiRequest, Idempotency},
+    model::{
+        enums::universe::UniverseNameCategory,
+        universe::{
+            Faction, ItemType, SolarSystem, Stargate, Station, Structure, StructureName,
+            SystemActivity, SystemJumps, SystemKills, UniverseName,
+        },
+        HasTypeId,
+    },
+    scope::UniverseScopes,
+    Client, Error, ScopeBuilder,
+};
 use reqwest::Method;
 
+#[cfg(feature = "universe-graph")]
+use crate::graph::UniverseGraph;
+#[cfg(feature = "sde")]
+use crate::sde::{StaticData, StaticType};
+
+/// Maximum number of IDs which can be resolved per universe names request
+const MAX_NAMES_PER_REQUEST: usize = 1000;
+
 /// Provides methods for accessing universe-related endpoints of the EVE Online ESI API.
 ///
 /// For an overview & usage examples, see the [endpoints module documentation](super)
@@ -36,4 +61,626 @@ impl<'a> UniverseEndpoints<'a> {
         method = Method::GET;
         path = "/universe/factions";
     }
+
+    define_esi_endpoint! {
+        /// Resolves a set of IDs to their names & categories
+        ///
+        /// Supports IDs of alliances, characters, constellations, corporations, inventory types,
+        /// regions, solar systems, stations, and factions. A maximum of 1000 IDs can be resolved per request,
+        /// the request will fail if any of the IDs provided are invalid.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/PostUniverseNames>
+        ///
+        /// # Arguments
+        /// - `ids` (Vec<[`i64`]>): A vec of IDs to resolve names for.
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of names & categories for the provided IDs when sent.
+        pub fn get_names_and_categories(
+        ) -> EsiRequest<Vec<UniverseName>>
+        method = Method::POST;
+        path = "/universe/names";
+        body = ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
+    }
+
+    /// Resolves a set of IDs to their names in bulk
+    ///
+    /// Names held by the client's [`NameCache`](crate::esi::NameCache) are returned without a
+    /// request. Duplicate IDs are removed and the remaining IDs are split into chunks of up to
+    /// 1000 IDs, making a [`Self::get_names_and_categories`] request for each chunk & caching
+    /// the resolved names.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `ids` (Vec<[`i64`]>): A vec of IDs to resolve names for.
+    ///
+    /// # Returns
+    /// A map of each ID to its name, or an [`Error`] if any of the requests fail such as when an
+    /// invalid ID is provided.
+    pub async fn resolve_names(&self, mut ids: Vec<i64>) -> Result<HashMap<i64, String>, Error> {
+        ids.sort_unstable();
+        ids.dedup();
+
+        let name_cache = self.client.name_cache();
+        let mut names = HashMap::with_capacity(ids.len());
+
+        ids.retain(|&id| match name_cache.get(id) {
+            Some(entry) => {
+                names.insert(id, entry.name);
+                false
+            }
+            None => true,
+        });
+
+        for chunk in ids.chunks(MAX_NAMES_PER_REQUEST) {
+            let response = self.get_names_and_categories(chunk.to_vec()).send().await?;
+
+            names.extend(
+                response
+                    .data
+                    .iter()
+                    .map(|entry| (entry.id, entry.name.clone())),
+            );
+            name_cache.insert(response.data);
+        }
+
+        Ok(names)
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the number of ship, pod, & NPC kills per solar system within the last hour
+        ///
+        /// Solar systems without any kills are not included.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseSystemKills>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of kill counts per solar system when sent.
+        pub fn get_system_kills() -> EsiRequest<Vec<SystemKills>>
+        method = Method::GET;
+        path = "/universe/system_kills";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the number of ship jumps per solar system within the last hour
+        ///
+        /// Solar systems without any jumps & wormhole space are not included.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseSystemJumps>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of jump counts per solar system when sent.
+        pub fn get_system_jumps() -> EsiRequest<Vec<SystemJumps>>
+        method = Method::GET;
+        path = "/universe/system_jumps";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves a list of the IDs of all solar systems
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseSystems>
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of solar system IDs when sent.
+        pub fn get_solar_systems() -> EsiRequest<Vec<i64>>
+        method = Method::GET;
+        path = "/universe/systems";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves information on the provided solar system ID
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseSystemsSystemId>
+        ///
+        /// # Arguments
+        /// - `system_id` (`i64`): The ID of the solar system to retrieve information for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns information on the solar system when sent.
+        pub fn get_solar_system_information(
+            system_id: i64
+        ) -> EsiRequest<SolarSystem>
+        method = Method::GET;
+        path = "/universe/systems/{}";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves information on the provided stargate ID including the solar system it jumps to
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseStargatesStargateId>
+        ///
+        /// # Arguments
+        /// - `stargate_id` (`i64`): The ID of the stargate to retrieve information for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns information on the stargate when sent.
+        pub fn get_stargate_information(
+            stargate_id: i64
+        ) -> EsiRequest<Stargate>
+        method = Method::GET;
+        path = "/universe/stargates/{}";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves information on the provided NPC station ID including the solar system it is in
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseStationsStationId>
+        ///
+        /// # Arguments
+        /// - `station_id` (`i64`): The ID of the station to retrieve information for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns information on the station when sent.
+        pub fn get_station_information(
+            station_id: i64
+        ) -> EsiRequest<Station>
+        method = Method::GET;
+        path = "/universe/stations/{}";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves information on the provided item type ID including its dogma attributes & effects
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseTypesTypeId>
+        ///
+        /// # Arguments
+        /// - `type_id` (`i64`): The ID of the item type to retrieve information for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns information on the item type when sent.
+        pub fn get_item_type_information(
+            type_id: i64
+        ) -> EsiRequest<ItemType>
+        method = Method::GET;
+        path = "/universe/types/{}";
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves information on the provided structure ID
+        ///
+        /// Additional permissions required: the owner of the access token must be on the structure's
+        /// access list to retrieve its information, otherwise ESI responds with 403 (Forbidden).
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetUniverseStructuresStructureId>
+        ///
+        /// # Required Scopes
+        /// - [`UniverseScopes::read_structures`](crate::scope::UniverseScopes::read_structures):
+        ///   `esi-universe.read_structures.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `structure_id` (`i64`): The ID of the structure to retrieve information for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns information on the structure when sent.
+        auth fn get_structure_information(
+            access_token: impl Into<AccessToken>,
+            structure_id: i64
+        ) -> EsiRequest<Structure>
+        method = Method::GET;
+        path = "/universe/structures/{}";
+        required_scopes = ScopeBuilder::new()
+            .universe(UniverseScopes::new().read_structures())
+            .build();
+    }
+
+    /// Resolves the names of a set of structure IDs, tolerating structures which are not accessible
+    ///
+    /// Makes a [`Self::get_structure_information`] request for each unique structure ID. Structures
+    /// the access token's character can't access are marked [`StructureName::Forbidden`] & structures
+    /// which no longer exist are marked [`StructureName::Unknown`] rather than failing the resolution.
+    ///
+    /// Each inaccessible structure counts towards the ESI error limit, the client waits for the error
+    /// limit to reset if it is reached. Prefer caching the results rather than resolving the same
+    /// inaccessible structures repeatedly.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`UniverseScopes::read_structures`](crate::scope::UniverseScopes::read_structures):
+    ///   `esi-universe.read_structures.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`  (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `structure_ids` (`Vec<i64>`): The IDs of the structures to resolve names for
+    ///
+    /// # Returns
+    /// A map of each structure ID to the result of resolving its name, or an [`Error`] if any of the
+    /// requests fail for reasons other than the structure being inaccessible.
+    pub async fn resolve_structure_names(
+        &self,
+        access_token: impl Into<AccessToken>,
+        mut structure_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, StructureName>, Error> {
+        let access_token = access_token.into();
+
+        structure_ids.sort_unstable();
+        structure_ids.dedup();
+
+        let mut names = HashMap::with_capacity(structure_ids.len());
+
+        for structure_id in structure_ids {
+            let result = self
+                .get_structure_information(&access_token, structure_id)
+                .send()
+                .await;
+
+            let name = match result {
+                Ok(response) => StructureName::Resolved(response.data.name),
+                Err(Error::EsiError(error)) if error.status == 403 => StructureName::Forbidden,
+                Err(Error::EsiError(error)) if error.status == 404 => StructureName::Unknown,
+                Err(error) => return Err(error),
+            };
+
+            names.insert(structure_id, name);
+        }
+
+        Ok(names)
+    }
+
+    /// Retrieves recent kill & jump activity for the provided solar systems with their names & security status
+    ///
+    /// Combines [`Self::get_system_kills`] & [`Self::get_system_jumps`] with
+    /// [`Self::get_solar_system_information`] for each solar system, intended for building intel heatmaps.
+    /// Solar systems without any recorded activity are included with counts of zero.
+    ///
+    /// Makes 2 requests for kills & jumps plus 1 request per solar system, ESI caches solar system
+    /// information so prefer limiting the solar systems to the area of interest such as a region.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `system_ids` (`&[i64]`): The IDs of the solar systems to retrieve activity for
+    ///
+    /// # Returns
+    /// Activity for each of the provided solar systems in the order provided, or an [`Error`] if any
+    /// of the requests fail.
+    pub async fn get_system_activity(
+        &self,
+        system_ids: &[i64],
+    ) -> Result<Vec<SystemActivity>, Error> {
+        let kills: HashMap<i64, SystemKills> = self
+            .get_system_kills()
+            .send()
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| (entry.system_id, entry))
+            .collect();
+        let jumps: HashMap<i64, i64> = self
+            .get_system_jumps()
+            .send()
+            .await?
+            .data
+            .into_iter()
+            .map(|entry| (entry.system_id, entry.ship_jumps))
+            .collect();
+
+        let mut activity = Vec::with_capacity(system_ids.len());
+
+        for system_id in system_ids {
+            let system = self
+                .get_solar_system_information(*system_id)
+                .send()
+                .await?
+                .data;
+            let system_kills = kills.get(system_id);
+
+            activity.push(SystemActivity {
+                system_id: *system_id,
+                name: system.name,
+                security_status: system.security_status,
+                ship_kills: system_kills.map_or(0, |entry| entry.ship_kills),
+                pod_kills: system_kills.map_or(0, |entry| entry.pod_kills),
+                npc_kills: system_kills.map_or(0, |entry| entry.npc_kills),
+                ship_jumps: jumps.get(system_id).copied().unwrap_or(0),
+            });
+        }
+
+        Ok(activity)
+    }
+
+    /// Requests every solar system & stargate to add the solar systems missing from the graph
+    ///
+    /// Solar systems already in the graph are skipped, so a crawl which failed part way resumes
+    /// where it left off when called again with the same graph. Each stargate pair is only
+    /// requested once as the destination of a stargate also reveals the destination of the
+    /// stargate it jumps to. Requests are made one at a time, a full crawl makes several thousand
+    /// requests; save the graph with [`UniverseGraph::save`] rather than crawling on every startup.
+    ///
+    /// Requires the `universe-graph` feature. For an overview & usage examples, see the
+    /// [graph module documentation](crate::graph)
+    ///
+    /// # Arguments
+    /// - `graph` (`&mut UniverseGraph`): The graph to add solar systems to, a new or previously saved graph
+    ///
+    /// # Returns
+    /// `Ok(())` once every solar system is in the graph, or an [`Error`] if a request fails. Solar
+    /// systems crawled before the error remain in the graph.
+    #[cfg(feature = "universe-graph")]
+    pub async fn crawl_universe_graph(&self, graph: &mut UniverseGraph) -> Result<(), Error> {
+        let system_ids = self.get_solar_systems().send().await?.data;
+        let missing: Vec<i64> = system_ids
+            .into_iter()
+            .filter(|system_id| !graph.contains_system(*system_id))
+            .collect();
+
+        log::debug!(
+            "Crawling {} solar systems missing from the universe graph",
+            missing.len()
+        );
+
+        for system_id in missing {
+            let system = self
+                .get_solar_system_information(system_id)
+                .send()
+                .await?
+                .data;
+
+            let mut neighbors = Vec::with_capacity(system.stargates.len());
+            for stargate_id in system.stargates {
+                let destination = match graph.stargate_destination(stargate_id) {
+                    Some(destination) => destination,
+                    None => {
+                        let stargate = self
+                            .get_stargate_information(stargate_id)
+                            .send()
+                            .await?
+                            .data;
+                        graph.insert_stargate(stargate.destination.stargate_id, system_id);
+
+                        stargate.destination.system_id
+                    }
+                };
+
+                neighbors.push(destination);
+            }
+
+            graph.insert_system(system_id, neighbors);
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the information of the provided item types
+    ///
+    /// Duplicate IDs are removed & each type is requested with [`Self::get_item_type_information`]
+    /// concurrently, up to 10 at a time. The names of the retrieved types are added to the client's
+    /// [`NameCache`](crate::esi::NameCache) so later calls of [`Self::resolve_names`] don't request
+    /// them again.
+    ///
+    /// A failed request doesn't stop the remaining types from being requested, the types retrieved
+    /// are returned alongside the failures, see [`CompositeError`].
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `type_ids` (`Vec<i64>`): The IDs of the item types to retrieve
+    ///
+    /// # Returns
+    /// A map of each item type ID to its information, or a [`CompositeError`] with the item types
+    /// retrieved & the error of each type ID which failed if any of the requests fail.
+    pub async fn get_item_types(
+        &self,
+        mut type_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, ItemType>, CompositeError<HashMap<i64, ItemType>>> {
+        type_ids.sort_unstable();
+        type_ids.dedup();
+
+        let result = join_limited_partial(
+            self.client,
+            type_ids,
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |type_id| *type_id,
+            |type_id| {
+                let request = self.get_item_type_information(type_id);
+
+                async move { request.send().await.map(|response| response.data) }
+            },
+        )
+        .await;
+
+        let item_types = match &result {
+            Ok(item_types) => item_types,
+            Err(partial) => partial.successes(),
+        };
+
+        self.client
+            .name_cache()
+            .insert(item_types.iter().map(|item_type| UniverseName {
+                category: UniverseNameCategory::InventoryType,
+                id: item_type.type_id,
+                name: item_type.name.clone(),
+            }));
+
+        let into_map = |item_types: Vec<ItemType>| {
+            item_types
+                .into_iter()
+                .map(|item_type| (item_type.type_id, item_type))
+                .collect()
+        };
+
+        result
+            .map(into_map)
+            .map_err(|partial| partial.map(into_map))
+    }
+
+    /// Retrieves the information of every distinct item type referenced by a list of items
+    ///
+    /// Collects the type IDs of items such as assets, blueprints or market orders & retrieves them
+    /// with [`Self::get_item_types`].
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `items` (`&[impl HasTypeId]`): The items to retrieve the item types of, see [`HasTypeId`]
+    ///
+    /// # Returns
+    /// A map of each item type ID to its information, or a [`CompositeError`] with the item types
+    /// retrieved & the error of each type ID which failed if any of the requests fail.
+    pub async fn get_item_types_for(
+        &self,
+        items: &[impl HasTypeId],
+    ) -> Result<HashMap<i64, ItemType>, CompositeError<HashMap<i64, ItemType>>> {
+        let type_ids = items.iter().map(HasTypeId::type_id).collect();
+
+        self.get_item_types(type_ids).await
+    }
+
+    /// Retrieves all NPC factions mapped by faction ID, cached on the client
+    ///
+    /// Factions are static data, so they are requested with [`Self::get_factions`] only on the first
+    /// call & shared by all clones of the client afterwards. Failed requests are not cached. The
+    /// names of the factions are added to the client's [`NameCache`](crate::esi::NameCache).
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Returns
+    /// A map of each faction ID to its information, or an [`Error`] if the request fails.
+    pub async fn get_faction_lookup(&self) -> Result<Arc<HashMap<i64, Faction>>, Error> {
+        let factions = self
+            .client
+            .inner
+            .factions
+            .get_or_try_init(|| async {
+                let factions = self.get_factions().send().await?.data;
+
+                self.client
+                    .name_cache()
+                    .insert(factions.iter().map(|faction| UniverseName {
+                        category: UniverseNameCategory::Faction,
+                        id: faction.faction_id,
+                        name: faction.name.clone(),
+                    }));
+
+                Ok::<_, Error>(Arc::new(
+                    factions
+                        .into_iter()
+                        .map(|faction| (faction.faction_id, faction))
+                        .collect(),
+                ))
+            })
+            .await?;
+
+        Ok(Arc::clone(factions))
+    }
+
+    /// Retrieves an NPC faction by ID using the faction lookup cached on the client
+    ///
+    /// See [`Self::get_faction_lookup`] for how factions are cached.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `faction_id` (`i64`): The ID of the faction
+    ///
+    /// # Returns
+    /// The faction's information, `None` if no faction has the ID, or an [`Error`] if the factions
+    /// could not be retrieved.
+    pub async fn get_faction(&self, faction_id: i64) -> Result<Option<Faction>, Error> {
+        Ok(self.get_faction_lookup().await?.get(&faction_id).cloned())
+    }
+
+    /// Retrieves static information on the provided item types, preferring the loaded SDE over ESI
+    ///
+    /// Item types found in `sde` are returned without making requests, the remaining types are
+    /// requested with [`Self::get_item_type_information`] concurrently, up to 10 at a time. A failed
+    /// request doesn't stop the remaining types from being requested, see [`CompositeError`].
+    ///
+    /// Requires the `sde` feature. For an overview & usage examples, see the
+    /// [sde module documentation](crate::sde)
+    ///
+    /// # Arguments
+    /// - `sde`      (`&StaticData`): Item types loaded from the Static Data Export
+    /// - `type_ids` (`Vec<i64>`): The IDs of the item types to retrieve
+    ///
+    /// # Returns
+    /// A map of each item type ID to its static information, or a [`CompositeError`] with the types
+    /// found in the SDE or retrieved & the error of each type ID which failed if any of the requests
+    /// for types missing from the SDE fail.
+    #[cfg(feature = "sde")]
+    pub async fn get_static_types(
+        &self,
+        sde: &StaticData,
+        mut type_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, StaticType>, CompositeError<HashMap<i64, StaticType>>> {
+        type_ids.sort_unstable();
+        type_ids.dedup();
+
+        let mut types = HashMap::with_capacity(type_ids.len());
+        let mut missing = Vec::new();
+
+        for type_id in type_ids {
+            match sde.get(type_id) {
+                Some(static_type) => {
+                    types.insert(type_id, static_type.clone());
+                }
+                None => missing.push(type_id),
+            }
+        }
+
+        if !missing.is_empty() {
+            log::debug!(
+                "Requesting {} item types missing from the SDE from ESI",
+                missing.len()
+            );
+        }
+
+        let requested = join_limited_partial(
+            self.client,
+            missing,
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |type_id| *type_id,
+            |type_id| {
+                let request = self.get_item_type_information(type_id);
+
+                async move { request.send().await.map(|response| response.data) }
+            },
+        )
+        .await;
+
+        let with_requested = move |requested: Vec<ItemType>| {
+            types.extend(
+                requested
+                    .into_iter()
+                    .map(|item_type| (item_type.type_id, StaticType::from(item_type))),
+            );
+
+            types
+        };
+
+        match requested {
+            Ok(requested) => Ok(with_requested(requested)),
+            Err(partial) => Err(partial.map(with_requested)),
+        }
+    }
 }
@@ -3,7 +3,22 @@
 //! This module provides the [`WalletEndpoints`] struct and associated methods for accessing
 //! wallet-related ESI endpoints.
 
-use crate::Client;
+use chrono::{DateTime, Utc};
+
+use crate::constant::DEFAULT_COMPOSITE_CONCURRENCY;
+use crate::error::CompositeError;
+use crate::esi::{join_limited_partial, AccessToken, EsiApi, EsiRequest};
+#[cfg(feature = "corporation")]
+use crate::model::wallet::NamedCorporationWallet;
+#[cfg(feature = "universe")]
+use crate::model::wallet::{accounting_records, AccountingRecord};
+use crate::model::wallet::{
+    link_transactions, summarize_income, CharacterIncome, CorporationWallet,
+    LinkedWalletTransaction, WalletJournalEntry, WalletTransaction,
+};
+use crate::scope::WalletScopes;
+use crate::{Client, Error, ScopeBuilder};
+use reqwest::Method;
 
 /// Provides methods for accessing wallet-related endpoints of the EVE Online ESI API.
 ///
@@ -20,4 +35,542 @@ impl<'a> WalletEndpoints<'a> {
     pub(super) fn new(client: &'a Client) -> Self {
         Self { client }
     }
+
+    define_esi_endpoint! {
+        /// Get paginated wallet journal of the provided character ID going 30 days back
+        ///
+        /// Entries are ordered from newest to oldest, use [`Self::sync_character_wallet_journal`]
+        /// to retrieve only the entries added since the last retrieved entry.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdWalletJournal>
+        ///
+        /// # Required Scopes
+        /// - [`WalletScopes::read_character_wallets`](crate::scope::WalletScopes::read_character_wallets):
+        ///   `esi-wallet.read_character_wallet.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to retrieve the wallet journal for
+        /// - `page`         (`i32`): The page of journal entries to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated list of wallet journal entries when sent.
+        auth fn get_character_wallet_journal(
+            access_token: impl Into<AccessToken>,
+            character_id: i64;
+            page: i32
+        ) -> EsiRequest<Vec<WalletJournalEntry>>
+        method = Method::GET;
+        path = "/characters/{}/wallet/journal";
+        required_scopes = ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_character_wallets())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Get paginated wallet journal of the provided corporation wallet division going 30 days back
+        ///
+        /// Entries are ordered from newest to oldest, use [`Self::sync_corporation_wallet_journal`]
+        /// to retrieve only the entries added since the last retrieved entry.
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Accountant`
+        /// or `Junior_Accountant` role within the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdWalletsDivisionJournal>
+        ///
+        /// # Required Scopes
+        /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+        ///   `esi-wallet.read_corporation_wallets.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation to retrieve the wallet journal for
+        /// - `division`       (`i32`): The wallet division (1-7), 1 being the master wallet
+        /// - `page`           (`i32`): The page of journal entries to retrieve, page numbers start at `1`
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a paginated list of wallet journal entries when sent.
+        auth fn get_corporation_wallet_journal(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64,
+            division: i32;
+            page: i32
+        ) -> EsiRequest<Vec<WalletJournalEntry>>
+        method = Method::GET;
+        path = "/corporations/{}/wallets/{}/journal";
+        required_scopes = ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_corporation_wallets())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Get up to 2,500 wallet transactions of the provided character ID going 30 days back
+        ///
+        /// Transactions are ordered from newest to oldest. Unlike the wallet journal this endpoint is
+        /// not paginated, provide the lowest transaction ID previously retrieved as `from_id` to
+        /// retrieve the next older transactions.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdWalletTransactions>
+        ///
+        /// # Required Scopes
+        /// - [`WalletScopes::read_character_wallets`](crate::scope::WalletScopes::read_character_wallets):
+        ///   `esi-wallet.read_character_wallet.v1`
+        ///
+        /// # Arguments
+        /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `character_id` (`i64`): The ID of the character to retrieve the wallet transactions for
+        /// - `from_id`      (`Option<i64>`): Only return transactions older than this transaction ID, `None` for the newest transactions
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of wallet transactions when sent.
+        auth fn get_character_wallet_transactions(
+            access_token: impl Into<AccessToken>,
+            character_id: i64;
+            from_id: Option<i64>
+        ) -> EsiRequest<Vec<WalletTransaction>>
+        method = Method::GET;
+        path = "/characters/{}/wallet/transactions";
+        required_scopes = ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_character_wallets())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Get up to 2,500 wallet transactions of the provided corporation wallet division going 30 days back
+        ///
+        /// Transactions are ordered from newest to oldest, see [`Self::get_character_wallet_transactions`].
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Accountant`
+        /// or `Junior_Accountant` role within the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdWalletsDivisionTransactions>
+        ///
+        /// # Required Scopes
+        /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+        ///   `esi-wallet.read_corporation_wallets.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation to retrieve the wallet transactions for
+        /// - `division`       (`i32`): The wallet division (1-7), 1 being the master wallet
+        /// - `from_id`        (`Option<i64>`): Only return transactions older than this transaction ID, `None` for the newest transactions
+        ///
+        /// # Returns
+        /// An ESI request builder that returns a list of wallet transactions when sent.
+        auth fn get_corporation_wallet_transactions(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64,
+            division: i32;
+            from_id: Option<i64>
+        ) -> EsiRequest<Vec<WalletTransaction>>
+        method = Method::GET;
+        path = "/corporations/{}/wallets/{}/transactions";
+        required_scopes = ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_corporation_wallets())
+            .build();
+    }
+
+    define_esi_endpoint! {
+        /// Retrieves the balance of each wallet division of the provided corporation ID
+        ///
+        /// Additional permissions required: the owner of the access token must hold the `Accountant`
+        /// or `Junior_Accountant` role within the corporation to access this information.
+        ///
+        /// For an overview & usage examples, see the [endpoints module documentation](super)
+        ///
+        /// # ESI Documentation
+        /// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdWallets>
+        ///
+        /// # Required Scopes
+        /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+        ///   `esi-wallet.read_corporation_wallets.v1`
+        ///
+        /// # Arguments
+        /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+        /// - `corporation_id` (`i64`): The ID of the corporation to retrieve the wallet balances for
+        ///
+        /// # Returns
+        /// An ESI request builder that returns the balance of each wallet division when sent.
+        auth fn get_corporation_wallets(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64
+        ) -> EsiRequest<Vec<CorporationWallet>>
+        method = Method::GET;
+        path = "/corporations/{}/wallets";
+        required_scopes = ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_corporation_wallets())
+            .build();
+    }
+
+    /// Retrieves the balance & name of each wallet division of a corporation
+    ///
+    /// Requests [`Self::get_corporation_wallets`] & [`CorporationEndpoints::get_corporation_divisions`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_divisions),
+    /// naming unnamed divisions with
+    /// [`CorporationDivisions::wallet_names`](crate::model::corporation::CorporationDivisions::wallet_names).
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `Accountant`
+    /// or `Junior_Accountant` role within the corporation to access this information.
+    ///
+    /// Requires the `corporation` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+    ///   `esi-wallet.read_corporation_wallets.v1`
+    /// - [`CorporationsScopes::read_divisions`](crate::scope::CorporationsScopes::read_divisions):
+    ///   `esi-corporations.read_divisions.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id` (`i64`): The ID of the corporation to retrieve the wallets for
+    ///
+    /// # Returns
+    /// The wallet divisions ordered by division number, or an [`Error`] if any of the requests fail.
+    #[cfg(feature = "corporation")]
+    pub async fn get_named_corporation_wallets(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+    ) -> Result<Vec<NamedCorporationWallet>, Error> {
+        let access_token = access_token.into();
+
+        let mut wallets = self
+            .get_corporation_wallets(&access_token, corporation_id)
+            .send()
+            .await?
+            .data;
+        let mut names = self
+            .client
+            .corporation()
+            .get_corporation_divisions(&access_token, corporation_id)
+            .send()
+            .await?
+            .data
+            .wallet_names();
+
+        wallets.sort_by_key(|wallet| wallet.division);
+
+        let wallets = wallets
+            .into_iter()
+            .map(|wallet| NamedCorporationWallet {
+                division: wallet.division,
+                name: names
+                    .remove(&wallet.division)
+                    .unwrap_or_else(|| format!("Wallet Division {}", wallet.division)),
+                balance: wallet.balance,
+            })
+            .collect();
+
+        Ok(wallets)
+    }
+
+    /// Retrieves the character wallet journal entries added since the provided reference ID
+    ///
+    /// Journal reference IDs are unique & increase with each new entry, so pages of
+    /// [`Self::get_character_wallet_journal`] are requested from newest to oldest only until a page
+    /// containing an entry at or below `last_ref_id` is reached rather than fetching every page.
+    ///
+    /// Store the `id` of the first returned entry as the `last_ref_id` for the next sync.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_character_wallets`](crate::scope::WalletScopes::read_character_wallets):
+    ///   `esi-wallet.read_character_wallet.v1`
+    ///
+    /// # Arguments
+    /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id` (`i64`): The ID of the character to sync the wallet journal of
+    /// - `last_ref_id`  (`Option<i64>`): The highest reference ID previously retrieved, `None` to retrieve all entries
+    ///
+    /// # Returns
+    /// The journal entries newer than `last_ref_id` ordered from newest to oldest, or an [`Error`]
+    /// if any of the requests fail.
+    pub async fn sync_character_wallet_journal(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+        last_ref_id: Option<i64>,
+    ) -> Result<Vec<WalletJournalEntry>, Error> {
+        let request = self.get_character_wallet_journal(access_token, character_id, 1);
+
+        sync_journal(self.client, request, last_ref_id).await
+    }
+
+    /// Retrieves the corporation wallet division journal entries added since the provided reference ID
+    ///
+    /// Pages of [`Self::get_corporation_wallet_journal`] are requested from newest to oldest only until
+    /// a page containing an entry at or below `last_ref_id` is reached, see
+    /// [`Self::sync_character_wallet_journal`].
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `Accountant`
+    /// or `Junior_Accountant` role within the corporation to access this information.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+    ///   `esi-wallet.read_corporation_wallets.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id` (`i64`): The ID of the corporation to sync the wallet journal of
+    /// - `division`       (`i32`): The wallet division (1-7), 1 being the master wallet
+    /// - `last_ref_id`    (`Option<i64>`): The highest reference ID previously retrieved, `None` to retrieve all entries
+    ///
+    /// # Returns
+    /// The journal entries newer than `last_ref_id` ordered from newest to oldest, or an [`Error`]
+    /// if any of the requests fail.
+    pub async fn sync_corporation_wallet_journal(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+        division: i32,
+        last_ref_id: Option<i64>,
+    ) -> Result<Vec<WalletJournalEntry>, Error> {
+        let request =
+            self.get_corporation_wallet_journal(access_token, corporation_id, division, 1);
+
+        sync_journal(self.client, request, last_ref_id).await
+    }
+
+    /// Maps wallet journal entries to accounting records with the names of their parties
+    ///
+    /// Resolves the names of the first & second party of every entry in bulk with
+    /// [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names)
+    /// & maps the entries with [`accounting_records`]. Combine with
+    /// [`Self::sync_character_wallet_journal`] or [`Self::sync_corporation_wallet_journal`] to
+    /// export a wallet's journal for accounting, e.g. with the `export` feature's
+    /// [`to_csv`](crate::export::to_csv).
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Arguments
+    /// - `journal` (`&[WalletJournalEntry]`): The wallet journal entries to map
+    ///
+    /// # Returns
+    /// An accounting record for each journal entry in the order provided, or an [`Error`] if the
+    /// names could not be resolved.
+    #[cfg(feature = "universe")]
+    pub async fn get_accounting_records(
+        &self,
+        journal: &[WalletJournalEntry],
+    ) -> Result<Vec<AccountingRecord>, Error> {
+        let party_ids = journal
+            .iter()
+            .flat_map(|entry| [entry.first_party_id, entry.second_party_id])
+            .flatten()
+            .collect();
+
+        let names = self.client.universe().resolve_names(party_ids).await?;
+
+        Ok(accounting_records(journal, &names))
+    }
+
+    /// Retrieves the newest character wallet transactions joined with their wallet journal entries
+    ///
+    /// Requests [`Self::get_character_wallet_transactions`], then pages of
+    /// [`Self::get_character_wallet_journal`] from newest to oldest only until the entry of the oldest
+    /// transaction is reached, see [`link_transactions`].
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_character_wallets`](crate::scope::WalletScopes::read_character_wallets):
+    ///   `esi-wallet.read_character_wallet.v1`
+    ///
+    /// # Arguments
+    /// - `access_token` (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `character_id` (`i64`): The ID of the character to retrieve the wallet transactions for
+    ///
+    /// # Returns
+    /// The transactions ordered from newest to oldest with their journal entries, or an [`Error`]
+    /// if any of the requests fail.
+    pub async fn get_linked_character_wallet_transactions(
+        &self,
+        access_token: impl Into<AccessToken>,
+        character_id: i64,
+    ) -> Result<Vec<LinkedWalletTransaction>, Error> {
+        let access_token = access_token.into();
+
+        let transactions = self
+            .get_character_wallet_transactions(&access_token, character_id, None)
+            .send()
+            .await?
+            .data;
+        let request = self.get_character_wallet_journal(&access_token, character_id, 1);
+
+        link_journal(self.client, transactions, request).await
+    }
+
+    /// Retrieves the newest corporation wallet division transactions joined with their wallet journal entries
+    ///
+    /// Requests [`Self::get_corporation_wallet_transactions`], then pages of
+    /// [`Self::get_corporation_wallet_journal`] only until the entry of the oldest transaction is
+    /// reached, see [`Self::get_linked_character_wallet_transactions`].
+    ///
+    /// Additional permissions required: the owner of the access token must hold the `Accountant`
+    /// or `Junior_Accountant` role within the corporation to access this information.
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_corporation_wallets`](crate::scope::WalletScopes::read_corporation_wallets):
+    ///   `esi-wallet.read_corporation_wallets.v1`
+    ///
+    /// # Arguments
+    /// - `access_token`   (`impl Into<AccessToken>`): Access token used for authenticated ESI routes, see [`AccessToken`](crate::esi::AccessToken)
+    /// - `corporation_id` (`i64`): The ID of the corporation to retrieve the wallet transactions for
+    /// - `division`       (`i32`): The wallet division (1-7), 1 being the master wallet
+    ///
+    /// # Returns
+    /// The transactions ordered from newest to oldest with their journal entries, or an [`Error`]
+    /// if any of the requests fail.
+    pub async fn get_linked_corporation_wallet_transactions(
+        &self,
+        access_token: impl Into<AccessToken>,
+        corporation_id: i64,
+        division: i32,
+    ) -> Result<Vec<LinkedWalletTransaction>, Error> {
+        let access_token = access_token.into();
+
+        let transactions = self
+            .get_corporation_wallet_transactions(&access_token, corporation_id, division, None)
+            .send()
+            .await?
+            .data;
+        let request =
+            self.get_corporation_wallet_journal(&access_token, corporation_id, division, 1);
+
+        link_journal(self.client, transactions, request).await
+    }
+
+    /// Retrieves the bounty & mission income of corporation members within a date range
+    ///
+    /// Requests every page of [`Self::get_character_wallet_journal`] for each member with the
+    /// member's own access token, concurrently for up to 10 members at a time, & sums the income
+    /// with [`summarize_income`]. Compare the tax paid by each member against the corporation's
+    /// wallet journal to audit ratting & mission taxes.
+    ///
+    /// ESI only returns the last 30 days of a wallet journal, income before then is not included.
+    /// A member whose journal can't be retrieved, e.g. due to an expired access token, doesn't stop
+    /// the remaining members from being audited, see [`CompositeError`].
+    ///
+    /// For an overview & usage examples, see the [endpoints module documentation](super)
+    ///
+    /// # Required Scopes
+    /// - [`WalletScopes::read_character_wallets`](crate::scope::WalletScopes::read_character_wallets):
+    ///   `esi-wallet.read_character_wallet.v1`
+    ///
+    /// # Arguments
+    /// - `members` (`impl IntoIterator<Item = (i64, impl Into<AccessToken>)>`): IDs of the members
+    ///   to audit with an access token of each member
+    /// - `from`    (`DateTime<Utc>`): Start of the date range, inclusive
+    /// - `to`      (`DateTime<Utc>`): End of the date range, exclusive
+    ///
+    /// # Returns
+    /// The income of each member in the order provided, or a [`CompositeError`] with the income of
+    /// the members audited & the error of each character ID which failed if any of the requests fail.
+    pub async fn audit_member_income<T: Into<AccessToken>>(
+        &self,
+        members: impl IntoIterator<Item = (i64, T)>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CharacterIncome>, CompositeError<Vec<CharacterIncome>>> {
+        join_limited_partial(
+            self.client,
+            members,
+            DEFAULT_COMPOSITE_CONCURRENCY,
+            |(character_id, _)| *character_id,
+            |(character_id, access_token)| {
+                let request = self.get_character_wallet_journal(access_token, character_id, 1);
+
+                async move {
+                    let journal = sync_journal(self.client, request, None).await?;
+
+                    Ok(summarize_income(character_id, &journal, from, to))
+                }
+            },
+        )
+        .await
+    }
+}
+
+/// Requests the journal pages covering the provided transactions & links them
+///
+/// Journal reference IDs increase with each entry, so every referenced entry has been retrieved once
+/// a page containing an entry below the lowest referenced ID is reached.
+async fn link_journal(
+    client: &Client,
+    transactions: Vec<WalletTransaction>,
+    request: EsiRequest<Vec<WalletJournalEntry>>,
+) -> Result<Vec<LinkedWalletTransaction>, Error> {
+    let Some(oldest_ref_id) = transactions.iter().map(|t| t.journal_ref_id).min() else {
+        return Ok(Vec::new());
+    };
+
+    let journal = sync_journal(client, request, Some(oldest_ref_id - 1)).await?;
+
+    Ok(link_transactions(transactions, journal))
+}
+
+/// Requests pages of a wallet journal until an entry at or below `last_ref_id` is reached
+///
+/// Entries may shift onto the next page as new entries are added between page requests,
+/// duplicates are removed by reference ID.
+async fn sync_journal(
+    client: &Client,
+    request: EsiRequest<Vec<WalletJournalEntry>>,
+    last_ref_id: Option<i64>,
+) -> Result<Vec<WalletJournalEntry>, Error> {
+    let esi = client.esi();
+    let is_new = |entry: &WalletJournalEntry| last_ref_id.is_none_or(|last| entry.id > last);
+
+    let mut request = request.with_page(1);
+    let (response, headers) = esi.request_with_headers(&request).await?;
+    let pages = EsiApi::extract_pages(&headers).unwrap_or(1);
+
+    let mut entries = Vec::new();
+    let mut page_entries = response.data;
+    let mut page = 1;
+
+    loop {
+        let reached_known = page_entries.iter().any(|entry| !is_new(entry));
+        entries.extend(page_entries.into_iter().filter(is_new));
+
+        if reached_known || page >= pages {
+            break;
+        }
+
+        page += 1;
+        request = request.with_page(page);
+        page_entries = esi.request(&request).await?.data;
+    }
+
+    log::debug!(
+        "Synced {} new wallet journal entries from {} of {} pages",
+        entries.len(),
+        page,
+        pages
+    );
+
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.id));
+    entries.dedup_by_key(|entry| entry.id);
+
+    Ok(entries)
 }
@@ -0,0 +1,252 @@
+//! # Partial Failures of Composite Helpers
+//!
+//! Composite helpers such as
+//! [`UniverseEndpoints::get_item_types`](crate::endpoints::universe::UniverseEndpoints::get_item_types)
+//! make a request per ID. Rather than discarding every result when one request fails, they
+//! continue with the remaining IDs & return a [`CompositeError`] holding the data which was
+//! retrieved alongside the error of each ID which failed.
+//!
+//! [`CompositeError`] converts into the [`Error`] of its first failure, so `?` keeps
+//! all-or-nothing handling. [`CompositeError::escalate`] accepts the partial data unless the share
+//! of failed requests exceeds a threshold.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let item_types = match esi_client.universe().get_item_types(vec![34, 35, 36]).await {
+//!     Ok(item_types) => item_types,
+//!     Err(partial) => {
+//!         for (type_id, error) in partial.failures() {
+//!             eprintln!("Failed to retrieve item type {}: {}", type_id, error);
+//!         }
+//!
+//!         // Use the item types retrieved unless more than 10% of the requests failed
+//!         partial.escalate(0.1)?
+//!     }
+//! };
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use super::Error;
+
+/// Data retrieved by a composite helper alongside the requests which failed
+///
+/// `T` is the data the helper returns when every request succeeds, holding only the data of the
+/// successful requests, & `K` is the key of each request such as the ID requested.
+///
+/// For an overview & usage example, see the [module-level documentation](self)
+#[derive(Debug)]
+pub struct CompositeError<T, K = i64> {
+    successes: T,
+    failures: Vec<(K, Error)>,
+    attempted: usize,
+}
+
+impl<T, K> CompositeError<T, K> {
+    /// Creates a [`CompositeError`] if any request failed
+    ///
+    /// # Arguments
+    /// - `successes` (`T`): The data of the requests which succeeded
+    /// - `failures`  (`Vec<(K, Error)>`): The key of each request which failed with its error
+    /// - `attempted` (`usize`): The number of requests attempted, including those which failed
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The data if no request failed
+    /// - `Err(CompositeError)`: The data & failures if any request failed
+    pub(crate) fn check(
+        successes: T,
+        failures: Vec<(K, Error)>,
+        attempted: usize,
+    ) -> Result<T, Self> {
+        if failures.is_empty() {
+            return Ok(successes);
+        }
+
+        Err(Self {
+            successes,
+            failures,
+            attempted,
+        })
+    }
+
+    /// Creates a [`CompositeError`] for a request which failed before the others could be attempted
+    ///
+    /// # Arguments
+    /// - `successes` (`T`): The data retrieved before the request failed, usually empty
+    /// - `key` (`K`): The key of the request which failed
+    /// - `error` ([`Error`]): The error of the request
+    #[cfg(any(
+        feature = "market",
+        all(feature = "contracts", feature = "routes", feature = "universe")
+    ))]
+    pub(crate) fn failed(successes: T, key: K, error: Error) -> Self {
+        Self {
+            successes,
+            failures: vec![(key, error)],
+            attempted: 1,
+        }
+    }
+
+    /// Returns the data of the requests which succeeded
+    pub fn successes(&self) -> &T {
+        &self.successes
+    }
+
+    /// Returns the key of each request which failed with its error, in the order the keys were provided
+    pub fn failures(&self) -> &[(K, Error)] {
+        &self.failures
+    }
+
+    /// Returns the number of requests attempted, including those which failed
+    pub fn attempted(&self) -> usize {
+        self.attempted
+    }
+
+    /// Returns the data of the requests which succeeded, discarding the failures
+    pub fn into_successes(self) -> T {
+        self.successes
+    }
+
+    /// Splits into the data of the requests which succeeded, the failures & the number attempted
+    #[cfg(all(
+        feature = "character",
+        feature = "corporation",
+        feature = "search",
+        feature = "universe"
+    ))]
+    pub(crate) fn into_parts(self) -> (T, Vec<(K, Error)>, usize) {
+        (self.successes, self.failures, self.attempted)
+    }
+
+    /// Returns the share of requests which failed, from `0.0` to `1.0`
+    pub fn failure_ratio(&self) -> f64 {
+        if self.attempted == 0 {
+            return 0.0;
+        }
+
+        self.failures.len() as f64 / self.attempted as f64
+    }
+
+    /// Returns the partial data if the share of failed requests doesn't exceed `max_failure_ratio`
+    ///
+    /// # Arguments
+    /// - `max_failure_ratio` (`f64`): The highest share of failed requests, from `0.0` to `1.0`,
+    ///   for which the partial data is accepted
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The data of the successful requests
+    /// - `Err(Error)`: The error of the first failed request if too many requests failed
+    pub fn escalate(self, max_failure_ratio: f64) -> Result<T, Error> {
+        if self.failure_ratio() > max_failure_ratio {
+            return Err(self.into());
+        }
+
+        Ok(self.successes)
+    }
+
+    /// Converts the data of the successful requests, keeping the failures
+    ///
+    /// # Arguments
+    /// - `f` (`FnOnce(T) -> U`): Converts the data, e.g. collecting a list into a map
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> CompositeError<U, K> {
+        CompositeError {
+            successes: f(self.successes),
+            failures: self.failures,
+            attempted: self.attempted,
+        }
+    }
+}
+
+impl<T, K> From<CompositeError<T, K>> for Error {
+    /// Converts into the error of the first failed request
+    fn from(error: CompositeError<T, K>) -> Self {
+        error
+            .failures
+            .into_iter()
+            .next()
+            .map(|(_, error)| error)
+            .expect("CompositeError always holds at least one failure")
+    }
+}
+
+impl<T, K: fmt::Debug> fmt::Display for CompositeError<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} requests failed",
+            self.failures.len(),
+            self.attempted
+        )?;
+
+        if let Some((key, error)) = self.failures.first() {
+            write!(f, ", first failure for {:?}: {}", key, error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug, K: fmt::Debug> std::error::Error for CompositeError<T, K> {}
+
+#[cfg(test)]
+mod composite_error_tests {
+    use super::*;
+
+    /// Creates a composite error with 1 failure out of the provided number of attempts
+    fn mock_composite_error(attempted: usize) -> CompositeError<Vec<i64>> {
+        let failures = vec![(35, Error::IoError(std::io::Error::other("request failed")))];
+
+        CompositeError::check(vec![34], failures, attempted).unwrap_err()
+    }
+
+    /// Tests escalating partial data depending on the share of failed requests
+    ///
+    /// # Assertions
+    /// - Assert no error is created without failures
+    /// - Assert partial data is returned when the failure ratio is at most the threshold
+    /// - Assert the first failure is returned when the failure ratio exceeds the threshold
+    #[test]
+    fn test_escalate() {
+        // Assert no error is created without failures
+        let result = CompositeError::<_, i64>::check(vec![34], Vec::new(), 1);
+        assert_eq!(result.unwrap(), vec![34]);
+
+        let partial = mock_composite_error(10);
+        assert_eq!(partial.failure_ratio(), 0.1);
+
+        // Assert partial data is returned when the failure ratio is at most the threshold
+        assert_eq!(partial.escalate(0.1).unwrap(), vec![34]);
+
+        // Assert the first failure is returned when the failure ratio exceeds the threshold
+        let result = mock_composite_error(2).escalate(0.1);
+        assert!(matches!(result, Err(Error::IoError(_))));
+    }
+
+    /// Tests converting the successes keeps the failures
+    ///
+    /// # Assertions
+    /// - Assert the successes are converted
+    /// - Assert the failures & attempts are kept
+    /// - Assert the message lists the failures & the first failed key
+    #[test]
+    fn test_map() {
+        let partial = mock_composite_error(2).map(|successes| successes.len());
+
+        // Assert the successes are converted
+        assert_eq!(*partial.successes(), 1);
+
+        // Assert the failures & attempts are kept
+        assert_eq!(partial.failures()[0].0, 35);
+        assert_eq!(partial.attempted(), 2);
+
+        // Assert the message lists the failures & the first failed key
+        assert_eq!(
+            partial.to_string(),
+            "1 of 2 requests failed, first failure for 35: request failed"
+        );
+    }
+}
@@ -169,4 +169,49 @@ pub enum ConfigError {
             is between 0 and 100."
     )]
     InvalidBackgroundRefreshThreshold,
+
+    /// The maximum number of concurrent ESI requests is set to 0
+    ///
+    /// This error occurs when the maximum number of ESI requests in flight at once is
+    /// configured to 0, which would queue every request forever.
+    ///
+    /// # Resolution
+    /// To fix this:
+    /// - Set a maximum of at least 1 using
+    ///   `config_builder.esi_max_concurrent_requests(max)`
+    /// - Leave the maximum unset to not limit concurrent requests
+    #[error(
+        "Invalid maximum concurrent ESI requests:\n\
+        \n\
+        To fix this:\n\
+          - Set a maximum of at least 1 using\n\
+            `config_builder.esi_max_concurrent_requests(max)`\n\
+          - Leave the maximum unset to not limit concurrent requests"
+    )]
+    InvalidMaxConcurrentRequests,
+
+    /// The user agent does not contain contact information
+    ///
+    /// This error occurs when strict user agent validation is enabled using
+    /// `esi_client_builder.strict_user_agent(true)` and the user agent is missing or
+    /// does not include an email address or URL.
+    ///
+    /// # Resolution
+    /// To fix this:
+    /// - Include an email address or URL in the user agent set using
+    ///   `esi_client_builder.user_agent(user_agent)`
+    /// - Format the user agent with [`UserAgent::new`](crate::builder::UserAgent::new)
+    ///
+    ///   e.g. `MyApp/1.0 (contact@example.com; +https://github.com/your/repository)`
+    #[error(
+        "Invalid ESI user agent:\n\
+        \n\
+        To fix this:\n\
+          - Include an email address or URL in the user agent set using\n\
+            `esi_client_builder.user_agent(user_agent)`\n\
+            e.g. MyApp/1.0 (contact@example.com; +https://github.com/your/repository)\n\
+        \n\
+        EVE Online requires user agents to contain contact information."
+    )]
+    InvalidUserAgent,
 }
@@ -15,6 +15,9 @@
 //!
 //! See the documentation for [`enum@Error`] and [`ConfigError`] for more details on each error variant.
 //!
+//! Composite helpers which make a request per ID return a [`CompositeError`] holding the data
+//! retrieved when only some of the requests fail, see the [`composite`] module.
+//!
 //! ## Usage Example
 //!
 //! ```rust
@@ -43,12 +46,15 @@
 //! }
 //! ```
 
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
+pub mod composite;
 pub mod config;
 pub mod oauth2;
 pub mod response;
 
+pub use composite::CompositeError;
 pub use config::ConfigError;
 pub use oauth2::OAuthError;
 pub use response::EsiError;
@@ -76,6 +82,62 @@ pub enum Error {
     /// Contains the error message from ESI along with cache and rate limit headers.
     #[error("ESI API error: {0}")]
     EsiError(#[from] Box<EsiError>),
+    /// ESI returned a 420 response as the application has exceeded the ESI error limit
+    ///
+    /// All ESI requests will be rejected until the error limit window resets, further requests
+    /// before then extend the ban. Enable [`ConfigBuilder::esi_error_limit_cool_off`](crate::ConfigBuilder::esi_error_limit_cool_off)
+    /// to have the client automatically park subsequent requests until the reset time.
+    #[error("ESI error limit reached, requests are blocked until {reset_at}")]
+    ErrorLimited {
+        /// Time at which the error limit window resets & requests may resume
+        reset_at: DateTime<Utc>,
+        /// The 420 error response returned by ESI
+        error: Box<EsiError>,
+    },
+    /// A request was not started by a bounded concurrency helper as too few ESI errors remained
+    ///
+    /// Returned by [`try_join_limited`](crate::esi::concurrency::try_join_limited), or for each item
+    /// not started by [`join_limited_partial`](crate::esi::concurrency::join_limited_partial) & the
+    /// composite helpers built on it, when the `X-ESI-Error-Limit-Remain` header of the client's most recent
+    /// response is below [`ConfigBuilder::esi_error_limit_threshold`](crate::ConfigBuilder::esi_error_limit_threshold),
+    /// preventing a batch of requests from driving the client into a 420. Enable
+    /// [`ConfigBuilder::esi_error_limit_cool_off`](crate::ConfigBuilder::esi_error_limit_cool_off)
+    /// to wait for the error limit to reset instead.
+    #[error("Only {remaining} ESI errors remain until {reset_at}, request was not started")]
+    ErrorLimitThreshold {
+        /// Errors remaining in the current error limit window
+        remaining: u32,
+        /// Time at which the error limit window resets
+        reset_at: DateTime<Utc>,
+    },
+    /// ESI returned a 503 response with a `Retry-After` header, as it does during the daily downtime
+    ///
+    /// Distinguishes ESI being unavailable for scheduled maintenance from genuine outages, which
+    /// return [`Error::EsiError`], so schedulers can postpone work until `retry_at`. Failed requests
+    /// are retried after the `Retry-After` duration before this error is returned, see
+    /// [`ConfigBuilder::esi_max_retry_after`](crate::ConfigBuilder::esi_max_retry_after).
+    #[error("ESI is unavailable during downtime, retry at {retry_at}")]
+    Downtime {
+        /// Time after which ESI expects to be available again, from the `Retry-After` header
+        retry_at: DateTime<Utc>,
+        /// The 503 error response returned by ESI
+        error: Box<EsiError>,
+    },
+    /// The body of an ESI response exceeded the maximum response size
+    ///
+    /// See [`ConfigBuilder::esi_max_response_size`](crate::ConfigBuilder::esi_max_response_size) &
+    /// [`EsiRequest::with_max_response_size`](crate::EsiRequest::with_max_response_size).
+    #[error(
+        "ESI response for {method} {endpoint} exceeded the maximum response size of {limit} bytes"
+    )]
+    ResponseTooLarge {
+        /// HTTP method of the request
+        method: String,
+        /// URL of the ESI endpoint which was requested
+        endpoint: String,
+        /// The maximum response size in bytes which was exceeded
+        limit: u64,
+    },
     /// Errors that occur during HTTP requests.
     ///
     /// For a more detailed description, see [`reqwest::Error`].
@@ -89,6 +151,28 @@ pub enum Error {
     /// For a more detailed description, see [`url::ParseError`].
     #[error(transparent)]
     UrlParseError(#[from] url::ParseError),
+    /// The body of a successful ESI response could not be deserialized into the expected type
+    ///
+    /// This usually occurs when CCP changes the schema of a response before the crate is updated,
+    /// the raw body is included for debugging. Use [`EsiRequest::send_raw`](crate::EsiRequest::send_raw)
+    /// to keep operating by parsing the response manually.
+    #[error("Failed to deserialize ESI response for {method} {endpoint} at `{path}`: {error}")]
+    DeserializationError {
+        /// HTTP method of the request
+        method: String,
+        /// URL of the ESI endpoint which was requested
+        endpoint: String,
+        /// JSON path to the field which failed to deserialize, e.g. `[0].corporation_id`
+        ///
+        /// `.` indicates the root of the response, such as when a required field is missing
+        /// from the top-level object.
+        path: String,
+        /// The raw response body which failed to deserialize
+        body: String,
+        /// The underlying deserialization error
+        #[source]
+        error: serde_json::Error,
+    },
     /// Errors related to serializing or deserializing JSON data
     ///
     /// This would occur if there is an issue serializing a Rust type
@@ -97,4 +181,34 @@ pub enum Error {
     /// For a more detailed description, see [`serde_json::Error`].
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// Errors reading or writing files & writers
+    ///
+    /// This would occur if writing to the writer provided to
+    /// [`EsiRequest::send_to_writer`](crate::EsiRequest::send_to_writer) fails or a
+    /// [`FileResponseCache`](crate::FileResponseCache) fails to read or write an entry.
+    ///
+    /// For a more detailed description, see [`std::io::Error`].
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    /// Errors communicating with a Redis server
+    ///
+    /// This would occur if a Redis backed [`ResponseCache`](crate::ResponseCache) or
+    /// [`TokenStore`](crate::oauth2::store::TokenStore) fails to read or write an entry.
+    ///
+    /// For a more detailed description, see [`redis::RedisError`].
+    #[cfg(feature = "redis")]
+    #[error(transparent)]
+    RedisError(#[from] redis::RedisError),
+    /// No response was recorded for a request made while replaying a
+    /// [`Cassette`](crate::esi::cassette::Cassette)
+    ///
+    /// Record the cassette again to include the request.
+    #[cfg(feature = "record-replay")]
+    #[error("No recorded response in cassette for {method} {endpoint}")]
+    ReplayMissing {
+        /// HTTP method of the request
+        method: String,
+        /// URL of the ESI endpoint which was requested
+        endpoint: String,
+    },
 }
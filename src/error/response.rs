@@ -29,8 +29,87 @@ pub struct EsiError {
     /// Only present when the `x-esi-error-limit-group` header is included in the response.
     pub rate_limit: Option<RateLimitHeaders>,
 
-    /// Duration in seconds until tokens are replenished enough for another request
+    /// Duration to wait before retrying the request, from the `Retry-After` header
     ///
-    /// Only present on 429 (Too Many Requests) responses.
+    /// Only present when the header is included in the response, typically on 429 (Too Many Requests)
+    /// responses once rate limit tokens are replenished & 503 (Service Unavailable) responses.
     pub retry_after: Option<Duration>,
 }
+
+impl EsiError {
+    /// Returns whether the error was caused by the request, e.g. a 404 (Not Found) response
+    ///
+    /// Rate limited responses are client errors, see [`Self::is_rate_limited`].
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.status)
+    }
+
+    /// Returns whether ESI encountered an error processing the request, e.g. a 503 (Service Unavailable) response
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.status)
+    }
+
+    /// Returns whether the request was rejected due to the ESI error limit (420) or rate limit (429)
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == 420 || self.status == 429
+    }
+
+    /// Returns whether the same request may succeed if retried later
+    ///
+    /// Server errors & rate limited responses are retryable, these should be retried after waiting
+    /// for [`Self::retry_after`] if present. All other client errors will fail again if retried.
+    pub fn is_retryable(&self) -> bool {
+        self.is_server_error() || self.is_rate_limited()
+    }
+}
+
+#[cfg(test)]
+mod esi_error_tests {
+    use chrono::Utc;
+
+    use super::EsiError;
+    use crate::esi::CacheHeaders;
+
+    /// Creates a mock ESI error with the provided status code
+    fn mock_esi_error(status: u16) -> EsiError {
+        EsiError {
+            status,
+            message: String::from("error"),
+            cache: CacheHeaders {
+                cache_control: String::new(),
+                etag: String::new(),
+                last_modified: Utc::now(),
+            },
+            rate_limit: None,
+            retry_after: None,
+        }
+    }
+
+    /// Tests status classification helpers of [`EsiError`]
+    ///
+    /// # Assertions
+    /// - Assert 404 is a client error which is not retryable
+    /// - Assert 420 & 429 are rate limited & retryable
+    /// - Assert 5xx are server errors which are retryable
+    #[test]
+    fn test_esi_error_status_helpers() {
+        let not_found = mock_esi_error(404);
+        assert!(not_found.is_client_error());
+        assert!(!not_found.is_rate_limited());
+        assert!(!not_found.is_retryable());
+
+        for status in [420, 429] {
+            let rate_limited = mock_esi_error(status);
+            assert!(rate_limited.is_client_error());
+            assert!(rate_limited.is_rate_limited());
+            assert!(rate_limited.is_retryable());
+        }
+
+        for status in [500, 502, 503, 504] {
+            let server_error = mock_esi_error(status);
+            assert!(server_error.is_server_error());
+            assert!(!server_error.is_client_error());
+            assert!(server_error.is_retryable());
+        }
+    }
+}
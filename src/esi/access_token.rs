@@ -0,0 +1,111 @@
+//! Access token type for authenticated ESI requests.
+//!
+//! Wraps the OAuth2 access token secret so it is never printed in `Debug` output, which keeps
+//! tokens out of logs when requests or errors are formatted with `{:?}`. Authenticated endpoints
+//! accept any `impl Into<AccessToken>`, so `&str`, `String` & the [`oauth2::AccessToken`] returned
+//! by [`OAuth2Endpoints::get_token`](crate::oauth2::OAuth2Endpoints::get_token) can be passed directly.
+
+use std::fmt;
+
+/// OAuth2 access token used for authenticated ESI requests
+///
+/// The `Debug` implementation redacts the token, use [`AccessToken::secret`] to access the
+/// token contents when they are explicitly needed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AccessToken(String);
+
+impl AccessToken {
+    /// Creates a new [`AccessToken`] from the provided token secret
+    ///
+    /// # Arguments
+    /// - `token` (`impl Into<String>`): The OAuth2 access token secret
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    /// Returns the access token secret
+    pub fn secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AccessToken([redacted])")
+    }
+}
+
+impl From<&str> for AccessToken {
+    fn from(token: &str) -> Self {
+        Self::new(token)
+    }
+}
+
+impl From<String> for AccessToken {
+    fn from(token: String) -> Self {
+        Self(token)
+    }
+}
+
+impl From<&String> for AccessToken {
+    fn from(token: &String) -> Self {
+        Self::new(token.as_str())
+    }
+}
+
+impl From<&AccessToken> for AccessToken {
+    fn from(token: &AccessToken) -> Self {
+        token.clone()
+    }
+}
+
+impl From<oauth2::AccessToken> for AccessToken {
+    fn from(token: oauth2::AccessToken) -> Self {
+        Self(token.into_secret())
+    }
+}
+
+impl From<&oauth2::AccessToken> for AccessToken {
+    fn from(token: &oauth2::AccessToken) -> Self {
+        Self::new(token.secret().as_str())
+    }
+}
+
+#[cfg(test)]
+mod access_token_tests {
+    use super::*;
+
+    /// Tests that the access token is redacted from `Debug` output
+    ///
+    /// # Test Setup
+    /// - Create an access token from a `&str`
+    ///
+    /// # Assertions
+    /// - Assert the `Debug` output does not contain the token
+    /// - Assert the secret is still accessible
+    #[test]
+    fn test_access_token_debug_redacted() {
+        let token = AccessToken::from("secret_token_value");
+
+        let debug = format!("{:?}", token);
+
+        assert!(!debug.contains("secret_token_value"));
+        assert_eq!(debug, "AccessToken([redacted])");
+        assert_eq!(token.secret(), "secret_token_value");
+    }
+
+    /// Tests conversion from the `oauth2` crate's access token
+    ///
+    /// # Test Setup
+    /// - Create an [`oauth2::AccessToken`]
+    ///
+    /// # Assertions
+    /// - Assert the converted token has the same secret
+    #[test]
+    fn test_access_token_from_oauth2() {
+        let token = oauth2::AccessToken::new("secret_token_value".to_string());
+
+        assert_eq!(AccessToken::from(&token).secret(), "secret_token_value");
+        assert_eq!(AccessToken::from(token).secret(), "secret_token_value");
+    }
+}
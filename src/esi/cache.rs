@@ -0,0 +1,352 @@
+//! Response caches for conditional ESI requests.
+//!
+//! A [`ResponseCache`] stores the ETag & raw body of successful responses keyed by request URL.
+//! [`EsiRequest::send_with_cache`](crate::esi::EsiRequest::send_with_cache) sends the stored ETag
+//! with the `If-None-Match` header & returns the stored body when ESI responds with
+//! 304 Not Modified, saving bandwidth & deserialization of unchanged responses.
+//!
+//! Two implementations are provided:
+//! - [`MemoryResponseCache`]: Stores responses in memory for the lifetime of the process
+//! - [`FileResponseCache`]: Stores responses in a directory, allowing 304 responses to be reused
+//!   across process restarts, e.g. for CLI tools that run intermittently
+//! - `RedisResponseCache`: Stores responses in Redis, allowing horizontally scaled applications to
+//!   share cached responses, requires the `redis` feature
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::{Client, FileResponseCache};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct ServerStatus {
+//!     players: i32,
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new("MyApp/1.0 (contact@example.com)")?;
+//! let cache = FileResponseCache::new(".esi-cache");
+//!
+//! // Returns the cached body if ESI responds with 304 Not Modified
+//! let status = client
+//!     .esi()
+//!     .new_request::<ServerStatus>("/status/")
+//!     .send_with_cache(&cache)
+//!     .await?;
+//! println!("Players online: {}", status.players);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::Error;
+
+/// A cached ESI response body with the ETag it was returned with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    /// The ETag header of the cached response
+    pub etag: String,
+    /// The raw body of the cached response
+    pub body: Vec<u8>,
+}
+
+impl CacheEntry {
+    /// Encodes the entry as the ETag on the first line followed by the body
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut contents = Vec::with_capacity(self.etag.len() + 1 + self.body.len());
+        contents.extend_from_slice(self.etag.as_bytes());
+        contents.push(b'\n');
+        contents.extend_from_slice(&self.body);
+
+        contents
+    }
+
+    /// Decodes an entry encoded with [`CacheEntry::encode`]
+    ///
+    /// Returns `None` if the contents do not contain the ETag line.
+    pub(crate) fn decode(contents: &[u8]) -> Option<Self> {
+        let separator = contents.iter().position(|byte| *byte == b'\n')?;
+
+        Some(Self {
+            etag: String::from_utf8_lossy(&contents[..separator]).into_owned(),
+            body: contents[separator + 1..].to_vec(),
+        })
+    }
+}
+
+/// Storage for ESI response bodies & their ETags keyed by request URL
+///
+/// Implement this trait to store cached responses in a custom backend, see the
+/// [module documentation](crate::esi::cache) for the provided implementations.
+pub trait ResponseCache: Send + Sync {
+    /// Retrieves the cached entry for the provided URL
+    ///
+    /// # Arguments
+    /// - `url` (`&str`): The full URL of the request including query parameters
+    ///
+    /// # Returns
+    /// - `Ok(Some(CacheEntry))`: The cached ETag & body
+    /// - `Ok(None)`: No entry is cached for the URL
+    /// - `Err(Error)`: The cache could not be read
+    fn get(&self, url: &str) -> impl Future<Output = Result<Option<CacheEntry>, Error>> + Send;
+
+    /// Stores the entry for the provided URL, replacing any existing entry
+    ///
+    /// # Arguments
+    /// - `url` (`&str`): The full URL of the request including query parameters
+    /// - `entry` (`CacheEntry`): The ETag & body of the response
+    fn put(&self, url: &str, entry: CacheEntry) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+/// In-memory [`ResponseCache`] which retains entries for the lifetime of the process
+#[derive(Debug, Default)]
+pub struct MemoryResponseCache {
+    entries: RwLock<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryResponseCache {
+    /// Creates a new empty [`MemoryResponseCache`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached entries
+    pub fn len(&self) -> usize {
+        self.entries
+            .read()
+            .map(|entries| entries.len())
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if no entries are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ResponseCache for MemoryResponseCache {
+    async fn get(&self, url: &str) -> Result<Option<CacheEntry>, Error> {
+        Ok(self
+            .entries
+            .read()
+            .ok()
+            .and_then(|entries| entries.get(url).cloned()))
+    }
+
+    async fn put(&self, url: &str, entry: CacheEntry) -> Result<(), Error> {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.insert(url.to_string(), entry);
+        }
+
+        Ok(())
+    }
+}
+
+/// Filesystem [`ResponseCache`] storing each entry as a file within a directory
+///
+/// Entries are stored in files named after a hash of the request URL containing the ETag on the
+/// first line followed by the response body. The directory is created on the first write if it
+/// does not exist. Entries are written to a temporary file & renamed so that an interrupted
+/// process never leaves a partially written entry behind.
+#[derive(Debug, Clone)]
+pub struct FileResponseCache {
+    directory: PathBuf,
+}
+
+impl FileResponseCache {
+    /// Creates a new [`FileResponseCache`] storing entries in the provided directory
+    ///
+    /// # Arguments
+    /// - `directory` (`impl Into<PathBuf>`): The directory to store cached responses in
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Returns the directory cached responses are stored in
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the path of the file the entry for the provided URL is stored in
+    fn entry_path(&self, url: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.cache", url_hash(url)))
+    }
+}
+
+impl ResponseCache for FileResponseCache {
+    async fn get(&self, url: &str) -> Result<Option<CacheEntry>, Error> {
+        let contents = match tokio::fs::read(self.entry_path(url)).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let entry = CacheEntry::decode(&contents);
+
+        if entry.is_none() {
+            log::warn!("Ignoring malformed response cache entry for {}", url);
+        }
+
+        Ok(entry)
+    }
+
+    async fn put(&self, url: &str, entry: CacheEntry) -> Result<(), Error> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+
+        let path = self.entry_path(url);
+        let temp_path = path.with_extension("tmp");
+
+        tokio::fs::write(&temp_path, entry.encode()).await?;
+        tokio::fs::rename(&temp_path, &path).await?;
+
+        Ok(())
+    }
+}
+
+/// Redis [`ResponseCache`] allowing cached responses to be shared between application instances
+///
+/// Entries are stored as strings keyed by the request URL with a configurable key prefix,
+/// defaulting to `eve_esi:response:`. Set an expiry with [`RedisResponseCache::with_expiry`] to
+/// prevent entries for URLs which are no longer requested from accumulating.
+#[cfg(feature = "redis")]
+#[derive(Clone)]
+pub struct RedisResponseCache {
+    connection: redis::aio::MultiplexedConnection,
+    prefix: String,
+    expiry: Option<std::time::Duration>,
+}
+
+#[cfg(feature = "redis")]
+impl RedisResponseCache {
+    /// Creates a new [`RedisResponseCache`] using the provided connection
+    ///
+    /// # Arguments
+    /// - `connection` (`MultiplexedConnection`): Connection to the Redis server, cloned for each operation
+    pub fn new(connection: redis::aio::MultiplexedConnection) -> Self {
+        Self {
+            connection,
+            prefix: "eve_esi:response:".to_string(),
+            expiry: None,
+        }
+    }
+
+    /// Sets the prefix prepended to the request URL to create the Redis key of an entry
+    ///
+    /// # Arguments
+    /// - `prefix` (`impl Into<String>`): The key prefix, e.g. `myapp:esi:`
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the duration after which Redis removes an entry once it has been stored
+    ///
+    /// # Arguments
+    /// - `expiry` (`Duration`): How long entries are retained, rounded down to seconds
+    pub fn with_expiry(mut self, expiry: std::time::Duration) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+}
+
+#[cfg(feature = "redis")]
+impl ResponseCache for RedisResponseCache {
+    async fn get(&self, url: &str) -> Result<Option<CacheEntry>, Error> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let contents: Option<Vec<u8>> = connection.get(format!("{}{}", self.prefix, url)).await?;
+
+        Ok(contents.and_then(|contents| CacheEntry::decode(&contents)))
+    }
+
+    async fn put(&self, url: &str, entry: CacheEntry) -> Result<(), Error> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let key = format!("{}{}", self.prefix, url);
+
+        match self.expiry {
+            Some(expiry) => {
+                let _: () = connection
+                    .set_ex(key, entry.encode(), expiry.as_secs().max(1))
+                    .await?;
+            }
+            None => {
+                let _: () = connection.set(key, entry.encode()).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Hashes a URL with 64-bit FNV-1a
+///
+/// Used instead of [`std::hash::DefaultHasher`] as its output is not guaranteed to be stable
+/// across Rust releases, which would invalidate cache directories after upgrading.
+fn url_hash(url: &str) -> u64 {
+    url.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+
+    /// Tests that entries persist between file cache instances using the same directory
+    ///
+    /// # Test Setup
+    /// - Create a file cache in a temporary directory & store an entry
+    ///
+    /// # Assertions
+    /// - Assert a new cache instance for the same directory returns the stored entry
+    /// - Assert an unknown URL returns `None`
+    #[tokio::test]
+    async fn test_file_response_cache_persists() {
+        let directory = std::env::temp_dir().join(format!(
+            "eve_esi_cache_test_{}_{}",
+            std::process::id(),
+            url_hash("test_file_response_cache_persists")
+        ));
+        let entry = CacheEntry {
+            etag: "\"etag\"".to_string(),
+            body: b"{\"message\":\n\"cached\"}".to_vec(),
+        };
+
+        FileResponseCache::new(&directory)
+            .put("https://esi.evetech.net/status/", entry.clone())
+            .await
+            .unwrap();
+
+        let cache = FileResponseCache::new(&directory);
+        let cached = cache.get("https://esi.evetech.net/status/").await.unwrap();
+        let missing = cache
+            .get("https://esi.evetech.net/alliances/")
+            .await
+            .unwrap();
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(cached, Some(entry));
+        assert_eq!(missing, None);
+    }
+
+    /// Tests that the URL hash is stable
+    ///
+    /// # Assertions
+    /// - Assert the hash matches the known 64-bit FNV-1a value, entries of existing cache
+    ///   directories would no longer be found if it changed
+    #[test]
+    fn test_url_hash_stable() {
+        assert_eq!(url_hash(""), 0xcbf29ce484222325);
+        assert_eq!(url_hash("a"), 0xaf63dc4c8601ec8c);
+    }
+}
@@ -0,0 +1,301 @@
+//! Recording & replaying ESI responses for deterministic tests.
+//!
+//! A [`Cassette`] configured with [`ClientBuilder::cassette`](crate::ClientBuilder::cassette)
+//! either records every ESI request & response made by the [`Client`](crate::Client) to a JSON
+//! file, or replays previously recorded responses without making any HTTP requests. Record a
+//! cassette once against ESI, commit it alongside your tests & replay it to test against real
+//! response shapes without network access. Requires the `record-replay` feature.
+//!
+//! Only the method, URL & JSON body of requests are recorded, request headers such as the
+//! `Authorization` header are never written to the cassette. Responses are recorded with their
+//! status, headers & body. Requests are matched by method, URL & body; when the same request is
+//! made multiple times, recorded responses are replayed in the order they were recorded with the
+//! last response repeated once all have been replayed.
+//!
+//! Access tokens of authenticated requests are still validated before replaying unless disabled
+//! with [`ConfigBuilder::esi_validate_token_before_request`](crate::ConfigBuilder::esi_validate_token_before_request),
+//! as tokens used while recording will have expired by the time the cassette is replayed.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::{Cassette, Client};
+//!
+//! # async fn example() -> Result<(), eve_esi::Error> {
+//! // Record responses from ESI, the cassette file is written after each request
+//! let client = Client::builder()
+//!     .user_agent("MyApp/1.0 (contact@example.com)")
+//!     .cassette(Cassette::record("tests/cassettes/alliance.json"))
+//!     .build()?;
+//! client.alliance().get_alliance_information(99013534).send().await?;
+//!
+//! // Replay the recorded responses without making HTTP requests
+//! let client = Client::builder()
+//!     .user_agent("MyApp/1.0 (contact@example.com)")
+//!     .cassette(Cassette::replay("tests/cassettes/alliance.json")?)
+//!     .build()?;
+//! let alliance = client.alliance().get_alliance_information(99013534).send().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use crate::Error;
+
+/// A recorded ESI request & its response
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interaction {
+    /// HTTP method of the request
+    pub method: String,
+    /// Full URL of the request including query parameters
+    pub url: String,
+    /// JSON body of the request, if any
+    pub request_body: Option<Value>,
+    /// Status code of the response
+    pub status: u16,
+    /// Headers of the response in the order they were received
+    pub headers: Vec<(String, String)>,
+    /// Body of the response
+    pub body: String,
+}
+
+/// Whether a [`Cassette`] records new interactions or replays recorded ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Requests are sent to ESI & each interaction is appended to the cassette file
+    Record,
+    /// Requests are answered with recorded interactions without sending HTTP requests
+    Replay,
+}
+
+/// A file of recorded ESI interactions, see the [module documentation](self)
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+/// Interactions of a [`Cassette`] & how many times each was replayed
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    replayed: Vec<usize>,
+}
+
+impl Cassette {
+    /// Creates a cassette which records interactions to the provided file
+    ///
+    /// Any existing file is replaced once the first interaction is recorded.
+    ///
+    /// # Arguments
+    /// - `path` (`impl Into<PathBuf>`): The file to write recorded interactions to
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record,
+            state: Mutex::new(CassetteState::default()),
+        }
+    }
+
+    /// Loads a cassette which replays the interactions recorded in the provided file
+    ///
+    /// # Arguments
+    /// - `path` (`impl Into<PathBuf>`): The file interactions were recorded to
+    ///
+    /// # Errors
+    /// - [`Error::IoError`]: If the file could not be read
+    /// - [`Error::SerdeJsonError`]: If the file does not contain recorded interactions
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let interactions: Vec<Interaction> = serde_json::from_slice(&std::fs::read(&path)?)?;
+
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            state: Mutex::new(CassetteState {
+                replayed: vec![0; interactions.len()],
+                interactions,
+            }),
+        })
+    }
+
+    /// Returns the file the cassette records to or replays from
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns whether the cassette records or replays interactions
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Returns a copy of the interactions recorded or loaded by the cassette
+    pub async fn interactions(&self) -> Vec<Interaction> {
+        self.state.lock().await.interactions.clone()
+    }
+
+    /// Returns the recorded response for the provided request
+    ///
+    /// # Errors
+    /// - [`Error::ReplayMissing`]: If no interaction was recorded for the request
+    pub(crate) async fn replay_response(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<&Value>,
+    ) -> Result<reqwest::Response, Error> {
+        let mut state = self.state.lock().await;
+        let CassetteState {
+            interactions,
+            replayed,
+        } = &mut *state;
+
+        let matches = |interaction: &&Interaction| {
+            interaction.method == method.as_str()
+                && interaction.url == url
+                && interaction.request_body.as_ref() == body
+        };
+
+        // Replay the first interaction which has not been replayed yet, then repeat the last one
+        let index = interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| matches(interaction))
+            .map(|(index, _)| index)
+            .reduce(|found, index| if replayed[found] > 0 { index } else { found })
+            .ok_or_else(|| Error::ReplayMissing {
+                method: method.to_string(),
+                endpoint: url.to_string(),
+            })?;
+
+        replayed[index] += 1;
+        log::debug!("Replaying recorded response for {} {}", method, url);
+
+        interactions[index].to_response()
+    }
+
+    /// Records the interaction & returns an equivalent response with the consumed body
+    ///
+    /// # Errors
+    /// - [`Error::ReqwestError`]: If the response body could not be read
+    /// - [`Error::IoError`]: If the cassette file could not be written
+    pub(crate) async fn record_response(
+        &self,
+        method: &Method,
+        url: &str,
+        body: Option<&Value>,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, Error> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        let response_body = response.bytes().await?;
+
+        let interaction = Interaction {
+            method: method.to_string(),
+            url: url.to_string(),
+            request_body: body.cloned(),
+            status,
+            headers,
+            body: String::from_utf8_lossy(&response_body).into_owned(),
+        };
+        let response = interaction.to_response()?;
+
+        // Hold the lock while writing so concurrent recordings can't write an older snapshot last
+        let mut state = self.state.lock().await;
+        state.interactions.push(interaction);
+        state.replayed.push(0);
+
+        if let Some(directory) = self.path.parent() {
+            tokio::fs::create_dir_all(directory).await?;
+        }
+        let temp_path = self.path.with_extension("tmp");
+        tokio::fs::write(&temp_path, serde_json::to_vec_pretty(&state.interactions)?).await?;
+        tokio::fs::rename(&temp_path, &self.path).await?;
+
+        Ok(response)
+    }
+}
+
+impl Interaction {
+    /// Builds a response with the recorded status, headers & body
+    fn to_response(&self) -> Result<reqwest::Response, Error> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.body(self.body.clone()).map_err(|e| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Invalid recorded response for {}: {}", self.url, e),
+            )
+        })?;
+
+        Ok(reqwest::Response::from(response))
+    }
+}
+
+#[cfg(test)]
+mod cassette_tests {
+    use super::*;
+
+    fn mock_interaction(body: &str) -> Interaction {
+        Interaction {
+            method: "GET".to_string(),
+            url: "https://esi.evetech.net/status".to_string(),
+            request_body: None,
+            status: 200,
+            headers: vec![("etag".to_string(), "\"etag\"".to_string())],
+            body: body.to_string(),
+        }
+    }
+
+    /// Tests repeated requests replay recorded responses in order, repeating the last response
+    ///
+    /// # Test Setup
+    /// - Create a cassette with two interactions recorded for the same request
+    ///
+    /// # Assertions
+    /// - Assert the responses are replayed in the order they were recorded
+    /// - Assert the last response is repeated once all were replayed
+    /// - Assert a request which was not recorded returns an error
+    #[tokio::test]
+    async fn test_replay_order() {
+        let interactions = vec![mock_interaction("first"), mock_interaction("second")];
+        let cassette = Cassette {
+            path: PathBuf::from("cassette.json"),
+            mode: CassetteMode::Replay,
+            state: Mutex::new(CassetteState {
+                replayed: vec![0; interactions.len()],
+                interactions,
+            }),
+        };
+        let url = "https://esi.evetech.net/status";
+
+        for expected in ["first", "second", "second"] {
+            let response = cassette
+                .replay_response(&Method::GET, url, None)
+                .await
+                .unwrap();
+
+            assert_eq!(response.headers()["etag"], "\"etag\"");
+            assert_eq!(response.text().await.unwrap(), expected);
+        }
+
+        let result = cassette.replay_response(&Method::POST, url, None).await;
+        assert!(matches!(result, Err(Error::ReplayMissing { .. })));
+    }
+}
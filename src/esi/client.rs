@@ -21,14 +21,21 @@
 //! # }
 //! ```
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::de::DeserializeOwned;
 use std::time::Duration;
 
+use crate::constant::DEFAULT_ESI_ERROR_LIMIT_RESET;
 use crate::error::EsiError;
 use crate::{Client, Error};
 
-use super::{CacheHeaders, CachedResponse, EsiRequest, EsiResponse, RateLimitHeaders};
+use super::{
+    CacheEntry, CacheHeaders, CachedResponse, EsiRequest, EsiResponse, Idempotency,
+    MeasuredResponse, RateLimitHeaders, RawEsiResponse, RequestTiming, ResponseCache,
+};
+
+/// Maximum number of bytes of a response body which failed to deserialize included in debug logs
+const MAX_LOGGED_BODY_BYTES: usize = 1024;
 
 /// Provides utility methods for making requests to EVE Online's ESI endpoints.
 ///
@@ -168,13 +175,31 @@ impl<'a> EsiApi<'a> {
         }
     }
 
+    /// Extracts the `Retry-After` header, either a number of seconds or an HTTP date
+    ///
+    /// # Returns
+    /// The duration to wait before retrying, `None` if the header is missing or invalid
+    fn extract_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+        headers
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| match s.trim().parse::<u64>() {
+                Ok(seconds) => Some(Duration::from_secs(seconds)),
+                Err(_) => DateTime::parse_from_rfc2822(s.trim()).ok().map(|date| {
+                    (date.with_timezone(&Utc) - Utc::now())
+                        .to_std()
+                        .unwrap_or_default()
+                }),
+            })
+    }
+
     /// Handles ESI error responses by extracting error data and all relevant headers.
     ///
     /// This method processes 4xx and 5xx responses from ESI, extracting:
     /// - The error message from the response body
     /// - Cache headers (always present)
     /// - Rate limit headers (if x-esi-error-limit-group is present)
-    /// - Retry-After header (only on 429 responses)
+    /// - Retry-After header (if present, e.g. on 429 & 503 responses)
     ///
     /// # Arguments
     /// - `response`: The HTTP response with an error status code
@@ -195,12 +220,7 @@ impl<'a> EsiApi<'a> {
         let cache = Self::extract_cache_headers(&headers);
         let rate_limit = Self::extract_rate_limit_headers(&headers);
 
-        // Extract retry-after header (only on 429 responses)
-        let retry_after = headers
-            .get("retry-after")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|s| s.parse::<u64>().ok())
-            .map(Duration::from_secs);
+        let retry_after = Self::extract_retry_after(&headers);
 
         // Extract error message from response body
         let body = response.text().await.unwrap_or_else(|_| String::from("{}"));
@@ -231,6 +251,199 @@ impl<'a> EsiApi<'a> {
         }
     }
 
+    /// Converts an ESI error response into an [`Error`], handling 420 error limited responses.
+    ///
+    /// For 420 responses the error limit reset time is calculated from the `X-ESI-Error-Limit-Reset`
+    /// header and, if automatic cool-off is enabled, stored on the client so subsequent requests wait
+    /// until the error limit window has passed.
+    ///
+    /// # Arguments
+    /// - `response`: The HTTP response with an error status code
+    /// - `method`: The HTTP method used for the request (for logging)
+    /// - `endpoint`: The endpoint that was called (for logging)
+    ///
+    /// # Returns
+    /// - [`Error::ErrorLimited`]: If ESI responded with a 420 status code
+    /// - [`Error::Downtime`]: If ESI responded with a 503 status code & a `Retry-After` header
+    /// - [`Error::EsiError`]: For all other error responses
+    async fn error_from_response(
+        &self,
+        response: reqwest::Response,
+        method: &str,
+        endpoint: &str,
+    ) -> Error {
+        // Seconds until the error limit window resets
+        let error_limit_reset = response
+            .headers()
+            .get("x-esi-error-limit-reset")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<u64>().ok())
+            .map(Duration::from_secs);
+
+        let esi_error = Self::handle_esi_error_response(response, method, endpoint).await;
+
+        if esi_error.status == 503 {
+            if let Some(retry_after) = esi_error.retry_after {
+                let retry_at =
+                    Utc::now() + TimeDelta::from_std(retry_after).unwrap_or(TimeDelta::zero());
+
+                return Error::Downtime {
+                    retry_at,
+                    error: Box::new(esi_error),
+                };
+            }
+        }
+
+        if esi_error.status != 420 {
+            return Box::new(esi_error).into();
+        }
+
+        self.error_limited(
+            error_limit_reset.unwrap_or(DEFAULT_ESI_ERROR_LIMIT_RESET),
+            esi_error,
+        )
+    }
+
+    /// Creates an [`Error::ErrorLimited`] & parks subsequent requests if automatic cool-off is enabled.
+    ///
+    /// # Arguments
+    /// - `reset_in`: Time until the error limit window resets
+    /// - `esi_error`: The 420 error response
+    fn error_limited(&self, reset_in: Duration, esi_error: EsiError) -> Error {
+        let reset_at = Utc::now() + TimeDelta::from_std(reset_in).unwrap_or(TimeDelta::zero());
+
+        if self.client.inner.esi_error_limit_cool_off {
+            let mut error_limit_reset = self
+                .client
+                .inner
+                .esi_error_limit_reset
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+
+            // Keep the latest reset time if multiple requests were error limited
+            if error_limit_reset.is_none_or(|existing| existing < reset_at) {
+                *error_limit_reset = Some(reset_at);
+            }
+
+            log::warn!(
+                "ESI error limit reached, parking subsequent ESI requests until {}",
+                reset_at
+            );
+        }
+
+        Error::ErrorLimited {
+            reset_at,
+            error: Box::new(esi_error),
+        }
+    }
+
+    /// Stores the remaining errors & reset time of the ESI error limit from a response's headers.
+    ///
+    /// Checked by the bounded concurrency helpers before starting each request, see
+    /// [`Self::error_limit_remaining`].
+    fn record_error_limit(&self, headers: &reqwest::header::HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|s| s.parse::<u32>().ok())
+        };
+
+        let (Some(remaining), Some(reset_secs)) = (
+            header("x-esi-error-limit-remain"),
+            header("x-esi-error-limit-reset"),
+        ) else {
+            return;
+        };
+
+        let reset_at = Utc::now() + TimeDelta::seconds(i64::from(reset_secs));
+
+        *self
+            .client
+            .inner
+            .esi_error_limit_remain
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some((remaining, reset_at));
+    }
+
+    /// Returns the errors remaining in the current ESI error limit window & when it resets.
+    ///
+    /// Reported from the `X-ESI-Error-Limit-Remain` & `X-ESI-Error-Limit-Reset` headers of the most
+    /// recent response, `None` if no response has reported them or the window has since reset.
+    pub(crate) fn error_limit_remaining(&self) -> Option<(u32, DateTime<Utc>)> {
+        let error_limit = *self
+            .client
+            .inner
+            .esi_error_limit_remain
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        error_limit.filter(|(_, reset_at)| *reset_at > Utc::now())
+    }
+
+    /// Stores the rate limit headers of a response as the latest for its rate limit group.
+    ///
+    /// The stored headers are reported by [`Client::health`](crate::Client::health).
+    fn record_rate_limit(&self, headers: &reqwest::header::HeaderMap) {
+        let Some(rate_limit) = Self::extract_rate_limit_headers(headers) else {
+            return;
+        };
+
+        self.client
+            .inner
+            .esi_rate_limits
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(rate_limit.group.clone(), rate_limit);
+    }
+
+    /// Invokes the request's [`EsiRequest::on_etag`] callback with the ETag of a successful response.
+    fn notify_etag<T: DeserializeOwned>(request: &EsiRequest<T>, response: &reqwest::Response) {
+        let Some(callback) = request.etag_callback() else {
+            return;
+        };
+
+        if !response.status().is_success() {
+            return;
+        }
+
+        if let Some(etag) = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+        {
+            callback(etag);
+        }
+    }
+
+    /// Waits until the ESI error limit window has passed if the client is cooling off from a 420.
+    ///
+    /// Does nothing unless automatic cool-off is enabled and a 420 response was previously received
+    /// with a reset time which has not yet passed.
+    async fn wait_for_error_limit_reset(&self) {
+        if !self.client.inner.esi_error_limit_cool_off {
+            return;
+        }
+
+        let reset_at = *self
+            .client
+            .inner
+            .esi_error_limit_reset
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        if let Some(reset_at) = reset_at {
+            if let Ok(wait_time) = (reset_at - Utc::now()).to_std() {
+                log::warn!(
+                    "ESI error limited, waiting {}ms for error limit to reset before request",
+                    wait_time.as_millis()
+                );
+
+                tokio::time::sleep(wait_time).await;
+            }
+        }
+    }
+
     /// Internal method that executes the request with common logic.
     ///
     /// This consolidates all the shared request execution logic:
@@ -247,11 +460,35 @@ impl<'a> EsiApi<'a> {
         &self,
         request: &EsiRequest<T>,
     ) -> Result<reqwest::Response, Error> {
+        let (response, _) = self.execute_request_measured(request).await?;
+
+        Ok(response)
+    }
+
+    /// Executes the request, returning the raw response alongside the time spent on each phase.
+    ///
+    /// Only the queue wait, attempts & time to headers of the returned [`RequestTiming`] are set,
+    /// the total is left for the caller to set once the body has been read.
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] to execute
+    ///
+    /// # Returns
+    /// A Result containing the raw [`reqwest::Response`] & its timing or an error
+    async fn execute_request_measured<T: DeserializeOwned>(
+        &self,
+        request: &EsiRequest<T>,
+    ) -> Result<(reqwest::Response, RequestTiming), Error> {
         let method = request.method().clone();
         let endpoint = request.endpoint().to_string();
 
         log::debug!("ESI Request: {} {}", method, endpoint);
 
+        // Fail without sending a request missing its body or a query parameter
+        request.check_serialization().inspect_err(|e| {
+            log::error!("Invalid ESI request: {} {} - {}", method, endpoint, e);
+        })?;
+
         // Validate URL before sending the request
         url::Url::parse(&endpoint).inspect_err(|e| {
             log::error!("Invalid URL for ESI request: {} - {}", endpoint, e);
@@ -259,13 +496,69 @@ impl<'a> EsiApi<'a> {
 
         // Validate token if this is an authenticated request
         if let Some(access_token) = request.access_token() {
-            self.validate_token_before_request(access_token, request.required_scopes().clone())
-                .await?;
+            self.validate_token_before_request(
+                access_token,
+                request.required_scopes().clone(),
+                request.token_validation(),
+            )
+            .await?;
+        }
+
+        // Park the request if the client is cooling off from an error limited response
+        let queue_start = std::time::Instant::now();
+        self.wait_for_error_limit_reset().await;
+
+        // Queue the request until it is within the client's limit of requests in flight
+        let in_flight = std::sync::Arc::new(self.client.inner.esi_request_limiter.acquire().await);
+
+        let mut timing = RequestTiming {
+            queue_wait: queue_start.elapsed(),
+            ..RequestTiming::default()
+        };
+
+        // Respond with a simulated 420 once the simulated error limit's budget has been spent
+        if let Some(simulation) = &self.client.inner.esi_error_limit_simulation {
+            if let Some(reset_in) = simulation.exhausted() {
+                log::error!(
+                    "ESI Request failed: {} {} - Simulated error limit exceeded",
+                    method,
+                    endpoint
+                );
+
+                let esi_error = EsiError {
+                    status: 420,
+                    message: "Simulated ESI error limit exceeded".to_string(),
+                    cache: Self::extract_cache_headers(&reqwest::header::HeaderMap::new()),
+                    rate_limit: None,
+                    retry_after: None,
+                };
+
+                return Err(self.error_limited(reset_in, esi_error));
+            }
+        }
+
+        // Answer the request with a recorded response rather than sending it
+        #[cfg(feature = "record-replay")]
+        if let Some(cassette) = &self.client.inner.cassette {
+            if cassette.mode() == crate::esi::CassetteMode::Replay {
+                let response = cassette
+                    .replay_response(&method, &endpoint, request.body_json())
+                    .await?;
+                Self::notify_etag(request, &response);
+
+                return Ok((response, timing));
+            }
         }
 
         let reqwest_client = &self.client.inner.reqwest_client;
-        let max_retries = self.client.inner.esi_max_retries;
         let base_backoff = self.client.inner.esi_retry_backoff;
+        let max_retry_after = self.client.inner.esi_max_retry_after;
+
+        // Requests which may duplicate their effect are never retried as ESI may have processed them
+        let max_retries = match request.idempotency() {
+            Idempotency::Safe => self.client.inner.esi_max_retries,
+            Idempotency::Unsafe => 0,
+        };
 
         // Retry loop
         for attempt in 0..=max_retries {
@@ -294,8 +587,16 @@ impl<'a> EsiApi<'a> {
             let response = req_builder.send().await;
             let elapsed = start_time.elapsed();
 
+            timing.attempts = attempt + 1;
+            timing.time_to_headers = elapsed;
+
             match response {
-                Ok(r) => {
+                Ok(mut r) => {
+                    // Count error responses against the simulated error limit
+                    if let Some(simulation) = &self.client.inner.esi_error_limit_simulation {
+                        simulation.record_response(&mut r);
+                    }
+
                     // Check if we should retry on 5xx errors
                     if r.status().is_server_error() && attempt < max_retries {
                         let status = r.status();
@@ -309,8 +610,12 @@ impl<'a> EsiApi<'a> {
                             max_retries
                         );
 
-                        // Calculate exponential backoff: base_backoff * 2^attempt
-                        let wait_time = base_backoff * 2_u32.pow(attempt);
+                        // Wait for the Retry-After header if present, e.g. during downtime, capped to
+                        // the configured maximum, otherwise use exponential backoff: base_backoff * 2^attempt
+                        let wait_time = match Self::extract_retry_after(r.headers()) {
+                            Some(retry_after) => retry_after.min(max_retry_after),
+                            None => base_backoff * 2_u32.pow(attempt),
+                        };
                         tokio::time::sleep(wait_time).await;
                         continue;
                     }
@@ -321,7 +626,24 @@ impl<'a> EsiApi<'a> {
                         endpoint,
                         elapsed.as_millis()
                     );
-                    return Ok(r);
+
+                    self.record_rate_limit(r.headers());
+                    self.record_error_limit(r.headers());
+                    Self::notify_etag(request, &r);
+
+                    // Keep the request in flight until the response & its body are dropped
+                    r.extensions_mut().insert(in_flight.clone());
+
+                    #[cfg(feature = "record-replay")]
+                    if let Some(cassette) = &self.client.inner.cassette {
+                        let response = cassette
+                            .record_response(&method, &endpoint, request.body_json(), r)
+                            .await?;
+
+                        return Ok((response, timing));
+                    }
+
+                    return Ok((r, timing));
                 }
                 Err(err) => {
                     // For network errors, retry if we haven't exhausted attempts
@@ -377,6 +699,24 @@ impl<'a> EsiApi<'a> {
         &self,
         request: &EsiRequest<T>,
     ) -> Result<EsiResponse<T>, Error> {
+        let (response, _) = self.request_with_headers(request).await?;
+
+        Ok(response)
+    }
+
+    /// Makes a request to ESI, returning the raw response headers alongside the [`EsiResponse`].
+    ///
+    /// Used internally for headers not exposed on [`EsiResponse`], such as `X-Pages` for paginated routes.
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] containing endpoint, method, headers, and authentication details
+    ///
+    /// # Returns
+    /// A Result containing a tuple of the EsiResponse and the raw response headers
+    pub(crate) async fn request_with_headers<T: DeserializeOwned>(
+        &self,
+        request: &EsiRequest<T>,
+    ) -> Result<(EsiResponse<T>, reqwest::header::HeaderMap), Error> {
         let method = request.method().clone();
         let endpoint = request.endpoint().to_string();
 
@@ -384,31 +724,361 @@ impl<'a> EsiApi<'a> {
 
         // Check for error status codes and handle ESI error responses
         if response.status().is_client_error() || response.status().is_server_error() {
-            let esi_error =
-                Self::handle_esi_error_response(response, method.as_str(), &endpoint).await;
-            return Err(Box::new(esi_error).into());
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
         }
 
         // Extract headers before consuming the response
         let headers = response.headers().clone();
 
         // Deserialize and return the response
-        let body = response.text().await?;
-        let result: T = serde_json::from_str(&body).map_err(|e| {
+        let body = self.read_body(request, response).await?;
+        let result: T = Self::deserialize_body(method.as_str(), &endpoint, body)?;
+
+        log::info!("ESI Request succeeded: {} {}", method, endpoint);
+
+        // Create a temporary response-like struct for header extraction
+        let response = Self::populate_esi_response_from_headers(&headers, result);
+
+        Ok((response, headers))
+    }
+
+    /// Makes a request to ESI, returning the response alongside the time spent on the request.
+    ///
+    /// **Note:** This method is typically called internally by [`EsiRequest::send_and_measure`].
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] containing endpoint, method, headers, and authentication details
+    ///
+    /// # Returns
+    /// A Result containing the [`MeasuredResponse`] with the deserialized response data, headers & timing
+    pub async fn request_measured<T: DeserializeOwned>(
+        &self,
+        request: &EsiRequest<T>,
+    ) -> Result<MeasuredResponse<T>, Error> {
+        let start_time = std::time::Instant::now();
+        let method = request.method().clone();
+        let endpoint = request.endpoint().to_string();
+
+        let (response, mut timing) = self.execute_request_measured(request).await?;
+
+        // Check for error status codes and handle ESI error responses
+        if response.status().is_client_error() || response.status().is_server_error() {
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
+        }
+
+        let headers = response.headers().clone();
+        let request_id = headers
+            .get("x-esi-request-id")
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        let body = self.read_body(request, response).await?;
+        let result: T = Self::deserialize_body(method.as_str(), &endpoint, body)?;
+        timing.total = start_time.elapsed();
+
+        log::info!(
+            "ESI Request succeeded: {} {} ({}ms)",
+            method,
+            endpoint,
+            timing.total.as_millis()
+        );
+
+        Ok(MeasuredResponse {
+            response: Self::populate_esi_response_from_headers(&headers, result),
+            timing,
+            request_id,
+        })
+    }
+
+    /// Makes a request to ESI, returning only the response headers.
+    ///
+    /// Used internally for `HEAD` requests where the response has no body to deserialize.
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] containing endpoint, method, headers, and authentication details
+    ///
+    /// # Returns
+    /// A Result containing the raw response headers
+    pub(crate) async fn request_headers<T: DeserializeOwned>(
+        &self,
+        request: &EsiRequest<T>,
+    ) -> Result<reqwest::header::HeaderMap, Error> {
+        let method = request.method().clone();
+        let endpoint = request.endpoint().to_string();
+
+        let response = self.execute_request(request).await?;
+
+        // Check for error status codes and handle ESI error responses
+        if response.status().is_client_error() || response.status().is_server_error() {
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
+        }
+
+        Ok(response.headers().clone())
+    }
+
+    /// Makes a request to ESI, returning the raw status, headers, & body without deserialization.
+    ///
+    /// **Note:** This method is typically called internally by [`EsiRequest::send_raw`].
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] containing endpoint, method, headers, and authentication details
+    ///
+    /// # Returns
+    /// A Result containing the [`RawEsiResponse`], or an error if ESI returned an error status code
+    pub async fn request_raw<T: DeserializeOwned>(
+        &self,
+        request: &EsiRequest<T>,
+    ) -> Result<RawEsiResponse, Error> {
+        let method = request.method().clone();
+        let endpoint = request.endpoint().to_string();
+
+        let response = self.execute_request(request).await?;
+
+        // Check for error status codes and handle ESI error responses
+        if response.status().is_client_error() || response.status().is_server_error() {
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = self.read_body(request, response).await?;
+
+        log::info!("ESI Raw Request succeeded: {} {}", method, endpoint);
+
+        Ok(RawEsiResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Makes a request to ESI, streaming the response body to the provided writer.
+    ///
+    /// **Note:** This method is typically called internally by [`EsiRequest::send_to_writer`].
+    ///
+    /// As with buffered requests, the `Content-Length` header is checked against the maximum response
+    /// size before the body is streamed, so nothing is written for a response declared too large. A
+    /// failure mid-stream, such as a body without `Content-Length` exceeding the limit or a dropped
+    /// connection, leaves the body written so far in the writer.
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] containing endpoint, method, headers, and authentication details
+    /// - `writer`: The writer to stream the response body to
+    ///
+    /// # Returns
+    /// A Result containing an EsiResponse with the number of bytes written & the response headers
+    pub async fn request_to_writer<T: DeserializeOwned, W>(
+        &self,
+        request: &EsiRequest<T>,
+        writer: &mut W,
+    ) -> Result<EsiResponse<u64>, Error>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        use tokio::io::AsyncWriteExt;
+
+        let method = request.method().clone();
+        let endpoint = request.endpoint().to_string();
+
+        let mut response = self.execute_request(request).await?;
+
+        // Check for error status codes and handle ESI error responses
+        if response.status().is_client_error() || response.status().is_server_error() {
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
+        }
+
+        let headers = response.headers().clone();
+        let limit = self.max_response_size(request);
+        let mut written: u64 = 0;
+
+        // Reject a response declared too large before anything is written to the writer
+        if let Some(content_length) = response.content_length() {
+            Self::check_response_size(method.as_str(), &endpoint, limit, content_length)?;
+        }
+
+        while let Some(chunk) = response.chunk().await? {
+            written += chunk.len() as u64;
+            Self::check_response_size(method.as_str(), &endpoint, limit, written)?;
+
+            writer.write_all(&chunk).await?;
+        }
+
+        writer.flush().await?;
+
+        log::info!(
+            "ESI Request streamed {} bytes: {} {}",
+            written,
+            method,
+            endpoint
+        );
+
+        Ok(Self::populate_esi_response_from_headers(&headers, written))
+    }
+
+    /// Returns the maximum response size for a request, preferring the request's override.
+    fn max_response_size<T: DeserializeOwned>(&self, request: &EsiRequest<T>) -> Option<u64> {
+        request
+            .max_response_size()
+            .or(self.client.inner.esi_max_response_size)
+    }
+
+    /// Returns an error if the size of a response body exceeds the maximum response size.
+    fn check_response_size(
+        method: &str,
+        endpoint: &str,
+        limit: Option<u64>,
+        size: u64,
+    ) -> Result<(), Error> {
+        match limit {
+            Some(limit) if size > limit => {
+                log::error!(
+                    "ESI response for {} {} exceeded the maximum response size of {} bytes",
+                    method,
+                    endpoint,
+                    limit
+                );
+
+                Err(Error::ResponseTooLarge {
+                    method: method.to_string(),
+                    endpoint: endpoint.to_string(),
+                    limit,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reads the body of a successful ESI response, enforcing the maximum response size.
+    ///
+    /// The `Content-Length` header is checked before reading when present, the body is read in
+    /// chunks until it completes or exceeds the limit.
+    ///
+    /// # Arguments
+    /// - `request`: The request the response was returned for
+    /// - `response`: The response to read the body of
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)`: The response body
+    /// - `Err(Error::ResponseTooLarge)`: If the body exceeded the maximum response size
+    async fn read_body<T: DeserializeOwned>(
+        &self,
+        request: &EsiRequest<T>,
+        mut response: reqwest::Response,
+    ) -> Result<Vec<u8>, Error> {
+        let method = request.method().as_str();
+        let endpoint = request.endpoint();
+        let limit = self.max_response_size(request);
+
+        if let Some(content_length) = response.content_length() {
+            Self::check_response_size(method, endpoint, limit, content_length)?;
+        }
+
+        // Read in chunks rather than with `bytes()` which drops the response, releasing the
+        // request's place in the client's limit of requests in flight, before the body is read
+        let mut body = Vec::new();
+
+        while let Some(chunk) = response.chunk().await? {
+            Self::check_response_size(method, endpoint, limit, (body.len() + chunk.len()) as u64)?;
+
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(body)
+    }
+
+    /// Deserializes the body of a successful ESI response.
+    ///
+    /// # Arguments
+    /// - `method`: The HTTP method used for the request (for error context)
+    /// - `endpoint`: The endpoint that was called (for error context)
+    /// - `body`: The response body
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The deserialized response body
+    /// - `Err(Error::DeserializationError)`: Including the JSON path of the field which failed & the
+    ///   raw body if deserialization failed, the body is only logged at debug level & truncated
+    pub(crate) fn deserialize_body<T: DeserializeOwned>(
+        method: &str,
+        endpoint: &str,
+        body: Vec<u8>,
+    ) -> Result<T, Error> {
+        let body = String::from_utf8(body)
+            .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+        let deserializer = &mut serde_json::Deserializer::from_str(&body);
+
+        serde_path_to_error::deserialize(deserializer).map_err(|error| {
+            let path = error.path().to_string();
+            let error = error.into_inner();
+
             log::error!(
-                "Failed to deserialize response for {} {}: {}. Body: {}",
+                "Failed to deserialize response for {} {} at `{}`: {}",
                 method,
                 endpoint,
-                e,
-                body
+                path,
+                error
+            );
+            log::debug!(
+                "Body of response which failed to deserialize for {} {}: {}",
+                method,
+                endpoint,
+                Self::truncate_body(&body)
             );
-            Error::from(e)
-        })?;
 
-        log::info!("ESI Request succeeded: {} {}", method, endpoint);
+            Error::DeserializationError {
+                method: method.to_string(),
+                endpoint: endpoint.to_string(),
+                path,
+                body,
+                error,
+            }
+        })
+    }
 
-        // Create a temporary response-like struct for header extraction
-        Ok(Self::populate_esi_response_from_headers(&headers, result))
+    /// Truncates a response body to at most [`MAX_LOGGED_BODY_BYTES`] for logging.
+    ///
+    /// Bodies of large responses such as market orders are too long to log in full, the full body
+    /// is kept in the returned error instead.
+    ///
+    /// # Arguments
+    /// - `body`: The response body
+    ///
+    /// # Returns
+    /// The body cut at the last character boundary within the limit
+    pub(crate) fn truncate_body(body: &str) -> &str {
+        if body.len() <= MAX_LOGGED_BODY_BYTES {
+            return body;
+        }
+
+        let mut end = MAX_LOGGED_BODY_BYTES;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        &body[..end]
+    }
+
+    /// Extracts the total number of pages from the `X-Pages` header of paginated routes.
+    ///
+    /// # Arguments
+    /// - `headers`: The HTTP headers from the response
+    ///
+    /// # Returns
+    /// `Some(i32)` with the total number of pages, or `None` if the route is not paginated
+    pub(crate) fn extract_pages(headers: &reqwest::header::HeaderMap) -> Option<i32> {
+        headers
+            .get("x-pages")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|s| s.parse::<i32>().ok())
     }
 
     /// Make a cached request to ESI using the provided [`EsiRequest`] configuration.
@@ -448,26 +1118,17 @@ impl<'a> EsiApi<'a> {
 
         // Check for error status codes and handle ESI error responses
         if response.status().is_client_error() || response.status().is_server_error() {
-            let esi_error =
-                Self::handle_esi_error_response(response, method.as_str(), &endpoint).await;
-            return Err(Box::new(esi_error).into());
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
         }
 
         // Extract headers before consuming the response
         let headers = response.headers().clone();
 
         // Deserialize and return the response
-        let body = response.text().await?;
-        let data: T = serde_json::from_str(&body).map_err(|e| {
-            log::error!(
-                "Failed to deserialize cached response for {} {}: {}. Body: {}",
-                method,
-                endpoint,
-                e,
-                body
-            );
-            Error::from(e)
-        })?;
+        let body = self.read_body(request, response).await?;
+        let data: T = Self::deserialize_body(method.as_str(), &endpoint, body)?;
 
         log::info!(
             "ESI Cached Request succeeded (fresh): {} {}",
@@ -479,4 +1140,95 @@ impl<'a> EsiApi<'a> {
             Self::populate_esi_response_from_headers(&headers, data),
         ))
     }
+
+    /// Make a request to ESI, reusing the body stored in the provided [`ResponseCache`] if unchanged.
+    ///
+    /// Sends the ETag of the cached entry for the request URL with the `If-None-Match` header. On a
+    /// 304 Not Modified response the cached body is deserialized, on a 200 OK response the cache
+    /// is updated with the new ETag & body.
+    ///
+    /// **Note:** This method is typically called internally by [`EsiRequest::send_with_cache`].
+    ///
+    /// # Arguments
+    /// - `request`: The configured [`EsiRequest`] containing endpoint, method, headers, and authentication details
+    /// - `cache`: The cache to read & store the response body in
+    ///
+    /// # Returns
+    /// A Result containing an EsiResponse with the deserialized response data and headers
+    pub async fn request_with_cache<T: DeserializeOwned, C: ResponseCache>(
+        &self,
+        request: &EsiRequest<T>,
+        cache: &C,
+    ) -> Result<EsiResponse<T>, Error> {
+        let method = request.method().clone();
+        let endpoint = request.endpoint().to_string();
+
+        request.check_serialization()?;
+        let cached = cache.get(&endpoint).await?;
+
+        let response = match &cached {
+            Some(entry) => {
+                let request = request
+                    .clone()
+                    .with_header("If-None-Match", entry.etag.clone());
+
+                self.execute_request(&request).await?
+            }
+            None => self.execute_request(request).await?,
+        };
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(entry) = cached {
+                log::info!(
+                    "ESI Request succeeded (response cache hit): {} {}",
+                    method,
+                    endpoint
+                );
+
+                let data: T = Self::deserialize_body(method.as_str(), &endpoint, entry.body)?;
+                let mut response =
+                    Self::populate_esi_response_from_headers(response.headers(), data);
+
+                // 304 responses are not required to repeat the ETag
+                if response.cache.etag.is_empty() {
+                    response.cache.etag = entry.etag;
+                }
+
+                return Ok(response);
+            }
+        }
+
+        // Check for error status codes and handle ESI error responses
+        if response.status().is_client_error() || response.status().is_server_error() {
+            return Err(self
+                .error_from_response(response, method.as_str(), &endpoint)
+                .await);
+        }
+
+        let headers = response.headers().clone();
+        let body = self.read_body(request, response).await?;
+        let data: T = Self::deserialize_body(method.as_str(), &endpoint, body.clone())?;
+
+        let esi_response = Self::populate_esi_response_from_headers(&headers, data);
+
+        if !esi_response.cache.etag.is_empty() {
+            cache
+                .put(
+                    &endpoint,
+                    CacheEntry {
+                        etag: esi_response.cache.etag.clone(),
+                        body,
+                    },
+                )
+                .await?;
+        }
+
+        log::info!(
+            "ESI Request succeeded (response cache miss): {} {}",
+            method,
+            endpoint
+        );
+
+        Ok(esi_response)
+    }
 }
@@ -0,0 +1,541 @@
+//! Bounded concurrency for sending many ESI requests at once.
+//!
+//! [`try_join_limited`] runs a future for each item with at most `limit` running at a time.
+//!
+//! Every error response counts towards ESI's [error limit](https://developers.eveonline.com/docs/services/esi/best-practices/#error-limit),
+//! so no further requests are started once one fails: the futures still running are dropped & the
+//! error is returned.
+//!
+//! [`join_limited_partial`] instead runs every future regardless of failures & returns a
+//! [`CompositeError`] with the outputs of the successful futures & the key of each failed item.
+//! Composite helpers such as
+//! [`UniverseEndpoints::get_item_types`](crate::endpoints::universe::UniverseEndpoints::get_item_types)
+//! & [`DogmaEndpoints::get_dynamic_item_details`](crate::endpoints::dogma::DogmaEndpoints::get_dynamic_item_details)
+//! use it so the data retrieved is kept when some requests fail. Enable the error limit cool off
+//! when using it for large batches as items not started due to the error limit are otherwise
+//! returned as failures.
+//!
+//! ## Error Limit
+//!
+//! Before starting each future, the errors remaining in the error limit window are checked from the
+//! `X-ESI-Error-Limit-Remain` header of the [`Client`]'s most recent response, including responses to
+//! requests made outside the batch. Once fewer than
+//! [`ConfigBuilder::esi_error_limit_threshold`](crate::ConfigBuilder::esi_error_limit_threshold)
+//! errors remain, further futures wait for the window to reset if
+//! [`ConfigBuilder::esi_error_limit_cool_off`](crate::ConfigBuilder::esi_error_limit_cool_off) is
+//! enabled, otherwise they fail with [`Error::ErrorLimitThreshold`] without being started so a batch
+//! can't drive the client into a 420.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::esi::concurrency::try_join_limited;
+//!
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let character_ids = vec![2114794365, 2117053828, 2123087197];
+//!
+//! // Request at most 2 characters at a time
+//! let characters = try_join_limited(&esi_client, character_ids, 2, |character_id| {
+//!     let request = esi_client.character().get_character_public_information(character_id);
+//!
+//!     async move { request.send().await.map(|response| response.data) }
+//! })
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Client Request Limit
+//!
+//! `try_join_limited` only bounds the futures it runs itself, application code joining thousands of
+//! requests at once would still open a connection to ESI for each. Setting
+//! [`ConfigBuilder::esi_max_concurrent_requests`](crate::ConfigBuilder::esi_max_concurrent_requests)
+//! caps the requests in flight across the whole [`Client`](crate::Client), further requests queue
+//! until one completes. A request stays in flight from being sent until its response body has been
+//! read or the response is dropped, the time spent queued is reported as
+//! [`RequestTiming::queue_wait`](crate::esi::RequestTiming::queue_wait). The number of requests
+//! currently in flight is returned by [`Client::in_flight_requests`](crate::Client::in_flight_requests).
+
+#[cfg(any(feature = "market", all(feature = "dogma", feature = "universe")))]
+use std::{collections::HashMap, hash::Hash, sync::Mutex};
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use chrono::Utc;
+use futures_util::{StreamExt, TryStreamExt};
+#[cfg(any(feature = "market", all(feature = "dogma", feature = "universe")))]
+use tokio::sync::OnceCell;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::error::CompositeError;
+use crate::{Client, Error};
+
+/// Runs a future for each item with at most `limit` running concurrently
+///
+/// Futures are started in the order of the items, a new future starting whenever one completes.
+/// See the [module documentation](self) for how errors & the error limit are handled.
+///
+/// # Arguments
+/// - `esi_client` (&[`Client`]): The client sending the requests, whose error limit is checked
+/// - `items` (`impl IntoIterator`): The items to run a future for, e.g. IDs to request
+/// - `limit` (`usize`): The maximum number of futures running at once, `0` is treated as `1`
+/// - `f` (`FnMut(item) -> Future`): Creates the future for an item, usually sending an ESI request
+///
+/// # Returns
+/// The output of each future in the order of the items, or the first [`Error`] returned by a future.
+pub async fn try_join_limited<I, F, Fut, T>(
+    esi_client: &Client,
+    items: I,
+    limit: usize,
+    mut f: F,
+) -> Result<Vec<T>, Error>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    futures_util::stream::iter(items)
+        .map(|item| {
+            let future = f(item);
+
+            async move {
+                check_error_limit(esi_client).await?;
+
+                future.await
+            }
+        })
+        .buffered(limit.max(1))
+        .try_collect()
+        .await
+}
+
+/// Runs a future for each item with at most `limit` running concurrently, continuing past failures
+///
+/// Futures are started in the order of the items like [`try_join_limited`], but a failed future
+/// doesn't stop further futures from starting. Items not started due to the error limit are
+/// returned as failures with [`Error::ErrorLimitThreshold`].
+///
+/// # Arguments
+/// - `esi_client` (&[`Client`]): The client sending the requests, whose error limit is checked
+/// - `items` (`impl IntoIterator`): The items to run a future for, e.g. IDs to request
+/// - `limit` (`usize`): The maximum number of futures running at once, `0` is treated as `1`
+/// - `key`   (`FnMut(&item) -> K`): Returns the key identifying an item in the failures, e.g. its ID
+/// - `f`     (`FnMut(item) -> Future`): Creates the future for an item, usually sending an ESI request
+///
+/// # Returns
+/// The output of each future in the order of the items, or a [`CompositeError`] with the outputs of
+/// the successful futures in order & the key & [`Error`] of each failed item if any future fails.
+pub async fn join_limited_partial<I, K, KF, F, Fut, T>(
+    esi_client: &Client,
+    items: I,
+    limit: usize,
+    mut key: KF,
+    mut f: F,
+) -> Result<Vec<T>, CompositeError<Vec<T>, K>>
+where
+    I: IntoIterator,
+    KF: FnMut(&I::Item) -> K,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    let results: Vec<(K, Result<T, Error>)> = futures_util::stream::iter(items)
+        .map(|item| {
+            let key = key(&item);
+            let future = f(item);
+
+            async move {
+                let result = match check_error_limit(esi_client).await {
+                    Ok(()) => future.await,
+                    Err(error) => Err(error),
+                };
+
+                (key, result)
+            }
+        })
+        .buffered(limit.max(1))
+        .collect()
+        .await;
+
+    let attempted = results.len();
+    let mut successes = Vec::with_capacity(attempted);
+    let mut failures = Vec::new();
+
+    for (key, result) in results {
+        match result {
+            Ok(output) => successes.push(output),
+            Err(error) => failures.push((key, error)),
+        }
+    }
+
+    CompositeError::check(successes, failures, attempted)
+}
+
+/// Checks the errors remaining in the client's ESI error limit window before starting a request
+///
+/// Waits for the window to reset if fewer than the client's threshold of errors remain & error limit
+/// cool off is enabled, otherwise returns an error.
+///
+/// # Returns
+/// - `Ok(())`: If the request may be started
+/// - `Err(Error::ErrorLimitThreshold)`: If too few errors remain & cool off is disabled
+async fn check_error_limit(esi_client: &Client) -> Result<(), Error> {
+    let Some((remaining, reset_at)) = esi_client.esi().error_limit_remaining() else {
+        return Ok(());
+    };
+
+    if remaining >= esi_client.inner.esi_error_limit_threshold {
+        return Ok(());
+    }
+
+    if !esi_client.inner.esi_error_limit_cool_off {
+        log::warn!(
+            "Only {} ESI errors remain until {}, not starting request",
+            remaining,
+            reset_at
+        );
+
+        return Err(Error::ErrorLimitThreshold {
+            remaining,
+            reset_at,
+        });
+    }
+
+    if let Ok(wait_time) = (reset_at - Utc::now()).to_std() {
+        log::warn!(
+            "Only {} ESI errors remain, waiting {}ms for error limit to reset before request",
+            remaining,
+            wait_time.as_millis()
+        );
+
+        tokio::time::sleep(wait_time).await;
+    }
+
+    Ok(())
+}
+
+/// Shares the output of a request needed by several futures of a composite helper
+///
+/// The first future to request a key sends the request & the others wait for its output. A failed
+/// request is sent again by the next future waiting for it, so each future gets either the output
+/// or an error of its own to report as its failure.
+#[cfg(any(feature = "market", all(feature = "dogma", feature = "universe")))]
+pub(crate) struct SharedRequests<K, T> {
+    outputs: Mutex<HashMap<K, Arc<OnceCell<T>>>>,
+}
+
+#[cfg(any(feature = "market", all(feature = "dogma", feature = "universe")))]
+impl<K, T> Default for SharedRequests<K, T> {
+    fn default() -> Self {
+        Self {
+            outputs: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[cfg(any(feature = "market", all(feature = "dogma", feature = "universe")))]
+impl<K: Eq + Hash, T: Clone> SharedRequests<K, T> {
+    /// Returns the output of the request for `key`, sending it with `send` if no future has yet
+    pub(crate) async fn get_or_send<F, Fut>(&self, key: K, send: F) -> Result<T, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let output = self
+            .outputs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key)
+            .or_default()
+            .clone();
+
+        output.get_or_try_init(send).await.cloned()
+    }
+}
+
+/// Limits & counts the ESI requests in flight across a [`Client`](crate::Client)
+pub(crate) struct RequestLimiter {
+    permits: Option<Arc<Semaphore>>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl RequestLimiter {
+    /// Creates a limiter allowing at most `max_concurrent_requests` in flight, `None` for no limit
+    pub(crate) fn new(max_concurrent_requests: Option<usize>) -> Self {
+        Self {
+            permits: max_concurrent_requests.map(|max| Arc::new(Semaphore::new(max))),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Waits until a request may be sent, the request is in flight until the returned guard is dropped
+    pub(crate) async fn acquire(&self) -> InFlightRequest {
+        let permit = match &self.permits {
+            // The semaphore is never closed so acquiring a permit can't fail
+            Some(permits) => permits.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+
+        InFlightRequest {
+            _permit: permit,
+            in_flight: self.in_flight.clone(),
+        }
+    }
+
+    /// Returns the number of requests currently in flight
+    pub(crate) fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// Guard marking an ESI request as in flight, releasing its place in the limit when dropped
+pub(crate) struct InFlightRequest {
+    _permit: Option<OwnedSemaphorePermit>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod request_limiter_tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Tests requests queue once the limit is reached & are counted while in flight
+    ///
+    /// # Test Setup
+    /// - Create a limiter allowing 2 requests in flight & acquire 2 guards
+    ///
+    /// # Assertions
+    /// - Assert a third request waits until a guard is dropped
+    /// - Assert the in flight count follows the guards
+    #[tokio::test]
+    async fn test_request_limiter() {
+        let limiter = RequestLimiter::new(Some(2));
+
+        let first = limiter.acquire().await;
+        let _second = limiter.acquire().await;
+        assert_eq!(limiter.in_flight(), 2);
+
+        // Assert a third request waits until a guard is dropped
+        let queued = tokio::time::timeout(Duration::from_millis(20), limiter.acquire()).await;
+        assert!(queued.is_err());
+
+        drop(first);
+        let _third = limiter.acquire().await;
+
+        // Assert the in flight count follows the guards
+        assert_eq!(limiter.in_flight(), 2);
+        assert_eq!(RequestLimiter::new(None).in_flight(), 0);
+    }
+}
+
+#[cfg(test)]
+mod try_join_limited_tests {
+    use std::time::Duration;
+
+    use chrono::TimeDelta;
+
+    use super::*;
+    use crate::Config;
+
+    /// Creates a client which last received a response with `remaining` errors left in the window
+    fn client_with_error_limit(remaining: u32, cool_off: bool) -> Client {
+        let config = Config::builder()
+            .esi_error_limit_cool_off(cool_off)
+            .build()
+            .unwrap();
+        let esi_client = Client::builder()
+            .user_agent("TestApp/1.0")
+            .config(config)
+            .build()
+            .unwrap();
+
+        *esi_client.inner.esi_error_limit_remain.lock().unwrap() =
+            Some((remaining, Utc::now() + TimeDelta::milliseconds(200)));
+
+        esi_client
+    }
+
+    /// Tests no more than the limit of futures run at once & outputs keep the order of the items
+    ///
+    /// # Test Setup
+    /// - Run 10 futures which sleep for longer the lower their item, with a limit of 3
+    ///
+    /// # Assertions
+    /// - Assert at most 3 futures were running at any time
+    /// - Assert the outputs are in the order of the items
+    #[tokio::test]
+    async fn test_try_join_limited() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+
+        let esi_client = Client::new("TestApp/1.0").unwrap();
+        let outputs = try_join_limited(&esi_client, 0..10u64, 3, |item| {
+            let (running, max_running) = (&running, &max_running);
+
+            async move {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now_running, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10 - item)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+
+                Ok(item * 2)
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+        assert_eq!(outputs, (0..10).map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    /// Tests no further futures are started once a future returns an error
+    ///
+    /// # Test Setup
+    /// - Run futures for 10 items with a limit of 1, the third returning an error
+    ///
+    /// # Assertions
+    /// - Assert the error is returned
+    /// - Assert futures for items after the error were never started
+    #[tokio::test]
+    async fn test_try_join_limited_stops_on_error() {
+        let started = AtomicUsize::new(0);
+
+        let esi_client = Client::new("TestApp/1.0").unwrap();
+        let result: Result<Vec<()>, Error> = try_join_limited(&esi_client, 0..10, 1, |item| {
+            started.fetch_add(1, Ordering::SeqCst);
+
+            async move {
+                if item == 2 {
+                    return Err(Error::IoError(std::io::Error::other("request failed")));
+                }
+
+                Ok(())
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::IoError(_))));
+        assert_eq!(started.load(Ordering::SeqCst), 3);
+    }
+
+    /// Tests no futures are started once fewer errors remain than the threshold
+    ///
+    /// # Test Setup
+    /// - Create a client whose last response had 9 errors remaining, below the default threshold of 10
+    /// - Run futures for 3 items with error limit cool off disabled
+    ///
+    /// # Assertions
+    /// - Assert an error limit threshold error is returned
+    /// - Assert none of the futures were polled
+    #[tokio::test]
+    async fn test_try_join_limited_stops_below_error_limit_threshold() {
+        let esi_client = client_with_error_limit(9, false);
+        let polled = AtomicUsize::new(0);
+
+        let result: Result<Vec<()>, Error> = try_join_limited(&esi_client, 0..3, 1, |_| {
+            let polled = &polled;
+
+            async move {
+                polled.fetch_add(1, Ordering::SeqCst);
+
+                Ok(())
+            }
+        })
+        .await;
+
+        // Assert an error limit threshold error is returned
+        assert!(matches!(
+            result,
+            Err(Error::ErrorLimitThreshold { remaining: 9, .. })
+        ));
+
+        // Assert none of the futures were polled
+        assert_eq!(polled.load(Ordering::SeqCst), 0);
+    }
+
+    /// Tests futures wait for the error limit to reset below the threshold with cool off enabled
+    ///
+    /// # Test Setup
+    /// - Create a client whose last response had 9 errors remaining, resetting in 200 milliseconds
+    /// - Run futures for 3 items with error limit cool off enabled
+    ///
+    /// # Assertions
+    /// - Assert every future completed successfully
+    /// - Assert the futures waited for the error limit to reset
+    #[tokio::test]
+    async fn test_try_join_limited_waits_below_error_limit_threshold() {
+        let esi_client = client_with_error_limit(9, true);
+        let start = std::time::Instant::now();
+
+        let outputs = try_join_limited(&esi_client, 0..3, 1, |item| async move { Ok(item) })
+            .await
+            .unwrap();
+
+        // Assert every future completed successfully
+        assert_eq!(outputs, vec![0, 1, 2]);
+
+        // Assert the futures waited for the error limit to reset
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+}
+
+#[cfg(test)]
+mod join_limited_partial_tests {
+    use super::*;
+
+    /// Tests every future runs when some fail & the failures are keyed by item
+    ///
+    /// # Test Setup
+    /// - Run futures for 5 items with a limit of 1, the second & fourth returning an error
+    ///
+    /// # Assertions
+    /// - Assert every future was started
+    /// - Assert the successful outputs are kept in order
+    /// - Assert the failed items are returned with their keys
+    #[tokio::test]
+    async fn test_join_limited_partial() {
+        let esi_client = Client::new("TestApp/1.0").unwrap();
+        let started = AtomicUsize::new(0);
+
+        let result = join_limited_partial(
+            &esi_client,
+            0..5,
+            1,
+            |item| *item,
+            |item| {
+                started.fetch_add(1, Ordering::SeqCst);
+
+                async move {
+                    if item % 2 == 1 {
+                        return Err(Error::IoError(std::io::Error::other("request failed")));
+                    }
+
+                    Ok(item * 2)
+                }
+            },
+        )
+        .await;
+
+        // Assert every future was started
+        assert_eq!(started.load(Ordering::SeqCst), 5);
+
+        // Assert the successful outputs are kept in order
+        let partial = result.unwrap_err();
+        assert_eq!(partial.successes(), &vec![0, 4, 8]);
+        assert_eq!(partial.attempted(), 5);
+
+        // Assert the failed items are returned with their keys
+        let keys: Vec<i32> = partial.failures().iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, vec![1, 3]);
+    }
+}
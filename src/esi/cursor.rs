@@ -0,0 +1,342 @@
+//! # Cursor Pagination
+//!
+//! Newer ESI routes, such as the
+//! [corporation projects endpoints](crate::endpoints::corporation_projects), paginate with cursor
+//! tokens rather than the `page` query parameter & `X-Pages` header handled by
+//! [`EsiRequest::send_all_pages`]. Each response contains a `cursor` with a `before` token, sent
+//! to retrieve the records older than the response, & an `after` token, sent to retrieve the
+//! records added or modified since the response.
+//!
+//! A request without a token returns the newest records. [`Cursor::new`] walks from the newest
+//! records towards the oldest using the `before` tokens, keeping the `after` token of the first
+//! page. Store the token returned by [`Cursor::after_token`] & resume with [`Cursor::after`] later
+//! to only retrieve the records which changed in the meantime.
+//!
+//! Responses are represented by a type implementing [`CursorPage`], which provides the cursor
+//! tokens & the records of the page. Routes returning a plain list of records with the tokens in a
+//! `Link` header, e.g. `Link: </route?before=abc>; rel="before"`, are iterated with a `Vec` of the
+//! records as the response type. Tokens in the response body take precedence over the header.
+//!
+//! [`EsiRequest::send_all_cursor`] retrieves every record at once when the `after` token isn't
+//! needed.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::esi::Cursor;
+//!
+//! # async fn example(esi_client: eve_esi::Client, access_token: &str) -> Result<(), eve_esi::Error> {
+//! let request = esi_client
+//!     .corporation_projects()
+//!     .list_corporation_projects(access_token, 98785281, None, None, None, None);
+//!
+//! // Retrieve every project, newest first
+//! let mut cursor = Cursor::new(request);
+//! while let Some(projects) = cursor.next_page().await? {
+//!     for project in projects {
+//!         println!("{}: {:?}", project.name, project.state);
+//!     }
+//! }
+//!
+//! // Store the token to only retrieve projects which changed on the next run
+//! let after_token = cursor.after_token().map(str::to_string);
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, LINK};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, EsiRequest};
+
+/// Name of the query parameter used to request the records older than a cursor
+const BEFORE_QUERY: &str = "before";
+/// Name of the query parameter used to request the records newer than a cursor
+const AFTER_QUERY: &str = "after";
+
+/// Tokens returned by cursor paginated ESI routes alongside the records of a page
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct CursorTokens {
+    /// Token used to retrieve the records older than the page
+    #[serde(default)]
+    pub before: Option<String>,
+    /// Token used to retrieve the records added or modified since the page
+    #[serde(default)]
+    pub after: Option<String>,
+}
+
+impl CursorTokens {
+    /// Parses the tokens from the value of a `Link` header
+    ///
+    /// Each link with a `before` or `after` relation provides the token in the query parameter of
+    /// the same name, e.g. `</route?before=abc>; rel="before", </route?after=def>; rel="after"`.
+    ///
+    /// # Arguments
+    /// - `value` (`&str`): The value of the `Link` header
+    ///
+    /// # Returns
+    /// The tokens of the header, or `None` if the header contains neither token
+    pub fn from_link_header(value: &str) -> Option<Self> {
+        let mut tokens = Self::default();
+
+        for link in value.split(',') {
+            let mut parts = link.split(';');
+            let Some(target) = parts.next() else {
+                continue;
+            };
+            let target = target.trim().trim_start_matches('<').trim_end_matches('>');
+
+            let Some(rel) = parts.find_map(|param| {
+                let (name, value) = param.split_once('=')?;
+                (name.trim() == "rel").then(|| value.trim().trim_matches('"'))
+            }) else {
+                continue;
+            };
+
+            let slot = match rel {
+                BEFORE_QUERY => &mut tokens.before,
+                AFTER_QUERY => &mut tokens.after,
+                _ => continue,
+            };
+
+            let query = target.split_once('?').map_or("", |(_, query)| query);
+            *slot = url::form_urlencoded::parse(query.as_bytes())
+                .find(|(name, _)| name == rel)
+                .map(|(_, token)| token.into_owned());
+        }
+
+        (tokens.before.is_some() || tokens.after.is_some()).then_some(tokens)
+    }
+
+    /// Parses the tokens from the `Link` header of a response, see [`Self::from_link_header`]
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get_all(LINK)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(Self::from_link_header)
+            .reduce(|mut tokens, other| {
+                tokens.before = tokens.before.or(other.before);
+                tokens.after = tokens.after.or(other.after);
+                tokens
+            })
+    }
+}
+
+/// A page returned by a cursor paginated ESI route
+///
+/// Implemented by the response models of cursor paginated endpoints, e.g.
+/// [`CorporationProjectsPage`](crate::model::corporation_projects::CorporationProjectsPage), allowing
+/// them to be iterated with a [`Cursor`]. Implement it for the response of a custom route to do
+/// the same.
+pub trait CursorPage: DeserializeOwned {
+    /// The type of the records within a page
+    type Item;
+
+    /// Returns the cursor tokens of the page, if any
+    fn cursor(&self) -> Option<&CursorTokens>;
+
+    /// Consumes the page, returning its records
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// A plain list of records, with the cursor tokens provided by the `Link` header of the response
+impl<T: DeserializeOwned> CursorPage for Vec<T> {
+    type Item = T;
+
+    fn cursor(&self) -> Option<&CursorTokens> {
+        None
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self
+    }
+}
+
+/// Direction in which a [`Cursor`] walks the records of a route
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CursorDirection {
+    /// Walking from the newest records towards the oldest with `before` tokens
+    Before,
+    /// Walking towards the records added or modified since a token with `after` tokens
+    After,
+}
+
+/// Iterates over the pages of a cursor paginated ESI route
+///
+/// For an overview & usage example, see the [module-level documentation](self)
+pub struct Cursor<T: CursorPage> {
+    request: EsiRequest<T>,
+    direction: CursorDirection,
+    /// Token sent with the next request, `None` for the first request of [`Cursor::new`]
+    token: Option<String>,
+    /// Latest `after` token returned, used to resume later with [`Cursor::after`]
+    after_token: Option<String>,
+    started: bool,
+    finished: bool,
+}
+
+impl<T: CursorPage> Cursor<T> {
+    /// Creates a cursor retrieving every record of a route, starting with the newest
+    ///
+    /// # Arguments
+    /// - `request` ([`EsiRequest`]): The request for the route, without a `before` or `after` token
+    pub fn new(request: EsiRequest<T>) -> Self {
+        Self {
+            request,
+            direction: CursorDirection::Before,
+            token: None,
+            after_token: None,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Creates a cursor retrieving the records added or modified since a previous cursor
+    ///
+    /// # Arguments
+    /// - `request` ([`EsiRequest`]): The request for the route, without a `before` or `after` token
+    /// - `token` (`impl Into<String>`): The token returned by [`Cursor::after_token`] of a previous cursor
+    pub fn after(request: EsiRequest<T>, token: impl Into<String>) -> Self {
+        let token = token.into();
+
+        Self {
+            request,
+            direction: CursorDirection::After,
+            after_token: Some(token.clone()),
+            token: Some(token),
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Retrieves the next page of records
+    ///
+    /// The cursor is finished once a page without records or without a token for the next page
+    /// is returned.
+    ///
+    /// # Returns
+    /// - `Ok(Some(items))`: The records of the next page
+    /// - `Ok(None)`: No further records are available
+    /// - `Err(Error)`: The request for the page failed, the cursor may be retried
+    pub async fn next_page(&mut self) -> Result<Option<Vec<T::Item>>, Error> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let mut request = self.request.clone();
+        if let Some(token) = &self.token {
+            let name = match self.direction {
+                CursorDirection::Before => BEFORE_QUERY,
+                CursorDirection::After => AFTER_QUERY,
+            };
+            request = request.with_query_replaced(name, token);
+        }
+
+        let (response, headers) = request.send_with_headers().await?;
+        let page = response.data;
+        let tokens = page
+            .cursor()
+            .cloned()
+            .or_else(|| CursorTokens::from_headers(&headers))
+            .unwrap_or_default();
+        let items = page.into_items();
+
+        // Walking backwards, only the first page holds the newest `after` token
+        let first_page = !self.started;
+        self.started = true;
+        if self.direction == CursorDirection::After || first_page {
+            if let Some(after) = &tokens.after {
+                self.after_token = Some(after.clone());
+            }
+        }
+
+        let next_token = match self.direction {
+            CursorDirection::Before => tokens.before,
+            CursorDirection::After => tokens.after,
+        };
+
+        if items.is_empty() || next_token.is_none() {
+            self.finished = true;
+        }
+        self.token = next_token;
+
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(items))
+    }
+
+    /// Retrieves every remaining page of records
+    ///
+    /// # Returns
+    /// The records of each remaining page in order, or the first [`Error`] returned by a request.
+    pub async fn collect_all(&mut self) -> Result<Vec<T::Item>, Error> {
+        let mut items = Vec::new();
+
+        while let Some(page) = self.next_page().await? {
+            items.extend(page);
+        }
+
+        Ok(items)
+    }
+
+    /// Returns the token to retrieve records added or modified since this cursor, see [`Cursor::after`]
+    ///
+    /// `None` until a page containing an `after` token has been retrieved.
+    pub fn after_token(&self) -> Option<&str> {
+        self.after_token.as_deref()
+    }
+
+    /// Returns `true` once no further records are available
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+#[cfg(test)]
+mod cursor_tokens_tests {
+    use super::CursorTokens;
+
+    /// Tests parsing the cursor tokens from the value of a `Link` header
+    ///
+    /// # Assertions
+    /// - Assert both tokens are parsed from their relations, decoding the query parameters
+    /// - Assert links with other relations are ignored
+    /// - Assert `None` is returned if neither token is present
+    #[test]
+    fn test_tokens_from_link_header() {
+        // Assert both tokens are parsed from their relations, decoding the query parameters
+        let tokens = CursorTokens::from_link_header(concat!(
+            "<https://esi.evetech.net/route?limit=50&before=MjAy%3D>; rel=\"before\", ",
+            "</route?after=def>; rel=after"
+        ));
+        assert_eq!(
+            tokens,
+            Some(CursorTokens {
+                before: Some("MjAy=".to_string()),
+                after: Some("def".to_string()),
+            })
+        );
+
+        // Assert links with other relations are ignored
+        let tokens = CursorTokens::from_link_header(
+            "</route?page=2>; rel=\"next\", </route?after=def>; rel=\"after\"",
+        );
+        assert_eq!(
+            tokens,
+            Some(CursorTokens {
+                before: None,
+                after: Some("def".to_string()),
+            })
+        );
+
+        // Assert `None` is returned if neither token is present
+        assert_eq!(
+            CursorTokens::from_link_header("</route?page=2>; rel=\"next\""),
+            None
+        );
+    }
+}
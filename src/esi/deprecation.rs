@@ -0,0 +1,151 @@
+//! Tracking of deprecated ESI routes.
+//!
+//! ESI routes are occasionally removed or restructured by CCP. Endpoints defined with
+//! deprecation metadata record each use in a per-client registry, logging a warning the
+//! first time a deprecated route is used & making a report available through
+//! [`Client::deprecated_routes`](crate::Client::deprecated_routes) so applications get
+//! early warning before routes they rely on are removed.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A deprecated ESI route which has been used by the client
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeprecatedRoute {
+    /// The name of the endpoint method used to access the route, e.g. `get_agents_research`
+    pub function: String,
+    /// The HTTP method of the route
+    pub method: String,
+    /// The path template of the route, e.g. `/characters/{}/agents_research`
+    pub path: String,
+    /// The date the route was deprecated in `YYYY-MM-DD` format
+    pub deprecated_since: String,
+    /// The date the route is expected to be removed in `YYYY-MM-DD` format, if announced
+    pub removal_date: Option<String>,
+    /// The number of requests built for the route by the client
+    pub calls: u64,
+}
+
+/// Registry of deprecated ESI routes used by a [`Client`](crate::Client)
+#[derive(Default)]
+pub(crate) struct DeprecationRegistry {
+    /// Deprecated routes which have been used, keyed by HTTP method & path template
+    routes: Mutex<HashMap<(String, String), DeprecatedRoute>>,
+}
+
+impl DeprecationRegistry {
+    /// Creates a new empty [`DeprecationRegistry`]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a use of a deprecated route
+    ///
+    /// Logs a warning the first time the route is used, subsequent uses only increment
+    /// the route's call count.
+    ///
+    /// # Arguments
+    /// - `function` (`&str`): The name of the endpoint method used to access the route
+    /// - `method` (`&str`): The HTTP method of the route
+    /// - `path` (`&str`): The path template of the route
+    /// - `deprecated_since` (`&str`): The date the route was deprecated
+    /// - `removal_date` (`Option<&str>`): The date the route is expected to be removed, if announced
+    pub(crate) fn record(
+        &self,
+        function: &str,
+        method: &str,
+        path: &str,
+        deprecated_since: &str,
+        removal_date: Option<&str>,
+    ) {
+        let mut routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+
+        let route = routes
+            .entry((method.to_string(), path.to_string()))
+            .or_insert_with(|| {
+                log::warn!(
+                    "ESI route {} {} used via `{}` is deprecated since {}{}",
+                    method,
+                    path,
+                    function,
+                    deprecated_since,
+                    removal_date
+                        .map(|date| format!(" and is scheduled for removal on {}", date))
+                        .unwrap_or_default()
+                );
+
+                DeprecatedRoute {
+                    function: function.to_string(),
+                    method: method.to_string(),
+                    path: path.to_string(),
+                    deprecated_since: deprecated_since.to_string(),
+                    removal_date: removal_date.map(str::to_string),
+                    calls: 0,
+                }
+            });
+
+        route.calls += 1;
+    }
+
+    /// Returns a report of all deprecated routes used, sorted by path & method
+    pub(crate) fn report(&self) -> Vec<DeprecatedRoute> {
+        let routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut report: Vec<DeprecatedRoute> = routes.values().cloned().collect();
+        report.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.method.cmp(&b.method)));
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod deprecation_registry_tests {
+    use super::*;
+
+    /// Tests that repeated uses of a deprecated route are recorded as a single entry
+    ///
+    /// # Test Setup
+    /// - Create a new registry
+    /// - Record the same route twice & another route once
+    ///
+    /// # Assertions
+    /// - Assert the report contains 2 routes sorted by path
+    /// - Assert the repeated route has a call count of 2
+    /// - Assert deprecation metadata is retained
+    #[test]
+    fn test_record_deprecated_route() {
+        let registry = DeprecationRegistry::new();
+
+        registry.record(
+            "get_agents_research",
+            "GET",
+            "/characters/{}/agents_research",
+            "2025-01-01",
+            Some("2025-06-01"),
+        );
+        registry.record(
+            "get_agents_research",
+            "GET",
+            "/characters/{}/agents_research",
+            "2025-01-01",
+            Some("2025-06-01"),
+        );
+        registry.record(
+            "get_opportunities",
+            "GET",
+            "/opportunities/groups",
+            "2024-01-01",
+            None,
+        );
+
+        let report = registry.report();
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].path, "/characters/{}/agents_research");
+        assert_eq!(report[0].calls, 2);
+        assert_eq!(report[0].removal_date.as_deref(), Some("2025-06-01"));
+        assert_eq!(report[1].path, "/opportunities/groups");
+        assert_eq!(report[1].calls, 1);
+        assert_eq!(report[1].removal_date, None);
+    }
+}
@@ -0,0 +1,184 @@
+//! # ESI Error Limit Simulation
+//!
+//! ESI bans an application's IP once too many error responses are returned within a window,
+//! responding with 420 (Error Limited) until the window resets. Exceeding the limit against the
+//! real ESI to test how an application backs off or queues requests risks a temporary ban, so the
+//! client can enforce a simulated error limit instead, see
+//! [`ConfigBuilder::esi_error_limit_simulation`](crate::ConfigBuilder::esi_error_limit_simulation).
+//!
+//! With a simulation configured, every 4xx & 5xx response, such as those returned by a mock server,
+//! counts against the [`ErrorLimitSimulation`]'s budget & the `X-ESI-Error-Limit-Remain` &
+//! `X-ESI-Error-Limit-Reset` headers of each response are set to the simulated values. Once the
+//! budget is spent, requests fail with [`Error::ErrorLimited`](crate::Error::ErrorLimited) without
+//! being sent until the window resets, honoring
+//! [`ConfigBuilder::esi_error_limit_cool_off`](crate::ConfigBuilder::esi_error_limit_cool_off) like
+//! a 420 returned by ESI.
+//!
+//! ## Usage
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use eve_esi::esi::ErrorLimitSimulation;
+//!
+//! // Error limit requests after 5 error responses within 10 seconds
+//! let config = eve_esi::Config::builder()
+//!     .esi_url("http://127.0.0.1:1234")
+//!     .esi_error_limit_simulation(ErrorLimitSimulation::new(5, Duration::from_secs(10)))
+//!     .build()
+//!     .expect("Failed to build ESI Config");
+//! ```
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderMap, HeaderValue};
+
+/// Budget & window of a simulated ESI error limit
+///
+/// For an overview & usage example, see the [module-level documentation](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorLimitSimulation {
+    budget: u32,
+    window: Duration,
+}
+
+impl ErrorLimitSimulation {
+    /// Creates a simulated error limit allowing `budget` error responses within each `window`
+    ///
+    /// # Arguments
+    /// - `budget` (`u32`): Number of error responses allowed before requests are error limited
+    /// - `window` (`Duration`): Time after which the number of error responses resets
+    pub fn new(budget: u32, window: Duration) -> Self {
+        Self { budget, window }
+    }
+
+    /// Returns the number of error responses allowed within each window
+    pub fn budget(&self) -> u32 {
+        self.budget
+    }
+
+    /// Returns the time after which the number of error responses resets
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+}
+
+impl Default for ErrorLimitSimulation {
+    /// Simulates ESI's error limit of 100 error responses within 60 seconds
+    fn default() -> Self {
+        Self::new(100, Duration::from_secs(60))
+    }
+}
+
+/// Error responses counted within the current window of a simulated error limit
+struct ErrorLimitWindow {
+    started: Instant,
+    errors: u32,
+}
+
+/// Simulated error limit state held by the [`Client`](crate::Client)
+pub(crate) struct SimulatedErrorLimit {
+    simulation: ErrorLimitSimulation,
+    window: Mutex<ErrorLimitWindow>,
+}
+
+impl SimulatedErrorLimit {
+    /// Creates the state of a simulated error limit starting with a full budget
+    pub(crate) fn new(simulation: ErrorLimitSimulation) -> Self {
+        Self {
+            simulation,
+            window: Mutex::new(ErrorLimitWindow {
+                started: Instant::now(),
+                errors: 0,
+            }),
+        }
+    }
+
+    /// Returns the remaining budget & time until the window resets, starting a new window if elapsed
+    fn remaining(&self, record_error: bool) -> (u32, Duration) {
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+
+        if window.started.elapsed() >= self.simulation.window {
+            window.started = Instant::now();
+            window.errors = 0;
+        }
+
+        if record_error {
+            window.errors = window.errors.saturating_add(1);
+        }
+
+        let remain = self.simulation.budget.saturating_sub(window.errors);
+        let reset_in = self
+            .simulation
+            .window
+            .saturating_sub(window.started.elapsed());
+
+        (remain, reset_in)
+    }
+
+    /// Returns the time until the window resets if the budget has been spent
+    pub(crate) fn exhausted(&self) -> Option<Duration> {
+        match self.remaining(false) {
+            (0, reset_in) => Some(reset_in),
+            _ => None,
+        }
+    }
+
+    /// Counts the response against the budget if it is an error & sets the simulated error limit headers
+    ///
+    /// # Arguments
+    /// - `response` (&mut [`reqwest::Response`]): The response returned for a request
+    pub(crate) fn record_response(&self, response: &mut reqwest::Response) {
+        let status = response.status();
+        let (remain, reset_in) =
+            self.remaining(status.is_client_error() || status.is_server_error());
+
+        Self::set_headers(response.headers_mut(), remain, reset_in);
+    }
+
+    /// Sets the `X-ESI-Error-Limit-Remain` & `X-ESI-Error-Limit-Reset` headers to the simulated values
+    fn set_headers(headers: &mut HeaderMap, remain: u32, reset_in: Duration) {
+        // Round up so the reset is never reported before the window has actually passed
+        let reset_secs = reset_in.as_secs() + u64::from(reset_in.subsec_nanos() > 0);
+
+        headers.insert("x-esi-error-limit-remain", HeaderValue::from(remain));
+        headers.insert("x-esi-error-limit-reset", HeaderValue::from(reset_secs));
+    }
+}
+
+#[cfg(test)]
+mod simulated_error_limit_tests {
+    use std::time::Duration;
+
+    use super::{ErrorLimitSimulation, SimulatedErrorLimit};
+
+    /// Tests the budget is spent by error responses & restored once the window passes
+    ///
+    /// # Test Setup
+    /// - Create a simulated error limit with a budget of 2 errors within 50 milliseconds
+    ///
+    /// # Assertions
+    /// - Assert the limit is not exhausted until the budget is spent
+    /// - Assert the limit is exhausted once the budget is spent
+    /// - Assert the budget is restored once the window passes
+    #[test]
+    fn test_simulated_error_limit_window() {
+        let limit =
+            SimulatedErrorLimit::new(ErrorLimitSimulation::new(2, Duration::from_millis(50)));
+
+        // Assert the limit is not exhausted until the budget is spent
+        assert_eq!(limit.remaining(true).0, 1);
+        assert!(limit.exhausted().is_none());
+
+        // Assert the limit is exhausted once the budget is spent
+        assert_eq!(limit.remaining(true).0, 0);
+        let reset_in = limit.exhausted().expect("Expected exhausted budget");
+        assert!(reset_in <= Duration::from_millis(50));
+
+        // Assert the budget is restored once the window passes
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(limit.exhausted().is_none());
+        assert_eq!(limit.remaining(false).0, 2);
+    }
+}
@@ -0,0 +1,214 @@
+//! Downloads of images hosted on EVE Online's image server.
+//!
+//! Endpoints such as
+//! [`CorporationEndpoints::get_corporation_icon`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_icon)
+//! return URLs of images rather than the images themselves. [`EsiApi::download_image`] retrieves
+//! the bytes of an image with the client's HTTP client, so applications serving or posting images
+//! don't need a second HTTP client. Helpers such as
+//! [`CorporationEndpoints::get_corporation_icon_image`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_icon_image)
+//! look up the icon URL & download it in one call.
+//!
+//! Images rarely change, the cache headers of each download are returned with the image so
+//! applications can store it & revalidate it later with a conditional request, receiving
+//! [`CachedResponse::NotModified`] rather than the image again while it is unchanged.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::esi::{CachedResponse, IconSize};
+//!
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let icon = esi_client
+//!     .corporation()
+//!     .get_corporation_icon_image(98785281, IconSize::Px128)
+//!     .await?;
+//! println!("Downloaded {} bytes of {}", icon.bytes.len(), icon.content_type);
+//!
+//! // Later, only download the icon again if it has changed
+//! let url = esi_client
+//!     .corporation()
+//!     .get_corporation_icon(98785281)
+//!     .send()
+//!     .await?
+//!     .url(IconSize::Px128)
+//!     .to_string();
+//!
+//! match esi_client.esi().download_image(&url, Some(icon.cache_strategy())).await? {
+//!     CachedResponse::Fresh(image) => println!("Icon changed, {} bytes", image.bytes.len()),
+//!     CachedResponse::NotModified => println!("Icon unchanged"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use log::debug;
+use reqwest::StatusCode;
+
+use crate::Error;
+
+use super::{CacheHeaders, CacheStrategy, CachedResponse, EsiApi};
+
+/// Size variant of a corporation or alliance icon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconSize {
+    /// 64x64 pixels
+    Px64,
+    /// 128x128 pixels
+    Px128,
+    /// 256x256 pixels, only provided for corporations
+    Px256,
+}
+
+/// An image downloaded from EVE Online's image server
+#[derive(Debug, Clone)]
+pub struct Image {
+    /// The raw bytes of the image
+    pub bytes: Vec<u8>,
+
+    /// MIME type of the image from the `Content-Type` header, e.g. `image/png`
+    ///
+    /// `application/octet-stream` if the header is missing.
+    pub content_type: String,
+
+    /// Caching headers of the response, used to revalidate the image with [`Self::cache_strategy`]
+    pub cache: CacheHeaders,
+}
+
+impl Image {
+    /// Returns the strategy to revalidate the image with a conditional request
+    ///
+    /// Uses the ETag of the image if provided, otherwise its last modified date.
+    pub fn cache_strategy(&self) -> CacheStrategy {
+        if self.cache.etag.is_empty() {
+            return CacheStrategy::IfModifiedSince(self.cache.last_modified);
+        }
+
+        CacheStrategy::IfNoneMatch(self.cache.etag.clone())
+    }
+}
+
+impl<'a> EsiApi<'a> {
+    /// Downloads an image, such as an icon URL returned by ESI
+    ///
+    /// The image is requested with the client's HTTP client & user agent, without the retries &
+    /// error limit handling of ESI requests.
+    ///
+    /// For an overview & usage example, see the [module-level documentation](self)
+    ///
+    /// # Arguments
+    /// - `url` (`&str`): URL of the image
+    /// - `strategy` (`Option<CacheStrategy>`): Conditional headers to send, e.g. from
+    ///   [`Image::cache_strategy`] of a previous download, `None` to always download the image
+    ///
+    /// # Returns
+    /// - [`CachedResponse::Fresh`]: The image with its content type & cache headers
+    /// - [`CachedResponse::NotModified`]: If the image hasn't changed since the conditional headers
+    ///
+    /// # Errors
+    /// - [`Error::ReqwestError`]: If the request fails or the image server returns an error status
+    pub async fn download_image(
+        &self,
+        url: &str,
+        strategy: Option<CacheStrategy>,
+    ) -> Result<CachedResponse<Image>, Error> {
+        debug!("Downloading image: {}", url);
+
+        let mut request = self.client.inner.reqwest_client.get(url);
+
+        match strategy {
+            Some(CacheStrategy::IfNoneMatch(etag)) => {
+                request = request.header("If-None-Match", etag);
+            }
+            Some(CacheStrategy::IfModifiedSince(date)) => {
+                request = request.header("If-Modified-Since", date.to_rfc2822());
+            }
+            Some(CacheStrategy::Both {
+                etag,
+                modified_since,
+            }) => {
+                request = request
+                    .header("If-None-Match", etag)
+                    .header("If-Modified-Since", modified_since.to_rfc2822());
+            }
+            None => {}
+        }
+
+        let response = request.send().await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(CachedResponse::NotModified);
+        }
+
+        Ok(CachedResponse::Fresh(read_image(response).await?))
+    }
+
+    /// Downloads an image without conditional headers
+    #[cfg(any(feature = "alliance", feature = "corporation"))]
+    pub(crate) async fn download_fresh_image(&self, url: &str) -> Result<Image, Error> {
+        debug!("Downloading image: {}", url);
+
+        let response = self.client.inner.reqwest_client.get(url).send().await?;
+
+        read_image(response).await
+    }
+}
+
+/// Reads the image & its headers from a response, returning an error for error statuses
+async fn read_image(response: reqwest::Response) -> Result<Image, Error> {
+    let response = response.error_for_status()?;
+    let headers = response.headers();
+
+    let content_type = headers
+        .get("content-type")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+    let cache = EsiApi::extract_cache_headers(headers);
+
+    let bytes = response.bytes().await?.to_vec();
+
+    Ok(Image {
+        bytes,
+        content_type,
+        cache,
+    })
+}
+
+#[cfg(test)]
+mod image_tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    /// Tests the cache strategy prefers the ETag of the image
+    ///
+    /// # Assertions
+    /// - Assert the ETag is used when present
+    /// - Assert the last modified date is used without an ETag
+    #[test]
+    fn test_cache_strategy() {
+        let last_modified = Utc::now();
+        let mut image = Image {
+            bytes: Vec::new(),
+            content_type: "image/png".to_string(),
+            cache: CacheHeaders {
+                cache_control: String::new(),
+                etag: "\"abc\"".to_string(),
+                last_modified,
+            },
+        };
+
+        // Assert the ETag is used when present
+        assert!(matches!(
+            image.cache_strategy(),
+            CacheStrategy::IfNoneMatch(etag) if etag == "\"abc\""
+        ));
+
+        // Assert the last modified date is used without an ETag
+        image.cache.etag.clear();
+        assert!(matches!(
+            image.cache_strategy(),
+            CacheStrategy::IfModifiedSince(date) if date == last_modified
+        ));
+    }
+}
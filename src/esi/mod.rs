@@ -7,8 +7,17 @@
 //! - **[`EsiRequest`]**: Builder for configuring ESI requests with headers, authentication, and body data
 //! - **[`CacheStrategy`]**: Type-safe caching strategy with `chrono::DateTime` for conditional requests
 //! - **[`CachedResponse`]**: Response type that handles 304 Not Modified responses
+//! - **[`ResponseCache`]**: Storage for response bodies reused on 304 Not Modified responses, see [`cache`]
 //! - **[`Language`]**: Type-safe enum for ESI language headers
+//! - **[`Idempotency`]**: Whether a request is safe to retry after a failed attempt
 //! - **[`EsiApi`]**: Request executor that handles authentication and HTTP communication
+//! - **[`DeprecatedRoute`]**: Report entry for deprecated ESI routes used by the client
+//! - **[`ErrorLimitSimulation`]**: Simulated ESI error limit for load testing, see [`error_limit`]
+//! - **[`NameCache`]**: Long-lived cache of names resolved from IDs, see [`name_cache`]
+//! - **[`try_join_limited`]**: Sends many requests with bounded concurrency, see [`concurrency`]
+//! - **[`join_limited_partial`]**: Sends many requests with bounded concurrency, keeping partial data on failures
+//! - **[`Image`]**: Image downloaded from EVE Online's image server, see [`image`]
+//! - **[`Cursor`]**: Iterates over the pages of cursor paginated routes, see [`cursor`]
 //!
 //! ## Basic Usage
 //!
@@ -66,6 +75,28 @@
 //! # }
 //! ```
 //!
+//! To persist ETags yourself, e.g. alongside the data in a database, set the stored ETag with
+//! [`EsiRequest::with_etag`], receive the ETag of each fresh response with [`EsiRequest::on_etag`]
+//! & send the request with [`EsiRequest::send_conditional`]:
+//!
+//! ```no_run
+//! # use serde::Deserialize;
+//! # #[derive(Deserialize)]
+//! # struct ServerStatus {
+//! #     players: i32,
+//! # }
+//! # async fn example(client: eve_esi::Client, stored_etag: Option<String>) -> Result<(), eve_esi::Error> {
+//! let response = client
+//!     .esi()
+//!     .new_request::<ServerStatus>("/status/")
+//!     .with_etag(stored_etag)
+//!     .on_etag(|etag| println!("Store ETag {}", etag))
+//!     .send_conditional()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Authenticated Requests
 //!
 //! ```no_run
@@ -89,9 +120,75 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Custom Routes
+//!
+//! Routes the crate does not provide, such as routes added to ESI after a release, are defined
+//! with the same building blocks the crate's endpoints use:
+//! - Path parameters are formatted into the path passed to [`EsiApi::new_request`]
+//! - [`EsiRequest::with_query`] appends query parameters, omitting `None` values
+//! - [`EsiRequest::with_body`] serializes the request body to JSON
+//! - [`EsiRequest::with_access_token`] & [`EsiRequest::with_required_scopes`] enable token
+//!   validation & the scope check before the request is sent, scopes not known to the crate can
+//!   be added with [`ScopeBuilder::custom`](crate::ScopeBuilder::custom)
+//!
+//! ```no_run
+//! use eve_esi::{scope::CorporationsScopes, Client, EsiRequest, ScopeBuilder};
+//! use reqwest::Method;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize)]
+//! struct ApplicationResponse {
+//!     accepted: bool,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Application {
+//!     application_id: i64,
+//!     character_id: i64,
+//! }
+//!
+//! /// Responds to an application to join a corporation
+//! fn respond_to_application(
+//!     client: &Client,
+//!     access_token: &str,
+//!     corporation_id: i64,
+//!     application_id: i64,
+//!     notify: Option<bool>,
+//!     accepted: bool,
+//! ) -> EsiRequest<Application> {
+//!     client
+//!         .esi()
+//!         .new_request(format!(
+//!             "/corporations/{}/applications/{}",
+//!             corporation_id, application_id
+//!         ))
+//!         .with_method(Method::PUT)
+//!         .with_query("notify", notify)
+//!         .with_body(ApplicationResponse { accepted })
+//!         .with_access_token(access_token)
+//!         .with_required_scopes(
+//!             ScopeBuilder::new()
+//!                 .corporations(CorporationsScopes::new().read_corporation_membership())
+//!                 .custom("esi-corporations.write_applications.v1")
+//!                 .build(),
+//!         )
+//! }
+//! ```
 
 // Submodules
+mod access_token;
+pub mod cache;
+#[cfg(feature = "record-replay")]
+pub mod cassette;
 mod client;
+pub mod concurrency;
+pub mod cursor;
+mod deprecation;
+pub mod error_limit;
+pub mod image;
+#[cfg(feature = "universe")]
+pub mod name_cache;
 mod request;
 mod response;
 
@@ -99,9 +196,28 @@ mod response;
 mod tests;
 
 // Re-export public API
+pub use access_token::AccessToken;
+#[cfg(feature = "redis")]
+pub use cache::RedisResponseCache;
+pub use cache::{CacheEntry, FileResponseCache, MemoryResponseCache, ResponseCache};
+#[cfg(feature = "record-replay")]
+pub use cassette::{Cassette, CassetteMode, Interaction};
 pub use client::EsiApi;
-pub use request::{CacheStrategy, EsiRequest, Language};
-pub use response::{CacheHeaders, CachedResponse, EsiResponse, RateLimitHeaders};
+pub use concurrency::{join_limited_partial, try_join_limited};
+pub use cursor::{Cursor, CursorPage, CursorTokens};
+pub use deprecation::DeprecatedRoute;
+pub use error_limit::ErrorLimitSimulation;
+pub use image::{IconSize, Image};
+#[cfg(feature = "universe")]
+pub use name_cache::NameCache;
+pub use request::{CacheStrategy, EsiRequest, Idempotency, Language};
+pub use response::{
+    CacheHeaders, CachedResponse, EsiResponse, MeasuredResponse, RateLimitHeaders, RawEsiResponse,
+    RequestTiming,
+};
 
 // Internal utilities
+pub(crate) use concurrency::RequestLimiter;
+pub(crate) use deprecation::DeprecationRegistry;
+pub(crate) use error_limit::SimulatedErrorLimit;
 mod util;
@@ -0,0 +1,215 @@
+//! Long-lived cache of names resolved from IDs.
+//!
+//! Names of types, solar systems, characters, corporations & alliances almost never change, yet
+//! applications commonly resolve the same IDs over & over. Each [`Client`](crate::Client) holds a
+//! [`NameCache`], accessible with [`Client::name_cache`](crate::Client::name_cache), which
+//! [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names)
+//! & the composite methods built on it consult before requesting names from ESI.
+//!
+//! Entries expire after a configurable time to live & the oldest entries are evicted once the
+//! cache reaches its configurable maximum size, see
+//! [`ConfigBuilder::name_cache_ttl`](crate::ConfigBuilder::name_cache_ttl) &
+//! [`ConfigBuilder::name_cache_max_size`](crate::ConfigBuilder::name_cache_max_size).
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::model::enums::universe::UniverseNameCategory;
+use crate::model::universe::UniverseName;
+
+/// Cache of names & categories keyed by ID, see the [module documentation](self)
+///
+/// IDs are unique across categories so each ID holds a single entry.
+#[derive(Debug)]
+pub struct NameCache {
+    max_size: usize,
+    ttl: Duration,
+    state: Mutex<NameCacheState>,
+}
+
+/// Entries of a [`NameCache`] & the order they were inserted in
+#[derive(Debug, Default)]
+struct NameCacheState {
+    entries: HashMap<i64, NameCacheEntry>,
+    /// IDs & insertion sequence numbers, oldest first, used to evict the oldest entries
+    order: VecDeque<(i64, u64)>,
+    /// Sequence number assigned to the next inserted entry
+    sequence: u64,
+}
+
+/// A cached name & when it was inserted
+#[derive(Debug)]
+struct NameCacheEntry {
+    category: UniverseNameCategory,
+    name: String,
+    inserted_at: Instant,
+    sequence: u64,
+}
+
+impl NameCache {
+    /// Creates an empty [`NameCache`]
+    ///
+    /// # Arguments
+    /// - `max_size` (`usize`): Maximum number of entries before the oldest are evicted, `0` disables caching
+    /// - `ttl` (`Duration`): Time after which an entry expires
+    pub fn new(max_size: usize, ttl: Duration) -> Self {
+        Self {
+            max_size,
+            ttl,
+            state: Mutex::new(NameCacheState::default()),
+        }
+    }
+
+    /// Returns the cached name & category of an ID if present & not expired
+    ///
+    /// # Arguments
+    /// - `id` (`i64`): The ID to retrieve the name of
+    pub fn get(&self, id: i64) -> Option<UniverseName> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        state
+            .entries
+            .get(&id)
+            .filter(|entry| entry.inserted_at.elapsed() < self.ttl)
+            .map(|entry| UniverseName {
+                category: entry.category,
+                id,
+                name: entry.name.clone(),
+            })
+    }
+
+    /// Returns the cached name of an ID if present, not expired & of the provided category
+    ///
+    /// # Arguments
+    /// - `id` (`i64`): The ID to retrieve the name of
+    /// - `category` ([`UniverseNameCategory`]): The category the ID is expected to belong to
+    pub fn get_name(&self, id: i64, category: UniverseNameCategory) -> Option<String> {
+        self.get(id)
+            .filter(|entry| entry.category == category)
+            .map(|entry| entry.name)
+    }
+
+    /// Inserts names into the cache, replacing any existing entries for the same IDs
+    ///
+    /// # Arguments
+    /// - `names` (`impl IntoIterator<Item = UniverseName>`): The names to cache
+    pub fn insert(&self, names: impl IntoIterator<Item = UniverseName>) {
+        if self.max_size == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        for name in names {
+            let sequence = state.sequence;
+            state.sequence += 1;
+
+            state.order.push_back((name.id, sequence));
+            state.entries.insert(
+                name.id,
+                NameCacheEntry {
+                    category: name.category,
+                    name: name.name,
+                    inserted_at: Instant::now(),
+                    sequence,
+                },
+            );
+        }
+
+        // Evict the oldest entries, skipping order entries replaced by a newer insert
+        while state.entries.len() > self.max_size {
+            let Some((id, sequence)) = state.order.pop_front() else {
+                break;
+            };
+
+            if state
+                .entries
+                .get(&id)
+                .is_some_and(|entry| entry.sequence == sequence)
+            {
+                state.entries.remove(&id);
+            }
+        }
+    }
+
+    /// Returns the maximum number of entries held before the oldest are evicted
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// Returns the number of entries in the cache, including expired entries not yet evicted
+    pub fn len(&self) -> usize {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        state.entries.len()
+    }
+
+    /// Returns `true` if the cache contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all entries from the cache
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        *state = NameCacheState::default();
+    }
+}
+
+#[cfg(test)]
+mod name_cache_tests {
+    use super::*;
+
+    fn mock_name(id: i64, name: &str) -> UniverseName {
+        UniverseName {
+            category: UniverseNameCategory::SolarSystem,
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    /// Tests the oldest entries are evicted once the maximum size is reached
+    ///
+    /// # Test Setup
+    /// - Create a cache with a maximum size of 2
+    ///
+    /// # Assertions
+    /// - Assert the oldest entry is evicted after inserting a third entry
+    /// - Assert a replaced entry is treated as newly inserted
+    /// - Assert names are only returned for the matching category
+    #[test]
+    fn test_name_cache_eviction() {
+        let cache = NameCache::new(2, Duration::from_secs(60));
+
+        cache.insert([mock_name(1, "Jita"), mock_name(2, "Amarr")]);
+        cache.insert([mock_name(1, "Jita")]);
+        cache.insert([mock_name(3, "Dodixie")]);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(2), None);
+        assert_eq!(cache.get(1), Some(mock_name(1, "Jita")));
+        assert_eq!(
+            cache.get_name(3, UniverseNameCategory::SolarSystem),
+            Some("Dodixie".to_string())
+        );
+        assert_eq!(cache.get_name(3, UniverseNameCategory::Character), None);
+    }
+
+    /// Tests expired entries & disabled caches return no names
+    ///
+    /// # Assertions
+    /// - Assert an entry is not returned once its time to live has passed
+    /// - Assert a cache with a maximum size of 0 stores nothing
+    #[test]
+    fn test_name_cache_expiry_and_disabled() {
+        let cache = NameCache::new(10, Duration::ZERO);
+        cache.insert([mock_name(1, "Jita")]);
+        assert_eq!(cache.get(1), None);
+
+        let cache = NameCache::new(0, Duration::from_secs(60));
+        cache.insert([mock_name(1, "Jita")]);
+        assert!(cache.is_empty());
+    }
+}
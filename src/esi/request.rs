@@ -32,15 +32,20 @@
 //! ```
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
 
-use crate::{esi::EsiResponse, Client, Error};
+use crate::{
+    esi::{Cursor, CursorPage, EsiApi, EsiResponse, MeasuredResponse, RawEsiResponse},
+    Client, Error,
+};
 
-use super::CachedResponse;
+use super::{AccessToken, CachedResponse, ResponseCache};
 
 /// Strategy for conditional caching requests to ESI.
 ///
@@ -76,11 +81,49 @@ pub enum CacheStrategy {
     },
 }
 
+/// Whether an ESI request is safe to retry after a failed attempt.
+///
+/// Requests failing with a network error or 5xx response may still have been processed by ESI, so
+/// retrying them is only safe if repeating the request has the same effect as sending it once.
+/// Requests which may duplicate their effect, such as sending a mail, fail on the first error
+/// rather than being retried up to
+/// [`ConfigBuilder::esi_max_retries`](crate::ConfigBuilder::esi_max_retries) times.
+///
+/// Endpoints which write data declare their class with
+/// [`define_esi_endpoint!`](crate::define_esi_endpoint). Other requests are classed by their
+/// HTTP method, see [`EsiRequest::idempotency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Idempotency {
+    /// Repeating the request has the same effect as sending it once, e.g. reads & fleet invitations
+    Safe,
+    /// Repeating the request may duplicate its effect, e.g. sending a mail or adding contacts
+    Unsafe,
+}
+
+impl Idempotency {
+    /// Returns the class of a request by its HTTP method, `POST` & `PATCH` requests are [`Self::Unsafe`]
+    ///
+    /// # Arguments
+    /// - `method` (&[`Method`]): The HTTP method of the request
+    pub fn from_method(method: &Method) -> Self {
+        if *method == Method::POST || *method == Method::PATCH {
+            Self::Unsafe
+        } else {
+            Self::Safe
+        }
+    }
+}
+
+/// Callback invoked with the page number & total number of pages as each page is retrieved
+type PageCallback = Arc<dyn Fn(i32, i32) + Send + Sync>;
+
+/// Callback invoked with the ETag of each successful response
+pub(crate) type EtagCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
 /// Builder for ESI API requests with configurable headers and authentication.
 ///
 /// Provides a fluent interface for setting endpoint URLs, authentication tokens,
 /// and ESI-specific HTTP headers like compatibility date, language, and caching headers.
-#[derive(Clone)]
 pub struct EsiRequest<T> {
     /// The ESI client (cloned - uses Arc internally so this is cheap)
     client: Client,
@@ -89,17 +132,55 @@ pub struct EsiRequest<T> {
     /// HTTP method for the request (GET, POST, PUT, DELETE, PATCH)
     method: Method,
     /// Access token used to access authenticated endpoints
-    access_token: Option<String>,
+    access_token: Option<AccessToken>,
     /// Required OAuth2 scopes for authenticated requests
     required_scopes: Vec<String>,
     /// Optional JSON body data for POST, PUT, PATCH requests
     body_json: Option<Value>,
     /// Headers to send with ESI request
     headers: HashMap<String, String>,
+    /// Per-request override of the client's `esi_validate_token_before_request` setting
+    validate_token: Option<bool>,
+    /// Per-request override of the client's `esi_max_response_size` setting
+    max_response_size: Option<u64>,
+    /// Whether the request is safe to retry, classed by the HTTP method if not set
+    idempotency: Option<Idempotency>,
+    /// Progress callback invoked by [`EsiRequest::send_all_pages`] after each page is retrieved
+    on_page: Option<PageCallback>,
+    /// Callback invoked with the ETag of each successful response, see [`EsiRequest::on_etag`]
+    on_etag: Option<EtagCallback>,
+    /// Error serializing the body or a query parameter, returned when the request is sent
+    serialization_error: Option<serde_json::Error>,
     /// Phantom data to hold the response type
     _phantom: std::marker::PhantomData<T>,
 }
 
+// Implemented manually as deriving would require the response type `T` to implement `Clone`
+impl<T> Clone for EsiRequest<T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            endpoint: self.endpoint.clone(),
+            method: self.method.clone(),
+            access_token: self.access_token.clone(),
+            required_scopes: self.required_scopes.clone(),
+            body_json: self.body_json.clone(),
+            headers: self.headers.clone(),
+            validate_token: self.validate_token,
+            max_response_size: self.max_response_size,
+            idempotency: self.idempotency,
+            on_page: self.on_page.clone(),
+            on_etag: self.on_etag.clone(),
+            // serde_json::Error is not Clone, the clone keeps the error's message
+            serialization_error: self
+                .serialization_error
+                .as_ref()
+                .map(<serde_json::Error as serde::ser::Error>::custom),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
 impl<T: DeserializeOwned> EsiRequest<T> {
     /// Creates a new [`EsiRequest`] with the specified client and endpoint path.
     ///
@@ -144,6 +225,12 @@ impl<T: DeserializeOwned> EsiRequest<T> {
             required_scopes: Vec::new(),
             body_json: None,
             headers: HashMap::new(),
+            validate_token: None,
+            max_response_size: None,
+            idempotency: None,
+            on_page: None,
+            on_etag: None,
+            serialization_error: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -160,18 +247,205 @@ impl<T: DeserializeOwned> EsiRequest<T> {
         self
     }
 
+    /// Sets whether the request is safe to retry after a failed attempt.
+    ///
+    /// Overrides the class derived from the HTTP method, see [`Idempotency`].
+    ///
+    /// # Arguments
+    /// - `idempotency`: Whether repeating the request has the same effect as sending it once
+    ///
+    /// # Returns
+    /// Updated instance with the idempotency class set
+    pub fn with_idempotency(mut self, idempotency: Idempotency) -> Self {
+        self.idempotency = Some(idempotency);
+        self
+    }
+
+    /// Returns whether the request is safe to retry after a failed attempt.
+    ///
+    /// # Returns
+    /// The class set with [`Self::with_idempotency`], otherwise the class of the HTTP method, see
+    /// [`Idempotency::from_method`]
+    pub fn idempotency(&self) -> Idempotency {
+        self.idempotency
+            .unwrap_or_else(|| Idempotency::from_method(&self.method))
+    }
+
     /// Sets the access token for authenticated ESI requests.
     ///
     /// # Arguments
-    /// - `token`: The OAuth2 access token
+    /// - `token`: The OAuth2 access token, see [`AccessToken`]
     ///
     /// # Returns
     /// Updated instance with the access token set
-    pub fn with_access_token(mut self, token: impl Into<String>) -> Self {
+    pub fn with_access_token(mut self, token: impl Into<AccessToken>) -> Self {
         self.access_token = Some(token.into());
         self
     }
 
+    /// Skips access token validation for this request regardless of client configuration.
+    ///
+    /// Useful for hot paths where the token was just validated and the additional
+    /// JWT key lookup, expiration, and scope checks would be redundant.
+    ///
+    /// # Returns
+    /// Updated instance with token validation disabled for this request
+    pub fn skip_token_validation(mut self) -> Self {
+        self.validate_token = Some(false);
+        self
+    }
+
+    /// Requires access token validation for this request regardless of client configuration.
+    ///
+    /// The token will be validated and checked for expiration & required scopes prior to
+    /// sending the request even if `esi_validate_token_before_request` is disabled on the client.
+    ///
+    /// # Returns
+    /// Updated instance with token validation enabled for this request
+    pub fn require_token_validation(mut self) -> Self {
+        self.validate_token = Some(true);
+        self
+    }
+
+    /// Returns the per-request token validation override if set.
+    ///
+    /// # Returns
+    /// - `Some(bool)`: Whether the token will be validated, overriding the client configuration
+    /// - `None`: The client's `esi_validate_token_before_request` setting is used
+    pub fn token_validation(&self) -> Option<bool> {
+        self.validate_token
+    }
+
+    /// Sets the maximum size of the response body for this request, overriding client configuration.
+    ///
+    /// The request fails with [`Error::ResponseTooLarge`] once the response body exceeds the limit,
+    /// see [`ConfigBuilder::esi_max_response_size`](crate::ConfigBuilder::esi_max_response_size).
+    ///
+    /// # Arguments
+    /// - `bytes`: Maximum size of the response body in bytes
+    ///
+    /// # Returns
+    /// Updated instance with the maximum response size set
+    pub fn with_max_response_size(mut self, bytes: u64) -> Self {
+        self.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Returns the per-request maximum response size override if set.
+    ///
+    /// # Returns
+    /// - `Some(u64)`: Maximum size of the response body in bytes, overriding the client configuration
+    /// - `None`: The client's `esi_max_response_size` setting is used
+    pub fn max_response_size(&self) -> Option<u64> {
+        self.max_response_size
+    }
+
+    /// Sets the `page` query parameter for paginated endpoints.
+    ///
+    /// Replaces the existing `page` query parameter if present.
+    ///
+    /// # Arguments
+    /// - `page`: The page to request, page numbers start at `1`
+    ///
+    /// # Returns
+    /// Updated instance with the page query parameter set
+    pub fn with_page(self, page: i32) -> Self {
+        self.with_query_replaced("page", &page.to_string())
+    }
+
+    /// Sets a query parameter, replacing any existing values of the parameter.
+    ///
+    /// Used for the `page` parameter & the tokens of cursor paginated routes, see
+    /// [`Cursor`](crate::esi::Cursor).
+    pub(crate) fn with_query_replaced(mut self, name: &str, value: &str) -> Self {
+        if let Ok(mut url) = url::Url::parse(&self.endpoint) {
+            // Retain all query parameters other than the one being replaced
+            let query: Vec<(String, String)> = url
+                .query_pairs()
+                .filter(|(key, _)| key != name)
+                .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                .collect();
+
+            url.query_pairs_mut()
+                .clear()
+                .extend_pairs(query)
+                .append_pair(name, value);
+
+            self.endpoint = url.to_string();
+        }
+
+        self
+    }
+
+    /// Appends a query parameter to the endpoint URL.
+    ///
+    /// Values are encoded the same way as the query parameters of the crate's endpoints: strings
+    /// such as serialized enum values are added without JSON quotes, other values as their JSON
+    /// representation & `None` values are omitted. If the value fails to serialize, sending the
+    /// request returns an [`Error::SerdeJsonError`] without making a request. Used together with
+    /// [`Self::with_access_token`], [`Self::with_required_scopes`] & [`Self::with_body`] to
+    /// define routes the crate does not provide, see the
+    /// [module documentation](crate::esi#custom-routes).
+    ///
+    /// # Arguments
+    /// - `name`: The name of the query parameter
+    /// - `value`: The value of the query parameter, any type implementing `Serialize`
+    ///
+    /// # Returns
+    /// Updated instance with the query parameter appended
+    pub fn with_query(mut self, name: &str, value: impl Serialize) -> Self {
+        let value = match query_value(&value) {
+            Ok(Some(value)) => value,
+            Ok(None) => return self,
+            Err(error) => return self.with_serialization_error(error),
+        };
+
+        if let Ok(mut url) = url::Url::parse(&self.endpoint) {
+            url.query_pairs_mut().append_pair(name, &value);
+
+            self.endpoint = url.to_string();
+        }
+
+        self
+    }
+
+    /// Sets the request body, serialized to JSON.
+    ///
+    /// If serialization fails, sending the request returns an [`Error::SerdeJsonError`] without
+    /// making a request.
+    ///
+    /// # Arguments
+    /// - `body`: The body to send, any type implementing `Serialize` such as the typed request
+    ///   bodies of [`crate::model::requests`]
+    ///
+    /// # Returns
+    /// Updated instance with the body JSON set
+    pub fn with_body(self, body: impl Serialize) -> Self {
+        match serde_json::to_value(&body) {
+            Ok(body) => self.with_body_json(body),
+            Err(error) => self.with_serialization_error(error),
+        }
+    }
+
+    /// Stores an error serializing the body or a query parameter, keeping the first error
+    fn with_serialization_error(mut self, error: serde_json::Error) -> Self {
+        self.serialization_error.get_or_insert(error);
+        self
+    }
+
+    /// Returns an error if the body or a query parameter failed to serialize.
+    ///
+    /// Checked before the request is sent so that no request is made with a missing body or
+    /// query parameter.
+    pub(crate) fn check_serialization(&self) -> Result<(), Error> {
+        match &self.serialization_error {
+            Some(error) => Err(Error::SerdeJsonError(
+                <serde_json::Error as serde::ser::Error>::custom(error),
+            )),
+            None => Ok(()),
+        }
+    }
+
     /// Sets the `X-Compatibility-Date` header (required by ESI).
     ///
     /// This header ensures API compatibility for breaking changes.
@@ -229,6 +503,54 @@ impl<T: DeserializeOwned> EsiRequest<T> {
         self
     }
 
+    /// Sets the `If-None-Match` header to an ETag stored from a previous response, if any.
+    ///
+    /// Intended for applications persisting ETags themselves, e.g. alongside the data in their
+    /// database with [`Self::on_etag`], rather than using a [`ResponseCache`]. Passing `None`
+    /// removes the header so the request is sent unconditionally.
+    ///
+    /// Send the request with [`Self::send_conditional`] to handle the 304 Not Modified response
+    /// returned when the resource has not changed.
+    ///
+    /// # Arguments
+    /// - `etag`: The ETag of the previous response, `None` if there is no previous response
+    ///
+    /// # Returns
+    /// Updated instance with the If-None-Match header set or removed
+    pub fn with_etag(mut self, etag: Option<String>) -> Self {
+        match etag {
+            Some(etag) => {
+                self.headers.insert("If-None-Match".to_string(), etag);
+            }
+            None => {
+                self.headers.remove("If-None-Match");
+            }
+        }
+        self
+    }
+
+    /// Registers a callback invoked with the ETag of each successful response to the request.
+    ///
+    /// The callback is invoked for every 2xx response which includes an `ETag` header, including each
+    /// page retrieved by [`EsiRequest::send_all_pages`], allowing the ETag to be persisted for use
+    /// with [`Self::with_etag`] on the next request. 304 Not Modified responses don't invoke it as
+    /// the stored ETag is still current.
+    ///
+    /// # Arguments
+    /// - `callback`: Function called with the ETag of each successful response
+    ///
+    /// # Returns
+    /// Updated instance with the ETag callback set
+    pub fn on_etag(mut self, callback: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.on_etag = Some(Arc::new(callback));
+        self
+    }
+
+    /// Returns the callback registered with [`Self::on_etag`], if any.
+    pub(crate) fn etag_callback(&self) -> Option<&EtagCallback> {
+        self.on_etag.as_ref()
+    }
+
     /// Sets a custom header for the request.
     ///
     /// Use this for headers not covered by the other methods.
@@ -258,7 +580,7 @@ impl<T: DeserializeOwned> EsiRequest<T> {
     /// `Some(&str)`: Reference to the access token string if present
     /// `None`: No access token is set
     pub fn access_token(&self) -> Option<&str> {
-        self.access_token.as_deref()
+        self.access_token.as_ref().map(AccessToken::secret)
     }
 
     /// Sets the required OAuth2 scopes for authenticated requests.
@@ -331,6 +653,86 @@ impl<T: DeserializeOwned> EsiRequest<T> {
         self.client.esi().request(&self).await
     }
 
+    /// Consumes the [`EsiRequest`] and sends it, returning the raw response headers alongside the response.
+    pub(crate) async fn send_with_headers(
+        self,
+    ) -> Result<(EsiResponse<T>, reqwest::header::HeaderMap), Error> {
+        self.client.esi().request_with_headers(&self).await
+    }
+
+    /// Consumes the [`EsiRequest`] and sends it, measuring the time spent on the request.
+    ///
+    /// Returns the response alongside a [`RequestTiming`](crate::esi::RequestTiming) breakdown &
+    /// the ESI request ID, e.g. for recording ESI latency on a dashboard or quoting the request ID
+    /// when reporting an issue to CCP.
+    ///
+    /// # Returns
+    /// A Result containing a [`MeasuredResponse`] with the deserialized response data, headers & timing
+    pub async fn send_and_measure(self) -> Result<MeasuredResponse<T>, Error> {
+        self.client.esi().request_measured(&self).await
+    }
+
+    /// Consumes the [`EsiRequest`] and sends it, returning the raw response without typed deserialization.
+    ///
+    /// An escape hatch for when CCP changes a response schema before the crate is updated, allowing the
+    /// response to be parsed manually, e.g. with [`RawEsiResponse::json`]. Error status codes are still
+    /// returned as an [`Error`].
+    ///
+    /// # Returns
+    /// A Result containing the [`RawEsiResponse`] with the status code, headers, & body
+    pub async fn send_raw(self) -> Result<RawEsiResponse, Error> {
+        self.client.esi().request_raw(&self).await
+    }
+
+    /// Consumes the [`EsiRequest`] and sends it, streaming the response body to the provided writer.
+    ///
+    /// The body is written as it is received rather than buffered into memory, intended for archiving
+    /// large raw ESI responses such as full region market order pages to a file. The maximum response
+    /// size, if configured, still applies: a response whose `Content-Length` exceeds it is rejected
+    /// before anything is written, while a failure mid-stream leaves a partial body in the writer.
+    ///
+    /// # Arguments
+    /// - `writer`: The writer to stream the response body to, e.g. a `tokio::fs::File`
+    ///
+    /// # Returns
+    /// A Result containing an EsiResponse with the number of bytes written & the response headers
+    pub async fn send_to_writer<W>(self, writer: &mut W) -> Result<EsiResponse<u64>, Error>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.client.esi().request_to_writer(&self, writer).await
+    }
+
+    /// Consumes the [`EsiRequest`] and sends it, reusing the response body stored in the provided cache.
+    ///
+    /// The ETag of the cached response for the request URL is sent with the `If-None-Match` header,
+    /// if ESI responds with 304 Not Modified the cached body is returned, otherwise the cache is
+    /// updated with the fresh response. See [`crate::esi::cache`] for the available caches.
+    ///
+    /// # Arguments
+    /// - `cache`: The [`ResponseCache`] to read & store the response body in
+    ///
+    /// # Returns
+    /// A Result containing an EsiResponse with the deserialized response data and headers
+    pub async fn send_with_cache<C: ResponseCache>(
+        self,
+        cache: &C,
+    ) -> Result<EsiResponse<T>, Error> {
+        self.client.esi().request_with_cache(&self, cache).await
+    }
+
+    /// Consumes the [`EsiRequest`] and sends it with the conditional headers already set.
+    ///
+    /// Handles the 304 Not Modified response ESI returns when the resource has not changed since
+    /// the ETag set with [`Self::with_etag`]. Requests without conditional headers always
+    /// return fresh data.
+    ///
+    /// # Returns
+    /// A Result containing a [`CachedResponse`] wrapping an EsiResponse that may be either fresh data or not modified
+    pub async fn send_conditional(self) -> Result<CachedResponse<EsiResponse<T>>, Error> {
+        self.client.esi().request_cached(&self).await
+    }
+
     /// Consumes the [`EsiRequest`] and sends it with caching headers using the stored [`Client`].
     ///
     /// This method handles conditional requests that may return 304 Not Modified responses.
@@ -376,6 +778,109 @@ impl<T: DeserializeOwned> EsiRequest<T> {
     }
 }
 
+impl<T: DeserializeOwned> EsiRequest<Vec<T>> {
+    /// Registers a progress callback invoked by [`Self::send_all_pages`] as each page is retrieved.
+    ///
+    /// The callback receives the page number which was retrieved & the total number of pages,
+    /// e.g. to update a progress bar.
+    ///
+    /// # Arguments
+    /// - `callback`: Function called with `(page, total_pages)` after each page is retrieved
+    ///
+    /// # Returns
+    /// Updated instance with the progress callback set
+    pub fn on_page(mut self, callback: impl Fn(i32, i32) + Send + Sync + 'static) -> Self {
+        self.on_page = Some(Arc::new(callback));
+        self
+    }
+
+    /// Retrieves the total number of pages of a paginated endpoint without fetching its entries.
+    ///
+    /// GET requests issue a `HEAD` request for the first page, other methods request the first page
+    /// in full. Useful to plan concurrency or display progress before calling [`Self::send_all_pages`].
+    ///
+    /// # Returns
+    /// A Result containing the value of the `X-Pages` header, or `1` if the endpoint is not paginated
+    pub async fn page_count(&self) -> Result<i32, Error> {
+        let esi = self.client.esi();
+        let request = self.clone().with_page(1);
+
+        let headers = if request.method == Method::GET {
+            esi.request_headers(&request.with_method(Method::HEAD))
+                .await?
+        } else {
+            esi.request_with_headers(&request).await?.1
+        };
+
+        Ok(EsiApi::extract_pages(&headers).unwrap_or(1))
+    }
+
+    /// Consumes the [`EsiRequest`] and sends it for every page of a paginated endpoint.
+    ///
+    /// The first page is requested to determine the total number of pages from the `X-Pages`
+    /// header, the remaining pages are then requested sequentially & their entries appended in order.
+    /// Endpoints which are not paginated will only be requested once.
+    ///
+    /// The callback registered with [`Self::on_page`], if any, is invoked after each page is retrieved.
+    ///
+    /// # Returns
+    /// A Result containing an EsiResponse with the entries of all pages & the headers of the first page
+    pub async fn send_all_pages(self) -> Result<EsiResponse<Vec<T>>, Error> {
+        let client = self.client.clone();
+        let esi = client.esi();
+        let on_page = self.on_page.clone();
+
+        // Request the first page to determine the total number of pages
+        let mut request = self.with_page(1);
+        let (mut response, headers) = esi.request_with_headers(&request).await?;
+        let pages = EsiApi::extract_pages(&headers).unwrap_or(1);
+
+        log::debug!("Retrieved page 1 of {} for {}", pages, request.endpoint());
+
+        if let Some(callback) = &on_page {
+            callback(1, pages);
+        }
+
+        for page in 2..=pages {
+            request = request.with_page(page);
+            let page_response = esi.request(&request).await?;
+
+            response.data.extend(page_response.data);
+
+            if let Some(callback) = &on_page {
+                callback(page, pages);
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+impl<T: CursorPage> EsiRequest<T> {
+    /// Consumes the [`EsiRequest`] and sends it for every page of a cursor paginated endpoint.
+    ///
+    /// Pages are requested sequentially from the newest records using the `before` token of each
+    /// page, provided by the response body or the `Link` header, until a page without records or
+    /// without a token is returned. Use a [`Cursor`] directly to process each page as it is
+    /// retrieved or to keep the `after` token for retrieving only the records modified later, see
+    /// the [cursor module documentation](crate::esi::cursor).
+    ///
+    /// # Returns
+    /// A Result containing the records of every page in order
+    pub async fn send_all_cursor(self) -> Result<Vec<T::Item>, Error> {
+        let endpoint = self.endpoint.clone();
+        let records = Cursor::new(self).collect_all().await?;
+
+        log::debug!(
+            "Retrieved {} records with cursor pagination for {}",
+            records.len(),
+            endpoint
+        );
+
+        Ok(records)
+    }
+}
+
 /// Type-safe enum for ESI language headers.
 ///
 /// Represents the supported languages for the `Accept-Language` header in ESI requests.
@@ -425,3 +930,15 @@ impl Language {
         }
     }
 }
+
+/// Encodes a value as an ESI query parameter value
+///
+/// Strings such as enum values are returned without JSON quotes as ESI expects e.g.
+/// `order_type=sell` rather than `order_type="sell"`, `None` values are omitted.
+fn query_value(value: &impl Serialize) -> Result<Option<String>, serde_json::Error> {
+    Ok(match serde_json::to_value(value)? {
+        Value::Null => None,
+        Value::String(value) => Some(value),
+        value => Some(value.to_string()),
+    })
+}
@@ -4,7 +4,13 @@
 //! particularly for cached requests that may return 304 Not Modified.
 
 use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
+use std::time::Duration;
+
+use crate::Error;
 
 /// Response from an ESI request including response data & headers
 ///
@@ -24,6 +30,84 @@ pub struct EsiResponse<T> {
     pub rate_limit: Option<RateLimitHeaders>,
 }
 
+/// Raw response from an ESI request without typed deserialization
+///
+/// Returned by [`EsiRequest::send_raw`](crate::esi::EsiRequest::send_raw) as an escape hatch when a
+/// response no longer matches its typed model, e.g. after CCP changes a schema before the crate is updated.
+#[derive(Debug, Clone)]
+pub struct RawEsiResponse {
+    /// HTTP status code of the response
+    pub status: StatusCode,
+
+    /// All HTTP headers of the response
+    pub headers: HeaderMap,
+
+    /// The response body as bytes
+    pub body: Vec<u8>,
+}
+
+impl RawEsiResponse {
+    /// Parses the response body as an untyped JSON [`serde_json::Value`]
+    ///
+    /// # Returns
+    /// - `Ok(Value)`: The parsed JSON body
+    /// - `Err(Error::SerdeJsonError)`: If the body is not valid JSON
+    pub fn json(&self) -> Result<serde_json::Value, Error> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Returns the response body as text, replacing invalid UTF-8 sequences
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Response from an ESI request including the time spent on the request
+///
+/// Returned by [`EsiRequest::send_and_measure`](crate::esi::EsiRequest::send_and_measure).
+#[derive(Debug, Clone)]
+pub struct MeasuredResponse<T> {
+    /// The response with the deserialized data & headers
+    pub response: EsiResponse<T>,
+
+    /// Time spent on each phase of the request
+    pub timing: RequestTiming,
+
+    /// ID assigned to the request by ESI from the `X-ESI-Request-ID` header, if present
+    pub request_id: Option<String>,
+}
+
+/// Time spent on each phase of an ESI request
+///
+/// Connection-level timings such as DNS resolution & connecting are not exposed by reqwest &
+/// are included in [`Self::time_to_headers`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestTiming {
+    /// Time the request waited before being sent, e.g. while the client was cooling off from an
+    /// error limited response or queued behind the client's limit of requests in flight
+    pub queue_wait: Duration,
+
+    /// Number of times the request was sent, greater than `1` if it was retried
+    ///
+    /// `0` if the response was replayed from a cassette.
+    pub attempts: u32,
+
+    /// Time from sending the final attempt until its response headers were received
+    pub time_to_headers: Duration,
+
+    /// Total time from sending the request until the response body was read & deserialized,
+    /// including token validation, queue wait & retries
+    pub total: Duration,
+}
+
+impl<T> Deref for MeasuredResponse<T> {
+    type Target = EsiResponse<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
+
 /// Caching-related HTTP headers from the ESI response.
 ///
 /// All fields are always present on successful (200) responses.
@@ -48,7 +132,7 @@ pub struct CacheHeaders {
 /// Rate limiting HTTP headers from the ESI response.
 ///
 /// These headers are only present when `x-esi-error-limit-group` is included in the response.
-#[derive(Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct RateLimitHeaders {
     /// Route group identifier for this endpoint.
     pub group: String,
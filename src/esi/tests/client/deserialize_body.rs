@@ -0,0 +1,50 @@
+//! Tests for deserializing ESI response bodies.
+
+use crate::esi::client::EsiApi;
+use crate::Error;
+
+/// Tests that a body which fails to deserialize is kept in full in the returned error.
+///
+/// Verifies that the error includes the JSON path of the failed field & the entire body,
+/// even when the body is longer than is logged.
+///
+/// Expected: DeserializationError with the full body
+#[test]
+fn test_deserialize_body_error_keeps_full_body() {
+    let body = format!("[1, 2, \"{}\"]", "a".repeat(4096));
+
+    let result = EsiApi::deserialize_body::<Vec<i64>>("GET", "/test", body.clone().into_bytes());
+
+    match result {
+        Err(Error::DeserializationError {
+            path,
+            body: error_body,
+            ..
+        }) => {
+            assert_eq!(path, "[2]");
+            assert_eq!(error_body, body);
+        }
+        other => panic!("Expected DeserializationError, got: {:?}", other),
+    }
+}
+
+/// Tests truncating response bodies for logging.
+///
+/// Verifies that short bodies are logged in full & long bodies are cut at a character
+/// boundary within the limit.
+///
+/// Expected: Short body unchanged, long body truncated to at most 1024 bytes
+#[test]
+fn test_truncate_body() {
+    assert_eq!(
+        EsiApi::truncate_body(r#"{"error": "x"}"#),
+        r#"{"error": "x"}"#
+    );
+
+    // Each character is 2 bytes, the limit falls within the 513th character
+    let body = format!("a{}", "é".repeat(1000));
+    let truncated = EsiApi::truncate_body(&body);
+
+    assert_eq!(truncated.len(), 1023);
+    assert!(body.starts_with(truncated));
+}
@@ -3,4 +3,5 @@
 //! This module verifies the EsiApi client behavior including header extraction
 //! for cache headers and rate limit headers, and response population logic.
 
+mod deserialize_body;
 mod header_extraction;
@@ -1,6 +1,6 @@
 //! Tests for EsiRequest builder and configuration methods.
 
-use crate::esi::request::{EsiRequest, Language};
+use crate::esi::request::{EsiRequest, Idempotency, Language};
 use crate::Client;
 use reqwest::Method;
 use serde::Deserialize;
@@ -384,3 +384,120 @@ fn test_endpoint_url_construction() -> Result<(), crate::Error> {
 
     Ok(())
 }
+
+/// Tests per-request token validation overrides.
+///
+/// Verifies that a new request has no override set by default and that
+/// the skip_token_validation & require_token_validation builder methods
+/// set the override accordingly, with the last call taking precedence.
+///
+/// Expected: token_validation() returns None, Some(false), then Some(true)
+#[test]
+fn test_token_validation_override() -> Result<(), crate::Error> {
+    let client = create_test_client();
+    let request = EsiRequest::<TestResponse>::new(&client, "/status/");
+    assert_eq!(request.token_validation(), None);
+
+    let request = request.skip_token_validation();
+    assert_eq!(request.token_validation(), Some(false));
+
+    let request = request.require_token_validation();
+    assert_eq!(request.token_validation(), Some(true));
+
+    Ok(())
+}
+
+/// Tests the idempotency class of a request.
+///
+/// Verifies that requests are classed by their HTTP method, `POST` being unsafe
+/// to retry, unless a class is set with with_idempotency.
+///
+/// Expected: GET, PUT & DELETE are safe, POST is unsafe until marked safe
+#[test]
+fn test_idempotency() -> Result<(), crate::Error> {
+    let client = create_test_client();
+    let request = EsiRequest::<TestResponse>::new(&client, "/characters/2114794365/mail");
+    assert_eq!(request.idempotency(), Idempotency::Safe);
+
+    let request = request.with_method(Method::PUT);
+    assert_eq!(request.idempotency(), Idempotency::Safe);
+
+    let request = request.with_method(Method::DELETE);
+    assert_eq!(request.idempotency(), Idempotency::Safe);
+
+    let request = request.with_method(Method::POST);
+    assert_eq!(request.idempotency(), Idempotency::Unsafe);
+
+    let request = request.with_idempotency(Idempotency::Safe);
+    assert_eq!(request.idempotency(), Idempotency::Safe);
+
+    Ok(())
+}
+
+/// Tests setting the page query parameter.
+///
+/// Verifies that with_page adds the page query parameter when absent and
+/// replaces it when present while retaining other query parameters.
+///
+/// Expected: URL contains a single page parameter with the latest value
+#[test]
+fn test_with_page() -> Result<(), crate::Error> {
+    let client = create_test_client();
+    let request =
+        EsiRequest::<TestResponse>::new(&client, "/markets/10000002/orders?order_type=all");
+
+    let request = request.with_page(1);
+    assert!(request.endpoint().ends_with("orders?order_type=all&page=1"));
+
+    let request = request.with_page(2);
+    assert!(request.endpoint().ends_with("orders?order_type=all&page=2"));
+
+    Ok(())
+}
+
+/// Tests appending query parameters.
+///
+/// Verifies that with_query encodes strings without JSON quotes, other values as
+/// JSON, omits `None` values & retains existing query parameters.
+///
+/// Expected: URL contains the encoded query parameters in the order they were added
+#[test]
+fn test_with_query() -> Result<(), crate::Error> {
+    let client = create_test_client();
+    let request = EsiRequest::<TestResponse>::new(&client, "/markets/10000002/orders?page=1")
+        .with_query("order_type", "sell")
+        .with_query("type_id", Some(34))
+        .with_query("from_id", None::<i64>)
+        .with_query("ids", vec![1, 2]);
+
+    assert!(request
+        .endpoint()
+        .ends_with("orders?page=1&order_type=sell&type_id=34&ids=%5B1%2C2%5D"));
+
+    Ok(())
+}
+
+/// Tests setting a serialized request body.
+///
+/// Verifies that with_body serializes the provided value to the body JSON.
+///
+/// Expected: body_json matches the serialized value
+#[test]
+fn test_with_body() -> Result<(), crate::Error> {
+    #[derive(serde::Serialize)]
+    struct TestBody {
+        accepted: bool,
+    }
+
+    let client = create_test_client();
+    let request = EsiRequest::<TestResponse>::new(&client, "/corporations/98785281/applications/1")
+        .with_method(Method::PUT)
+        .with_body(TestBody { accepted: true });
+
+    assert_eq!(
+        request.body_json(),
+        Some(&serde_json::json!({ "accepted": true }))
+    );
+
+    Ok(())
+}
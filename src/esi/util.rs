@@ -11,12 +11,20 @@ use crate::{model::oauth2::EveJwtClaims, Error, OAuthError};
 
 impl<'a> EsiApi<'a> {
     /// Utilty function which returns an error if token is invalid, expired, or is missing required scopes
+    ///
+    /// The `validate_override` takes precedence over the client's `esi_validate_token_before_request`
+    /// setting when present, see [`super::EsiRequest::skip_token_validation`] &
+    /// [`super::EsiRequest::require_token_validation`].
     pub(super) async fn validate_token_before_request(
         &self,
         access_token: &str,
         required_scopes: Vec<String>,
+        validate_override: Option<bool>,
     ) -> Result<(), Error> {
-        if self.client.inner.esi_validate_token_before_request {
+        let validate =
+            validate_override.unwrap_or(self.client.inner.esi_validate_token_before_request);
+
+        if validate {
             log::trace!("Validating token prior to expiration & scope checks");
 
             let claims = self
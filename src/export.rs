@@ -0,0 +1,504 @@
+//! # EVE ESI Export
+//!
+//! Serializes the results of composite helpers to JSON & CSV for reporting, such as exporting a
+//! corporation's structure dashboard to a spreadsheet or archiving member audit logs.
+//!
+//! Requires the `export` feature.
+//!
+//! ## Formats
+//! - JSON: [`to_json`] serializes any result implementing [`Serialize`]
+//! - CSV: [`to_csv`] & [`write_csv`] serialize rows implementing [`CsvRecord`] with a header row,
+//!   columns are always in the order of [`CsvRecord::HEADERS`] so exports remain stable across versions
+//!
+//! ## Supported Rows
+//! - [`CorporationMemberAccessChange`]: Member audit log from
+//!   [`CorporationMemberAccessSnapshot::diff`](crate::model::corporation::CorporationMemberAccessSnapshot::diff)
+//! - [`CorporationStructureDashboardEntry`]: Structure dashboard from
+//!   [`CorporationEndpoints::get_structure_dashboard`](crate::endpoints::corporation::CorporationEndpoints::get_structure_dashboard)
+//! - [`CorporationOffice`]: Offices from
+//!   [`CorporationEndpoints::get_corporation_offices`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_offices)
+//! - [`DividendPayout`]: Payouts of a [`DividendReport`](crate::model::corporation::DividendReport)
+//! - [`RouteSystem`]: Systems of a [`RouteReport`](crate::model::routes::RouteReport)
+//! - [`SystemActivity`]: System activity from
+//!   [`UniverseEndpoints::get_system_activity`](crate::endpoints::universe::UniverseEndpoints::get_system_activity)
+//! - [`AccountingRecord`]: Wallet journal from
+//!   [`WalletEndpoints::get_accounting_records`](crate::endpoints::wallet::WalletEndpoints::get_accounting_records)
+//!
+//! ## Usage Example
+//!
+//! ```no_run
+//! use eve_esi::export;
+//!
+//! # async fn example(esi_client: eve_esi::Client, access_token: &str) -> Result<(), eve_esi::Error> {
+//! let dashboard = esi_client
+//!     .corporation()
+//!     .get_structure_dashboard(access_token, 98785281)
+//!     .await?;
+//!
+//! let csv = export::to_csv(&dashboard);
+//! let json = export::to_json(&dashboard)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::model::corporation::{
+    CorporationMemberAccessChange, CorporationOffice, CorporationStructureDashboardEntry,
+    DividendPayout, StructureState,
+};
+use crate::model::routes::RouteSystem;
+use crate::model::universe::SystemActivity;
+use crate::model::wallet::AccountingRecord;
+use crate::model::Isk;
+use crate::Error;
+
+/// A row which can be exported as a CSV record
+pub trait CsvRecord {
+    /// Column names of the CSV header row, in the order values are returned by [`Self::record`]
+    const HEADERS: &'static [&'static str];
+
+    /// Returns the values of the row's columns in the order of [`Self::HEADERS`]
+    fn record(&self) -> Vec<String>;
+}
+
+/// Serializes a result to pretty-printed JSON
+///
+/// # Arguments
+/// - `value` (`&T`): The result to serialize
+///
+/// # Returns
+/// - `Ok(String)`: The result as JSON
+/// - `Err(Error::SerdeJsonError)`: If the result could not be serialized
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    Ok(serde_json::to_string_pretty(value)?)
+}
+
+/// Serializes rows to CSV with a header row
+///
+/// # Arguments
+/// - `rows` (`&[T]`): The rows to serialize
+///
+/// # Returns
+/// The rows as CSV with `\n` line endings
+pub fn to_csv<T: CsvRecord>(rows: &[T]) -> String {
+    let mut csv = Vec::new();
+
+    // Writing to a Vec<u8> can't fail
+    write_csv(rows, &mut csv).expect("Failed to write CSV to buffer");
+
+    String::from_utf8(csv).expect("CSV fields are valid UTF-8")
+}
+
+/// Writes rows as CSV with a header row to the provided writer
+///
+/// # Arguments
+/// - `rows` (`&[T]`): The rows to serialize
+/// - `writer` (`W`): The writer to write the CSV to, e.g. a [`std::fs::File`]
+///
+/// # Returns
+/// - `Ok(())`: If the CSV was written
+/// - `Err(Error::IoError)`: If writing to the writer failed
+pub fn write_csv<T: CsvRecord, W: Write>(rows: &[T], mut writer: W) -> Result<(), Error> {
+    write_csv_line(&mut writer, T::HEADERS.iter().copied())?;
+
+    for row in rows {
+        let record = row.record();
+        write_csv_line(&mut writer, record.iter().map(String::as_str))?;
+    }
+
+    Ok(())
+}
+
+/// Writes a single CSV line, quoting fields which contain delimiters, quotes, or line breaks
+fn write_csv_line<'a, W: Write>(
+    writer: &mut W,
+    fields: impl Iterator<Item = &'a str>,
+) -> Result<(), Error> {
+    let line = fields
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+
+    writeln!(writer, "{}", line)?;
+
+    Ok(())
+}
+
+/// Formats a unit enum variant using its serde representation, e.g. `Director` or `character`
+fn enum_field<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        _ => String::new(),
+    }
+}
+
+/// Formats an optional value, using an empty field for `None`
+fn optional_field<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Formats an optional ISK amount to 2 decimal places, using an empty field for `None`
+fn isk_field(value: Option<Isk>) -> String {
+    optional_field(value.map(|value| format!("{:.2}", value)))
+}
+
+/// Formats an optional timestamp as RFC 3339, using an empty field for `None`
+fn timestamp_field(value: Option<DateTime<Utc>>) -> String {
+    optional_field(value.map(|value| value.to_rfc3339()))
+}
+
+impl CsvRecord for CorporationMemberAccessChange {
+    const HEADERS: &'static [&'static str] =
+        &["change", "character_id", "role", "role_type", "title_id"];
+
+    fn record(&self) -> Vec<String> {
+        match self {
+            CorporationMemberAccessChange::RoleGranted {
+                character_id,
+                role,
+                role_type,
+            }
+            | CorporationMemberAccessChange::RoleRevoked {
+                character_id,
+                role,
+                role_type,
+            } => {
+                let change = match self {
+                    CorporationMemberAccessChange::RoleGranted { .. } => "role_granted",
+                    _ => "role_revoked",
+                };
+
+                vec![
+                    change.to_string(),
+                    character_id.to_string(),
+                    enum_field(role),
+                    enum_field(role_type),
+                    String::new(),
+                ]
+            }
+            CorporationMemberAccessChange::TitleGranted {
+                character_id,
+                title_id,
+            }
+            | CorporationMemberAccessChange::TitleRevoked {
+                character_id,
+                title_id,
+            } => {
+                let change = match self {
+                    CorporationMemberAccessChange::TitleGranted { .. } => "title_granted",
+                    _ => "title_revoked",
+                };
+
+                vec![
+                    change.to_string(),
+                    character_id.to_string(),
+                    String::new(),
+                    String::new(),
+                    title_id.to_string(),
+                ]
+            }
+        }
+    }
+}
+
+impl CsvRecord for CorporationStructureDashboardEntry {
+    const HEADERS: &'static [&'static str] = &[
+        "structure_id",
+        "name",
+        "type_id",
+        "type_name",
+        "system_id",
+        "system_name",
+        "state",
+        "state_ends",
+        "fuel_expires",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        let (state, state_ends) = match &self.state {
+            StructureState::Anchoring { ends } => ("anchoring", *ends),
+            StructureState::Onlining { ends } => ("onlining", *ends),
+            StructureState::Online => ("online", None),
+            StructureState::Reinforced { ends, .. } => ("reinforced", *ends),
+            StructureState::Vulnerable { ends, .. } => ("vulnerable", *ends),
+            StructureState::Unanchoring { at } => ("unanchoring", Some(*at)),
+            StructureState::Unanchored => ("unanchored", None),
+            StructureState::Unknown => ("unknown", None),
+        };
+
+        vec![
+            self.structure.structure_id.to_string(),
+            optional_field(self.structure.name.as_ref()),
+            self.structure.type_id.to_string(),
+            optional_field(self.type_name.as_ref()),
+            self.structure.system_id.to_string(),
+            optional_field(self.system_name.as_ref()),
+            state.to_string(),
+            timestamp_field(state_ends),
+            timestamp_field(self.structure.fuel_expires),
+        ]
+    }
+}
+
+impl CsvRecord for CorporationOffice {
+    const HEADERS: &'static [&'static str] =
+        &["office_id", "location_id", "location_type", "location_name"];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.office_id.to_string(),
+            self.location_id.to_string(),
+            enum_field(&self.location_type),
+            optional_field(self.location_name.as_ref()),
+        ]
+    }
+}
+
+impl CsvRecord for DividendPayout {
+    const HEADERS: &'static [&'static str] = &[
+        "shareholder_id",
+        "shareholder_type",
+        "share_count",
+        "amount",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.shareholder_id.to_string(),
+            enum_field(&self.shareholder_type),
+            self.share_count.to_string(),
+            format!("{:.2}", self.amount),
+        ]
+    }
+}
+
+impl CsvRecord for RouteSystem {
+    const HEADERS: &'static [&'static str] = &[
+        "system_id",
+        "ship_kills",
+        "pod_kills",
+        "alliance_id",
+        "corporation_id",
+        "faction_id",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.system_id.to_string(),
+            self.ship_kills.to_string(),
+            self.pod_kills.to_string(),
+            optional_field(self.alliance_id),
+            optional_field(self.corporation_id),
+            optional_field(self.faction_id),
+        ]
+    }
+}
+
+impl CsvRecord for SystemActivity {
+    const HEADERS: &'static [&'static str] = &[
+        "system_id",
+        "name",
+        "security_status",
+        "ship_kills",
+        "pod_kills",
+        "npc_kills",
+        "ship_jumps",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.system_id.to_string(),
+            self.name.clone(),
+            self.security_status.to_string(),
+            self.ship_kills.to_string(),
+            self.pod_kills.to_string(),
+            self.npc_kills.to_string(),
+            self.ship_jumps.to_string(),
+        ]
+    }
+}
+
+impl CsvRecord for AccountingRecord {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "date",
+        "ref_type",
+        "first_party_id",
+        "first_party",
+        "second_party_id",
+        "second_party",
+        "amount",
+        "balance",
+        "tax",
+        "reason",
+        "description",
+    ];
+
+    fn record(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.date.to_rfc3339(),
+            enum_field(&self.ref_type),
+            optional_field(self.first_party_id),
+            optional_field(self.first_party.as_ref()),
+            optional_field(self.second_party_id),
+            optional_field(self.second_party.as_ref()),
+            isk_field(self.amount),
+            isk_field(self.balance),
+            isk_field(self.tax),
+            optional_field(self.reason.as_ref()),
+            self.description.clone(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod export_tests {
+    use super::*;
+    use crate::model::enums::asset::LocationType;
+    use crate::model::enums::corporation::{CorporationRole, CorporationRoleType, ShareholderType};
+
+    /// Tests exporting member audit changes to CSV
+    ///
+    /// # Test Setup
+    /// - Create a role & a title change
+    ///
+    /// # Assertions
+    /// - Assert the header row is written first
+    /// - Assert role & title changes leave unrelated columns empty
+    #[test]
+    fn test_member_access_changes_to_csv() {
+        let changes = vec![
+            CorporationMemberAccessChange::RoleGranted {
+                character_id: 2114794365,
+                role: CorporationRole::Director,
+                role_type: CorporationRoleType::Roles,
+            },
+            CorporationMemberAccessChange::TitleRevoked {
+                character_id: 2114794365,
+                title_id: 1,
+            },
+        ];
+
+        let csv = to_csv(&changes);
+
+        assert_eq!(
+            csv,
+            "change,character_id,role,role_type,title_id\n\
+             role_granted,2114794365,Director,roles,\n\
+             title_revoked,2114794365,,,1\n"
+        );
+    }
+
+    /// Tests that fields containing delimiters or quotes are quoted & escaped
+    ///
+    /// # Test Setup
+    /// - Create an office with a location name containing a comma & quotes
+    ///
+    /// # Assertions
+    /// - Assert the location name is quoted with inner quotes doubled
+    #[test]
+    fn test_csv_escaping() {
+        let offices = vec![CorporationOffice {
+            office_id: 1,
+            location_id: 60003760,
+            location_type: LocationType::Station,
+            location_name: Some("Jita, \"IV\"".to_string()),
+        }];
+
+        let csv = to_csv(&offices);
+
+        assert_eq!(
+            csv,
+            "office_id,location_id,location_type,location_name\n\
+             1,60003760,station,\"Jita, \"\"IV\"\"\"\n"
+        );
+    }
+
+    /// Tests exporting rows to JSON
+    ///
+    /// # Test Setup
+    /// - Create a dividend payout
+    ///
+    /// # Assertions
+    /// - Assert the JSON can be parsed back into the same payout
+    #[test]
+    fn test_to_json() {
+        let payouts = vec![DividendPayout {
+            shareholder_id: 2114794365,
+            shareholder_type: ShareholderType::Character,
+            share_count: 100,
+            amount: 1_000_000.0,
+        }];
+
+        let json = to_json(&payouts).unwrap();
+        let parsed: Vec<DividendPayout> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, payouts);
+    }
+
+    /// Tests exporting wallet journal entries as accounting records
+    ///
+    /// # Test Setup
+    /// - Create a player donation with a reason & a bounty without a second party name
+    ///
+    /// # Assertions
+    /// - Assert party names are resolved from the provided names
+    /// - Assert ISK amounts are formatted to 2 decimal places & missing values are left empty
+    #[test]
+    fn test_accounting_records_to_csv() {
+        use std::collections::HashMap;
+
+        use crate::model::wallet::{accounting_records, WalletJournalEntry};
+
+        let journal: Vec<WalletJournalEntry> = serde_json::from_value(serde_json::json!([
+            {
+                "amount": -1000,
+                "balance": 5000,
+                "date": "2025-01-01T12:00:00Z",
+                "description": "Bob deposited cash into Alice's account",
+                "first_party_id": 2114794365,
+                "id": 2,
+                "reason": "Fuel, blocks",
+                "ref_type": "player_donation",
+                "second_party_id": 2117053828
+            },
+            {
+                "amount": 250000,
+                "date": "2025-01-01T11:00:00Z",
+                "description": "Bounty prizes",
+                "first_party_id": 1000125,
+                "id": 1,
+                "ref_type": "bounty_prizes",
+                "second_party_id": 2114794365,
+                "tax": 25000
+            }
+        ]))
+        .unwrap();
+        let names = HashMap::from([
+            (2114794365, "Bob".to_string()),
+            (2117053828, "Alice".to_string()),
+        ]);
+
+        let csv = to_csv(&accounting_records(&journal, &names));
+
+        // Assert party names are resolved from the provided names
+        // Assert ISK amounts are formatted to 2 decimal places & missing values are left empty
+        assert_eq!(
+            csv,
+            "id,date,ref_type,first_party_id,first_party,second_party_id,second_party,amount,balance,tax,reason,description\n\
+             2,2025-01-01T12:00:00+00:00,player_donation,2114794365,Bob,2117053828,Alice,-1000.00,5000.00,,\"Fuel, blocks\",Bob deposited cash into Alice's account\n\
+             1,2025-01-01T11:00:00+00:00,bounty_prizes,1000125,,2114794365,Bob,250000.00,,25000.00,,Bounty prizes\n"
+        );
+    }
+}
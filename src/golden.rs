@@ -0,0 +1,345 @@
+//! Golden-file tests of model deserialization.
+//!
+//! A golden file is a JSON response recorded from ESI & committed alongside tests. [`assert_golden`]
+//! deserializes a golden file into the model returned by an endpoint, serializes the model back to
+//! JSON & compares it to the golden file, catching model fields which were misspelled, are missing
+//! or have the wrong type. Record golden files from ESI with [`record_golden`], or with the
+//! `record_goldens` example for the goldens of this crate. Requires the `golden` feature.
+//!
+//! The comparison is semantic rather than textual: object keys may be in any order & numbers are
+//! compared by value, so `5` & `5.0` are equal. Fields which ESI omits from a response but the model
+//! serializes as `null`, `false` or an empty array, such as an `Option` field or a field with
+//! `#[serde(default)]`, are not a difference.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::Client;
+//!
+//! # async fn example() -> Result<(), eve_esi::Error> {
+//! let client = Client::builder()
+//!     .user_agent("MyApp/1.0 (contact@example.com)")
+//!     .build()?;
+//! let request = client.alliance().get_alliance_information(99013534);
+//!
+//! // Record the golden file once from ESI
+//! eve_esi::golden::record_golden(request.clone(), "tests/golden/alliance.json").await?;
+//!
+//! // Assert the Alliance model round-trips the recorded response
+//! eve_esi::golden::assert_golden(&request, "tests/golden/alliance.json");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::esi::EsiApi;
+use crate::{Error, EsiRequest};
+
+/// Relative tolerance when comparing numbers, ISK amounts may be serialized from a `Decimal`
+const NUMBER_TOLERANCE: f64 = 1e-9;
+
+/// A difference between a golden file & the JSON serialized from its model
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenDifference {
+    /// JSON path of the difference, e.g. `planets[0].moons`
+    pub path: String,
+    /// Value in the golden file, `None` if the model serialized a field missing from the golden file
+    pub expected: Option<Value>,
+    /// Value serialized from the model, `None` if the model did not serialize the field
+    pub actual: Option<Value>,
+}
+
+impl fmt::Display for GoldenDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "."
+        } else {
+            &self.path
+        };
+
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => {
+                write!(f, "`{}`: expected {}, found {}", path, expected, actual)
+            }
+            (Some(expected), None) => {
+                write!(f, "`{}`: missing from model, expected {}", path, expected)
+            }
+            (None, Some(actual)) => {
+                write!(f, "`{}`: not in golden file, found {}", path, actual)
+            }
+            (None, None) => write!(f, "`{}`: missing", path),
+        }
+    }
+}
+
+/// Deserializes a golden JSON value into `T`, serializes it back & returns the differences
+///
+/// # Arguments
+/// - `golden`: The JSON response recorded from ESI
+///
+/// # Returns
+/// - `Ok(Vec<GoldenDifference>)`: The differences between the golden value & the serialized model,
+///   empty if the model round-trips
+/// - `Err(Error::DeserializationError)`: Including the JSON path of the field which failed if the
+///   golden value could not be deserialized into `T`
+pub fn round_trip<T>(golden: &Value) -> Result<Vec<GoldenDifference>, Error>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let body = serde_json::to_vec(golden)?;
+    let model: T = EsiApi::deserialize_body("GOLDEN", "", body)?;
+    let actual = serde_json::to_value(&model)?;
+
+    let mut differences = Vec::new();
+    compare(String::new(), golden, &actual, &mut differences);
+
+    Ok(differences)
+}
+
+/// Asserts the model returned by an endpoint round-trips a golden file
+///
+/// # Arguments
+/// - `request`: The request of the endpoint the golden file was recorded from, only used for the
+///   model type & the panic message, it is never sent
+/// - `path`: Path of the golden file
+///
+/// # Panics
+/// If the golden file cannot be read or deserialized, or the serialized model differs from it
+pub fn assert_golden<T>(request: &EsiRequest<T>, path: impl AsRef<Path>)
+where
+    T: DeserializeOwned + Serialize,
+{
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read golden file {}: {}", path.display(), e));
+    let golden: Value = serde_json::from_str(&contents)
+        .unwrap_or_else(|e| panic!("Golden file {} is not valid JSON: {}", path.display(), e));
+
+    let differences = round_trip::<T>(&golden).unwrap_or_else(|e| {
+        panic!(
+            "Failed to deserialize golden file {} for {}: {}",
+            path.display(),
+            request.endpoint(),
+            e
+        )
+    });
+
+    if !differences.is_empty() {
+        let differences: Vec<String> = differences.iter().map(|d| format!("  {}", d)).collect();
+
+        panic!(
+            "Model for {} does not round-trip golden file {}:\n{}",
+            request.endpoint(),
+            path.display(),
+            differences.join("\n")
+        );
+    }
+}
+
+/// Sends a request to ESI & writes the response body to a golden file
+///
+/// The body is written as pretty printed JSON & the parent directory is created if it does not
+/// exist. Any existing golden file is replaced.
+///
+/// # Arguments
+/// - `request`: The request to record the golden file from
+/// - `path`: Path of the golden file
+///
+/// # Returns
+/// - `Ok(())`: If the golden file was written
+/// - `Err(Error)`: If the request failed, the body is not valid JSON or the file could not be written
+pub async fn record_golden<T>(request: EsiRequest<T>, path: impl AsRef<Path>) -> Result<(), Error>
+where
+    T: DeserializeOwned,
+{
+    let path = path.as_ref();
+    let response = request.send_raw().await?;
+    let mut contents = serde_json::to_string_pretty(&response.json()?)?;
+    contents.push('\n');
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, contents).await?;
+
+    Ok(())
+}
+
+/// Recursively compares the golden value to the serialized model, collecting differences
+fn compare(
+    path: String,
+    expected: &Value,
+    actual: &Value,
+    differences: &mut Vec<GoldenDifference>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                let field_path = join_key(&path, key);
+
+                match actual.get(key) {
+                    Some(actual) => compare(field_path, expected, actual, differences),
+                    None => differences.push(GoldenDifference {
+                        path: field_path,
+                        expected: Some(expected.clone()),
+                        actual: None,
+                    }),
+                }
+            }
+
+            for (key, actual) in actual {
+                if !expected.contains_key(key) && !is_omitted_default(actual) {
+                    differences.push(GoldenDifference {
+                        path: join_key(&path, key),
+                        expected: None,
+                        actual: Some(actual.clone()),
+                    });
+                }
+            }
+        }
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.len() == actual_items.len() =>
+        {
+            for (index, (expected, actual)) in expected_items.iter().zip(actual_items).enumerate() {
+                compare(
+                    format!("{}[{}]", path, index),
+                    expected,
+                    actual,
+                    differences,
+                );
+            }
+        }
+        (Value::Number(expected_number), Value::Number(actual_number)) => {
+            let (Some(expected_number), Some(actual_number)) =
+                (expected_number.as_f64(), actual_number.as_f64())
+            else {
+                return;
+            };
+
+            let tolerance = NUMBER_TOLERANCE * expected_number.abs().max(1.0);
+            if (expected_number - actual_number).abs() > tolerance {
+                differences.push(GoldenDifference {
+                    path,
+                    expected: Some(expected.clone()),
+                    actual: Some(actual.clone()),
+                });
+            }
+        }
+        _ => {
+            if expected != actual {
+                differences.push(GoldenDifference {
+                    path,
+                    expected: Some(expected.clone()),
+                    actual: Some(actual.clone()),
+                });
+            }
+        }
+    }
+}
+
+/// Appends an object key to a JSON path
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Whether a value the model serialized for a field ESI omitted is the field's default
+fn is_omitted_default(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => true,
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod golden_tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::{round_trip, GoldenDifference};
+
+    #[derive(Serialize, Deserialize)]
+    struct Model {
+        name: String,
+        price: f64,
+        description: Option<String>,
+        #[serde(default)]
+        is_deleted: bool,
+        #[serde(default)]
+        items: Vec<i64>,
+    }
+
+    /// Tests a golden value the model round-trips has no differences
+    ///
+    /// # Assertions
+    /// - Asserts keys in a different order, an integer price & omitted defaults are not differences
+    #[test]
+    fn test_round_trip() {
+        let golden = json!({
+            "price": 5,
+            "items": [1, 2],
+            "name": "Tritanium"
+        });
+
+        let differences = round_trip::<Model>(&golden).unwrap();
+
+        // Assert keys in a different order, an integer price & omitted defaults are not differences
+        assert!(differences.is_empty(), "{:?}", differences);
+    }
+
+    /// Tests a golden field misspelled by the model is a difference
+    ///
+    /// # Assertions
+    /// - Asserts the golden field unknown to the model is reported with its path
+    #[test]
+    fn test_round_trip_unknown_field() {
+        let golden = json!({
+            "name": "Tritanium",
+            "price": 5.25,
+            "descripton": "Misspelled"
+        });
+
+        let differences = round_trip::<Model>(&golden).unwrap();
+
+        // Assert the golden field unknown to the model is reported with its path
+        assert_eq!(
+            differences,
+            vec![GoldenDifference {
+                path: "descripton".to_string(),
+                expected: Some(json!("Misspelled")),
+                actual: None,
+            }]
+        );
+    }
+
+    /// Tests a golden value which cannot be deserialized returns an error
+    ///
+    /// # Assertions
+    /// - Asserts a deserialization error including the path of the field is returned
+    #[test]
+    fn test_round_trip_wrong_type() {
+        let golden = json!({
+            "name": "Tritanium",
+            "price": 5.25,
+            "items": ["one"]
+        });
+
+        let result = round_trip::<Model>(&golden);
+
+        // Assert a deserialization error including the path of the field is returned
+        assert!(matches!(
+            result,
+            Err(crate::Error::DeserializationError { ref path, .. }) if path == "items[0]"
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+//! # EVE ESI Universe Graph
+//!
+//! Provides the [`UniverseGraph`], an adjacency map of solar systems connected by stargates used to
+//! calculate routes locally rather than requesting
+//! [`RoutesEndpoints::get_route`](crate::endpoints::routes::RoutesEndpoints::get_route) for every query.
+//!
+//! Requires the `universe-graph` feature.
+//!
+//! The graph is built by [`UniverseEndpoints::crawl_universe_graph`](crate::endpoints::universe::UniverseEndpoints::crawl_universe_graph)
+//! which requests every solar system & stargate, several thousand requests which take a while to
+//! complete. Save the graph with [`UniverseGraph::save`] once crawled & [`UniverseGraph::load`] it
+//! on startup rather than crawling again. A crawl which fails part way keeps the systems crawled
+//! so far, crawling the same graph again resumes where it left off.
+//!
+//! Routes are the shortest by number of jumps, security status is not taken into account.
+//!
+//! ## Usage Example
+//!
+//! ```no_run
+//! use eve_esi::graph::UniverseGraph;
+//!
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let path = "universe_graph.json";
+//!
+//! let mut graph = UniverseGraph::load(path).await.unwrap_or_default();
+//! let result = esi_client.universe().crawl_universe_graph(&mut graph).await;
+//! graph.save(path).await?;
+//! result?;
+//!
+//! // Jita to Amarr
+//! let route = graph.shortest_path(30000142, 30002187, &[]);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// Solar systems & the solar systems their stargates jump to
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UniverseGraph {
+    /// Solar system IDs mapped to the IDs of the solar systems they have stargates to
+    systems: HashMap<i64, Vec<i64>>,
+    /// Stargate IDs mapped to the solar system they jump to, avoids requesting both sides of a
+    /// stargate pair while crawling
+    stargates: HashMap<i64, i64>,
+}
+
+impl UniverseGraph {
+    /// Creates a new empty [`UniverseGraph`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a graph previously saved with [`Self::save`]
+    ///
+    /// # Arguments
+    /// - `path` (`impl AsRef<Path>`): The file the graph was saved to
+    ///
+    /// # Errors
+    /// - [`Error::IoError`]: If the file could not be read
+    /// - [`Error::SerdeJsonError`]: If the file does not contain a saved graph
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = tokio::fs::read(path).await?;
+
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    /// Saves the graph as JSON to the provided file, replacing any existing file
+    ///
+    /// # Arguments
+    /// - `path` (`impl AsRef<Path>`): The file to save the graph to
+    ///
+    /// # Errors
+    /// - [`Error::IoError`]: If the file could not be written
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let temp_path = path.with_extension("tmp");
+
+        tokio::fs::write(&temp_path, serde_json::to_vec(self)?).await?;
+        tokio::fs::rename(&temp_path, path).await?;
+
+        Ok(())
+    }
+
+    /// Adds a solar system & the solar systems its stargates jump to, replacing any existing entry
+    ///
+    /// # Arguments
+    /// - `system_id` (`i64`): The ID of the solar system
+    /// - `neighbors` (`Vec<i64>`): The IDs of the solar systems the system has stargates to
+    pub fn insert_system(&mut self, system_id: i64, neighbors: Vec<i64>) {
+        self.systems.insert(system_id, neighbors);
+    }
+
+    /// Returns `true` if the solar system has been added to the graph
+    pub fn contains_system(&self, system_id: i64) -> bool {
+        self.systems.contains_key(&system_id)
+    }
+
+    /// Returns the IDs of the solar systems the provided system has stargates to
+    ///
+    /// Returns an empty slice for systems without stargates, such as wormhole systems, & systems
+    /// which have not been added to the graph.
+    pub fn neighbors(&self, system_id: i64) -> &[i64] {
+        self.systems
+            .get(&system_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of solar systems in the graph
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Returns `true` if no solar systems have been added to the graph
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    /// Returns the solar system a stargate jumps to if it was requested while crawling
+    pub(crate) fn stargate_destination(&self, stargate_id: i64) -> Option<i64> {
+        self.stargates.get(&stargate_id).copied()
+    }
+
+    /// Records the solar system a stargate jumps to
+    pub(crate) fn insert_stargate(&mut self, stargate_id: i64, destination_system_id: i64) {
+        self.stargates.insert(stargate_id, destination_system_id);
+    }
+
+    /// Finds the route with the fewest jumps between two solar systems
+    ///
+    /// # Arguments
+    /// - `origin` (`i64`): The ID of the solar system to start from
+    /// - `destination` (`i64`): The ID of the solar system to travel to
+    /// - `avoid` (`&[i64]`): IDs of solar systems the route must not pass through, the origin &
+    ///   destination are never avoided
+    ///
+    /// # Returns
+    /// - `Some(Vec<i64>)`: The solar system IDs of the route including the origin & destination
+    /// - `None`: No route exists between the solar systems
+    pub fn shortest_path(&self, origin: i64, destination: i64, avoid: &[i64]) -> Option<Vec<i64>> {
+        let avoid: HashSet<i64> = avoid.iter().copied().collect();
+        let mut previous: HashMap<i64, i64> = HashMap::new();
+        let mut queue = VecDeque::from([origin]);
+
+        while let Some(system_id) = queue.pop_front() {
+            if system_id == destination {
+                let mut route = vec![destination];
+                while let Some(&system_id) = previous.get(route.last()?) {
+                    route.push(system_id);
+                }
+                route.reverse();
+
+                return Some(route);
+            }
+
+            for &neighbor in self.neighbors(system_id) {
+                if neighbor == origin || previous.contains_key(&neighbor) {
+                    continue;
+                }
+                if avoid.contains(&neighbor) && neighbor != destination {
+                    continue;
+                }
+
+                previous.insert(neighbor, system_id);
+                queue.push_back(neighbor);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of jumps along the shortest route between two solar systems
+    ///
+    /// # Arguments
+    /// - `origin` (`i64`): The ID of the solar system to start from
+    /// - `destination` (`i64`): The ID of the solar system to travel to
+    ///
+    /// # Returns
+    /// - `Some(usize)`: The number of jumps, `0` if the origin is the destination
+    /// - `None`: No route exists between the solar systems
+    pub fn jumps(&self, origin: i64, destination: i64) -> Option<usize> {
+        self.shortest_path(origin, destination, &[])
+            .map(|route| route.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod universe_graph_tests {
+    use super::*;
+
+    /// Creates a graph of 5 systems: 1-2-3-4 in a line with 5 connecting 1 & 4, 6 has no stargates
+    fn mock_graph() -> UniverseGraph {
+        let mut graph = UniverseGraph::new();
+        graph.insert_system(1, vec![2, 5]);
+        graph.insert_system(2, vec![1, 3]);
+        graph.insert_system(3, vec![2, 4]);
+        graph.insert_system(4, vec![3, 5]);
+        graph.insert_system(5, vec![1, 4]);
+        graph.insert_system(6, vec![]);
+
+        graph
+    }
+
+    /// Tests finding the shortest route between solar systems
+    ///
+    /// # Test Setup
+    /// - Create a graph with two routes between systems 1 & 4
+    ///
+    /// # Assertions
+    /// - Assert the shorter route is returned including the origin & destination
+    /// - Assert the longer route is returned when avoiding a system on the shorter route
+    /// - Assert no route is returned for a system without stargates
+    /// - Assert the route from a system to itself contains only that system
+    #[test]
+    fn test_shortest_path() {
+        let graph = mock_graph();
+
+        assert_eq!(graph.shortest_path(1, 4, &[]), Some(vec![1, 5, 4]));
+        assert_eq!(graph.shortest_path(1, 4, &[5]), Some(vec![1, 2, 3, 4]));
+        assert_eq!(graph.shortest_path(1, 6, &[]), None);
+        assert_eq!(graph.shortest_path(3, 3, &[]), Some(vec![3]));
+        assert_eq!(graph.jumps(2, 5), Some(2));
+    }
+
+    /// Tests that a saved graph is loaded unchanged
+    ///
+    /// # Assertions
+    /// - Assert the loaded graph equals the saved graph
+    #[tokio::test]
+    async fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!(
+            "eve_esi_universe_graph_test_{}.json",
+            std::process::id()
+        ));
+        let mut graph = mock_graph();
+        graph.insert_stargate(50001248, 2);
+
+        graph.save(&path).await.unwrap();
+        let loaded = UniverseGraph::load(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, graph);
+    }
+}
@@ -0,0 +1,119 @@
+//! # EVE ESI Client Health Report
+//!
+//! Provides [`ClientHealth`], a snapshot of a [`Client`]'s configuration & internal state returned
+//! by [`Client::health`]. The report is serializable, e.g. to return from a `/healthz` endpoint of
+//! a service embedding the client or to log when diagnosing issues.
+//!
+//! ## Usage Example
+//!
+//! ```no_run
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let health = esi_client.health().await;
+//!
+//! if !health.jwt_key_cache.cached {
+//!     println!("No JWT keys have been fetched yet");
+//! }
+//!
+//! let json = serde_json::to_string(&health)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::esi::RateLimitHeaders;
+use crate::Client;
+
+/// Snapshot of a [`Client`]'s configuration & internal state, see the [module documentation](self)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClientHealth {
+    /// The base ESI URL requests are sent to
+    pub esi_url: String,
+    /// Whether OAuth2 is configured, required for login & token endpoints
+    pub oauth2_configured: bool,
+    /// Whether access tokens are validated before authenticated requests
+    pub validate_token_before_request: bool,
+    /// Whether requests wait for the error limit to reset after a 420 response
+    pub error_limit_cool_off: bool,
+    /// Time at which the error limit resets after the most recent 420 response, `None` if no 420
+    /// response was received or the reset time has passed
+    pub error_limit_reset_at: Option<DateTime<Utc>>,
+    /// Rate limit headers of the most recent response of each rate limit group, sorted by group
+    pub rate_limits: Vec<RateLimitHeaders>,
+    /// Number of ESI requests currently in flight, see [`Client::in_flight_requests`]
+    pub in_flight_requests: usize,
+    /// Number of names held by the name cache, including expired names not yet evicted
+    #[cfg(feature = "universe")]
+    pub name_cache_size: usize,
+    /// Maximum number of names held by the name cache
+    #[cfg(feature = "universe")]
+    pub name_cache_max_size: usize,
+    /// Number of deprecated routes used by the client, see [`Client::deprecated_routes`]
+    pub deprecated_routes_used: usize,
+    /// State of the cache of JWT keys used to validate access tokens
+    pub jwt_key_cache: JwtKeyCacheHealth,
+}
+
+/// State of a [`Client`]'s JWT key cache & background refresh tasks
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JwtKeyCacheHealth {
+    /// Whether JWT keys have been fetched & cached
+    pub cached: bool,
+    /// Number of cached JWT keys
+    pub key_count: usize,
+    /// Seconds since the cached keys were fetched, `None` if no keys are cached
+    pub age_secs: Option<u64>,
+    /// Whether the cached keys are older than the cache TTL & will be fetched again when next used
+    pub expired: bool,
+    /// Whether a refresh of the keys is currently in progress
+    pub refreshing: bool,
+    /// Seconds since the most recent set of refresh attempts failed, `None` if none failed
+    pub last_refresh_failure_secs: Option<u64>,
+    /// Whether keys are proactively refreshed by a background task before they expire
+    pub background_refresh_enabled: bool,
+    /// Number of background refresh tasks which are still running
+    pub background_tasks_running: usize,
+    /// Whether the client was shut down with [`Client::shutdown`], stopping background refreshes
+    pub shutdown: bool,
+}
+
+impl Client {
+    /// Returns a report of the [`Client`]'s configuration & internal state
+    ///
+    /// For an overview & usage example, see the [health module documentation](crate::health)
+    pub async fn health(&self) -> ClientHealth {
+        let inner = &self.inner;
+
+        let error_limit_reset_at = inner
+            .esi_error_limit_reset
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .filter(|reset_at| *reset_at > Utc::now());
+
+        let mut rate_limits: Vec<RateLimitHeaders> = inner
+            .esi_rate_limits
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect();
+        rate_limits.sort_by(|a, b| a.group.cmp(&b.group));
+
+        ClientHealth {
+            esi_url: inner.esi_url.clone(),
+            oauth2_configured: inner.oauth2_client.is_some(),
+            validate_token_before_request: inner.esi_validate_token_before_request,
+            error_limit_cool_off: inner.esi_error_limit_cool_off,
+            error_limit_reset_at,
+            rate_limits,
+            in_flight_requests: inner.esi_request_limiter.in_flight(),
+            #[cfg(feature = "universe")]
+            name_cache_size: inner.name_cache.len(),
+            #[cfg(feature = "universe")]
+            name_cache_max_size: inner.name_cache.max_size(),
+            deprecated_routes_used: inner.deprecated_routes.report().len(),
+            jwt_key_cache: inner.jwt_key_cache.health().await,
+        }
+    }
+}
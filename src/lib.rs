@@ -60,7 +60,29 @@
 //!
 //! ### Custom Endpoints
 //!
-//! - [Adding custom ESI endpoints](crate::esi)
+//! - [Adding custom ESI endpoints](crate::esi#custom-routes)
+//! - [Defining endpoint structs like the built-in endpoints](crate::define_esi_endpoint)
+//!
+//! # Endpoint Features
+//!
+//! Each ESI endpoint group, such as `market`, `corporation` or `mail`, is gated behind a cargo feature
+//! of the same name, with underscores replaced by hyphens, e.g. `planetary-interaction`. Enabling a
+//! group compiles its endpoints, models & the [`Client`] accessor without enabling other groups.
+//! Composite methods combining several groups are only compiled when every group they use is
+//! enabled, e.g. [`WalletEndpoints::get_named_corporation_wallets`](crate::endpoints::wallet::WalletEndpoints::get_named_corporation_wallets)
+//! requires both `wallet` & `corporation`, their documentation lists the features required. All
+//! groups are enabled by the default `full` feature, applications which only use a few groups or only
+//! OAuth2 can disable default features:
+//!
+//! ```toml
+//! [dependencies]
+//! eve_esi = { version = "0.5", default-features = false, features = ["market"] }
+//! ```
+//!
+//! [Scopes](crate::scope) are always available as logins commonly request scopes for endpoints
+//! which are called elsewhere. The [`NameCache`](crate::esi::NameCache) requires the `universe`
+//! feature & [`Client::entity`] requires the `alliance`, `character`, `corporation` & `universe`
+//! features.
 //!
 //! # Logging
 //!
@@ -98,17 +120,49 @@ pub mod config;
 pub mod endpoints;
 pub mod error;
 pub mod esi;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "golden")]
+pub mod golden;
+#[cfg(feature = "universe-graph")]
+pub mod graph;
+pub mod health;
 pub mod model;
 pub mod oauth2;
 pub mod scope;
+#[cfg(feature = "sde")]
+pub mod sde;
+pub mod session;
 
-pub use crate::builder::ClientBuilder;
+pub use crate::builder::{ClientBuilder, UserAgent};
 pub use crate::client::Client;
-pub use crate::config::{Config, ConfigBuilder};
-pub use crate::error::{ConfigError, Error, EsiError, OAuthError};
-pub use crate::esi::{CacheStrategy, CachedResponse, EsiRequest, EsiResponse, Language};
+pub use crate::config::{Config, ConfigBuilder, Environment};
+pub use crate::error::{CompositeError, ConfigError, Error, EsiError, OAuthError};
+#[cfg(feature = "record-replay")]
+pub use crate::esi::Cassette;
+#[cfg(feature = "redis")]
+pub use crate::esi::RedisResponseCache;
+pub use crate::esi::{
+    AccessToken, CacheStrategy, CachedResponse, EsiRequest, EsiResponse, FileResponseCache,
+    Language, MemoryResponseCache, RawEsiResponse, ResponseCache,
+};
 pub use crate::scope::ScopeBuilder;
 
+/// HTTP method of a request, see [`EsiRequest::with_method`] &
+/// [`define_esi_endpoint!`](crate::define_esi_endpoint)
+pub use reqwest::Method;
+
+#[doc(hidden)]
+pub use crate::endpoints::macros::support as __private;
+
+/// Date & time types used by model timestamps, [`DateTime<Utc>`](chrono::DateTime) for dates with
+/// a time & [`NaiveDate`](chrono::NaiveDate) for dates without one
+pub use chrono;
+
+/// Decimal type used for ISK amounts with the `decimal` feature, see [`Isk`](crate::model::Isk)
+#[cfg(feature = "decimal")]
+pub use rust_decimal;
+
 mod constant;
 
 #[cfg(test)]
@@ -5,6 +5,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::esi::IconSize;
+
 /// Represents an alliance in EVE Online
 ///
 /// # Documentation
@@ -39,3 +41,42 @@ pub struct AllianceIcons {
     /// 64x64 icon URL for an alliance
     pub px64x64: String,
 }
+
+impl AllianceIcons {
+    /// Returns the URL of the icon of the provided size
+    ///
+    /// Alliances have no 256x256 icon, the 128x128 URL is returned for [`IconSize::Px256`].
+    pub fn url(&self, size: IconSize) -> &str {
+        match size {
+            IconSize::Px64 => &self.px64x64,
+            IconSize::Px128 | IconSize::Px256 => &self.px128x128,
+        }
+    }
+}
+
+/// A member corporation of an alliance with its resolved name
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AllianceCorporation {
+    /// The ID of the member corporation
+    pub corporation_id: i64,
+    /// The name of the member corporation
+    pub name: String,
+}
+
+/// Represents an alliance along with its member corporations
+///
+/// Returned by [`AllianceEndpoints::get_alliance_with_corporations`](crate::endpoints::alliance::AllianceEndpoints::get_alliance_with_corporations).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AllianceWithCorporations {
+    /// Information on the alliance
+    pub alliance: Alliance,
+    /// The alliance's member corporations with their resolved names
+    pub corporations: Vec<AllianceCorporation>,
+}
+
+impl AllianceWithCorporations {
+    /// Returns the number of member corporations within the alliance
+    pub fn corporation_count(&self) -> usize {
+        self.corporations.len()
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! Provides structs representing assets within EVE Online such as blueprints
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::model::enums::asset::LocationType;
+use crate::model::HasTypeId;
 
 use super::enums::asset::LocationFlag;
 
@@ -35,6 +38,12 @@ pub struct Blueprint {
     pub type_id: i64,
 }
 
+impl HasTypeId for Blueprint {
+    fn type_id(&self) -> i64 {
+        self.type_id
+    }
+}
+
 /// An asset in EVE Online
 ///
 /// # Documentation
@@ -42,21 +51,27 @@ pub struct Blueprint {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Asset {
     /// If item is a blueprint, bool indicating whether or not it is a copy
-    is_blueprint_copy: Option<bool>,
+    pub is_blueprint_copy: Option<bool>,
     /// If item is stackable or not
-    is_singleton: bool,
+    pub is_singleton: bool,
     /// Unique ID of the item
-    item_id: i64,
+    pub item_id: i64,
     /// Flag indicating the location of the item
-    location_flag: LocationFlag,
+    pub location_flag: LocationFlag,
     /// ID of the item's location
-    location_id: i64,
+    pub location_id: i64,
     /// The type of location ID
-    location_type: LocationType,
+    pub location_type: LocationType,
     /// The quantity of the item
-    quantity: i64,
+    pub quantity: i64,
     /// Type ID of the item
-    type_id: i64,
+    pub type_id: i64,
+}
+
+impl HasTypeId for Asset {
+    fn type_id(&self) -> i64 {
+        self.type_id
+    }
 }
 
 /// The coordinates of where an item is located in space
@@ -68,11 +83,11 @@ pub struct Asset {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AssetLocationPosition {
     /// X coordinate of the item in space
-    x: f64,
+    pub x: f64,
     /// Y coordinate of the item in space
-    y: f64,
+    pub y: f64,
     /// Z coordinate of the item in space
-    z: f64,
+    pub z: f64,
 }
 
 /// Where an asset is located in space in EVE Online
@@ -82,9 +97,9 @@ pub struct AssetLocationPosition {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AssetLocation {
     /// Unique ID of the item
-    item_id: i64,
+    pub item_id: i64,
     /// Item coordinates in space, coordinates will be (0,0,0) if in a station or hangar
-    position: AssetLocationPosition,
+    pub position: AssetLocationPosition,
 }
 
 /// Repesents an asset's item ID and name of the item
@@ -94,7 +109,231 @@ pub struct AssetLocation {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AssetName {
     /// Unique ID of the item
-    item_id: i64,
+    pub item_id: i64,
     /// Name of the item
-    name: String,
+    pub name: String,
+}
+
+/// A character's or corporation's assets arranged by the container, ship or hangar they are stored in
+///
+/// ESI returns assets as a flat list where the `location_id` of an item within a container or ship
+/// is the `item_id` of that container or ship. The tree indexes the list by `item_id` & location so
+/// items can be looked up, traced back to the station, structure or solar system they are in, &
+/// totalled per location.
+///
+/// Assets are kept in the order they were provided in.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetTree {
+    assets: Vec<Asset>,
+    items: HashMap<i64, usize>,
+    contents: HashMap<i64, Vec<usize>>,
+}
+
+impl AssetTree {
+    /// Builds an asset tree from a flat list of assets
+    ///
+    /// # Arguments
+    /// - `assets` (`Vec<`[`Asset`]`>`): Every page of a character's or corporation's assets
+    pub fn new(assets: Vec<Asset>) -> Self {
+        let mut items = HashMap::with_capacity(assets.len());
+        let mut contents: HashMap<i64, Vec<usize>> = HashMap::new();
+
+        for (index, asset) in assets.iter().enumerate() {
+            items.insert(asset.item_id, index);
+            contents.entry(asset.location_id).or_default().push(index);
+        }
+
+        Self {
+            assets,
+            items,
+            contents,
+        }
+    }
+
+    /// Returns the asset with the provided item ID
+    pub fn get(&self, item_id: i64) -> Option<&Asset> {
+        self.items.get(&item_id).map(|index| &self.assets[*index])
+    }
+
+    /// Returns the assets stored directly within the provided location, container or ship
+    ///
+    /// Items within containers stored in the location are not included.
+    pub fn contents(&self, location_id: i64) -> Vec<&Asset> {
+        self.contents
+            .get(&location_id)
+            .map(|indices| indices.iter().map(|index| &self.assets[*index]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the assets which are not stored within another asset, such as items in a hangar
+    pub fn roots(&self) -> Vec<&Asset> {
+        self.assets
+            .iter()
+            .filter(|asset| !self.items.contains_key(&asset.location_id))
+            .collect()
+    }
+
+    /// Returns the asset with the provided item ID followed by each container or ship it is stored within
+    ///
+    /// The last asset of the path is stored directly in a station, structure or solar system, see
+    /// [`Self::root_location_id`]. Returns an empty path if the item ID is not in the tree.
+    pub fn path_to_root(&self, item_id: i64) -> Vec<&Asset> {
+        let mut path = Vec::new();
+        let mut current = self.get(item_id);
+
+        // Bounded by the number of assets in case of a cyclic location in malformed data
+        while let Some(asset) = current {
+            if path.len() == self.assets.len() {
+                break;
+            }
+
+            path.push(asset);
+            current = self.get(asset.location_id);
+        }
+
+        path
+    }
+
+    /// Returns the ID of the station, structure or solar system the asset with the provided item ID is in
+    pub fn root_location_id(&self, item_id: i64) -> Option<i64> {
+        self.path_to_root(item_id)
+            .last()
+            .map(|asset| asset.location_id)
+    }
+
+    /// Returns the assets with the provided location flag, such as the items fitted to a ship's high slots
+    pub fn filter_by_flag(&self, location_flag: &LocationFlag) -> Vec<&Asset> {
+        self.assets
+            .iter()
+            .filter(|asset| &asset.location_flag == location_flag)
+            .collect()
+    }
+
+    /// Returns the total quantity of each type ID within each station, structure or solar system
+    ///
+    /// Items within containers & ships count towards the location the container or ship is in.
+    ///
+    /// # Returns
+    /// A map of root location IDs, see [`Self::root_location_id`], to a map of type IDs to quantities
+    pub fn quantities_by_location(&self) -> HashMap<i64, HashMap<i64, i64>> {
+        let mut quantities: HashMap<i64, HashMap<i64, i64>> = HashMap::new();
+
+        for asset in &self.assets {
+            let Some(location_id) = self.root_location_id(asset.item_id) else {
+                continue;
+            };
+
+            *quantities
+                .entry(location_id)
+                .or_default()
+                .entry(asset.type_id)
+                .or_default() += asset.quantity;
+        }
+
+        quantities
+    }
+
+    /// Returns an iterator over every asset in the tree
+    pub fn iter(&self) -> std::slice::Iter<'_, Asset> {
+        self.assets.iter()
+    }
+
+    /// Returns the number of assets in the tree
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns `true` if the tree has no assets
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Returns the flat list of assets the tree was built from
+    pub fn into_assets(self) -> Vec<Asset> {
+        self.assets
+    }
+}
+
+impl From<Vec<Asset>> for AssetTree {
+    fn from(assets: Vec<Asset>) -> Self {
+        Self::new(assets)
+    }
+}
+
+#[cfg(test)]
+mod asset_tree_tests {
+    use super::*;
+
+    /// Creates an asset with the provided IDs & location
+    fn mock_asset(
+        item_id: i64,
+        type_id: i64,
+        location_id: i64,
+        location_flag: LocationFlag,
+    ) -> Asset {
+        Asset {
+            is_blueprint_copy: None,
+            is_singleton: true,
+            item_id,
+            location_flag,
+            location_id,
+            location_type: if location_id == 60003760 {
+                LocationType::Station
+            } else {
+                LocationType::Item
+            },
+            quantity: 10,
+            type_id,
+        }
+    }
+
+    /// Tests assets are looked up, traced to their root location & totalled per location
+    ///
+    /// # Test Setup
+    /// - Create a ship in a station hangar with a fitted module & a container holding ore in its cargo
+    /// - Create ore directly in the station hangar
+    ///
+    /// # Assertions
+    /// - Assert assets are looked up by item ID & container contents are listed
+    /// - Assert the path to root goes from the ore through the container & ship
+    /// - Assert assets are filtered by location flag
+    /// - Assert quantities within containers count towards the station
+    #[test]
+    fn test_asset_tree() {
+        let tree = AssetTree::new(vec![
+            mock_asset(1, 587, 60003760, LocationFlag::Hangar),
+            mock_asset(2, 3831, 1, LocationFlag::HiSlot0),
+            mock_asset(3, 3467, 1, LocationFlag::Cargo),
+            mock_asset(4, 1230, 3, LocationFlag::Unlocked),
+            mock_asset(5, 1230, 60003760, LocationFlag::Hangar),
+        ]);
+
+        // Assert assets are looked up by item ID & container contents are listed
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.get(4).map(|asset| asset.type_id), Some(1230));
+        assert!(tree.get(6).is_none());
+        let ship_contents: Vec<i64> = tree.contents(1).iter().map(|asset| asset.item_id).collect();
+        assert_eq!(ship_contents, vec![2, 3]);
+        let roots: Vec<i64> = tree.roots().iter().map(|asset| asset.item_id).collect();
+        assert_eq!(roots, vec![1, 5]);
+
+        // Assert the path to root goes from the ore through the container & ship
+        let path: Vec<i64> = tree
+            .path_to_root(4)
+            .iter()
+            .map(|asset| asset.item_id)
+            .collect();
+        assert_eq!(path, vec![4, 3, 1]);
+        assert_eq!(tree.root_location_id(4), Some(60003760));
+        assert!(tree.path_to_root(6).is_empty());
+
+        // Assert assets are filtered by location flag
+        assert_eq!(tree.filter_by_flag(&LocationFlag::Hangar).len(), 2);
+
+        // Assert quantities within containers count towards the station
+        let quantities = tree.quantities_by_location();
+        assert_eq!(quantities.len(), 1);
+        assert_eq!(quantities[&60003760][&1230], 20);
+        assert_eq!(quantities[&60003760][&587], 10);
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Provides calendar-related structs for EVE Online
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::model::enums::calendar::{CalendarEventOwnerType, CalendarEventResponse};
@@ -14,15 +14,15 @@ use crate::model::enums::calendar::{CalendarEventOwnerType, CalendarEventRespons
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CalendarEventSummary {
     /// Timestamp of the event
-    event_date: DateTime<Utc>,
+    pub event_date: DateTime<Utc>,
     /// Unique ID of the event
-    event_id: i64,
+    pub event_id: i64,
     /// Character's response to the event
-    event_response: CalendarEventResponse,
+    pub event_response: CalendarEventResponse,
     /// Importance of the event
-    importance: i64,
+    pub importance: i64,
     /// Name of the event
-    title: String,
+    pub title: String,
 }
 
 /// A calendar event in EVE Online
@@ -32,26 +32,33 @@ pub struct CalendarEventSummary {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CalendarEvent {
     /// Timestamp of the event
-    date: DateTime<Utc>,
+    pub date: DateTime<Utc>,
     /// Length of the event in minutes
-    duration: i64,
+    pub duration: i64,
     /// Unique ID of the event
-    event_id: i64,
+    pub event_id: i64,
     /// Importance of the event
-    importance: i64,
+    pub importance: i64,
     /// Unique ID of the event owner
-    owner_id: i64,
+    pub owner_id: i64,
     /// Name of the event owner
-    owner_name: String,
+    pub owner_name: String,
     /// The type of calendar event owner
-    owner_type: CalendarEventOwnerType,
+    pub owner_type: CalendarEventOwnerType,
     /// Character's response to the event
     // Maybe this is CalendarEventResponse enum but ESI documentation doesn't show an enum here
-    response: String,
+    pub response: String,
     /// Description of the event
-    text: String,
+    pub text: String,
     /// Name of the event
-    title: String,
+    pub title: String,
+}
+
+impl CalendarEvent {
+    /// Returns the date the event ends, calculated as the event date plus the duration in minutes
+    pub fn end_date(&self) -> DateTime<Utc> {
+        self.date + TimeDelta::minutes(self.duration)
+    }
 }
 
 /// An entry for a calendar event attendee character ID & their response to the event
@@ -61,7 +68,7 @@ pub struct CalendarEvent {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct CalendarEventAttendee {
     /// Unique ID of the character
-    character_id: i64,
+    pub character_id: i64,
     /// Character's response to the event
-    event_response: CalendarEventResponse,
+    pub event_response: CalendarEventResponse,
 }
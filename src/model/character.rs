@@ -241,3 +241,12 @@ pub struct CharacterCorporationTitle {
     /// The unique ID of the title
     pub title_id: i64,
 }
+
+impl CharacterCorporationTitle {
+    /// Returns the title's name with in-game markup such as `<color=...>` tags removed
+    ///
+    /// See [`strip_markup`](crate::model::markup::strip_markup)
+    pub fn plain_name(&self) -> String {
+        crate::model::markup::strip_markup(&self.name)
+    }
+}
@@ -1,11 +1,21 @@
 //! # EVE ESI Clone Models
 //!
-//! Provides clone-related structs for EVE Online
+//! Provides clone-related structs for EVE Online, including [`ImplantSet`] to classify the
+//! implants of a clone & total their attribute bonuses.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::model::enums::clones::CharacterCloneLocationType;
+use crate::model::skills::{AttributeBonuses, CharacterAttribute};
+#[cfg(feature = "universe")]
+use crate::model::universe::ItemType;
+
+/// Dogma attribute ID of the slot an implant is plugged into, from 1 to 10
+pub const IMPLANT_SLOT_DOGMA_ID: i64 = 331;
+
+/// Number of implants in a complete pirate implant set, one for each of slots 1 to 6
+pub const PIRATE_IMPLANT_SET_SIZE: usize = 6;
 
 /// A character's home station
 ///
@@ -54,3 +64,342 @@ pub struct CharacterClones {
     /// Last time character changed their home station
     pub last_station_change_date: DateTime<Utc>,
 }
+
+/// An implant with its slot & attribute bonuses
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Implant {
+    /// The type ID of the implant
+    pub type_id: i64,
+    /// The name of the implant
+    pub name: String,
+    /// The slot the implant is plugged into, from 1 to 10, `None` if the type is not an implant
+    pub slot: Option<i64>,
+    /// The bonuses the implant provides to the character's attributes
+    pub attribute_bonuses: AttributeBonuses,
+    /// The pirate implant set the implant belongs to, if any
+    pub pirate_set: Option<PirateImplantSet>,
+}
+
+impl Implant {
+    /// Creates an [`Implant`] from the implant's item type
+    ///
+    /// The slot & attribute bonuses are read from the type's dogma attributes & the pirate set from
+    /// its name, see [`PirateImplantSet::from_type_name`].
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// # Arguments
+    /// - `item_type` (&[`ItemType`]): The item type of the implant, see
+    ///   [`UniverseEndpoints::get_item_types`](crate::endpoints::universe::UniverseEndpoints::get_item_types)
+    #[cfg(feature = "universe")]
+    pub fn from_item_type(item_type: &ItemType) -> Self {
+        let attributes = item_type
+            .dogma_attributes
+            .iter()
+            .map(|attribute| (attribute.attribute_id, attribute.value));
+
+        Self {
+            type_id: item_type.type_id,
+            name: item_type.name.clone(),
+            slot: item_type
+                .dogma_attributes
+                .iter()
+                .find(|attribute| attribute.attribute_id == IMPLANT_SLOT_DOGMA_ID)
+                .map(|attribute| attribute.value as i64),
+            attribute_bonuses: AttributeBonuses::from_dogma_attributes(attributes),
+            pirate_set: PirateImplantSet::from_type_name(&item_type.name),
+        }
+    }
+
+    /// Returns `true` if the implant provides a bonus to any character attribute
+    pub fn is_attribute_implant(&self) -> bool {
+        self.attribute_bonuses != AttributeBonuses::default()
+    }
+}
+
+/// Grade of a pirate implant set, higher grades provide a stronger set bonus
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ImplantGrade {
+    /// Low-grade implants
+    Low,
+    /// Mid-grade implants
+    Mid,
+    /// High-grade implants
+    High,
+}
+
+/// A pirate implant set such as the High-grade Crystal set
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PirateImplantSet {
+    /// The grade of the set
+    pub grade: ImplantGrade,
+    /// The name of the set without its grade, e.g. `Crystal`
+    pub name: String,
+}
+
+impl PirateImplantSet {
+    /// Detects the pirate implant set an implant belongs to from its type name
+    ///
+    /// Pirate implants are named `<grade>-grade <set> <letter>`, e.g. `High-grade Crystal Alpha`,
+    /// where the Greek letter corresponds to the implant's slot from Alpha to Epsilon & Omega.
+    ///
+    /// # Arguments
+    /// - `name` (`&str`): The type name of the implant
+    ///
+    /// # Returns
+    /// - `Some(PirateImplantSet)`: The set the implant belongs to
+    /// - `None`: If the name is not the name of a pirate implant
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        let (grade, rest) = name.split_once("-grade ")?;
+        let grade = match grade {
+            "Low" => ImplantGrade::Low,
+            "Mid" => ImplantGrade::Mid,
+            "High" => ImplantGrade::High,
+            _ => return None,
+        };
+
+        let (set, letter) = rest.rsplit_once(' ')?;
+        if !matches!(
+            letter,
+            "Alpha" | "Beta" | "Gamma" | "Delta" | "Epsilon" | "Omega"
+        ) {
+            return None;
+        }
+
+        Some(Self {
+            grade,
+            name: set.to_string(),
+        })
+    }
+}
+
+/// A pirate implant set within an [`ImplantSet`] with the number of its implants which are plugged in
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PirateImplantSetPieces {
+    /// The pirate implant set
+    pub set: PirateImplantSet,
+    /// The number of the set's implants which are plugged in
+    pub pieces: usize,
+}
+
+impl PirateImplantSetPieces {
+    /// Returns `true` if all 6 implants of the set are plugged in
+    pub fn is_complete(&self) -> bool {
+        self.pieces >= PIRATE_IMPLANT_SET_SIZE
+    }
+}
+
+/// The implants plugged into a clone ordered by slot
+///
+/// Created with [`ImplantSet::from_item_types`] from the implants returned by
+/// [`ClonesEndpoints::get_active_implants`](crate::endpoints::clones::ClonesEndpoints::get_active_implants)
+/// or a jump clone's [`CharacterJumpClone::implants`], or retrieved for the active clone with
+/// [`ClonesEndpoints::get_active_implant_set`](crate::endpoints::clones::ClonesEndpoints::get_active_implant_set).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ImplantSet {
+    /// The implants ordered by slot, implants without a slot are ordered last
+    pub implants: Vec<Implant>,
+}
+
+impl ImplantSet {
+    /// Creates an [`ImplantSet`] from the item types of the implants
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// # Arguments
+    /// - `item_types` (`impl IntoIterator<Item = &ItemType>`): The item types of the implants
+    #[cfg(feature = "universe")]
+    pub fn from_item_types<'a>(item_types: impl IntoIterator<Item = &'a ItemType>) -> Self {
+        let mut implants: Vec<Implant> = item_types
+            .into_iter()
+            .map(Implant::from_item_type)
+            .collect();
+        implants.sort_by_key(|implant| (implant.slot.unwrap_or(i64::MAX), implant.type_id));
+
+        Self { implants }
+    }
+
+    /// Returns the implant plugged into the provided slot
+    ///
+    /// # Arguments
+    /// - `slot` (`i64`): The implant slot, from 1 to 10
+    pub fn slot(&self, slot: i64) -> Option<&Implant> {
+        self.implants
+            .iter()
+            .find(|implant| implant.slot == Some(slot))
+    }
+
+    /// Returns the implant providing a bonus to the provided attribute
+    ///
+    /// # Arguments
+    /// - `attribute` ([`CharacterAttribute`]): The character attribute
+    pub fn attribute_implant(&self, attribute: CharacterAttribute) -> Option<&Implant> {
+        self.implants
+            .iter()
+            .find(|implant| implant.attribute_bonuses.get(attribute) > 0)
+    }
+
+    /// Returns the total bonuses of the implants to the character's attributes
+    ///
+    /// Use with [`CharacterAttributes::sp_per_hour`](crate::model::skills::CharacterAttributes::sp_per_hour)
+    /// to calculate training rates.
+    pub fn attribute_bonuses(&self) -> AttributeBonuses {
+        self.implants
+            .iter()
+            .fold(AttributeBonuses::default(), |total, implant| {
+                let bonuses = implant.attribute_bonuses;
+
+                AttributeBonuses {
+                    charisma: total.charisma + bonuses.charisma,
+                    intelligence: total.intelligence + bonuses.intelligence,
+                    memory: total.memory + bonuses.memory,
+                    perception: total.perception + bonuses.perception,
+                    willpower: total.willpower + bonuses.willpower,
+                }
+            })
+    }
+
+    /// Returns the pirate implant sets with at least one implant plugged in, most pieces first
+    pub fn pirate_sets(&self) -> Vec<PirateImplantSetPieces> {
+        let mut sets: Vec<PirateImplantSetPieces> = Vec::new();
+
+        for set in self
+            .implants
+            .iter()
+            .filter_map(|implant| implant.pirate_set.as_ref())
+        {
+            match sets.iter_mut().find(|pieces| &pieces.set == set) {
+                Some(pieces) => pieces.pieces += 1,
+                None => sets.push(PirateImplantSetPieces {
+                    set: set.clone(),
+                    pieces: 1,
+                }),
+            }
+        }
+
+        sets.sort_by_key(|pieces| std::cmp::Reverse(pieces.pieces));
+        sets
+    }
+}
+
+#[cfg(test)]
+mod implant_set_tests {
+    use super::*;
+    use crate::model::universe::TypeDogmaAttribute;
+
+    /// Creates a mock implant item type with the provided slot & attribute bonus dogma attributes
+    fn mock_implant(type_id: i64, name: &str, slot: i64, bonuses: &[(i64, f64)]) -> ItemType {
+        let mut dogma_attributes = vec![TypeDogmaAttribute {
+            attribute_id: IMPLANT_SLOT_DOGMA_ID,
+            value: slot as f64,
+        }];
+        dogma_attributes.extend(
+            bonuses
+                .iter()
+                .map(|&(attribute_id, value)| TypeDogmaAttribute {
+                    attribute_id,
+                    value,
+                }),
+        );
+
+        ItemType {
+            capacity: None,
+            description: String::new(),
+            dogma_attributes,
+            dogma_effects: Vec::new(),
+            graphic_id: None,
+            group_id: 300,
+            icon_id: None,
+            market_group_id: None,
+            mass: None,
+            name: name.to_string(),
+            packaged_volume: None,
+            portion_size: None,
+            published: true,
+            radius: None,
+            type_id,
+            volume: None,
+        }
+    }
+
+    /// Tests classifying the implants of a clone & totalling their attribute bonuses
+    ///
+    /// # Test Setup
+    /// - Create 2 High-grade Crystal implants, a +5 perception implant & a hardwiring
+    ///
+    /// # Assertions
+    /// - Assert implants are ordered by slot
+    /// - Assert attribute implants are found by attribute
+    /// - Assert the attribute bonuses are totalled
+    /// - Assert the partial pirate set is detected
+    #[test]
+    fn test_implant_set() {
+        let item_types = [
+            mock_implant(13283, "Zainou 'Gnome' Shield Upgrades SU-601", 6, &[]),
+            mock_implant(20121, "High-grade Crystal Beta", 2, &[(177, 4.0)]),
+            mock_implant(10216, "Ocular Filter - Elite", 1, &[(178, 5.0)]),
+            mock_implant(20499, "High-grade Crystal Gamma", 3, &[(179, 4.0)]),
+        ];
+
+        let set = ImplantSet::from_item_types(&item_types);
+
+        // Assert implants are ordered by slot
+        let slots: Vec<Option<i64>> = set.implants.iter().map(|implant| implant.slot).collect();
+        assert_eq!(slots, vec![Some(1), Some(2), Some(3), Some(6)]);
+        assert_eq!(set.slot(6).unwrap().type_id, 13283);
+
+        // Assert attribute implants are found by attribute
+        assert_eq!(
+            set.attribute_implant(CharacterAttribute::Perception)
+                .unwrap()
+                .type_id,
+            10216
+        );
+        assert!(set
+            .attribute_implant(CharacterAttribute::Charisma)
+            .is_none());
+        assert!(!set.slot(6).unwrap().is_attribute_implant());
+
+        // Assert the attribute bonuses are totalled
+        let bonuses = set.attribute_bonuses();
+        assert_eq!(bonuses.perception, 5);
+        assert_eq!(bonuses.memory, 4);
+        assert_eq!(bonuses.willpower, 4);
+        assert_eq!(bonuses.intelligence, 0);
+
+        // Assert the partial pirate set is detected
+        let sets = set.pirate_sets();
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].set.grade, ImplantGrade::High);
+        assert_eq!(sets[0].set.name, "Crystal");
+        assert_eq!(sets[0].pieces, 2);
+        assert!(!sets[0].is_complete());
+    }
+
+    /// Tests detecting pirate implant sets from type names
+    ///
+    /// # Assertions
+    /// - Assert pirate implant names are parsed into their grade & set
+    /// - Assert other implant names are not detected as pirate implants
+    #[test]
+    fn test_pirate_implant_set_from_type_name() {
+        // Assert pirate implant names are parsed into their grade & set
+        assert_eq!(
+            PirateImplantSet::from_type_name("Mid-grade Ascendancy Omega"),
+            Some(PirateImplantSet {
+                grade: ImplantGrade::Mid,
+                name: "Ascendancy".to_string()
+            })
+        );
+
+        // Assert other implant names are not detected as pirate implants
+        assert_eq!(
+            PirateImplantSet::from_type_name("Ocular Filter - Elite"),
+            None
+        );
+        assert_eq!(
+            PirateImplantSet::from_type_name("High-grade Crystal Zeta"),
+            None
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Provides clone-related structs for EVE Online
 
+use std::collections::{BTreeMap, HashMap};
+
 use serde::{Deserialize, Serialize};
 
 use crate::model::enums::contacts::ContactType;
@@ -87,3 +89,488 @@ pub struct CorporationContact {
     /// Standings towards the contact
     pub standing: f64,
 }
+
+/// A contact entry with labels applied, shared by alliance, corporation & character contacts
+///
+/// Used by [`LabelSet`] to resolve the names of a contact's labels & filter contacts by label.
+pub trait HasLabelIds {
+    /// Returns the IDs of the labels applied to the contact
+    fn label_ids(&self) -> &[i64];
+}
+
+impl HasLabelIds for AllianceContact {
+    fn label_ids(&self) -> &[i64] {
+        &self.label_ids
+    }
+}
+
+impl HasLabelIds for CharacterContact {
+    fn label_ids(&self) -> &[i64] {
+        &self.label_ids
+    }
+}
+
+impl HasLabelIds for CorporationContact {
+    fn label_ids(&self) -> &[i64] {
+        &self.label_ids
+    }
+}
+
+impl HasLabelIds for DesiredContact {
+    fn label_ids(&self) -> &[i64] {
+        &self.label_ids
+    }
+}
+
+/// The contact labels of a character, corporation or alliance, keyed by label ID
+///
+/// Contacts only reference their labels by ID, a [`LabelSet`] created from the labels endpoint of
+/// the same contact list resolves those IDs to names, such as from
+/// [`ContactsEndpoints::get_contact_label_set`](crate::endpoints::contacts::ContactsEndpoints::get_contact_label_set).
+/// Label IDs are only unique within a contact list, a character's label set can't resolve the
+/// labels of its corporation's contacts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct LabelSet {
+    labels: BTreeMap<i64, String>,
+}
+
+impl LabelSet {
+    /// Creates a [`LabelSet`] from the labels returned by a contact labels endpoint
+    ///
+    /// # Arguments
+    /// - `labels` (`Vec<ContactLabel>`): The labels of the contact list
+    pub fn new(labels: Vec<ContactLabel>) -> Self {
+        Self {
+            labels: labels
+                .into_iter()
+                .map(|label| (label.label_id, label.label_name))
+                .collect(),
+        }
+    }
+
+    /// Returns the name of a label, `None` if the label ID is unknown
+    pub fn name(&self, label_id: i64) -> Option<&str> {
+        self.labels.get(&label_id).map(String::as_str)
+    }
+
+    /// Returns the ID of the label with the provided name, `None` if no label has the name
+    ///
+    /// Names are matched exactly, if multiple labels share a name the lowest label ID is returned.
+    pub fn id(&self, label_name: &str) -> Option<i64> {
+        self.labels
+            .iter()
+            .find(|(_, name)| name.as_str() == label_name)
+            .map(|(label_id, _)| *label_id)
+    }
+
+    /// Returns the names of the labels applied to a contact, skipping unknown label IDs
+    pub fn names<'s>(&'s self, contact: &impl HasLabelIds) -> Vec<&'s str> {
+        contact
+            .label_ids()
+            .iter()
+            .filter_map(|label_id| self.name(*label_id))
+            .collect()
+    }
+
+    /// Returns the contacts with a label of the provided name applied
+    ///
+    /// # Arguments
+    /// - `contacts` (`&[C]`): The contacts of the contact list the labels belong to
+    /// - `label_name` (`&str`): The exact name of the label
+    ///
+    /// # Returns
+    /// The contacts with the label in the order provided, empty if no label has the name
+    pub fn filter<'c, C: HasLabelIds>(&self, contacts: &'c [C], label_name: &str) -> Vec<&'c C> {
+        let label_ids: Vec<i64> = self
+            .labels
+            .iter()
+            .filter(|(_, name)| name.as_str() == label_name)
+            .map(|(label_id, _)| *label_id)
+            .collect();
+
+        contacts
+            .iter()
+            .filter(|contact| {
+                contact
+                    .label_ids()
+                    .iter()
+                    .any(|label_id| label_ids.contains(label_id))
+            })
+            .collect()
+    }
+
+    /// Returns the number of labels
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns `true` if there are no labels
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl From<Vec<ContactLabel>> for LabelSet {
+    fn from(labels: Vec<ContactLabel>) -> Self {
+        Self::new(labels)
+    }
+}
+
+/// Maximum number of contact IDs accepted per add or edit contacts request
+pub const MAX_CONTACTS_PER_WRITE: usize = 100;
+
+/// Maximum number of contact IDs accepted per delete contacts request
+pub const MAX_CONTACTS_PER_DELETE: usize = 20;
+
+/// Contacts grouped by standing bits, sorted label IDs, & watched status
+type ContactGroups = BTreeMap<(u64, Vec<i64>, bool), ContactBatch>;
+
+/// A contact which should exist on a character's contact list after a contact sync
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DesiredContact {
+    /// Unique ID of the contact
+    pub contact_id: i64,
+    /// Standings towards the contact
+    pub standing: f64,
+    /// List of label IDs to apply to the contact entry
+    #[serde(default)]
+    pub label_ids: Vec<i64>,
+}
+
+/// A batch of contacts sharing the same standing, labels, & watched status
+///
+/// Represents a single add or edit contacts request, containing at most [`MAX_CONTACTS_PER_WRITE`] contacts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ContactBatch {
+    /// The IDs of the contacts in the batch
+    pub contact_ids: Vec<i64>,
+    /// The standing applied to the contacts
+    pub standing: f64,
+    /// The label IDs applied to the contacts, sorted in ascending order
+    pub label_ids: Vec<i64>,
+    /// Whether the contacts are added to the buddy list
+    pub watched: bool,
+}
+
+/// The minimal set of contact requests required to sync a character's contacts to a desired contact list
+///
+/// Contacts missing from the current contacts are added, contacts whose standing or labels differ are
+/// edited, & current contacts missing from the desired contacts are deleted. Contacts which already match
+/// are left untouched. Batches are split to respect ESI's limit of [`MAX_CONTACTS_PER_WRITE`] contacts per
+/// add or edit request & [`MAX_CONTACTS_PER_DELETE`] contacts per delete request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ContactSyncPlan {
+    /// Batches of contacts to add, one request per batch
+    pub add: Vec<ContactBatch>,
+    /// Batches of contacts to edit, one request per batch
+    pub edit: Vec<ContactBatch>,
+    /// Batches of contact IDs to delete, one request per batch
+    pub delete: Vec<Vec<i64>>,
+}
+
+impl ContactSyncPlan {
+    /// Computes the contact requests required to sync the current contacts to the desired contacts
+    ///
+    /// The existing watched status of edited contacts is retained & added contacts are not watched. If
+    /// a contact ID is desired more than once, the last entry is used.
+    ///
+    /// # Arguments
+    /// - `current` (&[[`CharacterContact`]]): The character's current contacts
+    /// - `desired` (&[[`DesiredContact`]]): The contacts which should exist after the sync
+    ///
+    /// # Returns
+    /// A [`ContactSyncPlan`] with the batches of contacts to add, edit, & delete
+    pub fn new(current: &[CharacterContact], desired: &[DesiredContact]) -> Self {
+        let current: HashMap<i64, &CharacterContact> = current
+            .iter()
+            .map(|contact| (contact.contact_id, contact))
+            .collect();
+        let desired: BTreeMap<i64, &DesiredContact> = desired
+            .iter()
+            .map(|contact| (contact.contact_id, contact))
+            .collect();
+
+        // Group contacts by standing, labels, & watched status as each request applies the same values
+        // to all of its contacts. Standing is keyed by its bits as f64 does not implement Ord.
+        let mut add = ContactGroups::new();
+        let mut edit = ContactGroups::new();
+
+        for (contact_id, contact) in &desired {
+            let mut label_ids = contact.label_ids.clone();
+            label_ids.sort_unstable();
+            label_ids.dedup();
+
+            let (group, watched) = match current.get(contact_id) {
+                None => (&mut add, false),
+                Some(existing) => {
+                    let mut existing_labels = existing.label_ids.clone();
+                    existing_labels.sort_unstable();
+                    existing_labels.dedup();
+
+                    if existing.standing == contact.standing && existing_labels == label_ids {
+                        continue;
+                    }
+
+                    (&mut edit, existing.is_watched)
+                }
+            };
+
+            group
+                .entry((contact.standing.to_bits(), label_ids.clone(), watched))
+                .or_insert_with(|| ContactBatch {
+                    contact_ids: Vec::new(),
+                    standing: contact.standing,
+                    label_ids,
+                    watched,
+                })
+                .contact_ids
+                .push(*contact_id);
+        }
+
+        let mut delete: Vec<i64> = current
+            .keys()
+            .filter(|contact_id| !desired.contains_key(contact_id))
+            .copied()
+            .collect();
+        delete.sort_unstable();
+
+        Self {
+            add: Self::into_batches(add),
+            edit: Self::into_batches(edit),
+            delete: delete
+                .chunks(MAX_CONTACTS_PER_DELETE)
+                .map(|chunk| chunk.to_vec())
+                .collect(),
+        }
+    }
+
+    /// Returns true if the current contacts already match the desired contacts
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.edit.is_empty() && self.delete.is_empty()
+    }
+
+    /// Returns the number of ESI requests required to execute the plan
+    pub fn request_count(&self) -> usize {
+        self.add.len() + self.edit.len() + self.delete.len()
+    }
+
+    /// Splits grouped contacts into batches of at most [`MAX_CONTACTS_PER_WRITE`] contacts
+    fn into_batches(groups: ContactGroups) -> Vec<ContactBatch> {
+        groups
+            .into_values()
+            .flat_map(|group| {
+                group
+                    .contact_ids
+                    .chunks(MAX_CONTACTS_PER_WRITE)
+                    .map(|chunk| ContactBatch {
+                        contact_ids: chunk.to_vec(),
+                        standing: group.standing,
+                        label_ids: group.label_ids.clone(),
+                        watched: group.watched,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod contact_sync_plan_tests {
+    use super::*;
+
+    /// Creates a current character contact with the provided standing & labels
+    fn contact(contact_id: i64, standing: f64, label_ids: Vec<i64>) -> CharacterContact {
+        CharacterContact {
+            contact_id,
+            contact_type: ContactType::Character,
+            is_blocked: false,
+            is_watched: false,
+            label_ids,
+            standing,
+        }
+    }
+
+    /// Creates a desired contact with the provided standing & labels
+    fn desired(contact_id: i64, standing: f64, label_ids: Vec<i64>) -> DesiredContact {
+        DesiredContact {
+            contact_id,
+            standing,
+            label_ids,
+        }
+    }
+
+    /// Tests that the plan contains only the changes required to reach the desired contacts
+    ///
+    /// # Test Setup
+    /// - Current contacts: 1 unchanged, 1 with a different standing, 1 with different labels, 1 to remove
+    /// - Desired contacts: the unchanged & changed contacts plus 2 new contacts with the same standing
+    ///
+    /// # Assertions
+    /// - Assert the 2 new contacts are added in a single batch
+    /// - Assert the changed contacts are edited in separate batches
+    /// - Assert the removed contact is deleted
+    /// - Assert the unchanged contact is not included in any request
+    #[test]
+    fn test_contact_sync_plan() {
+        let current = vec![
+            contact(1, 10.0, vec![2, 1]),
+            contact(2, 5.0, vec![]),
+            contact(3, -10.0, vec![1]),
+            contact(4, 0.0, vec![]),
+        ];
+        let desired = vec![
+            desired(1, 10.0, vec![1, 2]),
+            desired(2, 10.0, vec![]),
+            desired(3, -10.0, vec![2]),
+            desired(5, 5.0, vec![]),
+            desired(6, 5.0, vec![]),
+        ];
+
+        let plan = ContactSyncPlan::new(&current, &desired);
+
+        assert_eq!(plan.add.len(), 1);
+        assert_eq!(plan.add[0].contact_ids, vec![5, 6]);
+        assert_eq!(plan.add[0].standing, 5.0);
+
+        assert_eq!(plan.edit.len(), 2);
+        let edited: Vec<i64> = plan
+            .edit
+            .iter()
+            .flat_map(|batch| batch.contact_ids.clone())
+            .collect();
+        assert!(edited.contains(&2));
+        assert!(edited.contains(&3));
+        assert!(!edited.contains(&1));
+
+        assert_eq!(plan.delete, vec![vec![4]]);
+        assert_eq!(plan.request_count(), 4);
+    }
+
+    /// Tests that large batches are split according to ESI's per request limits
+    ///
+    /// # Test Setup
+    /// - Current contacts: 25 contacts which are not desired
+    /// - Desired contacts: 150 new contacts with the same standing
+    ///
+    /// # Assertions
+    /// - Assert adds are split into batches of 100 & 50
+    /// - Assert deletes are split into batches of 20 & 5
+    #[test]
+    fn test_contact_sync_plan_chunks() {
+        let current: Vec<CharacterContact> =
+            (1000..1025).map(|id| contact(id, 0.0, vec![])).collect();
+        let desired: Vec<DesiredContact> = (1..=150).map(|id| desired(id, 5.0, vec![])).collect();
+
+        let plan = ContactSyncPlan::new(&current, &desired);
+
+        assert_eq!(plan.add.len(), 2);
+        assert_eq!(plan.add[0].contact_ids.len(), MAX_CONTACTS_PER_WRITE);
+        assert_eq!(plan.add[1].contact_ids.len(), 50);
+
+        assert_eq!(plan.delete.len(), 2);
+        assert_eq!(plan.delete[0].len(), MAX_CONTACTS_PER_DELETE);
+        assert_eq!(plan.delete[1].len(), 5);
+    }
+
+    /// Tests that no requests are planned when contacts already match
+    ///
+    /// # Assertions
+    /// - Assert the plan is empty
+    #[test]
+    fn test_contact_sync_plan_in_sync() {
+        let current = vec![contact(1, 10.0, vec![1])];
+        let desired = vec![desired(1, 10.0, vec![1])];
+
+        let plan = ContactSyncPlan::new(&current, &desired);
+
+        assert!(plan.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod label_set_tests {
+    use super::*;
+
+    /// Creates a character contact with the provided labels
+    fn contact(contact_id: i64, label_ids: Vec<i64>) -> CharacterContact {
+        CharacterContact {
+            contact_id,
+            contact_type: ContactType::Character,
+            is_blocked: false,
+            is_watched: false,
+            label_ids,
+            standing: 0.0,
+        }
+    }
+
+    /// Creates a label set with the labels `Friends` (1), `Enemies` (2) & `Friends` (4)
+    fn label_set() -> LabelSet {
+        LabelSet::from(vec![
+            ContactLabel {
+                label_id: 4,
+                label_name: "Friends".to_string(),
+            },
+            ContactLabel {
+                label_id: 1,
+                label_name: "Friends".to_string(),
+            },
+            ContactLabel {
+                label_id: 2,
+                label_name: "Enemies".to_string(),
+            },
+        ])
+    }
+
+    /// Tests resolving label IDs & names
+    ///
+    /// # Assertions
+    /// - Assert label IDs resolve to their names & unknown IDs to `None`
+    /// - Assert a name resolves to the lowest label ID with the name
+    /// - Assert a contact's label names are resolved in order, skipping unknown label IDs
+    #[test]
+    fn test_label_set_names() {
+        let labels = label_set();
+
+        // Assert label IDs resolve to their names & unknown IDs to `None`
+        assert_eq!(labels.len(), 3);
+        assert_eq!(labels.name(2), Some("Enemies"));
+        assert_eq!(labels.name(8), None);
+
+        // Assert a name resolves to the lowest label ID with the name
+        assert_eq!(labels.id("Friends"), Some(1));
+        assert_eq!(labels.id("friends"), None);
+
+        // Assert a contact's label names are resolved in order, skipping unknown label IDs
+        assert_eq!(
+            labels.names(&contact(1, vec![2, 8, 4])),
+            vec!["Enemies", "Friends"]
+        );
+    }
+
+    /// Tests filtering contacts by label name
+    ///
+    /// # Assertions
+    /// - Assert contacts with any label of the name are returned in order
+    /// - Assert an unknown label name returns no contacts
+    #[test]
+    fn test_label_set_filter() {
+        let labels = label_set();
+        let contacts = vec![
+            contact(1, vec![1]),
+            contact(2, vec![2]),
+            contact(3, vec![2, 4]),
+            contact(4, vec![]),
+        ];
+
+        // Assert contacts with any label of the name are returned in order
+        let friends: Vec<i64> = labels
+            .filter(&contacts, "Friends")
+            .iter()
+            .map(|contact| contact.contact_id)
+            .collect();
+        assert_eq!(friends, vec![1, 3]);
+
+        // Assert an unknown label name returns no contacts
+        assert!(labels.filter(&contacts, "Neutrals").is_empty());
+    }
+}
@@ -6,6 +6,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::enums::contract::ContractType;
+use super::enums::universe::SecurityClass;
+use super::Isk;
 
 /// A public contract's information
 ///
@@ -14,9 +16,9 @@ use super::enums::contract::ContractType;
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct PublicContract {
     /// Buyout price (for Auctions only)
-    pub buyout: Option<f64>,
+    pub buyout: Option<Isk>,
     /// Collateral (for Couriers only)
-    pub collateral: Option<f64>,
+    pub collateral: Option<Isk>,
     /// The EVE Online ID of the contract
     pub contract_id: i64,
     /// The expiration date of the contract
@@ -35,9 +37,9 @@ pub struct PublicContract {
     /// Character ID for the issuer
     pub issuer_id: i64,
     /// The price of the contract (for ItemExchange & Auction)
-    pub price: Option<f64>,
+    pub price: Option<Isk>,
     /// The reward for completing the contract (for Couriers only)
-    pub reward: Option<f64>,
+    pub reward: Option<Isk>,
     /// The location ID of the contract
     /// - ESI documents this improperly, the start location is actually shown for
     /// item exchanges as well as couriers.
@@ -80,3 +82,191 @@ pub struct PublicContractItem {
     /// Type ID for the item
     pub type_id: i64,
 }
+
+/// Represents a bid placed on a public auction contract
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/ContractsPublicBidsContractIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PublicContractBid {
+    /// The amount bid, in ISK
+    pub amount: Isk,
+    /// Unique ID for the bid
+    pub bid_id: i64,
+    /// Datetime when the bid was placed
+    pub date_bid: DateTime<Utc>,
+}
+
+/// A solar system along a courier contract's route with its security status
+///
+/// Part of a [`CourierRoute`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CourierRouteSystem {
+    /// The ID of the solar system
+    pub system_id: i64,
+    /// The security status of the solar system, ranging from -1.0 to 1.0
+    pub security_status: f64,
+}
+
+impl CourierRouteSystem {
+    /// Returns `true` if the solar system is low security, displayed in-game as 0.1 to 0.4
+    ///
+    /// Security statuses are rounded for display, so systems from 0.45 upwards are high security,
+    /// see [`SecurityClass::from_security_status`].
+    pub fn is_lowsec(&self) -> bool {
+        SecurityClass::from_security_status(self.security_status) == SecurityClass::Lowsec
+    }
+
+    /// Returns `true` if the solar system is null security, displayed in-game as 0.0 or below
+    pub fn is_nullsec(&self) -> bool {
+        SecurityClass::from_security_status(self.security_status) == SecurityClass::Nullsec
+    }
+}
+
+/// The route of a courier contract annotated with the security of each solar system
+///
+/// Built by [`ContractsEndpoints::get_courier_route`](crate::endpoints::contracts::ContractsEndpoints::get_courier_route)
+/// to evaluate a courier contract's risk & pay the way freight corporations do.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CourierRoute {
+    /// The EVE Online ID of the contract
+    pub contract_id: i64,
+    /// The ID of the station or structure the items are picked up at
+    pub start_location_id: i64,
+    /// The ID of the station or structure the items are delivered to
+    pub end_location_id: i64,
+    /// The solar systems along the route in order, including the start & end solar systems
+    pub systems: Vec<CourierRouteSystem>,
+    /// The reward for completing the contract
+    pub reward: Isk,
+    /// The collateral the contractor has to put up
+    pub collateral: Isk,
+    /// Volume of the items to deliver
+    pub volume: Option<u64>,
+}
+
+impl CourierRoute {
+    /// Returns the number of jumps along the route
+    pub fn jumps(&self) -> usize {
+        self.systems.len().saturating_sub(1)
+    }
+
+    /// Returns the number of jumps into low security solar systems
+    pub fn lowsec_jumps(&self) -> usize {
+        self.systems
+            .iter()
+            .skip(1)
+            .filter(|system| system.is_lowsec())
+            .count()
+    }
+
+    /// Returns the number of jumps into null security solar systems
+    pub fn nullsec_jumps(&self) -> usize {
+        self.systems
+            .iter()
+            .skip(1)
+            .filter(|system| system.is_nullsec())
+            .count()
+    }
+
+    /// Returns each consecutive stretch of low security solar systems along the route
+    pub fn lowsec_segments(&self) -> Vec<&[CourierRouteSystem]> {
+        self.segments(CourierRouteSystem::is_lowsec)
+    }
+
+    /// Returns each consecutive stretch of null security solar systems along the route
+    pub fn nullsec_segments(&self) -> Vec<&[CourierRouteSystem]> {
+        self.segments(CourierRouteSystem::is_nullsec)
+    }
+
+    /// Returns the reward per jump, `None` if the start & end locations are in the same solar system
+    pub fn reward_per_jump(&self) -> Option<Isk> {
+        self.per_jump(self.reward)
+    }
+
+    /// Returns the collateral per jump, `None` if the start & end locations are in the same solar system
+    pub fn collateral_per_jump(&self) -> Option<Isk> {
+        self.per_jump(self.collateral)
+    }
+
+    /// Returns the consecutive stretches of solar systems matching the predicate
+    fn segments(&self, predicate: fn(&CourierRouteSystem) -> bool) -> Vec<&[CourierRouteSystem]> {
+        self.systems
+            .chunk_by(|a, b| predicate(a) == predicate(b))
+            .filter(|segment| predicate(&segment[0]))
+            .collect()
+    }
+
+    /// Divides an amount of ISK by the number of jumps along the route
+    fn per_jump(&self, amount: Isk) -> Option<Isk> {
+        match u32::try_from(self.jumps()) {
+            Ok(0) | Err(_) => None,
+            Ok(jumps) => Some(amount / Isk::from(jumps)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod courier_route_tests {
+    use super::{CourierRoute, CourierRouteSystem, Isk};
+
+    /// Creates a courier route through solar systems with the provided security statuses
+    fn mock_courier_route(security_statuses: &[f64]) -> CourierRoute {
+        CourierRoute {
+            contract_id: 1,
+            start_location_id: 60003760,
+            end_location_id: 1035466617946,
+            systems: security_statuses
+                .iter()
+                .enumerate()
+                .map(|(index, security_status)| CourierRouteSystem {
+                    system_id: 30000000 + index as i64,
+                    security_status: *security_status,
+                })
+                .collect(),
+            reward: Isk::from(10_000_000),
+            collateral: Isk::from(500_000_000),
+            volume: Some(60_000),
+        }
+    }
+
+    /// Tests the security annotations & per jump amounts of a courier route
+    ///
+    /// # Test Setup
+    /// - Create a route from high security through low & null security back into low security
+    ///
+    /// # Assertions
+    /// - Assert jumps into low & null security systems are counted using rounded security
+    /// - Assert consecutive low & null security systems are grouped into segments
+    /// - Assert the reward & collateral are divided by the number of jumps
+    #[test]
+    fn test_courier_route_annotations() {
+        let route = mock_courier_route(&[0.9, 0.45, 0.44, 0.1, 0.0, -0.3, 0.2]);
+
+        // Assert jumps into low & null security systems are counted using rounded security
+        assert_eq!(route.jumps(), 6);
+        assert_eq!(route.lowsec_jumps(), 3);
+        assert_eq!(route.nullsec_jumps(), 2);
+
+        // Assert consecutive low & null security systems are grouped into segments
+        let lowsec_segments: Vec<usize> = route
+            .lowsec_segments()
+            .iter()
+            .map(|segment| segment.len())
+            .collect();
+        assert_eq!(lowsec_segments, vec![2, 1]);
+        assert_eq!(route.nullsec_segments().len(), 1);
+        assert_eq!(route.nullsec_segments()[0][0].system_id, 30000004);
+
+        // Assert the reward & collateral are divided by the number of jumps
+        assert_eq!(
+            route.reward_per_jump(),
+            Some(Isk::from(10_000_000) / Isk::from(6))
+        );
+        assert_eq!(
+            route.collateral_per_jump(),
+            Some(Isk::from(500_000_000) / Isk::from(6))
+        );
+        assert_eq!(mock_courier_route(&[0.9]).reward_per_jump(), None);
+    }
+}
@@ -2,18 +2,33 @@
 //!
 //! Provides models related to corporation endpoints for EVE Online's ESI API.
 
-use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::esi::IconSize;
+use crate::model::asset::Asset;
 use crate::model::enums::{
-    asset::LocationFlag,
+    asset::{LocationFlag, LocationType},
     character::CharacterMedalStatus,
     corporation::{
         CorporationRole, CorporationRoleType, CorporationSecureContainerAction,
         CorporationStarbasePermission, CorporationStarbaseState, CorporationStructureServiceState,
-        CorporationStructureState, ShareholderType,
+        CorporationStructureState, ShareholderType, StarbaseSize, StructureServiceName,
     },
 };
+use crate::model::RoleRestricted;
+
+/// Type ID of the Amarr Fuel Block
+pub const AMARR_FUEL_BLOCK_TYPE_ID: i64 = 4247;
+/// Type ID of the Caldari Fuel Block
+pub const CALDARI_FUEL_BLOCK_TYPE_ID: i64 = 4051;
+/// Type ID of the Gallente Fuel Block
+pub const GALLENTE_FUEL_BLOCK_TYPE_ID: i64 = 4312;
+/// Type ID of the Minmatar Fuel Block
+pub const MINMATAR_FUEL_BLOCK_TYPE_ID: i64 = 4246;
 
 /// Represents a corporation in EVE Online
 ///
@@ -96,6 +111,118 @@ pub struct CorporationSecureContainerLog {
     pub type_id: i64,
 }
 
+/// Filter for corporation audit log secure container log entries
+///
+/// Entries must match one of the provided values of each field which has values, fields without
+/// values match every entry. The default filter matches every entry.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SecureContainerLogFilter {
+    /// IDs of the containers to include
+    pub container_ids: Vec<i64>,
+    /// IDs of the locations of the containers to include
+    pub location_ids: Vec<i64>,
+    /// Actions to include
+    pub actions: Vec<CorporationSecureContainerAction>,
+}
+
+impl SecureContainerLogFilter {
+    /// Creates a new [`SecureContainerLogFilter`] matching every entry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Includes entries of the provided container
+    pub fn container(mut self, container_id: i64) -> Self {
+        self.container_ids.push(container_id);
+        self
+    }
+
+    /// Includes entries of containers at the provided location
+    pub fn location(mut self, location_id: i64) -> Self {
+        self.location_ids.push(location_id);
+        self
+    }
+
+    /// Includes entries of the provided action
+    pub fn action(mut self, action: CorporationSecureContainerAction) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Returns `true` if the log entry matches the filter
+    pub fn matches(&self, log: &CorporationSecureContainerLog) -> bool {
+        (self.container_ids.is_empty() || self.container_ids.contains(&log.container_id))
+            && (self.location_ids.is_empty() || self.location_ids.contains(&log.location_id))
+            && (self.actions.is_empty() || self.actions.contains(&log.action))
+    }
+}
+
+/// An audit log secure container log entry with the names of the character & item type involved
+///
+/// Returned by [`CorporationEndpoints::get_corporation_alsc_audit_trail`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_alsc_audit_trail),
+/// the [`Display`](fmt::Display) implementation describes the entry in a single line, e.g.
+/// `2025-01-01 12:00:00 UTC: Bob added 5 x Tritanium to container 1000000000001`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SecureContainerAuditEntry {
+    /// The log entry
+    pub log: CorporationSecureContainerLog,
+    /// Name of the character who performed the action, `None` if it could not be resolved
+    pub character_name: Option<String>,
+    /// Name of the item type acted upon, `None` if it could not be resolved
+    pub type_name: Option<String>,
+}
+
+impl fmt::Display for SecureContainerAuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let log = &self.log;
+
+        write!(f, "{}: ", log.logged_at.format("%Y-%m-%d %H:%M:%S UTC"))?;
+        match &self.character_name {
+            Some(name) => write!(f, "{}", name)?,
+            None => write!(f, "Character {}", log.character_id)?,
+        }
+
+        let item = match &self.type_name {
+            Some(name) => format!("{} x {}", log.quantity, name),
+            None => format!("{} x type {}", log.quantity, log.type_id),
+        };
+        let container = log.container_id;
+
+        match log.action {
+            CorporationSecureContainerAction::Add => {
+                write!(f, " added {} to container {}", item, container)
+            }
+            CorporationSecureContainerAction::Lock => {
+                write!(f, " locked {} in container {}", item, container)
+            }
+            CorporationSecureContainerAction::Unlock => {
+                write!(f, " unlocked {} in container {}", item, container)
+            }
+            CorporationSecureContainerAction::Assemble => {
+                write!(f, " assembled container {}", container)
+            }
+            CorporationSecureContainerAction::Configure => {
+                write!(f, " configured container {}", container)
+            }
+            CorporationSecureContainerAction::EnterPassword => {
+                write!(f, " entered the password of container {}", container)
+            }
+            CorporationSecureContainerAction::Move => {
+                write!(f, " moved container {}", container)
+            }
+            CorporationSecureContainerAction::Repackage => {
+                write!(f, " repackaged container {}", container)
+            }
+            CorporationSecureContainerAction::SetName => {
+                write!(f, " renamed container {}", container)
+            }
+            CorporationSecureContainerAction::SetPassword => {
+                write!(f, " set the password of container {}", container)
+            }
+        }
+    }
+}
+
 /// An entry for a corporation's hangar or wallet division
 ///
 /// # Documentation
@@ -120,6 +247,41 @@ pub struct CorporationDivisions {
     pub wallet: Vec<CorporationDivisionEntry>,
 }
 
+impl CorporationDivisions {
+    /// Returns the name of each hangar division 1 through 7
+    ///
+    /// Unnamed divisions are named `Hangar Division <number>`.
+    pub fn hangar_names(&self) -> BTreeMap<i64, String> {
+        division_names(&self.hangar, "Hangar Division")
+    }
+
+    /// Returns the name of each wallet division 1 through 7, division 1 being the master wallet
+    ///
+    /// Unnamed divisions are named `Wallet Division <number>`.
+    pub fn wallet_names(&self) -> BTreeMap<i64, String> {
+        division_names(&self.wallet, "Wallet Division")
+    }
+}
+
+/// Maps divisions 1 through 7 to their names, naming unnamed divisions `<default_name> <number>`
+fn division_names(
+    entries: &[CorporationDivisionEntry],
+    default_name: &str,
+) -> BTreeMap<i64, String> {
+    (1..=7)
+        .map(|division| {
+            let name = entries
+                .iter()
+                .find(|entry| entry.division == division)
+                .and_then(|entry| entry.name.clone())
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| format!("{} {}", default_name, division));
+
+            (division, name)
+        })
+        .collect()
+}
+
 /// Entry for corporation industry facilities
 ///
 /// # Documentation
@@ -134,6 +296,99 @@ pub struct CorporationFacilities {
     pub type_id: i64,
 }
 
+/// An office rented by a corporation at a station or structure
+///
+/// Returned by [`CorporationEndpoints::get_corporation_offices`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_offices).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationOffice {
+    /// Item ID of the office, the `location_id` of items stored within the office's hangar divisions
+    pub office_id: i64,
+    /// ID of the station or structure the office is located in
+    pub location_id: i64,
+    /// The type of location ID, [`LocationType::Station`] for stations & [`LocationType::Item`] for structures
+    pub location_type: LocationType,
+    /// Name of the station the office is located in
+    ///
+    /// Only resolved for stations, structure names require an authenticated request with
+    /// docking access to the structure.
+    pub location_name: Option<String>,
+}
+
+impl CorporationOffice {
+    /// Finds the offices within a corporation's assets
+    ///
+    /// Offices are the assets with the [`LocationFlag::OfficeFolder`] location flag, location names
+    /// are left unresolved.
+    ///
+    /// # Arguments
+    /// - `assets` (`&[`[`Asset`]`]`): The corporation's assets
+    pub fn from_assets(assets: &[Asset]) -> Vec<Self> {
+        assets
+            .iter()
+            .filter(|asset| asset.location_flag == LocationFlag::OfficeFolder)
+            .map(|asset| CorporationOffice {
+                office_id: asset.item_id,
+                location_id: asset.location_id,
+                location_type: asset.location_type.clone(),
+                location_name: None,
+            })
+            .collect()
+    }
+}
+
+/// The assets stored in one of a corporation's hangar divisions across all of its offices
+///
+/// Returned by [`CorporationEndpoints::get_corporation_hangar_divisions`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_hangar_divisions).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationHangarDivision {
+    /// The hangar division (1-7)
+    pub division: i64,
+    /// Name of the division, `Hangar Division <number>` if the division has not been named
+    pub name: String,
+    /// The assets stored directly in the division, items within containers are not included
+    pub assets: Vec<Asset>,
+}
+
+impl CorporationHangarDivision {
+    /// Groups a corporation's assets by the hangar division they are stored in
+    ///
+    /// Assets which are not stored directly in a hangar division, such as items within
+    /// containers, are ignored.
+    ///
+    /// # Arguments
+    /// - `assets` (`Vec<`[`Asset`]`>`): The corporation's assets
+    /// - `divisions` (&[`CorporationDivisions`]): The corporation's divisions used to name the hangars
+    ///
+    /// # Returns
+    /// Hangar divisions 1 through 7 in order, including divisions without any assets
+    pub fn from_assets(assets: Vec<Asset>, divisions: &CorporationDivisions) -> Vec<Self> {
+        let mut hangars: Vec<Self> = divisions
+            .hangar_names()
+            .into_iter()
+            .map(|(division, name)| CorporationHangarDivision {
+                division,
+                name,
+                assets: Vec::new(),
+            })
+            .collect();
+
+        for asset in assets {
+            let Some(division) = asset.location_flag.corporation_hangar_division() else {
+                continue;
+            };
+
+            if let Some(hangar) = hangars
+                .iter_mut()
+                .find(|hangar| hangar.division == division)
+            {
+                hangar.assets.push(asset);
+            }
+        }
+
+        hangars
+    }
+}
+
 /// Icon URLs for a corporation
 ///
 /// # Documentation
@@ -152,6 +407,17 @@ pub struct CorporationIcon {
     pub px64x64: String,
 }
 
+impl CorporationIcon {
+    /// Returns the URL of the icon of the provided size
+    pub fn url(&self, size: IconSize) -> &str {
+        match size {
+            IconSize::Px64 => &self.px64x64,
+            IconSize::Px128 => &self.px128x128,
+            IconSize::Px256 => &self.px256x256,
+        }
+    }
+}
+
 /// An entry for a corporation medal
 ///
 /// This model differs from [`CorporationIssuedMedal`] in that it describes the medal itself
@@ -210,6 +476,10 @@ pub struct CorporationMemberTitles {
 
 /// An entry for a corporation member's tracking information
 ///
+/// ESI omits the location, ship & logon/logoff dates of a member when the character the access
+/// token belongs to can't view them, use [`RoleRestricted::is_complete`] to check whether they were
+/// present.
+///
 /// # Documentation
 /// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdMembertrackingGet>
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -230,6 +500,101 @@ pub struct CorporationMemberTracking {
     pub start_date: Option<DateTime<Utc>>,
 }
 
+impl RoleRestricted for CorporationMemberTracking {
+    /// Returns `true` if the member's location, ship & logon/logoff dates were provided
+    fn is_complete(&self) -> bool {
+        self.location_id.is_some()
+            && self.ship_type_id.is_some()
+            && self.logon_date.is_some()
+            && self.logoff_date.is_some()
+    }
+}
+
+impl CorporationMemberTracking {
+    /// Returns `true` if the member is online, their last logon being after their last logoff
+    pub fn is_online(&self) -> bool {
+        match (self.logon_date, self.logoff_date) {
+            (Some(logon), Some(logoff)) => logon > logoff,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Returns the most recent time the member logged on or off, `None` if neither is known
+    pub fn last_active(&self) -> Option<DateTime<Utc>> {
+        self.logon_date.max(self.logoff_date)
+    }
+
+    /// Returns the number of whole days since the member last logged on
+    ///
+    /// Days are counted as 24 hour periods from the logon time rather than calendar days, a logon
+    /// time after `now` counts as `0` days.
+    ///
+    /// # Returns
+    /// The number of days, `None` if the member's last logon date is unknown
+    pub fn days_since_last_login(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.logon_date.map(|logon| (now - logon).num_days().max(0))
+    }
+
+    /// Returns `true` if the member has not been active for longer than the threshold
+    ///
+    /// Online members are never inactive, members without a known logon or logoff date are always
+    /// inactive.
+    ///
+    /// # Arguments
+    /// - `threshold` (`TimeDelta`): Time without logging on or off after which a member is inactive
+    /// - `now` (`DateTime<Utc>`): The current time
+    pub fn is_inactive(&self, threshold: TimeDelta, now: DateTime<Utc>) -> bool {
+        if self.is_online() {
+            return false;
+        }
+
+        match self.last_active() {
+            Some(last_active) => now - last_active > threshold,
+            None => true,
+        }
+    }
+
+    /// Groups members by their current location, members without a known location are grouped under `None`
+    pub fn group_by_location(members: &[Self]) -> HashMap<Option<i64>, Vec<&Self>> {
+        Self::group_by(members, |member| member.location_id)
+    }
+
+    /// Groups members by a category of the ship they are in, such as its group ID
+    ///
+    /// Members without a known ship or whose ship type has no category are grouped under `None`.
+    ///
+    /// # Arguments
+    /// - `members` (`&[CorporationMemberTracking]`): The corporation's member tracking entries
+    /// - `category` (`Fn(i64) -> Option<K>`): Returns the category of a ship type ID, e.g. its group ID
+    ///   from the SDE's `StaticData::type_group_id` or a map of item types retrieved from ESI
+    pub fn group_by_ship_category<K, F>(
+        members: &[Self],
+        category: F,
+    ) -> HashMap<Option<K>, Vec<&Self>>
+    where
+        K: Eq + std::hash::Hash,
+        F: Fn(i64) -> Option<K>,
+    {
+        Self::group_by(members, |member| member.ship_type_id.and_then(&category))
+    }
+
+    /// Groups members by the key returned for each member
+    fn group_by<K, F>(members: &[Self], key: F) -> HashMap<K, Vec<&Self>>
+    where
+        K: Eq + std::hash::Hash,
+        F: Fn(&Self) -> K,
+    {
+        let mut groups: HashMap<K, Vec<&Self>> = HashMap::new();
+
+        for member in members {
+            groups.entry(key(member)).or_default().push(member);
+        }
+
+        groups
+    }
+}
+
 /// An entry for a corporation member's assigned roles
 ///
 /// # Documentation
@@ -276,6 +641,188 @@ pub struct CorporationMemberRolesHistory {
     pub role_type: CorporationRoleType,
 }
 
+/// A change to a corporation member's roles or titles between 2 snapshots
+///
+/// Produced by [`CorporationMemberAccessSnapshot::diff`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CorporationMemberAccessChange {
+    /// Member gained a role
+    RoleGranted {
+        /// ID of the character who gained the role
+        character_id: i64,
+        /// The role which was gained
+        role: CorporationRole,
+        /// The location of the role
+        role_type: CorporationRoleType,
+    },
+    /// Member lost a role
+    RoleRevoked {
+        /// ID of the character who lost the role
+        character_id: i64,
+        /// The role which was lost
+        role: CorporationRole,
+        /// The location of the role
+        role_type: CorporationRoleType,
+    },
+    /// Member gained a title
+    TitleGranted {
+        /// ID of the character who gained the title
+        character_id: i64,
+        /// ID of the title which was gained
+        title_id: i64,
+    },
+    /// Member lost a title
+    TitleRevoked {
+        /// ID of the character who lost the title
+        character_id: i64,
+        /// ID of the title which was lost
+        title_id: i64,
+    },
+}
+
+/// Snapshot of the roles & titles of each member of a corporation
+///
+/// Successive snapshots, such as those retrieved by each sync of
+/// [`CorporationEndpoints::get_member_access_snapshot`](crate::endpoints::corporation::CorporationEndpoints::get_member_access_snapshot),
+/// can be compared with [`Self::diff`] to produce an audit log of role & title changes. Unlike
+/// [`CorporationMemberRolesHistory`] which only covers the last month, this also covers title changes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct CorporationMemberAccessSnapshot {
+    /// Roles of each member of the corporation
+    pub roles: Vec<CorporationMemberRoles>,
+    /// Titles of each member of the corporation
+    pub titles: Vec<CorporationMemberTitles>,
+}
+
+impl CorporationMemberRoles {
+    /// Returns the roles of the provided role type
+    ///
+    /// # Arguments
+    /// - `role_type` (&[`CorporationRoleType`]): The location of the roles to return
+    pub fn roles_of_type(&self, role_type: &CorporationRoleType) -> &[CorporationRole] {
+        match role_type {
+            CorporationRoleType::GrantableRoles => &self.grantable_roles,
+            CorporationRoleType::GrantableRolesAtBase => &self.grantable_roles_at_base,
+            CorporationRoleType::GrantableRolesAtHq => &self.grantable_roles_at_hq,
+            CorporationRoleType::GrantableRolesAtOther => &self.grantable_roles_at_other,
+            CorporationRoleType::Roles => &self.roles,
+            CorporationRoleType::RolesAtBase => &self.roles_at_base,
+            CorporationRoleType::RolseAtHq => &self.roles_at_hq,
+            CorporationRoleType::RolesAtOther => &self.roles_at_other,
+        }
+    }
+}
+
+impl CorporationMemberAccessSnapshot {
+    /// Compares the snapshot to a newer snapshot, returning the role & title changes of each member
+    ///
+    /// Members only present in one of the snapshots are compared against no roles or titles, as such
+    /// members who joined are reported as gaining their roles & titles while members who left are
+    /// reported as losing them.
+    ///
+    /// # Arguments
+    /// - `newer` (&[`CorporationMemberAccessSnapshot`]): The more recent snapshot to compare against
+    ///
+    /// # Returns
+    /// - `Vec<CorporationMemberAccessChange>`: Changes ordered by character ID with role changes before
+    ///   title changes
+    pub fn diff(
+        &self,
+        newer: &CorporationMemberAccessSnapshot,
+    ) -> Vec<CorporationMemberAccessChange> {
+        const ROLE_TYPES: [CorporationRoleType; 8] = [
+            CorporationRoleType::Roles,
+            CorporationRoleType::RolseAtHq,
+            CorporationRoleType::RolesAtBase,
+            CorporationRoleType::RolesAtOther,
+            CorporationRoleType::GrantableRoles,
+            CorporationRoleType::GrantableRolesAtHq,
+            CorporationRoleType::GrantableRolesAtBase,
+            CorporationRoleType::GrantableRolesAtOther,
+        ];
+
+        let mut character_ids: Vec<i64> = self
+            .roles
+            .iter()
+            .chain(&newer.roles)
+            .map(|member| member.character_id)
+            .chain(
+                self.titles
+                    .iter()
+                    .chain(&newer.titles)
+                    .map(|member| member.character_id),
+            )
+            .collect();
+        character_ids.sort_unstable();
+        character_ids.dedup();
+
+        let mut changes = Vec::new();
+
+        for character_id in character_ids {
+            let old_roles = self.roles.iter().find(|m| m.character_id == character_id);
+            let new_roles = newer.roles.iter().find(|m| m.character_id == character_id);
+
+            for role_type in ROLE_TYPES {
+                let old = old_roles
+                    .map(|m| m.roles_of_type(&role_type))
+                    .unwrap_or_default();
+                let new = new_roles
+                    .map(|m| m.roles_of_type(&role_type))
+                    .unwrap_or_default();
+
+                changes.extend(new.iter().filter(|role| !old.contains(role)).map(|role| {
+                    CorporationMemberAccessChange::RoleGranted {
+                        character_id,
+                        role: role.clone(),
+                        role_type: role_type.clone(),
+                    }
+                }));
+                changes.extend(old.iter().filter(|role| !new.contains(role)).map(|role| {
+                    CorporationMemberAccessChange::RoleRevoked {
+                        character_id,
+                        role: role.clone(),
+                        role_type: role_type.clone(),
+                    }
+                }));
+            }
+
+            let old_titles = self
+                .titles
+                .iter()
+                .find(|m| m.character_id == character_id)
+                .map(|m| m.titles.as_slice())
+                .unwrap_or_default();
+            let new_titles = newer
+                .titles
+                .iter()
+                .find(|m| m.character_id == character_id)
+                .map(|m| m.titles.as_slice())
+                .unwrap_or_default();
+
+            changes.extend(
+                new_titles
+                    .iter()
+                    .filter(|title_id| !old_titles.contains(title_id))
+                    .map(|&title_id| CorporationMemberAccessChange::TitleGranted {
+                        character_id,
+                        title_id,
+                    }),
+            );
+            changes.extend(
+                old_titles
+                    .iter()
+                    .filter(|title_id| !new_titles.contains(title_id))
+                    .map(|&title_id| CorporationMemberAccessChange::TitleRevoked {
+                        character_id,
+                        title_id,
+                    }),
+            );
+        }
+
+        changes
+    }
+}
+
 /// An entry for a corporation shareholder
 ///
 /// # Documentation
@@ -290,6 +837,127 @@ pub struct CorporationShareholder {
     pub shareholder_type: ShareholderType,
 }
 
+/// Rounding rule applied when splitting a dividend between shareholders
+///
+/// Payouts are calculated in hundredths of an ISK (0.01 ISK), the smallest amount which can be
+/// transferred in game.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DividendRounding {
+    /// Each payout is rounded down to 0.01 ISK, with the remainder left undistributed
+    #[default]
+    Floor,
+    /// Each payout is rounded down to 0.01 ISK, with the remainder distributed 0.01 ISK at a time to the
+    /// shareholders with the largest rounding remainders so the full amount is paid out
+    LargestRemainder,
+}
+
+/// The dividend payout for a single shareholder
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DividendPayout {
+    /// ID of the character or corporation who holds the shares
+    pub shareholder_id: i64,
+    /// Represents whether the shares are held by a character or corporation
+    pub shareholder_type: ShareholderType,
+    /// Amount of shares held by the shareholder
+    pub share_count: i64,
+    /// Amount of ISK to pay the shareholder
+    pub amount: f64,
+}
+
+/// Per-shareholder breakdown of a dividend paid out of a corporation's wallet
+///
+/// Created from a corporation's shareholders using [`DividendReport::new`] or fetched for a
+/// corporation using [`CorporationEndpoints::calculate_dividends`](crate::endpoints::corporation::CorporationEndpoints::calculate_dividends).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DividendReport {
+    /// Amount of ISK requested to be paid out as dividends
+    pub amount: f64,
+    /// Total amount of shares held across all shareholders
+    pub total_shares: i64,
+    /// Amount of ISK paid out across all shareholders after rounding
+    pub distributed: f64,
+    /// Amount of ISK left undistributed after rounding
+    pub undistributed: f64,
+    /// Rounding rule used to calculate the payouts
+    pub rounding: DividendRounding,
+    /// Payouts for each shareholder in the order the shareholders were provided
+    pub payouts: Vec<DividendPayout>,
+}
+
+impl DividendReport {
+    /// Splits a dividend between shareholders proportionally to the shares they hold
+    ///
+    /// Shareholders without any shares are excluded from the payouts. If no shares are held the
+    /// full amount is left undistributed.
+    ///
+    /// # Arguments
+    /// - `shareholders` (&[[`CorporationShareholder`]]): The shareholders of the corporation
+    /// - `amount` (`f64`): Amount of ISK to pay out, rounded to the nearest 0.01 ISK
+    /// - `rounding` ([`DividendRounding`]): Rounding rule applied to each payout
+    ///
+    /// # Returns
+    /// - [`DividendReport`]: Breakdown of the payout for each shareholder
+    pub fn new(
+        shareholders: &[CorporationShareholder],
+        amount: f64,
+        rounding: DividendRounding,
+    ) -> Self {
+        let total_cents = (amount.max(0.0) * 100.0).round() as i128;
+        let shareholders: Vec<&CorporationShareholder> = shareholders
+            .iter()
+            .filter(|shareholder| shareholder.share_count > 0)
+            .collect();
+        let total_shares: i64 = shareholders.iter().map(|s| s.share_count).sum();
+
+        // Calculate each payout in hundredths of an ISK alongside its rounding remainder
+        let mut payouts: Vec<(i128, i128)> = shareholders
+            .iter()
+            .map(|shareholder| {
+                let exact = total_cents * shareholder.share_count as i128;
+                (exact / total_shares as i128, exact % total_shares as i128)
+            })
+            .collect();
+
+        if rounding == DividendRounding::LargestRemainder {
+            let leftover = total_cents - payouts.iter().map(|(cents, _)| cents).sum::<i128>();
+
+            // Ties are broken by shareholder ID so the result is deterministic
+            let mut order: Vec<usize> = (0..payouts.len()).collect();
+            order.sort_by(|&a, &b| {
+                payouts[b].1.cmp(&payouts[a].1).then_with(|| {
+                    shareholders[a]
+                        .shareholder_id
+                        .cmp(&shareholders[b].shareholder_id)
+                })
+            });
+
+            for &index in order.iter().take(leftover as usize) {
+                payouts[index].0 += 1;
+            }
+        }
+
+        let distributed_cents: i128 = payouts.iter().map(|(cents, _)| cents).sum();
+
+        Self {
+            amount: total_cents as f64 / 100.0,
+            total_shares,
+            distributed: distributed_cents as f64 / 100.0,
+            undistributed: (total_cents - distributed_cents) as f64 / 100.0,
+            rounding,
+            payouts: shareholders
+                .into_iter()
+                .zip(payouts)
+                .map(|(shareholder, (cents, _))| DividendPayout {
+                    shareholder_id: shareholder.shareholder_id,
+                    shareholder_type: shareholder.shareholder_type.clone(),
+                    share_count: shareholder.share_count,
+                    amount: cents as f64 / 100.0,
+                })
+                .collect(),
+        }
+    }
+}
+
 /// Information regarding a starbase (POS) owned by a corporation
 ///
 /// # Documentation
@@ -333,6 +1001,10 @@ pub struct CorporationStarbaseFuel {
 
 /// Information regarding a starbase's (POS) details owned by a corporation
 ///
+/// ESI omits the fuel bay contents when the character the access token belongs to isn't permitted
+/// to view the fuel bay by [`Self::fuel_bay_view`], use [`RoleRestricted::is_complete`] to check
+/// whether they were present.
+///
 /// # ESI Documentation
 /// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdStarbasesStarbaseIdGet>
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -356,7 +1028,9 @@ pub struct CorporationStarbaseDetails {
     /// Enum indicating who has permission to view POS fuel bay
     pub fuel_bay_view: CorporationStarbasePermission,
     /// List of entries containing type_id of fuel and quantity stored within the POS
-    pub fuels: Vec<CorporationStarbaseFuel>,
+    ///
+    /// `None` if the character isn't permitted to view the fuel bay
+    pub fuels: Option<Vec<CorporationStarbaseFuel>>,
     /// Enum indicating who has permission to offline POS and its structures
     pub offline: CorporationStarbasePermission,
     /// Enum indicating who has permission to online POS and its structures
@@ -367,18 +1041,221 @@ pub struct CorporationStarbaseDetails {
     pub use_alliance_standings: bool,
 }
 
+/// Hourly fuel consumption of a starbase (POS) control tower
+///
+/// Used with [`CorporationStarbaseDetails::fuel_remaining`] to project when a starbase will run out
+/// of fuel. Consumption varies by control tower type, racial & faction towers can use
+/// [`StarbaseFuelConsumption::control_tower`] with [`CorporationStarbase::type_id`] while sovereignty
+/// bonuses or rates sourced from the control tower's dogma attributes can be configured with
+/// [`StarbaseFuelConsumption::with_rate`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StarbaseFuelConsumption {
+    /// Quantity of each fuel type ID consumed per hour
+    rates: HashMap<i64, i64>,
+}
+
+impl StarbaseFuelConsumption {
+    /// Creates a new [`StarbaseFuelConsumption`] with no fuel rates configured
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a [`StarbaseFuelConsumption`] with the standard fuel block rate for a control tower size
+    ///
+    /// # Arguments
+    /// - `fuel_block_type_id` (`i64`): Type ID of the racial fuel block consumed by the tower,
+    ///   e.g. [`AMARR_FUEL_BLOCK_TYPE_ID`]
+    /// - `size` ([`StarbaseSize`]): Size of the control tower
+    ///
+    /// # Returns
+    /// - [`StarbaseFuelConsumption`]: Instance consuming 10, 20, or 40 fuel blocks per hour for a small,
+    ///   medium, or large tower respectively
+    pub fn fuel_blocks(fuel_block_type_id: i64, size: StarbaseSize) -> Self {
+        let quantity_per_hour = match size {
+            StarbaseSize::Small => 10,
+            StarbaseSize::Medium => 20,
+            StarbaseSize::Large => 40,
+        };
+
+        Self::new().with_rate(fuel_block_type_id, quantity_per_hour)
+    }
+
+    /// Creates a [`StarbaseFuelConsumption`] with the fuel block rate of a control tower type
+    ///
+    /// # Arguments
+    /// - `control_tower_type_id` (`i64`): Type ID of the control tower, e.g. [`CorporationStarbase::type_id`]
+    ///
+    /// # Returns
+    /// - `Some(`[`StarbaseFuelConsumption`]`)`: Instance consuming the tower's fuel blocks per hour
+    /// - `None`: If the type ID is not a known racial or faction control tower
+    pub fn control_tower(control_tower_type_id: i64) -> Option<Self> {
+        let (fuel_block_type_id, quantity_per_hour) =
+            Self::control_tower_fuel_blocks(control_tower_type_id)?;
+
+        Some(Self::new().with_rate(fuel_block_type_id, quantity_per_hour))
+    }
+
+    /// Returns the fuel block type & hourly fuel block rate of a control tower type
+    ///
+    /// Faction towers consume fuel blocks of their associated race, with the first tier
+    /// (Angel, Blood, Guristas, Sansha, Serpentis) using 10% and the second tier (Dark Blood,
+    /// Domination, Dread Guristas, Shadow, True Sansha) using 20% fewer blocks than racial towers.
+    ///
+    /// # Arguments
+    /// - `control_tower_type_id` (`i64`): Type ID of the control tower
+    ///
+    /// # Returns
+    /// - `Some((i64, i64))`: Type ID of the fuel block & quantity consumed per hour
+    /// - `None`: If the type ID is not a known racial or faction control tower
+    pub fn control_tower_fuel_blocks(control_tower_type_id: i64) -> Option<(i64, i64)> {
+        let fuel_blocks = match control_tower_type_id {
+            // Amarr, Blood & Dark Blood, Sansha & True Sansha
+            12235 => (AMARR_FUEL_BLOCK_TYPE_ID, 40),
+            20059 => (AMARR_FUEL_BLOCK_TYPE_ID, 20),
+            20060 => (AMARR_FUEL_BLOCK_TYPE_ID, 10),
+            27530 | 27782 => (AMARR_FUEL_BLOCK_TYPE_ID, 36),
+            27589 | 27784 => (AMARR_FUEL_BLOCK_TYPE_ID, 18),
+            27592 | 27780 => (AMARR_FUEL_BLOCK_TYPE_ID, 9),
+            27532 | 27786 => (AMARR_FUEL_BLOCK_TYPE_ID, 32),
+            27591 | 27788 => (AMARR_FUEL_BLOCK_TYPE_ID, 16),
+            27594 | 27790 => (AMARR_FUEL_BLOCK_TYPE_ID, 8),
+            // Caldari, Guristas & Dread Guristas
+            16213 => (CALDARI_FUEL_BLOCK_TYPE_ID, 40),
+            20061 => (CALDARI_FUEL_BLOCK_TYPE_ID, 20),
+            20062 => (CALDARI_FUEL_BLOCK_TYPE_ID, 10),
+            27533 => (CALDARI_FUEL_BLOCK_TYPE_ID, 36),
+            27595 => (CALDARI_FUEL_BLOCK_TYPE_ID, 18),
+            27598 => (CALDARI_FUEL_BLOCK_TYPE_ID, 9),
+            27535 => (CALDARI_FUEL_BLOCK_TYPE_ID, 32),
+            27597 => (CALDARI_FUEL_BLOCK_TYPE_ID, 16),
+            27600 => (CALDARI_FUEL_BLOCK_TYPE_ID, 8),
+            // Gallente, Serpentis & Shadow
+            12236 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 40),
+            20063 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 20),
+            20064 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 10),
+            27536 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 36),
+            27601 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 18),
+            27604 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 9),
+            27538 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 32),
+            27603 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 16),
+            27606 => (GALLENTE_FUEL_BLOCK_TYPE_ID, 8),
+            // Minmatar, Angel & Domination
+            16214 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 40),
+            20065 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 20),
+            20066 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 10),
+            27539 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 36),
+            27607 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 18),
+            27610 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 9),
+            27540 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 32),
+            27609 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 16),
+            27612 => (MINMATAR_FUEL_BLOCK_TYPE_ID, 8),
+            _ => return None,
+        };
+
+        Some(fuel_blocks)
+    }
+
+    /// Sets the quantity of a fuel type consumed per hour
+    ///
+    /// # Arguments
+    /// - `type_id` (`i64`): Type ID of the fuel
+    /// - `quantity_per_hour` (`i64`): Quantity of the fuel consumed each hour
+    ///
+    /// # Returns
+    /// - [`StarbaseFuelConsumption`]: Instance with the fuel rate set
+    pub fn with_rate(mut self, type_id: i64, quantity_per_hour: i64) -> Self {
+        self.rates.insert(type_id, quantity_per_hour);
+        self
+    }
+
+    /// Returns the quantity of a fuel type consumed per hour if configured
+    pub fn rate(&self, type_id: i64) -> Option<i64> {
+        self.rates.get(&type_id).copied()
+    }
+}
+
+impl RoleRestricted for CorporationStarbaseDetails {
+    /// Returns `true` if the fuel bay contents were provided
+    fn is_complete(&self) -> bool {
+        self.fuels.is_some()
+    }
+}
+
+impl CorporationStarbaseDetails {
+    /// Estimates the time remaining until the starbase (POS) runs out of fuel
+    ///
+    /// A starbase consumes fuel at the start of each hour and goes offline once any fuel it
+    /// requires runs out, as such the estimate is the number of full hours the scarcest fuel lasts.
+    /// Fuels without a configured rate, such as strontium used only while reinforced, are ignored.
+    ///
+    /// # Arguments
+    /// - `consumption` (&[`StarbaseFuelConsumption`]): Hourly fuel consumption of the control tower
+    ///
+    /// # Returns
+    /// - `Some(TimeDelta)`: Estimated time remaining until fuel exhaustion, zero if a required fuel is missing
+    /// - `None`: If no fuel rates above zero are configured or the fuel bay contents weren't provided
+    pub fn fuel_remaining(&self, consumption: &StarbaseFuelConsumption) -> Option<TimeDelta> {
+        let fuels = self.fuels.as_ref()?;
+
+        consumption
+            .rates
+            .iter()
+            .filter(|(_, rate)| **rate > 0)
+            .map(|(type_id, rate)| {
+                // Sum quantity of the fuel type in the fuel bay
+                let quantity: i64 = fuels
+                    .iter()
+                    .filter(|fuel| fuel.type_id == *type_id)
+                    .map(|fuel| fuel.quantity)
+                    .sum();
+
+                quantity / rate
+            })
+            .min()
+            .map(TimeDelta::hours)
+    }
+
+    /// Estimates when the starbase (POS) will run out of fuel
+    ///
+    /// See [`Self::fuel_remaining`] for how the estimate is calculated.
+    ///
+    /// # Arguments
+    /// - `consumption` (&[`StarbaseFuelConsumption`]): Hourly fuel consumption of the control tower
+    /// - `from` (`DateTime<Utc>`): When the fuel bay contents were retrieved, typically the
+    ///   `Last-Modified` header of the response
+    ///
+    /// # Returns
+    /// - `Some(DateTime<Utc>)`: Estimated time of fuel exhaustion
+    /// - `None`: If no fuel rates above zero are configured
+    pub fn fuel_expires_at(
+        &self,
+        consumption: &StarbaseFuelConsumption,
+        from: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        self.fuel_remaining(consumption)
+            .map(|remaining| from + remaining)
+    }
+}
+
 /// An entry for a corporation's Upwell structure services
 ///
 /// # ESI Documentation
 /// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdStructuresGet>
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct CorporationStructureService {
+pub struct StructureService {
     /// The name of the structure service
-    pub name: String,
+    pub name: StructureServiceName,
     /// Enum representing the state of the structure service
     pub state: CorporationStructureServiceState,
 }
 
+impl StructureService {
+    /// Returns `true` if the service is online & usable
+    pub fn is_online(&self) -> bool {
+        self.state == CorporationStructureServiceState::Online
+    }
+}
+
 /// Details regarding a corporation's Upwell structure
 ///
 /// # ESI Documentation
@@ -401,7 +1278,7 @@ pub struct CorporationStructure {
     /// reinforcement and become vulnerable to attack for an armor or hull timer.
     pub reinforce_hour: Option<i64>,
     /// A list of entries for structure services
-    pub services: Vec<CorporationStructureService>,
+    pub services: Vec<StructureService>,
     /// An enum representing the current state of the structure
     pub state: CorporationStructureState,
     /// The timestamp when the structure will move to its next state
@@ -418,6 +1295,170 @@ pub struct CorporationStructure {
     pub unanchors_at: Option<DateTime<Utc>>,
 }
 
+impl CorporationStructure {
+    /// Returns `true` if the structure has the provided service online
+    ///
+    /// Services which are offline or being cleaned up are not counted.
+    ///
+    /// # Arguments
+    /// - `name` (&[`StructureServiceName`]): The service to look for
+    pub fn has_service(&self, name: &StructureServiceName) -> bool {
+        self.services
+            .iter()
+            .any(|service| &service.name == name && service.is_online())
+    }
+
+    /// Returns `true` if the structure has a market hub online
+    pub fn has_market(&self) -> bool {
+        self.has_service(&StructureServiceName::Market)
+    }
+
+    /// Returns `true` if the structure has a clone bay online
+    pub fn has_clone_bay(&self) -> bool {
+        self.has_service(&StructureServiceName::CloneBay)
+    }
+
+    /// Returns `true` if the structure has a reprocessing service online
+    pub fn has_reprocessing(&self) -> bool {
+        self.has_service(&StructureServiceName::Reprocessing)
+    }
+
+    /// Returns `true` if the structure has any manufacturing service online
+    pub fn has_manufacturing(&self) -> bool {
+        self.online_services().any(|name| {
+            matches!(
+                name,
+                StructureServiceName::ManufacturingStandard
+                    | StructureServiceName::ManufacturingCapitals
+                    | StructureServiceName::ManufacturingSuperCapitals
+            )
+        })
+    }
+
+    /// Returns the names of the structure's online services
+    pub fn online_services(&self) -> impl Iterator<Item = &StructureServiceName> {
+        self.services
+            .iter()
+            .filter(|service| service.is_online())
+            .map(|service| &service.name)
+    }
+}
+
+/// Stage of an Upwell structure's reinforcement cycle
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureTimerStage {
+    /// The armor timer following depletion of the structure's shield
+    Armor,
+    /// The final hull timer following depletion of the structure's armor
+    Hull,
+}
+
+/// Normalized state of an Upwell structure combining its state & timer fields
+///
+/// Created from a [`CorporationStructure`] using [`StructureState::from_structure`], grouping the
+/// [`CorporationStructureState`] variants by what they mean for the structure's owners alongside
+/// the relevant timer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum StructureState {
+    /// Structure is anchoring & has not yet had its quantum core installed
+    Anchoring {
+        /// When anchoring completes, if known
+        ends: Option<DateTime<Utc>>,
+    },
+    /// Structure is onlining after having its quantum core installed
+    Onlining {
+        /// When onlining completes, if known
+        ends: Option<DateTime<Utc>>,
+    },
+    /// Structure is online with its shield vulnerable during its reinforcement hour
+    Online,
+    /// Structure is reinforced & invulnerable until the timer ends
+    Reinforced {
+        /// The upcoming timer
+        stage: StructureTimerStage,
+        /// When the structure exits reinforcement & becomes vulnerable, if known
+        ends: Option<DateTime<Utc>>,
+    },
+    /// Structure's reinforcement timer has elapsed & it is currently vulnerable
+    Vulnerable {
+        /// The timer which has elapsed
+        stage: StructureTimerStage,
+        /// When the vulnerability window ends, if known
+        ends: Option<DateTime<Utc>>,
+    },
+    /// Structure is scheduled to unanchor
+    Unanchoring {
+        /// When the structure will unanchor
+        at: DateTime<Utc>,
+    },
+    /// Structure has been unanchored
+    Unanchored,
+    /// Structure state is unknown
+    Unknown,
+}
+
+impl StructureState {
+    /// Normalizes the state & timer fields of a [`CorporationStructure`] into a [`StructureState`]
+    ///
+    /// Structures scheduled to unanchor while otherwise online are represented as
+    /// [`StructureState::Unanchoring`], reinforcement takes precedence over unanchoring.
+    ///
+    /// # Arguments
+    /// - `structure` (&[`CorporationStructure`]): The structure to normalize the state of
+    ///
+    /// # Returns
+    /// - [`StructureState`]: The normalized state of the structure
+    pub fn from_structure(structure: &CorporationStructure) -> Self {
+        let ends = structure.state_timer_end;
+
+        match structure.state {
+            CorporationStructureState::Anchoring
+            | CorporationStructureState::AnchorVulnerable
+            | CorporationStructureState::DeployVulnerable => StructureState::Anchoring { ends },
+            CorporationStructureState::OnliningVulnerable => StructureState::Onlining { ends },
+            CorporationStructureState::ArmorReinforce => StructureState::Reinforced {
+                stage: StructureTimerStage::Armor,
+                ends,
+            },
+            CorporationStructureState::HullReinforce => StructureState::Reinforced {
+                stage: StructureTimerStage::Hull,
+                ends,
+            },
+            CorporationStructureState::ArmorVulnerable => StructureState::Vulnerable {
+                stage: StructureTimerStage::Armor,
+                ends,
+            },
+            CorporationStructureState::HullVulnerable => StructureState::Vulnerable {
+                stage: StructureTimerStage::Hull,
+                ends,
+            },
+            CorporationStructureState::ShieldVulnerable
+            | CorporationStructureState::FittingInvulnerable
+            | CorporationStructureState::OnlineDeprecated => match structure.unanchors_at {
+                Some(at) => StructureState::Unanchoring { at },
+                None => StructureState::Online,
+            },
+            CorporationStructureState::Unanchored => StructureState::Unanchored,
+            CorporationStructureState::Unknown => StructureState::Unknown,
+        }
+    }
+}
+
+/// Entry of a corporation structure dashboard with resolved names & normalized state
+///
+/// Returned by [`CorporationEndpoints::get_structure_dashboard`](crate::endpoints::corporation::CorporationEndpoints::get_structure_dashboard).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationStructureDashboardEntry {
+    /// The structure as returned by ESI
+    pub structure: CorporationStructure,
+    /// Name of the structure's type, e.g. `Astrahus`
+    pub type_name: Option<String>,
+    /// Name of the solar system the structure is located in
+    pub system_name: Option<String>,
+    /// Normalized state of the structure
+    pub state: StructureState,
+}
+
 /// An entry for a corporation's titles and its respective roles
 ///
 /// # ESI Documentation
@@ -445,3 +1486,761 @@ pub struct CorporationTitle {
     /// ID of the title
     pub title_id: i64,
 }
+
+impl CorporationTitle {
+    /// Returns the title's name with in-game markup such as `<color=...>` tags removed
+    ///
+    /// See [`strip_markup`](crate::model::markup::strip_markup)
+    pub fn plain_name(&self) -> String {
+        crate::model::markup::strip_markup(&self.name)
+    }
+}
+
+#[cfg(test)]
+mod member_tracking_tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use super::CorporationMemberTracking;
+    use crate::model::RoleRestricted;
+
+    /// Parses an RFC 3339 timestamp with any offset into UTC
+    fn utc(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp).unwrap().to_utc()
+    }
+
+    /// Creates a member tracking entry with the provided logon & logoff dates
+    fn mock_member(
+        character_id: i64,
+        logon_date: Option<&str>,
+        logoff_date: Option<&str>,
+    ) -> CorporationMemberTracking {
+        CorporationMemberTracking {
+            base_id: None,
+            character_id,
+            location_id: Some(60003760),
+            logoff_date: logoff_date.map(utc),
+            logon_date: logon_date.map(utc),
+            ship_type_id: Some(587),
+            start_date: None,
+        }
+    }
+
+    /// Tests days since last login count full 24 hour periods regardless of the timestamp's offset
+    ///
+    /// # Test Setup
+    /// - Create members who logged on just under & exactly 1 day ago using offset timestamps
+    /// - Create a member without a logon date & a member with a logon date after now
+    ///
+    /// # Assertions
+    /// - Assert days are counted in 24 hour periods from the UTC logon time
+    /// - Assert an unknown logon date returns `None` & a future logon date counts as 0 days
+    #[test]
+    fn test_days_since_last_login() {
+        let now = utc("2026-01-02T12:00:00Z");
+
+        // 2026-01-01T12:00:01Z, just under 1 day before now
+        let recent = mock_member(1, Some("2026-01-01T14:00:01+02:00"), None);
+        // 2026-01-01T12:00:00Z, exactly 1 day before now
+        let yesterday = mock_member(2, Some("2026-01-01T07:00:00-05:00"), None);
+
+        // Assert days are counted in 24 hour periods from the UTC logon time
+        assert_eq!(recent.days_since_last_login(now), Some(0));
+        assert_eq!(yesterday.days_since_last_login(now), Some(1));
+
+        // Assert an unknown logon date returns `None` & a future logon date counts as 0 days
+        assert_eq!(mock_member(3, None, None).days_since_last_login(now), None);
+        assert_eq!(
+            mock_member(4, Some("2026-01-03T00:00:00Z"), None).days_since_last_login(now),
+            Some(0)
+        );
+    }
+
+    /// Tests members are inactive once their last logon or logoff is older than the threshold
+    ///
+    /// # Test Setup
+    /// - Create an online member, a member who logged off recently, a member who logged off long ago
+    ///   & a member without logon or logoff dates
+    ///
+    /// # Assertions
+    /// - Assert online members are never inactive even if they logged on long ago
+    /// - Assert the most recent of the logon & logoff dates is compared to the threshold
+    /// - Assert members without logon or logoff dates are inactive
+    #[test]
+    fn test_is_inactive() {
+        let now = utc("2026-03-01T00:00:00Z");
+        let threshold = TimeDelta::days(30);
+
+        let online = mock_member(
+            1,
+            Some("2025-12-01T00:00:00Z"),
+            Some("2025-11-30T00:00:00Z"),
+        );
+        let recent = mock_member(
+            2,
+            Some("2026-01-01T00:00:00Z"),
+            Some("2026-02-15T00:00:00Z"),
+        );
+        let stale = mock_member(
+            3,
+            Some("2025-12-01T00:00:00Z"),
+            Some("2025-12-02T00:00:00Z"),
+        );
+
+        // Assert online members are never inactive even if they logged on long ago
+        assert!(online.is_online());
+        assert!(!online.is_inactive(threshold, now));
+
+        // Assert the most recent of the logon & logoff dates is compared to the threshold
+        assert!(!recent.is_inactive(threshold, now));
+        assert!(stale.is_inactive(threshold, now));
+
+        // Assert members without logon or logoff dates are inactive
+        assert!(mock_member(4, None, None).is_inactive(threshold, now));
+    }
+
+    /// Tests members are only complete with their location, ship & logon/logoff dates
+    ///
+    /// # Assertions
+    /// - Assert a member with every role-restricted field is complete
+    /// - Assert a member without a logoff date or ship is incomplete
+    /// - Assert a list of members is only complete if every member is
+    #[test]
+    fn test_is_complete() {
+        let complete = mock_member(
+            1,
+            Some("2026-01-01T00:00:00Z"),
+            Some("2026-01-02T00:00:00Z"),
+        );
+        let mut without_ship = complete.clone();
+        without_ship.ship_type_id = None;
+
+        // Assert a member with every role-restricted field is complete
+        assert!(complete.is_complete());
+
+        // Assert a member without a logoff date or ship is incomplete
+        assert!(!mock_member(2, Some("2026-01-01T00:00:00Z"), None).is_complete());
+        assert!(!without_ship.is_complete());
+
+        // Assert a list of members is only complete if every member is
+        assert!([complete.clone()].is_complete());
+        assert!(![complete, without_ship].is_complete());
+    }
+
+    /// Tests members are grouped by location & ship category
+    ///
+    /// # Test Setup
+    /// - Create members in 2 locations, one without a location & one without a ship
+    ///
+    /// # Assertions
+    /// - Assert members are grouped by location with unknown locations under `None`
+    /// - Assert members are grouped by the category of their ship with unknown ships under `None`
+    #[test]
+    fn test_member_grouping() {
+        let mut members: Vec<CorporationMemberTracking> =
+            (1..=4).map(|id| mock_member(id, None, None)).collect();
+        members[1].location_id = Some(1035466617946);
+        members[2].location_id = None;
+        members[3].ship_type_id = None;
+
+        // Assert members are grouped by location with unknown locations under `None`
+        let by_location = CorporationMemberTracking::group_by_location(&members);
+        assert_eq!(by_location[&Some(60003760)].len(), 2);
+        assert_eq!(by_location[&Some(1035466617946)][0].character_id, 2);
+        assert_eq!(by_location[&None][0].character_id, 3);
+
+        // Assert members are grouped by the category of their ship with unknown ships under `None`
+        let by_ship = CorporationMemberTracking::group_by_ship_category(&members, |type_id| {
+            (type_id == 587).then_some(25)
+        });
+        assert_eq!(by_ship[&Some(25)].len(), 3);
+        assert_eq!(by_ship[&None][0].character_id, 4);
+    }
+}
+
+#[cfg(test)]
+mod starbase_fuel_tests {
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    use super::{
+        CorporationStarbaseDetails, CorporationStarbaseFuel, StarbaseFuelConsumption,
+        AMARR_FUEL_BLOCK_TYPE_ID, MINMATAR_FUEL_BLOCK_TYPE_ID,
+    };
+    use crate::model::enums::corporation::{CorporationStarbasePermission, StarbaseSize};
+    use crate::model::RoleRestricted;
+
+    const STRONTIUM_TYPE_ID: i64 = 16275;
+    const CHARTER_TYPE_ID: i64 = 24592;
+
+    /// Creates mock starbase details with the provided fuel bay contents
+    fn mock_starbase_details(fuels: Vec<(i64, i64)>) -> CorporationStarbaseDetails {
+        CorporationStarbaseDetails {
+            allow_alliance_members: false,
+            allow_corporation_members: true,
+            anchor: CorporationStarbasePermission::ConfigStarbaseEquipmentRole,
+            attack_if_at_war: true,
+            attack_if_other_security_status_dropping: false,
+            attack_security_status_threshold: None,
+            attack_standing_threshold: None,
+            fuel_bay_take: CorporationStarbasePermission::StarbaseFuelTechnicianRole,
+            fuel_bay_view: CorporationStarbasePermission::CorporationMember,
+            fuels: Some(
+                fuels
+                    .into_iter()
+                    .map(|(type_id, quantity)| CorporationStarbaseFuel { quantity, type_id })
+                    .collect(),
+            ),
+            offline: CorporationStarbasePermission::ConfigStarbaseEquipmentRole,
+            online: CorporationStarbasePermission::ConfigStarbaseEquipmentRole,
+            unanchor: CorporationStarbasePermission::ConfigStarbaseEquipmentRole,
+            use_alliance_standings: true,
+        }
+    }
+
+    /// Fuel remaining is the full hours of fuel blocks, ignoring fuels without a rate
+    #[test]
+    fn test_fuel_remaining_fuel_blocks() {
+        let details = mock_starbase_details(vec![
+            (AMARR_FUEL_BLOCK_TYPE_ID, 1000),
+            (STRONTIUM_TYPE_ID, 5000),
+        ]);
+        let consumption =
+            StarbaseFuelConsumption::fuel_blocks(AMARR_FUEL_BLOCK_TYPE_ID, StarbaseSize::Large);
+
+        assert_eq!(
+            details.fuel_remaining(&consumption),
+            Some(TimeDelta::hours(25))
+        );
+    }
+
+    /// Fuel remaining is limited by the scarcest required fuel
+    #[test]
+    fn test_fuel_remaining_scarcest_fuel() {
+        let details =
+            mock_starbase_details(vec![(AMARR_FUEL_BLOCK_TYPE_ID, 1000), (CHARTER_TYPE_ID, 3)]);
+        let consumption =
+            StarbaseFuelConsumption::fuel_blocks(AMARR_FUEL_BLOCK_TYPE_ID, StarbaseSize::Small)
+                .with_rate(CHARTER_TYPE_ID, 1);
+
+        assert_eq!(
+            details.fuel_remaining(&consumption),
+            Some(TimeDelta::hours(3))
+        );
+    }
+
+    /// Fuel remaining is zero when a required fuel is missing & None without rates
+    #[test]
+    fn test_fuel_remaining_missing_fuel() {
+        let details = mock_starbase_details(vec![(STRONTIUM_TYPE_ID, 5000)]);
+        let consumption =
+            StarbaseFuelConsumption::fuel_blocks(AMARR_FUEL_BLOCK_TYPE_ID, StarbaseSize::Medium);
+
+        assert_eq!(
+            details.fuel_remaining(&consumption),
+            Some(TimeDelta::zero())
+        );
+        assert_eq!(
+            details.fuel_remaining(&StarbaseFuelConsumption::new()),
+            None
+        );
+    }
+
+    /// Fuel remaining is unknown & the details incomplete without the fuel bay contents
+    #[test]
+    fn test_fuel_remaining_fuel_bay_withheld() {
+        let mut details = mock_starbase_details(vec![(AMARR_FUEL_BLOCK_TYPE_ID, 1000)]);
+        let consumption =
+            StarbaseFuelConsumption::fuel_blocks(AMARR_FUEL_BLOCK_TYPE_ID, StarbaseSize::Large);
+        assert!(details.is_complete());
+
+        details.fuels = None;
+
+        assert!(!details.is_complete());
+        assert_eq!(details.fuel_remaining(&consumption), None);
+    }
+
+    /// Racial control towers consume the standard fuel block rate for their size
+    #[test]
+    fn test_control_tower_racial() {
+        let consumption = StarbaseFuelConsumption::control_tower(12235).unwrap();
+
+        assert_eq!(
+            consumption,
+            StarbaseFuelConsumption::fuel_blocks(AMARR_FUEL_BLOCK_TYPE_ID, StarbaseSize::Large)
+        );
+        assert_eq!(
+            StarbaseFuelConsumption::control_tower_fuel_blocks(20066),
+            Some((MINMATAR_FUEL_BLOCK_TYPE_ID, 10))
+        );
+    }
+
+    /// Faction control towers consume their race's fuel blocks at a reduced rate
+    #[test]
+    fn test_control_tower_faction() {
+        assert_eq!(
+            StarbaseFuelConsumption::control_tower_fuel_blocks(27539),
+            Some((MINMATAR_FUEL_BLOCK_TYPE_ID, 36))
+        );
+        assert_eq!(
+            StarbaseFuelConsumption::control_tower_fuel_blocks(27594),
+            Some((AMARR_FUEL_BLOCK_TYPE_ID, 8))
+        );
+    }
+
+    /// Unknown control tower type IDs have no fuel consumption
+    #[test]
+    fn test_control_tower_unknown() {
+        assert_eq!(StarbaseFuelConsumption::control_tower(587), None);
+        assert_eq!(
+            StarbaseFuelConsumption::control_tower_fuel_blocks(587),
+            None
+        );
+    }
+
+    /// Fuel expiry is offset from the provided timestamp
+    #[test]
+    fn test_fuel_expires_at() {
+        let details = mock_starbase_details(vec![(AMARR_FUEL_BLOCK_TYPE_ID, 400)]);
+        let consumption =
+            StarbaseFuelConsumption::fuel_blocks(AMARR_FUEL_BLOCK_TYPE_ID, StarbaseSize::Large);
+        let from = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(
+            details.fuel_expires_at(&consumption, from),
+            Some(Utc.with_ymd_and_hms(2025, 1, 1, 10, 0, 0).unwrap())
+        );
+    }
+}
+
+#[cfg(test)]
+mod dividend_tests {
+    use super::{CorporationShareholder, DividendReport, DividendRounding};
+    use crate::model::enums::corporation::ShareholderType;
+
+    /// Creates a mock character shareholder
+    fn mock_shareholder(shareholder_id: i64, share_count: i64) -> CorporationShareholder {
+        CorporationShareholder {
+            share_count,
+            shareholder_id,
+            shareholder_type: ShareholderType::Character,
+        }
+    }
+
+    /// Tests that payouts are rounded down with the remainder left undistributed
+    ///
+    /// # Test Setup
+    /// - Create 3 shareholders with an equal amount of shares
+    /// - Split 100 ISK using [`DividendRounding::Floor`]
+    ///
+    /// # Assertions
+    /// - Assert each shareholder receives 33.33 ISK
+    /// - Assert 0.01 ISK is left undistributed
+    #[test]
+    fn test_dividend_floor() {
+        let shareholders = vec![
+            mock_shareholder(1, 10),
+            mock_shareholder(2, 10),
+            mock_shareholder(3, 10),
+        ];
+
+        let report = DividendReport::new(&shareholders, 100.0, DividendRounding::Floor);
+
+        assert_eq!(report.total_shares, 30);
+        assert!(report.payouts.iter().all(|payout| payout.amount == 33.33));
+        assert_eq!(report.distributed, 99.99);
+        assert_eq!(report.undistributed, 0.01);
+    }
+
+    /// Tests that the rounding remainder is distributed to the largest remainders
+    ///
+    /// # Test Setup
+    /// - Create shareholders holding 1, 2, & 4 shares
+    /// - Split 1 ISK using [`DividendRounding::LargestRemainder`]
+    ///
+    /// # Assertions
+    /// - Assert the full amount is distributed
+    /// - Assert the leftover 0.01 ISK was given to the shareholder with the largest remainder
+    #[test]
+    fn test_dividend_largest_remainder() {
+        let shareholders = vec![
+            mock_shareholder(1, 1),
+            mock_shareholder(2, 2),
+            mock_shareholder(3, 4),
+        ];
+
+        let report = DividendReport::new(&shareholders, 1.0, DividendRounding::LargestRemainder);
+
+        // Exact payouts are 14 2/7, 28 4/7, & 57 1/7 hundredths of an ISK
+        assert_eq!(report.distributed, 1.0);
+        assert_eq!(report.undistributed, 0.0);
+        assert_eq!(report.payouts[0].amount, 0.14);
+        assert_eq!(report.payouts[1].amount, 0.29);
+        assert_eq!(report.payouts[2].amount, 0.57);
+    }
+
+    /// Tests that shareholders without shares are excluded from the payouts
+    ///
+    /// # Test Setup
+    /// - Create a shareholder without shares & a shareholder with shares
+    ///
+    /// # Assertions
+    /// - Assert only the shareholder with shares receives a payout
+    /// - Assert the full amount is left undistributed when no shares are held
+    #[test]
+    fn test_dividend_without_shares() {
+        let shareholders = vec![mock_shareholder(1, 0), mock_shareholder(2, 5)];
+
+        let report = DividendReport::new(&shareholders, 50.0, DividendRounding::Floor);
+
+        assert_eq!(report.payouts.len(), 1);
+        assert_eq!(report.payouts[0].shareholder_id, 2);
+        assert_eq!(report.payouts[0].amount, 50.0);
+
+        let report = DividendReport::new(&[mock_shareholder(1, 0)], 50.0, DividendRounding::Floor);
+
+        assert!(report.payouts.is_empty());
+        assert_eq!(report.undistributed, 50.0);
+    }
+}
+
+#[cfg(test)]
+mod member_access_tests {
+    use super::{
+        CorporationMemberAccessChange, CorporationMemberAccessSnapshot, CorporationMemberRoles,
+        CorporationMemberTitles,
+    };
+    use crate::model::enums::corporation::{CorporationRole, CorporationRoleType};
+
+    /// Creates mock member roles with the provided corporation-wide & HQ roles
+    fn mock_roles(
+        character_id: i64,
+        roles: Vec<CorporationRole>,
+        roles_at_hq: Vec<CorporationRole>,
+    ) -> CorporationMemberRoles {
+        CorporationMemberRoles {
+            character_id,
+            grantable_roles: Vec::new(),
+            grantable_roles_at_base: Vec::new(),
+            grantable_roles_at_hq: Vec::new(),
+            grantable_roles_at_other: Vec::new(),
+            roles,
+            roles_at_base: Vec::new(),
+            roles_at_hq,
+            roles_at_other: Vec::new(),
+        }
+    }
+
+    /// Tests that role & title changes between snapshots are reported
+    ///
+    /// # Test Setup
+    /// - Create an old snapshot where a member is an accountant with title 1
+    /// - Create a new snapshot where the member is a director at HQ with title 2
+    ///
+    /// # Assertions
+    /// - Assert the gained & lost roles are reported with their role type
+    /// - Assert the gained & lost titles are reported
+    #[test]
+    fn test_member_access_diff() {
+        let old = CorporationMemberAccessSnapshot {
+            roles: vec![mock_roles(1, vec![CorporationRole::Accountant], Vec::new())],
+            titles: vec![CorporationMemberTitles {
+                character_id: 1,
+                titles: vec![1],
+            }],
+        };
+        let new = CorporationMemberAccessSnapshot {
+            roles: vec![mock_roles(1, Vec::new(), vec![CorporationRole::Director])],
+            titles: vec![CorporationMemberTitles {
+                character_id: 1,
+                titles: vec![2],
+            }],
+        };
+
+        let changes = old.diff(&new);
+
+        assert_eq!(
+            changes,
+            vec![
+                CorporationMemberAccessChange::RoleRevoked {
+                    character_id: 1,
+                    role: CorporationRole::Accountant,
+                    role_type: CorporationRoleType::Roles,
+                },
+                CorporationMemberAccessChange::RoleGranted {
+                    character_id: 1,
+                    role: CorporationRole::Director,
+                    role_type: CorporationRoleType::RolseAtHq,
+                },
+                CorporationMemberAccessChange::TitleGranted {
+                    character_id: 1,
+                    title_id: 2,
+                },
+                CorporationMemberAccessChange::TitleRevoked {
+                    character_id: 1,
+                    title_id: 1,
+                },
+            ]
+        );
+    }
+
+    /// Tests that members who joined or left are compared against no roles or titles
+    ///
+    /// # Test Setup
+    /// - Create an old snapshot with member 1 & a new snapshot with member 2
+    ///
+    /// # Assertions
+    /// - Assert member 1 lost their role & title
+    /// - Assert member 2 gained their role
+    /// - Assert identical snapshots produce no changes
+    #[test]
+    fn test_member_access_diff_joined_and_left() {
+        let old = CorporationMemberAccessSnapshot {
+            roles: vec![mock_roles(1, vec![CorporationRole::Trader], Vec::new())],
+            titles: vec![CorporationMemberTitles {
+                character_id: 1,
+                titles: vec![4],
+            }],
+        };
+        let new = CorporationMemberAccessSnapshot {
+            roles: vec![mock_roles(2, vec![CorporationRole::Trader], Vec::new())],
+            titles: Vec::new(),
+        };
+
+        let changes = old.diff(&new);
+
+        assert_eq!(changes.len(), 3);
+        assert!(matches!(
+            changes[0],
+            CorporationMemberAccessChange::RoleRevoked {
+                character_id: 1,
+                ..
+            }
+        ));
+        assert!(matches!(
+            changes[1],
+            CorporationMemberAccessChange::TitleRevoked {
+                character_id: 1,
+                title_id: 4
+            }
+        ));
+        assert!(matches!(
+            changes[2],
+            CorporationMemberAccessChange::RoleGranted {
+                character_id: 2,
+                ..
+            }
+        ));
+
+        assert!(new.diff(&new).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod secure_container_log_tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// Creates a mock log entry of the provided action on the provided container
+    fn mock_log(
+        container_id: i64,
+        action: CorporationSecureContainerAction,
+    ) -> CorporationSecureContainerLog {
+        CorporationSecureContainerLog {
+            action,
+            character_id: 2114794365,
+            container_id,
+            container_type_id: 17363,
+            location_flag: LocationFlag::Hangar,
+            location_id: 60003760,
+            logged_at: Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+            new_config_bitmask: 0,
+            old_config_bitmask: 0,
+            quantity: 5,
+            type_id: 34,
+        }
+    }
+
+    /// Tests log entries are matched against each field of the filter
+    ///
+    /// # Assertions
+    /// - Assert the default filter matches every entry
+    /// - Assert an entry must match the container & one of the actions of the filter
+    #[test]
+    fn test_secure_container_log_filter() {
+        let added = mock_log(1, CorporationSecureContainerAction::Add);
+        let moved = mock_log(1, CorporationSecureContainerAction::Move);
+        let other_container = mock_log(2, CorporationSecureContainerAction::Add);
+
+        assert!(SecureContainerLogFilter::new().matches(&moved));
+
+        let filter = SecureContainerLogFilter::new()
+            .container(1)
+            .action(CorporationSecureContainerAction::Add)
+            .action(CorporationSecureContainerAction::Unlock);
+
+        assert!(filter.matches(&added));
+        assert!(!filter.matches(&moved));
+        assert!(!filter.matches(&other_container));
+    }
+
+    /// Tests audit entries are described with resolved names, falling back to IDs
+    ///
+    /// # Assertions
+    /// - Assert the description uses the character & item type names when resolved
+    /// - Assert the description uses the IDs when the names were not resolved
+    #[test]
+    fn test_secure_container_audit_entry_display() {
+        let entry = SecureContainerAuditEntry {
+            log: mock_log(1, CorporationSecureContainerAction::Add),
+            character_name: Some("Bob".to_string()),
+            type_name: Some("Tritanium".to_string()),
+        };
+        assert_eq!(
+            entry.to_string(),
+            "2025-01-01 12:00:00 UTC: Bob added 5 x Tritanium to container 1"
+        );
+
+        let entry = SecureContainerAuditEntry {
+            character_name: None,
+            type_name: None,
+            ..entry
+        };
+        assert_eq!(
+            entry.to_string(),
+            "2025-01-01 12:00:00 UTC: Character 2114794365 added 5 x type 34 to container 1"
+        );
+    }
+}
+
+#[cfg(test)]
+mod division_names_tests {
+    use super::*;
+
+    /// Tests every division is named, using default names for unnamed divisions
+    ///
+    /// # Test Setup
+    /// - Create divisions with a named hangar division, an empty hangar name & a named master wallet
+    ///
+    /// # Assertions
+    /// - Assert divisions 1 through 7 are named for both hangars & wallets
+    /// - Assert set names are kept & unnamed or empty names are replaced with default names
+    #[test]
+    fn test_division_names() {
+        let divisions = CorporationDivisions {
+            hangar: vec![
+                CorporationDivisionEntry {
+                    division: 1,
+                    name: Some("Ships".to_string()),
+                },
+                CorporationDivisionEntry {
+                    division: 2,
+                    name: Some(String::new()),
+                },
+            ],
+            wallet: vec![CorporationDivisionEntry {
+                division: 1,
+                name: Some("Master Wallet".to_string()),
+            }],
+        };
+
+        let hangar_names = divisions.hangar_names();
+        let wallet_names = divisions.wallet_names();
+
+        assert_eq!(
+            hangar_names.keys().copied().collect::<Vec<_>>(),
+            (1..=7).collect::<Vec<_>>()
+        );
+        assert_eq!(hangar_names[&1], "Ships");
+        assert_eq!(hangar_names[&2], "Hangar Division 2");
+        assert_eq!(wallet_names.len(), 7);
+        assert_eq!(wallet_names[&1], "Master Wallet");
+        assert_eq!(wallet_names[&3], "Wallet Division 3");
+    }
+}
+
+#[cfg(test)]
+mod structure_service_tests {
+    use super::*;
+
+    /// Creates a structure with the provided services
+    fn mock_structure(services: serde_json::Value) -> CorporationStructure {
+        serde_json::from_value(serde_json::json!({
+            "corporation_id": 98785281,
+            "profile_id": 1,
+            "services": services,
+            "state": "shield_vulnerable",
+            "structure_id": 1035466617946_i64,
+            "system_id": 30000142,
+            "type_id": 35832
+        }))
+        .unwrap()
+    }
+
+    /// Tests deserializing the services of a structure into typed names
+    ///
+    /// # Assertions
+    /// - Assert known service names are deserialized into their variant
+    /// - Assert unknown service names are kept as `Other`
+    /// - Assert names serialize back into the names returned by ESI
+    #[test]
+    fn test_service_names() {
+        let structure = mock_structure(serde_json::json!([
+            { "name": "Manufacturing (Standard)", "state": "online" },
+            { "name": "Orbital Laundromat", "state": "offline" }
+        ]));
+
+        // Assert known service names are deserialized into their variant
+        assert_eq!(
+            structure.services[0].name,
+            StructureServiceName::ManufacturingStandard
+        );
+
+        // Assert unknown service names are kept as `Other`
+        assert_eq!(
+            structure.services[1].name,
+            StructureServiceName::Other("Orbital Laundromat".to_string())
+        );
+
+        // Assert names serialize back into the names returned by ESI
+        assert_eq!(
+            serde_json::to_value(&structure.services).unwrap(),
+            serde_json::json!([
+                { "name": "Manufacturing (Standard)", "state": "online" },
+                { "name": "Orbital Laundromat", "state": "offline" }
+            ])
+        );
+    }
+
+    /// Tests service helpers only count online services
+    ///
+    /// # Test Setup
+    /// - Create a structure with an online market, an offline clone bay & a reprocessing service
+    ///   being cleaned up
+    ///
+    /// # Assertions
+    /// - Assert the online market is found
+    /// - Assert the offline clone bay & reprocessing service being cleaned up are not found
+    /// - Assert only the market is listed as an online service
+    #[test]
+    fn test_service_helpers() {
+        let structure = mock_structure(serde_json::json!([
+            { "name": "Market", "state": "online" },
+            { "name": "Clone Bay", "state": "offline" },
+            { "name": "Reprocessing", "state": "cleanup" }
+        ]));
+
+        // Assert the online market is found
+        assert!(structure.has_market());
+
+        // Assert the offline clone bay & reprocessing service being cleaned up are not found
+        assert!(!structure.has_clone_bay());
+        assert!(!structure.has_reprocessing());
+        assert!(!structure.has_manufacturing());
+
+        // Assert only the market is listed as an online service
+        assert_eq!(
+            structure.online_services().collect::<Vec<_>>(),
+            vec![&StructureServiceName::Market]
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! # EVE ESI Corporation Project Models
+//!
+//! Provides corporation project-related structs for EVE Online
+//!
+//! The corporation project endpoints are paginated with cursors rather than page numbers, each page
+//! implements [`CursorPage`] to be iterated with a [`Cursor`](crate::esi::Cursor).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::enums::corporation_projects::CorporationProjectState;
+use super::Isk;
+use crate::esi::{CursorPage, CursorTokens};
+
+/// Progress of a corporation project towards its goal
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProjectProgress {
+    /// The amount contributed towards the goal so far
+    pub current: i64,
+    /// The amount required to reach the goal
+    pub desired: i64,
+}
+
+/// ISK reward paid out to contributors of a corporation project
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProjectReward {
+    /// The ISK reward initially allocated to the project
+    pub initial: Isk,
+    /// The ISK reward which has not been paid out yet
+    pub remaining: Isk,
+}
+
+/// A project created by a corporation for its members to contribute to
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProject {
+    /// The UUID of the project
+    pub id: String,
+    /// Date & time the project was last modified
+    pub last_modified: DateTime<Utc>,
+    /// The name of the project
+    pub name: String,
+    /// Progress of the project towards its goal
+    pub progress: CorporationProjectProgress,
+    /// ISK reward paid out to contributors, if the project has one
+    pub reward: Option<CorporationProjectReward>,
+    /// The state of the project
+    pub state: CorporationProjectState,
+}
+
+/// A page of a corporation's projects
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProjectsPage {
+    /// Tokens to retrieve the projects older or newer than this page
+    pub cursor: Option<CursorTokens>,
+    /// The projects of the page, newest first
+    #[serde(default)]
+    pub projects: Vec<CorporationProject>,
+}
+
+impl CursorPage for CorporationProjectsPage {
+    type Item = CorporationProject;
+
+    fn cursor(&self) -> Option<&CursorTokens> {
+        self.cursor.as_ref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.projects
+    }
+}
+
+/// A character who contributed to a corporation project
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsProjectIdContributorsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProjectContributor {
+    /// The amount the character contributed towards the project's goal
+    pub contributed: i64,
+    /// The ID of the character
+    pub id: i64,
+    /// The name of the character
+    pub name: String,
+}
+
+/// A page of the contributors to a corporation project
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsProjectIdContributorsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProjectContributorsPage {
+    /// Tokens to retrieve the contributors older or newer than this page
+    pub cursor: Option<CursorTokens>,
+    /// The contributors of the page
+    #[serde(default)]
+    pub contributors: Vec<CorporationProjectContributor>,
+}
+
+impl CursorPage for CorporationProjectContributorsPage {
+    type Item = CorporationProjectContributor;
+
+    fn cursor(&self) -> Option<&CursorTokens> {
+        self.cursor.as_ref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.contributors
+    }
+}
+
+/// A character's contribution to a corporation project
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdProjectsProjectIdContributionCharacterIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationProjectContribution {
+    /// The amount the character contributed towards the project's goal
+    pub contributed: i64,
+    /// Date & time the character last contributed to the project
+    pub last_modified: DateTime<Utc>,
+}
@@ -0,0 +1,193 @@
+//! # EVE ESI Dogma Models
+//!
+//! Provides models related to dogma endpoints for EVE Online's ESI API.
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::universe::{TypeDogmaAttribute, TypeDogmaEffect};
+
+/// Dogma attributes & effects of a mutated item, such as an abyssal module
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/DogmaDynamicItemsTypeIdItemIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DynamicItem {
+    /// The ID of the character who mutated the item
+    pub created_by: i64,
+    /// Dogma attribute values of the mutated item
+    #[serde(default)]
+    pub dogma_attributes: Vec<TypeDogmaAttribute>,
+    /// Dogma effects of the mutated item
+    #[serde(default)]
+    pub dogma_effects: Vec<TypeDogmaEffect>,
+    /// The type ID of the mutaplasmid used to mutate the item
+    pub mutator_type_id: i64,
+    /// The type ID of the item before it was mutated
+    pub source_type_id: i64,
+}
+
+/// A dogma attribute of a mutated item alongside the value of the item it was mutated from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DynamicItemAttribute {
+    /// The ID of the dogma attribute
+    pub attribute_id: i64,
+    /// The value of the attribute for the item type the item was mutated from, `None` if the
+    /// source type does not have the attribute
+    pub base_value: Option<f64>,
+    /// The value of the attribute for the mutated item, `None` if the mutated item does not
+    /// have the attribute
+    pub mutated_value: Option<f64>,
+}
+
+impl DynamicItemAttribute {
+    /// Returns the value of the attribute, preferring the mutated value over the base value
+    pub fn value(&self) -> Option<f64> {
+        self.mutated_value.or(self.base_value)
+    }
+
+    /// Returns the ratio of the mutated value to the base value, e.g. `1.1` for a 10% increase
+    ///
+    /// Returns `None` if either value is missing or the base value is `0`.
+    pub fn ratio(&self) -> Option<f64> {
+        match (self.base_value, self.mutated_value) {
+            (Some(base), Some(mutated)) if base != 0.0 => Some(mutated / base),
+            _ => None,
+        }
+    }
+}
+
+/// A mutated item's attributes merged with the attributes of the item type it was mutated from
+///
+/// Built by [`DogmaEndpoints::get_dynamic_item_details`](crate::endpoints::dogma::DogmaEndpoints::get_dynamic_item_details).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DynamicItemDetails {
+    /// The ID of the mutated item
+    pub item_id: i64,
+    /// The type ID of the mutated item
+    pub type_id: i64,
+    /// The type ID of the item before it was mutated
+    pub source_type_id: i64,
+    /// The type ID of the mutaplasmid used to mutate the item
+    pub mutator_type_id: i64,
+    /// The ID of the character who mutated the item
+    pub created_by: i64,
+    /// Attributes of the source type & mutated item ordered by attribute ID
+    pub attributes: Vec<DynamicItemAttribute>,
+}
+
+impl DynamicItemDetails {
+    /// Merges the attributes of a mutated item with the attributes of the item type it was mutated from
+    ///
+    /// # Arguments
+    /// - `type_id` (`i64`): The type ID of the mutated item
+    /// - `item_id` (`i64`): The ID of the mutated item
+    /// - `item` (`DynamicItem`): The mutated item's dogma attributes
+    /// - `base_attributes` (`&[TypeDogmaAttribute]`): The dogma attributes of the item's source type
+    pub fn new(
+        type_id: i64,
+        item_id: i64,
+        item: DynamicItem,
+        base_attributes: &[TypeDogmaAttribute],
+    ) -> Self {
+        let mut attributes: Vec<DynamicItemAttribute> = base_attributes
+            .iter()
+            .map(|base| DynamicItemAttribute {
+                attribute_id: base.attribute_id,
+                base_value: Some(base.value),
+                mutated_value: None,
+            })
+            .collect();
+
+        for mutated in item.dogma_attributes {
+            match attributes
+                .iter_mut()
+                .find(|attribute| attribute.attribute_id == mutated.attribute_id)
+            {
+                Some(attribute) => attribute.mutated_value = Some(mutated.value),
+                None => attributes.push(DynamicItemAttribute {
+                    attribute_id: mutated.attribute_id,
+                    base_value: None,
+                    mutated_value: Some(mutated.value),
+                }),
+            }
+        }
+
+        attributes.sort_by_key(|attribute| attribute.attribute_id);
+
+        Self {
+            item_id,
+            type_id,
+            source_type_id: item.source_type_id,
+            mutator_type_id: item.mutator_type_id,
+            created_by: item.created_by,
+            attributes,
+        }
+    }
+
+    /// Returns the provided attribute of the item
+    ///
+    /// # Arguments
+    /// - `attribute_id` (`i64`): The ID of the dogma attribute
+    pub fn attribute(&self, attribute_id: i64) -> Option<&DynamicItemAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.attribute_id == attribute_id)
+    }
+}
+
+#[cfg(test)]
+mod dynamic_item_tests {
+    use super::*;
+
+    /// Tests merging mutated attributes with the source type's attributes
+    ///
+    /// # Test Setup
+    /// - Create a mutated item with one attribute shared with its source type & one unique attribute
+    ///
+    /// # Assertions
+    /// - Assert shared attributes contain both values
+    /// - Assert attributes present on only one side contain a single value
+    /// - Assert attributes are ordered by attribute ID
+    #[test]
+    fn test_dynamic_item_details_merge() {
+        let item = DynamicItem {
+            created_by: 2114794365,
+            dogma_attributes: vec![
+                TypeDogmaAttribute {
+                    attribute_id: 54,
+                    value: 15400.0,
+                },
+                TypeDogmaAttribute {
+                    attribute_id: 20,
+                    value: -60.0,
+                },
+            ],
+            dogma_effects: Vec::new(),
+            mutator_type_id: 47702,
+            source_type_id: 526,
+        };
+        let base = [
+            TypeDogmaAttribute {
+                attribute_id: 54,
+                value: 14000.0,
+            },
+            TypeDogmaAttribute {
+                attribute_id: 30,
+                value: 1.0,
+            },
+        ];
+
+        let details = DynamicItemDetails::new(47701, 1044416040, item, &base);
+
+        let ids: Vec<i64> = details.attributes.iter().map(|a| a.attribute_id).collect();
+        assert_eq!(ids, vec![20, 30, 54]);
+
+        let range = details.attribute(54).unwrap();
+        assert_eq!(range.base_value, Some(14000.0));
+        assert_eq!(range.mutated_value, Some(15400.0));
+        assert!((range.ratio().unwrap() - 1.1).abs() < 1e-9);
+
+        assert_eq!(details.attribute(30).unwrap().value(), Some(1.0));
+        assert_eq!(details.attribute(20).unwrap().base_value, None);
+    }
+}
@@ -183,4 +183,128 @@ pub enum LocationFlag {
     /// Carrier or structure's fighter tube #5
     FighterTube4,
     Module,
+    /// Corporation office, items within an office are located in the office's hangar divisions
+    OfficeFolder,
+    /// Corporation hangar division #1
+    #[serde(rename = "CorpSAG1")]
+    CorpSag1,
+    /// Corporation hangar division #2
+    #[serde(rename = "CorpSAG2")]
+    CorpSag2,
+    /// Corporation hangar division #3
+    #[serde(rename = "CorpSAG3")]
+    CorpSag3,
+    /// Corporation hangar division #4
+    #[serde(rename = "CorpSAG4")]
+    CorpSag4,
+    /// Corporation hangar division #5
+    #[serde(rename = "CorpSAG5")]
+    CorpSag5,
+    /// Corporation hangar division #6
+    #[serde(rename = "CorpSAG6")]
+    CorpSag6,
+    /// Corporation hangar division #7
+    #[serde(rename = "CorpSAG7")]
+    CorpSag7,
+    /// Corporation deliveries hangar
+    CorpDeliveries,
+    /// Items impounded due to an office lease expiring or being cancelled
+    Impounded,
+    /// Structure's fuel bay
+    StructureFuel,
+    /// Structure's moon material bay
+    MoonMaterialBay,
+    /// Structure's service module slot #1
+    ServiceSlot0,
+    /// Structure's service module slot #2
+    ServiceSlot1,
+    /// Structure's service module slot #3
+    ServiceSlot2,
+    /// Structure's service module slot #4
+    ServiceSlot3,
+    /// Structure's service module slot #5
+    ServiceSlot4,
+    /// Structure's service module slot #6
+    ServiceSlot5,
+    /// Structure's service module slot #7
+    ServiceSlot6,
+    /// Structure's service module slot #8
+    ServiceSlot7,
+}
+
+impl LocationFlag {
+    /// Returns the number of the corporation hangar division (1-7) the location is, if any
+    ///
+    /// Use [`CorporationDivisions::hangar_names`](crate::model::corporation::CorporationDivisions::hangar_names)
+    /// to look up the division's name.
+    pub fn corporation_hangar_division(&self) -> Option<i64> {
+        match self {
+            LocationFlag::CorpSag1 => Some(1),
+            LocationFlag::CorpSag2 => Some(2),
+            LocationFlag::CorpSag3 => Some(3),
+            LocationFlag::CorpSag4 => Some(4),
+            LocationFlag::CorpSag5 => Some(5),
+            LocationFlag::CorpSag6 => Some(6),
+            LocationFlag::CorpSag7 => Some(7),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the location is a fitting slot of a ship or structure
+    ///
+    /// Fitting slots are the high, medium, low, rig, subsystem, & service slots, items in other
+    /// locations such as the cargo or drone bay are not fitted.
+    pub fn is_fitting_slot(&self) -> bool {
+        matches!(
+            self,
+            LocationFlag::LoSlot0
+                | LocationFlag::LoSlot1
+                | LocationFlag::LoSlot2
+                | LocationFlag::LoSlot3
+                | LocationFlag::LoSlot4
+                | LocationFlag::LoSlot5
+                | LocationFlag::LoSlot6
+                | LocationFlag::LoSlot7
+                | LocationFlag::MedSlot0
+                | LocationFlag::MedSlot1
+                | LocationFlag::MedSlot2
+                | LocationFlag::MedSlot3
+                | LocationFlag::MedSlot4
+                | LocationFlag::MedSlot5
+                | LocationFlag::MedSlot6
+                | LocationFlag::MedSlot7
+                | LocationFlag::HiSlot0
+                | LocationFlag::HiSlot1
+                | LocationFlag::HiSlot2
+                | LocationFlag::HiSlot3
+                | LocationFlag::HiSlot4
+                | LocationFlag::HiSlot5
+                | LocationFlag::HiSlot6
+                | LocationFlag::HiSlot7
+                | LocationFlag::RigSlot0
+                | LocationFlag::RigSlot1
+                | LocationFlag::RigSlot2
+                | LocationFlag::RigSlot3
+                | LocationFlag::RigSlot4
+                | LocationFlag::RigSlot5
+                | LocationFlag::RigSlot6
+                | LocationFlag::RigSlot7
+                | LocationFlag::SubSystemSlot0
+                | LocationFlag::SubSystemSlot1
+                | LocationFlag::SubSystemSlot2
+                | LocationFlag::SubSystemSlot3
+                | LocationFlag::SubSystemSlot4
+                | LocationFlag::SubSystemSlot5
+                | LocationFlag::SubSystemSlot6
+                | LocationFlag::SubSystemSlot7
+                | LocationFlag::ServiceSlot0
+                | LocationFlag::ServiceSlot1
+                | LocationFlag::ServiceSlot2
+                | LocationFlag::ServiceSlot3
+                | LocationFlag::ServiceSlot4
+                | LocationFlag::ServiceSlot5
+                | LocationFlag::ServiceSlot6
+                | LocationFlag::ServiceSlot7
+        )
+    }
 }
@@ -27,7 +27,8 @@ pub enum CalendarEventResponse {
 /// The response to send for a calendar event on behalf of a character
 ///
 /// Differs from [`CalendarEventResponse`] in that it does not have the [`CalendarEventResponse::NotResponded`]
-/// variant.
+/// variant. Converts into a [`CalendarEventResponse`] for comparison against attendee responses returned by
+/// [`CalendarEndpoints::get_attendees`](crate::endpoints::calendar::CalendarEndpoints::get_attendees).
 ///
 /// # Documentation
 /// - <https://developers.eveonline.com/api-explorer#/operations/PutCharactersCharacterIdCalendarEventId>
@@ -44,6 +45,34 @@ pub enum PutCalendarEventResponse {
     Tentative,
 }
 
+impl From<PutCalendarEventResponse> for CalendarEventResponse {
+    fn from(response: PutCalendarEventResponse) -> Self {
+        match response {
+            PutCalendarEventResponse::Declined => Self::Declined,
+            PutCalendarEventResponse::Accepted => Self::Accepted,
+            PutCalendarEventResponse::Tentative => Self::Tentative,
+        }
+    }
+}
+
+impl TryFrom<CalendarEventResponse> for PutCalendarEventResponse {
+    type Error = CalendarEventResponse;
+
+    /// Converts an attendee's [`CalendarEventResponse`] into a response which can be sent for an event
+    ///
+    /// # Returns
+    /// - [`PutCalendarEventResponse`]: The equivalent response to send
+    /// - [`CalendarEventResponse`]: The original response if it was [`CalendarEventResponse::NotResponded`]
+    fn try_from(response: CalendarEventResponse) -> Result<Self, Self::Error> {
+        match response {
+            CalendarEventResponse::Declined => Ok(Self::Declined),
+            CalendarEventResponse::Accepted => Ok(Self::Accepted),
+            CalendarEventResponse::Tentative => Ok(Self::Tentative),
+            CalendarEventResponse::NotResponded => Err(response),
+        }
+    }
+}
+
 /// Represents a character's response to a calendar event
 ///
 /// # Documentation
@@ -66,3 +95,37 @@ pub enum CalendarEventOwnerType {
     #[serde(rename = "alliance")]
     Alliance,
 }
+
+#[cfg(test)]
+mod calendar_event_response_tests {
+    use super::*;
+
+    /// Tests conversion between attendee responses & responses sent for an event
+    ///
+    /// # Test Setup
+    /// - Convert each [`PutCalendarEventResponse`] into a [`CalendarEventResponse`] & back
+    ///
+    /// # Assertions
+    /// - Assert each response round-trips to the same variant
+    /// - Assert [`CalendarEventResponse::NotResponded`] cannot be sent as a response
+    #[test]
+    fn test_calendar_event_response_conversion() {
+        for response in [
+            PutCalendarEventResponse::Declined,
+            PutCalendarEventResponse::Accepted,
+            PutCalendarEventResponse::Tentative,
+        ] {
+            let attendee_response = CalendarEventResponse::from(response.clone());
+
+            assert_eq!(
+                PutCalendarEventResponse::try_from(attendee_response),
+                Ok(response)
+            );
+        }
+
+        assert_eq!(
+            PutCalendarEventResponse::try_from(CalendarEventResponse::NotResponded),
+            Err(CalendarEventResponse::NotResponded)
+        );
+    }
+}
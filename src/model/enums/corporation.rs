@@ -282,6 +282,20 @@ pub enum CorporationStarbaseState {
     Unanchoring,
 }
 
+/// The size of a starbase (POS) control tower
+///
+/// Determines the standard hourly fuel block consumption of a control tower,
+/// see [`StarbaseFuelConsumption::fuel_blocks`](crate::model::corporation::StarbaseFuelConsumption::fuel_blocks).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StarbaseSize {
+    /// Small control tower, consumes 10 fuel blocks per hour
+    Small,
+    /// Medium control tower, consumes 20 fuel blocks per hour
+    Medium,
+    /// Large control tower, consumes 40 fuel blocks per hour
+    Large,
+}
+
 /// The permission required to perform an action on a corporation owned starbase (POS)
 ///
 /// # Documentation
@@ -314,10 +328,76 @@ pub enum CorporationStructureServiceState {
     /// Structure service is currently offline
     #[serde(rename = "offline")]
     Offline,
+    /// Structure service is offline & being removed from the structure
     #[serde(rename = "cleanup")]
     Cleanup,
 }
 
+/// The services provided by the service modules of an Upwell structure
+///
+/// Names of services unknown to the crate, such as those added by a new service module, are
+/// kept as [`StructureServiceName::Other`].
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdStructuresGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StructureServiceName {
+    /// Clone bay allowing characters to install jump clones & change their medical clone
+    #[serde(rename = "Clone Bay")]
+    CloneBay,
+    /// Market hub allowing characters to trade on the structure's market
+    #[serde(rename = "Market")]
+    Market,
+    /// Manufacturing of standard items such as ships & modules
+    #[serde(rename = "Manufacturing (Standard)")]
+    ManufacturingStandard,
+    /// Manufacturing of capital ships
+    #[serde(rename = "Manufacturing (Capitals)")]
+    ManufacturingCapitals,
+    /// Manufacturing of supercapital ships
+    #[serde(rename = "Manufacturing (Super Capitals)")]
+    ManufacturingSuperCapitals,
+    /// Copying of blueprints
+    #[serde(rename = "Blueprint Copying")]
+    BlueprintCopying,
+    /// Material efficiency research of blueprints
+    #[serde(rename = "Material Efficiency Research")]
+    MaterialEfficiencyResearch,
+    /// Time efficiency research of blueprints
+    #[serde(rename = "Time Efficiency Research")]
+    TimeEfficiencyResearch,
+    /// Invention of tech 2 & tech 3 blueprints
+    #[serde(rename = "Invention")]
+    Invention,
+    /// Reprocessing of ore & items
+    #[serde(rename = "Reprocessing")]
+    Reprocessing,
+    /// Extraction of moon ore by a refinery
+    #[serde(rename = "Moon Drilling")]
+    MoonDrilling,
+    /// Composite reactions
+    #[serde(rename = "Composite Reactions")]
+    CompositeReactions,
+    /// Hybrid reactions
+    #[serde(rename = "Hybrid Reactions")]
+    HybridReactions,
+    /// Biochemical reactions
+    #[serde(rename = "Biochemical Reactions")]
+    BiochemicalReactions,
+    /// Jump bridge access provided by an Ansiblex jump gate
+    #[serde(rename = "Jump Gate Access")]
+    JumpGateAccess,
+    /// Cynosural beacon provided by a Pharolux cyno beacon
+    #[serde(rename = "Cyno Beacon")]
+    CynoBeacon,
+    /// Cynosural field jamming provided by a Tenebrex cyno jammer
+    #[serde(rename = "Cyno Jammer")]
+    CynoJammer,
+    /// Any other service, with the name returned by ESI
+    #[serde(untagged)]
+    Other(String),
+}
+
 /// The possible states of a corporation's Upwell structure
 ///
 /// # Documentation
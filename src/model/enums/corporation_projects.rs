@@ -0,0 +1,25 @@
+//! # EVE ESI Corporation Project Enums
+//!
+//! Provides enums related to corporation projects in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// The state of a corporation project
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/GetCorporationsCorporationIdProjects>
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorporationProjectState {
+    /// The state of the project is not specified
+    Unspecified,
+    /// The project is accepting contributions
+    Active,
+    /// The project was closed before being completed
+    Closed,
+    /// The project reached its goal
+    Completed,
+    /// The project expired before being completed
+    Expired,
+    /// The project was deleted
+    Deleted,
+}
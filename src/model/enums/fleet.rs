@@ -0,0 +1,25 @@
+//! # EVE ESI Fleet Enums
+//!
+//! Provides enums related to fleets in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// The role of a member within a fleet
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostFleetsFleetIdMembers>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum FleetRole {
+    /// Commander of the entire fleet
+    #[serde(rename = "fleet_commander")]
+    FleetCommander,
+    /// Commander of a wing within the fleet
+    #[serde(rename = "wing_commander")]
+    WingCommander,
+    /// Commander of a squad within a wing
+    #[serde(rename = "squad_commander")]
+    SquadCommander,
+    /// Member of a squad
+    #[serde(rename = "squad_member")]
+    SquadMember,
+}
@@ -0,0 +1,54 @@
+//! # EVE ESI Industry Enums
+//!
+//! Provides enums related to industry in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// The type of a corporation's mining observer
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationCorporationIdMiningObserversGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MiningObserverType {
+    /// The observer is an Upwell structure such as a refinery
+    #[serde(rename = "structure")]
+    Structure,
+}
+
+/// An industry activity which can be performed with a blueprint or formula
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/IndustrySystemsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndustryActivity {
+    /// Copying a blueprint original
+    #[serde(rename = "copying")]
+    Copying,
+    /// Duplicating, no longer performed in-game
+    #[serde(rename = "duplicating")]
+    Duplicating,
+    /// Inventing a tech 2 or tech 3 blueprint copy
+    #[serde(rename = "invention")]
+    Invention,
+    /// Manufacturing items from a blueprint
+    #[serde(rename = "manufacturing")]
+    Manufacturing,
+    /// No activity
+    #[serde(rename = "none")]
+    None,
+    /// Reacting materials with a reaction formula
+    #[serde(rename = "reaction")]
+    Reaction,
+    /// Researching a blueprint's material efficiency
+    #[serde(rename = "researching_material_efficiency")]
+    ResearchingMaterialEfficiency,
+    /// Researching technology, no longer performed in-game
+    #[serde(rename = "researching_technology")]
+    ResearchingTechnology,
+    /// Researching a blueprint's time efficiency
+    #[serde(rename = "researching_time_efficiency")]
+    ResearchingTimeEfficiency,
+    /// Reverse engineering, no longer performed in-game
+    #[serde(rename = "reverse_engineering")]
+    ReverseEngineering,
+}
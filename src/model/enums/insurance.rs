@@ -0,0 +1,32 @@
+//! # EVE ESI Insurance Enums
+//!
+//! Provides enums related to ship insurance in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// The level of an insurance policy, from the cheapest & lowest payout to the most expensive &
+/// highest payout
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/InsurancePricesGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum InsuranceLevel {
+    /// Free insurance every insurable ship has without purchasing a policy
+    #[serde(rename = "Basic")]
+    Basic,
+    /// Standard insurance policy
+    #[serde(rename = "Standard")]
+    Standard,
+    /// Bronze insurance policy
+    #[serde(rename = "Bronze")]
+    Bronze,
+    /// Silver insurance policy
+    #[serde(rename = "Silver")]
+    Silver,
+    /// Gold insurance policy
+    #[serde(rename = "Gold")]
+    Gold,
+    /// Platinum insurance policy
+    #[serde(rename = "Platinum")]
+    Platinum,
+}
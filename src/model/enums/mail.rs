@@ -0,0 +1,25 @@
+//! # EVE ESI Mail Enums
+//!
+//! Provides enums related to mail in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// The type of recipient of an EVE mail
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdMail>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MailRecipientType {
+    /// Recipient is an alliance
+    #[serde(rename = "alliance")]
+    Alliance,
+    /// Recipient is a character
+    #[serde(rename = "character")]
+    Character,
+    /// Recipient is a corporation
+    #[serde(rename = "corporation")]
+    Corporation,
+    /// Recipient is a mailing list
+    #[serde(rename = "mailing_list")]
+    MailingList,
+}
@@ -50,6 +50,26 @@ pub enum MarketOrderRange {
     Station,
 }
 
+impl fmt::Display for MarketOrderRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            MarketOrderRange::OneJump => "1",
+            MarketOrderRange::TwoJumps => "2",
+            MarketOrderRange::ThreeJumps => "3",
+            MarketOrderRange::FourJumps => "4",
+            MarketOrderRange::FiveJumps => "5",
+            MarketOrderRange::TenJumps => "10",
+            MarketOrderRange::TwentyJumps => "20",
+            MarketOrderRange::ThirtyJumps => "30",
+            MarketOrderRange::FourtyJumps => "40",
+            MarketOrderRange::Region => "region",
+            MarketOrderRange::SolarSystem => "solarsystem",
+            MarketOrderRange::Station => "station",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Indicates whether a historical market order expired or was cancelled
 ///
 /// # Documentation
@@ -97,7 +117,7 @@ impl fmt::Display for OrderType {
 
 #[cfg(test)]
 mod market_enum_tests {
-    use crate::model::enums::market::OrderType;
+    use crate::model::enums::market::{MarketOrderRange, OrderType};
 
     /// Ensures [`OrderType`] displays as string "buy"
     #[test]
@@ -116,4 +136,33 @@ mod market_enum_tests {
     fn test_order_type_all_display() {
         assert_eq!(OrderType::All.to_string(), "all")
     }
+
+    /// Ensures [`MarketOrderRange`] deserializes from both jump counts & named ranges
+    #[test]
+    fn test_market_order_range_deserialize() {
+        let ranges: Vec<MarketOrderRange> =
+            serde_json::from_str(r#"["1", "40", "region", "solarsystem", "station"]"#).unwrap();
+
+        assert_eq!(
+            ranges,
+            vec![
+                MarketOrderRange::OneJump,
+                MarketOrderRange::FourtyJumps,
+                MarketOrderRange::Region,
+                MarketOrderRange::SolarSystem,
+                MarketOrderRange::Station,
+            ]
+        );
+    }
+
+    /// Ensures [`MarketOrderRange`] displays as the same string ESI uses
+    #[test]
+    fn test_market_order_range_display() {
+        for range in [MarketOrderRange::TenJumps, MarketOrderRange::SolarSystem] {
+            assert_eq!(
+                serde_json::to_string(&range).unwrap(),
+                format!("\"{}\"", range)
+            );
+        }
+    }
 }
@@ -2,13 +2,52 @@
 //!
 //! Defines shared enums used across ESI models
 
+#[cfg(any(
+    feature = "assets",
+    feature = "character",
+    feature = "corporation",
+    feature = "fittings"
+))]
 pub mod asset;
+#[cfg(feature = "calendar")]
 pub mod calendar;
+#[cfg(any(feature = "character", feature = "corporation"))]
 pub mod character;
+#[cfg(feature = "clones")]
 pub mod clones;
+#[cfg(feature = "contacts")]
 pub mod contacts;
+#[cfg(feature = "contracts")]
 pub mod contract;
+#[cfg(any(feature = "character", feature = "corporation"))]
 pub mod corporation;
+#[cfg(feature = "corporation-projects")]
+pub mod corporation_projects;
+#[cfg(feature = "fleets")]
+pub mod fleet;
+#[cfg(feature = "industry")]
+pub mod industry;
+#[cfg(feature = "insurance")]
+pub mod insurance;
+#[cfg(feature = "mail")]
+pub mod mail;
+#[cfg(feature = "market")]
 pub mod market;
+#[cfg(feature = "character")]
 pub mod notification;
+#[cfg(feature = "planetary-interaction")]
+pub mod planetary_interaction;
+#[cfg(feature = "routes")]
+pub mod routes;
+#[cfg(feature = "search")]
+pub mod search;
 pub mod standing;
+#[cfg(any(
+    feature = "contracts",
+    feature = "dogma",
+    feature = "killmails",
+    feature = "universe"
+))]
+pub mod universe;
+#[cfg(feature = "wallet")]
+pub mod wallet;
@@ -520,3 +520,28 @@ pub enum NotificationType {
     /// War surrender offer has been received
     WarSurrenderOfferMsg,
 }
+
+impl NotificationType {
+    /// Notification types sent when a structure is attacked, reinforced or destroyed
+    ///
+    /// Includes Upwell structures, skyhooks, customs offices, mercenary dens, sovereignty structures
+    /// & POS towers, for use as the filter of
+    /// [`CharacterEndpoints::get_new_notifications`](crate::endpoints::character::CharacterEndpoints::get_new_notifications).
+    pub const STRUCTURE_ATTACKS: &'static [NotificationType] = &[
+        NotificationType::StructureUnderAttack,
+        NotificationType::StructureLostShields,
+        NotificationType::StructureLostArmor,
+        NotificationType::StructureDestroyed,
+        NotificationType::SkyhookUnderAttack,
+        NotificationType::SkyhookLostShields,
+        NotificationType::SkyhookDestroyed,
+        NotificationType::OrbitalAttacked,
+        NotificationType::OrbitalReinforced,
+        NotificationType::MercenaryDenAttacked,
+        NotificationType::MercenaryDenReinforced,
+        NotificationType::EntosisCaptureStarted,
+        NotificationType::SovStructureReinforced,
+        NotificationType::SovStructureDestroyed,
+        NotificationType::TowerAlertMsg,
+    ];
+}
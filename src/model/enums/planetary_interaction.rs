@@ -0,0 +1,37 @@
+//! # EVE ESI Planetary Interaction Enums
+//!
+//! Provides enums related to planetary interaction in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// The type of a planet
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdPlanets>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PlanetType {
+    /// Temperate planet
+    #[serde(rename = "temperate")]
+    Temperate,
+    /// Barren planet
+    #[serde(rename = "barren")]
+    Barren,
+    /// Oceanic planet
+    #[serde(rename = "oceanic")]
+    Oceanic,
+    /// Ice planet
+    #[serde(rename = "ice")]
+    Ice,
+    /// Gas planet
+    #[serde(rename = "gas")]
+    Gas,
+    /// Lava planet
+    #[serde(rename = "lava")]
+    Lava,
+    /// Storm planet
+    #[serde(rename = "storm")]
+    Storm,
+    /// Plasma planet
+    #[serde(rename = "plasma")]
+    Plasma,
+}
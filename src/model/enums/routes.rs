@@ -0,0 +1,22 @@
+//! # EVE ESI Routes Enums
+//!
+//! Provides enums related to routes in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// Route preference used when calculating a route between solar systems
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/GetRouteOriginDestination>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum RouteFlag {
+    /// Shortest route regardless of security status
+    #[serde(rename = "shortest")]
+    Shortest,
+    /// Route through high security space where possible
+    #[serde(rename = "secure")]
+    Secure,
+    /// Route through low & null security space where possible
+    #[serde(rename = "insecure")]
+    Insecure,
+}
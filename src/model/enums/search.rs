@@ -0,0 +1,101 @@
+//! # EVE ESI Search Enums
+//!
+//! Provides enums related to search in EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// Category of results returned by the character search endpoint
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/GetCharactersCharacterIdSearch>
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchCategory {
+    /// Agents
+    #[serde(rename = "agent")]
+    Agent,
+    /// Alliances
+    #[serde(rename = "alliance")]
+    Alliance,
+    /// Characters
+    #[serde(rename = "character")]
+    Character,
+    /// Constellations
+    #[serde(rename = "constellation")]
+    Constellation,
+    /// Corporations
+    #[serde(rename = "corporation")]
+    Corporation,
+    /// NPC factions
+    #[serde(rename = "faction")]
+    Faction,
+    /// Item types
+    #[serde(rename = "inventory_type")]
+    InventoryType,
+    /// Regions
+    #[serde(rename = "region")]
+    Region,
+    /// Solar systems
+    #[serde(rename = "solar_system")]
+    SolarSystem,
+    /// NPC stations
+    #[serde(rename = "station")]
+    Station,
+    /// Player structures the character has access to
+    #[serde(rename = "structure")]
+    Structure,
+}
+
+impl SearchCategory {
+    /// Returns the name of the category used by ESI, e.g. `solar_system`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Agent => "agent",
+            Self::Alliance => "alliance",
+            Self::Character => "character",
+            Self::Constellation => "constellation",
+            Self::Corporation => "corporation",
+            Self::Faction => "faction",
+            Self::InventoryType => "inventory_type",
+            Self::Region => "region",
+            Self::SolarSystem => "solar_system",
+            Self::Station => "station",
+            Self::Structure => "structure",
+        }
+    }
+
+    /// Joins categories into the comma separated list expected by the `categories` query parameter
+    ///
+    /// # Arguments
+    /// - `categories` (`&[SearchCategory]`): The categories to search
+    pub fn join(categories: &[Self]) -> String {
+        categories
+            .iter()
+            .map(SearchCategory::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod search_category_tests {
+    use super::*;
+
+    /// Tests that categories are joined using the names expected by ESI
+    ///
+    /// # Assertions
+    /// - Assert categories are joined with commas using their ESI names
+    /// - Assert the ESI names match the serialized names
+    #[test]
+    fn test_search_category_join() {
+        let categories = [SearchCategory::Character, SearchCategory::SolarSystem];
+
+        // Assert categories are joined with commas using their ESI names
+        assert_eq!(SearchCategory::join(&categories), "character,solar_system");
+
+        // Assert the ESI names match the serialized names
+        assert_eq!(
+            serde_json::to_value(SearchCategory::InventoryType).unwrap(),
+            SearchCategory::InventoryType.as_str()
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! # EVE ESI Universe Enums
+//!
+//! Provides enums related to the EVE Online universe
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::universe::{POCHVEN_REGION_ID, WORMHOLE_SYSTEM_IDS};
+
+/// The category of an ID resolved to a name
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseNamesPost>
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniverseNameCategory {
+    /// ID belongs to an alliance
+    #[serde(rename = "alliance")]
+    Alliance,
+    /// ID belongs to a character
+    #[serde(rename = "character")]
+    Character,
+    /// ID belongs to a constellation
+    #[serde(rename = "constellation")]
+    Constellation,
+    /// ID belongs to a corporation
+    #[serde(rename = "corporation")]
+    Corporation,
+    /// ID belongs to an inventory type
+    #[serde(rename = "inventory_type")]
+    InventoryType,
+    /// ID belongs to a region
+    #[serde(rename = "region")]
+    Region,
+    /// ID belongs to a solar system
+    #[serde(rename = "solar_system")]
+    SolarSystem,
+    /// ID belongs to an NPC station
+    #[serde(rename = "station")]
+    Station,
+    /// ID belongs to an NPC faction
+    #[serde(rename = "faction")]
+    Faction,
+}
+
+/// The security classification of a solar system
+///
+/// Classifies known space by the security status displayed in-game, which is rounded to 1
+/// decimal place, while wormhole space & Pochven are classified by their IDs as their security
+/// status doesn't reflect their rules. Not to be confused with the `security_class` field of
+/// [`SolarSystem`](crate::model::universe::SolarSystem), an internal letter code returned by ESI.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecurityClass {
+    /// High security space, displayed in-game as 0.5 to 1.0
+    #[serde(rename = "highsec")]
+    Highsec,
+    /// Low security space, displayed in-game as 0.1 to 0.4
+    #[serde(rename = "lowsec")]
+    Lowsec,
+    /// Null security space, displayed in-game as 0.0 or below
+    #[serde(rename = "nullsec")]
+    Nullsec,
+    /// Wormhole space, including Thera
+    #[serde(rename = "wormhole")]
+    Wormhole,
+    /// The Triglavian region of Pochven
+    #[serde(rename = "pochven")]
+    Pochven,
+}
+
+impl SecurityClass {
+    /// Classifies a solar system by its ID, region & security status
+    ///
+    /// # Arguments
+    /// - `system_id` (`i64`): The ID of the solar system
+    /// - `region_id` (`i64`): The ID of the region the solar system is in
+    /// - `security_status` (`f64`): The security status of the solar system, ranging from -1.0 to 1.0
+    pub fn new(system_id: i64, region_id: i64, security_status: f64) -> Self {
+        if WORMHOLE_SYSTEM_IDS.contains(&system_id) {
+            return Self::Wormhole;
+        }
+
+        if region_id == POCHVEN_REGION_ID {
+            return Self::Pochven;
+        }
+
+        Self::from_security_status(security_status)
+    }
+
+    /// Classifies known space by security status alone, for systems known not to be in wormhole space or Pochven
+    ///
+    /// Security statuses are rounded for display, systems from 0.45 upwards are high security &
+    /// systems above 0.0 but below 0.05 are displayed as 0.1, making them low security.
+    ///
+    /// # Arguments
+    /// - `security_status` (`f64`): The security status of the solar system, ranging from -1.0 to 1.0
+    pub fn from_security_status(security_status: f64) -> Self {
+        if security_status >= 0.45 {
+            Self::Highsec
+        } else if security_status > 0.0 {
+            Self::Lowsec
+        } else {
+            Self::Nullsec
+        }
+    }
+}
+
+#[cfg(test)]
+mod universe_enum_tests {
+    use crate::model::enums::universe::SecurityClass;
+
+    /// Ensures [`SecurityClass`] uses the rounded security status displayed in-game
+    ///
+    /// # Assertions
+    /// - Assert systems from 0.45 upwards are high security
+    /// - Assert systems above 0.0 up to 0.45 are low security, including those below 0.05
+    /// - Assert systems at 0.0 or below are null security
+    #[test]
+    fn test_security_class_from_security_status() {
+        // Assert systems from 0.45 upwards are high security
+        assert_eq!(
+            SecurityClass::from_security_status(1.0),
+            SecurityClass::Highsec
+        );
+        assert_eq!(
+            SecurityClass::from_security_status(0.45),
+            SecurityClass::Highsec
+        );
+
+        // Assert systems above 0.0 up to 0.45 are low security, including those below 0.05
+        assert_eq!(
+            SecurityClass::from_security_status(0.4499),
+            SecurityClass::Lowsec
+        );
+        assert_eq!(
+            SecurityClass::from_security_status(0.01),
+            SecurityClass::Lowsec
+        );
+
+        // Assert systems at 0.0 or below are null security
+        assert_eq!(
+            SecurityClass::from_security_status(0.0),
+            SecurityClass::Nullsec
+        );
+        assert_eq!(
+            SecurityClass::from_security_status(-0.8),
+            SecurityClass::Nullsec
+        );
+    }
+
+    /// Ensures wormhole space & Pochven are classified by ID regardless of security status
+    ///
+    /// # Assertions
+    /// - Assert wormhole systems are classified as wormhole space
+    /// - Assert systems in Pochven are classified as Pochven
+    /// - Assert other systems are classified by security status
+    #[test]
+    fn test_security_class_new() {
+        // Assert wormhole systems are classified as wormhole space
+        assert_eq!(
+            SecurityClass::new(31000005, 10000070, -1.0),
+            SecurityClass::Wormhole
+        );
+
+        // Assert systems in Pochven are classified as Pochven
+        assert_eq!(
+            SecurityClass::new(30003504, 10000070, -1.0),
+            SecurityClass::Pochven
+        );
+
+        // Assert other systems are classified by security status
+        assert_eq!(
+            SecurityClass::new(30000142, 10000002, 0.9459),
+            SecurityClass::Highsec
+        );
+    }
+}
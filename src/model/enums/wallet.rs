@@ -0,0 +1,515 @@
+//! # EVE ESI Wallet Enums
+//!
+//! Provides wallet-related enums for EVE Online ESI
+
+use serde::{Deserialize, Serialize};
+
+/// The type of reference a wallet journal entry was created for
+///
+/// Variants follow the names used by ESI including its misspellings.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdWalletJournalGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum WalletJournalRefType {
+    /// Acceleration gate fee
+    #[serde(rename = "acceleration_gate_fee")]
+    AccelerationGateFee,
+    /// Advertisement listing fee
+    #[serde(rename = "advertisement_listing_fee")]
+    AdvertisementListingFee,
+    /// Agent donation
+    #[serde(rename = "agent_donation")]
+    AgentDonation,
+    /// Agent location services
+    #[serde(rename = "agent_location_services")]
+    AgentLocationServices,
+    /// Agent miscellaneous
+    #[serde(rename = "agent_miscellaneous")]
+    AgentMiscellaneous,
+    /// Agent mission collateral paid
+    #[serde(rename = "agent_mission_collateral_paid")]
+    AgentMissionCollateralPaid,
+    /// Agent mission collateral refunded
+    #[serde(rename = "agent_mission_collateral_refunded")]
+    AgentMissionCollateralRefunded,
+    /// Agent mission reward
+    #[serde(rename = "agent_mission_reward")]
+    AgentMissionReward,
+    /// Agent mission reward corporation tax
+    #[serde(rename = "agent_mission_reward_corporation_tax")]
+    AgentMissionRewardCorporationTax,
+    /// Agent mission time bonus reward
+    #[serde(rename = "agent_mission_time_bonus_reward")]
+    AgentMissionTimeBonusReward,
+    /// Agent mission time bonus reward corporation tax
+    #[serde(rename = "agent_mission_time_bonus_reward_corporation_tax")]
+    AgentMissionTimeBonusRewardCorporationTax,
+    /// Agent security services
+    #[serde(rename = "agent_security_services")]
+    AgentSecurityServices,
+    /// Agent services rendered
+    #[serde(rename = "agent_services_rendered")]
+    AgentServicesRendered,
+    /// Agent mission preward
+    #[serde(rename = "agents_preward")]
+    AgentsPreward,
+    /// Alliance maintenance fee (misspelled by ESI)
+    #[serde(rename = "alliance_maintainance_fee")]
+    AllianceMaintainanceFee,
+    /// Alliance registration fee
+    #[serde(rename = "alliance_registration_fee")]
+    AllianceRegistrationFee,
+    /// Alignment based gate toll (misspelled by ESI)
+    #[serde(rename = "allignment_based_gate_toll")]
+    AllignmentBasedGateToll,
+    /// Asset safety recovery tax
+    #[serde(rename = "asset_safety_recovery_tax")]
+    AssetSafetyRecoveryTax,
+    /// Bounty
+    #[serde(rename = "bounty")]
+    Bounty,
+    /// Bounty prize
+    #[serde(rename = "bounty_prize")]
+    BountyPrize,
+    /// Bounty prize corporation tax
+    #[serde(rename = "bounty_prize_corporation_tax")]
+    BountyPrizeCorporationTax,
+    /// Bounty prizes, legacy grouped bounty payouts
+    #[serde(rename = "bounty_prizes")]
+    BountyPrizes,
+    /// Bounty reimbursement
+    #[serde(rename = "bounty_reimbursement")]
+    BountyReimbursement,
+    /// Bounty surcharge
+    #[serde(rename = "bounty_surcharge")]
+    BountySurcharge,
+    /// Broker's fee for placing a market order
+    #[serde(rename = "brokers_fee")]
+    BrokersFee,
+    /// Clone activation
+    #[serde(rename = "clone_activation")]
+    CloneActivation,
+    /// Clone transfer
+    #[serde(rename = "clone_transfer")]
+    CloneTransfer,
+    /// Contraband fine
+    #[serde(rename = "contraband_fine")]
+    ContrabandFine,
+    /// Contract auction bid
+    #[serde(rename = "contract_auction_bid")]
+    ContractAuctionBid,
+    /// Contract auction bid corp
+    #[serde(rename = "contract_auction_bid_corp")]
+    ContractAuctionBidCorp,
+    /// Contract auction bid refund
+    #[serde(rename = "contract_auction_bid_refund")]
+    ContractAuctionBidRefund,
+    /// Contract auction sold
+    #[serde(rename = "contract_auction_sold")]
+    ContractAuctionSold,
+    /// Contract brokers fee
+    #[serde(rename = "contract_brokers_fee")]
+    ContractBrokersFee,
+    /// Contract brokers fee corp
+    #[serde(rename = "contract_brokers_fee_corp")]
+    ContractBrokersFeeCorp,
+    /// Contract collateral
+    #[serde(rename = "contract_collateral")]
+    ContractCollateral,
+    /// Contract collateral deposited corp
+    #[serde(rename = "contract_collateral_deposited_corp")]
+    ContractCollateralDepositedCorp,
+    /// Contract collateral payout
+    #[serde(rename = "contract_collateral_payout")]
+    ContractCollateralPayout,
+    /// Contract collateral refund
+    #[serde(rename = "contract_collateral_refund")]
+    ContractCollateralRefund,
+    /// Contract deposit
+    #[serde(rename = "contract_deposit")]
+    ContractDeposit,
+    /// Contract deposit corp
+    #[serde(rename = "contract_deposit_corp")]
+    ContractDepositCorp,
+    /// Contract deposit refund
+    #[serde(rename = "contract_deposit_refund")]
+    ContractDepositRefund,
+    /// Contract deposit sales tax
+    #[serde(rename = "contract_deposit_sales_tax")]
+    ContractDepositSalesTax,
+    /// Contract price
+    #[serde(rename = "contract_price")]
+    ContractPrice,
+    /// Contract price payment corp
+    #[serde(rename = "contract_price_payment_corp")]
+    ContractPricePaymentCorp,
+    /// Contract reversal
+    #[serde(rename = "contract_reversal")]
+    ContractReversal,
+    /// Contract reward
+    #[serde(rename = "contract_reward")]
+    ContractReward,
+    /// Contract reward deposited
+    #[serde(rename = "contract_reward_deposited")]
+    ContractRewardDeposited,
+    /// Contract reward deposited corp
+    #[serde(rename = "contract_reward_deposited_corp")]
+    ContractRewardDepositedCorp,
+    /// Contract reward refund
+    #[serde(rename = "contract_reward_refund")]
+    ContractRewardRefund,
+    /// Contract sales tax
+    #[serde(rename = "contract_sales_tax")]
+    ContractSalesTax,
+    /// Copying
+    #[serde(rename = "copying")]
+    Copying,
+    /// Corporate reward payout
+    #[serde(rename = "corporate_reward_payout")]
+    CorporateRewardPayout,
+    /// Corporate reward tax
+    #[serde(rename = "corporate_reward_tax")]
+    CorporateRewardTax,
+    /// Corporation account withdrawal
+    #[serde(rename = "corporation_account_withdrawal")]
+    CorporationAccountWithdrawal,
+    /// Corporation bulk payment
+    #[serde(rename = "corporation_bulk_payment")]
+    CorporationBulkPayment,
+    /// Corporation dividend payment
+    #[serde(rename = "corporation_dividend_payment")]
+    CorporationDividendPayment,
+    /// Corporation liquidation
+    #[serde(rename = "corporation_liquidation")]
+    CorporationLiquidation,
+    /// Corporation logo change cost
+    #[serde(rename = "corporation_logo_change_cost")]
+    CorporationLogoChangeCost,
+    /// Corporation payment
+    #[serde(rename = "corporation_payment")]
+    CorporationPayment,
+    /// Corporation registration fee
+    #[serde(rename = "corporation_registration_fee")]
+    CorporationRegistrationFee,
+    /// Cosmetic market component item purchase
+    #[serde(rename = "cosmetic_market_component_item_purchase")]
+    CosmeticMarketComponentItemPurchase,
+    /// Cosmetic market skin purchase
+    #[serde(rename = "cosmetic_market_skin_purchase")]
+    CosmeticMarketSkinPurchase,
+    /// Cosmetic market skin sale
+    #[serde(rename = "cosmetic_market_skin_sale")]
+    CosmeticMarketSkinSale,
+    /// Cosmetic market skin sale broker fee
+    #[serde(rename = "cosmetic_market_skin_sale_broker_fee")]
+    CosmeticMarketSkinSaleBrokerFee,
+    /// Cosmetic market skin sale tax
+    #[serde(rename = "cosmetic_market_skin_sale_tax")]
+    CosmeticMarketSkinSaleTax,
+    /// Cosmetic market skin transaction
+    #[serde(rename = "cosmetic_market_skin_transaction")]
+    CosmeticMarketSkinTransaction,
+    /// Courier mission escrow
+    #[serde(rename = "courier_mission_escrow")]
+    CourierMissionEscrow,
+    /// CONCORD Spam Prevention Act charge for sending mail
+    #[serde(rename = "cspa")]
+    Cspa,
+    /// Refund of a CONCORD Spam Prevention Act charge
+    #[serde(rename = "cspaofflinerefund")]
+    Cspaofflinerefund,
+    /// Daily challenge reward
+    #[serde(rename = "daily_challenge_reward")]
+    DailyChallengeReward,
+    /// Daily goal payouts
+    #[serde(rename = "daily_goal_payouts")]
+    DailyGoalPayouts,
+    /// Daily goal payouts tax
+    #[serde(rename = "daily_goal_payouts_tax")]
+    DailyGoalPayoutsTax,
+    /// Datacore fee
+    #[serde(rename = "datacore_fee")]
+    DatacoreFee,
+    /// DNA modification fee
+    #[serde(rename = "dna_modification_fee")]
+    DnaModificationFee,
+    /// Docking fee
+    #[serde(rename = "docking_fee")]
+    DockingFee,
+    /// Duel wager escrow
+    #[serde(rename = "duel_wager_escrow")]
+    DuelWagerEscrow,
+    /// Duel wager payment
+    #[serde(rename = "duel_wager_payment")]
+    DuelWagerPayment,
+    /// Duel wager refund
+    #[serde(rename = "duel_wager_refund")]
+    DuelWagerRefund,
+    /// Transfer from an Encounter Surveillance System escrow
+    #[serde(rename = "ess_escrow_transfer")]
+    EssEscrowTransfer,
+    /// External trade delivery
+    #[serde(rename = "external_trade_delivery")]
+    ExternalTradeDelivery,
+    /// External trade freeze
+    #[serde(rename = "external_trade_freeze")]
+    ExternalTradeFreeze,
+    /// External trade thaw
+    #[serde(rename = "external_trade_thaw")]
+    ExternalTradeThaw,
+    /// Factory slot rental fee
+    #[serde(rename = "factory_slot_rental_fee")]
+    FactorySlotRentalFee,
+    /// Flux payout
+    #[serde(rename = "flux_payout")]
+    FluxPayout,
+    /// Flux tax
+    #[serde(rename = "flux_tax")]
+    FluxTax,
+    /// Flux ticket repayment
+    #[serde(rename = "flux_ticket_repayment")]
+    FluxTicketRepayment,
+    /// Flux ticket sale
+    #[serde(rename = "flux_ticket_sale")]
+    FluxTicketSale,
+    /// Freelance jobs broadcasting fee
+    #[serde(rename = "freelance_jobs_broadcasting_fee")]
+    FreelanceJobsBroadcastingFee,
+    /// Freelance jobs duration fee
+    #[serde(rename = "freelance_jobs_duration_fee")]
+    FreelanceJobsDurationFee,
+    /// Freelance jobs escrow refund
+    #[serde(rename = "freelance_jobs_escrow_refund")]
+    FreelanceJobsEscrowRefund,
+    /// Freelance jobs reward
+    #[serde(rename = "freelance_jobs_reward")]
+    FreelanceJobsReward,
+    /// Freelance jobs reward corporation tax
+    #[serde(rename = "freelance_jobs_reward_corporation_tax")]
+    FreelanceJobsRewardCorporationTax,
+    /// Freelance jobs reward escrow
+    #[serde(rename = "freelance_jobs_reward_escrow")]
+    FreelanceJobsRewardEscrow,
+    /// ISK transfer performed by a game master
+    #[serde(rename = "gm_cash_transfer")]
+    GmCashTransfer,
+    /// PLEX fee refund performed by a game master
+    #[serde(rename = "gm_plex_fee_refund")]
+    GmPlexFeeRefund,
+    /// Industry job tax
+    #[serde(rename = "industry_job_tax")]
+    IndustryJobTax,
+    /// Infrastructure hub maintenance
+    #[serde(rename = "infrastructure_hub_maintenance")]
+    InfrastructureHubMaintenance,
+    /// Inheritance
+    #[serde(rename = "inheritance")]
+    Inheritance,
+    /// Insurance
+    #[serde(rename = "insurance")]
+    Insurance,
+    /// Insurgency corruption contribution reward
+    #[serde(rename = "insurgency_corruption_contribution_reward")]
+    InsurgencyCorruptionContributionReward,
+    /// Insurgency suppression contribution reward
+    #[serde(rename = "insurgency_suppression_contribution_reward")]
+    InsurgencySuppressionContributionReward,
+    /// Item trader payment
+    #[serde(rename = "item_trader_payment")]
+    ItemTraderPayment,
+    /// Jump clone activation fee
+    #[serde(rename = "jump_clone_activation_fee")]
+    JumpCloneActivationFee,
+    /// Jump clone installation fee
+    #[serde(rename = "jump_clone_installation_fee")]
+    JumpCloneInstallationFee,
+    /// Kill right fee
+    #[serde(rename = "kill_right_fee")]
+    KillRightFee,
+    /// Loyalty point store purchase
+    #[serde(rename = "lp_store")]
+    LpStore,
+    /// Manufacturing
+    #[serde(rename = "manufacturing")]
+    Manufacturing,
+    /// Market escrow
+    #[serde(rename = "market_escrow")]
+    MarketEscrow,
+    /// Market fine paid
+    #[serde(rename = "market_fine_paid")]
+    MarketFinePaid,
+    /// Market provider tax
+    #[serde(rename = "market_provider_tax")]
+    MarketProviderTax,
+    /// Market transaction
+    #[serde(rename = "market_transaction")]
+    MarketTransaction,
+    /// Medal creation
+    #[serde(rename = "medal_creation")]
+    MedalCreation,
+    /// Medal issued
+    #[serde(rename = "medal_issued")]
+    MedalIssued,
+    /// Milestone reward payment
+    #[serde(rename = "milestone_reward_payment")]
+    MilestoneRewardPayment,
+    /// Mission completion
+    #[serde(rename = "mission_completion")]
+    MissionCompletion,
+    /// Mission cost
+    #[serde(rename = "mission_cost")]
+    MissionCost,
+    /// Mission expiration
+    #[serde(rename = "mission_expiration")]
+    MissionExpiration,
+    /// Mission reward
+    #[serde(rename = "mission_reward")]
+    MissionReward,
+    /// Office rental fee
+    #[serde(rename = "office_rental_fee")]
+    OfficeRentalFee,
+    /// Operation bonus
+    #[serde(rename = "operation_bonus")]
+    OperationBonus,
+    /// Opportunity reward
+    #[serde(rename = "opportunity_reward")]
+    OpportunityReward,
+    /// Planetary construction
+    #[serde(rename = "planetary_construction")]
+    PlanetaryConstruction,
+    /// Planetary export tax
+    #[serde(rename = "planetary_export_tax")]
+    PlanetaryExportTax,
+    /// Planetary import tax
+    #[serde(rename = "planetary_import_tax")]
+    PlanetaryImportTax,
+    /// Player donation
+    #[serde(rename = "player_donation")]
+    PlayerDonation,
+    /// Player trading
+    #[serde(rename = "player_trading")]
+    PlayerTrading,
+    /// Project discovery reward
+    #[serde(rename = "project_discovery_reward")]
+    ProjectDiscoveryReward,
+    /// Project discovery tax
+    #[serde(rename = "project_discovery_tax")]
+    ProjectDiscoveryTax,
+    /// Reaction
+    #[serde(rename = "reaction")]
+    Reaction,
+    /// Redeemed isk token
+    #[serde(rename = "redeemed_isk_token")]
+    RedeemedIskToken,
+    /// Release of impounded property
+    #[serde(rename = "release_of_impounded_property")]
+    ReleaseOfImpoundedProperty,
+    /// Repair bill
+    #[serde(rename = "repair_bill")]
+    RepairBill,
+    /// Reprocessing tax
+    #[serde(rename = "reprocessing_tax")]
+    ReprocessingTax,
+    /// Researching material productivity
+    #[serde(rename = "researching_material_productivity")]
+    ResearchingMaterialProductivity,
+    /// Researching technology
+    #[serde(rename = "researching_technology")]
+    ResearchingTechnology,
+    /// Researching time productivity
+    #[serde(rename = "researching_time_productivity")]
+    ResearchingTimeProductivity,
+    /// Resource wars reward
+    #[serde(rename = "resource_wars_reward")]
+    ResourceWarsReward,
+    /// Reverse engineering
+    #[serde(rename = "reverse_engineering")]
+    ReverseEngineering,
+    /// Season challenge reward
+    #[serde(rename = "season_challenge_reward")]
+    SeasonChallengeReward,
+    /// Security processing fee
+    #[serde(rename = "security_processing_fee")]
+    SecurityProcessingFee,
+    /// Shares
+    #[serde(rename = "shares")]
+    Shares,
+    /// Skill purchase
+    #[serde(rename = "skill_purchase")]
+    SkillPurchase,
+    /// Skyhook claim fee
+    #[serde(rename = "skyhook_claim_fee")]
+    SkyhookClaimFee,
+    /// Sovereignty bill (misspelled by ESI)
+    #[serde(rename = "sovereignity_bill")]
+    SovereignityBill,
+    /// Store purchase
+    #[serde(rename = "store_purchase")]
+    StorePurchase,
+    /// Store purchase refund
+    #[serde(rename = "store_purchase_refund")]
+    StorePurchaseRefund,
+    /// Structure gate jump
+    #[serde(rename = "structure_gate_jump")]
+    StructureGateJump,
+    /// Transaction tax
+    #[serde(rename = "transaction_tax")]
+    TransactionTax,
+    /// Upkeep adjustment fee
+    #[serde(rename = "upkeep_adjustment_fee")]
+    UpkeepAdjustmentFee,
+    /// War ally contract
+    #[serde(rename = "war_ally_contract")]
+    WarAllyContract,
+    /// War fee
+    #[serde(rename = "war_fee")]
+    WarFee,
+    /// War fee surrender
+    #[serde(rename = "war_fee_surrender")]
+    WarFeeSurrender,
+}
+
+/// The type of ID contained in the `context_id` of a wallet journal entry
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdWalletJournalGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum WalletJournalContextIdType {
+    /// Context ID is an alliance ID
+    #[serde(rename = "alliance_id")]
+    AllianceId,
+    /// Context ID is a character ID
+    #[serde(rename = "character_id")]
+    CharacterId,
+    /// Context ID is a contract ID
+    #[serde(rename = "contract_id")]
+    ContractId,
+    /// Context ID is a corporation ID
+    #[serde(rename = "corporation_id")]
+    CorporationId,
+    /// Context ID refers to an EVE system entity such as an NPC
+    #[serde(rename = "eve_system")]
+    EveSystem,
+    /// Context ID is an industry job ID
+    #[serde(rename = "industry_job_id")]
+    IndustryJobId,
+    /// Context ID is a market transaction ID
+    #[serde(rename = "market_transaction_id")]
+    MarketTransactionId,
+    /// Context ID is a planet ID
+    #[serde(rename = "planet_id")]
+    PlanetId,
+    /// Context ID is a station ID
+    #[serde(rename = "station_id")]
+    StationId,
+    /// Context ID is a structure ID
+    #[serde(rename = "structure_id")]
+    StructureId,
+    /// Context ID is a solar system ID
+    #[serde(rename = "system_id")]
+    SystemId,
+    /// Context ID is an item type ID
+    #[serde(rename = "type_id")]
+    TypeId,
+}
@@ -0,0 +1,238 @@
+//! # EVE ESI Fitting Models
+//!
+//! Provides fitting-related structs for EVE Online
+
+#[cfg(feature = "universe")]
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::requests::FittingItem;
+#[cfg(feature = "universe")]
+use crate::model::universe::ItemType;
+
+/// Dogma attribute ID of a ship's CPU output (`cpuOutput`)
+pub const CPU_OUTPUT_DOGMA_ID: i64 = 48;
+/// Dogma attribute ID of a module's CPU usage (`cpu`)
+pub const CPU_DOGMA_ID: i64 = 50;
+/// Dogma attribute ID of a ship's powergrid output (`powerOutput`)
+pub const POWER_OUTPUT_DOGMA_ID: i64 = 11;
+/// Dogma attribute ID of a module's powergrid usage (`power`)
+pub const POWER_DOGMA_ID: i64 = 30;
+/// Dogma attribute ID of a ship's calibration (`upgradeCapacity`)
+pub const UPGRADE_CAPACITY_DOGMA_ID: i64 = 1132;
+/// Dogma attribute ID of a rig's calibration cost (`upgradeCost`)
+pub const UPGRADE_COST_DOGMA_ID: i64 = 1153;
+
+/// A fitting saved by a character
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdFittingsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Fitting {
+    /// Description of the fitting
+    pub description: String,
+    /// Unique ID of the fitting
+    pub fitting_id: i64,
+    /// Items of the fitting
+    pub items: Vec<FittingItem>,
+    /// Name of the fitting
+    pub name: String,
+    /// Type ID of the ship the fitting is for
+    pub ship_type_id: i64,
+}
+
+/// The response to creating a new fitting for a character
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdFittings>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CreatedFitting {
+    /// The ID of the newly created fitting
+    pub fitting_id: i64,
+}
+
+/// Basic resource usage of a fitting calculated from dogma attributes
+///
+/// Values are the base values of the hull & modules, they do not account for skills, implants, or
+/// modules & rigs which modify the ship's CPU, powergrid, or calibration. Only items in fitting
+/// slots are included, see [`LocationFlag::is_fitting_slot`](crate::model::enums::asset::LocationFlag::is_fitting_slot).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FittingStats {
+    /// CPU used by fitted modules in tf
+    pub cpu_used: f64,
+    /// CPU output of the ship in tf
+    pub cpu_output: f64,
+    /// Powergrid used by fitted modules in MW
+    pub powergrid_used: f64,
+    /// Powergrid output of the ship in MW
+    pub powergrid_output: f64,
+    /// Calibration used by fitted rigs
+    pub calibration_used: f64,
+    /// Calibration of the ship
+    pub calibration_output: f64,
+}
+
+impl FittingStats {
+    /// Calculates the resource usage of a fitting
+    ///
+    /// Fitted items without an entry in `types` are skipped.
+    ///
+    /// Requires the `universe` feature.
+    ///
+    /// # Arguments
+    /// - `ship` (&[`ItemType`]): Item type information of the ship hull
+    /// - `items` (`&[`[`FittingItem`]`]`): The items of the fitting
+    /// - `types` (`&HashMap<i64, `[`ItemType`]`>`): Item type information of the fitting's items keyed by type ID
+    #[cfg(feature = "universe")]
+    pub fn calculate(
+        ship: &ItemType,
+        items: &[FittingItem],
+        types: &HashMap<i64, ItemType>,
+    ) -> Self {
+        let mut stats = Self {
+            cpu_used: 0.0,
+            cpu_output: ship.dogma_attribute(CPU_OUTPUT_DOGMA_ID).unwrap_or(0.0),
+            powergrid_used: 0.0,
+            powergrid_output: ship.dogma_attribute(POWER_OUTPUT_DOGMA_ID).unwrap_or(0.0),
+            calibration_used: 0.0,
+            calibration_output: ship
+                .dogma_attribute(UPGRADE_CAPACITY_DOGMA_ID)
+                .unwrap_or(0.0),
+        };
+
+        for item in items.iter().filter(|item| item.flag.is_fitting_slot()) {
+            let Some(item_type) = types.get(&item.type_id) else {
+                continue;
+            };
+            let quantity = item.quantity as f64;
+
+            stats.cpu_used += item_type.dogma_attribute(CPU_DOGMA_ID).unwrap_or(0.0) * quantity;
+            stats.powergrid_used +=
+                item_type.dogma_attribute(POWER_DOGMA_ID).unwrap_or(0.0) * quantity;
+            stats.calibration_used += item_type
+                .dogma_attribute(UPGRADE_COST_DOGMA_ID)
+                .unwrap_or(0.0)
+                * quantity;
+        }
+
+        stats
+    }
+
+    /// Returns the CPU remaining, negative if the fitting exceeds the ship's CPU output
+    pub fn cpu_remaining(&self) -> f64 {
+        self.cpu_output - self.cpu_used
+    }
+
+    /// Returns the powergrid remaining, negative if the fitting exceeds the ship's powergrid output
+    pub fn powergrid_remaining(&self) -> f64 {
+        self.powergrid_output - self.powergrid_used
+    }
+
+    /// Returns the calibration remaining, negative if the fitting exceeds the ship's calibration
+    pub fn calibration_remaining(&self) -> f64 {
+        self.calibration_output - self.calibration_used
+    }
+
+    /// Returns `true` if the fitting's CPU, powergrid, & calibration usage are within the ship's output
+    pub fn is_within_limits(&self) -> bool {
+        self.cpu_remaining() >= 0.0
+            && self.powergrid_remaining() >= 0.0
+            && self.calibration_remaining() >= 0.0
+    }
+}
+
+#[cfg(test)]
+mod fitting_stats_tests {
+    use super::*;
+    use crate::model::enums::asset::LocationFlag;
+    use crate::model::universe::TypeDogmaAttribute;
+
+    /// Creates an item type with the provided dogma attributes
+    fn item_type(type_id: i64, attributes: &[(i64, f64)]) -> ItemType {
+        ItemType {
+            capacity: None,
+            description: String::new(),
+            dogma_attributes: attributes
+                .iter()
+                .map(|(attribute_id, value)| TypeDogmaAttribute {
+                    attribute_id: *attribute_id,
+                    value: *value,
+                })
+                .collect(),
+            dogma_effects: Vec::new(),
+            graphic_id: None,
+            group_id: 0,
+            icon_id: None,
+            market_group_id: None,
+            mass: None,
+            name: String::new(),
+            packaged_volume: None,
+            portion_size: None,
+            published: true,
+            radius: None,
+            type_id,
+            volume: None,
+        }
+    }
+
+    /// Tests calculating the CPU, powergrid, & calibration usage of a fitting
+    ///
+    /// # Test Setup
+    /// - Create a ship with CPU, powergrid, & calibration output
+    /// - Create a fitting with 2 modules & a rig fitted plus modules in cargo
+    ///
+    /// # Assertions
+    /// - Assert only fitted items are included in the usage
+    /// - Assert the remaining resources & limits are calculated from the ship's output
+    #[test]
+    fn test_calculate_fitting_stats() {
+        let ship = item_type(
+            587,
+            &[
+                (CPU_OUTPUT_DOGMA_ID, 150.0),
+                (POWER_OUTPUT_DOGMA_ID, 40.0),
+                (UPGRADE_CAPACITY_DOGMA_ID, 400.0),
+            ],
+        );
+        let types = HashMap::from([
+            (
+                3082,
+                item_type(3082, &[(CPU_DOGMA_ID, 20.0), (POWER_DOGMA_ID, 8.0)]),
+            ),
+            (31117, item_type(31117, &[(UPGRADE_COST_DOGMA_ID, 150.0)])),
+        ]);
+        let items = vec![
+            FittingItem {
+                flag: LocationFlag::HiSlot0,
+                quantity: 1,
+                type_id: 3082,
+            },
+            FittingItem {
+                flag: LocationFlag::HiSlot1,
+                quantity: 1,
+                type_id: 3082,
+            },
+            FittingItem {
+                flag: LocationFlag::RigSlot0,
+                quantity: 1,
+                type_id: 31117,
+            },
+            FittingItem {
+                flag: LocationFlag::Cargo,
+                quantity: 10,
+                type_id: 3082,
+            },
+        ];
+
+        let stats = FittingStats::calculate(&ship, &items, &types);
+
+        assert_eq!(stats.cpu_used, 40.0);
+        assert_eq!(stats.powergrid_used, 16.0);
+        assert_eq!(stats.calibration_used, 150.0);
+        assert_eq!(stats.cpu_remaining(), 110.0);
+        assert_eq!(stats.powergrid_remaining(), 24.0);
+        assert_eq!(stats.calibration_remaining(), 250.0);
+        assert!(stats.is_within_limits());
+    }
+}
@@ -0,0 +1,287 @@
+//! # EVE ESI Industry Models
+//!
+//! Provides models related to industry endpoints for EVE Online's ESI API.
+
+#[cfg(feature = "market")]
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::enums::industry::{IndustryActivity, MiningObserverType};
+#[cfg(feature = "market")]
+use crate::model::market::MarketItemPrices;
+use crate::model::Isk;
+
+/// Rate of the SCC surcharge added to the install cost of every industry job, 4% of the estimated item value
+pub const SCC_SURCHARGE_RATE: f64 = 0.04;
+
+/// A moon mining extraction timer of a corporation's refinery
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationCorporationIdMiningExtractionsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MoonExtraction {
+    /// The time at which the chunk being extracted will arrive and can be fractured by the moon mining drill
+    pub chunk_arrival_time: DateTime<Utc>,
+    /// The time at which the current extraction was initiated
+    pub extraction_start_time: DateTime<Utc>,
+    /// The ID of the moon being extracted from
+    pub moon_id: i64,
+    /// The time at which the chunk being extracted will naturally fracture if it is not
+    /// first fractured by the moon mining drill (auto-fracture)
+    pub natural_decay_time: DateTime<Utc>,
+    /// The ID of the refinery structure performing the extraction
+    pub structure_id: i64,
+}
+
+/// A mining observer of a corporation, such as a refinery recording moon mining
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationCorporationIdMiningObserversGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MiningObserver {
+    /// The date the observer last recorded mining
+    pub last_updated: NaiveDate,
+    /// The ID of the observer, the structure ID for structure observers
+    pub observer_id: i64,
+    /// The type of the observer
+    pub observer_type: MiningObserverType,
+}
+
+/// An entry of ore mined by a character recorded by a corporation's mining observer
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationCorporationIdMiningObserversObserverIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MiningObserverEntry {
+    /// The ID of the character who mined the ore
+    pub character_id: i64,
+    /// The date the entry was last updated
+    pub last_updated: NaiveDate,
+    /// The quantity of ore mined
+    pub quantity: i64,
+    /// The ID of the corporation the character was a member of when the mining was recorded
+    pub recorded_corporation_id: i64,
+    /// The type ID of the ore mined
+    pub type_id: i64,
+}
+
+/// The industry cost indices of a solar system
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/IndustrySystemsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IndustrySystem {
+    /// The cost index of each industry activity in the solar system
+    pub cost_indices: Vec<IndustryCostIndex>,
+    /// The ID of the solar system
+    pub solar_system_id: i64,
+}
+
+impl IndustrySystem {
+    /// Returns the cost index of the provided activity in the solar system
+    pub fn cost_index(&self, activity: IndustryActivity) -> Option<f64> {
+        self.cost_indices
+            .iter()
+            .find(|cost_index| cost_index.activity == activity)
+            .map(|cost_index| cost_index.cost_index)
+    }
+}
+
+/// The cost index of an industry activity within a solar system
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/IndustrySystemsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IndustryCostIndex {
+    /// The industry activity
+    pub activity: IndustryActivity,
+    /// The fraction of the estimated item value charged to install a job of the activity
+    pub cost_index: f64,
+}
+
+/// A material required by a single run of a blueprint or reaction formula
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BlueprintMaterial {
+    /// The type ID of the material
+    pub type_id: i64,
+    /// The quantity of the material required per run, before material efficiency
+    pub quantity: u32,
+}
+
+/// The estimated install cost of a manufacturing or reaction job, see [`estimate_job_cost`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JobCostEstimate {
+    /// The estimated item value (EIV), the adjusted price of the job's materials for all runs
+    pub estimated_item_value: Isk,
+    /// The system cost, the estimated item value multiplied by the solar system's cost index
+    pub system_cost: Isk,
+    /// The facility tax charged by the station or structure owner
+    pub facility_tax: Isk,
+    /// The SCC surcharge, see [`SCC_SURCHARGE_RATE`]
+    pub scc_surcharge: Isk,
+    /// Type IDs of materials without an adjusted price, which are excluded from the estimated item value
+    pub unpriced_type_ids: Vec<i64>,
+}
+
+impl JobCostEstimate {
+    /// Returns the total ISK charged to install the job
+    pub fn total(&self) -> Isk {
+        self.system_cost + self.facility_tax + self.scc_surcharge
+    }
+}
+
+/// Estimates the install cost of a manufacturing or reaction job
+///
+/// The estimated item value is the sum of the adjusted price of each material multiplied by the
+/// quantity required per run, before material efficiency, & the number of runs. The install cost is
+/// the system cost, facility tax & SCC surcharge, each a fraction of the estimated item value.
+/// Structure rig & role bonuses reducing the system cost are not applied.
+///
+/// Requires the `market` feature.
+///
+/// # Arguments
+/// - `materials` (`&[BlueprintMaterial]`): Materials required per run of the blueprint or formula
+/// - `runs` (`u32`): The number of runs of the job
+/// - `prices` (`&[MarketItemPrices]`): Adjusted prices of items, see
+///   [`MarketEndpoints::list_market_prices`](crate::endpoints::market::MarketEndpoints::list_market_prices)
+/// - `cost_index` (`f64`): The solar system's cost index of the job's activity, see [`IndustrySystem::cost_index`]
+/// - `facility_tax_rate` (`f64`): The facility's tax rate, e.g. `0.0025` for the 0.25% of NPC stations
+///
+/// # Returns
+/// The estimated install cost of the job
+#[cfg(feature = "market")]
+pub fn estimate_job_cost(
+    materials: &[BlueprintMaterial],
+    runs: u32,
+    prices: &[MarketItemPrices],
+    cost_index: f64,
+    facility_tax_rate: f64,
+) -> JobCostEstimate {
+    let adjusted_prices: HashMap<i64, Isk> = prices
+        .iter()
+        .filter_map(|price| Some((price.type_id, price.adjusted_price?)))
+        .collect();
+
+    let mut estimated_item_value = Isk::default();
+    let mut unpriced_type_ids = Vec::new();
+
+    for material in materials {
+        match adjusted_prices.get(&material.type_id) {
+            Some(adjusted_price) => {
+                estimated_item_value += *adjusted_price * Isk::from(material.quantity)
+            }
+            None => unpriced_type_ids.push(material.type_id),
+        }
+    }
+
+    let estimated_item_value = estimated_item_value * Isk::from(runs);
+
+    JobCostEstimate {
+        estimated_item_value,
+        system_cost: estimated_item_value * isk_rate(cost_index),
+        facility_tax: estimated_item_value * isk_rate(facility_tax_rate),
+        scc_surcharge: estimated_item_value * isk_rate(SCC_SURCHARGE_RATE),
+        unpriced_type_ids,
+    }
+}
+
+/// Converts a rate such as a cost index into [`Isk`] to multiply ISK amounts by
+#[cfg(all(feature = "market", not(feature = "decimal")))]
+fn isk_rate(rate: f64) -> Isk {
+    rate
+}
+
+/// Converts a rate such as a cost index into [`Isk`] to multiply ISK amounts by
+#[cfg(all(feature = "market", feature = "decimal"))]
+fn isk_rate(rate: f64) -> Isk {
+    rust_decimal::prelude::FromPrimitive::from_f64(rate).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod job_cost_tests {
+    use super::*;
+
+    /// Tests the install cost of a job is estimated from adjusted prices & rates
+    ///
+    /// # Test Setup
+    /// - Create materials with adjusted prices & a material without an adjusted price
+    ///
+    /// # Assertions
+    /// - Assert the estimated item value sums the adjusted price of each material for all runs
+    /// - Assert the system cost, facility tax & SCC surcharge are fractions of the estimated item value
+    /// - Assert the material without an adjusted price is reported
+    #[test]
+    fn test_estimate_job_cost() {
+        let materials = vec![
+            BlueprintMaterial {
+                type_id: 34,
+                quantity: 100,
+            },
+            BlueprintMaterial {
+                type_id: 35,
+                quantity: 10,
+            },
+            BlueprintMaterial {
+                type_id: 36,
+                quantity: 1,
+            },
+        ];
+        let prices = vec![
+            MarketItemPrices {
+                adjusted_price: Some(Isk::from(4)),
+                average_price: Some(Isk::from(5)),
+                type_id: 34,
+            },
+            MarketItemPrices {
+                adjusted_price: Some(Isk::from(20)),
+                average_price: None,
+                type_id: 35,
+            },
+            MarketItemPrices {
+                adjusted_price: None,
+                average_price: Some(Isk::from(50)),
+                type_id: 36,
+            },
+        ];
+
+        let estimate = estimate_job_cost(&materials, 5, &prices, 0.5, 0.25);
+
+        // Assert the estimated item value sums the adjusted price of each material for all runs
+        assert_eq!(estimate.estimated_item_value, Isk::from(3_000));
+
+        // Assert the system cost, facility tax & SCC surcharge are fractions of the estimated item value
+        assert_eq!(estimate.system_cost, Isk::from(1_500));
+        assert_eq!(estimate.facility_tax, Isk::from(750));
+        assert_eq!(estimate.scc_surcharge, Isk::from(120));
+        assert_eq!(estimate.total(), Isk::from(2_370));
+
+        // Assert the material without an adjusted price is reported
+        assert_eq!(estimate.unpriced_type_ids, vec![36]);
+    }
+
+    /// Tests the cost index of an activity is found within a solar system's cost indices
+    ///
+    /// # Test Setup
+    /// - Create a solar system with a manufacturing cost index
+    ///
+    /// # Assertions
+    /// - Assert the manufacturing cost index is returned & the missing reaction cost index is not
+    #[test]
+    fn test_industry_system_cost_index() {
+        let system = IndustrySystem {
+            cost_indices: vec![IndustryCostIndex {
+                activity: IndustryActivity::Manufacturing,
+                cost_index: 0.0412,
+            }],
+            solar_system_id: 30000142,
+        };
+
+        assert_eq!(
+            system.cost_index(IndustryActivity::Manufacturing),
+            Some(0.0412)
+        );
+        assert_eq!(system.cost_index(IndustryActivity::Reaction), None);
+    }
+}
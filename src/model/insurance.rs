@@ -0,0 +1,193 @@
+//! # EVE ESI Insurance Models
+//!
+//! Provides insurance-related structs for EVE Online
+
+use serde::{Deserialize, Serialize};
+
+use super::enums::insurance::InsuranceLevel;
+#[cfg(feature = "killmails")]
+use super::killmail::Killmail;
+use super::Isk;
+
+/// The insurance levels available for an insurable ship type
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/InsurancePricesGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InsurancePrice {
+    /// The cost & payout of each insurance level available for the type
+    pub levels: Vec<InsuranceLevelPrice>,
+    /// The ID of the insurable ship type
+    pub type_id: i64,
+}
+
+impl InsurancePrice {
+    /// Returns the cost & payout of the provided insurance level if available for the type
+    pub fn level(&self, level: &InsuranceLevel) -> Option<&InsuranceLevelPrice> {
+        self.levels.iter().find(|price| price.name == *level)
+    }
+}
+
+/// The cost & payout of an insurance level for a ship type
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/InsurancePricesGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InsuranceLevelPrice {
+    /// The cost of the insurance policy
+    pub cost: Isk,
+    /// The insurance level
+    pub name: InsuranceLevel,
+    /// The amount of ISK paid out when the insured ship is destroyed
+    pub payout: Isk,
+}
+
+/// The estimated insurance payout for the ship lost in a killmail
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InsurancePayoutEstimate {
+    /// The ID of the killmail the ship was lost in
+    pub killmail_id: i64,
+    /// The type ID of the lost ship
+    pub ship_type_id: i64,
+    /// The insurance level the estimate is for
+    pub level: InsuranceLevel,
+    /// The cost of the insurance policy at current prices
+    pub cost: Isk,
+    /// The amount of ISK paid out for the lost ship at current prices
+    pub payout: Isk,
+}
+
+impl InsurancePayoutEstimate {
+    /// Returns the payout minus the cost of the insurance policy
+    pub fn net_payout(&self) -> Isk {
+        self.payout - self.cost
+    }
+}
+
+/// Estimates the insurance payout for the ship lost in a killmail from current insurance prices
+///
+/// Payouts are based on current prices, which may differ from the prices at the time the policy
+/// was purchased or the ship was lost.
+///
+/// Requires the `killmails` feature.
+///
+/// # Arguments
+/// - `prices` (`&[InsurancePrice]`): Insurance prices of all insurable types
+/// - `killmail` (`&Killmail`): The killmail of the lost ship
+/// - `level` (`&InsuranceLevel`): The insurance level the ship was insured at
+///
+/// # Returns
+/// The estimated payout, or `None` if the victim's ship type can't be insured at the provided level
+#[cfg(feature = "killmails")]
+pub fn estimate_payout(
+    prices: &[InsurancePrice],
+    killmail: &Killmail,
+    level: &InsuranceLevel,
+) -> Option<InsurancePayoutEstimate> {
+    let ship_type_id = killmail.victim.ship_type_id;
+
+    let price = prices
+        .iter()
+        .find(|price| price.type_id == ship_type_id)?
+        .level(level)?;
+
+    Some(InsurancePayoutEstimate {
+        killmail_id: killmail.killmail_id,
+        ship_type_id,
+        level: price.name.clone(),
+        cost: price.cost,
+        payout: price.payout,
+    })
+}
+
+#[cfg(test)]
+mod estimate_payout_tests {
+    use chrono::DateTime;
+
+    use super::*;
+    use crate::model::killmail::KillmailVictim;
+
+    fn mock_killmail(ship_type_id: i64) -> Killmail {
+        Killmail {
+            attackers: Vec::new(),
+            killmail_id: 81646519,
+            killmail_time: DateTime::default(),
+            moon_id: None,
+            solar_system_id: 30000142,
+            victim: KillmailVictim {
+                alliance_id: None,
+                character_id: Some(2114794365),
+                corporation_id: Some(98785281),
+                damage_taken: 1000,
+                faction_id: None,
+                items: Vec::new(),
+                position: None,
+                ship_type_id,
+            },
+            war_id: None,
+        }
+    }
+
+    fn mock_prices() -> Vec<InsurancePrice> {
+        vec![InsurancePrice {
+            levels: vec![
+                InsuranceLevelPrice {
+                    cost: Isk::from(0),
+                    name: InsuranceLevel::Basic,
+                    payout: Isk::from(100),
+                },
+                InsuranceLevelPrice {
+                    cost: Isk::from(300),
+                    name: InsuranceLevel::Platinum,
+                    payout: Isk::from(1000),
+                },
+            ],
+            type_id: 587,
+        }]
+    }
+
+    /// Tests the payout of the victim's hull at the provided level is estimated
+    ///
+    /// # Test Setup
+    /// - Create insurance prices for a Rifter & a killmail of a lost Rifter
+    ///
+    /// # Assertions
+    /// - Assert the estimate uses the cost & payout of the requested level
+    /// - Assert the net payout subtracts the cost from the payout
+    #[test]
+    fn test_estimate_payout() {
+        let estimate = estimate_payout(
+            &mock_prices(),
+            &mock_killmail(587),
+            &InsuranceLevel::Platinum,
+        )
+        .unwrap();
+
+        assert_eq!(estimate.killmail_id, 81646519);
+        assert_eq!(estimate.ship_type_id, 587);
+        assert_eq!(estimate.payout, Isk::from(1000));
+        assert_eq!(estimate.net_payout(), Isk::from(700));
+    }
+
+    /// Tests no estimate is returned for uninsurable hulls or unavailable levels
+    ///
+    /// # Test Setup
+    /// - Create insurance prices for a Rifter without a Gold level
+    ///
+    /// # Assertions
+    /// - Assert no estimate is returned for a hull without insurance prices
+    /// - Assert no estimate is returned for a level not available for the hull
+    #[test]
+    fn test_estimate_payout_uninsurable() {
+        let prices = mock_prices();
+
+        assert_eq!(
+            estimate_payout(&prices, &mock_killmail(670), &InsuranceLevel::Basic),
+            None
+        );
+        assert_eq!(
+            estimate_payout(&prices, &mock_killmail(587), &InsuranceLevel::Gold),
+            None
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! # EVE ESI Killmail Models
+//!
+//! Provides killmail-related structs for EVE Online
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::universe::UniversePosition;
+
+/// Reference to a killmail used to retrieve the killmail's details
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdKillmailsRecentGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KillmailReference {
+    /// A hash of the killmail, required alongside the ID to retrieve the killmail
+    pub killmail_hash: String,
+    /// The ID of the killmail
+    pub killmail_id: i64,
+}
+
+/// A killmail's details
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/KillmailsKillmailIdKillmailHashGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Killmail {
+    /// The characters, corporations & NPCs which were involved in the kill
+    pub attackers: Vec<KillmailAttacker>,
+    /// The ID of the killmail
+    pub killmail_id: i64,
+    /// Date and time the kill occurred
+    pub killmail_time: DateTime<Utc>,
+    /// The ID of the moon the kill occurred at, if any
+    pub moon_id: Option<i64>,
+    /// The ID of the solar system the kill occurred in
+    pub solar_system_id: i64,
+    /// The victim of the kill
+    pub victim: KillmailVictim,
+    /// The ID of the war the kill occurred in, if any
+    pub war_id: Option<i64>,
+}
+
+/// An attacker involved in a kill
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/KillmailsKillmailIdKillmailHashGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KillmailAttacker {
+    /// The alliance ID of the attacker, if any
+    pub alliance_id: Option<i64>,
+    /// The character ID of the attacker, None for NPCs & structures
+    pub character_id: Option<i64>,
+    /// The corporation ID of the attacker, if any
+    pub corporation_id: Option<i64>,
+    /// The amount of damage dealt to the victim by the attacker
+    pub damage_done: i64,
+    /// The faction ID of the attacker, if any
+    pub faction_id: Option<i64>,
+    /// True if the attacker dealt the final blow
+    pub final_blow: bool,
+    /// Security status of the attacker at the time of the kill
+    pub security_status: f64,
+    /// The type ID of the ship flown by the attacker
+    pub ship_type_id: Option<i64>,
+    /// The type ID of the weapon used by the attacker
+    pub weapon_type_id: Option<i64>,
+}
+
+/// The victim of a kill
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/KillmailsKillmailIdKillmailHashGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KillmailVictim {
+    /// The alliance ID of the victim, if any
+    pub alliance_id: Option<i64>,
+    /// The character ID of the victim, None for structures
+    pub character_id: Option<i64>,
+    /// The corporation ID of the victim, if any
+    pub corporation_id: Option<i64>,
+    /// The total amount of damage the victim took
+    pub damage_taken: i64,
+    /// The faction ID of the victim, if any
+    pub faction_id: Option<i64>,
+    /// The items fitted to or carried by the victim's ship
+    #[serde(default)]
+    pub items: Vec<KillmailItem>,
+    /// Coordinates of the victim in space
+    pub position: Option<UniversePosition>,
+    /// The type ID of the ship the victim was flying
+    pub ship_type_id: i64,
+}
+
+/// An item fitted to or carried by a victim's ship
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/KillmailsKillmailIdKillmailHashGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct KillmailItem {
+    /// The inventory flag of the location the item was in
+    pub flag: i64,
+    /// The type ID of the item
+    pub item_type_id: i64,
+    /// Items contained within this item, such as the contents of a container
+    #[serde(default)]
+    pub items: Vec<KillmailItem>,
+    /// The quantity of the item destroyed, if any
+    pub quantity_destroyed: Option<i64>,
+    /// The quantity of the item dropped, if any
+    pub quantity_dropped: Option<i64>,
+    /// Whether the item is a singleton (2 for blueprint copies)
+    pub singleton: i64,
+}
@@ -0,0 +1,126 @@
+//! # EVE ESI Location Models
+//!
+//! Provides location-related structs for EVE Online
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A character's online status
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdOnlineGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterOnlineStatus {
+    /// Date and time of the character's last login
+    pub last_login: Option<DateTime<Utc>>,
+    /// Date and time of the character's last logout
+    pub last_logout: Option<DateTime<Utc>>,
+    /// Total number of times the character has logged in
+    pub logins: Option<i64>,
+    /// Whether the character is currently online
+    pub online: bool,
+}
+
+/// The solar system & the station or structure a character is in
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdLocationGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterLocation {
+    /// The ID of the solar system the character is in
+    pub solar_system_id: i64,
+    /// The ID of the station the character is docked in, if docked in a station
+    pub station_id: Option<i64>,
+    /// The ID of the structure the character is docked in, if docked in a structure
+    pub structure_id: Option<i64>,
+}
+
+impl CharacterLocation {
+    /// Returns `true` if the character is docked in a station or structure
+    pub fn is_docked(&self) -> bool {
+        self.station_id.is_some() || self.structure_id.is_some()
+    }
+}
+
+/// The ship a character is currently flying
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdShipGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterShip {
+    /// The unique item ID of the ship, which stays the same when it is repackaged
+    pub ship_item_id: i64,
+    /// The name the character gave the ship
+    pub ship_name: String,
+    /// The type ID of the ship
+    pub ship_type_id: i64,
+}
+
+/// A character's location, ship & online status with resolved names
+///
+/// Returned by [`LocationEndpoints::get_character_presence`](crate::endpoints::location::LocationEndpoints::get_character_presence)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterPresence {
+    /// The ID of the character
+    pub character_id: i64,
+    /// The solar system & the station or structure the character is in
+    pub location: CharacterLocation,
+    /// The name of the solar system the character is in
+    pub solar_system_name: String,
+    /// The name of the station the character is docked in, if docked in a station
+    pub station_name: Option<String>,
+    /// The ship the character is currently flying
+    pub ship: CharacterShip,
+    /// The name of the type of the ship, e.g. `Rifter`
+    pub ship_type_name: String,
+    /// The character's online status
+    pub online: CharacterOnlineStatus,
+}
+
+impl CharacterPresence {
+    /// Returns `true` if the character is currently online
+    pub fn is_online(&self) -> bool {
+        self.online.online
+    }
+
+    /// Returns `true` if the character is docked in a station or structure
+    pub fn is_docked(&self) -> bool {
+        self.location.is_docked()
+    }
+}
+
+/// A change of a character's online status observed by
+/// [`LocationEndpoints::online_status_feed`](crate::endpoints::location::LocationEndpoints::online_status_feed)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum OnlineStatusEvent {
+    /// The character logged in, or was online when first polled
+    Online {
+        /// The ID of the character
+        character_id: i64,
+        /// The character's online status when polled
+        status: CharacterOnlineStatus,
+    },
+    /// The character logged out, or was offline when first polled
+    Offline {
+        /// The ID of the character
+        character_id: i64,
+        /// The character's online status when polled
+        status: CharacterOnlineStatus,
+    },
+}
+
+impl OnlineStatusEvent {
+    /// Returns the ID of the character whose online status changed
+    pub fn character_id(&self) -> i64 {
+        match self {
+            Self::Online { character_id, .. } | Self::Offline { character_id, .. } => *character_id,
+        }
+    }
+
+    /// Returns the character's online status when polled
+    pub fn status(&self) -> &CharacterOnlineStatus {
+        match self {
+            Self::Online { status, .. } | Self::Offline { status, .. } => status,
+        }
+    }
+}
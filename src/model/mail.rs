@@ -0,0 +1,343 @@
+//! # EVE ESI Mail Models
+//!
+//! EVE mail bodies are returned by ESI with the subset of HTML used by the game client, e.g. font &
+//! color tags & links such as `<a href="showinfo:1377//2114794365">Hyziri</a>`. Provides
+//! [`MailBody`] to convert a body into plain text & extract its links as typed [`MailLink`]s.
+//!
+//! ## Usage Example
+//!
+//! ```
+//! use eve_esi::model::mail::{MailBody, MailLinkTarget};
+//!
+//! let body = MailBody::parse(
+//!     "<font size=\"12\">Fly safe <a href=\"showinfo:1377//2114794365\">Hyziri</a></font>",
+//! );
+//!
+//! assert_eq!(body.text(), "Fly safe Hyziri");
+//! assert_eq!(body.links()[0].target, MailLinkTarget::Character(2114794365));
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::markup::strip_markup;
+
+/// Type ID used by `showinfo` links to corporations
+const CORPORATION_TYPE_ID: i64 = 2;
+/// Type ID used by `showinfo` links to regions
+const REGION_TYPE_ID: i64 = 3;
+/// Type ID used by `showinfo` links to constellations
+const CONSTELLATION_TYPE_ID: i64 = 4;
+/// Type ID used by `showinfo` links to solar systems
+const SOLAR_SYSTEM_TYPE_ID: i64 = 5;
+/// Type ID used by `showinfo` links to alliances
+const ALLIANCE_TYPE_ID: i64 = 16159;
+/// Type IDs used by `showinfo` links to characters, one for each bloodline
+const CHARACTER_TYPE_IDS: std::ops::RangeInclusive<i64> = 1373..=1386;
+/// Range of IDs assigned to NPC stations
+const NPC_STATION_IDS: std::ops::Range<i64> = 60_000_000..64_000_000;
+
+/// An EVE mail body converted into plain text with the links it contains
+///
+/// For an overview & usage example, see the [module-level documentation](self)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MailBody {
+    text: String,
+    links: Vec<MailLink>,
+}
+
+impl MailBody {
+    /// Parses a mail body returned by ESI
+    ///
+    /// The text is converted with [`strip_markup`], keeping the text of links while removing the
+    /// links themselves. Links are returned in the order they appear in the body.
+    ///
+    /// # Arguments
+    /// - `body` (`&str`): The mail body containing in-game markup
+    pub fn parse(body: &str) -> Self {
+        Self {
+            text: strip_markup(body),
+            links: extract_links(body),
+        }
+    }
+
+    /// Returns the body as plain text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the links within the body
+    pub fn links(&self) -> &[MailLink] {
+        &self.links
+    }
+}
+
+/// A link within an EVE mail body
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MailLink {
+    /// The text displayed for the link, as plain text
+    pub text: String,
+    /// What the link refers to
+    pub target: MailLinkTarget,
+}
+
+/// What a link within an EVE mail body refers to
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum MailLinkTarget {
+    /// A character, with the character's ID
+    Character(i64),
+    /// A corporation, with the corporation's ID
+    Corporation(i64),
+    /// An alliance, with the alliance's ID
+    Alliance(i64),
+    /// A solar system, with the solar system's ID
+    SolarSystem(i64),
+    /// A constellation, with the constellation's ID
+    Constellation(i64),
+    /// A region, with the region's ID
+    Region(i64),
+    /// An NPC station
+    Station {
+        /// The type ID of the station
+        type_id: i64,
+        /// The ID of the station
+        station_id: i64,
+    },
+    /// An item type, or a specific item such as a player structure when `item_id` is set
+    Item {
+        /// The type ID of the item
+        type_id: i64,
+        /// The ID of the specific item
+        item_id: Option<i64>,
+    },
+    /// A killmail
+    Killmail {
+        /// The ID of the killmail
+        killmail_id: i64,
+        /// The hash of the killmail, required alongside the ID to retrieve the killmail
+        killmail_hash: String,
+    },
+    /// A web URL
+    Url(String),
+    /// Any other link, such as a fitting or chat channel, with the link's `href`
+    Other(String),
+}
+
+impl MailLinkTarget {
+    /// Parses the `href` of a link within an EVE mail body
+    ///
+    /// # Arguments
+    /// - `href` (`&str`): The `href` attribute of the link, e.g. `showinfo:1377//2114794365`
+    pub fn parse(href: &str) -> Self {
+        if let Some(info) = href.strip_prefix("showinfo:") {
+            if let Some(target) = Self::parse_showinfo(info) {
+                return target;
+            }
+        }
+
+        if let Some(killmail) = href.strip_prefix("killReport:") {
+            if let Some((id, hash)) = killmail.split_once(':') {
+                if let Ok(killmail_id) = id.parse() {
+                    return Self::Killmail {
+                        killmail_id,
+                        killmail_hash: hash.to_string(),
+                    };
+                }
+            }
+        }
+
+        if href.starts_with("http://") || href.starts_with("https://") {
+            return Self::Url(href.to_string());
+        }
+
+        Self::Other(href.to_string())
+    }
+
+    /// Parses the `<type ID>//<item ID>` or `<type ID>` following `showinfo:`
+    fn parse_showinfo(info: &str) -> Option<Self> {
+        let (type_id, item_id) = match info.split_once("//") {
+            Some((type_id, item_id)) => (type_id.parse().ok()?, Some(item_id.parse().ok()?)),
+            None => (info.parse().ok()?, None),
+        };
+
+        let Some(id) = item_id else {
+            return Some(Self::Item {
+                type_id,
+                item_id: None,
+            });
+        };
+
+        let target = match type_id {
+            CORPORATION_TYPE_ID => Self::Corporation(id),
+            REGION_TYPE_ID => Self::Region(id),
+            CONSTELLATION_TYPE_ID => Self::Constellation(id),
+            SOLAR_SYSTEM_TYPE_ID => Self::SolarSystem(id),
+            ALLIANCE_TYPE_ID => Self::Alliance(id),
+            _ if CHARACTER_TYPE_IDS.contains(&type_id) => Self::Character(id),
+            _ if NPC_STATION_IDS.contains(&id) => Self::Station {
+                type_id,
+                station_id: id,
+            },
+            _ => Self::Item {
+                type_id,
+                item_id: Some(id),
+            },
+        };
+
+        Some(target)
+    }
+}
+
+/// Extracts each `<a href="...">` link from a mail body, links without an `href` are skipped
+fn extract_links(body: &str) -> Vec<MailLink> {
+    let mut links = Vec::new();
+    let mut rest = body;
+
+    while let Some(start) = find_ascii_case_insensitive(rest, "<a ") {
+        rest = &rest[start + 3..];
+
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let href = attribute_value(&rest[..tag_end], "href");
+        rest = &rest[tag_end + 1..];
+
+        let text_end = find_ascii_case_insensitive(rest, "</a>").unwrap_or(rest.len());
+        let text = strip_markup(&rest[..text_end]);
+        rest = &rest[text_end..];
+
+        if let Some(href) = href {
+            links.push(MailLink {
+                text,
+                target: MailLinkTarget::parse(&strip_markup(href)),
+            });
+        }
+    }
+
+    links
+}
+
+/// Returns the value of an attribute within the contents of a tag, quoted or unquoted
+fn attribute_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = find_ascii_case_insensitive(tag, &format!("{}=", name))? + name.len() + 1;
+    let value = &tag[start..];
+
+    match value.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let value = &value[1..];
+            value.find(quote).map(|end| &value[..end])
+        }
+        _ => Some(value.split_whitespace().next().unwrap_or(value)),
+    }
+}
+
+/// Returns the byte index of the first match of an ASCII needle, ignoring ASCII case
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+#[cfg(test)]
+mod mail_body_tests {
+    use super::*;
+
+    /// Tests converting a mail body with font tags, line breaks & links into plain text
+    ///
+    /// # Assertions
+    /// - Assert tags are removed, line breaks kept & link text kept
+    /// - Assert each link is extracted in order with its plain text
+    #[test]
+    fn test_parse_mail_body() {
+        let body = MailBody::parse(concat!(
+            "<font size=\"12\" color=\"#bfffffff\">Welcome to ",
+            "<a href=\"showinfo:2//98785281\"><b>The Order of Autumn</b></a>!<br>",
+            "Stage at <A HREF='showinfo:1529//60003760'>Jita IV - Moon 4</A>, ",
+            "read <a href=\"https://example.com/guide?a=1&amp;b=2\">the guide</a>.</font>"
+        ));
+
+        // Assert tags are removed, line breaks kept & link text kept
+        assert_eq!(
+            body.text(),
+            "Welcome to The Order of Autumn!\nStage at Jita IV - Moon 4, read the guide."
+        );
+
+        // Assert each link is extracted in order with its plain text
+        assert_eq!(
+            body.links(),
+            &[
+                MailLink {
+                    text: "The Order of Autumn".to_string(),
+                    target: MailLinkTarget::Corporation(98785281),
+                },
+                MailLink {
+                    text: "Jita IV - Moon 4".to_string(),
+                    target: MailLinkTarget::Station {
+                        type_id: 1529,
+                        station_id: 60003760
+                    },
+                },
+                MailLink {
+                    text: "the guide".to_string(),
+                    target: MailLinkTarget::Url("https://example.com/guide?a=1&b=2".to_string()),
+                },
+            ]
+        );
+    }
+
+    /// Tests the targets of the link types used in EVE mail bodies
+    ///
+    /// # Assertions
+    /// - Assert showinfo links are typed by their type ID
+    /// - Assert killmail links are parsed with their hash
+    /// - Assert malformed & unknown links are kept as other links
+    #[test]
+    fn test_parse_mail_link_targets() {
+        // Assert showinfo links are typed by their type ID
+        assert_eq!(
+            MailLinkTarget::parse("showinfo:1377//2114794365"),
+            MailLinkTarget::Character(2114794365)
+        );
+        assert_eq!(
+            MailLinkTarget::parse("showinfo:16159//99013534"),
+            MailLinkTarget::Alliance(99013534)
+        );
+        assert_eq!(
+            MailLinkTarget::parse("showinfo:5//30000142"),
+            MailLinkTarget::SolarSystem(30000142)
+        );
+        assert_eq!(
+            MailLinkTarget::parse("showinfo:587"),
+            MailLinkTarget::Item {
+                type_id: 587,
+                item_id: None
+            }
+        );
+        assert_eq!(
+            MailLinkTarget::parse("showinfo:35834//1035466617946"),
+            MailLinkTarget::Item {
+                type_id: 35834,
+                item_id: Some(1035466617946)
+            }
+        );
+
+        // Assert killmail links are parsed with their hash
+        assert_eq!(
+            MailLinkTarget::parse("killReport:128734567:2f3a9c"),
+            MailLinkTarget::Killmail {
+                killmail_id: 128734567,
+                killmail_hash: "2f3a9c".to_string()
+            }
+        );
+
+        // Assert malformed & unknown links are kept as other links
+        assert_eq!(
+            MailLinkTarget::parse("showinfo:abc//1"),
+            MailLinkTarget::Other("showinfo:abc//1".to_string())
+        );
+        assert_eq!(
+            MailLinkTarget::parse("joinChannel:-12345"),
+            MailLinkTarget::Other("joinChannel:-12345".to_string())
+        );
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! Provides models related to market endpoints for EVE Online's ESI API.
 
-use chrono::{DateTime, NaiveDate, Utc};
+use std::cmp::Ordering;
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::model::enums::market::{HistoricalMarketOrderState, MarketOrderRange};
+use crate::model::HasTypeId;
+use crate::model::Isk;
 
 /// Details for a character's market order
 ///
@@ -20,7 +24,7 @@ pub struct CharacterMarketOrder {
     /// An order expires at time issued + duration
     pub duration: i64,
     /// For buy orders, the amount of ISK in escrow
-    pub escrow: Option<f64>,
+    pub escrow: Option<Isk>,
     /// True if the order is a buy order
     #[serde(default)]
     pub is_buy_order: bool,
@@ -35,7 +39,7 @@ pub struct CharacterMarketOrder {
     /// Unique ID of the order
     pub order_id: i64,
     /// The cost per unit for this order
-    pub price: f64,
+    pub price: Isk,
     /// The range of the order
     pub range: MarketOrderRange,
     /// ID of the region where the order was placed
@@ -50,6 +54,40 @@ pub struct CharacterMarketOrder {
     pub volume_total: i64,
 }
 
+impl HasTypeId for CharacterMarketOrder {
+    fn type_id(&self) -> i64 {
+        self.type_id
+    }
+}
+
+impl CharacterMarketOrder {
+    /// Returns the date the order expires, calculated as the issued date plus the duration in days
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.issued + TimeDelta::days(self.duration)
+    }
+
+    /// Returns the best competing order if the order has been undercut
+    ///
+    /// See [`UndercutOrder`] for which orders compete with the order.
+    ///
+    /// # Arguments
+    /// - `region_orders` (&[[`MarketRegionOrder`]]): Current orders of the order's region, such as
+    ///   from [`MarketEndpoints::list_orders_in_a_region`](crate::endpoints::market::MarketEndpoints::list_orders_in_a_region)
+    ///
+    /// # Returns
+    /// The order & the best competing price, `None` if no competing order has a better price
+    pub fn undercut_by(&self, region_orders: &[MarketRegionOrder]) -> Option<UndercutOrder> {
+        UndercutOrder::find(
+            self.order_id,
+            self.type_id,
+            self.location_id,
+            self.is_buy_order,
+            self.price,
+            region_orders,
+        )
+    }
+}
+
 /// Details for a corporation's market order
 ///
 /// # Documentation
@@ -63,7 +101,7 @@ pub struct CorporationMarketOrder {
     /// An order expires at time issued + duration
     pub duration: i64,
     /// For buy orders, the amount of ISK in escrow
-    pub escrow: Option<f64>,
+    pub escrow: Option<Isk>,
     /// True if the order is a buy order
     #[serde(default)]
     pub is_buy_order: bool,
@@ -78,7 +116,7 @@ pub struct CorporationMarketOrder {
     /// Unique ID of the order
     pub order_id: i64,
     /// The cost per unit for this order
-    pub price: f64,
+    pub price: Isk,
     /// The range of the order
     pub range: MarketOrderRange,
     /// ID of the region where the order was placed
@@ -93,6 +131,121 @@ pub struct CorporationMarketOrder {
     pub volume_total: i64,
 }
 
+impl HasTypeId for CorporationMarketOrder {
+    fn type_id(&self) -> i64 {
+        self.type_id
+    }
+}
+
+impl CorporationMarketOrder {
+    /// Returns the date the order expires, calculated as the issued date plus the duration in days
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.issued + TimeDelta::days(self.duration)
+    }
+
+    /// Returns the best competing order if the order has been undercut
+    ///
+    /// See [`UndercutOrder`] for which orders compete with the order.
+    ///
+    /// # Arguments
+    /// - `region_orders` (&[[`MarketRegionOrder`]]): Current orders of the order's region, such as
+    ///   from [`MarketEndpoints::list_orders_in_a_region`](crate::endpoints::market::MarketEndpoints::list_orders_in_a_region)
+    ///
+    /// # Returns
+    /// The order & the best competing price, `None` if no competing order has a better price
+    pub fn undercut_by(&self, region_orders: &[MarketRegionOrder]) -> Option<UndercutOrder> {
+        UndercutOrder::find(
+            self.order_id,
+            self.type_id,
+            self.location_id,
+            self.is_buy_order,
+            self.price,
+            region_orders,
+        )
+    }
+}
+
+/// An open market order with a better priced competing order at the same location
+///
+/// Competing orders are orders of the same item type & side of the market at the same location,
+/// a sell order is undercut by a cheaper sell order while a buy order is outbid by a higher buy
+/// order. Orders with the same price are not considered undercut.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UndercutOrder {
+    /// ID of the undercut order
+    pub order_id: i64,
+    /// The type ID of the item in the order
+    pub type_id: i64,
+    /// ID of the location where the order was placed
+    pub location_id: i64,
+    /// True if the order is a buy order
+    pub is_buy_order: bool,
+    /// The cost per unit of the undercut order
+    pub price: Isk,
+    /// ID of the competing order with the best price
+    pub competing_order_id: i64,
+    /// The best price of the competing orders, the lowest sell or the highest buy price
+    pub competing_price: Isk,
+}
+
+impl UndercutOrder {
+    /// Returns the difference between the order's price & the best competing price
+    pub fn price_difference(&self) -> Isk {
+        (self.price - self.competing_price).abs()
+    }
+
+    /// Finds the best priced competing order which undercuts the order, if any
+    fn find(
+        order_id: i64,
+        type_id: i64,
+        location_id: i64,
+        is_buy_order: bool,
+        price: Isk,
+        region_orders: &[MarketRegionOrder],
+    ) -> Option<Self> {
+        // Orders which are better priced for the counterparty, higher buys or lower sells
+        let better = |competitor: &MarketRegionOrder| {
+            let ordering = competitor
+                .price
+                .partial_cmp(&price)
+                .unwrap_or(Ordering::Equal);
+
+            if is_buy_order {
+                ordering == Ordering::Greater
+            } else {
+                ordering == Ordering::Less
+            }
+        };
+
+        let competitors = region_orders.iter().filter(|competitor| {
+            competitor.order_id != order_id
+                && competitor.type_id == type_id
+                && competitor.location_id == location_id
+                && competitor.is_buy_order == is_buy_order
+                && better(competitor)
+        });
+
+        let compare = |a: &&MarketRegionOrder, b: &&MarketRegionOrder| {
+            a.price.partial_cmp(&b.price).unwrap_or(Ordering::Equal)
+        };
+        let best = if is_buy_order {
+            competitors.max_by(compare)
+        } else {
+            competitors.min_by(compare)
+        }?;
+
+        Some(Self {
+            order_id,
+            type_id,
+            location_id,
+            is_buy_order,
+            price,
+            competing_order_id: best.order_id,
+            competing_price: best.price,
+        })
+    }
+}
+
 /// Information regarding a specific market group
 ///
 /// # Documentation
@@ -118,9 +271,9 @@ pub struct MarketItemGroupInformation {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MarketItemPrices {
     /// The estimated price of what the item actually sells for on the market
-    pub adjusted_price: Option<f64>,
+    pub adjusted_price: Option<Isk>,
     /// The average price of the item on the market
-    pub average_price: Option<f64>,
+    pub average_price: Option<Isk>,
     /// The type ID of the item on the market
     pub type_id: i64,
 }
@@ -148,7 +301,7 @@ pub struct StructureMarketOrder {
     /// Unique ID of the order
     pub order_id: i64,
     /// The cost per unit for this order
-    pub price: f64,
+    pub price: Isk,
     /// The range of the order
     pub range: MarketOrderRange,
     /// The type ID of the item in the order
@@ -159,6 +312,19 @@ pub struct StructureMarketOrder {
     pub volume_total: i64,
 }
 
+impl HasTypeId for StructureMarketOrder {
+    fn type_id(&self) -> i64 {
+        self.type_id
+    }
+}
+
+impl StructureMarketOrder {
+    /// Returns the date the order expires, calculated as the issued date plus the duration in days
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.issued + TimeDelta::days(self.duration)
+    }
+}
+
 /// An entry for the market statistics of an item within a specific region on a given date
 ///
 /// # Documentation
@@ -166,13 +332,13 @@ pub struct StructureMarketOrder {
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct MarketItemRegionStatistics {
     /// The average price of the item in this entry
-    pub average: f64,
+    pub average: Isk,
     /// The YYYY-MM-DD of this statistic entry
     pub date: NaiveDate,
     /// The highest price the item sold for in this entry
-    pub highest: f64,
+    pub highest: Isk,
     /// The lowest price the item sold for in this enry
-    pub lowest: f64,
+    pub lowest: Isk,
     /// Total numbers of orders that occurred for this entry
     pub order_count: i64,
     /// The volume of the item traded for this entry
@@ -202,7 +368,7 @@ pub struct MarketRegionOrder {
     /// Unique ID of the order
     pub order_id: i64,
     /// The cost per unit for this order
-    pub price: f64,
+    pub price: Isk,
     /// The range of the order
     pub range: MarketOrderRange,
     /// ID of the solar system where the order was placed
@@ -214,3 +380,150 @@ pub struct MarketRegionOrder {
     /// Quantity of items for sale or to buy when the order was placed
     pub volume_total: i64,
 }
+
+impl HasTypeId for MarketRegionOrder {
+    fn type_id(&self) -> i64 {
+        self.type_id
+    }
+}
+
+impl MarketRegionOrder {
+    /// Returns the date the order expires, calculated as the issued date plus the duration in days
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.issued + TimeDelta::days(self.duration)
+    }
+}
+
+#[cfg(test)]
+mod market_order_tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// Tests deserializing an order using the date format returned by ESI
+    ///
+    /// # Test Setup
+    /// - Create the JSON of a regional market order as returned by ESI
+    ///
+    /// # Assertions
+    /// - Assert the issued date is parsed as UTC
+    /// - Assert the order expires the order duration in days after it was issued
+    #[test]
+    fn test_market_order_expires_at() {
+        let order: MarketRegionOrder = serde_json::from_value(serde_json::json!({
+            "duration": 90,
+            "is_buy_order": false,
+            "issued": "2025-01-14T09:57:18Z",
+            "location_id": 60003760,
+            "min_volume": 1,
+            "order_id": 6969481207_i64,
+            "price": 5.11,
+            "range": "region",
+            "system_id": 30000142,
+            "type_id": 34,
+            "volume_remain": 1000,
+            "volume_total": 1000
+        }))
+        .unwrap();
+
+        assert_eq!(
+            order.issued,
+            Utc.with_ymd_and_hms(2025, 1, 14, 9, 57, 18).unwrap()
+        );
+        assert_eq!(
+            order.expires_at(),
+            Utc.with_ymd_and_hms(2025, 4, 14, 9, 57, 18).unwrap()
+        );
+    }
+
+    /// Creates a regional market order for Tritanium at Jita 4-4
+    fn mock_region_order(order_id: i64, is_buy_order: bool, price: i32) -> MarketRegionOrder {
+        MarketRegionOrder {
+            duration: 90,
+            is_buy_order,
+            issued: Utc.with_ymd_and_hms(2025, 1, 14, 9, 57, 18).unwrap(),
+            location_id: 60003760,
+            min_volume: 1,
+            order_id,
+            price: Isk::from(price),
+            range: MarketOrderRange::Region,
+            system_id: 30000142,
+            type_id: 34,
+            volume_remain: 1000,
+            volume_total: 1000,
+        }
+    }
+
+    /// Creates a character's market order matching a regional market order
+    fn mock_character_order(order: &MarketRegionOrder) -> CharacterMarketOrder {
+        CharacterMarketOrder {
+            duration: order.duration,
+            escrow: None,
+            is_buy_order: order.is_buy_order,
+            is_corporation: false,
+            issued: order.issued,
+            location_id: order.location_id,
+            min_volume: None,
+            order_id: order.order_id,
+            price: order.price,
+            range: order.range.clone(),
+            region_id: 10000002,
+            state: None,
+            type_id: order.type_id,
+            volume_remain: order.volume_remain,
+            volume_total: order.volume_total,
+        }
+    }
+
+    /// Tests orders are undercut by the best priced competing order at the same location
+    ///
+    /// # Test Setup
+    /// - Create sell orders at 10, 8 & 7 ISK with the 7 ISK order at another location, and buy
+    ///   orders at 5, 6 & 5 ISK
+    /// - Create a sell order at 8 ISK which isn't part of the regional orders
+    ///
+    /// # Assertions
+    /// - Assert a sell order is undercut by the cheapest sell order at the same location
+    /// - Assert the cheapest sell order at a location isn't undercut
+    /// - Assert a buy order is outbid by the highest buy order
+    /// - Assert an order with the same price as the best competing order isn't undercut
+    #[test]
+    fn test_undercut_by() {
+        let mut other_location = mock_region_order(3, false, 7);
+        other_location.location_id = 1035466617946;
+        let region_orders = vec![
+            mock_region_order(1, false, 10),
+            mock_region_order(2, false, 8),
+            other_location,
+            mock_region_order(4, true, 5),
+            mock_region_order(5, true, 6),
+            mock_region_order(6, true, 5),
+        ];
+
+        // Assert a sell order is undercut by the cheapest sell order at the same location
+        let undercut = mock_character_order(&region_orders[0])
+            .undercut_by(&region_orders)
+            .unwrap();
+        assert_eq!(undercut.competing_order_id, 2);
+        assert_eq!(undercut.competing_price, Isk::from(8));
+        assert_eq!(undercut.price_difference(), Isk::from(2));
+
+        // Assert the cheapest sell order at a location isn't undercut
+        assert_eq!(
+            mock_character_order(&region_orders[1]).undercut_by(&region_orders),
+            None
+        );
+
+        // Assert a buy order is outbid by the highest buy order
+        let outbid = mock_character_order(&region_orders[3])
+            .undercut_by(&region_orders)
+            .unwrap();
+        assert!(outbid.is_buy_order);
+        assert_eq!(outbid.competing_order_id, 5);
+        assert_eq!(outbid.price_difference(), Isk::from(1));
+
+        // Assert an order with the same price as the best competing order isn't undercut
+        let matched = mock_character_order(&mock_region_order(7, false, 8));
+        assert_eq!(matched.undercut_by(&region_orders), None);
+    }
+}
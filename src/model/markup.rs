@@ -0,0 +1,151 @@
+//! # EVE Online Markup
+//!
+//! Text entered in-game such as corporation titles & EVE mail bodies is returned by ESI with the
+//! HTML-like markup used by the game client, e.g. `<color=0xffff0000>Director</color>`. Provides
+//! [`strip_markup`] to convert such text into plain text for display outside of the game.
+//!
+//! EVE mail bodies can also be parsed into plain text & typed links with `MailBody` from the
+//! `mail` model module, which requires the `mail` feature.
+
+/// Converts text containing in-game markup into plain text
+///
+/// Removes all tags such as `<color=0xffff0000>`, `<font size="12">`, `<b>` & `<a href="...">`
+/// while keeping the text they enclose, converts `<br>` tags into line breaks & decodes the
+/// `&lt;`, `&gt;`, `&amp;`, `&quot;` & `&apos;` entities as well as numeric entities. A `<`
+/// without a closing `>` is kept as text.
+///
+/// # Arguments
+/// - `text` (`&str`): The text containing markup
+///
+/// # Returns
+/// The text with all markup removed
+///
+/// # Example
+/// ```
+/// use eve_esi::model::markup::strip_markup;
+///
+/// let title = "<color=0xffff0000><b>Director</b></color> &amp; CEO";
+/// assert_eq!(strip_markup(title), "Director & CEO");
+/// ```
+pub fn strip_markup(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        let Some(length) = rest[start..].find('>') else {
+            break;
+        };
+
+        plain.push_str(&rest[..start]);
+
+        let tag = rest[start + 1..start + length].trim().to_ascii_lowercase();
+        if tag == "br" || tag.starts_with("br ") || tag.starts_with("br/") {
+            plain.push('\n');
+        }
+
+        rest = &rest[start + length + 1..];
+    }
+    plain.push_str(rest);
+
+    decode_entities(&plain)
+}
+
+/// Decodes named & numeric character entities, unknown entities are kept as text
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .map(|end| (&rest[1..end], end))
+            .and_then(|(name, end)| Some((decode_entity(name)?, end)));
+
+        match entity {
+            Some((character, end)) => {
+                decoded.push(character);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+/// Returns the character of an entity name without the surrounding `&` & `;`
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix('#')? {
+                hex if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16).ok()?,
+                decimal => decimal.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod strip_markup_tests {
+    use super::*;
+
+    /// Tests removing the font & color tags commonly found in titles
+    ///
+    /// # Assertions
+    /// - Assert color, font, bold, italic & underline tags are removed
+    /// - Assert nested tags are removed while keeping the enclosed text
+    /// - Assert text without markup is unchanged
+    #[test]
+    fn test_strip_font_and_color_tags() {
+        assert_eq!(
+            strip_markup("<color=0xff00ff00>Logistics</color>"),
+            "Logistics"
+        );
+        assert_eq!(
+            strip_markup(
+                "<font size=\"14\" color=\"#bfffffff\"><b><i><u>Fleet</u> Commander</i></b></font>"
+            ),
+            "Fleet Commander"
+        );
+        assert_eq!(strip_markup("Member"), "Member");
+    }
+
+    /// Tests converting line breaks, links & entities
+    ///
+    /// # Assertions
+    /// - Assert `<br>` variants become line breaks
+    /// - Assert link tags are removed while keeping the link text
+    /// - Assert named & numeric entities are decoded & unknown entities kept
+    /// - Assert an unclosed `<` is kept as text
+    #[test]
+    fn test_strip_breaks_links_and_entities() {
+        assert_eq!(
+            strip_markup("Line 1<br>Line 2<br />Line 3"),
+            "Line 1\nLine 2\nLine 3"
+        );
+        assert_eq!(
+            strip_markup("<a href=\"showinfo:1377//2114794365\">Hyziri</a>"),
+            "Hyziri"
+        );
+        assert_eq!(
+            strip_markup("&lt;3 &amp; &quot;hi&quot; &#39;x&#x27; &unknown; AT&T"),
+            "<3 & \"hi\" 'x' &unknown; AT&T"
+        );
+        assert_eq!(strip_markup("1 < 2"), "1 < 2");
+    }
+}
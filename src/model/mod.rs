@@ -4,16 +4,102 @@
 //! as defined by the EVE Online Stable Infrastructure (ESI) API. These models are used for serialization and
 //! deserialization of data exchanged with the ESI endpoints.
 
+#[cfg(feature = "alliance")]
 pub mod alliance;
+#[cfg(any(feature = "assets", feature = "character", feature = "corporation"))]
 pub mod asset;
+#[cfg(feature = "calendar")]
 pub mod calendar;
+#[cfg(feature = "character")]
 pub mod character;
+#[cfg(feature = "clones")]
 pub mod clones;
+#[cfg(feature = "contacts")]
 pub mod contacts;
+#[cfg(feature = "contracts")]
 pub mod contract;
+#[cfg(feature = "corporation")]
 pub mod corporation;
+#[cfg(feature = "corporation-projects")]
+pub mod corporation_projects;
+#[cfg(feature = "dogma")]
+pub mod dogma;
 pub mod enums;
+#[cfg(feature = "fittings")]
+pub mod fitting;
+#[cfg(feature = "industry")]
+pub mod industry;
+#[cfg(feature = "insurance")]
+pub mod insurance;
+#[cfg(feature = "killmails")]
+pub mod killmail;
+#[cfg(feature = "location")]
+pub mod location;
+#[cfg(feature = "mail")]
+pub mod mail;
+#[cfg(feature = "market")]
 pub mod market;
+pub mod markup;
 pub mod oauth2;
+#[cfg(feature = "planetary-interaction")]
+pub mod planetary_interaction;
+pub mod requests;
+#[cfg(feature = "routes")]
+pub mod routes;
+#[cfg(feature = "search")]
+pub mod search;
+#[cfg(any(feature = "clones", feature = "skills"))]
+pub mod skills;
+#[cfg(feature = "sovereignty")]
+pub mod sovereignty;
 pub mod standing;
+#[cfg(any(
+    feature = "contracts",
+    feature = "dogma",
+    feature = "killmails",
+    feature = "universe"
+))]
 pub mod universe;
+#[cfg(feature = "wallet")]
+pub mod wallet;
+
+/// An amount of ISK as returned by wallet, market & contract endpoints
+///
+/// ESI returns ISK amounts as JSON numbers which are deserialized into an [`f64`] by default,
+/// amounts above roughly 90 trillion ISK lose precision to the cent. Enable the `decimal` feature
+/// to deserialize them into a [`rust_decimal::Decimal`] without losing precision instead.
+#[cfg(not(feature = "decimal"))]
+pub type Isk = f64;
+
+/// An amount of ISK as returned by wallet, market & contract endpoints
+///
+/// Deserialized into a [`rust_decimal::Decimal`] without losing precision as the `decimal` feature
+/// is enabled, without the feature ISK amounts are an [`f64`].
+#[cfg(feature = "decimal")]
+pub type Isk = rust_decimal::Decimal;
+
+/// An item referencing an item type, such as an asset, blueprint or market order
+///
+/// Used by [`UniverseEndpoints::get_item_types_for`](crate::endpoints::universe::UniverseEndpoints::get_item_types_for)
+/// to retrieve the type information of every distinct type referenced by a list of items.
+pub trait HasTypeId {
+    /// Returns the ID of the item type referenced by the item
+    fn type_id(&self) -> i64;
+}
+
+/// A response whose fields depend on the roles of the character the access token belongs to
+///
+/// ESI omits some fields, rather than failing the request, when the character lacks the roles to
+/// view them. Such fields are always an [`Option`], [`Self::is_complete`] reports whether they were
+/// present so applications can prompt for a token of a character with the required roles.
+pub trait RoleRestricted {
+    /// Returns `true` if every role-restricted field was present in the response
+    fn is_complete(&self) -> bool;
+}
+
+impl<T: RoleRestricted> RoleRestricted for [T] {
+    /// Returns `true` if every entry is complete
+    fn is_complete(&self) -> bool {
+        self.iter().all(RoleRestricted::is_complete)
+    }
+}
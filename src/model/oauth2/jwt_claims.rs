@@ -8,7 +8,9 @@
 //! For usage of OAuth2 in the `eve_esi` crate, please see the [`crate::oauth2`]
 //! module documentation.
 
-use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{Error, OAuthError};
@@ -56,7 +58,8 @@ pub struct EveJwtClaims {
     pub scp: Vec<String>,
     /// The character's name
     pub name: String,
-    /// The character's ID
+    /// Hash of the account owning the character, changes when the character is transferred,
+    /// see [`check_owner`](crate::oauth2::owner::check_owner)
     pub owner: String,
     /// Client ID
     pub azp: String,
@@ -104,22 +107,49 @@ impl EveJwtClaims {
         }
     }
 
+    /// Returns when the token expires, the `exp` claim
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.exp
+    }
+
+    /// Returns when the token was issued, the `iat` claim
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        self.iat
+    }
+
+    /// Returns the time remaining until the token expires, negative once the token has expired
+    pub fn time_until_expiry(&self) -> Duration {
+        self.exp - Utc::now()
+    }
+
     /// Utility function to check token claims to see if it is expired
     ///
     /// If your token is expired then a request to an authenticated ESI route will return an error. It is ideal to
     /// stop the request from happening within your application to not incur ESI error limits.
     ///
+    /// Use [`Self::is_expired_within`] to also consider tokens which are about to expire as expired.
+    ///
     /// # Returns
     /// - `bool`: Indicating whether or not token is expired
     pub fn is_expired(&self) -> bool {
-        let character_id = self.character_id().unwrap_or(0);
-
-        let now = Utc::now();
-        let token_expiration = self.exp;
+        self.is_expired_within(Duration::zero())
+    }
 
-        if now < token_expiration {
-            let time_remaining = self.exp - now;
+    /// Utility function to check if the token has expired or expires within the provided duration
+    ///
+    /// A request started just before the token expires may reach ESI after it has expired, a leeway of
+    /// a few seconds to a minute avoids this & leaves time to refresh the token.
+    ///
+    /// # Arguments
+    /// - `leeway` (`Duration`): Margin before expiration at which the token is considered expired
+    ///
+    /// # Returns
+    /// - `bool`: Indicating whether or not token is expired or expires within the leeway
+    pub fn is_expired_within(&self, leeway: Duration) -> bool {
+        let character_id = self.character_id().unwrap_or(0);
+        let time_remaining = self.time_until_expiry();
 
+        if time_remaining > leeway {
             log::debug!(
                 "Checked token for expiration, token for character ID {} is not yet expired, expiration in {}s",
                 character_id,
@@ -129,16 +159,44 @@ impl EveJwtClaims {
             return false;
         }
 
-        let time_remaining = now - self.exp;
         log::debug!(
-            "Checked token for expiration, token for character ID {} is expired, expired {}s ago",
+            "Checked token for expiration, token for character ID {} is expired or expires within {}s, expiration in {}s",
             character_id,
+            leeway.num_seconds(),
             time_remaining.num_seconds()
         );
 
         true
     }
 
+    /// Returns the scopes granted by the token as a set
+    pub fn scopes(&self) -> HashSet<&str> {
+        self.scp.iter().map(String::as_str).collect()
+    }
+
+    /// Returns `true` if the provided scope was granted to the token
+    ///
+    /// # Arguments
+    /// - `scope` (`&str`): The scope string to check for, e.g. `esi-wallet.read_character_wallet.v1`
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scp.iter().any(|granted| granted == scope)
+    }
+
+    /// Returns the provided scopes which were not granted to the token
+    ///
+    /// # Arguments
+    /// - `scopes` (`&[String]`): The scopes to check for, see [`crate::ScopeBuilder`]
+    ///
+    /// # Returns
+    /// - `Vec<String>`: The missing scopes in the order provided, empty if all scopes were granted
+    pub fn missing_scopes(&self, scopes: &[String]) -> Vec<String> {
+        scopes
+            .iter()
+            .filter(|scope| !self.has_scope(scope))
+            .cloned()
+            .collect()
+    }
+
     /// Utility function to check if claims has provided scopes
     ///
     /// If your token is missing the scopes required for an authenticated ESI route your request will return
@@ -332,6 +390,19 @@ mod is_expired_tests {
 
         assert!(result);
     }
+
+    /// Ensures that a token expiring within the leeway is considered expired
+    #[test]
+    fn test_is_expired_within_leeway() {
+        let mut mock_claims = create_mock_jwt_claims();
+        mock_claims.exp = Utc::now() + Duration::seconds(30); // Expires in 30 seconds
+
+        assert!(!mock_claims.is_expired());
+        assert!(!mock_claims.is_expired_within(Duration::seconds(10)));
+        assert!(mock_claims.is_expired_within(Duration::seconds(60)));
+        assert!(mock_claims.time_until_expiry() <= Duration::seconds(30));
+        assert_eq!(mock_claims.expires_at(), mock_claims.exp);
+    }
 }
 
 #[cfg(test)]
@@ -361,6 +432,30 @@ mod has_scopes_tests {
 
         assert!(!result);
     }
+
+    /// Test that the scopes which were not granted are returned
+    #[test]
+    fn test_missing_scopes() {
+        let mut mock_claims = create_mock_jwt_claims();
+        mock_claims.scp = vec![
+            "publicData".to_string(),
+            "esi-wallet.read_character_wallet.v1".to_string(),
+        ];
+
+        let expected_scopes = vec![
+            "esi-wallet.read_character_wallet.v1".to_string(),
+            "esi-assets.read_assets.v1".to_string(),
+        ];
+
+        assert!(mock_claims.has_scope("publicData"));
+        assert!(mock_claims
+            .scopes()
+            .contains("esi-wallet.read_character_wallet.v1"));
+        assert_eq!(
+            mock_claims.missing_scopes(&expected_scopes),
+            vec!["esi-assets.read_assets.v1".to_string()]
+        );
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,45 @@
+//! # EVE Online OAuth2 Server Metadata Models
+//!
+//! Provides the [`AuthorizationServerMetadata`] struct representing the metadata EVE Online's SSO
+//! publishes at `/.well-known/oauth-authorization-server`, describing the endpoints & features of
+//! the authorization server.
+//!
+//! For usage of OAuth2 in the `eve_esi` crate, please see the [`crate::oauth2`]
+//! module documentation.
+
+use serde::{Deserialize, Serialize};
+
+/// Metadata of EVE Online's OAuth2 authorization server
+///
+/// Retrieved with
+/// [`OAuth2Endpoints::get_server_metadata`](crate::oauth2::OAuth2Endpoints::get_server_metadata).
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/docs/services/sso/>
+/// - <https://www.rfc-editor.org/rfc/rfc8414>
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuthorizationServerMetadata {
+    /// The issuer of tokens signed by the server, e.g. `https://login.eveonline.com`
+    pub issuer: String,
+    /// URL users are sent to in order to log in
+    pub authorization_endpoint: String,
+    /// URL used to exchange authorization codes & refresh tokens for access tokens
+    pub token_endpoint: String,
+    /// URL of the JSON web key set used to validate access tokens
+    pub jwks_uri: String,
+    /// URL used to revoke refresh tokens
+    #[serde(default)]
+    pub revocation_endpoint: Option<String>,
+    /// Scopes which may be requested, if advertised by the server
+    #[serde(default)]
+    pub scopes_supported: Option<Vec<String>>,
+    /// OAuth2 response types supported by the authorization endpoint
+    #[serde(default)]
+    pub response_types_supported: Vec<String>,
+    /// Methods supported to authenticate the application with the token endpoint
+    #[serde(default)]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    /// PKCE code challenge methods supported by the authorization endpoint
+    #[serde(default)]
+    pub code_challenge_methods_supported: Vec<String>,
+}
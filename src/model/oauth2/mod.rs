@@ -8,7 +8,11 @@
 pub mod jwt_claims;
 pub mod jwt_key;
 pub mod login;
+pub mod metadata;
+pub mod token;
 
 pub use jwt_claims::EveJwtClaims;
 pub use jwt_key::{EveJwtKey, EveJwtKeys};
 pub use login::AuthenticationData;
+pub use metadata::AuthorizationServerMetadata;
+pub use token::{TokenGrant, TokenResponse};
@@ -0,0 +1,314 @@
+//! # EVE Online OAuth2 Token Model
+//!
+//! Provides the [`TokenResponse`] struct returned from the
+//! [`crate::oauth2::OAuth2Endpoints::get_token_refresh`] method when refreshing a token & the
+//! [`TokenGrant`] enum describing how a token is requested with
+//! [`crate::oauth2::OAuth2Endpoints::request_token`].
+//!
+//! For usage of OAuth2 in the `eve_esi` crate, please see the [`crate::oauth2`]
+//! module documentation.
+
+use std::time::Duration;
+
+use oauth2::basic::BasicTokenType;
+use oauth2::{EmptyExtraTokenFields, RefreshToken, StandardTokenResponse};
+
+use crate::esi::AccessToken;
+
+/// A token returned from EVE Online's OAuth2 API when refreshing a token
+///
+/// EVE Online may grant a reduced set of scopes when refreshing a token, for example after
+/// the character's owner revokes a scope from the application. Compare [`TokenResponse::scopes`]
+/// against the scopes your application requires, or use [`TokenResponse::missing_scopes`] when
+/// requested scopes were provided, to detect scope revocation early.
+///
+/// # Documentation
+/// See [EVE SSO documentation](https://developers.eveonline.com/docs/services/sso/)
+#[derive(Debug, Clone)]
+pub struct TokenResponse {
+    /// The new access token
+    pub access_token: AccessToken,
+    /// The rotated refresh token which should replace the previously stored refresh token
+    pub refresh_token: Option<RefreshToken>,
+    /// Duration until the access token expires
+    pub expires_in: Option<Duration>,
+    /// Scopes granted to the access token
+    ///
+    /// Read from the `scope` field of the response when present, otherwise from the unverified
+    /// `scp` claim of the access token. Use
+    /// [`OAuth2Endpoints::validate_token`](crate::oauth2::OAuth2Endpoints::validate_token) before
+    /// trusting the token's claims.
+    pub scopes: Vec<String>,
+    /// Requested scopes which were not granted, empty if no requested scopes were provided
+    pub missing_scopes: Vec<String>,
+}
+
+impl TokenResponse {
+    /// Converts a token response from the `oauth2` crate, comparing granted scopes against requested scopes
+    ///
+    /// # Arguments
+    /// - `token` (`StandardTokenResponse`): The token response returned by EVE Online's OAuth2 API
+    /// - `requested_scopes` (`Option<&[String]>`): The scopes expected to be granted to the token
+    pub(crate) fn from_token(
+        token: StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType>,
+        requested_scopes: Option<&[String]>,
+    ) -> Self {
+        use oauth2::TokenResponse as _;
+
+        let access_token = token.access_token().secret().clone();
+
+        let scopes = match token.scopes() {
+            Some(scopes) => scopes.iter().map(|scope| scope.to_string()).collect(),
+            None => peek_token_scopes(&access_token),
+        };
+
+        let missing_scopes = requested_scopes
+            .unwrap_or_default()
+            .iter()
+            .filter(|scope| !scopes.contains(scope))
+            .cloned()
+            .collect();
+
+        Self {
+            access_token: AccessToken::new(access_token),
+            refresh_token: token.refresh_token().cloned(),
+            expires_in: token.expires_in(),
+            scopes,
+            missing_scopes,
+        }
+    }
+
+    /// Returns `true` if all of the provided scopes were granted to the token
+    ///
+    /// # Arguments
+    /// - `scopes` (`&[String]`): The scopes to check for
+    pub fn has_scopes(&self, scopes: &[String]) -> bool {
+        scopes.iter().all(|scope| self.scopes.contains(scope))
+    }
+}
+
+/// A grant used to request a token from EVE Online's OAuth2 API
+///
+/// Each variant corresponds to an OAuth2 grant type supported by EVE Online's single sign-on. The
+/// enum is non-exhaustive so grant types EVE Online enables in the future, such as the client
+/// credentials grant, can be added without breaking existing matches.
+///
+/// The `Debug` implementation redacts the authorization code & refresh token.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenGrant {
+    /// Exchanges the authorization code returned to the callback route after login
+    AuthorizationCode {
+        /// Authorization code from the callback route's query parameters
+        code: String,
+    },
+    /// Exchanges a refresh token for a new access token & rotated refresh token
+    RefreshToken {
+        /// Refresh token returned by a previous token request
+        refresh_token: String,
+        /// The scopes expected to be granted to the refreshed token, see
+        /// [`TokenResponse::missing_scopes`]
+        requested_scopes: Option<Vec<String>>,
+    },
+}
+
+impl TokenGrant {
+    /// Creates an authorization code grant
+    ///
+    /// # Arguments
+    /// - `code` (`impl Into<String>`): Authorization code from the callback route's query parameters
+    pub fn authorization_code(code: impl Into<String>) -> Self {
+        Self::AuthorizationCode { code: code.into() }
+    }
+
+    /// Creates a refresh token grant
+    ///
+    /// # Arguments
+    /// - `refresh_token` (`impl Into<String>`): Refresh token returned by a previous token request
+    /// - `requested_scopes` (`Option<Vec<String>>`): The scopes expected to be granted to the
+    ///   refreshed token
+    pub fn refresh_token(
+        refresh_token: impl Into<String>,
+        requested_scopes: Option<Vec<String>>,
+    ) -> Self {
+        Self::RefreshToken {
+            refresh_token: refresh_token.into(),
+            requested_scopes,
+        }
+    }
+
+    /// Returns the OAuth2 `grant_type` sent with the token request, e.g. `refresh_token`
+    pub fn grant_type(&self) -> &'static str {
+        match self {
+            Self::AuthorizationCode { .. } => "authorization_code",
+            Self::RefreshToken { .. } => "refresh_token",
+        }
+    }
+
+    /// Returns the scopes expected to be granted to the token, if any were provided
+    pub fn requested_scopes(&self) -> Option<&[String]> {
+        match self {
+            Self::AuthorizationCode { .. } => None,
+            Self::RefreshToken {
+                requested_scopes, ..
+            } => requested_scopes.as_deref(),
+        }
+    }
+}
+
+impl std::fmt::Debug for TokenGrant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AuthorizationCode { .. } => f
+                .debug_struct("AuthorizationCode")
+                .field("code", &"[redacted]")
+                .finish(),
+            Self::RefreshToken {
+                requested_scopes, ..
+            } => f
+                .debug_struct("RefreshToken")
+                .field("refresh_token", &"[redacted]")
+                .field("requested_scopes", requested_scopes)
+                .finish(),
+        }
+    }
+}
+
+/// Reads the `scp` claim of an access token without validating it
+///
+/// Returns an empty list if the token could not be decoded or has no scopes.
+fn peek_token_scopes(token_secret: &str) -> Vec<String> {
+    #[derive(serde::Deserialize)]
+    struct ScopeClaim {
+        #[serde(default)]
+        scp: ScopeClaimValue,
+    }
+
+    // EVE Online returns a single scope as a string rather than an array
+    #[derive(serde::Deserialize, Default)]
+    #[serde(untagged)]
+    enum ScopeClaimValue {
+        #[default]
+        None,
+        Single(String),
+        Multiple(Vec<String>),
+    }
+
+    match jsonwebtoken::dangerous::insecure_decode::<ScopeClaim>(token_secret) {
+        Ok(token_data) => match token_data.claims.scp {
+            ScopeClaimValue::None => Vec::new(),
+            ScopeClaimValue::Single(scope) => vec![scope],
+            ScopeClaimValue::Multiple(scopes) => scopes,
+        },
+        Err(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod token_response_tests {
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use oauth2::{AccessToken as OAuth2AccessToken, Scope};
+
+    use super::*;
+
+    /// Creates a token response with an access token containing the provided `scp` claim
+    fn mock_token(
+        scp: serde_json::Value,
+    ) -> StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType> {
+        let claims = serde_json::json!({ "sub": "CHARACTER:EVE:2114794365", "scp": scp });
+        let secret = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap();
+
+        let mut token = StandardTokenResponse::new(
+            OAuth2AccessToken::new(secret),
+            BasicTokenType::Bearer,
+            EmptyExtraTokenFields {},
+        );
+        token.set_expires_in(Some(&Duration::from_secs(1199)));
+        token.set_refresh_token(Some(RefreshToken::new("rotated".to_string())));
+
+        token
+    }
+
+    /// Tests that scopes are read from the access token claims & compared against requested scopes
+    ///
+    /// # Test Setup
+    /// - Create a token with 1 of 2 requested scopes in its `scp` claim
+    ///
+    /// # Assertions
+    /// - Assert the granted scope is parsed from the single string claim
+    /// - Assert the scope which was not granted is reported as missing
+    /// - Assert the refresh token & expiration are retained
+    #[test]
+    fn test_token_response_missing_scopes() {
+        let token = mock_token(serde_json::json!("publicData"));
+        let requested = vec![
+            "publicData".to_string(),
+            "esi-wallet.read_character_wallet.v1".to_string(),
+        ];
+
+        let response = TokenResponse::from_token(token, Some(&requested));
+
+        assert_eq!(response.scopes, vec!["publicData".to_string()]);
+        assert_eq!(
+            response.missing_scopes,
+            vec!["esi-wallet.read_character_wallet.v1".to_string()]
+        );
+        assert!(!response.has_scopes(&requested));
+        assert_eq!(response.refresh_token.unwrap().secret(), "rotated");
+        assert_eq!(response.expires_in, Some(Duration::from_secs(1199)));
+    }
+
+    /// Tests that the `scope` field of the response takes precedence over the access token claims
+    ///
+    /// # Test Setup
+    /// - Create a token without an `scp` claim but with a `scope` field
+    ///
+    /// # Assertions
+    /// - Assert scopes are read from the `scope` field
+    /// - Assert no scopes are missing when none were requested
+    #[test]
+    fn test_token_response_scope_field() {
+        let mut token = mock_token(serde_json::Value::Null);
+        token.set_scopes(Some(vec![Scope::new("publicData".to_string())]));
+
+        let response = TokenResponse::from_token(token, None);
+
+        assert_eq!(response.scopes, vec!["publicData".to_string()]);
+        assert!(response.missing_scopes.is_empty());
+    }
+
+    /// Tests the grant type & requested scopes of each token grant
+    ///
+    /// # Assertions
+    /// - Assert each grant reports its OAuth2 grant type
+    /// - Assert only the refresh token grant carries requested scopes
+    /// - Assert the code & refresh token are redacted from `Debug` output
+    #[test]
+    fn test_token_grant() {
+        let code = TokenGrant::authorization_code("secret-code");
+        let refresh =
+            TokenGrant::refresh_token("secret-refresh", Some(vec!["publicData".to_string()]));
+
+        // Assert each grant reports its OAuth2 grant type
+        assert_eq!(code.grant_type(), "authorization_code");
+        assert_eq!(refresh.grant_type(), "refresh_token");
+
+        // Assert only the refresh token grant carries requested scopes
+        assert_eq!(code.requested_scopes(), None);
+        assert_eq!(
+            refresh.requested_scopes(),
+            Some(&["publicData".to_string()][..])
+        );
+
+        // Assert the code & refresh token are redacted from `Debug` output
+        let debug = format!("{:?} {:?}", code, refresh);
+        assert!(!debug.contains("secret-code"));
+        assert!(!debug.contains("secret-refresh"));
+        assert!(debug.contains("publicData"));
+    }
+}
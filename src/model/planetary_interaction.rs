@@ -0,0 +1,319 @@
+//! # EVE ESI Planetary Interaction Models
+//!
+//! Provides planetary interaction-related structs for EVE Online, including helpers for deriving
+//! the remaining duration & yield of extractor programs.
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::model::enums::planetary_interaction::PlanetType;
+
+/// A planetary colony owned by a character
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Colony {
+    /// The last time the colony was updated
+    pub last_update: DateTime<Utc>,
+    /// The number of pins (structures) within the colony
+    pub num_pins: i64,
+    /// The ID of the character who owns the colony
+    pub owner_id: i64,
+    /// The ID of the planet the colony is on
+    pub planet_id: i64,
+    /// The type of the planet the colony is on
+    pub planet_type: PlanetType,
+    /// The ID of the solar system the planet is in
+    pub solar_system_id: i64,
+    /// The level of the owner's Command Center Upgrades skill applied to the colony
+    pub upgrade_level: i64,
+}
+
+/// The full layout of a planetary colony including its pins, links, & routes
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ColonyLayout {
+    /// Links between pins of the colony
+    pub links: Vec<PlanetLink>,
+    /// Pins (structures) of the colony
+    pub pins: Vec<PlanetPin>,
+    /// Routes transferring commodities between pins of the colony
+    pub routes: Vec<PlanetRoute>,
+}
+
+/// A link between 2 pins of a planetary colony
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetLink {
+    /// The ID of the pin the link ends at
+    pub destination_pin_id: i64,
+    /// The upgrade level of the link
+    pub link_level: i64,
+    /// The ID of the pin the link starts from
+    pub source_pin_id: i64,
+}
+
+/// A pin (structure) of a planetary colony
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetPin {
+    /// Commodities stored within the pin
+    #[serde(default)]
+    pub contents: Vec<PlanetPinContent>,
+    /// The time the extractor program of the pin ends, only present for extractors
+    pub expiry_time: Option<DateTime<Utc>>,
+    /// Details of the extractor program, only present for extractors
+    pub extractor_details: Option<PlanetExtractorDetails>,
+    /// Details of the factory schematic, only present for factories
+    pub factory_details: Option<PlanetFactoryDetails>,
+    /// The time the pin's current program was installed
+    pub install_time: Option<DateTime<Utc>>,
+    /// The time the pin's last cycle started
+    pub last_cycle_start: Option<DateTime<Utc>>,
+    /// Latitude of the pin on the planet
+    pub latitude: f64,
+    /// Longitude of the pin on the planet
+    pub longitude: f64,
+    /// Unique ID of the pin
+    pub pin_id: i64,
+    /// The ID of the schematic the pin is producing, only present for factories
+    pub schematic_id: Option<i64>,
+    /// The type ID of the pin structure
+    pub type_id: i64,
+}
+
+/// A commodity stored within a pin of a planetary colony
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetPinContent {
+    /// The quantity of the commodity
+    pub amount: i64,
+    /// The type ID of the commodity
+    pub type_id: i64,
+}
+
+/// Details of an extractor control unit's program
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetExtractorDetails {
+    /// The duration of each extraction cycle in seconds
+    pub cycle_time: Option<i64>,
+    /// The radius of the extractor heads
+    pub head_radius: Option<f64>,
+    /// The extractor heads placed by the extractor
+    pub heads: Vec<PlanetExtractorHead>,
+    /// The type ID of the resource being extracted
+    pub product_type_id: Option<i64>,
+    /// The base quantity extracted per cycle across all heads
+    pub qty_per_cycle: Option<i64>,
+}
+
+/// An extractor head placed by an extractor control unit
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetExtractorHead {
+    /// The ID of the extractor head
+    pub head_id: i64,
+    /// Latitude of the extractor head on the planet
+    pub latitude: f64,
+    /// Longitude of the extractor head on the planet
+    pub longitude: f64,
+}
+
+/// Details of a factory's schematic
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetFactoryDetails {
+    /// The ID of the schematic the factory is producing
+    pub schematic_id: i64,
+}
+
+/// A route transferring commodities between 2 pins of a planetary colony
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdPlanetsPlanetIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlanetRoute {
+    /// The type ID of the commodity being transferred
+    pub content_type_id: i64,
+    /// The ID of the pin receiving the commodity
+    pub destination_pin_id: i64,
+    /// The quantity of the commodity transferred
+    pub quantity: f64,
+    /// Unique ID of the route
+    pub route_id: i64,
+    /// The ID of the pin sending the commodity
+    pub source_pin_id: i64,
+    /// The IDs of the pins the route passes through
+    #[serde(default)]
+    pub waypoints: Vec<i64>,
+}
+
+impl PlanetPin {
+    /// Returns the duration of each extraction cycle if the pin is an extractor with an active program
+    pub fn extraction_cycle_time(&self) -> Option<TimeDelta> {
+        self.extractor_details
+            .as_ref()
+            .and_then(|details| details.cycle_time)
+            .filter(|cycle_time| *cycle_time > 0)
+            .map(TimeDelta::seconds)
+    }
+
+    /// Calculates the time remaining until the pin's extractor program ends
+    ///
+    /// # Arguments
+    /// - `now` (`DateTime<Utc>`): The time to calculate the remaining duration from, typically `Utc::now()`
+    ///
+    /// # Returns
+    /// - `Some(TimeDelta)`: Time remaining until the program ends, zero if it has already ended
+    /// - `None`: If the pin is not an extractor or has no program installed
+    pub fn extraction_remaining(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        self.extractor_details.as_ref()?;
+
+        self.expiry_time
+            .map(|expiry_time| (expiry_time - now).max(TimeDelta::zero()))
+    }
+
+    /// Calculates the number of extraction cycles remaining in the pin's extractor program
+    ///
+    /// A cycle which has started but not yet completed counts as remaining.
+    ///
+    /// # Arguments
+    /// - `now` (`DateTime<Utc>`): The time to calculate the remaining cycles from, typically `Utc::now()`
+    ///
+    /// # Returns
+    /// - `Some(i64)`: The number of cycles remaining, zero if the program has ended
+    /// - `None`: If the pin is not an extractor with an active program
+    pub fn extraction_cycles_remaining(&self, now: DateTime<Utc>) -> Option<i64> {
+        let cycle_seconds = self.extraction_cycle_time()?.num_seconds();
+        let remaining_seconds = self.extraction_remaining(now)?.num_seconds();
+
+        Some((remaining_seconds + cycle_seconds - 1) / cycle_seconds)
+    }
+
+    /// Estimates the quantity extracted per hour by the pin's extractor program
+    ///
+    /// Based upon ESI's `qty_per_cycle` which is the program's base yield, the actual yield of each
+    /// cycle varies over the course of the program.
+    ///
+    /// # Returns
+    /// - `Some(f64)`: Estimated quantity extracted per hour
+    /// - `None`: If the pin is not an extractor with an active program
+    pub fn extraction_yield_per_hour(&self) -> Option<f64> {
+        let cycle_seconds = self.extraction_cycle_time()?.num_seconds();
+        let qty_per_cycle = self.extractor_details.as_ref()?.qty_per_cycle?;
+
+        Some(qty_per_cycle as f64 * 3600.0 / cycle_seconds as f64)
+    }
+
+    /// Estimates the total quantity remaining to be extracted by the pin's extractor program
+    ///
+    /// Calculated as the remaining cycles multiplied by ESI's `qty_per_cycle`, see
+    /// [`Self::extraction_yield_per_hour`] regarding the accuracy of the estimate.
+    ///
+    /// # Arguments
+    /// - `now` (`DateTime<Utc>`): The time to calculate the remaining yield from, typically `Utc::now()`
+    ///
+    /// # Returns
+    /// - `Some(i64)`: Estimated quantity remaining to be extracted
+    /// - `None`: If the pin is not an extractor with an active program
+    pub fn extraction_yield_remaining(&self, now: DateTime<Utc>) -> Option<i64> {
+        let qty_per_cycle = self.extractor_details.as_ref()?.qty_per_cycle?;
+
+        Some(self.extraction_cycles_remaining(now)? * qty_per_cycle)
+    }
+}
+
+#[cfg(test)]
+mod planet_pin_tests {
+    use super::*;
+
+    /// Creates an extractor pin with a 2 hour cycle time expiring at the provided time
+    fn extractor(expiry_time: DateTime<Utc>) -> PlanetPin {
+        PlanetPin {
+            contents: Vec::new(),
+            expiry_time: Some(expiry_time),
+            extractor_details: Some(PlanetExtractorDetails {
+                cycle_time: Some(7200),
+                head_radius: Some(0.01),
+                heads: Vec::new(),
+                product_type_id: Some(2268),
+                qty_per_cycle: Some(6000),
+            }),
+            factory_details: None,
+            install_time: None,
+            last_cycle_start: None,
+            latitude: 0.0,
+            longitude: 0.0,
+            pin_id: 1,
+            schematic_id: None,
+            type_id: 2848,
+        }
+    }
+
+    /// Tests extraction estimates of an extractor with time remaining
+    ///
+    /// # Test Setup
+    /// - Create an extractor with 5 hours remaining & a 2 hour cycle time
+    ///
+    /// # Assertions
+    /// - Assert remaining duration is 5 hours
+    /// - Assert 3 cycles remain including the partially completed cycle
+    /// - Assert yield per hour & remaining yield are derived from the quantity per cycle
+    #[test]
+    fn test_extraction_estimates() {
+        let now = Utc::now();
+        let pin = extractor(now + TimeDelta::hours(5));
+
+        assert_eq!(pin.extraction_remaining(now), Some(TimeDelta::hours(5)));
+        assert_eq!(pin.extraction_cycles_remaining(now), Some(3));
+        assert_eq!(pin.extraction_yield_per_hour(), Some(3000.0));
+        assert_eq!(pin.extraction_yield_remaining(now), Some(18000));
+    }
+
+    /// Tests extraction estimates of an extractor whose program has ended
+    ///
+    /// # Assertions
+    /// - Assert remaining duration, cycles, & yield are zero
+    #[test]
+    fn test_extraction_expired() {
+        let now = Utc::now();
+        let pin = extractor(now - TimeDelta::hours(1));
+
+        assert_eq!(pin.extraction_remaining(now), Some(TimeDelta::zero()));
+        assert_eq!(pin.extraction_cycles_remaining(now), Some(0));
+        assert_eq!(pin.extraction_yield_remaining(now), Some(0));
+    }
+
+    /// Tests that extraction estimates are not available for non-extractor pins
+    ///
+    /// # Assertions
+    /// - Assert all estimates are `None`
+    #[test]
+    fn test_extraction_non_extractor() {
+        let now = Utc::now();
+        let mut pin = extractor(now);
+        pin.expiry_time = None;
+        pin.extractor_details = None;
+
+        assert_eq!(pin.extraction_remaining(now), None);
+        assert_eq!(pin.extraction_cycles_remaining(now), None);
+        assert_eq!(pin.extraction_yield_per_hour(), None);
+        assert_eq!(pin.extraction_yield_remaining(now), None);
+    }
+}
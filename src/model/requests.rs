@@ -0,0 +1,101 @@
+//! # EVE ESI Request Body Models
+//!
+//! Provides typed request bodies for ESI endpoints which accept a JSON object body, such as sending
+//! a new mail, creating a fitting, or inviting a character to a fleet.
+
+#[cfg(any(feature = "fittings", feature = "fleets", feature = "mail"))]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "fittings")]
+use crate::model::enums::asset::LocationFlag;
+#[cfg(feature = "fleets")]
+use crate::model::enums::fleet::FleetRole;
+#[cfg(feature = "mail")]
+use crate::model::enums::mail::MailRecipientType;
+
+/// A new EVE mail to send on behalf of a character
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdMail>
+#[cfg(feature = "mail")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewMail {
+    /// CSPA charge to approve, ISK which will be paid to recipients with CSPA charges enabled
+    #[serde(default)]
+    pub approved_cost: i64,
+    /// The body of the mail, up to 10000 characters
+    pub body: String,
+    /// The recipients of the mail, at least 1 & up to 50 recipients
+    pub recipients: Vec<MailRecipient>,
+    /// The subject of the mail, up to 1000 characters
+    pub subject: String,
+}
+
+/// A recipient of a new EVE mail
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdMail>
+#[cfg(feature = "mail")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MailRecipient {
+    /// The ID of the recipient
+    pub recipient_id: i64,
+    /// The type of the recipient
+    pub recipient_type: MailRecipientType,
+}
+
+/// A new fitting to save for a character
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdFittings>
+#[cfg(feature = "fittings")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewFitting {
+    /// Description of the fitting, up to 500 characters
+    pub description: String,
+    /// Items of the fitting, up to 512 items
+    pub items: Vec<FittingItem>,
+    /// Name of the fitting, up to 50 characters
+    pub name: String,
+    /// Type ID of the ship the fitting is for
+    pub ship_type_id: i64,
+}
+
+/// An item within a fitting
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostCharactersCharacterIdFittings>
+#[cfg(feature = "fittings")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FittingItem {
+    /// The location of the item on the ship, e.g. [`LocationFlag::HiSlot0`] or [`LocationFlag::Cargo`]
+    pub flag: LocationFlag,
+    /// The quantity of the item
+    pub quantity: i64,
+    /// The type ID of the item
+    pub type_id: i64,
+}
+
+/// An invitation for a character to join a fleet
+///
+/// The [`FleetInvitation::wing_id`] & [`FleetInvitation::squad_id`] required depend on the role: a
+/// [`FleetRole::FleetCommander`] requires neither, a [`FleetRole::WingCommander`] requires a wing ID, &
+/// squad roles require both a wing & squad ID. If neither is provided for a [`FleetRole::SquadMember`]
+/// the character is placed in any squad with space.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/operations/PostFleetsFleetIdMembers>
+#[cfg(feature = "fleets")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FleetInvitation {
+    /// The ID of the character to invite
+    pub character_id: i64,
+    /// The role to invite the character to
+    pub role: FleetRole,
+    /// The ID of the squad to invite the character to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub squad_id: Option<i64>,
+    /// The ID of the wing to invite the character to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wing_id: Option<i64>,
+}
@@ -0,0 +1,65 @@
+//! # EVE ESI Routes Models
+//!
+//! Provides route-related structs for EVE Online
+
+use serde::{Deserialize, Serialize};
+
+/// A solar system along a route annotated with recent kills & its sovereignty holder
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseSystemKillsGet>
+/// - <https://developers.eveonline.com/api-explorer#/schemas/SovereigntyMapGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RouteSystem {
+    /// The ID of the solar system
+    pub system_id: i64,
+    /// Number of player ships killed in the solar system within the last hour
+    pub ship_kills: i64,
+    /// Number of pods killed in the solar system within the last hour
+    pub pod_kills: i64,
+    /// The ID of the alliance holding sovereignty of the system if any
+    pub alliance_id: Option<i64>,
+    /// The ID of the corporation holding sovereignty of the system if any
+    pub corporation_id: Option<i64>,
+    /// The ID of the faction holding sovereignty of the system if any
+    pub faction_id: Option<i64>,
+}
+
+/// A route between 2 solar systems with each system annotated with recent kills & sovereignty
+///
+/// Built by [`RoutesEndpoints::get_route_report`](crate::endpoints::routes::RoutesEndpoints::get_route_report).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RouteReport {
+    /// The ID of the solar system the route starts at
+    pub origin: i64,
+    /// The ID of the solar system the route ends at
+    pub destination: i64,
+    /// The solar systems along the route in order, including the origin & destination
+    pub systems: Vec<RouteSystem>,
+}
+
+impl RouteReport {
+    /// Returns the number of jumps along the route
+    pub fn jumps(&self) -> usize {
+        self.systems.len().saturating_sub(1)
+    }
+
+    /// Returns the total number of player ships & pods killed along the route within the last hour
+    pub fn total_kills(&self) -> i64 {
+        self.systems
+            .iter()
+            .map(|system| system.ship_kills + system.pod_kills)
+            .sum()
+    }
+
+    /// Returns the systems along the route with at least the provided number of ship & pod kills
+    ///
+    /// # Arguments
+    /// - `min_kills` (`i64`): The minimum number of ship & pod kills within the last hour
+    pub fn dangerous_systems(&self, min_kills: i64) -> Vec<&RouteSystem> {
+        self.systems
+            .iter()
+            .filter(|system| system.ship_kills + system.pod_kills >= min_kills)
+            .collect()
+    }
+}
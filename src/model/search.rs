@@ -0,0 +1,107 @@
+//! # EVE ESI Search Models
+//!
+//! Provides search-related structs for EVE Online
+
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+use crate::model::{
+    character::CharacterAffiliation, corporation::Corporation, universe::SolarSystem,
+};
+
+/// IDs matching a search string grouped by category
+///
+/// Categories which were not searched or had no matches are empty.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdSearchGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SearchResult {
+    /// IDs of matching agents
+    #[serde(default)]
+    pub agent: Vec<i64>,
+    /// IDs of matching alliances
+    #[serde(default)]
+    pub alliance: Vec<i64>,
+    /// IDs of matching characters
+    #[serde(default)]
+    pub character: Vec<i64>,
+    /// IDs of matching constellations
+    #[serde(default)]
+    pub constellation: Vec<i64>,
+    /// IDs of matching corporations
+    #[serde(default)]
+    pub corporation: Vec<i64>,
+    /// IDs of matching NPC factions
+    #[serde(default)]
+    pub faction: Vec<i64>,
+    /// Type IDs of matching item types
+    #[serde(default)]
+    pub inventory_type: Vec<i64>,
+    /// IDs of matching regions
+    #[serde(default)]
+    pub region: Vec<i64>,
+    /// IDs of matching solar systems
+    #[serde(default)]
+    pub solar_system: Vec<i64>,
+    /// IDs of matching NPC stations
+    #[serde(default)]
+    pub station: Vec<i64>,
+    /// IDs of matching player structures
+    #[serde(default)]
+    pub structure: Vec<i64>,
+}
+
+impl SearchResult {
+    /// Returns `true` if no IDs matched in any category
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.agent,
+            &self.alliance,
+            &self.character,
+            &self.constellation,
+            &self.corporation,
+            &self.faction,
+            &self.inventory_type,
+            &self.region,
+            &self.solar_system,
+            &self.station,
+            &self.structure,
+        ]
+        .iter()
+        .all(|ids| ids.is_empty())
+    }
+}
+
+/// A character matched by a search with its name & affiliation
+///
+/// Requires the `character`, `corporation` & `universe` features.
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SearchCharacter {
+    /// The name of the character
+    pub name: String,
+    /// The corporation, alliance & faction the character belongs to
+    pub affiliation: CharacterAffiliation,
+}
+
+/// Public information of the characters, corporations & solar systems matched by a search
+///
+/// Returned by
+/// [`SearchEndpoints::resolve_search_result`](crate::endpoints::search::SearchEndpoints::resolve_search_result),
+/// each map is keyed by the matched ID.
+///
+/// Requires the `character`, `corporation` & `universe` features.
+#[cfg(all(feature = "character", feature = "corporation", feature = "universe"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ResolvedSearchResult {
+    /// Matched characters with their names & affiliations
+    pub characters: HashMap<i64, SearchCharacter>,
+    /// Matched corporations with their public information
+    pub corporations: HashMap<i64, Corporation>,
+    /// Matched solar systems with their information
+    pub solar_systems: HashMap<i64, SolarSystem>,
+}
@@ -0,0 +1,470 @@
+//! # EVE ESI Skills Models
+//!
+//! Provides skill-related structs for EVE Online, including helpers for calculating the remaining
+//! training time of a skill queue & the skillpoints trained per hour.
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Dogma attribute ID of a skill's primary training attribute
+pub const PRIMARY_ATTRIBUTE_DOGMA_ID: i64 = 180;
+/// Dogma attribute ID of a skill's secondary training attribute
+pub const SECONDARY_ATTRIBUTE_DOGMA_ID: i64 = 181;
+
+/// The attributes of a character used to train skills
+///
+/// Attributes returned by ESI do not include bonuses from implants, see [`AttributeBonuses`].
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdAttributesGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterAttributes {
+    /// The date the character's neural remap cooldown ends, if on cooldown
+    pub accrued_remap_cooldown_date: Option<DateTime<Utc>>,
+    /// The number of bonus neural remaps available to the character
+    pub bonus_remaps: Option<i64>,
+    /// The character's charisma attribute
+    pub charisma: i64,
+    /// The character's intelligence attribute
+    pub intelligence: i64,
+    /// The date the character last remapped their attributes
+    pub last_remap_date: Option<DateTime<Utc>>,
+    /// The character's memory attribute
+    pub memory: i64,
+    /// The character's perception attribute
+    pub perception: i64,
+    /// The character's willpower attribute
+    pub willpower: i64,
+}
+
+/// A character attribute used to train skills
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterAttribute {
+    /// Charisma attribute
+    Charisma,
+    /// Intelligence attribute
+    Intelligence,
+    /// Memory attribute
+    Memory,
+    /// Perception attribute
+    Perception,
+    /// Willpower attribute
+    Willpower,
+}
+
+impl CharacterAttribute {
+    /// Converts the value of a skill's [`PRIMARY_ATTRIBUTE_DOGMA_ID`] or [`SECONDARY_ATTRIBUTE_DOGMA_ID`]
+    /// dogma attribute, which is the dogma attribute ID of the character attribute, into a [`CharacterAttribute`]
+    ///
+    /// # Arguments
+    /// - `attribute_id` (`i64`): The dogma attribute ID of the character attribute
+    ///
+    /// # Returns
+    /// - `Some(CharacterAttribute)`: The character attribute with the provided dogma attribute ID
+    /// - `None`: If the dogma attribute ID is not a character attribute
+    pub fn from_dogma_attribute_id(attribute_id: i64) -> Option<Self> {
+        match attribute_id {
+            164 => Some(CharacterAttribute::Charisma),
+            165 => Some(CharacterAttribute::Intelligence),
+            166 => Some(CharacterAttribute::Memory),
+            167 => Some(CharacterAttribute::Perception),
+            168 => Some(CharacterAttribute::Willpower),
+            _ => None,
+        }
+    }
+
+    /// Returns the dogma attribute ID used by implants to bonus the character attribute
+    pub fn implant_bonus_dogma_attribute_id(&self) -> i64 {
+        match self {
+            CharacterAttribute::Charisma => 175,
+            CharacterAttribute::Intelligence => 176,
+            CharacterAttribute::Memory => 177,
+            CharacterAttribute::Perception => 178,
+            CharacterAttribute::Willpower => 179,
+        }
+    }
+}
+
+/// Bonuses to a character's attributes from their active implants
+///
+/// Created from the dogma attributes of the implants returned by
+/// [`ClonesEndpoints::get_active_implants`](crate::endpoints::clones::ClonesEndpoints::get_active_implants)
+/// using [`AttributeBonuses::from_dogma_attributes`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AttributeBonuses {
+    /// Bonus to the charisma attribute
+    pub charisma: i64,
+    /// Bonus to the intelligence attribute
+    pub intelligence: i64,
+    /// Bonus to the memory attribute
+    pub memory: i64,
+    /// Bonus to the perception attribute
+    pub perception: i64,
+    /// Bonus to the willpower attribute
+    pub willpower: i64,
+}
+
+impl AttributeBonuses {
+    /// Sums the attribute bonuses of the provided implant dogma attributes
+    ///
+    /// Dogma attributes which are not attribute bonuses are ignored.
+    ///
+    /// # Arguments
+    /// - `attributes` (`impl IntoIterator<Item = (i64, f64)>`): The `(attribute_id, value)` pairs of the
+    ///   dogma attributes of each active implant
+    ///
+    /// # Returns
+    /// - [`AttributeBonuses`]: The combined bonuses of the implants
+    pub fn from_dogma_attributes(attributes: impl IntoIterator<Item = (i64, f64)>) -> Self {
+        let mut bonuses = Self::default();
+
+        for (attribute_id, value) in attributes {
+            let bonus = match attribute_id {
+                175 => &mut bonuses.charisma,
+                176 => &mut bonuses.intelligence,
+                177 => &mut bonuses.memory,
+                178 => &mut bonuses.perception,
+                179 => &mut bonuses.willpower,
+                _ => continue,
+            };
+
+            *bonus += value as i64;
+        }
+
+        bonuses
+    }
+
+    /// Returns the bonus to the provided attribute
+    pub fn get(&self, attribute: CharacterAttribute) -> i64 {
+        match attribute {
+            CharacterAttribute::Charisma => self.charisma,
+            CharacterAttribute::Intelligence => self.intelligence,
+            CharacterAttribute::Memory => self.memory,
+            CharacterAttribute::Perception => self.perception,
+            CharacterAttribute::Willpower => self.willpower,
+        }
+    }
+}
+
+impl CharacterAttributes {
+    /// Returns the value of the provided attribute excluding implant bonuses
+    pub fn get(&self, attribute: CharacterAttribute) -> i64 {
+        match attribute {
+            CharacterAttribute::Charisma => self.charisma,
+            CharacterAttribute::Intelligence => self.intelligence,
+            CharacterAttribute::Memory => self.memory,
+            CharacterAttribute::Perception => self.perception,
+            CharacterAttribute::Willpower => self.willpower,
+        }
+    }
+
+    /// Calculates the skillpoints trained per hour for a skill with the provided training attributes
+    ///
+    /// Skills train at `primary + secondary / 2` skillpoints per minute. Omega clone state is assumed,
+    /// alpha clones train at half the rate.
+    ///
+    /// # Arguments
+    /// - `primary` ([`CharacterAttribute`]): The skill's primary training attribute
+    /// - `secondary` ([`CharacterAttribute`]): The skill's secondary training attribute
+    /// - `bonuses` (&[`AttributeBonuses`]): Attribute bonuses from the character's active implants
+    ///
+    /// # Returns
+    /// - `f64`: The skillpoints trained per hour
+    pub fn sp_per_hour(
+        &self,
+        primary: CharacterAttribute,
+        secondary: CharacterAttribute,
+        bonuses: &AttributeBonuses,
+    ) -> f64 {
+        let primary = (self.get(primary) + bonuses.get(primary)) as f64;
+        let secondary = (self.get(secondary) + bonuses.get(secondary)) as f64;
+
+        (primary + secondary / 2.0) * 60.0
+    }
+}
+
+/// An entry within a character's skill queue
+///
+/// Entries of a paused skill queue have no start or finish date.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdSkillqueueGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SkillQueueEntry {
+    /// The date the skill finishes training, `None` if the queue is paused
+    pub finish_date: Option<DateTime<Utc>>,
+    /// The level the skill will be trained to
+    pub finished_level: i64,
+    /// The skillpoints of the skill once the level is trained
+    pub level_end_sp: Option<i64>,
+    /// The skillpoints of the skill at the start of the level
+    pub level_start_sp: Option<i64>,
+    /// The position of the entry within the queue starting at 0
+    pub queue_position: i64,
+    /// The type ID of the skill
+    pub skill_id: i64,
+    /// The date the skill started training, `None` if the queue is paused
+    pub start_date: Option<DateTime<Utc>>,
+    /// The skillpoints of the skill when the entry started training
+    pub training_start_sp: Option<i64>,
+}
+
+impl SkillQueueEntry {
+    /// Returns the skillpoints remaining to train the entry from when it started training
+    ///
+    /// # Returns
+    /// - `Some(i64)`: The skillpoints remaining, zero if the level has already been reached
+    /// - `None`: If ESI did not provide the skillpoints at the end of the level
+    pub fn remaining_sp(&self) -> Option<i64> {
+        let start_sp = self.training_start_sp.or(self.level_start_sp).unwrap_or(0);
+
+        self.level_end_sp.map(|end_sp| (end_sp - start_sp).max(0))
+    }
+}
+
+/// A character's skill queue ordered by queue position
+///
+/// Wraps the entries returned by
+/// [`SkillsEndpoints::get_character_skill_queue`](crate::endpoints::skills::SkillsEndpoints::get_character_skill_queue)
+/// to calculate the remaining training time of the queue.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SkillQueue {
+    /// The entries of the skill queue ordered by queue position
+    pub entries: Vec<SkillQueueEntry>,
+}
+
+impl SkillQueue {
+    /// Creates a new [`SkillQueue`] ordering the provided entries by queue position
+    ///
+    /// # Arguments
+    /// - `entries` (`Vec<SkillQueueEntry>`): The entries of the skill queue
+    pub fn new(mut entries: Vec<SkillQueueEntry>) -> Self {
+        entries.sort_by_key(|entry| entry.queue_position);
+
+        Self { entries }
+    }
+
+    /// Returns whether the skill queue is paused
+    ///
+    /// A queue is paused when it contains entries but none of them have a finish date.
+    pub fn is_paused(&self) -> bool {
+        !self.entries.is_empty() && self.entries.iter().all(|entry| entry.finish_date.is_none())
+    }
+
+    /// Calculates the time remaining until the last skill in the queue finishes training
+    ///
+    /// # Arguments
+    /// - `now` (`DateTime<Utc>`): The time to calculate the remaining duration from, typically `Utc::now()`
+    ///
+    /// # Returns
+    /// - `Some(TimeDelta)`: Time remaining until the queue completes, zero if empty or already completed
+    /// - `None`: If the queue is paused, see [`Self::projected_finish_dates`]
+    pub fn remaining_time(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        if self.is_paused() {
+            return None;
+        }
+
+        Some(
+            self.entries
+                .iter()
+                .filter_map(|entry| entry.finish_date)
+                .max()
+                .map(|finish_date| (finish_date - now).max(TimeDelta::zero()))
+                .unwrap_or_else(TimeDelta::zero),
+        )
+    }
+
+    /// Projects the finish date of each entry in the queue, including when the queue is paused
+    ///
+    /// Entries with a finish date use the date provided by ESI. Entries without a finish date, such
+    /// as those of a paused queue, are projected sequentially from the finish of the previous entry
+    /// or `now` as if the queue were resumed, using the remaining skillpoints of the entry & the
+    /// training rate provided by `sp_per_hour`.
+    ///
+    /// # Arguments
+    /// - `now` (`DateTime<Utc>`): The time to project paused entries from, typically `Utc::now()`
+    /// - `sp_per_hour` (`impl Fn(&SkillQueueEntry) -> f64`): The skillpoints trained per hour for an entry,
+    ///   e.g. using [`CharacterAttributes::sp_per_hour`] with the skill's training attributes
+    ///
+    /// # Returns
+    /// - `Vec<Option<DateTime<Utc>>>`: The finish date of each entry in queue order, `None` for entries
+    ///   which can't be projected due to missing skillpoints or a training rate of zero
+    pub fn projected_finish_dates(
+        &self,
+        now: DateTime<Utc>,
+        sp_per_hour: impl Fn(&SkillQueueEntry) -> f64,
+    ) -> Vec<Option<DateTime<Utc>>> {
+        let mut cursor = Some(now);
+
+        self.entries
+            .iter()
+            .map(|entry| {
+                if let Some(finish_date) = entry.finish_date {
+                    cursor = Some(finish_date.max(now));
+                    return Some(finish_date);
+                }
+
+                let rate = sp_per_hour(entry);
+                let remaining_sp = entry.remaining_sp().filter(|_| rate > 0.0);
+
+                cursor = match (cursor, remaining_sp) {
+                    (Some(start), Some(remaining_sp)) => {
+                        let seconds = (remaining_sp as f64 / rate * 3600.0).ceil() as i64;
+                        Some(start + TimeDelta::seconds(seconds))
+                    }
+                    // Later entries can't be projected without the finish of this entry
+                    _ => None,
+                };
+
+                cursor
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod skill_queue_tests {
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    use super::{
+        AttributeBonuses, CharacterAttribute, CharacterAttributes, SkillQueue, SkillQueueEntry,
+    };
+
+    /// Creates a mock skill queue entry requiring the provided skillpoints to train
+    fn mock_entry(
+        queue_position: i64,
+        remaining_sp: i64,
+        finish_date: Option<chrono::DateTime<Utc>>,
+    ) -> SkillQueueEntry {
+        SkillQueueEntry {
+            finish_date,
+            finished_level: 5,
+            level_end_sp: Some(256000 + remaining_sp),
+            level_start_sp: Some(256000),
+            queue_position,
+            skill_id: 3300 + queue_position,
+            start_date: finish_date.map(|_| Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+            training_start_sp: Some(256000),
+        }
+    }
+
+    /// Creates mock character attributes with 27 in each attribute
+    fn mock_attributes() -> CharacterAttributes {
+        CharacterAttributes {
+            accrued_remap_cooldown_date: None,
+            bonus_remaps: Some(1),
+            charisma: 27,
+            intelligence: 27,
+            last_remap_date: None,
+            memory: 27,
+            perception: 27,
+            willpower: 27,
+        }
+    }
+
+    /// Tests calculating skillpoints per hour with implant bonuses
+    ///
+    /// # Test Setup
+    /// - Create character attributes with 27 in each attribute
+    /// - Create attribute bonuses from +5 perception & willpower implant dogma attributes
+    ///
+    /// # Assertions
+    /// - Assert unrelated dogma attributes were ignored
+    /// - Assert SP/hour is calculated as `(primary + secondary / 2) * 60`
+    #[test]
+    fn test_sp_per_hour() {
+        let attributes = mock_attributes();
+        let bonuses = AttributeBonuses::from_dogma_attributes([(178, 5.0), (179, 5.0), (331, 1.0)]);
+
+        assert_eq!(bonuses.perception, 5);
+        assert_eq!(bonuses.willpower, 5);
+        assert_eq!(bonuses.memory, 0);
+
+        let rate = attributes.sp_per_hour(
+            CharacterAttribute::Perception,
+            CharacterAttribute::Willpower,
+            &bonuses,
+        );
+        assert_eq!(rate, (32.0 + 16.0) * 60.0);
+
+        let rate = attributes.sp_per_hour(
+            CharacterAttribute::Memory,
+            CharacterAttribute::Intelligence,
+            &AttributeBonuses::default(),
+        );
+        assert_eq!(rate, (27.0 + 13.5) * 60.0);
+    }
+
+    /// Tests remaining training time of an active skill queue
+    ///
+    /// # Test Setup
+    /// - Create an active queue with entries finishing in 2 & 10 hours, provided out of order
+    ///
+    /// # Assertions
+    /// - Assert the queue is not paused & ordered by queue position
+    /// - Assert the remaining time is until the last entry finishes
+    /// - Assert ESI finish dates are used as the projected finish dates
+    #[test]
+    fn test_active_skill_queue() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let queue = SkillQueue::new(vec![
+            mock_entry(1, 1000, Some(now + TimeDelta::hours(10))),
+            mock_entry(0, 1000, Some(now + TimeDelta::hours(2))),
+        ]);
+
+        assert!(!queue.is_paused());
+        assert_eq!(queue.entries[0].queue_position, 0);
+        assert_eq!(queue.remaining_time(now), Some(TimeDelta::hours(10)));
+        assert_eq!(
+            queue.projected_finish_dates(now, |_| 1800.0),
+            vec![
+                Some(now + TimeDelta::hours(2)),
+                Some(now + TimeDelta::hours(10))
+            ]
+        );
+    }
+
+    /// Tests remaining training time of a paused skill queue
+    ///
+    /// # Test Setup
+    /// - Create a paused queue with entries requiring 3600 & 1800 skillpoints
+    ///
+    /// # Assertions
+    /// - Assert the queue is paused & has no remaining time
+    /// - Assert finish dates are projected sequentially from now at 1800 SP/hour
+    #[test]
+    fn test_paused_skill_queue() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let queue = SkillQueue::new(vec![mock_entry(0, 3600, None), mock_entry(1, 1800, None)]);
+
+        assert!(queue.is_paused());
+        assert_eq!(queue.remaining_time(now), None);
+        assert_eq!(
+            queue.projected_finish_dates(now, |_| 1800.0),
+            vec![
+                Some(now + TimeDelta::hours(2)),
+                Some(now + TimeDelta::hours(3))
+            ]
+        );
+
+        // Assert entries can't be projected without a training rate
+        assert_eq!(queue.projected_finish_dates(now, |_| 0.0), vec![None, None]);
+    }
+
+    /// Tests remaining training time of an empty or completed skill queue
+    ///
+    /// # Assertions
+    /// - Assert an empty queue is not paused & has no remaining time
+    /// - Assert a queue which finished in the past has no remaining time
+    #[test]
+    fn test_completed_skill_queue() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        let queue = SkillQueue::new(Vec::new());
+        assert!(!queue.is_paused());
+        assert_eq!(queue.remaining_time(now), Some(TimeDelta::zero()));
+
+        let queue = SkillQueue::new(vec![mock_entry(0, 1000, Some(now - TimeDelta::hours(1)))]);
+        assert_eq!(queue.remaining_time(now), Some(TimeDelta::zero()));
+    }
+}
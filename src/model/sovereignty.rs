@@ -0,0 +1,273 @@
+//! # EVE ESI Sovereignty Models
+//!
+//! Provides sovereignty-related structs for EVE Online
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "corporation")]
+use super::corporation::{CorporationStructure, StructureState, StructureTimerStage};
+
+/// The sovereignty holder of a solar system
+///
+/// Systems held by a faction, such as NPC null-sec & faction warfare systems, only have a
+/// faction ID while player-held systems have an alliance & corporation ID.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/SovereigntyMapGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SovereigntyMapSystem {
+    /// The ID of the alliance holding sovereignty of the system
+    pub alliance_id: Option<i64>,
+    /// The ID of the corporation holding sovereignty of the system
+    pub corporation_id: Option<i64>,
+    /// The ID of the faction holding sovereignty of the system
+    pub faction_id: Option<i64>,
+    /// The ID of the solar system
+    pub system_id: i64,
+}
+
+/// A sovereignty structure & its vulnerability window
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/SovereigntyStructuresGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SovereigntyStructure {
+    /// The ID of the alliance that owns the structure
+    pub alliance_id: i64,
+    /// The ID of the solar system the structure is located in
+    pub solar_system_id: i64,
+    /// The unique ID of the structure
+    pub structure_id: i64,
+    /// The type ID of the structure
+    pub structure_type_id: i64,
+    /// The occupancy level of the system which determines the structure's defense multiplier
+    pub vulnerability_occupancy_level: Option<f64>,
+    /// When the structure's next vulnerability window opens
+    pub vulnerable_start_time: Option<DateTime<Utc>>,
+    /// When the structure's next vulnerability window closes
+    pub vulnerable_end_time: Option<DateTime<Utc>>,
+}
+
+/// The structure an upcoming [`Timer`] applies to
+///
+/// Requires the `corporation` feature.
+#[cfg(feature = "corporation")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TimerStructure {
+    /// The unique ID of the structure
+    pub structure_id: i64,
+    /// The ID of the solar system the structure is located in
+    pub system_id: i64,
+    /// The type ID of the structure
+    pub type_id: i64,
+    /// Name of the structure, only known for corporation structures
+    pub name: Option<String>,
+}
+
+/// The kind of an upcoming [`Timer`]
+///
+/// Requires the `corporation` feature.
+#[cfg(feature = "corporation")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TimerKind {
+    /// A sovereignty structure's vulnerability window opens
+    SovereigntyVulnerability {
+        /// When the vulnerability window closes, if known
+        ends: Option<DateTime<Utc>>,
+    },
+    /// An Upwell structure exits reinforcement & becomes vulnerable
+    Reinforcement {
+        /// The timer the structure is reinforced for
+        stage: StructureTimerStage,
+    },
+    /// An Upwell structure unanchors
+    Unanchor,
+}
+
+/// An upcoming sovereignty or Upwell structure timer
+///
+/// Created from sovereignty & corporation structures using [`upcoming_timers`].
+///
+/// Requires the `corporation` feature.
+#[cfg(feature = "corporation")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Timer {
+    /// The structure the timer applies to
+    pub structure: TimerStructure,
+    /// The kind of timer
+    pub kind: TimerKind,
+    /// When the timer elapses
+    pub at: DateTime<Utc>,
+}
+
+/// Combines sovereignty vulnerability windows & corporation structure timers into a single list
+///
+/// Sovereignty structures produce a [`TimerKind::SovereigntyVulnerability`] timer when their next
+/// vulnerability window opens. Corporation structures produce a [`TimerKind::Reinforcement`] timer
+/// when reinforced & a [`TimerKind::Unanchor`] timer when scheduled to unanchor, see
+/// [`StructureState::from_structure`]. Timers which elapsed before `now` are excluded.
+///
+/// Requires the `corporation` feature.
+///
+/// # Arguments
+/// - `sovereignty_structures` (&[[`SovereigntyStructure`]]): Sovereignty structures to include windows of
+/// - `structures` (&[[`CorporationStructure`]]): Corporation structures to include timers of
+/// - `now` ([`DateTime<Utc>`]): Timers elapsing before this timestamp are excluded
+///
+/// # Returns
+/// - [`Vec<Timer>`]: The upcoming timers sorted chronologically
+#[cfg(feature = "corporation")]
+pub fn upcoming_timers(
+    sovereignty_structures: &[SovereigntyStructure],
+    structures: &[CorporationStructure],
+    now: DateTime<Utc>,
+) -> Vec<Timer> {
+    let sovereignty_timers = sovereignty_structures.iter().filter_map(|structure| {
+        Some(Timer {
+            structure: TimerStructure {
+                structure_id: structure.structure_id,
+                system_id: structure.solar_system_id,
+                type_id: structure.structure_type_id,
+                name: None,
+            },
+            kind: TimerKind::SovereigntyVulnerability {
+                ends: structure.vulnerable_end_time,
+            },
+            at: structure.vulnerable_start_time?,
+        })
+    });
+
+    let structure_timers = structures.iter().filter_map(|structure| {
+        let (kind, at) = match StructureState::from_structure(structure) {
+            StructureState::Reinforced {
+                stage,
+                ends: Some(ends),
+            } => (TimerKind::Reinforcement { stage }, ends),
+            StructureState::Unanchoring { at } => (TimerKind::Unanchor, at),
+            _ => return None,
+        };
+
+        Some(Timer {
+            structure: TimerStructure {
+                structure_id: structure.structure_id,
+                system_id: structure.system_id,
+                type_id: structure.type_id,
+                name: structure.name.clone(),
+            },
+            kind,
+            at,
+        })
+    });
+
+    let mut timers: Vec<Timer> = sovereignty_timers
+        .chain(structure_timers)
+        .filter(|timer| timer.at >= now)
+        .collect();
+    timers.sort_by_key(|timer| timer.at);
+
+    timers
+}
+
+#[cfg(test)]
+mod upcoming_timers_tests {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{upcoming_timers, SovereigntyStructure, TimerKind};
+    use crate::model::corporation::{CorporationStructure, StructureTimerStage};
+    use crate::model::enums::corporation::CorporationStructureState;
+
+    /// Creates a mock sovereignty structure with the provided vulnerability window start
+    fn mock_sovereignty_structure(
+        structure_id: i64,
+        vulnerable_start_time: Option<DateTime<Utc>>,
+    ) -> SovereigntyStructure {
+        SovereigntyStructure {
+            alliance_id: 99003214,
+            solar_system_id: 30004759,
+            structure_id,
+            structure_type_id: 32226,
+            vulnerability_occupancy_level: Some(2.0),
+            vulnerable_start_time,
+            vulnerable_end_time: None,
+        }
+    }
+
+    /// Creates a mock corporation structure with the provided state & timer end
+    fn mock_structure(
+        structure_id: i64,
+        state: CorporationStructureState,
+        state_timer_end: Option<DateTime<Utc>>,
+    ) -> CorporationStructure {
+        CorporationStructure {
+            corporation_id: 98785281,
+            fuel_expires: None,
+            name: Some("Test Structure".to_string()),
+            next_reinforce_apply: None,
+            next_reinforce_hour: None,
+            profile_id: 1,
+            reinforce_hour: Some(18),
+            services: Vec::new(),
+            state,
+            state_timer_end,
+            state_timer_start: None,
+            structure_id,
+            system_id: 30000142,
+            type_id: 35832,
+            unanchors_at: None,
+        }
+    }
+
+    /// Tests combining sovereignty & corporation structure timers
+    ///
+    /// # Test Setup
+    /// - Create sovereignty structures with a past, future & unknown vulnerability window
+    /// - Create a reinforced, an unanchoring & an online corporation structure
+    ///
+    /// # Assertions
+    /// - Assert past & unknown timers and online structures are excluded
+    /// - Assert timers are sorted chronologically with the expected kinds
+    #[test]
+    fn test_upcoming_timers() {
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let hours = |hours: i64| now + chrono::Duration::hours(hours);
+
+        let sovereignty_structures = vec![
+            mock_sovereignty_structure(1, Some(hours(-1))),
+            mock_sovereignty_structure(2, Some(hours(12))),
+            mock_sovereignty_structure(3, None),
+        ];
+
+        let mut unanchoring = mock_structure(6, CorporationStructureState::ShieldVulnerable, None);
+        unanchoring.unanchors_at = Some(hours(2));
+        let structures = vec![
+            mock_structure(4, CorporationStructureState::HullReinforce, Some(hours(24))),
+            mock_structure(5, CorporationStructureState::ShieldVulnerable, None),
+            unanchoring,
+        ];
+
+        let timers = upcoming_timers(&sovereignty_structures, &structures, now);
+
+        // Assert past & unknown timers and online structures are excluded
+        let structure_ids: Vec<i64> = timers
+            .iter()
+            .map(|timer| timer.structure.structure_id)
+            .collect();
+        assert_eq!(structure_ids, vec![6, 2, 4]);
+
+        // Assert timers are sorted chronologically with the expected kinds
+        assert_eq!(timers[0].kind, TimerKind::Unanchor);
+        assert_eq!(timers[0].at, hours(2));
+        assert_eq!(
+            timers[1].kind,
+            TimerKind::SovereigntyVulnerability { ends: None }
+        );
+        assert_eq!(
+            timers[2].kind,
+            TimerKind::Reinforcement {
+                stage: StructureTimerStage::Hull
+            }
+        );
+        assert_eq!(timers[2].structure.name.as_deref(), Some("Test Structure"));
+    }
+}
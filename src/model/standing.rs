@@ -1,6 +1,7 @@
 //! # EVE ESI Standing Models
 //!
-//! This module define the [`Standing`] model shared between characters & corporations
+//! This module define the [`Standing`] model shared between characters & corporations & the
+//! [`effective_standing`] helper which merges a character's standings with their corporation's
 
 use serde::{Deserialize, Serialize};
 
@@ -20,3 +21,215 @@ pub struct Standing {
     /// The character's standing with the listed entity
     pub standing: f64,
 }
+
+/// Levels of the social skills which modify a character's standings
+///
+/// Each level moves the character's standing 4% of the way towards `10.0`, Connections applies to
+/// positive standings & Diplomacy to negative standings.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StandingSkills {
+    /// Trained level of the Connections skill, `0` to `5`
+    pub connections: u8,
+    /// Trained level of the Diplomacy skill, `0` to `5`
+    pub diplomacy: u8,
+}
+
+impl StandingSkills {
+    /// Returns the standing modified by the skill which applies to it
+    ///
+    /// # Arguments
+    /// - `standing` (`f64`): The unmodified standing as returned by ESI
+    pub fn apply(&self, standing: f64) -> f64 {
+        let level = if standing > 0.0 {
+            self.connections
+        } else if standing < 0.0 {
+            self.diplomacy
+        } else {
+            0
+        };
+
+        standing + (10.0 - standing) * 0.04 * f64::from(level.min(5))
+    }
+}
+
+/// Whether an [`EffectiveStanding`] is the character's personal standing or their corporation's
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandingSource {
+    /// The character's personal standing
+    Character,
+    /// The standing of the character's corporation
+    Corporation,
+}
+
+/// The standing which applies to a character towards an NPC entity
+///
+/// Returned by [`effective_standing`] & [`merge_standings`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EffectiveStanding {
+    /// The ID of the entity the standing is with
+    pub from_id: i64,
+    /// The type of entity the standing entry is with (Agent, NpcCorp, or Faction)
+    pub from_type: StandingType,
+    /// The standing with the entity, including skill modifiers for personal standings
+    pub standing: f64,
+    /// Whether the standing is the character's or their corporation's
+    pub source: StandingSource,
+}
+
+/// Returns the standing which applies to a character towards the provided NPC entity
+///
+/// A personal standing overrides the corporation's standing towards the same entity, the
+/// corporation's standing is used only if the character has no standing with the entity. Skills
+/// are applied to personal standings only.
+///
+/// # Arguments
+/// - `character_standings` (`&[Standing]`): The character's standings, see
+///   [`CharacterEndpoints::get_standings`](crate::endpoints::character::CharacterEndpoints::get_standings)
+/// - `corporation_standings` (`&[Standing]`): The standings of the character's corporation, see
+///   [`CorporationEndpoints::get_corporation_standings`](crate::endpoints::corporation::CorporationEndpoints::get_corporation_standings)
+/// - `from_id` (`i64`): The ID of the agent, NPC corporation or faction
+/// - `skills` (`Option<StandingSkills>`): The character's social skills, `None` to use unmodified standings
+///
+/// # Returns
+/// The effective standing, or `None` if neither the character nor corporation has a standing
+/// with the entity
+pub fn effective_standing(
+    character_standings: &[Standing],
+    corporation_standings: &[Standing],
+    from_id: i64,
+    skills: Option<StandingSkills>,
+) -> Option<EffectiveStanding> {
+    if let Some(standing) = character_standings.iter().find(|s| s.from_id == from_id) {
+        return Some(EffectiveStanding::personal(standing, skills));
+    }
+
+    corporation_standings
+        .iter()
+        .find(|s| s.from_id == from_id)
+        .map(EffectiveStanding::corporation)
+}
+
+/// Merges a character's standings with their corporation's into the standings which apply to the character
+///
+/// See [`effective_standing`] for the precedence rules.
+///
+/// # Arguments
+/// - `character_standings` (`&[Standing]`): The character's standings
+/// - `corporation_standings` (`&[Standing]`): The standings of the character's corporation
+/// - `skills` (`Option<StandingSkills>`): The character's social skills, `None` to use unmodified standings
+///
+/// # Returns
+/// The effective standing towards every entity either the character or corporation has a standing with
+pub fn merge_standings(
+    character_standings: &[Standing],
+    corporation_standings: &[Standing],
+    skills: Option<StandingSkills>,
+) -> Vec<EffectiveStanding> {
+    let mut merged: Vec<EffectiveStanding> = character_standings
+        .iter()
+        .map(|standing| EffectiveStanding::personal(standing, skills))
+        .collect();
+
+    merged.extend(
+        corporation_standings
+            .iter()
+            .filter(|standing| {
+                !character_standings
+                    .iter()
+                    .any(|s| s.from_id == standing.from_id)
+            })
+            .map(EffectiveStanding::corporation),
+    );
+
+    merged
+}
+
+impl EffectiveStanding {
+    /// Creates an effective standing from a character's standing, applying skills if provided
+    fn personal(standing: &Standing, skills: Option<StandingSkills>) -> Self {
+        Self {
+            from_id: standing.from_id,
+            from_type: standing.from_type.clone(),
+            standing: skills.map_or(standing.standing, |skills| skills.apply(standing.standing)),
+            source: StandingSource::Character,
+        }
+    }
+
+    /// Creates an effective standing from a corporation's standing
+    fn corporation(standing: &Standing) -> Self {
+        Self {
+            from_id: standing.from_id,
+            from_type: standing.from_type.clone(),
+            standing: standing.standing,
+            source: StandingSource::Corporation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod standing_tests {
+    use super::*;
+
+    fn mock_standing(from_id: i64, standing: f64) -> Standing {
+        Standing {
+            from_id,
+            from_type: StandingType::Faction,
+            standing,
+        }
+    }
+
+    /// Tests skills modify positive & negative standings with the respective skill
+    ///
+    /// # Assertions
+    /// - Assert Connections moves a positive standing towards 10
+    /// - Assert Diplomacy moves a negative standing towards 10
+    /// - Assert a neutral standing is unchanged
+    #[test]
+    fn test_standing_skills_apply() {
+        let skills = StandingSkills {
+            connections: 5,
+            diplomacy: 4,
+        };
+
+        assert!((skills.apply(5.0) - 6.0).abs() < 1e-9);
+        assert!((skills.apply(-5.0) - -2.6).abs() < 1e-9);
+        assert_eq!(skills.apply(0.0), 0.0);
+    }
+
+    /// Tests personal standings override corporation standings
+    ///
+    /// # Test Setup
+    /// - Create character & corporation standings which both contain faction 500001
+    ///
+    /// # Assertions
+    /// - Assert the personal standing is used with skills applied when both exist
+    /// - Assert the corporation standing is used without skills when no personal standing exists
+    /// - Assert `None` is returned when neither has a standing
+    /// - Assert merging returns one standing per entity
+    #[test]
+    fn test_effective_standing_precedence() {
+        let character = vec![mock_standing(500001, -2.0)];
+        let corporation = vec![mock_standing(500001, 8.0), mock_standing(500002, 5.0)];
+        let skills = Some(StandingSkills {
+            connections: 5,
+            diplomacy: 5,
+        });
+
+        let personal = effective_standing(&character, &corporation, 500001, skills).unwrap();
+        assert_eq!(personal.source, StandingSource::Character);
+        assert!((personal.standing - 0.4).abs() < 1e-9);
+
+        let corp = effective_standing(&character, &corporation, 500002, skills).unwrap();
+        assert_eq!(corp.source, StandingSource::Corporation);
+        assert_eq!(corp.standing, 5.0);
+
+        assert_eq!(
+            effective_standing(&character, &corporation, 500003, skills),
+            None
+        );
+
+        let merged = merge_standings(&character, &corporation, None);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].standing, -2.0);
+    }
+}
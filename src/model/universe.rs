@@ -4,6 +4,19 @@
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(all(feature = "alliance", feature = "character", feature = "corporation"))]
+use crate::model::alliance::Alliance;
+#[cfg(all(feature = "alliance", feature = "character", feature = "corporation"))]
+use crate::model::character::Character;
+#[cfg(all(feature = "alliance", feature = "character", feature = "corporation"))]
+use crate::model::corporation::Corporation;
+use crate::model::enums::universe::{SecurityClass, UniverseNameCategory};
+
+/// Region ID of Pochven
+pub const POCHVEN_REGION_ID: i64 = 10000070;
+/// Range of solar system IDs within wormhole space
+pub const WORMHOLE_SYSTEM_IDS: std::ops::Range<i64> = 31_000_000..32_000_000;
+
 /// Represents an NPC faction in EVE Online
 ///
 /// # Documentation
@@ -16,6 +29,7 @@ pub struct Faction {
     pub description: String,
     /// Unique ID of the faction
     pub faction_id: i64,
+    /// Whether the faction is unique
     pub is_unique: bool,
     /// The faction warfare militia corporation if applicable
     pub militia_corporation_id: Option<i64>,
@@ -30,3 +44,469 @@ pub struct Faction {
     /// The amount of systems the NPC faction has stations in
     pub station_system_count: i64,
 }
+
+/// The name & category of an ID resolved using the universe names endpoint
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseNamesPost>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct UniverseName {
+    /// The category the ID belongs to
+    pub category: UniverseNameCategory,
+    /// The ID which was resolved
+    pub id: i64,
+    /// The name of the ID
+    pub name: String,
+}
+
+/// The public information of an ID which belongs to an alliance, character or corporation
+///
+/// Returned by [`Client::entity`](crate::Client::entity), requires the `alliance`, `character` &
+/// `corporation` features.
+#[cfg(all(feature = "alliance", feature = "character", feature = "corporation"))]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum Entity {
+    /// The ID belongs to an alliance
+    Alliance {
+        /// The ID of the alliance
+        alliance_id: i64,
+        /// The alliance's public information
+        alliance: Alliance,
+    },
+    /// The ID belongs to a character
+    Character {
+        /// The ID of the character
+        character_id: i64,
+        /// The character's public information
+        character: Character,
+    },
+    /// The ID belongs to a corporation
+    Corporation {
+        /// The ID of the corporation
+        corporation_id: i64,
+        /// The corporation's public information
+        corporation: Corporation,
+    },
+}
+
+#[cfg(all(feature = "alliance", feature = "character", feature = "corporation"))]
+impl Entity {
+    /// Returns the ID of the alliance, character or corporation
+    pub fn id(&self) -> i64 {
+        match self {
+            Self::Alliance { alliance_id, .. } => *alliance_id,
+            Self::Character { character_id, .. } => *character_id,
+            Self::Corporation { corporation_id, .. } => *corporation_id,
+        }
+    }
+
+    /// Returns the name of the alliance, character or corporation
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Alliance { alliance, .. } => &alliance.name,
+            Self::Character { character, .. } => &character.name,
+            Self::Corporation { corporation, .. } => &corporation.name,
+        }
+    }
+
+    /// Returns the category of the ID
+    pub fn category(&self) -> UniverseNameCategory {
+        match self {
+            Self::Alliance { .. } => UniverseNameCategory::Alliance,
+            Self::Character { .. } => UniverseNameCategory::Character,
+            Self::Corporation { .. } => UniverseNameCategory::Corporation,
+        }
+    }
+}
+
+/// Number of ship, pod, & NPC kills in a solar system within the last hour
+///
+/// Only solar systems with at least 1 kill are included in the response.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseSystemKillsGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SystemKills {
+    /// Number of NPC ships killed in the solar system
+    pub npc_kills: i64,
+    /// Number of pods killed in the solar system
+    pub pod_kills: i64,
+    /// Number of player ships killed in the solar system
+    pub ship_kills: i64,
+    /// The ID of the solar system
+    pub system_id: i64,
+}
+
+/// Number of ship jumps into a solar system within the last hour
+///
+/// Only solar systems with at least 1 jump are included in the response, wormhole space
+/// is excluded.
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseSystemJumpsGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SystemJumps {
+    /// Number of ships which jumped into the solar system
+    pub ship_jumps: i64,
+    /// The ID of the solar system
+    pub system_id: i64,
+}
+
+/// Coordinates of an object in space
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseSystemsSystemIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct UniversePosition {
+    /// X coordinate
+    pub x: f64,
+    /// Y coordinate
+    pub y: f64,
+    /// Z coordinate
+    pub z: f64,
+}
+
+/// A planet within a solar system
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseSystemsSystemIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SolarSystemPlanet {
+    /// IDs of the asteroid belts orbiting the planet
+    #[serde(default)]
+    pub asteroid_belts: Vec<i64>,
+    /// IDs of the moons orbiting the planet
+    #[serde(default)]
+    pub moons: Vec<i64>,
+    /// The ID of the planet
+    pub planet_id: i64,
+}
+
+/// Information on a solar system
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseSystemsSystemIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SolarSystem {
+    /// The ID of the constellation the solar system is in
+    pub constellation_id: i64,
+    /// The name of the solar system
+    pub name: String,
+    /// Planets within the solar system
+    #[serde(default)]
+    pub planets: Vec<SolarSystemPlanet>,
+    /// Position of the solar system within the universe
+    pub position: UniversePosition,
+    /// The security class of the solar system
+    pub security_class: Option<String>,
+    /// The security status of the solar system, ranging from -1.0 to 1.0
+    pub security_status: f64,
+    /// The ID of the solar system's star
+    pub star_id: Option<i64>,
+    /// IDs of the stargates within the solar system
+    #[serde(default)]
+    pub stargates: Vec<i64>,
+    /// IDs of the NPC stations within the solar system
+    #[serde(default)]
+    pub stations: Vec<i64>,
+    /// The ID of the solar system
+    pub system_id: i64,
+}
+
+impl SolarSystem {
+    /// Returns the security status of the solar system as displayed in-game, rounded to 1 decimal place
+    ///
+    /// Security statuses above 0.0 but below 0.05 are displayed as 0.1 rather than rounded down.
+    pub fn displayed_security_status(&self) -> f64 {
+        if self.security_status > 0.0 && self.security_status < 0.05 {
+            return 0.1;
+        }
+
+        (self.security_status * 10.0).round() / 10.0
+    }
+
+    /// Returns the security classification of the solar system
+    ///
+    /// The solar system's region is required to identify Pochven, such as from ESI's
+    /// `/universe/constellations/{constellation_id}` route for the system's [`Self::constellation_id`]
+    /// or the Static Data Export. Use [`SecurityClass::from_security_status`] for systems known to
+    /// be outside of Pochven.
+    ///
+    /// # Arguments
+    /// - `region_id` (`i64`): The ID of the region the solar system is in
+    pub fn security(&self, region_id: i64) -> SecurityClass {
+        SecurityClass::new(self.system_id, region_id, self.security_status)
+    }
+}
+
+/// The stargate & solar system a stargate jumps to
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseStargatesStargateIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StargateDestination {
+    /// The ID of the stargate jumped to
+    pub stargate_id: i64,
+    /// The ID of the solar system jumped to
+    pub system_id: i64,
+}
+
+/// Information on a stargate
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseStargatesStargateIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Stargate {
+    /// The stargate & solar system the stargate jumps to
+    pub destination: StargateDestination,
+    /// The name of the stargate
+    pub name: String,
+    /// Position of the stargate within its solar system
+    pub position: UniversePosition,
+    /// The ID of the stargate
+    pub stargate_id: i64,
+    /// The ID of the solar system the stargate is in
+    pub system_id: i64,
+    /// The type ID of the stargate
+    pub type_id: i64,
+}
+
+/// Information on an NPC station
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseStationsStationIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Station {
+    /// The maximum volume of a ship which can dock at the station
+    pub max_dockable_ship_volume: f64,
+    /// The name of the station
+    pub name: String,
+    /// The cost of renting an office at the station
+    pub office_rental_cost: f64,
+    /// The ID of the NPC corporation which owns the station
+    pub owner: Option<i64>,
+    /// Position of the station within its solar system
+    pub position: UniversePosition,
+    /// The ID of the race the station belongs to
+    pub race_id: Option<i64>,
+    /// The base reprocessing efficiency of the station
+    pub reprocessing_efficiency: f64,
+    /// The share of reprocessed materials taken by the station
+    pub reprocessing_stations_take: f64,
+    /// The services offered by the station, e.g. `market`
+    #[serde(default)]
+    pub services: Vec<String>,
+    /// The ID of the station
+    pub station_id: i64,
+    /// The ID of the solar system the station is in
+    pub system_id: i64,
+    /// The type ID of the station
+    pub type_id: i64,
+}
+
+/// Recent kill & jump activity within a solar system combined with its name & security status
+///
+/// Built by [`UniverseEndpoints::get_system_activity`](crate::endpoints::universe::UniverseEndpoints::get_system_activity)
+/// for use in intel heatmaps.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct SystemActivity {
+    /// The ID of the solar system
+    pub system_id: i64,
+    /// The name of the solar system
+    pub name: String,
+    /// The security status of the solar system, ranging from -1.0 to 1.0
+    pub security_status: f64,
+    /// Number of player ships killed in the solar system within the last hour
+    pub ship_kills: i64,
+    /// Number of pods killed in the solar system within the last hour
+    pub pod_kills: i64,
+    /// Number of NPC ships killed in the solar system within the last hour
+    pub npc_kills: i64,
+    /// Number of ships which jumped into the solar system within the last hour
+    pub ship_jumps: i64,
+}
+
+/// A dogma attribute value of an item type
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseTypesTypeIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TypeDogmaAttribute {
+    /// The ID of the dogma attribute
+    pub attribute_id: i64,
+    /// The value of the dogma attribute for the item type
+    pub value: f64,
+}
+
+/// A dogma effect of an item type
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseTypesTypeIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct TypeDogmaEffect {
+    /// The ID of the dogma effect
+    pub effect_id: i64,
+    /// Whether the effect is the item type's default effect
+    pub is_default: bool,
+}
+
+/// Information on an item type
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseTypesTypeIdGet>
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ItemType {
+    /// Cargo capacity of the item type in m3
+    pub capacity: Option<f64>,
+    /// Description of the item type
+    pub description: String,
+    /// Dogma attribute values of the item type
+    #[serde(default)]
+    pub dogma_attributes: Vec<TypeDogmaAttribute>,
+    /// Dogma effects of the item type
+    #[serde(default)]
+    pub dogma_effects: Vec<TypeDogmaEffect>,
+    /// The ID of the item type's graphic
+    pub graphic_id: Option<i64>,
+    /// The ID of the item type's group
+    pub group_id: i64,
+    /// The ID of the item type's icon
+    pub icon_id: Option<i64>,
+    /// The ID of the market group the item type is listed in, `None` if not sold on the market
+    pub market_group_id: Option<i64>,
+    /// Mass of the item type in kg
+    pub mass: Option<f64>,
+    /// Name of the item type
+    pub name: String,
+    /// Volume of the item type in m3 when packaged
+    pub packaged_volume: Option<f64>,
+    /// Number of items produced per manufacturing run or required per reprocessing batch
+    pub portion_size: Option<i64>,
+    /// Whether the item type is published in-game
+    pub published: bool,
+    /// Radius of the item type in meters
+    pub radius: Option<f64>,
+    /// The ID of the item type
+    pub type_id: i64,
+    /// Volume of the item type in m3
+    pub volume: Option<f64>,
+}
+
+impl ItemType {
+    /// Returns the value of the provided dogma attribute for the item type
+    ///
+    /// # Arguments
+    /// - `attribute_id` (`i64`): The ID of the dogma attribute
+    ///
+    /// # Returns
+    /// - `Some(f64)`: The attribute value
+    /// - `None`: If the item type does not have the attribute
+    pub fn dogma_attribute(&self, attribute_id: i64) -> Option<f64> {
+        self.dogma_attributes
+            .iter()
+            .find(|attribute| attribute.attribute_id == attribute_id)
+            .map(|attribute| attribute.value)
+    }
+}
+
+/// Information on a player-owned structure
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/UniverseStructuresStructureIdGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Structure {
+    /// The full name of the structure
+    pub name: String,
+    /// The ID of the corporation which owns the structure
+    pub owner_id: i64,
+    /// Coordinates of the structure in space
+    pub position: Option<UniversePosition>,
+    /// The ID of the solar system the structure is located in
+    pub solar_system_id: i64,
+    /// The type ID of the structure
+    pub type_id: Option<i64>,
+}
+
+/// The result of resolving the name of a structure
+///
+/// Returned by [`UniverseEndpoints::resolve_structure_names`](crate::endpoints::universe::UniverseEndpoints::resolve_structure_names)
+/// for each structure ID as many structure IDs found in asset & market data are not accessible.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum StructureName {
+    /// The name of the structure was resolved
+    Resolved(String),
+    /// The access token's character is not on the structure's access list (403)
+    Forbidden,
+    /// The structure was not found, e.g. because it was destroyed (404)
+    Unknown,
+}
+
+impl StructureName {
+    /// Returns the name of the structure if it was resolved
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            StructureName::Resolved(name) => Some(name),
+            StructureName::Forbidden | StructureName::Unknown => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod solar_system_tests {
+    use super::*;
+
+    /// Creates a solar system with the provided ID & security status
+    fn mock_solar_system(system_id: i64, security_status: f64) -> SolarSystem {
+        SolarSystem {
+            constellation_id: 20000020,
+            name: String::from("Jita"),
+            planets: Vec::new(),
+            position: UniversePosition {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            security_class: None,
+            security_status,
+            star_id: None,
+            stargates: Vec::new(),
+            stations: Vec::new(),
+            system_id,
+        }
+    }
+
+    /// Tests the displayed security status & classification of solar systems
+    ///
+    /// # Assertions
+    /// - Assert security statuses are rounded to 1 decimal place for display
+    /// - Assert security statuses just above 0.0 are displayed as 0.1
+    /// - Assert systems are classified by their ID, region & security status
+    #[test]
+    fn test_solar_system_security() {
+        // Assert security statuses are rounded to 1 decimal place for display
+        assert_eq!(
+            mock_solar_system(30000142, 0.9459).displayed_security_status(),
+            0.9
+        );
+        assert_eq!(
+            mock_solar_system(30002053, 0.4501).displayed_security_status(),
+            0.5
+        );
+
+        // Assert security statuses just above 0.0 are displayed as 0.1
+        assert_eq!(
+            mock_solar_system(30002813, 0.0204).displayed_security_status(),
+            0.1
+        );
+
+        // Assert systems are classified by their ID, region & security status
+        assert_eq!(
+            mock_solar_system(30002053, 0.4501).security(10000032),
+            SecurityClass::Highsec
+        );
+        assert_eq!(
+            mock_solar_system(31000005, -1.0).security(11000031),
+            SecurityClass::Wormhole
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! # EVE ESI Wallet Models
+//!
+//! Provides wallet-related structs for EVE Online
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::enums::wallet::{WalletJournalContextIdType, WalletJournalRefType};
+use super::Isk;
+
+/// An entry of a character's or corporation division's wallet journal
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdWalletJournalGet>
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdWalletsDivisionJournalGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WalletJournalEntry {
+    /// The amount of ISK given or taken from the wallet as a result of the transaction,
+    /// positive when ISK is deposited & negative when ISK is withdrawn
+    pub amount: Option<Isk>,
+    /// Wallet balance after the transaction occurred
+    pub balance: Option<Isk>,
+    /// An ID that gives extra context to the entry, see `context_id_type` for the type of ID
+    pub context_id: Option<i64>,
+    /// The type of ID contained in `context_id`
+    pub context_id_type: Option<WalletJournalContextIdType>,
+    /// Date and time of the transaction
+    pub date: DateTime<Utc>,
+    /// The reason for the transaction, mirrors what is seen in the client
+    pub description: String,
+    /// The ID of the first party involved in the transaction, usually the party paying ISK
+    pub first_party_id: Option<i64>,
+    /// Unique journal reference ID, increases with each new entry
+    pub id: i64,
+    /// The user stated reason for the transaction, only applies to some reference types
+    pub reason: Option<String>,
+    /// The type of reference the entry was created for
+    pub ref_type: WalletJournalRefType,
+    /// The ID of the second party involved in the transaction, usually the party receiving ISK
+    pub second_party_id: Option<i64>,
+    /// Tax amount received, only applies to tax related transactions
+    pub tax: Option<Isk>,
+    /// The corporation ID receiving any tax paid, only applies to tax related transactions
+    pub tax_receiver_id: Option<i64>,
+}
+
+/// A market transaction of a character's or corporation division's wallet
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CharactersCharacterIdWalletTransactionsGet>
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdWalletsDivisionTransactionsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WalletTransaction {
+    /// The ID of the character or corporation on the other side of the transaction
+    pub client_id: i64,
+    /// Date and time of the transaction
+    pub date: DateTime<Utc>,
+    /// True if the wallet owner bought the items, false if they sold them
+    pub is_buy: bool,
+    /// True if the transaction was made for the character rather than their corporation,
+    /// `None` for corporation wallet transactions
+    pub is_personal: Option<bool>,
+    /// The ID of the wallet journal entry created for the transaction
+    pub journal_ref_id: i64,
+    /// The ID of the station or structure the transaction took place in
+    pub location_id: i64,
+    /// The number of items bought or sold
+    pub quantity: i64,
+    /// Unique transaction ID
+    pub transaction_id: i64,
+    /// The type ID of the items bought or sold
+    pub type_id: i64,
+    /// The price of a single item
+    pub unit_price: Isk,
+}
+
+/// The balance of a corporation wallet division
+///
+/// # Documentation
+/// - <https://developers.eveonline.com/api-explorer#/schemas/CorporationsCorporationIdWalletsGet>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CorporationWallet {
+    /// The wallet's balance
+    pub balance: Isk,
+    /// The wallet division (1-7), 1 being the master wallet
+    pub division: i64,
+}
+
+/// The balance of a corporation wallet division with the division's name
+///
+/// Returned by [`WalletEndpoints::get_named_corporation_wallets`](crate::endpoints::wallet::WalletEndpoints::get_named_corporation_wallets).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NamedCorporationWallet {
+    /// The wallet division (1-7), 1 being the master wallet
+    pub division: i64,
+    /// Name of the division, `Wallet Division <number>` if the division has not been named
+    pub name: String,
+    /// The wallet's balance
+    pub balance: Isk,
+}
+
+/// A wallet transaction joined with the wallet journal entry created for it, see [`link_transactions`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LinkedWalletTransaction {
+    /// The wallet transaction
+    pub transaction: WalletTransaction,
+    /// The journal entry referenced by the transaction's `journal_ref_id`, `None` if the entry was
+    /// not provided, e.g. it is older than the 30 days of journal ESI returns
+    pub journal: Option<WalletJournalEntry>,
+}
+
+impl LinkedWalletTransaction {
+    /// Returns the amount of ISK the transaction added to or removed from the wallet
+    pub fn amount(&self) -> Option<Isk> {
+        self.journal.as_ref()?.amount
+    }
+
+    /// Returns the wallet balance after the transaction
+    pub fn balance(&self) -> Option<Isk> {
+        self.journal.as_ref()?.balance
+    }
+
+    /// Returns the tax paid for the transaction as recorded by its journal entry
+    pub fn tax(&self) -> Option<Isk> {
+        self.journal.as_ref()?.tax
+    }
+}
+
+/// Joins wallet transactions with the journal entries their `journal_ref_id` references
+///
+/// Journal entries which are not referenced by a transaction are ignored.
+///
+/// # Arguments
+/// - `transactions` (`Vec<WalletTransaction>`): The wallet transactions to link
+/// - `journal` (`Vec<WalletJournalEntry>`): Wallet journal entries of the same wallet
+///
+/// # Returns
+/// The transactions in the order provided, each with its journal entry if one was provided
+pub fn link_transactions(
+    transactions: Vec<WalletTransaction>,
+    journal: Vec<WalletJournalEntry>,
+) -> Vec<LinkedWalletTransaction> {
+    let mut journal: HashMap<i64, WalletJournalEntry> =
+        journal.into_iter().map(|entry| (entry.id, entry)).collect();
+
+    transactions
+        .into_iter()
+        .map(|transaction| LinkedWalletTransaction {
+            journal: journal.remove(&transaction.journal_ref_id),
+            transaction,
+        })
+        .collect()
+}
+
+/// Bounty & mission income of a character within a date range, see [`summarize_income`]
+///
+/// Returned by [`WalletEndpoints::audit_member_income`](crate::endpoints::wallet::WalletEndpoints::audit_member_income).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CharacterIncome {
+    /// The ID of the character
+    pub character_id: i64,
+    /// ISK received from bounties, the `bounty_prizes` & `ess_escrow_transfer` journal entries
+    pub bounties: Isk,
+    /// ISK received from the `agent_mission_reward` & `agent_mission_time_bonus_reward` journal entries
+    pub missions: Isk,
+    /// Corporation tax paid on the bounties & mission rewards
+    pub tax: Isk,
+    /// The number of journal entries the income was summed from
+    pub entries: usize,
+}
+
+impl CharacterIncome {
+    /// Returns the bounty & mission income combined, after tax
+    pub fn total(&self) -> Isk {
+        self.bounties + self.missions
+    }
+}
+
+/// Sums the bounty & mission income of a character's wallet journal within a date range
+///
+/// Entries of other reference types & outside of the date range are ignored. The amounts of
+/// journal entries are the ISK received after corporation tax, the tax paid is summed separately
+/// from each entry's `tax`.
+///
+/// # Arguments
+/// - `character_id` (`i64`): The ID of the character the journal belongs to
+/// - `journal`      (`&[WalletJournalEntry]`): The character's wallet journal entries
+/// - `from`         (`DateTime<Utc>`): Start of the date range, inclusive
+/// - `to`           (`DateTime<Utc>`): End of the date range, exclusive
+///
+/// # Returns
+/// The character's bounty & mission income within the date range
+pub fn summarize_income(
+    character_id: i64,
+    journal: &[WalletJournalEntry],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> CharacterIncome {
+    let mut income = CharacterIncome {
+        character_id,
+        bounties: Isk::default(),
+        missions: Isk::default(),
+        tax: Isk::default(),
+        entries: 0,
+    };
+
+    for entry in journal {
+        if entry.date < from || entry.date >= to {
+            continue;
+        }
+
+        let amount = entry.amount.unwrap_or_default();
+
+        match entry.ref_type {
+            WalletJournalRefType::BountyPrizes | WalletJournalRefType::EssEscrowTransfer => {
+                income.bounties += amount
+            }
+            WalletJournalRefType::AgentMissionReward
+            | WalletJournalRefType::AgentMissionTimeBonusReward => income.missions += amount,
+            _ => continue,
+        }
+
+        income.tax += entry.tax.unwrap_or_default();
+        income.entries += 1;
+    }
+
+    income
+}
+
+/// A wallet journal entry mapped to a stable record for accounting, see [`accounting_records`]
+///
+/// Returned by [`WalletEndpoints::get_accounting_records`](crate::endpoints::wallet::WalletEndpoints::get_accounting_records)
+/// & exported with the `export` feature's [`to_csv`](crate::export::to_csv), its columns are kept
+/// stable across versions of the crate for spreadsheets & accounting tools.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AccountingRecord {
+    /// Unique journal reference ID of the entry
+    pub id: i64,
+    /// Date and time of the transaction
+    pub date: DateTime<Utc>,
+    /// The type of reference the entry was created for
+    pub ref_type: WalletJournalRefType,
+    /// The ID of the first party involved in the transaction, usually the party paying ISK
+    pub first_party_id: Option<i64>,
+    /// The name of the first party, `None` if the entry has no first party or its name is unknown
+    pub first_party: Option<String>,
+    /// The ID of the second party involved in the transaction, usually the party receiving ISK
+    pub second_party_id: Option<i64>,
+    /// The name of the second party, `None` if the entry has no second party or its name is unknown
+    pub second_party: Option<String>,
+    /// The amount of ISK deposited, positive, or withdrawn, negative
+    pub amount: Option<Isk>,
+    /// Wallet balance after the transaction occurred
+    pub balance: Option<Isk>,
+    /// Tax amount received, only applies to tax related transactions
+    pub tax: Option<Isk>,
+    /// The user stated reason for the transaction, only applies to some reference types
+    pub reason: Option<String>,
+    /// The reason for the transaction as seen in the client
+    pub description: String,
+}
+
+/// Maps wallet journal entries to accounting records with the names of their parties
+///
+/// # Arguments
+/// - `journal` (`&[WalletJournalEntry]`): The wallet journal entries to map
+/// - `names`   (`&HashMap<i64, String>`): Names of the parties by ID, e.g. from
+///   [`UniverseEndpoints::resolve_names`](crate::endpoints::universe::UniverseEndpoints::resolve_names),
+///   parties missing from the map are left without a name
+///
+/// # Returns
+/// An accounting record for each journal entry in the order provided
+pub fn accounting_records(
+    journal: &[WalletJournalEntry],
+    names: &HashMap<i64, String>,
+) -> Vec<AccountingRecord> {
+    let name = |id: Option<i64>| id.and_then(|id| names.get(&id).cloned());
+
+    journal
+        .iter()
+        .map(|entry| AccountingRecord {
+            id: entry.id,
+            date: entry.date,
+            ref_type: entry.ref_type.clone(),
+            first_party_id: entry.first_party_id,
+            first_party: name(entry.first_party_id),
+            second_party_id: entry.second_party_id,
+            second_party: name(entry.second_party_id),
+            amount: entry.amount,
+            balance: entry.balance,
+            tax: entry.tax,
+            reason: entry.reason.clone().filter(|reason| !reason.is_empty()),
+            description: entry.description.clone(),
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "decimal"))]
+mod isk_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// Tests ISK amounts too large for an f64 are deserialized without losing precision
+    ///
+    /// # Assertions
+    /// - Assert the balance equals the exact decimal amount
+    /// - Assert the balance is serialized back to the same JSON number
+    #[test]
+    fn test_decimal_isk_precision() {
+        let json = r#"{"amount":-0.01,"balance":9876543210987654.32,"date":"2025-01-01T00:00:00Z","description":"Test","id":1,"ref_type":"player_donation"}"#;
+
+        let entry: WalletJournalEntry = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            entry.balance,
+            Some(Isk::from_str("9876543210987654.32").unwrap())
+        );
+        assert!(serde_json::to_string(&entry)
+            .unwrap()
+            .contains(r#""balance":9876543210987654.32"#));
+    }
+}
+
+#[cfg(test)]
+mod link_transactions_tests {
+    use super::*;
+
+    fn mock_transaction(transaction_id: i64, journal_ref_id: i64) -> WalletTransaction {
+        WalletTransaction {
+            client_id: 2114794365,
+            date: DateTime::default(),
+            is_buy: true,
+            is_personal: Some(true),
+            journal_ref_id,
+            location_id: 60003760,
+            quantity: 1,
+            transaction_id,
+            type_id: 34,
+            unit_price: Isk::from(5),
+        }
+    }
+
+    fn mock_entry(id: i64, amount: i32) -> WalletJournalEntry {
+        WalletJournalEntry {
+            amount: Some(Isk::from(amount)),
+            balance: Some(Isk::from(1000)),
+            context_id: None,
+            context_id_type: None,
+            date: DateTime::default(),
+            description: String::new(),
+            first_party_id: None,
+            id,
+            reason: None,
+            ref_type: WalletJournalRefType::MarketTransaction,
+            second_party_id: None,
+            tax: None,
+            tax_receiver_id: None,
+        }
+    }
+
+    /// Tests transactions are joined with the journal entries they reference
+    ///
+    /// # Test Setup
+    /// - Create two transactions, only one of which references a provided journal entry
+    ///
+    /// # Assertions
+    /// - Assert the transactions are returned in the order provided
+    /// - Assert the referenced journal entry's amount & balance are available
+    /// - Assert the transaction without a provided journal entry has none
+    #[test]
+    fn test_link_transactions() {
+        let transactions = vec![mock_transaction(1, 100), mock_transaction(2, 200)];
+        let journal = vec![mock_entry(99, -1), mock_entry(100, -5)];
+
+        let linked = link_transactions(transactions, journal);
+
+        assert_eq!(linked.len(), 2);
+        assert_eq!(linked[0].transaction.transaction_id, 1);
+        assert_eq!(linked[0].amount(), Some(Isk::from(-5)));
+        assert_eq!(linked[0].balance(), Some(Isk::from(1000)));
+        assert_eq!(linked[1].journal, None);
+        assert_eq!(linked[1].amount(), None);
+    }
+}
+
+#[cfg(test)]
+mod summarize_income_tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn mock_entry(
+        ref_type: WalletJournalRefType,
+        day: u32,
+        amount: i32,
+        tax: Option<i32>,
+    ) -> WalletJournalEntry {
+        WalletJournalEntry {
+            amount: Some(Isk::from(amount)),
+            balance: None,
+            context_id: None,
+            context_id_type: None,
+            date: Utc.with_ymd_and_hms(2025, 10, day, 12, 0, 0).unwrap(),
+            description: String::new(),
+            first_party_id: None,
+            id: day as i64,
+            reason: None,
+            ref_type,
+            second_party_id: None,
+            tax: tax.map(Isk::from),
+            tax_receiver_id: None,
+        }
+    }
+
+    /// Tests bounty & mission income within the date range is summed by category
+    ///
+    /// # Test Setup
+    /// - Create bounty, ESS, mission & market entries, one bounty being outside of the date range
+    ///
+    /// # Assertions
+    /// - Assert bounties & ESS payouts are summed as bounties & mission rewards as missions
+    /// - Assert the entry outside of the date range & the market entry are ignored
+    /// - Assert the tax of the counted entries is summed
+    #[test]
+    fn test_summarize_income() {
+        let journal = vec![
+            mock_entry(WalletJournalRefType::BountyPrizes, 1, 900, Some(100)),
+            mock_entry(WalletJournalRefType::BountyPrizes, 2, 1800, Some(200)),
+            mock_entry(WalletJournalRefType::EssEscrowTransfer, 3, 500, None),
+            mock_entry(WalletJournalRefType::AgentMissionReward, 4, 300, Some(30)),
+            mock_entry(
+                WalletJournalRefType::AgentMissionTimeBonusReward,
+                4,
+                150,
+                None,
+            ),
+            mock_entry(WalletJournalRefType::MarketTransaction, 5, 10000, None),
+        ];
+
+        let income = summarize_income(
+            2114794365,
+            &journal,
+            Utc.with_ymd_and_hms(2025, 10, 2, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 10, 6, 0, 0, 0).unwrap(),
+        );
+
+        assert_eq!(income.bounties, Isk::from(2300));
+        assert_eq!(income.missions, Isk::from(450));
+        assert_eq!(income.total(), Isk::from(2750));
+        assert_eq!(income.tax, Isk::from(230));
+        assert_eq!(income.entries, 4);
+    }
+}
@@ -0,0 +1,270 @@
+//! # EVE Online OAuth2 Axum Integration
+//!
+//! Provides ready-made building blocks for implementing EVE Online single sign-on (SSO) in an
+//! [Axum](https://docs.rs/axum) web application, requires the `axum` feature.
+//!
+//! - [`login_redirect`]: Creates a response redirecting the user to EVE Online's login page
+//! - [`AuthenticatedCharacter`]: Extractor for the callback route which validates the state,
+//!   exchanges the authorization code for a token & validates the token
+//!
+//! The state string used to prevent CSRF is stored in a short-lived `HttpOnly` cookie by
+//! [`login_redirect`] & compared against the state returned to the callback route, no session
+//! store is required. The cookie is marked `Secure`, browsers accept it over plain HTTP only for
+//! `localhost`.
+//!
+//! The [`Client`] is retrieved from the router state with [`FromRef`], see the usage example.
+//!
+//! ## Usage Example
+//!
+//! ```no_run
+//! use axum::extract::State;
+//! use axum::response::Response;
+//! use axum::routing::get;
+//! use axum::Router;
+//! use eve_esi::oauth2::axum::{login_redirect, AuthenticatedCharacter, SsoRejection};
+//!
+//! async fn login(State(esi_client): State<eve_esi::Client>) -> Result<Response, SsoRejection> {
+//!     let scopes = eve_esi::ScopeBuilder::new().public_data().build();
+//!
+//!     login_redirect(&esi_client, scopes)
+//! }
+//!
+//! async fn callback(character: AuthenticatedCharacter) -> String {
+//!     format!("Logged in as {} ({})", character.character_name, character.character_id)
+//! }
+//!
+//! # fn example(esi_client: eve_esi::Client) {
+//! let app: Router = Router::new()
+//!     .route("/login", get(login))
+//!     .route("/callback", get(callback))
+//!     .with_state(esi_client);
+//! # }
+//! ```
+
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::header::{COOKIE, LOCATION, SET_COOKIE};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+use crate::model::oauth2::{EveJwtClaims, TokenResponse};
+use crate::{Client, Error};
+
+/// Name of the cookie storing the state string between the login & callback routes
+const STATE_COOKIE: &str = "eve_esi_sso_state";
+
+/// Seconds the state cookie remains valid, the user must complete the login within this time
+const STATE_COOKIE_MAX_AGE: u64 = 300;
+
+/// A character which completed the single sign-on process
+///
+/// Extracting this type in the callback route validates the `state` query parameter against the
+/// state cookie set by [`login_redirect`], exchanges the `code` query parameter for a token &
+/// validates the access token. See the [module-level documentation](self) for a usage example.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedCharacter {
+    /// The ID of the character which logged in
+    pub character_id: i64,
+    /// The name of the character which logged in
+    pub character_name: String,
+    /// Hash which changes when the character is transferred to another EVE Online account
+    pub owner_hash: String,
+    /// The token retrieved with the authorization code, including the refresh token
+    pub token: TokenResponse,
+    /// The validated claims of the access token
+    pub claims: EveJwtClaims,
+}
+
+/// Errors which occur while completing the single sign-on callback
+///
+/// Implements [`IntoResponse`], returning `400 Bad Request` for invalid callback requests & `500
+/// Internal Server Error` if the token could not be retrieved or validated. The error is logged
+/// before the response is returned.
+#[derive(thiserror::Error, Debug)]
+pub enum SsoRejection {
+    /// The callback request is missing the `code` or `state` query parameter
+    #[error("Callback request is missing the {0} query parameter")]
+    MissingParameter(&'static str),
+    /// The state cookie is missing, e.g. it expired or the login was not started by this application
+    #[error("Callback request is missing the state cookie")]
+    MissingState,
+    /// The `state` query parameter does not match the state cookie, the request may be forged
+    #[error("Callback state does not match the state cookie")]
+    StateMismatch,
+    /// The login URL could not be created or the token could not be retrieved or validated
+    #[error(transparent)]
+    Esi(#[from] Error),
+}
+
+impl IntoResponse for SsoRejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Self::MissingParameter(_) | Self::MissingState | Self::StateMismatch => {
+                log::warn!("Rejected single sign-on callback: {}", self);
+
+                StatusCode::BAD_REQUEST
+            }
+            Self::Esi(ref err) => {
+                log::error!("Failed to complete single sign-on callback: {:#?}", err);
+
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        (
+            status,
+            "There was an issue logging you in, please try again.",
+        )
+            .into_response()
+    }
+}
+
+/// Creates a response redirecting the user to EVE Online's login page
+///
+/// The state string of the login URL is stored in a cookie which [`AuthenticatedCharacter`]
+/// validates in the callback route.
+///
+/// # Arguments
+/// - `client` (&[`Client`]): ESI client configured for OAuth2
+/// - `scopes` (`Vec<String>`): The scopes to request from the user, see [`ScopeBuilder`](crate::ScopeBuilder)
+///
+/// # Returns
+/// A `303 See Other` response to the login URL, or an [`SsoRejection`] if OAuth2 is not configured
+/// for the client.
+pub fn login_redirect(client: &Client, scopes: Vec<String>) -> Result<Response, SsoRejection> {
+    let login = client.oauth2().login_url(scopes)?;
+
+    let cookie = format!(
+        "{}={}; Max-Age={}; Path=/; HttpOnly; Secure; SameSite=Lax",
+        STATE_COOKIE, login.state, STATE_COOKIE_MAX_AGE
+    );
+
+    Ok((
+        StatusCode::SEE_OTHER,
+        [(LOCATION, login.login_url), (SET_COOKIE, cookie)],
+    )
+        .into_response())
+}
+
+impl<S> FromRequestParts<S> for AuthenticatedCharacter
+where
+    Client: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = SsoRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let client = Client::from_ref(state);
+
+        let (code, callback_state) = callback_params(parts.uri.query().unwrap_or_default());
+        let code = code.ok_or(SsoRejection::MissingParameter("code"))?;
+        let callback_state = callback_state.ok_or(SsoRejection::MissingParameter("state"))?;
+
+        let cookie_state = state_cookie(parts).ok_or(SsoRejection::MissingState)?;
+        if cookie_state != callback_state {
+            return Err(SsoRejection::StateMismatch);
+        }
+
+        let token = client.oauth2().get_token(&code).await?;
+        let token = TokenResponse::from_token(token, None);
+
+        let claims = client
+            .oauth2()
+            .validate_token(token.access_token.secret().to_string())
+            .await?;
+
+        Ok(Self {
+            character_id: claims.character_id()?,
+            character_name: claims.name.clone(),
+            owner_hash: claims.owner.clone(),
+            token,
+            claims,
+        })
+    }
+}
+
+/// Parses the `code` & `state` parameters from the query string of a callback request
+fn callback_params(query: &str) -> (Option<String>, Option<String>) {
+    let mut code = None;
+    let mut state = None;
+
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        match key.as_ref() {
+            "code" => code = Some(value.into_owned()),
+            "state" => state = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+
+    (code, state)
+}
+
+/// Retrieves the value of the state cookie from the request's `Cookie` headers
+fn state_cookie(parts: &Parts) -> Option<String> {
+    parts
+        .headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(|header| header.split(';'))
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == STATE_COOKIE)
+        .map(|(_, value)| value.to_string())
+}
+
+#[cfg(test)]
+mod axum_tests {
+    use axum::http::Request;
+
+    use super::*;
+
+    /// Tests the state cookie is read from a request containing multiple cookies
+    ///
+    /// # Test Setup
+    /// - Create a request with the state cookie between other cookies
+    ///
+    /// # Assertions
+    /// - Assert the state cookie's value is returned
+    /// - Assert `None` is returned for a request without the state cookie
+    #[test]
+    fn test_state_cookie() {
+        let (parts, _) = Request::builder()
+            .header(
+                COOKIE,
+                "session=abc; eve_esi_sso_state=state123; theme=dark",
+            )
+            .body(())
+            .unwrap()
+            .into_parts();
+        assert_eq!(state_cookie(&parts), Some("state123".to_string()));
+
+        let (parts, _) = Request::builder()
+            .header(COOKIE, "session=abc")
+            .body(())
+            .unwrap()
+            .into_parts();
+        assert_eq!(state_cookie(&parts), None);
+    }
+
+    /// Tests the callback is rejected when the state does not match the state cookie
+    ///
+    /// # Test Setup
+    /// - Create a callback request with a state which differs from the state cookie
+    ///
+    /// # Assertions
+    /// - Assert the request is rejected with a state mismatch before the code is exchanged
+    #[tokio::test]
+    async fn test_callback_state_mismatch() {
+        let client = Client::new("MyApp/1.0 (contact@example.com)").unwrap();
+        let (mut parts, _) = Request::builder()
+            .uri("/callback?code=code123&state=forged")
+            .header(COOKIE, "eve_esi_sso_state=state123")
+            .body(())
+            .unwrap()
+            .into_parts();
+
+        let result = AuthenticatedCharacter::from_request_parts(&mut parts, &client).await;
+
+        assert!(matches!(result, Err(SsoRejection::StateMismatch)));
+    }
+}
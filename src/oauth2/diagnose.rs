@@ -0,0 +1,306 @@
+//! # EVE Online OAuth2 Diagnostics
+//!
+//! Misconfigured OAuth2 settings usually only surface once a user attempts to log in, e.g. as a
+//! `redirect_uri` mismatch on EVE Online's login page or a token which fails validation.
+//! [`OAuth2Endpoints::diagnose`] checks the client's OAuth2 settings & the scopes an application
+//! requests against the live SSO ahead of time, returning each problem found as an [`OAuthIssue`].
+//!
+//! The following checks are made:
+//! - OAuth2 has been configured with a client ID, client secret & callback URL
+//! - The client ID has the format of the client IDs issued by EVE Online's developer portal
+//! - The callback URL uses HTTPS unless it points to the local machine & has no fragment
+//! - The authorization, token & JWK URLs & the JWT issuers match the SSO's
+//!   [server metadata](super::metadata)
+//! - The JWK URL is reachable & provides at least one key
+//! - Each scope is known to the crate & supported by the SSO, if it advertises its scopes
+//!
+//! The callback URL can't be compared against the callback registered for the application as the
+//! SSO doesn't publish it, ensure it matches the callback shown in the developer portal exactly.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::ScopeBuilder;
+//!
+//! # async fn example(esi_client: eve_esi::Client) {
+//! let scopes = ScopeBuilder::new().public_data().build();
+//!
+//! let diagnosis = esi_client.oauth2().diagnose(scopes).await;
+//! for issue in diagnosis.issues() {
+//!     eprintln!("OAuth2 misconfiguration: {}", issue);
+//! }
+//! # }
+//! ```
+
+use std::str::FromStr;
+
+use crate::model::oauth2::AuthorizationServerMetadata;
+use crate::scope::Scope;
+
+use super::OAuth2Endpoints;
+
+/// Length of the client IDs issued by EVE Online's developer portal, 32 hexadecimal characters
+const CLIENT_ID_LENGTH: usize = 32;
+
+/// Hosts which callback URLs may use without HTTPS
+const LOCAL_HOSTS: [&str; 3] = ["localhost", "127.0.0.1", "[::1]"];
+
+/// A problem with the client's OAuth2 settings found by [`OAuth2Endpoints::diagnose`]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum OAuthIssue {
+    /// OAuth2 has not been configured, a client ID, client secret & callback URL are required
+    #[error("OAuth2 has not been configured, set the client ID, client secret & callback URL when building the client")]
+    OAuth2NotConfigured,
+    /// The client ID doesn't have the format of the client IDs issued by EVE Online
+    #[error("Client ID {client_id:?} is not a 32 character hexadecimal ID, copy it from the developer portal")]
+    MalformedClientId {
+        /// The configured client ID
+        client_id: String,
+    },
+    /// The callback URL uses HTTP for a host other than the local machine
+    #[error("Callback URL {callback_url} should use HTTPS for hosts other than the local machine")]
+    InsecureCallbackUrl {
+        /// The configured callback URL
+        callback_url: String,
+    },
+    /// The callback URL contains a fragment, which the SSO rejects
+    #[error("Callback URL {callback_url} must not contain a fragment")]
+    CallbackUrlFragment {
+        /// The configured callback URL
+        callback_url: String,
+    },
+    /// The SSO's server metadata could not be retrieved
+    #[error("Failed to retrieve the SSO's server metadata from {url}: {error}")]
+    MetadataUnreachable {
+        /// The URL of the server metadata
+        url: String,
+        /// Description of the error returned by the request
+        error: String,
+    },
+    /// The configured authorization URL differs from the SSO's
+    #[error(
+        "Authorization URL {configured} differs from the SSO's authorization endpoint {advertised}"
+    )]
+    AuthUrlMismatch {
+        /// The configured authorization URL
+        configured: String,
+        /// The authorization URL advertised by the SSO
+        advertised: String,
+    },
+    /// The configured token URL differs from the SSO's
+    #[error("Token URL {configured} differs from the SSO's token endpoint {advertised}")]
+    TokenUrlMismatch {
+        /// The configured token URL
+        configured: String,
+        /// The token URL advertised by the SSO
+        advertised: String,
+    },
+    /// The configured JWK URL differs from the SSO's
+    #[error("JWK URL {configured} differs from the SSO's JWK endpoint {advertised}")]
+    JwkUrlMismatch {
+        /// The configured JWK URL
+        configured: String,
+        /// The JWK URL advertised by the SSO
+        advertised: String,
+    },
+    /// The SSO's token issuer isn't one of the expected JWT issuers, tokens would fail validation
+    #[error("The SSO issues tokens as {advertised} which is not one of the expected JWT issuers {configured:?}")]
+    IssuerMismatch {
+        /// The configured JWT issuers
+        configured: Vec<String>,
+        /// The issuer advertised by the SSO
+        advertised: String,
+    },
+    /// The JWT keys used to validate tokens could not be retrieved
+    #[error("Failed to retrieve JWT keys from {url}: {error}")]
+    JwkUnreachable {
+        /// The configured JWK URL
+        url: String,
+        /// Description of the error returned by the request
+        error: String,
+    },
+    /// The JWK URL returned no keys, no token can be validated
+    #[error("JWK URL {url} returned no JWT keys")]
+    NoJwtKeys {
+        /// The configured JWK URL
+        url: String,
+    },
+    /// A scope is not known to the crate, usually a typo
+    #[error("Scope {scope:?} is not a known ESI scope")]
+    UnknownScope {
+        /// The requested scope
+        scope: String,
+    },
+    /// A scope is not among the scopes advertised by the SSO
+    #[error("Scope {scope:?} is not supported by the SSO")]
+    UnsupportedScope {
+        /// The requested scope
+        scope: String,
+    },
+}
+
+/// Report of the problems with the client's OAuth2 settings, returned by [`OAuth2Endpoints::diagnose`]
+///
+/// For an overview & usage example, see the [module-level documentation](self)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuthDiagnosis {
+    metadata: Option<AuthorizationServerMetadata>,
+    issues: Vec<OAuthIssue>,
+}
+
+impl OAuthDiagnosis {
+    /// Returns `true` if no problems were found
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the problems found, in the order they were checked
+    pub fn issues(&self) -> &[OAuthIssue] {
+        &self.issues
+    }
+
+    /// Returns the SSO's server metadata, `None` if it could not be retrieved
+    pub fn metadata(&self) -> Option<&AuthorizationServerMetadata> {
+        self.metadata.as_ref()
+    }
+}
+
+impl<'a> OAuth2Endpoints<'a> {
+    /// Checks the client's OAuth2 settings & requested scopes against the live SSO
+    ///
+    /// Problems are returned in the report rather than as an error, so every check is made even
+    /// if the SSO can't be reached. See the [module-level documentation](self) for the checks made.
+    ///
+    /// # Arguments
+    /// - `scopes` (`Vec<String>`): The scopes the application requests when users log in, see
+    ///   [`ScopeBuilder`](crate::ScopeBuilder)
+    ///
+    /// # Returns
+    /// - [`OAuthDiagnosis`]: The problems found & the SSO's server metadata if it was retrieved
+    pub async fn diagnose(&self, scopes: Vec<String>) -> OAuthDiagnosis {
+        let mut issues = Vec::new();
+
+        self.diagnose_client(&mut issues);
+
+        let metadata = match self.get_server_metadata().await {
+            Ok(metadata) => {
+                self.diagnose_endpoints(&metadata, &mut issues);
+                Some(metadata)
+            }
+            Err(error) => {
+                issues.push(OAuthIssue::MetadataUnreachable {
+                    url: self.server_metadata_url().unwrap_or_default(),
+                    error: error.to_string(),
+                });
+                None
+            }
+        };
+
+        let jwk_url = self.client.inner.jwt_key_cache.jwk_url().to_string();
+        match self.jwk().fetch_jwt_keys().await {
+            Ok(keys) if keys.keys.is_empty() => issues.push(OAuthIssue::NoJwtKeys { url: jwk_url }),
+            Ok(_) => (),
+            Err(error) => issues.push(OAuthIssue::JwkUnreachable {
+                url: jwk_url,
+                error: error.to_string(),
+            }),
+        }
+
+        let supported_scopes = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.scopes_supported.as_ref());
+        for scope in scopes {
+            if supported_scopes.is_some_and(|supported| !supported.contains(&scope)) {
+                issues.push(OAuthIssue::UnsupportedScope { scope });
+            } else if Scope::from_str(&scope).is_err() {
+                issues.push(OAuthIssue::UnknownScope { scope });
+            }
+        }
+
+        for issue in &issues {
+            log::warn!("OAuth2 diagnosis: {}", issue);
+        }
+
+        OAuthDiagnosis { metadata, issues }
+    }
+
+    /// Checks the client ID & callback URL of the OAuth2 client
+    fn diagnose_client(&self, issues: &mut Vec<OAuthIssue>) {
+        let Some(oauth2_client) = &self.client.inner.oauth2_client else {
+            issues.push(OAuthIssue::OAuth2NotConfigured);
+            return;
+        };
+
+        let client_id = oauth2_client.client_id().as_str();
+        if client_id.len() != CLIENT_ID_LENGTH || !client_id.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            issues.push(OAuthIssue::MalformedClientId {
+                client_id: client_id.to_string(),
+            });
+        }
+
+        if let Some(redirect_url) = oauth2_client.redirect_uri() {
+            let url = redirect_url.url();
+            let callback_url = url.to_string();
+
+            let local = url
+                .host_str()
+                .is_some_and(|host| LOCAL_HOSTS.contains(&host));
+            if url.scheme() != "https" && !local {
+                issues.push(OAuthIssue::InsecureCallbackUrl {
+                    callback_url: callback_url.clone(),
+                });
+            }
+
+            if url.fragment().is_some() {
+                issues.push(OAuthIssue::CallbackUrlFragment { callback_url });
+            }
+        }
+    }
+
+    /// Checks the configured endpoints & JWT issuers against the SSO's server metadata
+    fn diagnose_endpoints(
+        &self,
+        metadata: &AuthorizationServerMetadata,
+        issues: &mut Vec<OAuthIssue>,
+    ) {
+        if let Some(oauth2_client) = self.client.oauth2_client() {
+            let auth_url = oauth2_client.auth_uri().as_str();
+            if !same_url(auth_url, &metadata.authorization_endpoint) {
+                issues.push(OAuthIssue::AuthUrlMismatch {
+                    configured: auth_url.to_string(),
+                    advertised: metadata.authorization_endpoint.clone(),
+                });
+            }
+
+            let token_url = oauth2_client.token_uri().as_str();
+            if !same_url(token_url, &metadata.token_endpoint) {
+                issues.push(OAuthIssue::TokenUrlMismatch {
+                    configured: token_url.to_string(),
+                    advertised: metadata.token_endpoint.clone(),
+                });
+            }
+        }
+
+        let jwk_url = self.client.inner.jwt_key_cache.jwk_url();
+        if !same_url(jwk_url, &metadata.jwks_uri) {
+            issues.push(OAuthIssue::JwkUrlMismatch {
+                configured: jwk_url.to_string(),
+                advertised: metadata.jwks_uri.clone(),
+            });
+        }
+
+        let issuers = &self.client.inner.jwt_issuers;
+        if !issuers.contains(&metadata.issuer) {
+            issues.push(OAuthIssue::IssuerMismatch {
+                configured: issuers.clone(),
+                advertised: metadata.issuer.clone(),
+            });
+        }
+    }
+}
+
+/// Compares two URLs ignoring a trailing slash
+fn same_url(configured: &str, advertised: &str) -> bool {
+    configured.trim_end_matches('/') == advertised.trim_end_matches('/')
+}
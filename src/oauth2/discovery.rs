@@ -0,0 +1,183 @@
+//! # EVE Online OAuth2 Endpoint Discovery
+//!
+//! By default the client uses the authorization, token & JWK URLs of the
+//! [`Config`](crate::Config), which default to EVE Online's current SSO endpoints. Enabling
+//! [`ConfigBuilder::oauth2_discovery`](crate::ConfigBuilder::oauth2_discovery) instead retrieves
+//! the endpoints from the SSO's [server metadata](super::metadata) so the client follows changes
+//! to EVE Online's endpoints without an update to the crate or its configuration.
+//!
+//! The server metadata is requested once, at the origin of the configured authorization URL, when
+//! the client first requests a token or JWT keys. Call [`OAuth2Endpoints::discover_endpoints`]
+//! after building the client to discover the endpoints up front, login URLs created before
+//! discovery use the configured authorization URL.
+//!
+//! If the metadata can't be retrieved or advertises an invalid URL, a warning is logged & the
+//! configured URLs are used for that request. Failures aren't cached, the metadata is requested
+//! again by the next token or JWT key request until discovery succeeds.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), eve_esi::Error> {
+//! let config = eve_esi::Config::builder().oauth2_discovery(true).build()?;
+//!
+//! let esi_client = eve_esi::Client::builder()
+//!     .user_agent("MyApp/1.0 (contact@example.com)")
+//!     .client_id("client_id")
+//!     .client_secret("client_secret")
+//!     .callback_url("http://localhost:8000/callback")
+//!     .config(config)
+//!     .build()?;
+//!
+//! // Discover the endpoints before creating login URLs
+//! if !esi_client.oauth2().discover_endpoints().await {
+//!     println!("Using the configured OAuth2 endpoints");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use oauth2::{AuthUrl, TokenUrl};
+use tokio::sync::OnceCell;
+
+use crate::model::oauth2::AuthorizationServerMetadata;
+use crate::{Client, Error};
+
+use super::client::OAuth2Client;
+use super::metadata::fetch_server_metadata;
+use super::OAuth2Endpoints;
+
+/// State of the discovery of the SSO's endpoints, present on clients with discovery enabled
+pub(crate) struct OAuth2Discovery {
+    /// URL of the SSO's server metadata
+    metadata_url: String,
+    /// Endpoints discovered on first use, unset until discovery succeeds
+    endpoints: OnceCell<DiscoveredEndpoints>,
+}
+
+/// Endpoints discovered from the SSO's server metadata
+struct DiscoveredEndpoints {
+    /// OAuth2 client using the discovered authorization & token URLs, `None` if OAuth2 isn't configured
+    oauth2_client: Option<OAuth2Client>,
+}
+
+impl OAuth2Discovery {
+    /// Creates a new instance of [`OAuth2Discovery`] which has not discovered endpoints yet
+    ///
+    /// # Arguments
+    /// - `metadata_url` (`String`): URL of the SSO's server metadata
+    pub(crate) fn new(metadata_url: String) -> Self {
+        Self {
+            metadata_url,
+            endpoints: OnceCell::new(),
+        }
+    }
+}
+
+impl<'a> OAuth2Endpoints<'a> {
+    /// Discovers the SSO's endpoints ahead of their first use
+    ///
+    /// Requests the server metadata until discovery succeeds, further calls return without making
+    /// a request. Does nothing if discovery isn't enabled with
+    /// [`ConfigBuilder::oauth2_discovery`](crate::ConfigBuilder::oauth2_discovery).
+    ///
+    /// For an overview & usage example, see the [module-level documentation](self)
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the discovered endpoints are used, `false` if the configured endpoints are
+    pub async fn discover_endpoints(&self) -> bool {
+        self.client.discover_oauth2_endpoints().await;
+
+        self.client
+            .inner
+            .oauth2_discovery
+            .as_ref()
+            .is_some_and(|discovery| discovery.endpoints.initialized())
+    }
+}
+
+impl Client {
+    /// Discovers the SSO's endpoints if discovery is enabled & hasn't succeeded yet
+    ///
+    /// A failed discovery isn't cached, the configured endpoints are used until a later call
+    /// succeeds.
+    pub(crate) async fn discover_oauth2_endpoints(&self) {
+        let Some(discovery) = &self.inner.oauth2_discovery else {
+            return;
+        };
+
+        let result = discovery
+            .endpoints
+            .get_or_try_init(|| async {
+                let metadata =
+                    fetch_server_metadata(&self.inner.reqwest_client, &discovery.metadata_url)
+                        .await?;
+
+                self.apply_server_metadata(metadata)
+            })
+            .await;
+
+        if let Err(error) = result {
+            log::warn!(
+                "Failed to discover OAuth2 endpoints from {}, using the configured endpoints: {}",
+                discovery.metadata_url,
+                error
+            );
+        }
+    }
+
+    /// Returns the OAuth2 client, using the discovered endpoints once discovered
+    pub(crate) fn oauth2_client(&self) -> Option<&OAuth2Client> {
+        let discovered = self
+            .inner
+            .oauth2_discovery
+            .as_ref()
+            .and_then(|discovery| discovery.endpoints.get())
+            .and_then(|endpoints| endpoints.oauth2_client.as_ref());
+
+        discovered.or(self.inner.oauth2_client.as_ref())
+    }
+
+    /// Validates the endpoints of the server metadata & applies them to the client
+    fn apply_server_metadata(
+        &self,
+        metadata: AuthorizationServerMetadata,
+    ) -> Result<DiscoveredEndpoints, Error> {
+        let urls = (
+            AuthUrl::new(metadata.authorization_endpoint.clone()),
+            TokenUrl::new(metadata.token_endpoint.clone()),
+            url::Url::parse(&metadata.jwks_uri),
+        );
+
+        let (auth_url, token_url) = match urls {
+            (Ok(auth_url), Ok(token_url), Ok(_)) => (auth_url, token_url),
+            (Err(error), _, _) | (_, Err(error), _) | (_, _, Err(error)) => {
+                log::warn!(
+                    "SSO server metadata advertises an invalid endpoint URL: {:?}",
+                    metadata
+                );
+
+                return Err(error.into());
+            }
+        };
+
+        log::debug!(
+            "Discovered OAuth2 endpoints: authorization {}, token {}, JWK {}",
+            auth_url.as_str(),
+            token_url.as_str(),
+            metadata.jwks_uri
+        );
+
+        self.inner
+            .jwt_key_cache
+            .set_discovered_jwk_url(metadata.jwks_uri);
+
+        let oauth2_client = self
+            .inner
+            .oauth2_client
+            .clone()
+            .map(|client| client.set_auth_uri(auth_url).set_token_uri(token_url));
+
+        Ok(DiscoveredEndpoints { oauth2_client })
+    }
+}
@@ -14,10 +14,14 @@
 //! For details, see the [`JwtKeyCache`] struct.
 //! For a higher level overview of the usage of JWT keys, see [module-level documentation](super)
 
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{Mutex, OnceLock};
 use std::time::Instant;
 use std::{sync::atomic::AtomicBool, time::Duration};
 
 use tokio::sync::{Notify, RwLock};
+use tokio::task::JoinHandle;
 
 use crate::{
     config::Config,
@@ -26,6 +30,7 @@ use crate::{
         DEFAULT_JWK_REFRESH_BACKOFF, DEFAULT_JWK_REFRESH_COOLDOWN, DEFAULT_JWK_REFRESH_MAX_RETRIES,
         DEFAULT_JWK_REFRESH_TIMEOUT, DEFAULT_JWK_URL,
     },
+    health::JwtKeyCacheHealth,
     model::oauth2::EveJwtKeys,
 };
 
@@ -44,6 +49,8 @@ pub(crate) struct JwtKeyCacheConfig {
     // Refresh Settings
     /// JSON web token key URL that provides keys used to validate tokens
     pub(crate) jwk_url: String,
+    /// Additional JWT issuers mapped to the JWK URL providing that issuer's own key set
+    pub(crate) issuer_jwk_urls: HashMap<String, String>,
     /// Backoff period after a JWT key refresh failure when cache is empty or expired (default 100 milliseconds)
     pub(crate) refresh_backoff: Duration,
     /// Timeout when waiting for another thread to refresh JWT key (default 5 seconds)
@@ -76,19 +83,87 @@ pub(crate) struct JwtKeyCacheConfig {
 /// - [`RwLock`]: To allow for simultaneous reads of the cache and the last refresh failure timestamp
 /// - [`AtomicBool`]: To manage a high volume of simultaneous attempts to acquire a refresh lock
 /// - [`Notify`]: To provide notifications of when the cache has been updated
+/// - [`Mutex`]: To track handles of spawned background refresh tasks for shutdown
 pub(crate) struct JwtKeyCache {
     /// RwLock with a tuple containing JWT keys and timestamp of when keys were updated
     pub(super) cache: RwLock<Option<(EveJwtKeys, Instant)>>,
+    /// RwLock with JWT keys & timestamp of when keys were updated for each issuer configured
+    /// with its own JWK URL, keyed by issuer
+    pub(super) issuer_cache: RwLock<HashMap<String, (EveJwtKeys, Instant)>>,
+    /// Refresh lock, notifier & last refresh failure for each issuer configured with its own
+    /// JWK URL, keyed by issuer
+    pub(super) issuer_refresh: HashMap<String, IssuerRefreshState>,
     /// AtomicBool indicating whether a JWT key refresh is currently in progress
     pub(super) refresh_lock: AtomicBool,
     /// Notifier for when a JWT key refresh is completed
     pub(super) refresh_notifier: Notify,
     /// RwLock with a timestamp of last failed set of JWT key refresh attemmpts
     pub(super) last_refresh_failure: RwLock<Option<Instant>>,
+    /// AtomicBool indicating whether the client has been shut down, preventing new background refreshes
+    pub(super) shutdown: AtomicBool,
+    /// Handles of spawned background refresh tasks which are aborted upon shutdown
+    pub(super) background_tasks: Mutex<Vec<JoinHandle<()>>>,
+    /// JWK URL discovered from the SSO's server metadata, used instead of the configured JWK URL
+    pub(super) discovered_jwk_url: OnceLock<String>,
     /// Configuration for JWT key cache & refreshes
     pub(super) config: JwtKeyCacheConfig,
 }
 
+/// Coordinates refreshes of the keys of an issuer configured with its own JWK URL
+///
+/// Mirrors the refresh lock, notifier & last refresh failure of [`JwtKeyCache`] used for the
+/// default key set, so concurrent validations of tokens from the issuer make a single fetch &
+/// failed fetches are followed by the same cooldown.
+#[derive(Default)]
+pub(crate) struct IssuerRefreshState {
+    /// AtomicBool indicating whether a refresh of the issuer's keys is currently in progress
+    pub(super) refresh_lock: AtomicBool,
+    /// Notifier for when a refresh of the issuer's keys is completed
+    pub(super) refresh_notifier: Notify,
+    /// RwLock with a timestamp of the last failed set of refresh attempts for the issuer
+    pub(super) last_refresh_failure: RwLock<Option<Instant>>,
+}
+
+impl IssuerRefreshState {
+    /// Attempts to acquire the lock to refresh the issuer's keys
+    ///
+    /// # Returns
+    /// - [`true`] if the lock is acquired successfully,
+    /// - [`false`] if the lock is already held by another thread
+    pub(super) fn refresh_lock_try_acquire(&self) -> bool {
+        self.refresh_lock
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    /// Releases the lock to refresh the issuer's keys & notifies any waiting threads
+    pub(super) fn refresh_lock_release_and_notify(&self) {
+        self.refresh_lock.store(false, Ordering::Release);
+        self.refresh_notifier.notify_waiters();
+    }
+
+    /// Returns the cooldown remaining in seconds after the last failed refresh of the issuer's keys
+    ///
+    /// # Arguments
+    /// - `refresh_cooldown` ([`Duration`]): The cooldown following a failed set of refresh attempts
+    ///
+    /// # Returns
+    /// - Some([`u64`]): The cooldown remaining in seconds
+    /// - None: If there is no remaining cooldown
+    pub(super) async fn check_refresh_cooldown(&self, refresh_cooldown: Duration) -> Option<u64> {
+        let last_failure = (*self.last_refresh_failure.read().await)?;
+        let elapsed_secs = last_failure.elapsed().as_secs();
+
+        (elapsed_secs < refresh_cooldown.as_secs())
+            .then(|| refresh_cooldown.as_secs() - elapsed_secs)
+    }
+
+    /// Sets the last refresh failure time of the issuer's keys
+    pub(super) async fn set_refresh_failure(&self, failure_timestamp: Option<Instant>) {
+        *self.last_refresh_failure.write().await = failure_timestamp;
+    }
+}
+
 impl JwtKeyCacheConfig {
     /// Initializes a new JWT key cache config with the default settings
     pub(crate) fn new() -> Self {
@@ -98,6 +173,7 @@ impl JwtKeyCacheConfig {
 
             // Refresh Settings
             jwk_url: DEFAULT_JWK_URL.to_string(),
+            issuer_jwk_urls: HashMap::new(),
             refresh_max_retries: DEFAULT_JWK_REFRESH_MAX_RETRIES,
             refresh_backoff: DEFAULT_JWK_REFRESH_BACKOFF,
             refresh_timeout: DEFAULT_JWK_REFRESH_TIMEOUT,
@@ -122,9 +198,19 @@ impl JwtKeyCache {
     pub(crate) fn new(config: &Config) -> Self {
         Self {
             cache: RwLock::new(None),
+            issuer_cache: RwLock::new(HashMap::new()),
+            issuer_refresh: config
+                .jwt_key_cache_config
+                .issuer_jwk_urls
+                .keys()
+                .map(|issuer| (issuer.clone(), IssuerRefreshState::default()))
+                .collect(),
             refresh_lock: AtomicBool::new(false),
             refresh_notifier: Notify::new(),
             last_refresh_failure: RwLock::new(None),
+            shutdown: AtomicBool::new(false),
+            background_tasks: Mutex::new(Vec::new()),
+            discovered_jwk_url: OnceLock::new(),
             config: config.jwt_key_cache_config.clone(),
         }
     }
@@ -215,6 +301,214 @@ impl JwtKeyCache {
         log::debug!("{}", message);
     }
 
+    /// Returns the JWK URL providing the keys of the default issuers
+    ///
+    /// The URL discovered from the SSO's server metadata is preferred over the configured URL.
+    pub(crate) fn jwk_url(&self) -> &str {
+        self.discovered_jwk_url
+            .get()
+            .map(String::as_str)
+            .unwrap_or(&self.config.jwk_url)
+    }
+
+    /// Sets the JWK URL discovered from the SSO's server metadata, ignored if already set
+    pub(crate) fn set_discovered_jwk_url(&self, jwk_url: String) {
+        let _ = self.discovered_jwk_url.set(jwk_url);
+    }
+
+    /// Returns whether the issuer is configured with its own JWK URL & key set
+    pub(crate) fn has_issuer_jwk_url(&self, issuer: &str) -> bool {
+        self.config.issuer_jwk_urls.contains_key(issuer)
+    }
+
+    /// Retrieves JWT keys from the cache for an issuer configured with its own JWK URL
+    ///
+    /// # Arguments
+    /// - `issuer` (&[`str`]): The issuer the keys were fetched for
+    ///
+    /// # Returns
+    /// - `Some((EveJwtKeys, Instant))`: Keys & timestamp of when they were cached for the issuer
+    /// - `None`: No keys are cached for the issuer
+    pub(super) async fn get_issuer_keys(&self, issuer: &str) -> Option<(EveJwtKeys, Instant)> {
+        log::trace!(
+            "Attempting to retrieve JWT keys from cache for issuer {}",
+            issuer
+        );
+
+        let cache = self.issuer_cache.read().await;
+
+        cache.get(issuer).cloned()
+    }
+
+    /// Updates the cached JWT keys for an issuer configured with its own JWK URL
+    ///
+    /// # Arguments
+    /// - `issuer` (&[`str`]): The issuer the keys were fetched for
+    /// - `keys` ([`EveJwtKeys`]): The keys to store in the cache for the issuer
+    pub(super) async fn update_issuer_keys(&self, issuer: &str, keys: EveJwtKeys) {
+        let key_count = keys.keys.len();
+
+        let mut cache = self.issuer_cache.write().await;
+        cache.insert(issuer.to_string(), (keys, Instant::now()));
+
+        log::debug!(
+            "JWT keys cache for issuer {} successfully updated with {} keys",
+            issuer,
+            key_count
+        );
+    }
+
+    /// Clears the cached JWT keys for an issuer configured with its own JWK URL
+    ///
+    /// Follows the same refresh cooldown rules as [`Self::clear_cache`], keys set within
+    /// the cooldown period will not be cleared.
+    ///
+    /// # Arguments
+    /// - `issuer` (&[`str`]): The issuer to clear the keys for
+    ///
+    /// # Returns
+    /// - [`bool`]: Indicates whether or not the keys for the issuer were cleared.
+    pub(crate) async fn clear_issuer_cache(&self, issuer: &str) -> bool {
+        let mut cache = self.issuer_cache.write().await;
+
+        match cache.get(issuer) {
+            Some((_, timestamp)) if timestamp.elapsed() > self.config.refresh_cooldown => {
+                log::info!(
+                    "Clearing JWT key cache for issuer {} of keys that were set {}s ago",
+                    issuer,
+                    timestamp.elapsed().as_secs()
+                );
+
+                cache.remove(issuer);
+
+                true
+            }
+            Some(_) => {
+                log::debug!(
+                    "JWT key cache for issuer {} not cleared due to keys being within {} seconds of age",
+                    issuer,
+                    self.config.refresh_cooldown.as_secs()
+                );
+
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether the cache has been shut down & no longer spawns background refreshes
+    pub(super) fn is_shutdown(&self) -> bool {
+        self.shutdown.load(Ordering::Acquire)
+    }
+
+    /// Tracks the handle of a spawned background refresh task so it can be aborted upon shutdown
+    ///
+    /// Handles of tasks which have already finished are pruned to prevent unbounded growth.
+    ///
+    /// The shutdown flag is checked again while holding the task list's lock, [`Self::shutdown`]
+    /// sets the flag before draining the list so a task spawned after the caller's earlier check
+    /// but tracked after the list was drained is aborted here rather than outliving the shutdown.
+    ///
+    /// # Returns
+    /// - `bool`: `true` if the task was tracked, `false` if it was aborted due to a shutdown
+    pub(super) fn track_background_task(&self, handle: JoinHandle<()>) -> bool {
+        let mut tasks = self
+            .background_tasks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if self.is_shutdown() {
+            drop(tasks);
+
+            if !handle.is_finished() {
+                handle.abort();
+
+                // The aborted task may have been holding the refresh lock
+                self.refresh_lock_release_and_notify();
+            }
+
+            return false;
+        }
+
+        tasks.retain(|task| !task.is_finished());
+        tasks.push(handle);
+
+        true
+    }
+
+    /// Returns the current state of the cache & its background refresh tasks
+    pub(crate) async fn health(&self) -> JwtKeyCacheHealth {
+        let (key_count, age) = match &*self.cache.read().await {
+            Some((keys, timestamp)) => (keys.keys.len(), Some(timestamp.elapsed())),
+            None => (0, None),
+        };
+        let last_refresh_failure = self
+            .last_refresh_failure
+            .read()
+            .await
+            .map(|timestamp| timestamp.elapsed().as_secs());
+        let background_tasks_running = self
+            .background_tasks
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .filter(|task| !task.is_finished())
+            .count();
+
+        JwtKeyCacheHealth {
+            cached: age.is_some(),
+            key_count,
+            age_secs: age.map(|age| age.as_secs()),
+            expired: age.is_some_and(|age| age >= self.config.cache_ttl),
+            refreshing: self.refresh_lock.load(Ordering::Acquire),
+            last_refresh_failure_secs: last_refresh_failure,
+            background_refresh_enabled: self.config.background_refresh_enabled,
+            background_tasks_running,
+            shutdown: self.is_shutdown(),
+        }
+    }
+
+    /// Prevents new background refreshes & aborts any outstanding background refresh tasks
+    ///
+    /// Waits for aborted tasks to finish, then releases the refresh lock & notifies waiting
+    /// threads in case a task was aborted mid-refresh.
+    ///
+    /// # Returns
+    /// - [`usize`]: The number of outstanding background refresh tasks which were aborted
+    pub(crate) async fn shutdown(&self) -> usize {
+        self.shutdown.store(true, Ordering::Release);
+
+        let tasks: Vec<JoinHandle<()>> = {
+            let mut tasks = self
+                .background_tasks
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            tasks.drain(..).filter(|task| !task.is_finished()).collect()
+        }; // Mutex released here
+
+        let aborted = tasks.len();
+
+        for task in tasks {
+            task.abort();
+
+            // Wait for the task to finish, the result is expected to be a cancellation error
+            let _ = task.await;
+        }
+
+        if aborted > 0 {
+            // An aborted task may have been holding the refresh lock
+            self.refresh_lock_release_and_notify();
+        }
+
+        log::debug!(
+            "JWT key cache shut down, aborted {} background refresh task(s)",
+            aborted
+        );
+
+        aborted
+    }
+
     /// Clears the JWT key cache of any keys present
     ///
     /// You would typically use this in the event of a validation failure
@@ -579,6 +873,164 @@ mod clear_cache_tests {
     }
 }
 
+#[cfg(test)]
+mod issuer_cache_tests {
+    use std::time::{Duration, Instant};
+
+    use super::super::tests::create_mock_keys;
+    use crate::tests::setup;
+
+    /// Keys cached for an issuer are retrievable only for that issuer
+    ///
+    /// # Test Setup
+    /// - Setup a basic ESI client
+    /// - Update the issuer cache with mock keys for an issuer
+    ///
+    /// # Assert
+    /// - Assert keys are returned for the issuer
+    /// - Assert no keys are returned for another issuer
+    /// - Assert the default issuer's cache remains empty
+    #[tokio::test]
+    async fn issuer_cache_update_and_get() {
+        // Setup a basic ESI client
+        let (esi_client, _) = setup().await;
+        let jwt_key_cache = &esi_client.inner.jwt_key_cache;
+
+        // Update the issuer cache with mock keys
+        jwt_key_cache
+            .update_issuer_keys("https://sisilogin.testeveonline.com", create_mock_keys())
+            .await;
+
+        // Assert keys are only returned for the issuer they were cached for
+        assert!(jwt_key_cache
+            .get_issuer_keys("https://sisilogin.testeveonline.com")
+            .await
+            .is_some());
+        assert!(jwt_key_cache
+            .get_issuer_keys("https://example.com")
+            .await
+            .is_none());
+
+        // Assert the default issuer's cache remains empty
+        assert!(jwt_key_cache.get_keys().await.is_none());
+    }
+
+    /// Issuer cache only clears when keys are older than the refresh cooldown
+    ///
+    /// # Test Setup
+    /// - Setup a basic ESI client
+    /// - Fill issuer cache with recent keys for one issuer & old keys for another
+    ///
+    /// # Assert
+    /// - Assert recent keys were not cleared
+    /// - Assert old keys were cleared
+    #[tokio::test]
+    async fn issuer_cache_clear() {
+        // Setup a basic ESI client
+        let (esi_client, _) = setup().await;
+        let jwt_key_cache = &esi_client.inner.jwt_key_cache;
+
+        // Fill issuer cache with recent & old keys
+        jwt_key_cache
+            .update_issuer_keys("recent", create_mock_keys())
+            .await;
+        {
+            let timestamp = Instant::now() - Duration::from_secs(61);
+
+            let mut cache = jwt_key_cache.issuer_cache.write().await;
+            cache.insert("old".to_string(), (create_mock_keys(), timestamp));
+        } // Write lock released here
+
+        // Assert recent keys were not cleared
+        assert!(!jwt_key_cache.clear_issuer_cache("recent").await);
+        assert!(jwt_key_cache.get_issuer_keys("recent").await.is_some());
+
+        // Assert old keys were cleared
+        assert!(jwt_key_cache.clear_issuer_cache("old").await);
+        assert!(jwt_key_cache.get_issuer_keys("old").await.is_none());
+    }
+}
+
+#[cfg(test)]
+mod shutdown_tests {
+    use std::time::Duration;
+
+    use crate::tests::setup;
+
+    /// Outstanding background tasks are aborted & the refresh lock released on shutdown
+    ///
+    /// # Test Setup
+    /// - Setup a basic ESI client
+    /// - Acquire a refresh lock & track a long running task emulating a background refresh
+    ///
+    /// # Assert
+    /// - Assert the task was aborted
+    /// - Assert the cache is marked as shut down
+    /// - Assert the refresh lock was released
+    #[tokio::test]
+    async fn shutdown_aborts_background_tasks() {
+        // Setup a basic ESI client
+        let (esi_client, _) = setup().await;
+        let jwt_key_cache = &esi_client.inner.jwt_key_cache;
+
+        // Acquire a refresh lock & track a long running task
+        assert!(jwt_key_cache.refresh_lock_try_acquire());
+        let handle = tokio::spawn(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+        jwt_key_cache.track_background_task(handle);
+
+        let aborted = esi_client.shutdown().await;
+
+        // Assert the task was aborted
+        assert_eq!(aborted, 1);
+
+        // Assert the cache is marked as shut down
+        assert!(jwt_key_cache.is_shutdown());
+
+        // Assert the refresh lock was released
+        assert!(jwt_key_cache.refresh_lock_try_acquire());
+    }
+
+    /// A background task tracked after the client was shut down is aborted rather than tracked
+    ///
+    /// # Test Setup
+    /// - Setup a basic ESI client & shut it down
+    /// - Acquire a refresh lock & track a long running task emulating a background refresh
+    ///   spawned before the shutdown was observed
+    ///
+    /// # Assert
+    /// - Assert the task was not tracked
+    /// - Assert the task was aborted
+    /// - Assert the refresh lock was released
+    #[tokio::test]
+    async fn track_after_shutdown_aborts_task() {
+        // Setup a basic ESI client & shut it down
+        let (esi_client, _) = setup().await;
+        let jwt_key_cache = &esi_client.inner.jwt_key_cache;
+        esi_client.shutdown().await;
+
+        // Acquire a refresh lock & track a long running task
+        assert!(jwt_key_cache.refresh_lock_try_acquire());
+        let (sender, receiver) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(async move {
+            let _sender = sender;
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+        let tracked = jwt_key_cache.track_background_task(handle);
+
+        // Assert the task was not tracked
+        assert!(!tracked);
+
+        // Assert the task was aborted, dropping the sender
+        let result = tokio::time::timeout(Duration::from_secs(1), receiver).await;
+        assert!(matches!(result, Ok(Err(_))));
+
+        // Assert the refresh lock was released
+        assert!(jwt_key_cache.refresh_lock_try_acquire());
+    }
+}
+
 #[cfg(test)]
 mod jwk_refresh_lock_try_acquire_tests {
     use crate::Client;
@@ -70,7 +70,7 @@ use std::time::Instant;
 use crate::error::{Error, OAuthError};
 use crate::model::oauth2::EveJwtKeys;
 use crate::oauth2::jwk::cache::JwtKeyCache;
-use crate::oauth2::jwk::refresh::refresh_jwt_keys;
+use crate::oauth2::jwk::refresh::{refresh_issuer_jwt_keys, refresh_jwt_keys};
 use crate::oauth2::jwk::util::{
     check_refresh_cooldown, is_cache_approaching_expiry, is_cache_expired,
 };
@@ -145,6 +145,8 @@ impl<'a> JwkApi<'a> {
     /// - [`Error`]: Returns an error if the JWT key cache is empty and new keys could not be fetched.
     pub async fn get_jwt_keys(&self) -> Result<EveJwtKeys, Error> {
         let esi_client = self.client;
+        esi_client.discover_oauth2_endpoints().await;
+
         let jwt_key_cache = &esi_client.inner.jwt_key_cache;
         let config = &jwt_key_cache.config;
 
@@ -236,6 +238,7 @@ impl<'a> JwkApi<'a> {
     /// - [`Error::ReqwestError`]: If the request to fetch JWT keys fails.
     pub async fn fetch_and_update_cache(&self) -> Result<EveJwtKeys, Error> {
         let esi_client = self.client;
+        esi_client.discover_oauth2_endpoints().await;
 
         fetch_and_update_cache(
             &esi_client.inner.reqwest_client,
@@ -244,6 +247,88 @@ impl<'a> JwkApi<'a> {
         .await
     }
 
+    /// Gets JWT keys for the provided token issuer with caching support.
+    ///
+    /// Issuers configured with their own JWK URL using
+    /// [`ConfigBuilder::jwt_issuer_jwk_url`](crate::ConfigBuilder::jwt_issuer_jwk_url) have a key set
+    /// cached separately from the default JWK URL, allowing tokens from multiple EVE SSO hostnames to
+    /// validate during transitions. Keys for these issuers are fetched when missing or expired, with
+    /// the same refresh lock, retries & failure cooldown as the default key set so concurrent
+    /// validations make a single fetch.
+    ///
+    /// All other issuers use the default key set, see [`Self::get_jwt_keys`].
+    ///
+    /// # Arguments
+    /// - `issuer` (&[`str`]): The `iss` claim of the token to be validated
+    ///
+    /// # Returns
+    /// - [`EveJwtKeys`]: Struct representing JWT keys for the issuer.
+    ///
+    /// # Errors
+    /// - [`Error`]: If there is an issue fetching the JWT keys for the issuer.
+    pub async fn get_jwt_keys_for_issuer(&self, issuer: &str) -> Result<EveJwtKeys, Error> {
+        let jwt_key_cache = &self.client.inner.jwt_key_cache;
+
+        // Use the default key set for issuers without their own JWK URL
+        let Some(jwk_url) = jwt_key_cache.config.issuer_jwk_urls.get(issuer) else {
+            return self.get_jwt_keys().await;
+        };
+
+        // Return keys from the issuer's cache if present & not yet expired
+        if let Some((keys, timestamp)) = jwt_key_cache.get_issuer_keys(issuer).await {
+            if !is_cache_expired(jwt_key_cache, timestamp) {
+                log::trace!(
+                    "JWT keys for issuer {} still valid, using keys from cache (age: {}s)",
+                    issuer,
+                    timestamp.elapsed().as_secs()
+                );
+
+                return Ok(keys);
+            }
+        }
+
+        let Some(refresh_state) = jwt_key_cache.issuer_refresh.get(issuer) else {
+            return fetch_jwt_keys(&self.client.inner.reqwest_client, jwk_url).await;
+        };
+
+        // Return an error if a recent set of refresh attempts for the issuer failed
+        if let Some(cooldown_remaining) = refresh_state
+            .check_refresh_cooldown(jwt_key_cache.config.refresh_cooldown)
+            .await
+        {
+            let message = format!(
+                "JWT key refresh cooldown for issuer {} still active due to recent refresh failure during last {} seconds. Cooldown remaining: {} seconds.",
+                issuer,
+                jwt_key_cache.config.refresh_cooldown.as_secs(),
+                cooldown_remaining
+            );
+
+            log::error!("{}", message);
+
+            return Err(Error::OAuthError(OAuthError::JwtKeyRefreshCooldown(
+                message,
+            )));
+        }
+
+        // Wait for the refresh in progress on another thread rather than fetching again, the
+        // notification future is created first so a refresh completing in between is not missed
+        let notified = refresh_state.refresh_notifier.notified();
+        if !refresh_state.refresh_lock_try_acquire() {
+            return self.wait_for_ongoing_issuer_refresh(issuer, notified).await;
+        }
+
+        // We have the lock, fetch keys from the issuer's JWK URL & update the issuer's cache
+        refresh_issuer_jwt_keys(
+            &self.client.inner.reqwest_client,
+            jwt_key_cache,
+            refresh_state,
+            issuer,
+            jwk_url,
+            jwt_key_cache.config.refresh_max_retries,
+        )
+        .await
+    }
+
     /// Fetches JWT keys from EVE's OAuth2 API
     ///
     /// This function does not implement measures to prevent concurrent JWT key fetch
@@ -257,10 +342,11 @@ impl<'a> JwkApi<'a> {
     /// - [`Error::ReqwestError`]: If the request to fetch JWT keys fails.
     pub async fn fetch_jwt_keys(&self) -> Result<EveJwtKeys, Error> {
         let esi_client = self.client;
+        esi_client.discover_oauth2_endpoints().await;
 
         fetch_jwt_keys(
             &esi_client.inner.reqwest_client,
-            &esi_client.inner.jwt_key_cache.config.jwk_url,
+            esi_client.inner.jwt_key_cache.jwk_url(),
         )
         .await
     }
@@ -381,7 +467,7 @@ pub(super) async fn fetch_and_update_cache(
     let start_time = Instant::now();
 
     // Fetch fresh keys from EVE's OAuth2 API
-    let fetch_result = fetch_jwt_keys(reqwest_client, &jwt_key_cache.config.jwk_url).await;
+    let fetch_result = fetch_jwt_keys(reqwest_client, jwt_key_cache.jwk_url()).await;
 
     match fetch_result {
         Ok(fresh_keys) => {
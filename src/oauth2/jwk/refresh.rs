@@ -12,14 +12,15 @@
 
 use std::time::Instant;
 
+use ::tokio::sync::futures::Notified;
 use ::tokio::time::Duration;
 
 use crate::error::{Error, OAuthError};
 use crate::model::oauth2::EveJwtKeys;
-use crate::oauth2::jwk::cache::JwtKeyCache;
+use crate::oauth2::jwk::cache::{IssuerRefreshState, JwtKeyCache};
 
 use super::util::check_refresh_cooldown;
-use super::{fetch_and_update_cache, JwkApi};
+use super::{fetch_and_update_cache, fetch_jwt_keys, JwkApi};
 
 impl<'a> JwkApi<'a> {
     /// Waits for an ongoing JWT key cache refresh operation to complete and returns the result
@@ -112,6 +113,73 @@ impl<'a> JwkApi<'a> {
         )))
     }
 
+    /// Waits for an ongoing refresh of the keys of an issuer configured with its own JWK URL
+    ///
+    /// The issuer's counterpart to [`Self::wait_for_ongoing_refresh`], waiting for the thread
+    /// holding the issuer's refresh lock to finish & returning the keys from the issuer's cache.
+    ///
+    /// # Arguments
+    /// - `issuer` (&[`str`]): The issuer whose keys are being refreshed
+    /// - `notified` ([`Notified`]): Future for the issuer's refresh notifier, created before
+    ///   the attempt to acquire the refresh lock so a refresh completing in between is not missed
+    ///
+    /// # Returns
+    /// - Ok([`EveJwtKeys`]) if the refresh was successful and keys are now in the issuer's cache
+    /// - Err([`Error`]) if the refresh attempt failed or timed out
+    pub(super) async fn wait_for_ongoing_issuer_refresh(
+        &self,
+        issuer: &str,
+        notified: Notified<'_>,
+    ) -> Result<EveJwtKeys, Error> {
+        let jwt_key_cache = &self.client.inner.jwt_key_cache;
+        let config = &jwt_key_cache.config;
+
+        let start_time = Instant::now();
+
+        log::debug!(
+            "Waiting for another thread to refresh JWT keys for issuer {}",
+            issuer
+        );
+
+        let refresh_success = tokio::select! {
+            _ = notified => {true}
+            _ = tokio::time::sleep(config.refresh_timeout) => {false}
+        };
+
+        let elapsed = start_time.elapsed();
+        if !refresh_success {
+            let error_message = format!(
+                "Timed out after waiting {}ms for JWT key refresh for issuer {}.",
+                elapsed.as_millis(),
+                issuer
+            );
+
+            log::debug!("{}", error_message);
+
+            return Err(Error::OAuthError(OAuthError::JwtKeyRefreshTimeout(
+                error_message,
+            )));
+        }
+
+        if let Some((keys, timestamp)) = jwt_key_cache.get_issuer_keys(issuer).await {
+            if timestamp.elapsed() < config.cache_ttl {
+                return Ok(keys);
+            }
+        }
+
+        let error_message = format!(
+            "JWT key cache for issuer {} still empty after waiting {}ms for refresh. Likely due to a failure to refresh the keys.",
+            issuer,
+            elapsed.as_millis()
+        );
+
+        log::debug!("{}", error_message);
+
+        Err(Error::OAuthError(OAuthError::JwtKeyRefreshFailure(
+            error_message,
+        )))
+    }
+
     /// Helper function to trigger a background JWT refresh task.
     ///
     /// This method initiates an asynchronous task to refresh the JWT keys without blocking the caller:
@@ -122,6 +190,7 @@ impl<'a> JwkApi<'a> {
     /// 5. Records success or failure for backoff management
     ///
     /// The background refresh is only triggered when ALL of the following conditions are met:
+    /// - The client has not been shut down with [`Client::shutdown`](crate::Client::shutdown)
     /// - Not within the backoff period from a previous failed refresh
     /// - No refresh operation is currently in progress (acquired via atomic lock)
     ///
@@ -144,6 +213,13 @@ impl<'a> JwkApi<'a> {
         let esi_client = self.client;
         let jwt_key_cache = &esi_client.inner.jwt_key_cache;
 
+        // Don't spawn new tasks once the client has been shut down
+        if jwt_key_cache.is_shutdown() {
+            log::debug!("Client has been shut down, skipping background JWT key refresh");
+
+            return false;
+        }
+
         // Check if we are still in cooldown due to fetch failure within 60 second cooldown period
         if check_refresh_cooldown(jwt_key_cache).await.is_some() {
             log::debug!("Respecting refresh cooldown, delaying JWT key refresh");
@@ -163,11 +239,20 @@ impl<'a> JwkApi<'a> {
         // Clone the required components
         let client_ref = esi_client.inner.clone();
 
-        tokio::spawn(async move {
+        let handle = tokio::spawn(async move {
             // Make no retries as the background refresh utilizes a 60 second cooldown between attempts instead.
-            refresh_jwt_keys(&client_ref.reqwest_client, &client_ref.jwt_key_cache, 0).await
+            let _ =
+                refresh_jwt_keys(&client_ref.reqwest_client, &client_ref.jwt_key_cache, 0).await;
         });
 
+        // Track the task so it can be aborted if the client is shut down, a shutdown since the
+        // check above aborts the task instead
+        if !jwt_key_cache.track_background_task(handle) {
+            log::debug!("Client was shut down, aborted background JWT key refresh");
+
+            return false;
+        }
+
         log::debug!("Background JWT key refresh task started");
 
         true
@@ -217,7 +302,10 @@ pub(super) async fn refresh_jwt_keys(
 
     // Attempt inital JWT key refresh
 
-    log::trace!("Fetching JWT keys from JWK URL: {}", &config.jwk_url);
+    log::trace!(
+        "Fetching JWT keys from JWK URL: {}",
+        jwt_key_cache.jwk_url()
+    );
 
     let mut result = fetch_and_update_cache(reqwest_client, jwt_key_cache).await;
 
@@ -295,6 +383,81 @@ pub(super) async fn refresh_jwt_keys(
     }
 }
 
+/// Refreshes the keys of an issuer configured with its own JWK URL with retry logic
+///
+/// The issuer's counterpart to [`refresh_jwt_keys`], fetching from the issuer's JWK URL with the
+/// same exponential backoff & updating the issuer's cache. Assumes the issuer's refresh lock is
+/// already acquired & always releases it, recording a failure for the issuer's refresh cooldown
+/// if all attempts fail.
+///
+/// # Arguments
+/// - `reqwest_client` (&[`reqwest::Client`]): Client used for making HTTP requests
+/// - `jwt_key_cache` (&[`JwtKeyCache`]): Cache to update with the issuer's keys
+/// - `refresh_state` (&[`IssuerRefreshState`]): The issuer's refresh lock & last refresh failure
+/// - `issuer` (&[`str`]): The issuer to refresh the keys of
+/// - `jwk_url` (&[`str`]): The issuer's JWK URL
+/// - `max_retries` ([`u32`]): The amount of retries to make if the first attempt fails
+///
+/// # Returns
+/// - `Ok(`[`EveJwtKeys`]`)` if keys were successfully fetched and cached
+/// - `Err(`[`Error`]`)` if all request attempts failed
+pub(super) async fn refresh_issuer_jwt_keys(
+    reqwest_client: &reqwest::Client,
+    jwt_key_cache: &JwtKeyCache,
+    refresh_state: &IssuerRefreshState,
+    issuer: &str,
+    jwk_url: &str,
+    max_retries: u32,
+) -> Result<EveJwtKeys, Error> {
+    let config = &jwt_key_cache.config;
+
+    let mut result = fetch_jwt_keys(reqwest_client, jwk_url).await;
+
+    let mut retry_attempts = 0;
+    while result.is_err() && retry_attempts < max_retries {
+        let backoff_duration = Duration::from_millis(
+            config.refresh_backoff.as_millis() as u64 * 2u64.pow(retry_attempts),
+        );
+
+        log::debug!(
+            "JWT key fetch for issuer {} failed. Retrying ({}/{}) after {}ms",
+            issuer,
+            retry_attempts + 1,
+            max_retries,
+            backoff_duration.as_millis()
+        );
+
+        tokio::time::sleep(backoff_duration).await;
+
+        result = fetch_jwt_keys(reqwest_client, jwk_url).await;
+        retry_attempts += 1;
+    }
+
+    match &result {
+        Ok(keys) => {
+            jwt_key_cache.update_issuer_keys(issuer, keys.clone()).await;
+            refresh_state.set_refresh_failure(None).await;
+        }
+        Err(err) => {
+            log::error!(
+                "JWT key refresh for issuer {} failed: attempts={}, error={:?}",
+                issuer,
+                retry_attempts,
+                err
+            );
+
+            refresh_state
+                .set_refresh_failure(Some(Instant::now()))
+                .await;
+        }
+    }
+
+    // Always release the lock, after the cache is updated so waiting threads find the keys
+    refresh_state.refresh_lock_release_and_notify();
+
+    result
+}
+
 #[cfg(test)]
 mod wait_for_ongoing_refresh_tests {
     use crate::error::{Error, OAuthError};
@@ -682,6 +845,43 @@ mod trigger_background_jwt_refresh_test {
         // Assert background refresh was not triggered
         assert!(!refresh_triggered);
     }
+
+    /// Tests the background refresh after the client has been shut down
+    ///
+    /// # Test Setup
+    /// - Create a basic Client & mock HTTP server
+    /// - Shut down the client
+    ///
+    /// # Assertions
+    /// - Assert background refresh was not triggered
+    /// - Assert no background tasks are tracked
+    #[tokio::test]
+    async fn test_background_refresh_after_shutdown() {
+        // Setup a basic Client & mock HTTP server
+        let (esi_client, _) = setup().await;
+
+        // Shut down the client
+        esi_client.shutdown().await;
+
+        // Trigger background refresh
+        let refresh_triggered = esi_client
+            .oauth2()
+            .jwk()
+            .trigger_background_jwt_refresh()
+            .await;
+
+        // Assert background refresh was not triggered
+        assert!(!refresh_triggered);
+
+        // Assert no background tasks are tracked
+        let tasks = esi_client
+            .inner
+            .jwt_key_cache
+            .background_tasks
+            .lock()
+            .unwrap();
+        assert!(tasks.is_empty());
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,118 @@
+//! # EVE Online OAuth2 Server Metadata
+//!
+//! EVE Online's SSO publishes the metadata of its authorization server, such as the URLs of its
+//! authorization, token & JWK endpoints, at `/.well-known/oauth-authorization-server`. Provides
+//! [`OAuth2Endpoints::get_server_metadata`] to retrieve the metadata of the SSO the client is
+//! configured for.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let metadata = esi_client.oauth2().get_server_metadata().await?;
+//!
+//! println!("Token endpoint: {}", metadata.token_endpoint);
+//! # Ok(())
+//! # }
+//! ```
+
+use log::debug;
+
+use crate::constant::OAUTH_SERVER_METADATA_PATH;
+use crate::model::oauth2::AuthorizationServerMetadata;
+use crate::Error;
+
+use super::OAuth2Endpoints;
+
+impl<'a> OAuth2Endpoints<'a> {
+    /// Retrieves the metadata of the SSO's authorization server
+    ///
+    /// The metadata is requested from `/.well-known/oauth-authorization-server` at the origin of
+    /// the configured authorization URL, or of the JWK URL if OAuth2 has not been configured.
+    ///
+    /// For an overview & usage example, see the [module-level documentation](self)
+    ///
+    /// # Returns
+    /// - [`AuthorizationServerMetadata`]: The endpoints & features advertised by the SSO
+    ///
+    /// # Errors
+    /// - [`Error::UrlParseError`]: If the configured URL the metadata URL is derived from is invalid
+    /// - [`Error::ReqwestError`]: If the request fails, returns an error status or the body can't be parsed
+    pub async fn get_server_metadata(&self) -> Result<AuthorizationServerMetadata, Error> {
+        let url = self.server_metadata_url()?;
+
+        fetch_server_metadata(&self.client.inner.reqwest_client, &url).await
+    }
+
+    /// Returns the URL of the SSO's authorization server metadata
+    pub(crate) fn server_metadata_url(&self) -> Result<String, Error> {
+        let base_url = match &self.client.inner.oauth2_client {
+            Some(oauth2_client) => oauth2_client.auth_uri().as_str(),
+            None => self.client.inner.jwt_key_cache.jwk_url(),
+        };
+
+        server_metadata_url(base_url)
+    }
+}
+
+/// Returns the authorization server metadata URL at the origin of the provided SSO URL
+///
+/// # Arguments
+/// - `sso_url` (`&str`): Any URL of the SSO, e.g. its authorization URL
+pub(crate) fn server_metadata_url(sso_url: &str) -> Result<String, Error> {
+    let mut url = url::Url::parse(sso_url)?;
+
+    url.set_path(OAUTH_SERVER_METADATA_PATH);
+    url.set_query(None);
+    url.set_fragment(None);
+
+    Ok(url.to_string())
+}
+
+/// Fetches the authorization server metadata from the provided URL
+///
+/// # Arguments
+/// - `reqwest_client` (&[`reqwest::Client`]): HTTP client used to make the request
+/// - `url` (`&str`): The URL of the metadata, see [`server_metadata_url`]
+pub(crate) async fn fetch_server_metadata(
+    reqwest_client: &reqwest::Client,
+    url: &str,
+) -> Result<AuthorizationServerMetadata, Error> {
+    debug!("Fetching OAuth2 authorization server metadata: {}", url);
+
+    let metadata = reqwest_client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<AuthorizationServerMetadata>()
+        .await?;
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod server_metadata_url_tests {
+    use super::server_metadata_url;
+
+    /// Tests deriving the metadata URL from URLs of the SSO
+    ///
+    /// # Assertions
+    /// - Assert the path, query & fragment of the SSO URL are replaced
+    /// - Assert the port of a custom deployment is kept
+    #[test]
+    fn test_server_metadata_url() {
+        // Assert the path, query & fragment of the SSO URL are replaced
+        assert_eq!(
+            server_metadata_url("https://login.eveonline.com/v2/oauth/authorize?state=1#top")
+                .unwrap(),
+            "https://login.eveonline.com/.well-known/oauth-authorization-server"
+        );
+
+        // Assert the port of a custom deployment is kept
+        assert_eq!(
+            server_metadata_url("http://127.0.0.1:1234/oauth/jwks").unwrap(),
+            "http://127.0.0.1:1234/.well-known/oauth-authorization-server"
+        );
+    }
+}
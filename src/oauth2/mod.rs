@@ -12,8 +12,14 @@
 //! ## Modules
 //!
 //! - [`login`]: Methods to begin the OAuth2 login process
+//! - [`metadata`]: Retrieving the metadata of the SSO's authorization server
+//! - [`discovery`]: Using the endpoints advertised by the SSO's server metadata
+//! - [`diagnose`]: Checking the client's OAuth2 settings against the live SSO
 //! - [`token`]: Methods to retrieve, validate, & refresh OAuth2 tokens
 //! - [`jwk`]: Methods to handle JSON web keys used to validate authentication tokens
+//! - [`owner`]: Detecting characters transferred to another account by their owner hash
+//! - [`store`]: Storage for refreshed tokens shared between application instances
+//! - `axum`: Login redirect & callback extractor for Axum web applications, requires the `axum` feature
 //!
 //! ## Usage Examples
 //!
@@ -21,9 +27,19 @@
 //! - [Fetching an access token](crate::oauth2::token)
 //! - [Validating an access token](crate::oauth2::token)
 //! - [Refreshing an access token](crate::oauth2::token)
+//! - [Detecting a transferred character](crate::oauth2::owner)
+//! - [Diagnosing OAuth2 misconfigurations](crate::oauth2::diagnose)
+//! - [Discovering the SSO's endpoints](crate::oauth2::discovery)
 
+#[cfg(feature = "axum")]
+pub mod axum;
+pub mod diagnose;
+pub mod discovery;
 pub mod jwk;
 pub mod login;
+pub mod metadata;
+pub mod owner;
+pub mod store;
 pub mod token;
 
 pub(crate) mod client;
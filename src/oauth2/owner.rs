@@ -0,0 +1,118 @@
+//! # EVE Online Character Ownership
+//!
+//! Provides [`check_owner`] to detect when a character has been transferred to another EVE Online
+//! account since it last logged in to your application.
+//!
+//! The `owner` claim of a validated access token, see [`EveJwtClaims::owner`], is a hash which
+//! changes when a character is transferred. Store the owner hash alongside the character when it
+//! logs in & compare it against the claims of each new or refreshed token. When it changes, the
+//! previous owner's sessions & refresh tokens should no longer grant access to the character.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::oauth2::owner::check_owner;
+//!
+//! # async fn example(
+//! #     esi_client: eve_esi::Client,
+//! #     access_token: String,
+//! #     stored_owner_hash: String,
+//! # ) -> Result<(), eve_esi::Error> {
+//! let claims = esi_client.oauth2().validate_token(access_token).await?;
+//!
+//! if let Err(changed) = check_owner(&stored_owner_hash, &claims) {
+//!     // Invalidate the sessions & stored tokens of the character's previous owner...
+//!     println!("Character {} was transferred", changed.character_id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::model::oauth2::EveJwtClaims;
+
+/// A character's owner hash differs from the stored owner hash, the character was transferred
+///
+/// Returned by [`check_owner`]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Character {character_id} has been transferred to another account")]
+pub struct OwnerChanged {
+    /// The ID of the character which was transferred, `0` if the `sub` claim could not be parsed
+    pub character_id: i64,
+    /// The owner hash stored for the character's previous owner
+    pub previous_owner_hash: String,
+    /// The owner hash of the character's current owner
+    pub owner_hash: String,
+}
+
+/// Compares a stored owner hash against the owner hash of freshly validated token claims
+///
+/// See the [module documentation](self) for how to use this within the authentication flow.
+///
+/// # Arguments
+/// - `stored_owner_hash` (`&str`): The owner hash stored when the character previously logged in
+/// - `claims` (&[`EveJwtClaims`]): The claims returned by
+///   [`OAuth2Endpoints::validate_token`](crate::oauth2::OAuth2Endpoints::validate_token)
+///
+/// # Returns
+/// - `Ok(())`: The character is owned by the same account
+/// - `Err(`[`OwnerChanged`]`)`: The character has been transferred to another account
+pub fn check_owner(stored_owner_hash: &str, claims: &EveJwtClaims) -> Result<(), OwnerChanged> {
+    if claims.owner == stored_owner_hash {
+        return Ok(());
+    }
+
+    let character_id = claims.character_id().unwrap_or(0);
+
+    log::warn!(
+        "Owner hash of character ID {} has changed, the character was transferred",
+        character_id
+    );
+
+    Err(OwnerChanged {
+        character_id,
+        previous_owner_hash: stored_owner_hash.to_string(),
+        owner_hash: claims.owner.clone(),
+    })
+}
+
+#[cfg(test)]
+mod owner_tests {
+    use super::*;
+
+    /// Tests a transferred character is detected by its owner hash
+    ///
+    /// # Test Setup
+    /// - Create claims for a character with an owner hash
+    ///
+    /// # Assertions
+    /// - Assert the matching owner hash returns `Ok`
+    /// - Assert a different owner hash returns the previous & current owner hashes
+    #[test]
+    fn test_check_owner() {
+        let claims: EveJwtClaims = serde_json::from_value(serde_json::json!({
+            "iss": "https://login.eveonline.com",
+            "sub": "CHARACTER:EVE:2114794365",
+            "aud": ["client_id", "EVE Online"],
+            "jti": "jti",
+            "kid": "JWT-Signature-Key",
+            "tenant": "tranquility",
+            "region": "world",
+            "exp": 1735693200,
+            "iat": 1735692000,
+            "name": "Hyziri",
+            "owner": "current_owner_hash",
+            "azp": "client_id"
+        }))
+        .unwrap();
+
+        assert_eq!(check_owner("current_owner_hash", &claims), Ok(()));
+        assert_eq!(
+            check_owner("previous_owner_hash", &claims),
+            Err(OwnerChanged {
+                character_id: 2114794365,
+                previous_owner_hash: "previous_owner_hash".to_string(),
+                owner_hash: "current_owner_hash".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! # EVE Online OAuth2 Token Storage
+//!
+//! Provides the [`TokenStore`] trait used to persist refreshed tokens keyed by character ID, allowing
+//! applications to share the rotated refresh token returned by
+//! [`OAuth2Endpoints::get_token_refresh`](crate::oauth2::OAuth2Endpoints::get_token_refresh)
+//! rather than each instance refreshing with a refresh token which has since been replaced.
+//!
+//! Two implementations are provided:
+//! - [`MemoryTokenStore`]: Stores tokens in memory for the lifetime of the process
+//! - `RedisTokenStore`: Stores tokens in Redis, allowing horizontally scaled applications to share
+//!   refreshed tokens, requires the `redis` feature
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! use eve_esi::oauth2::store::{MemoryTokenStore, StoredToken, TokenStore};
+//!
+//! # async fn example(
+//! #     esi_client: eve_esi::Client,
+//! #     character_id: i64,
+//! #     refresh_token: String,
+//! # ) -> Result<(), eve_esi::Error> {
+//! let store = MemoryTokenStore::new();
+//!
+//! let refresh_token = match store.get(character_id).await? {
+//!     Some(token) if !token.is_expired(chrono::Duration::seconds(60)) => return Ok(()),
+//!     Some(token) => token.refresh_token,
+//!     None => refresh_token,
+//! };
+//!
+//! let token = esi_client
+//!     .oauth2()
+//!     .get_token_refresh(refresh_token, None)
+//!     .await?;
+//!
+//! if let Some(token) = StoredToken::from_token_response(&token) {
+//!     store.put(character_id, token).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::esi::AccessToken;
+use crate::model::oauth2::TokenResponse;
+use crate::Error;
+
+/// An access token & the refresh token used to renew it
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredToken {
+    /// The access token used for authenticated ESI requests
+    pub access_token: AccessToken,
+    /// The refresh token used to retrieve a new access token once it expires
+    pub refresh_token: String,
+    /// The date the access token expires
+    pub expires_at: DateTime<Utc>,
+}
+
+impl StoredToken {
+    /// Creates a [`StoredToken`] from a refreshed token
+    ///
+    /// # Arguments
+    /// - `token` (`&TokenResponse`): The token returned from
+    ///   [`OAuth2Endpoints::get_token_refresh`](crate::oauth2::OAuth2Endpoints::get_token_refresh)
+    ///
+    /// # Returns
+    /// - `Some(StoredToken)`: The token with its expiration calculated from the current time
+    /// - `None`: The token has no refresh token
+    pub fn from_token_response(token: &TokenResponse) -> Option<Self> {
+        let refresh_token = token.refresh_token.as_ref()?.secret().clone();
+        let expires_in = token
+            .expires_in
+            .and_then(|expires_in| Duration::from_std(expires_in).ok())
+            .unwrap_or_default();
+
+        Some(Self {
+            access_token: token.access_token.clone(),
+            refresh_token,
+            expires_at: Utc::now() + expires_in,
+        })
+    }
+
+    /// Returns `true` if the access token has expired or expires within the provided duration
+    ///
+    /// # Arguments
+    /// - `within` (`Duration`): Margin before expiration at which the token is considered expired,
+    ///   allowing the token to be refreshed before requests using it begin failing
+    pub fn is_expired(&self, within: Duration) -> bool {
+        self.expires_at - within <= Utc::now()
+    }
+}
+
+/// Storage for refreshed tokens keyed by character ID
+///
+/// Implement this trait to store tokens in a custom backend, see the
+/// [module documentation](self) for the provided implementations.
+pub trait TokenStore: Send + Sync {
+    /// Retrieves the stored token for the provided character
+    ///
+    /// # Arguments
+    /// - `character_id` (`i64`): The ID of the character the token belongs to
+    fn get(
+        &self,
+        character_id: i64,
+    ) -> impl Future<Output = Result<Option<StoredToken>, Error>> + Send;
+
+    /// Stores the token for the provided character, replacing any existing token
+    ///
+    /// # Arguments
+    /// - `character_id` (`i64`): The ID of the character the token belongs to
+    /// - `token` (`StoredToken`): The token to store
+    fn put(
+        &self,
+        character_id: i64,
+        token: StoredToken,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+
+    /// Removes the stored token for the provided character, e.g. after the refresh token was revoked
+    ///
+    /// # Arguments
+    /// - `character_id` (`i64`): The ID of the character the token belongs to
+    fn remove(&self, character_id: i64) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+/// In-memory [`TokenStore`] which retains tokens for the lifetime of the process
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    tokens: RwLock<HashMap<i64, StoredToken>>,
+}
+
+impl MemoryTokenStore {
+    /// Creates a new empty [`MemoryTokenStore`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    async fn get(&self, character_id: i64) -> Result<Option<StoredToken>, Error> {
+        Ok(self
+            .tokens
+            .read()
+            .ok()
+            .and_then(|tokens| tokens.get(&character_id).cloned()))
+    }
+
+    async fn put(&self, character_id: i64, token: StoredToken) -> Result<(), Error> {
+        if let Ok(mut tokens) = self.tokens.write() {
+            tokens.insert(character_id, token);
+        }
+
+        Ok(())
+    }
+
+    async fn remove(&self, character_id: i64) -> Result<(), Error> {
+        if let Ok(mut tokens) = self.tokens.write() {
+            tokens.remove(&character_id);
+        }
+
+        Ok(())
+    }
+}
+
+/// Redis [`TokenStore`] allowing refreshed tokens to be shared between application instances
+///
+/// Tokens are stored as JSON keyed by character ID with a configurable key prefix, defaulting to
+/// `eve_esi:token:`. Tokens are stored unencrypted, restrict access to the Redis server accordingly.
+#[cfg(feature = "redis")]
+#[derive(Clone)]
+pub struct RedisTokenStore {
+    connection: redis::aio::MultiplexedConnection,
+    prefix: String,
+}
+
+/// JSON representation of a [`StoredToken`] within Redis
+#[cfg(feature = "redis")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RedisStoredToken {
+    access_token: String,
+    refresh_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+#[cfg(feature = "redis")]
+impl RedisTokenStore {
+    /// Creates a new [`RedisTokenStore`] using the provided connection
+    ///
+    /// # Arguments
+    /// - `connection` (`MultiplexedConnection`): Connection to the Redis server, cloned for each operation
+    pub fn new(connection: redis::aio::MultiplexedConnection) -> Self {
+        Self {
+            connection,
+            prefix: "eve_esi:token:".to_string(),
+        }
+    }
+
+    /// Sets the prefix prepended to the character ID to create the Redis key of a token
+    ///
+    /// # Arguments
+    /// - `prefix` (`impl Into<String>`): The key prefix, e.g. `myapp:token:`
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+}
+
+#[cfg(feature = "redis")]
+impl TokenStore for RedisTokenStore {
+    async fn get(&self, character_id: i64) -> Result<Option<StoredToken>, Error> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let contents: Option<String> = connection
+            .get(format!("{}{}", self.prefix, character_id))
+            .await?;
+
+        let Some(contents) = contents else {
+            return Ok(None);
+        };
+
+        let token: RedisStoredToken = serde_json::from_str(&contents)?;
+
+        Ok(Some(StoredToken {
+            access_token: AccessToken::new(token.access_token),
+            refresh_token: token.refresh_token,
+            expires_at: token.expires_at,
+        }))
+    }
+
+    async fn put(&self, character_id: i64, token: StoredToken) -> Result<(), Error> {
+        use redis::AsyncCommands;
+
+        let contents = serde_json::to_string(&RedisStoredToken {
+            access_token: token.access_token.secret().to_string(),
+            refresh_token: token.refresh_token,
+            expires_at: token.expires_at,
+        })?;
+
+        let mut connection = self.connection.clone();
+        let _: () = connection
+            .set(format!("{}{}", self.prefix, character_id), contents)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove(&self, character_id: i64) -> Result<(), Error> {
+        use redis::AsyncCommands;
+
+        let mut connection = self.connection.clone();
+        let _: () = connection
+            .del(format!("{}{}", self.prefix, character_id))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod token_store_tests {
+    use std::time::Duration as StdDuration;
+
+    use oauth2::RefreshToken;
+
+    use super::*;
+
+    /// Creates a token response expiring in the provided number of seconds
+    fn mock_token_response(expires_in: u64, refresh_token: Option<&str>) -> TokenResponse {
+        TokenResponse {
+            access_token: AccessToken::new("access_token"),
+            refresh_token: refresh_token.map(|token| RefreshToken::new(token.to_string())),
+            expires_in: Some(StdDuration::from_secs(expires_in)),
+            scopes: Vec::new(),
+            missing_scopes: Vec::new(),
+        }
+    }
+
+    /// Tests converting a refreshed token into a stored token
+    ///
+    /// # Test Setup
+    /// - Create token responses with & without a refresh token
+    ///
+    /// # Assertions
+    /// - Assert the refresh token is retained
+    /// - Assert the expiration is calculated from the current time
+    /// - Assert a token without a refresh token cannot be stored
+    #[test]
+    fn test_stored_token_from_token_response() {
+        let token =
+            StoredToken::from_token_response(&mock_token_response(1199, Some("refresh"))).unwrap();
+
+        assert_eq!(token.refresh_token, "refresh");
+        assert!(!token.is_expired(Duration::seconds(60)));
+        assert!(token.is_expired(Duration::seconds(1200)));
+        assert!(StoredToken::from_token_response(&mock_token_response(1199, None)).is_none());
+    }
+
+    /// Tests storing, retrieving & removing tokens from the in-memory store
+    ///
+    /// # Assertions
+    /// - Assert the stored token is returned for its character
+    /// - Assert no token is returned after it is removed
+    #[tokio::test]
+    async fn test_memory_token_store() {
+        let store = MemoryTokenStore::new();
+        let token =
+            StoredToken::from_token_response(&mock_token_response(1199, Some("refresh"))).unwrap();
+
+        store.put(2114794365, token.clone()).await.unwrap();
+        assert_eq!(store.get(2114794365).await.unwrap(), Some(token));
+        assert_eq!(store.get(1).await.unwrap(), None);
+
+        store.remove(2114794365).await.unwrap();
+        assert_eq!(store.get(2114794365).await.unwrap(), None);
+    }
+}
@@ -7,6 +7,7 @@
 //! ## Methods
 //! - [`OAuth2Endpoints::get_token`]: Retrieves a token from EVE Online's OAuth2 API
 //! - [`OAuth2Endpoints::get_token_refresh`]: Retrieves a new token using a refresh token
+//! - [`OAuth2Endpoints::request_token`]: Retrieves a token using any supported [`TokenGrant`]
 //! - [`OAuth2Endpoints::validate_token`]: Validates token retrieved via the [`OAuth2Endpoints::get_token`] method
 //!
 //! ## ESI Documentation
@@ -55,12 +56,23 @@
 //!     // Use helper function to get character ID from the claims
 //!     let character_id = claims.character_id()?;
 //!
+//!     // Compare against the owner hash stored at the character's previous login, if any
+//!     # let stored_owner_hash = claims.owner.clone();
+//!     if eve_esi::oauth2::owner::check_owner(&stored_owner_hash, &claims).is_err() {
+//!         // Character was transferred, invalidate the previous owner's sessions...
+//!     }
+//!
 //!     // Refresh the token
 //!     let new_token = esi_client
 //!         .oauth2()
-//!         .get_token_refresh(refresh_token.secret().to_string())
+//!         .get_token_refresh(refresh_token.secret().to_string(), Some(claims.scp.clone()))
 //!         .await?;
 //!
+//!     // Scopes may have been revoked by the character since login
+//!     if !new_token.missing_scopes.is_empty() {
+//!         // Prompt the character to login again...
+//!     }
+//!
 //!     Ok(())
 //! }
 //! ```
@@ -70,7 +82,7 @@ use oauth2::basic::BasicTokenType;
 use oauth2::{AuthorizationCode, EmptyExtraTokenFields, RefreshToken, StandardTokenResponse};
 
 use crate::error::{Error, OAuthError};
-use crate::model::oauth2::{EveJwtClaims, EveJwtKey};
+use crate::model::oauth2::{EveJwtClaims, EveJwtKey, TokenGrant, TokenResponse};
 use crate::oauth2::client::OAuth2Client;
 use crate::oauth2::OAuth2Endpoints;
 use crate::Client;
@@ -108,29 +120,8 @@ impl<'a> OAuth2Endpoints<'a> {
         &self,
         code: &str,
     ) -> Result<StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType>, Error> {
-        let oauth_client = get_oauth_client(self.client)?;
-
-        // Attempt to fetch token
-        let message = "Attempting to fetch JWT token using provided authorization code";
-        log::debug!("{}", message);
-
-        match oauth_client
-            .exchange_code(AuthorizationCode::new(code.to_string()))
-            .request_async(&self.client.inner.reqwest_client)
+        self.exchange_grant(&TokenGrant::authorization_code(code))
             .await
-        {
-            Ok(token) => {
-                log::debug!("{}", "JWT Token fetched successfully");
-
-                Ok(token)
-            }
-            Err(err) => {
-                let message = format!("Error fetching token: {:#?}", err);
-                log::error!("{}", message);
-
-                Err(Error::OAuthError(OAuthError::RequestTokenError(err)))
-            }
-        }
     }
 
     /// Retrieves a new token using a refresh token
@@ -152,6 +143,13 @@ impl<'a> OAuth2Endpoints<'a> {
     ///   [`Self::get_token`] method. You can get the refresh token from the token with the
     ///   `token.refresh_token()` method if you haven't yet converted it to a string for database
     ///   storage.
+    /// - `requested_scopes` (`Option<Vec<String>>`): The scopes expected to be granted to the refreshed
+    ///   token, a warning is logged & the scopes are listed in [`TokenResponse::missing_scopes`] if any
+    ///   were not granted.
+    ///
+    /// # Returns
+    /// A [`TokenResponse`] containing the new access token, the rotated refresh token which should replace
+    /// your stored refresh token, the token's expiration, & the granted scopes.
     ///
     /// # Errors
     /// - [`Error`]: If OAuth2 is not configured for the ESI client, the provided refresh_token
@@ -159,28 +157,102 @@ impl<'a> OAuth2Endpoints<'a> {
     pub async fn get_token_refresh(
         &self,
         refresh_token: String,
+        requested_scopes: Option<Vec<String>>,
+    ) -> Result<TokenResponse, Error> {
+        self.request_token(TokenGrant::refresh_token(refresh_token, requested_scopes))
+            .await
+    }
+
+    /// Requests a token from EVE Online's OAuth2 API using the provided grant
+    ///
+    /// [`Self::get_token`] & [`Self::get_token_refresh`] are shorthands for the
+    /// [`TokenGrant::AuthorizationCode`] & [`TokenGrant::RefreshToken`] grants, this method allows
+    /// applications to handle each grant type through a single call, e.g. when a background task
+    /// processes both logins & refreshes.
+    ///
+    /// For an overview & usage, see the [module-level documentation](super)
+    ///
+    /// # Documentation
+    /// See <https://developers.eveonline.com/docs/services/sso/>
+    ///
+    /// # Arguments
+    /// - `grant` ([`TokenGrant`]): The grant used to request the token
+    ///
+    /// # Returns
+    /// A [`TokenResponse`] containing the access token, the refresh token which should replace
+    /// any stored refresh token, the token's expiration, & the granted scopes. If the grant has
+    /// requested scopes, any which were not granted are listed in [`TokenResponse::missing_scopes`].
+    ///
+    /// # Errors
+    /// - [`Error`]: If OAuth2 is not configured for the ESI client, the grant is invalid, or there
+    ///   is an issue fetching the JWT token from EVE Online's OAuth2 API.
+    pub async fn request_token(&self, grant: TokenGrant) -> Result<TokenResponse, Error> {
+        let token = self.exchange_grant(&grant).await?;
+        let token = TokenResponse::from_token(token, grant.requested_scopes());
+
+        if !token.missing_scopes.is_empty() {
+            log::warn!(
+                "Token was not granted requested scopes, they may have been revoked: {:?}",
+                token.missing_scopes
+            );
+        }
+
+        Ok(token)
+    }
+
+    /// Exchanges a grant for a token with EVE Online's OAuth2 API
+    ///
+    /// # Arguments
+    /// - `grant` (&[`TokenGrant`]): The grant used to request the token
+    ///
+    /// # Errors
+    /// - [`Error`]: If OAuth2 is not configured for the ESI client or the token request fails
+    async fn exchange_grant(
+        &self,
+        grant: &TokenGrant,
     ) -> Result<StandardTokenResponse<EmptyExtraTokenFields, BasicTokenType>, Error> {
-        let oauth_client = get_oauth_client(self.client)?;
+        self.client.discover_oauth2_endpoints().await;
 
-        // Convert refresh_token string to RefreshToken
-        let refresh_token = RefreshToken::new(refresh_token);
+        let oauth_client = get_oauth_client(self.client)?;
+        let http_client = &self.client.inner.reqwest_client;
 
-        // Attempt to refresh token
-        let message = "Attempting to refresh JWT token using provided refresh token";
-        log::debug!("{}", message);
+        // Attempt to fetch token
+        log::debug!(
+            "Attempting to fetch JWT token using {} grant",
+            grant.grant_type()
+        );
+
+        let result = match grant {
+            TokenGrant::AuthorizationCode { code } => {
+                oauth_client
+                    .exchange_code(AuthorizationCode::new(code.clone()))
+                    .request_async(http_client)
+                    .await
+            }
+            TokenGrant::RefreshToken { refresh_token, .. } => {
+                oauth_client
+                    .exchange_refresh_token(&RefreshToken::new(refresh_token.clone()))
+                    .request_async(http_client)
+                    .await
+            }
+        };
 
-        match oauth_client
-            .exchange_refresh_token(&refresh_token)
-            .request_async(&self.client.inner.reqwest_client)
-            .await
-        {
+        match result {
             Ok(token) => {
-                log::debug!("{}", "JWT Token refreshed successfully");
+                log::debug!(
+                    "JWT Token fetched successfully using {} grant",
+                    grant.grant_type()
+                );
 
                 Ok(token)
             }
             Err(err) => {
-                let message = format!("Error refreshing JWT token token: {:#?}", err);
+                // Display is used as the debug output of a parse error contains the raw token response body
+                let message = format!(
+                    "Error fetching token using {} grant: {}",
+                    grant.grant_type(),
+                    err
+                );
                 log::error!("{}", message);
 
                 Err(Error::OAuthError(OAuthError::RequestTokenError(err)))
@@ -219,7 +291,13 @@ impl<'a> OAuth2Endpoints<'a> {
             Ok(claims) => Ok(claims),
             Err(err) => {
                 // Clear the cache to trigger a JWT key refresh on next attempt
-                let cache_cleared = self.client.inner.jwt_key_cache.clear_cache().await;
+                let jwt_key_cache = &self.client.inner.jwt_key_cache;
+                let cache_cleared = match peek_token_issuer(&token_secret) {
+                    Some(issuer) if jwt_key_cache.has_issuer_jwk_url(&issuer) => {
+                        jwt_key_cache.clear_issuer_cache(&issuer).await
+                    }
+                    _ => jwt_key_cache.clear_cache().await,
+                };
 
                 // Second attempt (retry) if cache was successfully cleared
                 if cache_cleared {
@@ -257,10 +335,19 @@ impl<'a> OAuth2Endpoints<'a> {
 /// - [`Error`]: If there is an issue retrieving JWT keys from ESI Client's cache or there is an
 ///   issue validating the token.
 async fn attempt_validation(client: &Client, token_secret: &str) -> Result<EveJwtClaims, Error> {
-    // Get JWT keys to validate token
+    // Get JWT keys for the token's issuer to validate token
     log::trace!("Retrieving keys for validation from JWT key cache");
 
-    let jwt_keys = client.oauth2().jwk().get_jwt_keys().await?;
+    let jwt_keys = match peek_token_issuer(token_secret) {
+        Some(issuer) => {
+            client
+                .oauth2()
+                .jwk()
+                .get_jwt_keys_for_issuer(&issuer)
+                .await?
+        }
+        None => client.oauth2().jwk().get_jwt_keys().await?,
+    };
 
     // Configure validation
     let mut validation = Validation::new(jsonwebtoken::Algorithm::RS256);
@@ -323,13 +410,32 @@ async fn attempt_validation(client: &Client, token_secret: &str) -> Result<EveJw
     }
 }
 
+/// Reads the `iss` claim of a token without validating it
+///
+/// Used solely to select which issuer's key set the token will be validated with, the
+/// issuer is still validated against the configured issuers during validation.
+///
+/// # Returns
+/// - `Some(String)`: The token's issuer
+/// - `None`: If the token could not be decoded or has no `iss` claim
+fn peek_token_issuer(token_secret: &str) -> Option<String> {
+    #[derive(serde::Deserialize)]
+    struct IssuerClaim {
+        iss: String,
+    }
+
+    jsonwebtoken::dangerous::insecure_decode::<IssuerClaim>(token_secret)
+        .ok()
+        .map(|token_data| token_data.claims.iss)
+}
+
 /// Utility function to retrieve OAuth2 client or return an error
 fn get_oauth_client(client: &Client) -> Result<&OAuth2Client, Error> {
     // Attempt to retrieve OAuth2 client from ESI client
     log::trace!("Attempting to retrieve OAuth2 client from ESI client");
 
-    match client.inner.oauth2_client {
-        Some(ref client) => {
+    match client.oauth2_client() {
+        Some(client) => {
             log::trace!("{}", "Found OAuth2 client on ESI client");
 
             Ok(client)
@@ -3,11 +3,13 @@
 //! This module provides a type-safe way to define and manage EVE Online ESI OAuth2 scopes
 //! using the [`ScopeBuilder`].
 
+use std::ops::Sub;
+
 use crate::scope::{
     AlliancesScopes, AssetsScopes, CalendarScopes, CharactersScopes, ClonesScopes, ContractsScopes,
     CorporationsScopes, FittingsScopes, FleetsScopes, IndustryScopes, KillmailsScopes,
-    LocationScopes, MailScopes, MarketsScopes, PlanetsScopes, SearchScopes, SkillsScopes, UiScopes,
-    UniverseScopes, WalletScopes,
+    LocationScopes, MailScopes, MarketsScopes, PlanetsScopes, Scope, SearchScopes, SkillsScopes,
+    UiScopes, UniverseScopes, WalletScopes,
 };
 
 /// `publicData` scope
@@ -33,8 +35,11 @@ impl ScopeBuilder {
         ScopeBuilder { scopes: Vec::new() }
     }
 
-    /// Builds a [`ScopeBuilder`] into a `Vec<`[`String`]`>` containing all scopes
-    pub fn all() -> Vec<String> {
+    /// Creates a new [`ScopeBuilder`] instance with all scopes applied
+    ///
+    /// Intended for development tooling requiring a full-scope login, scopes which aren't needed can be
+    /// removed with [`Self::without`] or by subtracting another [`ScopeBuilder`].
+    pub fn all_scopes() -> Self {
         ScopeBuilder::new()
             .public_data()
             .alliances(AlliancesScopes::all())
@@ -57,7 +62,11 @@ impl ScopeBuilder {
             .ui(UiScopes::all())
             .universe(UniverseScopes::all())
             .wallet(WalletScopes::all())
-            .build()
+    }
+
+    /// Builds a [`ScopeBuilder`] into a `Vec<`[`String`]`>` containing all scopes
+    pub fn all() -> Vec<String> {
+        ScopeBuilder::all_scopes().build()
     }
 
     /// Builds a [`ScopeBuilder`] into a `Vec<`[`String`]`>` containing the configured scopes
@@ -65,6 +74,18 @@ impl ScopeBuilder {
         self.scopes
     }
 
+    /// Adds the provided [`Scope`]
+    pub fn scope(mut self, scope: Scope) -> Self {
+        self.scopes.push(scope.to_string());
+        self
+    }
+
+    /// Removes the provided [`Scope`] if it has been added
+    pub fn without(mut self, scope: Scope) -> Self {
+        self.scopes.retain(|existing| existing != scope.as_str());
+        self
+    }
+
     /// Adds a custom scope
     pub fn custom(mut self, scope: &str) -> Self {
         self.scopes.push(scope.to_string());
@@ -200,6 +221,24 @@ impl ScopeBuilder {
     }
 }
 
+impl Sub for ScopeBuilder {
+    type Output = ScopeBuilder;
+
+    /// Removes all scopes added to the right-hand [`ScopeBuilder`]
+    ///
+    /// ```rust
+    /// use eve_esi::ScopeBuilder;
+    /// use eve_esi::scope::WalletScopes;
+    ///
+    /// // Request every scope except wallet scopes
+    /// let scopes = (ScopeBuilder::all_scopes() - ScopeBuilder::new().wallet(WalletScopes::all())).build();
+    /// ```
+    fn sub(mut self, rhs: ScopeBuilder) -> Self::Output {
+        self.scopes.retain(|scope| !rhs.scopes.contains(scope));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,4 +266,35 @@ mod tests {
 
         assert_eq!(scopes[0], "custom_scope");
     }
+
+    /// Tests removing a single scope from all scopes
+    #[test]
+    fn test_scope_builder_without() {
+        let scopes = ScopeBuilder::all_scopes()
+            .without(Scope::PublicData)
+            .build();
+
+        assert_eq!(scopes.len(), ScopeBuilder::all().len() - 1);
+        assert!(!scopes.contains(&"publicData".to_string()));
+    }
+
+    /// Tests subtracting a category of scopes from all scopes
+    #[test]
+    fn test_scope_builder_sub() {
+        let scopes =
+            (ScopeBuilder::all_scopes() - ScopeBuilder::new().wallet(WalletScopes::all())).build();
+
+        assert_eq!(scopes.len(), ScopeBuilder::all().len() - 2);
+        assert!(!scopes.iter().any(|scope| scope.starts_with("esi-wallet")));
+    }
+
+    /// Tests adding a scope using the [`Scope`] enum
+    #[test]
+    fn test_scope_builder_scope() {
+        let scopes = ScopeBuilder::new()
+            .scope(Scope::CharactersReadContacts)
+            .build();
+
+        assert_eq!(scopes, vec!["esi-characters.read_contacts.v1"]);
+    }
 }
@@ -26,6 +26,8 @@ pub const READ_TITLES: &str = "esi-corporations.read_titles.v1";
 pub const TRACK_MEMBERS: &str = "esi-corporations.track_members.v1";
 /// Read access to corporation's contacts
 pub const READ_CONTACTS: &str = "esi-corporations.read_contacts.v1";
+/// Read access to corporation's projects & their contributions
+pub const READ_PROJECTS: &str = "esi-corporations.read_projects.v1";
 
 /// Struct with methods for listing corporation scopes to request for OAuth2
 pub struct CorporationsScopes {
@@ -60,6 +62,7 @@ impl CorporationsScopes {
             .read_starbases()
             .read_structures()
             .read_contacts()
+            .read_projects()
     }
 
     /// Access to retrieve information on corporation's blueprints
@@ -157,6 +160,14 @@ impl CorporationsScopes {
         self.scopes.push(READ_CONTACTS.to_string());
         self
     }
+
+    /// Read access to corporation's projects & their contributions
+    ///
+    /// Adds the `esi-corporations.read_projects.v1` scope
+    pub fn read_projects(mut self) -> Self {
+        self.scopes.push(READ_PROJECTS.to_string());
+        self
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,583 @@
+//! # EVE ESI Scope Enum
+//!
+//! This module provides the [`Scope`] enum covering every scope supported by the [`super::ScopeBuilder`],
+//! allowing scopes to be parsed from & converted to their canonical strings.
+//!
+//! ## Usage Example
+//!
+//! ```rust
+//! use eve_esi::scope::{characters, Scope};
+//!
+//! // Parse a scope from a string, for example from a config file
+//! let scope: Scope = "esi-characters.read_contacts.v1".parse().unwrap();
+//!
+//! assert_eq!(scope, Scope::CharactersReadContacts);
+//! assert_eq!(scope.as_str(), characters::READ_CONTACTS);
+//! assert_eq!(scope.to_string(), "esi-characters.read_contacts.v1");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::scope::{
+    alliances, assets, builder, calendar, characters, clones, contracts, corporations, fittings,
+    fleets, industry, killmails, location, mail, markets, planets, search, skills, ui, universe,
+    wallet,
+};
+
+/// Error returned when parsing a string which is not a known ESI scope into a [`Scope`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown ESI scope: {0}")]
+pub struct ParseScopeError(pub String);
+
+/// An EVE Online ESI OAuth2 scope
+///
+/// Converts to & from the canonical scope string with [`Display`](fmt::Display) & [`FromStr`],
+/// serializing as the scope string.
+///
+/// For a full overview & examples, see the [module-level documentation](self).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Scope {
+    /// Access to retrieve public information on a character
+    ///
+    /// `publicData`
+    #[serde(rename = "publicData")]
+    PublicData,
+    /// Read access to alliance contact information
+    ///
+    /// `esi-alliances.read_contacts.v1`
+    #[serde(rename = "esi-alliances.read_contacts.v1")]
+    AlliancesReadContacts,
+    /// Read access to character's assets
+    ///
+    /// `esi-assets.read_assets.v1`
+    #[serde(rename = "esi-assets.read_assets.v1")]
+    AssetsReadAssets,
+    /// Read access to corporation's assets
+    ///
+    /// `esi-assets.read_corporation_assets.v1`
+    #[serde(rename = "esi-assets.read_corporation_assets.v1")]
+    AssetsReadCorporationAssets,
+    /// Read access to calendar events
+    ///
+    /// `esi-calendar.read_calendar_events.v1`
+    #[serde(rename = "esi-calendar.read_calendar_events.v1")]
+    CalendarReadCalendarEvents,
+    /// Access to respond to calendar events on behalf of the character
+    ///
+    /// `esi-calendar.respond_calendar_events.v1`
+    #[serde(rename = "esi-calendar.respond_calendar_events.v1")]
+    CalendarRespondCalendarEvents,
+    /// Access to retrieve information on character's research agents
+    ///
+    /// `esi-characters.read_agents_research.v1`
+    #[serde(rename = "esi-characters.read_agents_research.v1")]
+    CharactersReadAgentsResearch,
+    /// Access to retrieve information on character's blueprints
+    ///
+    /// `esi-characters.read_blueprints.v1`
+    #[serde(rename = "esi-characters.read_blueprints.v1")]
+    CharactersReadBlueprints,
+    /// Access to read chat channels character is in (does not include channel messages)
+    ///
+    /// `esi-characters.read_chat_channels.v1`
+    #[serde(rename = "esi-characters.read_chat_channels.v1")]
+    CharactersReadChatChannels,
+    /// Access to read a character's contacts
+    ///
+    /// `esi-characters.read_contacts.v1`
+    #[serde(rename = "esi-characters.read_contacts.v1")]
+    CharactersReadContacts,
+    /// Access to read the character's corporation roles
+    ///
+    /// `esi-characters.read_corporation_roles.v1`
+    #[serde(rename = "esi-characters.read_corporation_roles.v1")]
+    CharactersReadCorporationRoles,
+    /// Access to retrieve information on character's jump fatigue status
+    ///
+    /// `esi-characters.read_fatigue.v1`
+    #[serde(rename = "esi-characters.read_fatigue.v1")]
+    CharactersReadFatigue,
+    /// Access to retrieve character's faction warfare stats
+    ///
+    /// `esi-characters.read_fw_stats.v1`
+    #[serde(rename = "esi-characters.read_fw_stats.v1")]
+    CharactersReadFwStats,
+    /// Access to retrieve character's loyalty point information
+    ///
+    /// `esi-characters.read_loyalty.v1`
+    #[serde(rename = "esi-characters.read_loyalty.v1")]
+    CharactersReadLoyalty,
+    /// Access to retrieve information on character's medals
+    ///
+    /// `esi-characters.read_medals.v1`
+    #[serde(rename = "esi-characters.read_medals.v1")]
+    CharactersReadMedals,
+    /// Access to retrieve the character's notifications
+    ///
+    /// `esi-characters.read_notifications.v1`
+    #[serde(rename = "esi-characters.read_notifications.v1")]
+    CharactersReadNotifications,
+    /// Access to read the character's standings
+    ///
+    /// `esi-characters.read_standings.v1`
+    #[serde(rename = "esi-characters.read_standings.v1")]
+    CharactersReadStandings,
+    /// Access to read the character's corporation titles
+    ///
+    /// `esi-characters.read_titles.v1`
+    #[serde(rename = "esi-characters.read_titles.v1")]
+    CharactersReadTitles,
+    /// Access to add/modify character contacts
+    ///
+    /// `esi-characters.write_contacts.v1`
+    #[serde(rename = "esi-characters.write_contacts.v1")]
+    CharactersWriteContacts,
+    /// Access to read information on character's clones
+    ///
+    /// `esi-clones.read_clones.v1`
+    #[serde(rename = "esi-clones.read_clones.v1")]
+    ClonesReadClones,
+    /// Access to read character's implants
+    ///
+    /// `esi-clones.read_implants.v1`
+    #[serde(rename = "esi-clones.read_implants.v1")]
+    ClonesReadImplants,
+    /// Read access to character contracts
+    ///
+    /// `esi-contracts.read_character_contracts.v1`
+    #[serde(rename = "esi-contracts.read_character_contracts.v1")]
+    ContractsReadCharacterContracts,
+    /// Read access to corporation contracts
+    ///
+    /// `esi-contracts.read_corporation_contracts.v1`
+    #[serde(rename = "esi-contracts.read_corporation_contracts.v1")]
+    ContractsReadCorporationContracts,
+    /// Access to retrieve information on corporation's blueprints
+    ///
+    /// `esi-corporations.read_blueprints.v1`
+    #[serde(rename = "esi-corporations.read_blueprints.v1")]
+    CorporationsReadBlueprints,
+    /// Access to read information on corporation container logs
+    ///
+    /// `esi-corporations.read_container_logs.v1`
+    #[serde(rename = "esi-corporations.read_container_logs.v1")]
+    CorporationsReadContainerLogs,
+    /// Access to read roles & membership for a corporation
+    ///
+    /// `esi-corporations.read_corporation_membership.v1`
+    #[serde(rename = "esi-corporations.read_corporation_membership.v1")]
+    CorporationsReadCorporationMembership,
+    /// Access to retrieve information on corporation's wallet & hangar divisions
+    ///
+    /// `esi-corporations.read_divisions.v1`
+    #[serde(rename = "esi-corporations.read_divisions.v1")]
+    CorporationsReadDivisions,
+    /// Access to retrieve information on corporation's industry facilities
+    ///
+    /// `esi-corporations.read_facilities.v1`
+    #[serde(rename = "esi-corporations.read_facilities.v1")]
+    CorporationsReadFacilities,
+    /// Access to retrieve information on corporation's medals
+    ///
+    /// `esi-corporations.read_medals.v1`
+    #[serde(rename = "esi-corporations.read_medals.v1")]
+    CorporationsReadMedals,
+    /// Access to retrieve information on a corporation's NPC standings
+    ///
+    /// `esi-corporations.read_standings.v1`
+    #[serde(rename = "esi-corporations.read_standings.v1")]
+    CorporationsReadStandings,
+    /// Access to retrieve information on a corporation's starbases (POSes)
+    ///
+    /// `esi-corporations.read_starbases.v1`
+    #[serde(rename = "esi-corporations.read_starbases.v1")]
+    CorporationsReadStarbases,
+    /// Access to retrieve information on corporation's Upwell structures
+    ///
+    /// `esi-corporations.read_structures.v1`
+    #[serde(rename = "esi-corporations.read_structures.v1")]
+    CorporationsReadStructures,
+    /// Access to retrieve information on a corporation's member titles
+    ///
+    /// `esi-corporations.read_titles.v1`
+    #[serde(rename = "esi-corporations.read_titles.v1")]
+    CorporationsReadTitles,
+    /// Access to member tracking-related information for a corporation
+    ///
+    /// `esi-corporations.track_members.v1`
+    #[serde(rename = "esi-corporations.track_members.v1")]
+    CorporationsTrackMembers,
+    /// Read access to corporation's contacts
+    ///
+    /// `esi-corporations.read_contacts.v1`
+    #[serde(rename = "esi-corporations.read_contacts.v1")]
+    CorporationsReadContacts,
+    /// Read access to corporation's projects & their contributions
+    ///
+    /// `esi-corporations.read_projects.v1`
+    #[serde(rename = "esi-corporations.read_projects.v1")]
+    CorporationsReadProjects,
+    /// Read access to character fittings
+    ///
+    /// `esi-fittings.read_fittings.v1`
+    #[serde(rename = "esi-fittings.read_fittings.v1")]
+    FittingsReadFittings,
+    /// Write access to character fittings
+    ///
+    /// `esi-fittings.write_fittings.v1`
+    #[serde(rename = "esi-fittings.write_fittings.v1")]
+    FittingsWriteFittings,
+    /// Read access to character fleet information
+    ///
+    /// `esi-fleets.read_fleet.v1`
+    #[serde(rename = "esi-fleets.read_fleet.v1")]
+    FleetsReadFleet,
+    /// Write access to fleet if character holds sufficient fleet roles
+    ///
+    /// `esi-fleets.write_fleet.v1`
+    #[serde(rename = "esi-fleets.write_fleet.v1")]
+    FleetsWriteFleet,
+    /// Read access to character industry jobs
+    ///
+    /// `esi-industry.read_character_jobs.v1`
+    #[serde(rename = "esi-industry.read_character_jobs.v1")]
+    IndustryReadCharacterJobs,
+    /// Read access to character's mining ledger
+    ///
+    /// `esi-industry.read_character_mining.v1`
+    #[serde(rename = "esi-industry.read_character_mining.v1")]
+    IndustryReadCharacterMining,
+    /// Read access to corporation industry jobs
+    ///
+    /// `esi-industry.read_corporation_jobs.v1`
+    #[serde(rename = "esi-industry.read_corporation_jobs.v1")]
+    IndustryReadCorporationJobs,
+    /// Read access to corporation mining ledger (moon mining structures)
+    ///
+    /// `esi-industry.read_corporation_mining.v1`
+    #[serde(rename = "esi-industry.read_corporation_mining.v1")]
+    IndustryReadCorporationMining,
+    /// Read access to corporation killmails
+    ///
+    /// `esi-killmails.read_corporation_killmails.v1`
+    #[serde(rename = "esi-killmails.read_corporation_killmails.v1")]
+    KillmailsReadCorporationKillmails,
+    /// Read access to character killmails
+    ///
+    /// `esi-killmails.read_killmails.v1`
+    #[serde(rename = "esi-killmails.read_killmails.v1")]
+    KillmailsReadKillmails,
+    /// Read access to character's current location
+    ///
+    /// `esi-location.read_location.v1`
+    #[serde(rename = "esi-location.read_location.v1")]
+    LocationReadLocation,
+    /// Read access to characer's online status
+    ///
+    /// `esi-location.read_online.v1`
+    #[serde(rename = "esi-location.read_online.v1")]
+    LocationReadOnline,
+    /// Read access to character's ship type
+    ///
+    /// `esi-location.read_ship_type.v1`
+    #[serde(rename = "esi-location.read_ship_type.v1")]
+    LocationReadShipType,
+    /// Access to organize character's mail
+    ///
+    /// `esi-mail.organize_mail.v1`
+    #[serde(rename = "esi-mail.organize_mail.v1")]
+    MailOrganizeMail,
+    /// Read access to character's eve mails
+    ///
+    /// `esi-mail.read_mail.v1`
+    #[serde(rename = "esi-mail.read_mail.v1")]
+    MailReadMail,
+    /// Write access to send eve mails on behalf of character
+    ///
+    /// `esi-mail.send_mail.v1`
+    #[serde(rename = "esi-mail.send_mail.v1")]
+    MailSendMail,
+    /// Access to retrieve information on character's market orders
+    ///
+    /// `esi-markets.read_character_orders.v1`
+    #[serde(rename = "esi-markets.read_character_orders.v1")]
+    MarketsReadCharacterOrders,
+    /// Access to retrieve information on corporation's market orders
+    ///
+    /// `esi-markets.read_corporation_orders.v1`
+    #[serde(rename = "esi-markets.read_corporation_orders.v1")]
+    MarketsReadCorporationOrders,
+    /// Access to retrieve information on a structure's market orders
+    ///
+    /// `esi-markets.structure_markets.v1`
+    #[serde(rename = "esi-markets.structure_markets.v1")]
+    MarketsStructureMarkets,
+    /// Read access to character's planetary interaction
+    ///
+    /// `esi-planets.manage_planets.v1`
+    #[serde(rename = "esi-planets.manage_planets.v1")]
+    PlanetsManagePlanets,
+    /// Read access to corporation-owned customs offices
+    ///
+    /// `esi-planets.read_customs_offices.v1`
+    #[serde(rename = "esi-planets.read_customs_offices.v1")]
+    PlanetsReadCustomsOffices,
+    /// Access to search for structures character has access to
+    ///
+    /// `esi-search.search_structures.v1`
+    #[serde(rename = "esi-search.search_structures.v1")]
+    SearchSearchStructures,
+    /// Read access to character's skill queue
+    ///
+    /// `esi-skills.read_skillqueue.v1`
+    #[serde(rename = "esi-skills.read_skillqueue.v1")]
+    SkillsReadSkillqueue,
+    /// Read access to character's skills
+    ///
+    /// `esi-skills.read_skills.v1`
+    #[serde(rename = "esi-skills.read_skills.v1")]
+    SkillsReadSkills,
+    /// Access to open UI windows for character
+    ///
+    /// `esi-ui.open_window.v1`
+    #[serde(rename = "esi-ui.open_window.v1")]
+    UiOpenWindow,
+    /// Write access to set autopilot waypoint for character
+    ///
+    /// `esi-ui.write_waypoint.v1`
+    #[serde(rename = "esi-ui.write_waypoint.v1")]
+    UiWriteWaypoint,
+    /// Read access to information on structures character has access to
+    ///
+    /// `esi-universe.read_structures.v1`
+    #[serde(rename = "esi-universe.read_structures.v1")]
+    UniverseReadStructures,
+    /// Read access to character's wallet
+    ///
+    /// `esi-wallet.read_character_wallet.v1`
+    #[serde(rename = "esi-wallet.read_character_wallet.v1")]
+    WalletReadCharacterWallet,
+    /// Read access to corporation's wallets
+    ///
+    /// `esi-wallet.read_corporation_wallets.v1`
+    #[serde(rename = "esi-wallet.read_corporation_wallets.v1")]
+    WalletReadCorporationWallets,
+}
+
+impl Scope {
+    /// All scopes supported by the [`super::ScopeBuilder`]
+    pub const ALL: [Scope; 65] = [
+        Scope::PublicData,
+        Scope::AlliancesReadContacts,
+        Scope::AssetsReadAssets,
+        Scope::AssetsReadCorporationAssets,
+        Scope::CalendarReadCalendarEvents,
+        Scope::CalendarRespondCalendarEvents,
+        Scope::CharactersReadAgentsResearch,
+        Scope::CharactersReadBlueprints,
+        Scope::CharactersReadChatChannels,
+        Scope::CharactersReadContacts,
+        Scope::CharactersReadCorporationRoles,
+        Scope::CharactersReadFatigue,
+        Scope::CharactersReadFwStats,
+        Scope::CharactersReadLoyalty,
+        Scope::CharactersReadMedals,
+        Scope::CharactersReadNotifications,
+        Scope::CharactersReadStandings,
+        Scope::CharactersReadTitles,
+        Scope::CharactersWriteContacts,
+        Scope::ClonesReadClones,
+        Scope::ClonesReadImplants,
+        Scope::ContractsReadCharacterContracts,
+        Scope::ContractsReadCorporationContracts,
+        Scope::CorporationsReadBlueprints,
+        Scope::CorporationsReadContainerLogs,
+        Scope::CorporationsReadCorporationMembership,
+        Scope::CorporationsReadDivisions,
+        Scope::CorporationsReadFacilities,
+        Scope::CorporationsReadMedals,
+        Scope::CorporationsReadStandings,
+        Scope::CorporationsReadStarbases,
+        Scope::CorporationsReadStructures,
+        Scope::CorporationsReadTitles,
+        Scope::CorporationsTrackMembers,
+        Scope::CorporationsReadContacts,
+        Scope::CorporationsReadProjects,
+        Scope::FittingsReadFittings,
+        Scope::FittingsWriteFittings,
+        Scope::FleetsReadFleet,
+        Scope::FleetsWriteFleet,
+        Scope::IndustryReadCharacterJobs,
+        Scope::IndustryReadCharacterMining,
+        Scope::IndustryReadCorporationJobs,
+        Scope::IndustryReadCorporationMining,
+        Scope::KillmailsReadCorporationKillmails,
+        Scope::KillmailsReadKillmails,
+        Scope::LocationReadLocation,
+        Scope::LocationReadOnline,
+        Scope::LocationReadShipType,
+        Scope::MailOrganizeMail,
+        Scope::MailReadMail,
+        Scope::MailSendMail,
+        Scope::MarketsReadCharacterOrders,
+        Scope::MarketsReadCorporationOrders,
+        Scope::MarketsStructureMarkets,
+        Scope::PlanetsManagePlanets,
+        Scope::PlanetsReadCustomsOffices,
+        Scope::SearchSearchStructures,
+        Scope::SkillsReadSkillqueue,
+        Scope::SkillsReadSkills,
+        Scope::UiOpenWindow,
+        Scope::UiWriteWaypoint,
+        Scope::UniverseReadStructures,
+        Scope::WalletReadCharacterWallet,
+        Scope::WalletReadCorporationWallets,
+    ];
+
+    /// Returns the canonical scope string, e.g. `esi-characters.read_contacts.v1`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::PublicData => builder::PUBLIC_DATA,
+            Scope::AlliancesReadContacts => alliances::READ_CONTACTS,
+            Scope::AssetsReadAssets => assets::READ_ASSETS,
+            Scope::AssetsReadCorporationAssets => assets::READ_CORPORATION_ASSETS,
+            Scope::CalendarReadCalendarEvents => calendar::READ_CALENDAR_EVENTS,
+            Scope::CalendarRespondCalendarEvents => calendar::RESPOND_CALENDAR_EVENTS,
+            Scope::CharactersReadAgentsResearch => characters::READ_AGENTS_RESEARCH,
+            Scope::CharactersReadBlueprints => characters::READ_BLUEPRINTS,
+            Scope::CharactersReadChatChannels => characters::READ_CHAT_CHANNELS,
+            Scope::CharactersReadContacts => characters::READ_CONTACTS,
+            Scope::CharactersReadCorporationRoles => characters::READ_CORPORATION_ROLES,
+            Scope::CharactersReadFatigue => characters::READ_FATIGUE,
+            Scope::CharactersReadFwStats => characters::READ_FW_STATS,
+            Scope::CharactersReadLoyalty => characters::READ_LOYALTY,
+            Scope::CharactersReadMedals => characters::READ_MEDALS,
+            Scope::CharactersReadNotifications => characters::READ_NOTIFICATIONS,
+            Scope::CharactersReadStandings => characters::READ_STANDINGS,
+            Scope::CharactersReadTitles => characters::READ_TITLES,
+            Scope::CharactersWriteContacts => characters::WRITE_CONTACTS,
+            Scope::ClonesReadClones => clones::READ_CLONES,
+            Scope::ClonesReadImplants => clones::READ_IMPLANTS,
+            Scope::ContractsReadCharacterContracts => contracts::READ_CHARACTER_CONTRACTS,
+            Scope::ContractsReadCorporationContracts => contracts::READ_CORPORATION_CONTRACTS,
+            Scope::CorporationsReadBlueprints => corporations::READ_BLUEPRINTS,
+            Scope::CorporationsReadContainerLogs => corporations::READ_CONTAINER_LOGS,
+            Scope::CorporationsReadCorporationMembership => {
+                corporations::READ_CORPORATION_MEMBERSHIP
+            }
+            Scope::CorporationsReadDivisions => corporations::READ_DIVISIONS,
+            Scope::CorporationsReadFacilities => corporations::READ_FACILITIES,
+            Scope::CorporationsReadMedals => corporations::READ_MEDALS,
+            Scope::CorporationsReadStandings => corporations::READ_STANDINGS,
+            Scope::CorporationsReadStarbases => corporations::READ_STARBASES,
+            Scope::CorporationsReadStructures => corporations::READ_STRUCTURES,
+            Scope::CorporationsReadTitles => corporations::READ_TITLES,
+            Scope::CorporationsTrackMembers => corporations::TRACK_MEMBERS,
+            Scope::CorporationsReadContacts => corporations::READ_CONTACTS,
+            Scope::CorporationsReadProjects => corporations::READ_PROJECTS,
+            Scope::FittingsReadFittings => fittings::READ_FITTINGS,
+            Scope::FittingsWriteFittings => fittings::WRITE_FITTINGS,
+            Scope::FleetsReadFleet => fleets::READ_FLEET,
+            Scope::FleetsWriteFleet => fleets::WRITE_FLEET,
+            Scope::IndustryReadCharacterJobs => industry::READ_CHARACTER_JOBS,
+            Scope::IndustryReadCharacterMining => industry::READ_CHARACTER_MINING,
+            Scope::IndustryReadCorporationJobs => industry::READ_CORPORATION_JOBS,
+            Scope::IndustryReadCorporationMining => industry::READ_CORPORATION_MINING,
+            Scope::KillmailsReadCorporationKillmails => killmails::READ_CORPORATION_KILLMAILS,
+            Scope::KillmailsReadKillmails => killmails::READ_KILLMAILS,
+            Scope::LocationReadLocation => location::READ_LOCATION,
+            Scope::LocationReadOnline => location::READ_ONLINE,
+            Scope::LocationReadShipType => location::READ_SHIP_TYPE,
+            Scope::MailOrganizeMail => mail::ORGANIZE_MAIL,
+            Scope::MailReadMail => mail::READ_MAIL,
+            Scope::MailSendMail => mail::SEND_MAIL,
+            Scope::MarketsReadCharacterOrders => markets::READ_CHARACTER_ORDERS,
+            Scope::MarketsReadCorporationOrders => markets::READ_CORPORATION_ORDERS,
+            Scope::MarketsStructureMarkets => markets::STRUCTURE_MARKETS,
+            Scope::PlanetsManagePlanets => planets::MANAGE_PLANETS,
+            Scope::PlanetsReadCustomsOffices => planets::READ_CUSTOMS_OFFICES,
+            Scope::SearchSearchStructures => search::SEARCH_STRUCTURES,
+            Scope::SkillsReadSkillqueue => skills::READ_SKILLQUEUE,
+            Scope::SkillsReadSkills => skills::READ_SKILLS,
+            Scope::UiOpenWindow => ui::OPEN_WINDOW,
+            Scope::UiWriteWaypoint => ui::WRITE_WAYPOINT,
+            Scope::UniverseReadStructures => universe::READ_STRUCTURES,
+            Scope::WalletReadCharacterWallet => wallet::READ_CHARACTER_WALLET,
+            Scope::WalletReadCorporationWallets => wallet::READ_CORPORATION_WALLETS,
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ParseScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scope::ALL
+            .iter()
+            .find(|scope| scope.as_str() == s)
+            .copied()
+            .ok_or_else(|| ParseScopeError(s.to_string()))
+    }
+}
+
+impl From<Scope> for String {
+    fn from(scope: Scope) -> Self {
+        scope.as_str().to_string()
+    }
+}
+
+#[cfg(test)]
+mod scope_tests {
+    use super::*;
+    use crate::ScopeBuilder;
+
+    /// Tests that every scope round-trips through its string representation
+    #[test]
+    fn test_scope_round_trip() {
+        for scope in Scope::ALL {
+            assert_eq!(scope.to_string().parse::<Scope>(), Ok(scope));
+        }
+    }
+
+    /// Tests that the scope enum covers every scope built by [`ScopeBuilder::all`]
+    #[test]
+    fn test_scope_covers_scope_builder() {
+        let scopes = ScopeBuilder::all();
+
+        assert_eq!(scopes.len(), Scope::ALL.len());
+        for scope in scopes {
+            assert!(scope.parse::<Scope>().is_ok(), "Missing scope: {}", scope);
+        }
+    }
+
+    /// Tests that parsing an unknown scope returns an error
+    #[test]
+    fn test_scope_parse_unknown() {
+        let result = "esi-unknown.read_unknown.v1".parse::<Scope>();
+
+        assert_eq!(
+            result,
+            Err(ParseScopeError("esi-unknown.read_unknown.v1".to_string()))
+        );
+    }
+
+    /// Tests that scopes serialize as their scope string
+    #[test]
+    fn test_scope_serde() {
+        let json = serde_json::to_string(&Scope::PublicData).unwrap();
+        assert_eq!(json, "\"publicData\"");
+
+        let scope: Scope = serde_json::from_str("\"esi-wallet.read_character_wallet.v1\"").unwrap();
+        assert_eq!(scope, Scope::WalletReadCharacterWallet);
+    }
+}
@@ -23,8 +23,12 @@
 //!
 //! // Use with `esi_client.oauth2().login_url(scopes)` method...
 //! ```
+//!
+//! Scope strings are available as constants within each module, e.g. [`characters::READ_CONTACTS`],
+//! & as the [`Scope`] enum which can be parsed from & converted to scope strings.
 
 pub mod builder;
+pub mod enums;
 
 pub mod alliances;
 pub mod assets;
@@ -48,6 +52,7 @@ pub mod universe;
 pub mod wallet;
 
 pub use builder::ScopeBuilder;
+pub use enums::{ParseScopeError, Scope};
 
 pub use alliances::AlliancesScopes;
 pub use assets::AssetsScopes;
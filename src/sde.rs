@@ -0,0 +1,463 @@
+//! # EVE Static Data Export
+//!
+//! Provides [`StaticData`], item type information loaded from a local copy of the
+//! [Static Data Export](https://developers.eveonline.com/static-data) (SDE) to look up type names,
+//! volumes & groups without requesting
+//! [`UniverseEndpoints::get_item_type_information`](crate::endpoints::universe::UniverseEndpoints::get_item_type_information)
+//! for every type of an asset or market listing.
+//!
+//! Requires the `sde` feature.
+//!
+//! Only the `types.jsonl` file & optionally the `typeDogma.jsonl` & `blueprints.jsonl` files of the
+//! JSON Lines SDE are loaded, blueprint materials can be used to
+//! [estimate industry job costs](crate::endpoints::industry::IndustryEndpoints::estimate_job_cost). Types missing from the loaded SDE, such as types added since it was downloaded, can be
+//! retrieved from ESI instead with
+//! [`UniverseEndpoints::get_static_types`](crate::endpoints::universe::UniverseEndpoints::get_static_types).
+//!
+//! ## Usage Example
+//!
+//! ```no_run
+//! use eve_esi::sde::StaticData;
+//!
+//! # async fn example(esi_client: eve_esi::Client) -> Result<(), eve_esi::Error> {
+//! let mut sde = StaticData::load_types("sde/types.jsonl").await?;
+//! sde.load_type_dogma("sde/typeDogma.jsonl").await?;
+//! sde.load_blueprints("sde/blueprints.jsonl").await?;
+//!
+//! // Tritanium
+//! let name = sde.type_name(34);
+//!
+//! // Falls back to ESI for types missing from the SDE
+//! let types = esi_client.universe().get_static_types(&sde, vec![34, 35]).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::enums::industry::IndustryActivity;
+use crate::model::industry::BlueprintMaterial;
+use crate::model::universe::{ItemType, TypeDogmaAttribute, TypeDogmaEffect};
+use crate::Error;
+
+/// Static information on an item type, loaded from the SDE or converted from an ESI [`ItemType`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StaticType {
+    /// The ID of the item type
+    pub type_id: i64,
+    /// English name of the item type
+    pub name: String,
+    /// The ID of the item type's group
+    pub group_id: i64,
+    /// The ID of the market group the item type is listed in, `None` if not sold on the market
+    pub market_group_id: Option<i64>,
+    /// Volume of the item type in m3
+    pub volume: Option<f64>,
+    /// Cargo capacity of the item type in m3
+    pub capacity: Option<f64>,
+    /// Mass of the item type in kg
+    pub mass: Option<f64>,
+    /// Number of items produced per manufacturing run or required per reprocessing batch
+    pub portion_size: Option<i64>,
+    /// Whether the item type is published in-game
+    pub published: bool,
+    /// Dogma attribute values of the item type, empty unless the type dogma file was loaded
+    pub dogma_attributes: Vec<TypeDogmaAttribute>,
+    /// Dogma effects of the item type, empty unless the type dogma file was loaded
+    pub dogma_effects: Vec<TypeDogmaEffect>,
+}
+
+impl From<ItemType> for StaticType {
+    fn from(item_type: ItemType) -> Self {
+        Self {
+            type_id: item_type.type_id,
+            name: item_type.name,
+            group_id: item_type.group_id,
+            market_group_id: item_type.market_group_id,
+            volume: item_type.volume,
+            capacity: item_type.capacity,
+            mass: item_type.mass,
+            portion_size: item_type.portion_size,
+            published: item_type.published,
+            dogma_attributes: item_type.dogma_attributes,
+            dogma_effects: item_type.dogma_effects,
+        }
+    }
+}
+
+/// Item types loaded from the Static Data Export, see the [module documentation](self)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaticData {
+    types: HashMap<i64, StaticType>,
+    blueprints: HashMap<i64, HashMap<IndustryActivity, Vec<BlueprintMaterial>>>,
+}
+
+/// A line of the SDE's `types.jsonl` file
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SdeTypeRecord {
+    #[serde(rename = "_key")]
+    key: i64,
+    name: HashMap<String, String>,
+    #[serde(rename = "groupID")]
+    group_id: i64,
+    #[serde(rename = "marketGroupID")]
+    market_group_id: Option<i64>,
+    volume: Option<f64>,
+    capacity: Option<f64>,
+    mass: Option<f64>,
+    portion_size: Option<i64>,
+    #[serde(default)]
+    published: bool,
+}
+
+/// A line of the SDE's `typeDogma.jsonl` file
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SdeTypeDogmaRecord {
+    #[serde(rename = "_key")]
+    key: i64,
+    #[serde(default)]
+    dogma_attributes: Vec<SdeDogmaAttribute>,
+    #[serde(default)]
+    dogma_effects: Vec<SdeDogmaEffect>,
+}
+
+/// A line of the SDE's `blueprints.jsonl` file
+#[derive(Deserialize)]
+struct SdeBlueprintRecord {
+    #[serde(rename = "_key")]
+    key: i64,
+    #[serde(default)]
+    activities: HashMap<String, SdeBlueprintActivity>,
+}
+
+#[derive(Deserialize)]
+struct SdeBlueprintActivity {
+    #[serde(default)]
+    materials: Vec<SdeBlueprintMaterial>,
+}
+
+#[derive(Deserialize)]
+struct SdeBlueprintMaterial {
+    #[serde(rename = "typeID")]
+    type_id: i64,
+    quantity: u32,
+}
+
+#[derive(Deserialize)]
+struct SdeDogmaAttribute {
+    #[serde(rename = "attributeID")]
+    attribute_id: i64,
+    value: f64,
+}
+
+#[derive(Deserialize)]
+struct SdeDogmaEffect {
+    #[serde(rename = "effectID")]
+    effect_id: i64,
+    #[serde(rename = "isDefault")]
+    is_default: bool,
+}
+
+impl StaticData {
+    /// Creates a new empty [`StaticData`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads item types from the SDE's `types.jsonl` file
+    ///
+    /// # Arguments
+    /// - `path` (`impl AsRef<Path>`): The SDE's `types.jsonl` file
+    ///
+    /// # Errors
+    /// - [`Error::IoError`]: If the file could not be read
+    /// - [`Error::SerdeJsonError`]: If a line of the file is not an SDE item type
+    pub async fn load_types(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let mut static_data = Self::new();
+
+        for record in read_json_lines::<SdeTypeRecord>(path.as_ref()).await? {
+            let mut name = record.name;
+
+            static_data.insert(StaticType {
+                type_id: record.key,
+                name: name.remove("en").unwrap_or_default(),
+                group_id: record.group_id,
+                market_group_id: record.market_group_id,
+                volume: record.volume,
+                capacity: record.capacity,
+                mass: record.mass,
+                portion_size: record.portion_size,
+                published: record.published,
+                dogma_attributes: Vec::new(),
+                dogma_effects: Vec::new(),
+            });
+        }
+
+        Ok(static_data)
+    }
+
+    /// Adds the dogma attributes & effects from the SDE's `typeDogma.jsonl` file to the loaded types
+    ///
+    /// Dogma of types which were not loaded with [`Self::load_types`] is ignored.
+    ///
+    /// # Arguments
+    /// - `path` (`impl AsRef<Path>`): The SDE's `typeDogma.jsonl` file
+    ///
+    /// # Errors
+    /// - [`Error::IoError`]: If the file could not be read
+    /// - [`Error::SerdeJsonError`]: If a line of the file is not an SDE type's dogma
+    pub async fn load_type_dogma(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        for record in read_json_lines::<SdeTypeDogmaRecord>(path.as_ref()).await? {
+            let Some(static_type) = self.types.get_mut(&record.key) else {
+                continue;
+            };
+
+            static_type.dogma_attributes = record
+                .dogma_attributes
+                .into_iter()
+                .map(|attribute| TypeDogmaAttribute {
+                    attribute_id: attribute.attribute_id,
+                    value: attribute.value,
+                })
+                .collect();
+            static_type.dogma_effects = record
+                .dogma_effects
+                .into_iter()
+                .map(|effect| TypeDogmaEffect {
+                    effect_id: effect.effect_id,
+                    is_default: effect.is_default,
+                })
+                .collect();
+        }
+
+        Ok(())
+    }
+
+    /// Loads the materials of each blueprint & reaction formula activity from the SDE's `blueprints.jsonl` file
+    ///
+    /// Activities the SDE names differently from ESI, `research_material` & `research_time`, are
+    /// mapped to their [`IndustryActivity`], unknown activities are ignored.
+    ///
+    /// # Arguments
+    /// - `path` (`impl AsRef<Path>`): The SDE's `blueprints.jsonl` file
+    ///
+    /// # Errors
+    /// - [`Error::IoError`]: If the file could not be read
+    /// - [`Error::SerdeJsonError`]: If a line of the file is not an SDE blueprint
+    pub async fn load_blueprints(&mut self, path: impl AsRef<Path>) -> Result<(), Error> {
+        for record in read_json_lines::<SdeBlueprintRecord>(path.as_ref()).await? {
+            let activities = record
+                .activities
+                .into_iter()
+                .filter_map(|(name, activity)| {
+                    let materials = activity
+                        .materials
+                        .into_iter()
+                        .map(|material| BlueprintMaterial {
+                            type_id: material.type_id,
+                            quantity: material.quantity,
+                        })
+                        .collect();
+
+                    Some((sde_industry_activity(&name)?, materials))
+                })
+                .collect();
+
+            self.blueprints.insert(record.key, activities);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the materials required per run of an activity of the blueprint or reaction formula type ID
+    ///
+    /// Returns `None` if the blueprint was not loaded with [`Self::load_blueprints`] or lacks the activity.
+    pub fn blueprint_materials(
+        &self,
+        blueprint_type_id: i64,
+        activity: IndustryActivity,
+    ) -> Option<&[BlueprintMaterial]> {
+        self.blueprints
+            .get(&blueprint_type_id)?
+            .get(&activity)
+            .map(Vec::as_slice)
+    }
+
+    /// Adds an item type, replacing any existing entry with the same type ID
+    pub fn insert(&mut self, static_type: StaticType) {
+        self.types.insert(static_type.type_id, static_type);
+    }
+
+    /// Returns the item type with the provided ID if it was loaded
+    pub fn get(&self, type_id: i64) -> Option<&StaticType> {
+        self.types.get(&type_id)
+    }
+
+    /// Returns the English name of the item type with the provided ID
+    pub fn type_name(&self, type_id: i64) -> Option<&str> {
+        self.get(type_id)
+            .map(|static_type| static_type.name.as_str())
+    }
+
+    /// Returns the volume in m3 of the item type with the provided ID
+    pub fn type_volume(&self, type_id: i64) -> Option<f64> {
+        self.get(type_id)?.volume
+    }
+
+    /// Returns the group ID of the item type with the provided ID
+    pub fn type_group_id(&self, type_id: i64) -> Option<i64> {
+        self.get(type_id).map(|static_type| static_type.group_id)
+    }
+
+    /// Returns the number of item types loaded
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if no item types were loaded
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+}
+
+/// Maps the name of a blueprint activity in the SDE to its [`IndustryActivity`]
+fn sde_industry_activity(name: &str) -> Option<IndustryActivity> {
+    match name {
+        "copying" => Some(IndustryActivity::Copying),
+        "invention" => Some(IndustryActivity::Invention),
+        "manufacturing" => Some(IndustryActivity::Manufacturing),
+        "reaction" => Some(IndustryActivity::Reaction),
+        "research_material" => Some(IndustryActivity::ResearchingMaterialEfficiency),
+        "research_time" => Some(IndustryActivity::ResearchingTimeEfficiency),
+        _ => None,
+    }
+}
+
+/// Reads a JSON Lines file, skipping empty lines
+async fn read_json_lines<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Vec<T>, Error> {
+    let contents = tokio::fs::read_to_string(path).await?;
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+#[cfg(test)]
+mod static_data_tests {
+    use super::*;
+
+    /// Tests item types & their dogma are loaded from SDE JSON Lines files
+    ///
+    /// # Test Setup
+    /// - Write a types file with 2 types & a type dogma file with dogma for 1 loaded & 1 unknown type
+    ///
+    /// # Assertions
+    /// - Assert both types are loaded with their English names, volumes & groups
+    /// - Assert the dogma is added to the loaded type & dogma of the unknown type is ignored
+    /// - Assert nothing is returned for a type which was not loaded
+    #[tokio::test]
+    async fn test_load_types_and_dogma() {
+        let directory = std::env::temp_dir();
+        let types_path = directory.join(format!(
+            "eve_esi_sde_types_test_{}.jsonl",
+            std::process::id()
+        ));
+        let dogma_path = directory.join(format!(
+            "eve_esi_sde_dogma_test_{}.jsonl",
+            std::process::id()
+        ));
+
+        std::fs::write(
+            &types_path,
+            concat!(
+                r#"{"_key":34,"name":{"de":"Tritanium","en":"Tritanium"},"groupID":18,"marketGroupID":1857,"volume":0.01,"portionSize":1,"published":true}"#,
+                "\n",
+                r#"{"_key":587,"name":{"en":"Rifter"},"groupID":25,"volume":27289.0,"capacity":140.0,"mass":1067000.0,"published":true}"#,
+                "\n\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            &dogma_path,
+            concat!(
+                r#"{"_key":587,"dogmaAttributes":[{"attributeID":9,"value":350.0}],"dogmaEffects":[{"effectID":11,"isDefault":false}]}"#,
+                "\n",
+                r#"{"_key":999999,"dogmaAttributes":[{"attributeID":9,"value":1.0}]}"#,
+            ),
+        )
+        .unwrap();
+
+        let mut sde = StaticData::load_types(&types_path).await.unwrap();
+        sde.load_type_dogma(&dogma_path).await.unwrap();
+        std::fs::remove_file(&types_path).unwrap();
+        std::fs::remove_file(&dogma_path).unwrap();
+
+        assert_eq!(sde.len(), 2);
+        assert_eq!(sde.type_name(34), Some("Tritanium"));
+        assert_eq!(sde.type_volume(34), Some(0.01));
+        assert_eq!(sde.type_group_id(587), Some(25));
+        assert_eq!(sde.get(587).unwrap().dogma_attributes[0].value, 350.0);
+        assert!(sde.get(34).unwrap().dogma_attributes.is_empty());
+        assert_eq!(sde.type_name(999999), None);
+    }
+
+    /// Tests blueprint materials are loaded from an SDE JSON Lines file
+    ///
+    /// # Test Setup
+    /// - Write a blueprints file with a blueprint's manufacturing & research activities
+    ///
+    /// # Assertions
+    /// - Assert the manufacturing materials are loaded
+    /// - Assert the SDE's research activity names are mapped to ESI's activities
+    /// - Assert nothing is returned for a missing activity or blueprint
+    #[tokio::test]
+    async fn test_load_blueprints() {
+        let blueprints_path = std::env::temp_dir().join(format!(
+            "eve_esi_sde_blueprints_test_{}.jsonl",
+            std::process::id()
+        ));
+
+        std::fs::write(
+            &blueprints_path,
+            concat!(
+                r#"{"_key":681,"activities":{"copying":{"time":480},"manufacturing":{"materials":[{"quantity":86,"typeID":38}],"products":[{"quantity":1,"typeID":165}],"time":600},"research_material":{"time":210}},"blueprintTypeID":681,"maxProductionLimit":300}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        let mut sde = StaticData::new();
+        sde.load_blueprints(&blueprints_path).await.unwrap();
+        std::fs::remove_file(&blueprints_path).unwrap();
+
+        assert_eq!(
+            sde.blueprint_materials(681, IndustryActivity::Manufacturing),
+            Some(
+                [BlueprintMaterial {
+                    type_id: 38,
+                    quantity: 86
+                }]
+                .as_slice()
+            )
+        );
+        assert_eq!(
+            sde.blueprint_materials(681, IndustryActivity::ResearchingMaterialEfficiency),
+            Some([].as_slice())
+        );
+        assert_eq!(
+            sde.blueprint_materials(681, IndustryActivity::Reaction),
+            None
+        );
+        assert_eq!(
+            sde.blueprint_materials(682, IndustryActivity::Manufacturing),
+            None
+        );
+    }
+}
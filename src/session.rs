@@ -0,0 +1,433 @@
+//! # EVE ESI Character Sessions
+//!
+//! Provides [`CharacterSession`], a character's access token bundled with the character's ID,
+//! name & granted scopes. The session exposes the same endpoint categories as the [`Client`],
+//! with every character endpoint pre-bound to the session's access token & character ID, so
+//! applications handling many logged in characters don't need to pass both to each request.
+//!
+//! Sessions are usually created from the authorization code of the OAuth2 callback with
+//! [`CharacterSession::from_callback`], or from a token refreshed with
+//! [`OAuth2Endpoints::get_token_refresh`](crate::oauth2::OAuth2Endpoints::get_token_refresh)
+//! using [`CharacterSession::from_token`]. Both validate the access token to read the character's
+//! ID & the granted scopes from its claims.
+//!
+//! Endpoints requiring IDs other than the character's, such as corporation endpoints, are not
+//! bound by the session, use the [`Client`] with [`CharacterSession::access_token`] instead.
+//!
+//! ## Usage Example
+//!
+//! ```no_run
+//! use eve_esi::session::CharacterSession;
+//!
+//! # async fn example(esi_client: eve_esi::Client, code: &str) -> Result<(), eve_esi::Error> {
+//! // Exchange the authorization code of the OAuth2 callback for a session
+//! let mut session = CharacterSession::from_callback(esi_client, code).await?;
+//!
+//! // Refresh the access token before it expires
+//! if session.is_expired_within(chrono::Duration::seconds(60)) {
+//!     session.refresh().await?;
+//! }
+//!
+//! let assets = session.assets().get_character_assets(1).send().await?;
+//! let wallet_journal = session.wallet().get_character_wallet_journal(1).send().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use oauth2::RefreshToken;
+
+use crate::esi::AccessToken;
+#[cfg(feature = "character")]
+use crate::model::asset::Blueprint;
+#[cfg(feature = "assets")]
+use crate::model::asset::{Asset, AssetLocation, AssetName};
+#[cfg(feature = "calendar")]
+use crate::model::calendar::{CalendarEvent, CalendarEventAttendee, CalendarEventSummary};
+#[cfg(feature = "character")]
+use crate::model::character::{
+    CharacterCorporationRole, CharacterCorporationTitle, CharacterJumpFatigue, CharacterMedal,
+    CharacterNewContactNotification, CharacterNotification, CharacterResearchAgent,
+};
+#[cfg(feature = "clones")]
+use crate::model::clones::CharacterClones;
+#[cfg(feature = "contacts")]
+use crate::model::contacts::{CharacterContact, ContactLabel};
+#[cfg(feature = "calendar")]
+use crate::model::enums::calendar::PutCalendarEventResponse;
+#[cfg(feature = "fittings")]
+use crate::model::fitting::{CreatedFitting, Fitting};
+#[cfg(feature = "killmails")]
+use crate::model::killmail::KillmailReference;
+#[cfg(feature = "location")]
+use crate::model::location::{CharacterLocation, CharacterOnlineStatus, CharacterShip};
+#[cfg(feature = "market")]
+use crate::model::market::CharacterMarketOrder;
+use crate::model::oauth2::{EveJwtClaims, TokenResponse};
+#[cfg(feature = "planetary-interaction")]
+use crate::model::planetary_interaction::{Colony, ColonyLayout};
+#[cfg(feature = "fittings")]
+use crate::model::requests::NewFitting;
+#[cfg(feature = "mail")]
+use crate::model::requests::NewMail;
+#[cfg(feature = "skills")]
+use crate::model::skills::{CharacterAttributes, SkillQueueEntry};
+#[cfg(feature = "character")]
+use crate::model::standing::Standing;
+#[cfg(feature = "wallet")]
+use crate::model::wallet::{WalletJournalEntry, WalletTransaction};
+use crate::{Client, Error, OAuthError};
+
+/// A character's access token bundled with its character ID & granted scopes
+///
+/// For an overview & usage example, see the [session module documentation](self)
+#[derive(Clone)]
+pub struct CharacterSession {
+    client: Client,
+    character_id: i64,
+    character_name: String,
+    owner_hash: String,
+    access_token: AccessToken,
+    refresh_token: Option<RefreshToken>,
+    scopes: Vec<String>,
+    expires_at: DateTime<Utc>,
+}
+
+impl std::fmt::Debug for CharacterSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CharacterSession")
+            .field("character_id", &self.character_id)
+            .field("character_name", &self.character_name)
+            .field("access_token", &self.access_token)
+            .field("scopes", &self.scopes)
+            .field("expires_at", &self.expires_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CharacterSession {
+    /// Creates a session by exchanging the authorization code of the OAuth2 callback for a token
+    ///
+    /// # Arguments
+    /// - `client` (`Client`): The client used for the session's requests
+    /// - `code` (`&str`): The authorization code from the `code` parameter of the callback
+    ///
+    /// # Errors
+    /// - [`Error::OAuthError`]: If OAuth2 is not configured, the code could not be exchanged for a
+    ///   token or the access token failed validation
+    pub async fn from_callback(client: Client, code: &str) -> Result<Self, Error> {
+        let token = client.oauth2().get_token(code).await?;
+
+        Self::from_token(client, TokenResponse::from_token(token, None)).await
+    }
+
+    /// Creates a session from a token, validating the access token to read the character's claims
+    ///
+    /// # Arguments
+    /// - `client` (`Client`): The client used for the session's requests
+    /// - `token` (`TokenResponse`): A token returned by
+    ///   [`OAuth2Endpoints::get_token_refresh`](crate::oauth2::OAuth2Endpoints::get_token_refresh)
+    ///
+    /// # Errors
+    /// - [`Error::OAuthError`]: If the access token failed validation or its claims don't contain a
+    ///   character ID
+    pub async fn from_token(client: Client, token: TokenResponse) -> Result<Self, Error> {
+        let claims = client
+            .oauth2()
+            .validate_token(token.access_token.secret().to_string())
+            .await?;
+
+        Self::from_claims(client, token, &claims)
+    }
+
+    /// Creates a session from a token & the claims of its already validated access token
+    ///
+    /// # Arguments
+    /// - `client` (`Client`): The client used for the session's requests
+    /// - `token` (`TokenResponse`): The token containing the access & refresh tokens
+    /// - `claims` (`&EveJwtClaims`): The claims returned when validating the token's access token
+    ///
+    /// # Errors
+    /// - [`Error::OAuthError`]: If the claims don't contain a character ID
+    pub fn from_claims(
+        client: Client,
+        token: TokenResponse,
+        claims: &EveJwtClaims,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            client,
+            character_id: claims.character_id()?,
+            character_name: claims.name.clone(),
+            owner_hash: claims.owner.clone(),
+            access_token: token.access_token,
+            refresh_token: token.refresh_token,
+            scopes: claims.scp.clone(),
+            expires_at: claims.expires_at(),
+        })
+    }
+
+    /// Refreshes the session's access token using its refresh token
+    ///
+    /// The refreshed access token is validated & the session's scopes & expiry are replaced with
+    /// those of the new token, as EVE Online may grant fewer scopes when refreshing.
+    ///
+    /// # Errors
+    /// - [`Error::OAuthError`]: [`OAuthError::AccessTokenExpired`] if the session has no refresh
+    ///   token, otherwise if the token could not be refreshed or failed validation
+    pub async fn refresh(&mut self) -> Result<(), Error> {
+        let Some(refresh_token) = &self.refresh_token else {
+            return Err(Error::OAuthError(OAuthError::AccessTokenExpired()));
+        };
+
+        let token = self
+            .client
+            .oauth2()
+            .get_token_refresh(refresh_token.secret().to_string(), None)
+            .await?;
+
+        let mut session = Self::from_token(self.client.clone(), token).await?;
+
+        // Keep the current refresh token if EVE Online didn't rotate it
+        if session.refresh_token.is_none() {
+            session.refresh_token = self.refresh_token.take();
+        }
+
+        *self = session;
+
+        Ok(())
+    }
+
+    /// Returns the ID of the session's character
+    pub fn character_id(&self) -> i64 {
+        self.character_id
+    }
+
+    /// Returns the name of the session's character
+    pub fn character_name(&self) -> &str {
+        &self.character_name
+    }
+
+    /// Returns the hash of the account owning the character, see
+    /// [`check_owner`](crate::oauth2::owner::check_owner)
+    pub fn owner_hash(&self) -> &str {
+        &self.owner_hash
+    }
+
+    /// Returns the session's access token, e.g. for endpoints not bound by the session
+    pub fn access_token(&self) -> &AccessToken {
+        &self.access_token
+    }
+
+    /// Returns the session's refresh token, `None` if the token response didn't include one
+    pub fn refresh_token(&self) -> Option<&RefreshToken> {
+        self.refresh_token.as_ref()
+    }
+
+    /// Returns the scopes granted to the session's access token
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    /// Returns `true` if all of the provided scopes were granted to the session's access token
+    ///
+    /// # Arguments
+    /// - `scopes` (`&[String]`): The scopes to check for, e.g. built with
+    ///   [`ScopeBuilder`](crate::ScopeBuilder)
+    pub fn has_scopes(&self, scopes: &[String]) -> bool {
+        scopes.iter().all(|scope| self.scopes.contains(scope))
+    }
+
+    /// Returns when the session's access token expires
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.expires_at
+    }
+
+    /// Returns `true` if the session's access token expires within the provided duration
+    ///
+    /// # Arguments
+    /// - `duration` (`Duration`): How long before expiry the token is considered expired
+    pub fn is_expired_within(&self, duration: Duration) -> bool {
+        self.expires_at <= Utc::now() + duration
+    }
+}
+
+/// Defines an endpoint category bound to a session & the [`CharacterSession`] method returning it
+///
+/// Each method calls the method of the same name of the [`Client`]'s endpoint category with the
+/// session's access token & character ID followed by the remaining arguments.
+// Unused when none of the endpoint groups bound by sessions are enabled
+#[allow(unused_macros)]
+macro_rules! session_endpoints {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $accessor:ident: $module:ident::$endpoints:ident {
+            $(
+                fn $method:ident($($arg:ident: $ty:ty),*) -> $response:ty;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        ///
+        /// For an overview & usage example, see the [session module documentation](self)
+        pub struct $name<'a> {
+            session: &'a CharacterSession,
+        }
+
+        impl $name<'_> {
+            $(
+                #[doc = concat!(
+                    "Calls [`", stringify!($endpoints), "::", stringify!($method), "`](crate::endpoints::",
+                    stringify!($module), "::", stringify!($endpoints), "::", stringify!($method),
+                    ") with the session's access token & character ID"
+                )]
+                pub fn $method(&self, $($arg: $ty),*) -> crate::esi::EsiRequest<$response> {
+                    self.session.client.$accessor().$method(
+                        &self.session.access_token,
+                        self.session.character_id,
+                        $($arg),*
+                    )
+                }
+            )*
+        }
+
+        impl CharacterSession {
+            #[doc = concat!(
+                "Access to the session's [", stringify!($module), "](crate::endpoints::",
+                stringify!($module), ") endpoints"
+            )]
+            pub fn $accessor(&self) -> $name<'_> {
+                $name { session: self }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "assets")]
+session_endpoints! {
+    /// Character asset endpoints bound to a [`CharacterSession`]
+    SessionAssetsEndpoints => assets: assets::AssetsEndpoints {
+        fn get_character_assets(page: i32) -> Vec<Asset>;
+        fn get_character_asset_locations(item_ids: Vec<i64>) -> Vec<AssetLocation>;
+        fn get_character_asset_names(item_ids: Vec<i64>) -> Vec<AssetName>;
+    }
+}
+
+#[cfg(feature = "calendar")]
+session_endpoints! {
+    /// Character calendar endpoints bound to a [`CharacterSession`]
+    SessionCalendarEndpoints => calendar: calendar::CalendarEndpoints {
+        fn list_calendar_event_summaries() -> Vec<CalendarEventSummary>;
+        fn get_an_event(event_id: i64) -> CalendarEvent;
+        fn respond_to_an_event(event_id: i64, event_response: PutCalendarEventResponse) -> ();
+        fn get_attendees(event_id: i64) -> Vec<CalendarEventAttendee>;
+    }
+}
+
+#[cfg(feature = "character")]
+session_endpoints! {
+    /// Character endpoints bound to a [`CharacterSession`]
+    SessionCharacterEndpoints => character: character::CharacterEndpoints {
+        fn get_agents_research() -> Vec<CharacterResearchAgent>;
+        fn get_blueprints(page: i32) -> Vec<Blueprint>;
+        fn calculate_a_cspa_charge_cost(character_ids: Vec<i64>) -> f64;
+        fn get_jump_fatigue() -> CharacterJumpFatigue;
+        fn get_medals() -> Vec<CharacterMedal>;
+        fn get_character_notifications() -> Vec<CharacterNotification>;
+        fn get_new_contact_notifications() -> Vec<CharacterNewContactNotification>;
+        fn get_character_corporation_roles() -> CharacterCorporationRole;
+        fn get_standings() -> Vec<Standing>;
+        fn get_character_corporation_titles() -> Vec<CharacterCorporationTitle>;
+    }
+}
+
+#[cfg(feature = "clones")]
+session_endpoints! {
+    /// Character clone endpoints bound to a [`CharacterSession`]
+    SessionClonesEndpoints => clones: clones::ClonesEndpoints {
+        fn get_clones() -> CharacterClones;
+        fn get_active_implants() -> Vec<i64>;
+    }
+}
+
+#[cfg(feature = "contacts")]
+session_endpoints! {
+    /// Character contact endpoints bound to a [`CharacterSession`]
+    SessionContactsEndpoints => contacts: contacts::ContactsEndpoints {
+        fn delete_contacts(contact_ids: Vec<i64>) -> ();
+        fn get_contacts() -> Vec<CharacterContact>;
+        fn add_contacts(standing: f64, label_ids: Vec<i64>, watched: bool, contact_ids: Vec<i64>) -> Vec<i64>;
+        fn edit_contacts(standing: f64, label_ids: Vec<i64>, watched: bool, contact_ids: Vec<i64>) -> Vec<i64>;
+        fn get_contact_labels() -> Vec<ContactLabel>;
+    }
+}
+
+#[cfg(feature = "fittings")]
+session_endpoints! {
+    /// Character fitting endpoints bound to a [`CharacterSession`]
+    SessionFittingsEndpoints => fittings: fittings::FittingsEndpoints {
+        fn get_fittings() -> Vec<Fitting>;
+        fn create_fitting(fitting: NewFitting) -> CreatedFitting;
+    }
+}
+
+#[cfg(feature = "killmails")]
+session_endpoints! {
+    /// Character killmail endpoints bound to a [`CharacterSession`]
+    SessionKillmailsEndpoints => killmails: killmails::KillmailsEndpoints {
+        fn get_character_recent_killmails(page: i32) -> Vec<KillmailReference>;
+    }
+}
+
+#[cfg(feature = "location")]
+session_endpoints! {
+    /// Character location endpoints bound to a [`CharacterSession`]
+    SessionLocationEndpoints => location: location::LocationEndpoints {
+        fn get_character_location() -> CharacterLocation;
+        fn get_character_online() -> CharacterOnlineStatus;
+        fn get_character_ship() -> CharacterShip;
+    }
+}
+
+#[cfg(feature = "mail")]
+session_endpoints! {
+    /// Character mail endpoints bound to a [`CharacterSession`]
+    SessionMailEndpoints => mail: mail::MailEndpoints {
+        fn send_mail(mail: NewMail) -> i64;
+    }
+}
+
+#[cfg(feature = "market")]
+session_endpoints! {
+    /// Character market endpoints bound to a [`CharacterSession`]
+    SessionMarketEndpoints => market: market::MarketEndpoints {
+        fn list_open_orders_from_a_character() -> Vec<CharacterMarketOrder>;
+        fn list_historical_orders_by_a_character(page: i32) -> Vec<CharacterMarketOrder>;
+    }
+}
+
+#[cfg(feature = "planetary-interaction")]
+session_endpoints! {
+    /// Character planetary interaction endpoints bound to a [`CharacterSession`]
+    SessionPlanetaryInteractionEndpoints => planetary_interaction: planetary_interaction::PlanetaryInteractionEndpoints {
+        fn get_colonies() -> Vec<Colony>;
+        fn get_colony_layout(planet_id: i64) -> ColonyLayout;
+    }
+}
+
+#[cfg(feature = "skills")]
+session_endpoints! {
+    /// Character skill endpoints bound to a [`CharacterSession`]
+    SessionSkillsEndpoints => skills: skills::SkillsEndpoints {
+        fn get_character_attributes() -> CharacterAttributes;
+        fn get_character_skill_queue() -> Vec<SkillQueueEntry>;
+    }
+}
+
+#[cfg(feature = "wallet")]
+session_endpoints! {
+    /// Character wallet endpoints bound to a [`CharacterSession`]
+    SessionWalletEndpoints => wallet: wallet::WalletEndpoints {
+        fn get_character_wallet_journal(page: i32) -> Vec<WalletJournalEntry>;
+        fn get_character_wallet_transactions(from_id: Option<i64>) -> Vec<WalletTransaction>;
+    }
+}
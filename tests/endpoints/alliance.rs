@@ -46,3 +46,104 @@ public_esi_request_test! {
         "px64x64":"ABCD"
     })
 }
+
+/// Tests retrieving an alliance along with its member corporations & their names
+///
+/// # Test Setup
+/// - Create mock endpoints for alliance information, member corporations, & universe names
+///
+/// # Assertions
+/// - Assert each endpoint was requested once
+/// - Assert member corporations were resolved & sorted by name
+/// - Assert member corporation count matches
+#[tokio::test]
+async fn test_get_alliance_with_corporations() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    // Create mock endpoints for alliance information, member corporations, & universe names
+    let mock_alliance = mock_server
+        .mock("GET", "/alliances/99013534")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "creator_corporation_id": 98784257,
+                "creator_id": 2114794365,
+                "date_founded": "2024-09-25T06:25:58Z",
+                "executor_corporation_id": 98787881,
+                "name": "Autumn.",
+                "ticker": "AUTMN",
+            })
+            .to_string(),
+        )
+        .create();
+    let mock_corporations = mock_server
+        .mock("GET", "/alliances/99013534/corporations")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([98787881, 98784257]).to_string())
+        .create();
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"category": "corporation", "id": 98784257, "name": "Beta Corp"},
+                {"category": "corporation", "id": 98787881, "name": "Alpha Corp"}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let result = esi_client
+        .alliance()
+        .get_alliance_with_corporations(99013534)
+        .await;
+
+    // Assert each endpoint was requested once
+    mock_alliance.assert();
+    mock_corporations.assert();
+    mock_names.assert();
+
+    let alliance = result.expect("Failed to get alliance with corporations");
+
+    // Assert member corporations were resolved & sorted by name
+    let names: Vec<&str> = alliance
+        .corporations
+        .iter()
+        .map(|corporation| corporation.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["Alpha Corp", "Beta Corp"]);
+
+    // Assert member corporation count matches
+    assert_eq!(alliance.corporation_count(), 2);
+}
+
+/// Tests retrieving the member corporation count of an alliance
+///
+/// # Assertions
+/// - Assert member corporations were requested once
+/// - Assert count matches the number of member corporations
+#[tokio::test]
+async fn test_get_alliance_corporation_count() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_corporations = mock_server
+        .mock("GET", "/alliances/99013534/corporations")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([98787881, 98784257, 98785281]).to_string())
+        .create();
+
+    let result = esi_client
+        .alliance()
+        .get_alliance_corporation_count(99013534)
+        .await;
+
+    // Assert member corporations were requested once
+    mock_corporations.assert();
+
+    // Assert count matches the number of member corporations
+    assert_eq!(result.expect("Failed to get corporation count"), 3);
+}
@@ -123,3 +123,67 @@ authenticated_esi_request_test! {
       }
     ]),
 }
+
+/// Tests every page of a character's assets is retrieved into an asset tree
+///
+/// # Test Setup
+/// - Create mock endpoints for 2 pages of character assets, a ship in a station & its cargo
+///
+/// # Assertions
+/// - Assert both pages were requested
+/// - Assert the cargo on the second page is traced back to the station through the ship
+#[tokio::test]
+async fn test_get_character_asset_tree() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .assets(AssetsScopes::new().read_assets())
+            .build(),
+    );
+
+    let asset = |item_id: i64, location_id: i64, location_flag: &str, location_type: &str| {
+        serde_json::json!({
+            "is_singleton": true,
+            "item_id": item_id,
+            "location_flag": location_flag,
+            "location_id": location_id,
+            "location_type": location_type,
+            "quantity": 1,
+            "type_id": 587
+        })
+    };
+
+    // Create mock endpoints for 2 pages of character assets, a ship in a station & its cargo
+    let mock_page_1 = mock_server
+        .mock("GET", "/characters/2114794365/assets?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(serde_json::json!([asset(1, 60003760, "Hangar", "station")]).to_string())
+        .create();
+    let mock_page_2 = mock_server
+        .mock("GET", "/characters/2114794365/assets?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(serde_json::json!([asset(2, 1, "Cargo", "item")]).to_string())
+        .create();
+
+    let result = esi_client
+        .assets()
+        .get_character_asset_tree(&access_token, 2114794365)
+        .await;
+
+    // Assert both pages were requested
+    mock_jwt_key_endpoint.assert();
+    mock_page_1.assert();
+    mock_page_2.assert();
+
+    let tree = result.expect("Failed to get character asset tree");
+
+    // Assert the cargo on the second page is traced back to the station through the ship
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.root_location_id(2), Some(60003760));
+    assert_eq!(tree.path_to_root(2).len(), 2);
+}
@@ -1,5 +1,6 @@
 use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
 use crate::util::integration_test_setup;
+use eve_esi::model::enums::notification::NotificationType;
 use eve_esi::{scope::CharactersScopes, ScopeBuilder};
 
 public_esi_request_test! {
@@ -261,3 +262,119 @@ authenticated_esi_request_test! {
         "title_id": 1
     }]),
 }
+
+/// Tests retrieving only new notifications of the provided types
+///
+/// # Test Setup
+/// - Create a mock notifications endpoint returning notifications out of order, including one
+///   already seen & one of a type which is not requested
+///
+/// # Assertions
+/// - Assert the notifications endpoint was requested once
+/// - Assert only unseen structure attack notifications are returned, oldest first
+#[tokio::test]
+async fn test_get_new_notifications() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .characters(CharactersScopes::new().read_notifications())
+            .build(),
+    );
+
+    let notification = |id: i64, timestamp: &str, r#type: &str| {
+        serde_json::json!({
+            "is_read": false,
+            "notification_id": id,
+            "sender_id": 1000137,
+            "sender_type": "corporation",
+            "text": "",
+            "timestamp": timestamp,
+            "type": r#type
+        })
+    };
+
+    let mock_notifications = mock_server
+        .mock("GET", "/characters/2114794365/notifications")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                notification(5, "2025-01-01T12:10:00Z", "StructureLostShields"),
+                notification(7, "2025-01-01T12:20:00Z", "WarDeclared"),
+                notification(4, "2025-01-01T12:00:00Z", "StructureUnderAttack"),
+                notification(6, "2025-01-01T12:05:00Z", "StructureUnderAttack"),
+                notification(3, "2025-01-01T11:00:00Z", "StructureUnderAttack"),
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let result = esi_client
+        .character()
+        .get_new_notifications(
+            &access_token,
+            2114794365,
+            Some(3),
+            NotificationType::STRUCTURE_ATTACKS,
+        )
+        .await;
+
+    mock_jwt_key_endpoint.assert();
+    mock_notifications.assert();
+
+    let notifications = result.expect("Failed to get new notifications");
+    let ids: Vec<i64> = notifications
+        .iter()
+        .map(|notification| notification.notification_id)
+        .collect();
+
+    // Assert only unseen structure attack notifications are returned, oldest first
+    assert_eq!(ids, vec![4, 6, 5]);
+}
+
+/// Tests the deprecated research agents route is recorded when used
+///
+/// # Test Setup
+/// - Create a mock research agents endpoint
+///
+/// # Assertions
+/// - Assert the research agents endpoint was requested
+/// - Assert the route is reported as deprecated with a single call
+#[tokio::test]
+async fn test_get_agents_research_deprecated() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .characters(CharactersScopes::new().read_agents_research())
+            .build(),
+    );
+
+    let mock_agents_research = mock_server
+        .mock("GET", "/characters/2114794365/agents_research")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create();
+
+    let result = esi_client
+        .character()
+        .get_agents_research(&access_token, 2114794365)
+        .send()
+        .await;
+
+    // Assert the research agents endpoint was requested
+    mock_jwt_key_endpoint.assert();
+    mock_agents_research.assert();
+    assert!(result.is_ok());
+
+    let report = esi_client.deprecated_routes();
+
+    // Assert the route is reported as deprecated with a single call
+    assert_eq!(report.len(), 1);
+    assert_eq!(report[0].function, "get_agents_research");
+    assert_eq!(report[0].path, "/characters/{}/agents_research");
+    assert_eq!(report[0].deprecated_since, "2025-01-01");
+    assert_eq!(report[0].calls, 1);
+}
@@ -46,3 +46,87 @@ authenticated_esi_request_test! {
       0
     ]),
 }
+
+/// Tests retrieving the active implants of a character classified by slot & attribute bonus
+///
+/// # Test Setup
+/// - Create a mock endpoint for the active implants listing 2 implants
+/// - Create mock endpoints for the item types of both implants
+///
+/// # Assertions
+/// - Assert each endpoint was requested once
+/// - Assert the implants are ordered by slot & their attribute bonuses totalled
+#[tokio::test]
+async fn test_get_active_implant_set() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .clones(ClonesScopes::new().read_implants())
+            .build(),
+    );
+
+    // Create a mock endpoint for the active implants listing 2 implants
+    let mock_implants = mock_server
+        .mock("GET", "/characters/2114794365/implants")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([20121, 10216]).to_string())
+        .expect(1)
+        .create();
+
+    // Create mock endpoints for the item types of both implants
+    let mock_types: Vec<mockito::Mock> = [
+        (20121, "High-grade Crystal Beta", 2.0, 177),
+        (10216, "Ocular Filter - Elite", 1.0, 178),
+    ]
+    .into_iter()
+    .map(|(type_id, name, slot, bonus_attribute_id)| {
+        mock_server
+            .mock("GET", format!("/universe/types/{}", type_id).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "description": "",
+                    "dogma_attributes": [
+                        {"attribute_id": 331, "value": slot},
+                        {"attribute_id": bonus_attribute_id, "value": 4.0}
+                    ],
+                    "group_id": 300,
+                    "name": name,
+                    "published": true,
+                    "type_id": type_id
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create()
+    })
+    .collect();
+
+    let implant_set = esi_client
+        .clones()
+        .get_active_implant_set(&access_token, 2114794365)
+        .await
+        .expect("Failed to retrieve implant set");
+
+    // Assert each endpoint was requested once
+    mock_jwt_key_endpoint.assert();
+    mock_implants.assert();
+    for mock in mock_types {
+        mock.assert();
+    }
+
+    // Assert the implants are ordered by slot & their attribute bonuses totalled
+    let type_ids: Vec<i64> = implant_set
+        .implants
+        .iter()
+        .map(|implant| implant.type_id)
+        .collect();
+    assert_eq!(type_ids, vec![10216, 20121]);
+
+    let bonuses = implant_set.attribute_bonuses();
+    assert_eq!(bonuses.perception, 4);
+    assert_eq!(bonuses.memory, 4);
+}
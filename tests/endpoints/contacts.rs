@@ -1,3 +1,4 @@
+use eve_esi::model::contacts::DesiredContact;
 use eve_esi::scope::{AlliancesScopes, CharactersScopes, CorporationsScopes};
 use eve_esi::ScopeBuilder;
 
@@ -158,3 +159,242 @@ authenticated_esi_request_test! {
       }
     ]),
 }
+
+/// Tests syncing a character's contacts to a desired contact list
+///
+/// # Test Setup
+/// - Create mock endpoints for the current contacts & the delete, edit, & add requests
+///
+/// # Assertions
+/// - Assert each request was made once with only the required contacts
+/// - Assert the executed plan was returned
+#[tokio::test]
+async fn test_sync_contacts() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .characters(CharactersScopes::new().read_contacts().write_contacts())
+            .build(),
+    );
+
+    // Create mock endpoints for the current contacts & the delete, edit, & add requests
+    let mock_contacts = mock_server
+        .mock("GET", "/characters/2114794365/contacts?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"contact_id": 1, "contact_type": "character", "label_ids": [], "standing": 10.0},
+                {"contact_id": 2, "contact_type": "corporation", "label_ids": [], "standing": 5.0},
+                {"contact_id": 3, "contact_type": "alliance", "label_ids": [], "standing": -5.0}
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_delete = mock_server
+        .mock(
+            "DELETE",
+            "/characters/2114794365/contacts?contact_ids=%5B3%5D",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("null")
+        .expect(1)
+        .create();
+    let mock_edit = mock_server
+        .mock(
+            "PUT",
+            "/characters/2114794365/contacts?standing=10.0&label_ids=%5B%5D&watched=false",
+        )
+        .match_body(mockito::Matcher::Json(serde_json::json!([2])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[2]")
+        .expect(1)
+        .create();
+    let mock_add = mock_server
+        .mock(
+            "POST",
+            "/characters/2114794365/contacts?standing=-10.0&label_ids=%5B1%5D&watched=false",
+        )
+        .match_body(mockito::Matcher::Json(serde_json::json!([4])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[4]")
+        .expect(1)
+        .create();
+
+    let desired = vec![
+        DesiredContact {
+            contact_id: 1,
+            standing: 10.0,
+            label_ids: vec![],
+        },
+        DesiredContact {
+            contact_id: 2,
+            standing: 10.0,
+            label_ids: vec![],
+        },
+        DesiredContact {
+            contact_id: 4,
+            standing: -10.0,
+            label_ids: vec![1],
+        },
+    ];
+
+    let result = esi_client
+        .contacts()
+        .sync_contacts(&access_token, 2114794365, &desired)
+        .await;
+
+    // Assert each request was made once with only the required contacts
+    mock_jwt_key_endpoint.assert();
+    mock_contacts.assert();
+    mock_delete.assert();
+    mock_edit.assert();
+    mock_add.assert();
+
+    // Assert the executed plan was returned
+    let plan = result.expect("Failed to sync contacts");
+    assert_eq!(plan.request_count(), 3);
+    assert_eq!(plan.delete, vec![vec![3]]);
+}
+
+/// Tests syncing a character's contacts spanning multiple pages
+///
+/// # Test Setup
+/// - Create mock endpoints for 2 pages of current contacts & the delete request
+///
+/// # Assertions
+/// - Assert both pages of contacts were requested
+/// - Assert only the contact on page 2 missing from the desired contacts was deleted
+/// - Assert desired contacts on page 2 were not added again
+#[tokio::test]
+async fn test_sync_contacts_multiple_pages() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .characters(CharactersScopes::new().read_contacts().write_contacts())
+            .build(),
+    );
+
+    // Create mock endpoints for 2 pages of current contacts & the delete request
+    let mock_page_1 = mock_server
+        .mock("GET", "/characters/2114794365/contacts?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                {"contact_id": 1, "contact_type": "character", "label_ids": [], "standing": 10.0}
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_page_2 = mock_server
+        .mock("GET", "/characters/2114794365/contacts?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                {"contact_id": 2, "contact_type": "corporation", "label_ids": [], "standing": 5.0},
+                {"contact_id": 3, "contact_type": "alliance", "label_ids": [], "standing": -5.0}
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_delete = mock_server
+        .mock(
+            "DELETE",
+            "/characters/2114794365/contacts?contact_ids=%5B3%5D",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("null")
+        .expect(1)
+        .create();
+
+    let desired = vec![
+        DesiredContact {
+            contact_id: 1,
+            standing: 10.0,
+            label_ids: vec![],
+        },
+        DesiredContact {
+            contact_id: 2,
+            standing: 5.0,
+            label_ids: vec![],
+        },
+    ];
+
+    let result = esi_client
+        .contacts()
+        .sync_contacts(&access_token, 2114794365, &desired)
+        .await;
+
+    // Assert both pages of contacts were requested
+    mock_jwt_key_endpoint.assert();
+    mock_page_1.assert();
+    mock_page_2.assert();
+
+    // Assert only the contact on page 2 missing from the desired contacts was deleted
+    mock_delete.assert();
+    let plan = result.expect("Failed to sync contacts");
+    assert_eq!(plan.delete, vec![vec![3]]);
+
+    // Assert desired contacts on page 2 were not added again
+    assert!(plan.add.is_empty());
+    assert_eq!(plan.request_count(), 1);
+}
+
+/// Tests a corporation's contact labels resolve the labels of its contacts
+///
+/// # Test Setup
+/// - Create a mock corporation contact labels endpoint with 2 labels
+///
+/// # Assertions
+/// - Assert the labels were requested once
+/// - Assert the label set resolves label IDs & names
+#[tokio::test]
+async fn test_get_corporation_contact_label_set() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_contacts())
+            .build(),
+    );
+
+    let mock_labels = mock_server
+        .mock("GET", "/corporations/98785281/contacts/labels")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"label_id": 1, "label_name": "Blues"},
+                {"label_id": 2, "label_name": "Reds"}
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .contacts()
+        .get_corporation_contact_label_set(&access_token, 98785281)
+        .await;
+
+    // Assert the labels were requested once
+    mock_jwt_key_endpoint.assert();
+    mock_labels.assert();
+
+    // Assert the label set resolves label IDs & names
+    let labels = result.expect("Failed to get contact label set");
+    assert_eq!(labels.name(2), Some("Reds"));
+    assert_eq!(labels.id("Blues"), Some(1));
+}
@@ -1,3 +1,10 @@
+use eve_esi::endpoints::contracts::Contract;
+use eve_esi::model::contract::PublicContract;
+use eve_esi::model::enums::routes::RouteFlag;
+use eve_esi::scope::UniverseScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
 use crate::util::integration_test_setup;
 
 public_esi_request_test! {
@@ -52,3 +59,374 @@ public_esi_request_test! {
         ]
     )
 }
+
+public_esi_request_test! {
+    get_public_contract_bids,
+    contracts,
+    get_public_contract_bids[1, 1],
+    request_type = "GET",
+    url = "/contracts/public/bids/1?page=1",
+    mock_response = serde_json::json!(
+        [
+          {
+            "amount": 0,
+            "bid_id": 0,
+            "date_bid": "2019-08-24T14:15:22Z"
+          }
+        ]
+    )
+}
+
+/// Creates a public contract of the provided type with the ID `1`
+fn mock_public_contract(r#type: &str) -> PublicContract {
+    serde_json::from_value(serde_json::json!({
+        "contract_id": 1,
+        "date_expired": "2019-08-24T14:15:22Z",
+        "date_issued": "2019-08-24T14:15:22Z",
+        "issuer_corporation_id": 98000001,
+        "issuer_id": 2114794365,
+        "type": r#type
+    }))
+    .unwrap()
+}
+
+/// Tests a contract's items & bids are requested once & reused by later calls
+///
+/// # Test Setup
+/// - Create mock contract items & bids endpoints expecting to be requested once each
+///
+/// # Assertions
+/// - Assert the items & bids are returned by repeated calls
+/// - Assert each endpoint was only requested once
+#[tokio::test]
+async fn test_contract_items_and_bids_memoized() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_items = mock_server
+        .mock("GET", "/contracts/public/items/1?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{"is_included": true, "quantity": 5, "record_id": 1, "type_id": 34}])
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_bids = mock_server
+        .mock("GET", "/contracts/public/bids/1?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{"amount": 1000000.0, "bid_id": 1, "date_bid": "2019-08-24T14:15:22Z"}])
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let contract = Contract::new(mock_public_contract("auction"));
+
+    for _ in 0..2 {
+        let items = contract
+            .items(&esi_client)
+            .await
+            .expect("Failed to get items");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].type_id, 34);
+
+        let bids = contract
+            .bids(&esi_client)
+            .await
+            .expect("Failed to get bids");
+        assert_eq!(bids.len(), 1);
+        assert_eq!(bids[0].bid_id, 1);
+    }
+
+    mock_items.assert();
+    mock_bids.assert();
+}
+
+/// Tests bids are not requested for contracts which are not auctions
+///
+/// # Test Setup
+/// - Create a mock contract bids endpoint expecting no requests
+///
+/// # Assertions
+/// - Assert an item exchange contract returns no bids without making a request
+#[tokio::test]
+async fn test_contract_bids_not_auction() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_bids = mock_server
+        .mock("GET", "/contracts/public/bids/1?page=1")
+        .expect(0)
+        .create();
+
+    let contract = Contract::new(mock_public_contract("item_exchange"));
+    let bids = contract
+        .bids(&esi_client)
+        .await
+        .expect("Failed to get bids");
+
+    assert!(bids.is_empty());
+    mock_bids.assert();
+}
+
+/// Tests resolving & annotating the route of a courier contract from a station to a structure
+///
+/// # Test Setup
+/// - Create mock endpoints for the start station, end structure, route & route solar systems
+///
+/// # Assertions
+/// - Assert each endpoint was requested
+/// - Assert the route is annotated with the security of each solar system in order
+/// - Assert the reward per jump is calculated from the contract's reward
+#[tokio::test]
+async fn test_get_courier_route() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .universe(UniverseScopes::new().read_structures())
+            .build(),
+    );
+
+    let mut contract = mock_public_contract("courier");
+    contract.start_location_id = Some(60003760);
+    contract.end_location_id = Some(1035466617946);
+    contract.reward = Some(eve_esi::model::Isk::from(30_000_000));
+
+    // Create mock endpoints for the start station, end structure, route & route solar systems
+    let mock_station = mock_server
+        .mock("GET", "/universe/stations/60003760")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "max_dockable_ship_volume": 50000000.0,
+                "name": "Jita IV - Moon 4 - Caldari Navy Assembly Plant",
+                "office_rental_cost": 187400.0,
+                "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+                "reprocessing_efficiency": 0.5,
+                "reprocessing_stations_take": 0.05,
+                "station_id": 60003760,
+                "system_id": 30000142,
+                "type_id": 1531
+            })
+            .to_string(),
+        )
+        .create();
+    let mock_structure = mock_server
+        .mock("GET", "/universe/structures/1035466617946")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "name": "Tama - Freeport",
+                "owner_id": 98785281,
+                "solar_system_id": 30002813,
+                "type_id": 35834
+            })
+            .to_string(),
+        )
+        .create();
+    let mock_route = mock_server
+        .mock("GET", "/route/30000142/30002813?flag=shortest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([30000142, 30002812, 30002813]).to_string())
+        .create();
+    let mock_systems: Vec<_> = [(30000142, 0.945913), (30002812, 0.5), (30002813, 0.3)]
+        .into_iter()
+        .map(|(system_id, security_status)| {
+            mock_server
+                .mock("GET", format!("/universe/systems/{}", system_id).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "constellation_id": 20000020,
+                        "name": "System",
+                        "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+                        "security_status": security_status,
+                        "system_id": system_id
+                    })
+                    .to_string(),
+                )
+                .create()
+        })
+        .collect();
+
+    let result = esi_client
+        .contracts()
+        .get_courier_route(&access_token, &contract, RouteFlag::Shortest)
+        .await;
+
+    // Assert each endpoint was requested
+    mock_jwt_key_endpoint.assert();
+    mock_station.assert();
+    mock_structure.assert();
+    mock_route.assert();
+    for mock_system in &mock_systems {
+        mock_system.assert();
+    }
+
+    let route = result
+        .expect("Failed to get courier route")
+        .expect("Expected a courier route");
+
+    // Assert the route is annotated with the security of each solar system in order
+    let system_ids: Vec<i64> = route
+        .systems
+        .iter()
+        .map(|system| system.system_id)
+        .collect();
+    assert_eq!(system_ids, vec![30000142, 30002812, 30002813]);
+    assert_eq!(route.jumps(), 2);
+    assert_eq!(route.lowsec_jumps(), 1);
+    assert_eq!(route.lowsec_segments().len(), 1);
+
+    // Assert the reward per jump is calculated from the contract's reward
+    assert_eq!(
+        route.reward_per_jump(),
+        Some(eve_esi::model::Isk::from(15_000_000))
+    );
+}
+
+/// Tests contracts which are not courier contracts have no route
+///
+/// # Test Setup
+/// - Create a mock route endpoint expecting no requests
+///
+/// # Assertions
+/// - Assert an item exchange contract returns no route without making a request
+#[tokio::test]
+async fn test_get_courier_route_not_courier() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_route = mock_server
+        .mock("GET", mockito::Matcher::Regex("^/route/".to_string()))
+        .expect(0)
+        .create();
+
+    let route = esi_client
+        .contracts()
+        .get_courier_route(
+            "token",
+            &mock_public_contract("item_exchange"),
+            RouteFlag::Shortest,
+        )
+        .await
+        .expect("Failed to get courier route");
+
+    assert!(route.is_none());
+    mock_route.assert();
+}
+
+/// Tests the route is kept without a solar system whose request fails
+///
+/// # Test Setup
+/// - Create mock endpoints for the start & end stations & the route between them
+/// - Create mock endpoints for the route's solar systems, the middle system returning an error
+///
+/// # Assertions
+/// - Assert every solar system along the route was requested
+/// - Assert the route is kept with the solar systems retrieved
+/// - Assert the failed solar system is returned with its ID
+#[tokio::test]
+async fn test_get_courier_route_partial_failure() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mut contract = mock_public_contract("courier");
+    contract.start_location_id = Some(60003760);
+    contract.end_location_id = Some(60004588);
+
+    // Create mock endpoints for the start & end stations & the route between them
+    let _mock_stations: Vec<_> = [(60003760, 30000142), (60004588, 30002813)]
+        .into_iter()
+        .map(|(station_id, system_id)| {
+            mock_server
+                .mock("GET", format!("/universe/stations/{}", station_id).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "max_dockable_ship_volume": 50000000.0,
+                        "name": "Station",
+                        "office_rental_cost": 187400.0,
+                        "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+                        "reprocessing_efficiency": 0.5,
+                        "reprocessing_stations_take": 0.05,
+                        "station_id": station_id,
+                        "system_id": system_id,
+                        "type_id": 1531
+                    })
+                    .to_string(),
+                )
+                .create()
+        })
+        .collect();
+    let _mock_route = mock_server
+        .mock("GET", "/route/30000142/30002813?flag=shortest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([30000142, 30002812, 30002813]).to_string())
+        .create();
+
+    // Create mock endpoints for the route's solar systems, the middle system returning an error
+    let mut mock_systems: Vec<_> = [(30000142, 0.945913), (30002813, 0.3)]
+        .into_iter()
+        .map(|(system_id, security_status)| {
+            mock_server
+                .mock("GET", format!("/universe/systems/{}", system_id).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "constellation_id": 20000020,
+                        "name": "System",
+                        "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+                        "security_status": security_status,
+                        "system_id": system_id
+                    })
+                    .to_string(),
+                )
+                .create()
+        })
+        .collect();
+    mock_systems.push(
+        mock_server
+            .mock("GET", "/universe/systems/30002812")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error": "Solar system not found"}"#)
+            .create(),
+    );
+
+    let result = esi_client
+        .contracts()
+        .get_courier_route("token", &contract, RouteFlag::Shortest)
+        .await;
+
+    // Assert every solar system along the route was requested
+    for mock_system in &mock_systems {
+        mock_system.assert();
+    }
+
+    // Assert the route is kept with the solar systems retrieved
+    let partial = result.expect_err("Expected a partial failure");
+    let route = partial
+        .successes()
+        .as_ref()
+        .expect("Expected a courier route");
+    let system_ids: Vec<i64> = route
+        .systems
+        .iter()
+        .map(|system| system.system_id)
+        .collect();
+    assert_eq!(system_ids, vec![30000142, 30002813]);
+    assert_eq!(partial.attempted(), 3);
+
+    // Assert the failed solar system is returned with its ID
+    assert_eq!(partial.failures().len(), 1);
+    assert_eq!(partial.failures()[0].0, 30002812);
+}
@@ -1,6 +1,11 @@
 use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
 use crate::util::integration_test_setup;
-use eve_esi::scope::{CorporationsScopes, WalletScopes};
+use eve_esi::esi::IconSize;
+use eve_esi::model::corporation::{
+    DividendRounding, SecureContainerLogFilter, StructureState, StructureTimerStage,
+};
+use eve_esi::model::enums::corporation::CorporationSecureContainerAction;
+use eve_esi::scope::{AssetsScopes, CorporationsScopes, WalletScopes};
 use eve_esi::ScopeBuilder;
 
 public_esi_request_test! {
@@ -427,3 +432,594 @@ authenticated_esi_request_test! {
         "title_id": 0
     }]),
 }
+
+/// Tests retrieving the structure dashboard composite
+///
+/// # Test Setup
+/// - Create mock endpoints for both pages of corporation structures & universe names
+///
+/// # Assertions
+/// - Assert all structure pages & names were requested
+/// - Assert structures are sorted by fuel expiration with low power structures first
+/// - Assert names & normalized states were set
+#[tokio::test]
+async fn test_get_structure_dashboard() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_structures())
+            .build(),
+    );
+
+    let structure = |structure_id: i64, fuel_expires: Option<&str>, state: &str| {
+        serde_json::json!({
+            "corporation_id": 98785281,
+            "fuel_expires": fuel_expires,
+            "profile_id": 1,
+            "services": [],
+            "state": state,
+            "state_timer_end": "2018-12-20T16:11:54Z",
+            "structure_id": structure_id,
+            "system_id": 30000142,
+            "type_id": 35832
+        })
+    };
+
+    // Create mock endpoints for both pages of corporation structures & universe names
+    let mock_page_1 = mock_server
+        .mock("GET", "/corporations/98785281/structures?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                structure(1, Some("2018-12-25T00:00:00Z"), "shield_vulnerable"),
+                structure(2, Some("2018-12-21T00:00:00Z"), "armor_reinforce"),
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_page_2 = mock_server
+        .mock("GET", "/corporations/98785281/structures?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(serde_json::json!([structure(3, None, "shield_vulnerable")]).to_string())
+        .create();
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"category": "inventory_type", "id": 35832, "name": "Astrahus"},
+                {"category": "solar_system", "id": 30000142, "name": "Jita"}
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .corporation()
+        .get_structure_dashboard(&access_token, 98785281)
+        .await;
+
+    // Assert all structure pages & names were requested
+    mock_jwt_key_endpoint.assert();
+    mock_page_1.assert();
+    mock_page_2.assert();
+    mock_names.assert();
+
+    let dashboard = result.expect("Failed to get structure dashboard");
+
+    // Assert structures are sorted by fuel expiration with low power structures first
+    let structure_ids: Vec<i64> = dashboard
+        .iter()
+        .map(|entry| entry.structure.structure_id)
+        .collect();
+    assert_eq!(structure_ids, vec![3, 2, 1]);
+
+    // Assert names & normalized states were set
+    assert_eq!(dashboard[0].type_name.as_deref(), Some("Astrahus"));
+    assert_eq!(dashboard[0].system_name.as_deref(), Some("Jita"));
+    assert_eq!(dashboard[0].state, StructureState::Online);
+    assert!(matches!(
+        dashboard[1].state,
+        StructureState::Reinforced {
+            stage: StructureTimerStage::Armor,
+            ends: Some(_)
+        }
+    ));
+}
+
+/// Tests calculating dividends across all pages of corporation shareholders
+///
+/// # Test Setup
+/// - Create mock endpoints for both pages of corporation shareholders
+///
+/// # Assertions
+/// - Assert all shareholder pages were requested
+/// - Assert the dividend was split proportionally to the shares held
+#[tokio::test]
+async fn test_calculate_dividends() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_corporation_wallets())
+            .build(),
+    );
+
+    // Create mock endpoints for both pages of corporation shareholders
+    let mock_page_1 = mock_server
+        .mock("GET", "/corporations/98785281/shareholders?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                {"share_count": 750, "shareholder_id": 2114794365, "shareholder_type": "character"}
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_page_2 = mock_server
+        .mock("GET", "/corporations/98785281/shareholders?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                {"share_count": 250, "shareholder_id": 98785281, "shareholder_type": "corporation"}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let result = esi_client
+        .corporation()
+        .calculate_dividends(
+            &access_token,
+            98785281,
+            1_000_000.0,
+            DividendRounding::LargestRemainder,
+        )
+        .await;
+
+    // Assert all shareholder pages were requested
+    mock_jwt_key_endpoint.assert();
+    mock_page_1.assert();
+    mock_page_2.assert();
+
+    let report = result.expect("Failed to calculate dividends");
+
+    // Assert the dividend was split proportionally to the shares held
+    assert_eq!(report.total_shares, 1000);
+    assert_eq!(report.payouts[0].amount, 750_000.0);
+    assert_eq!(report.payouts[1].amount, 250_000.0);
+    assert_eq!(report.undistributed, 0.0);
+}
+
+/// Tests listing the stations & structures where a corporation rents an office
+///
+/// # Test Setup
+/// - Create mock endpoints for corporation assets with an office in a station & a structure
+/// - Create a mock endpoint for resolving station names
+///
+/// # Assertions
+/// - Assert the assets & names endpoints were requested
+/// - Assert only office assets are returned
+/// - Assert only the station name is resolved
+#[tokio::test]
+async fn test_get_corporation_offices() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .assets(AssetsScopes::new().read_corporation_assets())
+            .build(),
+    );
+
+    // Create mock endpoints for corporation assets with an office in a station & a structure
+    let mock_assets = mock_server
+        .mock("GET", "/corporations/98785281/assets?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "1")
+        .with_body(
+            serde_json::json!([
+                {
+                    "is_singleton": true,
+                    "item_id": 1000000000001_i64,
+                    "location_flag": "OfficeFolder",
+                    "location_id": 60003760,
+                    "location_type": "station",
+                    "quantity": 1,
+                    "type_id": 27
+                },
+                {
+                    "is_singleton": false,
+                    "item_id": 1000000000002_i64,
+                    "location_flag": "CorpSAG1",
+                    "location_id": 1000000000001_i64,
+                    "location_type": "item",
+                    "quantity": 100,
+                    "type_id": 34
+                },
+                {
+                    "is_singleton": true,
+                    "item_id": 1000000000003_i64,
+                    "location_flag": "OfficeFolder",
+                    "location_id": 1035466617946_i64,
+                    "location_type": "item",
+                    "quantity": 1,
+                    "type_id": 27
+                }
+            ])
+            .to_string(),
+        )
+        .create();
+
+    // Create a mock endpoint for resolving station names
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {
+                    "category": "station",
+                    "id": 60003760,
+                    "name": "Jita IV - Moon 4 - Caldari Navy Assembly Plant"
+                }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .corporation()
+        .get_corporation_offices(&access_token, 98785281)
+        .await;
+
+    // Assert the assets & names endpoints were requested
+    mock_jwt_key_endpoint.assert();
+    mock_assets.assert();
+    mock_names.assert();
+
+    let offices = result.expect("Failed to list corporation offices");
+
+    // Assert only office assets are returned
+    assert_eq!(offices.len(), 2);
+    assert_eq!(offices[0].office_id, 1000000000001);
+    assert_eq!(offices[1].location_id, 1035466617946);
+
+    // Assert only the station name is resolved
+    assert_eq!(
+        offices[0].location_name.as_deref(),
+        Some("Jita IV - Moon 4 - Caldari Navy Assembly Plant")
+    );
+    assert_eq!(offices[1].location_name, None);
+}
+
+/// Tests grouping a corporation's assets by hangar division
+///
+/// # Test Setup
+/// - Create mock endpoints for corporation assets in hangar divisions 1 & 3 & an office
+/// - Create a mock endpoint for the divisions with only hangar division 1 named
+///
+/// # Assertions
+/// - Assert the assets & divisions endpoints were requested
+/// - Assert all 7 hangar divisions are returned with names
+/// - Assert assets are grouped by division & the office is not included
+#[tokio::test]
+async fn test_get_corporation_hangar_divisions() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .assets(AssetsScopes::new().read_corporation_assets())
+            .corporations(CorporationsScopes::new().read_divisions())
+            .build(),
+    );
+
+    // Create mock endpoints for corporation assets in hangar divisions 1 & 3 & an office
+    let mock_assets = mock_server
+        .mock("GET", "/corporations/98785281/assets?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "1")
+        .with_body(
+            serde_json::json!([
+                {
+                    "is_singleton": true,
+                    "item_id": 1000000000001_i64,
+                    "location_flag": "OfficeFolder",
+                    "location_id": 60003760,
+                    "location_type": "station",
+                    "quantity": 1,
+                    "type_id": 27
+                },
+                {
+                    "is_singleton": false,
+                    "item_id": 1000000000002_i64,
+                    "location_flag": "CorpSAG1",
+                    "location_id": 1000000000001_i64,
+                    "location_type": "item",
+                    "quantity": 100,
+                    "type_id": 34
+                },
+                {
+                    "is_singleton": false,
+                    "item_id": 1000000000003_i64,
+                    "location_flag": "CorpSAG3",
+                    "location_id": 1000000000001_i64,
+                    "location_type": "item",
+                    "quantity": 50,
+                    "type_id": 35
+                }
+            ])
+            .to_string(),
+        )
+        .create();
+
+    // Create a mock endpoint for the divisions with only hangar division 1 named
+    let mock_divisions = mock_server
+        .mock("GET", "/corporations/98785281/divisions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "hangar": [{ "division": 1, "name": "Minerals" }],
+                "wallet": []
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .corporation()
+        .get_corporation_hangar_divisions(&access_token, 98785281)
+        .await;
+
+    // Assert the assets & divisions endpoints were requested
+    mock_jwt_key_endpoint.assert();
+    mock_assets.assert();
+    mock_divisions.assert();
+
+    let hangars = result.expect("Failed to group corporation assets by hangar division");
+
+    // Assert all 7 hangar divisions are returned with names
+    assert_eq!(hangars.len(), 7);
+    assert_eq!(hangars[0].name, "Minerals");
+    assert_eq!(hangars[2].name, "Hangar Division 3");
+
+    // Assert assets are grouped by division & the office is not included
+    assert_eq!(hangars[0].assets[0].type_id, 34);
+    assert_eq!(hangars[2].assets[0].type_id, 35);
+    assert_eq!(
+        hangars
+            .iter()
+            .map(|hangar| hangar.assets.len())
+            .sum::<usize>(),
+        2
+    );
+}
+
+/// Tests retrieving a snapshot of member roles & titles
+///
+/// # Test Setup
+/// - Create mock endpoints for corporation member roles & titles
+///
+/// # Assertions
+/// - Assert both endpoints were requested
+/// - Assert the snapshot contains the roles & titles of each member
+#[tokio::test]
+async fn test_get_member_access_snapshot() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .corporations(
+                CorporationsScopes::new()
+                    .read_corporation_membership()
+                    .read_titles(),
+            )
+            .build(),
+    );
+
+    // Create mock endpoints for corporation member roles & titles
+    let mock_roles = mock_server
+        .mock("GET", "/corporations/98785281/roles")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{
+                "character_id": 2114794365,
+                "grantable_roles": [],
+                "grantable_roles_at_base": [],
+                "grantable_roles_at_hq": [],
+                "grantable_roles_at_other": [],
+                "roles": ["Director"],
+                "roles_at_base": [],
+                "roles_at_hq": [],
+                "roles_at_other": []
+            }])
+            .to_string(),
+        )
+        .create();
+    let mock_titles = mock_server
+        .mock("GET", "/corporations/98785281/members/titles")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([{"character_id": 2114794365, "titles": [1, 2]}]).to_string())
+        .create();
+
+    let result = esi_client
+        .corporation()
+        .get_member_access_snapshot(&access_token, 98785281)
+        .await;
+
+    // Assert both endpoints were requested
+    mock_jwt_key_endpoint.assert();
+    mock_roles.assert();
+    mock_titles.assert();
+
+    let snapshot = result.expect("Failed to get member access snapshot");
+
+    // Assert the snapshot contains the roles & titles of each member
+    assert_eq!(snapshot.roles.len(), 1);
+    assert_eq!(snapshot.titles[0].titles, vec![1, 2]);
+    assert!(snapshot.diff(&snapshot).is_empty());
+}
+
+/// Tests retrieving a filtered ALSC audit trail with resolved names
+///
+/// # Test Setup
+/// - Create a mock ALSC logs endpoint with entries for 2 containers
+/// - Create a mock universe names endpoint for the character & item type of the matching entry
+///
+/// # Assertions
+/// - Assert only the entry matching the filter is returned
+/// - Assert only the IDs of the matching entry are resolved
+/// - Assert the entry is described with the resolved names
+#[tokio::test]
+async fn test_get_corporation_alsc_audit_trail() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_container_logs())
+            .build(),
+    );
+
+    let log = |container_id: i64, character_id: i64, type_id: i64| {
+        serde_json::json!({
+            "action": "add",
+            "character_id": character_id,
+            "container_id": container_id,
+            "container_type_id": 17363,
+            "location_flag": "Hangar",
+            "location_id": 60003760,
+            "logged_at": "2018-12-20T16:11:54Z",
+            "new_config_bitmask": 0,
+            "old_config_bitmask": 0,
+            "quantity": 5,
+            "type_id": type_id
+        })
+    };
+
+    let mock_logs = mock_server
+        .mock("GET", "/corporations/98785281/containers/logs?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([log(1, 2114794365, 34), log(2, 2117053828, 35)]).to_string())
+        .create();
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .match_body(mockito::Matcher::Json(serde_json::json!([34, 2114794365])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"category": "character", "id": 2114794365, "name": "Bob"},
+                {"category": "inventory_type", "id": 34, "name": "Tritanium"}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let filter = SecureContainerLogFilter::new()
+        .container(1)
+        .action(CorporationSecureContainerAction::Add);
+    let result = esi_client
+        .corporation()
+        .get_corporation_alsc_audit_trail(&access_token, 98785281, &filter)
+        .await;
+
+    mock_jwt_key_endpoint.assert();
+    mock_logs.assert();
+    mock_names.assert();
+
+    let entries = result.expect("Failed to get ALSC audit trail");
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(
+        entries[0].to_string(),
+        "2018-12-20 16:11:54 UTC: Bob added 5 x Tritanium to container 1"
+    );
+}
+
+/// Tests a corporation icon is downloaded & revalidated with its cache headers
+///
+/// # Test Setup
+/// - Create a mock icon endpoint returning URLs on the mock server
+/// - Create a mock image returning PNG bytes with an ETag, & a 304 for requests with that ETag
+///
+/// # Assertions
+/// - Assert the icon of the requested size is downloaded with its content type & ETag
+/// - Assert revalidating the icon with its cache strategy returns not modified
+#[tokio::test]
+async fn test_get_corporation_icon_image() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let url = mock_server.url();
+    let mock_icons = mock_server
+        .mock("GET", "/corporations/98785281/icons")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "px128x128": format!("{}/corporations/98785281/logo?size=128", url),
+                "px256x256": format!("{}/corporations/98785281/logo?size=256", url),
+                "px64x64": format!("{}/corporations/98785281/logo?size=64", url)
+            })
+            .to_string(),
+        )
+        .create();
+    let mock_image = mock_server
+        .mock("GET", "/corporations/98785281/logo?size=128")
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_header("etag", "\"logo-128\"")
+        .with_body([0x89, b'P', b'N', b'G'])
+        .expect(1)
+        .create();
+    let mock_not_modified = mock_server
+        .mock("GET", "/corporations/98785281/logo?size=128")
+        .match_header("if-none-match", "\"logo-128\"")
+        .with_status(304)
+        .expect(1)
+        .create();
+
+    let icon = esi_client
+        .corporation()
+        .get_corporation_icon_image(98785281, IconSize::Px128)
+        .await
+        .expect("Failed to download corporation icon");
+
+    // Assert the icon of the requested size is downloaded with its content type & ETag
+    mock_icons.assert();
+    mock_image.assert();
+    assert_eq!(icon.bytes, vec![0x89, b'P', b'N', b'G']);
+    assert_eq!(icon.content_type, "image/png");
+    assert_eq!(icon.cache.etag, "\"logo-128\"");
+
+    // Assert revalidating the icon with its cache strategy returns not modified
+    let result = esi_client
+        .esi()
+        .download_image(
+            &format!("{}/corporations/98785281/logo?size=128", url),
+            Some(icon.cache_strategy()),
+        )
+        .await
+        .expect("Failed to revalidate corporation icon");
+
+    mock_not_modified.assert();
+    assert!(result.is_not_modified());
+}
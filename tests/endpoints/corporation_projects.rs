@@ -0,0 +1,76 @@
+use eve_esi::model::enums::corporation_projects::CorporationProjectState;
+use eve_esi::scope::CorporationsScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+authenticated_esi_request_test! {
+    list_corporation_projects,
+    corporation_projects,
+    list_corporation_projects[98785281, None, Some("MjAyNS0wNi0wMQ"), Some(50), Some(CorporationProjectState::Active)],
+    request_type = "GET",
+    url = "/corporations/98785281/projects?before=MjAyNS0wNi0wMQ&limit=50&state=Active",
+    required_scopes = ScopeBuilder::new()
+        .corporations(CorporationsScopes::new().read_projects())
+        .build();
+    mock_response = serde_json::json!({
+        "cursor": {
+            "after": "MjAyNS0wNy0wMQ",
+            "before": "MjAyNS0wNS0wMQ"
+        },
+        "projects": [
+            {
+                "id": "4f9a3c52-8d1e-4b7a-9c2f-1e6d5b8a7f30",
+                "last_modified": "2025-06-15T12:00:00Z",
+                "name": "Stock the Jita staging hangar",
+                "progress": {
+                    "current": 1500,
+                    "desired": 5000
+                },
+                "reward": {
+                    "initial": 100000000.0,
+                    "remaining": 70000000.0
+                },
+                "state": "Active"
+            }
+        ]
+    }),
+}
+
+authenticated_esi_request_test! {
+    list_corporation_project_contributors,
+    corporation_projects,
+    list_corporation_project_contributors[98785281, "4f9a3c52-8d1e-4b7a-9c2f-1e6d5b8a7f30", None, None, None],
+    request_type = "GET",
+    url = "/corporations/98785281/projects/4f9a3c52-8d1e-4b7a-9c2f-1e6d5b8a7f30/contributors",
+    required_scopes = ScopeBuilder::new()
+        .corporations(CorporationsScopes::new().read_projects())
+        .build();
+    mock_response = serde_json::json!({
+        "cursor": {
+            "after": "MjAyNS0wNy0wMQ"
+        },
+        "contributors": [
+            {
+                "contributed": 1500,
+                "id": 2114794365,
+                "name": "Hyziri"
+            }
+        ]
+    }),
+}
+
+authenticated_esi_request_test! {
+    get_corporation_project_contribution,
+    corporation_projects,
+    get_corporation_project_contribution[98785281, "4f9a3c52-8d1e-4b7a-9c2f-1e6d5b8a7f30", 2114794365],
+    request_type = "GET",
+    url = "/corporations/98785281/projects/4f9a3c52-8d1e-4b7a-9c2f-1e6d5b8a7f30/contribution/2114794365",
+    required_scopes = ScopeBuilder::new()
+        .corporations(CorporationsScopes::new().read_projects())
+        .build();
+    mock_response = serde_json::json!({
+        "contributed": 1500,
+        "last_modified": "2025-06-15T12:00:00Z"
+    }),
+}
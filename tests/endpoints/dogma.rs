@@ -0,0 +1,146 @@
+use crate::util::integration_test_setup;
+
+/// Creates a mock mutated item response with the provided attribute value
+fn mock_dynamic_item(attribute_value: f64) -> serde_json::Value {
+    serde_json::json!({
+        "created_by": 2114794365,
+        "dogma_attributes": [{"attribute_id": 54, "value": attribute_value}],
+        "dogma_effects": [{"effect_id": 6426, "is_default": false}],
+        "mutator_type_id": 47702,
+        "source_type_id": 526
+    })
+}
+
+public_esi_request_test! {
+    get_dynamic_item_information,
+    dogma,
+    get_dynamic_item_information[47701, 1044416040],
+    request_type = "GET",
+    url = "/dogma/dynamic/items/47701/1044416040",
+    mock_response = mock_dynamic_item(15400.0)
+}
+
+/// Tests merging mutated items with the attributes of their shared source type
+///
+/// # Test Setup
+/// - Create mock endpoints for 2 mutated items of the same source type
+/// - Create a mock endpoint for the source type's information
+///
+/// # Assertions
+/// - Assert the source type is only requested once
+/// - Assert the details are returned in the order provided with base & mutated values
+#[tokio::test]
+async fn test_get_dynamic_item_details() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_item_1 = mock_server
+        .mock("GET", "/dogma/dynamic/items/47701/1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_dynamic_item(15400.0).to_string())
+        .create();
+    let mock_item_2 = mock_server
+        .mock("GET", "/dogma/dynamic/items/47701/2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_dynamic_item(12600.0).to_string())
+        .create();
+    let mock_source_type = mock_server
+        .mock("GET", "/universe/types/526")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "description": "Stasis Webifier I",
+                "dogma_attributes": [{"attribute_id": 54, "value": 14000.0}],
+                "group_id": 65,
+                "name": "Stasis Webifier I",
+                "published": true,
+                "type_id": 526
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .dogma()
+        .get_dynamic_item_details(vec![(47701, 1), (47701, 2)])
+        .await;
+
+    mock_item_1.assert();
+    mock_item_2.assert();
+    mock_source_type.assert();
+
+    let details = result.expect("Failed to get dynamic item details");
+    assert_eq!(details.len(), 2);
+    assert_eq!(details[0].item_id, 1);
+    assert_eq!(details[1].item_id, 2);
+
+    let range = details[1].attribute(54).unwrap();
+    assert_eq!(range.base_value, Some(14000.0));
+    assert_eq!(range.mutated_value, Some(12600.0));
+}
+
+/// Tests the details retrieved are kept when an item's request fails
+///
+/// # Test Setup
+/// - Create a mock endpoint for a mutated item & the information of its source type
+/// - Create a mock endpoint returning an error for a second mutated item
+///
+/// # Assertions
+/// - Assert both items were requested
+/// - Assert the details of the first item are kept
+/// - Assert the failed item is returned with its type & item ID
+#[tokio::test]
+async fn test_get_dynamic_item_details_partial_failure() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_item_1 = mock_server
+        .mock("GET", "/dogma/dynamic/items/47701/1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_dynamic_item(15400.0).to_string())
+        .create();
+    let mock_item_2 = mock_server
+        .mock("GET", "/dogma/dynamic/items/47701/2")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Item not found"}"#)
+        .create();
+    let _mock_source_type = mock_server
+        .mock("GET", "/universe/types/526")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "description": "Stasis Webifier I",
+                "dogma_attributes": [{"attribute_id": 54, "value": 14000.0}],
+                "group_id": 65,
+                "name": "Stasis Webifier I",
+                "published": true,
+                "type_id": 526
+            })
+            .to_string(),
+        )
+        .create();
+
+    let result = esi_client
+        .dogma()
+        .get_dynamic_item_details(vec![(47701, 1), (47701, 2)])
+        .await;
+
+    // Assert both items were requested
+    mock_item_1.assert();
+    mock_item_2.assert();
+
+    // Assert the details of the first item are kept
+    let partial = result.expect_err("Expected a partial failure");
+    assert_eq!(partial.successes().len(), 1);
+    assert_eq!(partial.successes()[0].item_id, 1);
+    assert_eq!(partial.attempted(), 2);
+
+    // Assert the failed item is returned with its type & item ID
+    assert_eq!(partial.failures().len(), 1);
+    assert_eq!(partial.failures()[0].0, (47701, 2));
+}
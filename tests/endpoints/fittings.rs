@@ -0,0 +1,140 @@
+use eve_esi::model::enums::asset::LocationFlag;
+use eve_esi::model::fitting::Fitting;
+use eve_esi::model::requests::{FittingItem, NewFitting};
+use eve_esi::scope::FittingsScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+use crate::util::integration_test_setup;
+
+authenticated_esi_request_test! {
+    get_fittings,
+    fittings,
+    get_fittings[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/fittings",
+    required_scopes = ScopeBuilder::new()
+        .fittings(FittingsScopes::new().read_fittings())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "description": "Mining fit",
+        "fitting_id": 1,
+        "items": [{"flag": "HiSlot0", "quantity": 1, "type_id": 17482}],
+        "name": "Venture",
+        "ship_type_id": 32880
+      }
+    ]),
+}
+
+authenticated_esi_request_test! {
+    create_fitting,
+    fittings,
+    create_fitting[2114794365, NewFitting {
+        description: "Mining fit".to_string(),
+        items: vec![FittingItem {
+            flag: LocationFlag::HiSlot0,
+            quantity: 1,
+            type_id: 17482,
+        }],
+        name: "Venture".to_string(),
+        ship_type_id: 32880,
+    }],
+    request_type = "POST",
+    url = "/characters/2114794365/fittings",
+    required_scopes = ScopeBuilder::new()
+        .fittings(FittingsScopes::new().write_fittings())
+        .build();
+    mock_response = serde_json::json!({
+        "fitting_id": 1
+    }),
+}
+
+/// Tests calculating the CPU, powergrid, & calibration usage of a fitting
+///
+/// # Test Setup
+/// - Create mock endpoints for the item type information of a ship & a module
+/// - Create a fitting with the module fitted twice & once in cargo
+///
+/// # Assertions
+/// - Assert each item type was requested once
+/// - Assert the usage only includes fitted modules
+#[tokio::test]
+async fn test_calculate_fitting_stats() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let item_type = |type_id: i64, attributes: serde_json::Value| {
+        serde_json::json!({
+            "description": "",
+            "dogma_attributes": attributes,
+            "group_id": 0,
+            "name": "",
+            "published": true,
+            "type_id": type_id
+        })
+        .to_string()
+    };
+
+    // Create mock endpoints for the item type information of a ship & a module
+    let mock_ship = mock_server
+        .mock("GET", "/universe/types/32880")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(item_type(
+            32880,
+            serde_json::json!([
+                {"attribute_id": 48, "value": 150.0},
+                {"attribute_id": 11, "value": 30.0}
+            ]),
+        ))
+        .expect(1)
+        .create();
+    let mock_module = mock_server
+        .mock("GET", "/universe/types/17482")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(item_type(
+            17482,
+            serde_json::json!([
+                {"attribute_id": 50, "value": 60.0},
+                {"attribute_id": 30, "value": 2.0}
+            ]),
+        ))
+        .expect(1)
+        .create();
+
+    // Create a fitting with the module fitted twice & once in cargo
+    let item = |flag: LocationFlag| FittingItem {
+        flag,
+        quantity: 1,
+        type_id: 17482,
+    };
+    let fitting = Fitting {
+        description: "Mining fit".to_string(),
+        fitting_id: 1,
+        items: vec![
+            item(LocationFlag::HiSlot0),
+            item(LocationFlag::HiSlot1),
+            item(LocationFlag::Cargo),
+        ],
+        name: "Venture".to_string(),
+        ship_type_id: 32880,
+    };
+
+    let result = esi_client
+        .fittings()
+        .calculate_fitting_stats(&fitting)
+        .await;
+
+    // Assert each item type was requested once
+    mock_ship.assert();
+    mock_module.assert();
+
+    let stats = result.expect("Failed to calculate fitting stats");
+
+    // Assert the usage only includes fitted modules
+    assert_eq!(stats.cpu_used, 120.0);
+    assert_eq!(stats.cpu_output, 150.0);
+    assert_eq!(stats.powergrid_used, 4.0);
+    assert!(stats.is_within_limits());
+}
@@ -0,0 +1,23 @@
+use eve_esi::model::enums::fleet::FleetRole;
+use eve_esi::model::requests::FleetInvitation;
+use eve_esi::scope::FleetsScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+authenticated_esi_request_test! {
+    create_fleet_invitation,
+    fleets,
+    create_fleet_invitation[1234567890, FleetInvitation {
+        character_id: 2114794365,
+        role: FleetRole::SquadMember,
+        squad_id: None,
+        wing_id: None,
+    }],
+    request_type = "POST",
+    url = "/fleets/1234567890/members",
+    required_scopes = ScopeBuilder::new()
+        .fleets(FleetsScopes::new().write_fleet())
+        .build();
+    mock_response = serde_json::json!(()),
+}
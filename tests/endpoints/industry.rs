@@ -0,0 +1,169 @@
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+use crate::util::integration_test_setup;
+use eve_esi::model::enums::industry::IndustryActivity;
+use eve_esi::model::industry::BlueprintMaterial;
+use eve_esi::model::Isk;
+use eve_esi::scope::IndustryScopes;
+use eve_esi::ScopeBuilder;
+
+authenticated_esi_request_test! {
+    moon_extraction_timers,
+    industry,
+    moon_extraction_timers[98785281],
+    request_type = "GET",
+    url = "/corporation/98785281/mining/extractions",
+    required_scopes = ScopeBuilder::new()
+        .industry(IndustryScopes::new().read_corporation_mining())
+        .build();
+    mock_response = serde_json::json!([{
+        "chunk_arrival_time": "2018-12-20T16:11:54Z",
+        "extraction_start_time": "2018-12-06T16:11:54Z",
+        "moon_id": 40009082,
+        "natural_decay_time": "2018-12-20T19:11:54Z",
+        "structure_id": 1000000000001_i64
+    }]),
+}
+
+authenticated_esi_request_test! {
+    corporation_mining_observers,
+    industry,
+    corporation_mining_observers[98785281, 1],
+    request_type = "GET",
+    url = "/corporation/98785281/mining/observers?page=1",
+    required_scopes = ScopeBuilder::new()
+        .industry(IndustryScopes::new().read_corporation_mining())
+        .build();
+    mock_response = serde_json::json!([{
+        "last_updated": "2018-12-20",
+        "observer_id": 1000000000001_i64,
+        "observer_type": "structure"
+    }]),
+}
+
+authenticated_esi_request_test! {
+    observed_corporation_mining,
+    industry,
+    observed_corporation_mining[98785281, 1000000000001, 1],
+    request_type = "GET",
+    url = "/corporation/98785281/mining/observers/1000000000001?page=1",
+    required_scopes = ScopeBuilder::new()
+        .industry(IndustryScopes::new().read_corporation_mining())
+        .build();
+    mock_response = serde_json::json!([{
+        "character_id": 2114794365,
+        "last_updated": "2018-12-20",
+        "quantity": 500,
+        "recorded_corporation_id": 98785281,
+        "type_id": 45490
+    }]),
+}
+
+public_esi_request_test! {
+    list_solar_system_cost_indices,
+    industry,
+    list_solar_system_cost_indices[],
+    request_type = "GET",
+    url = "/industry/systems",
+    mock_response = serde_json::json!([
+      {
+        "cost_indices": [
+          {"activity": "manufacturing", "cost_index": 0.0412},
+          {"activity": "reaction", "cost_index": 0.0014}
+        ],
+        "solar_system_id": 30000142
+      }
+    ])
+}
+
+/// Tests estimating the install cost of a manufacturing job from cost indices & adjusted prices
+///
+/// # Test Setup
+/// - Create mock endpoints for the solar system cost indices & market prices
+///
+/// # Assertions
+/// - Assert both endpoints were requested
+/// - Assert the estimate uses the solar system's manufacturing cost index & adjusted prices
+/// - Assert a solar system without cost indices returns no estimate
+#[tokio::test]
+async fn test_estimate_job_cost() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    // Create mock endpoints for the solar system cost indices & market prices
+    let mock_systems = mock_server
+        .mock("GET", "/industry/systems")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {
+                    "cost_indices": [
+                        {"activity": "manufacturing", "cost_index": 0.5},
+                        {"activity": "reaction", "cost_index": 0.25}
+                    ],
+                    "solar_system_id": 30000142
+                }
+            ])
+            .to_string(),
+        )
+        .expect(2)
+        .create();
+    let mock_prices = mock_server
+        .mock("GET", "/markets/prices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"adjusted_price": 4.0, "average_price": 5.0, "type_id": 34},
+                {"adjusted_price": 20.0, "type_id": 35}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let materials = vec![
+        BlueprintMaterial {
+            type_id: 34,
+            quantity: 100,
+        },
+        BlueprintMaterial {
+            type_id: 35,
+            quantity: 10,
+        },
+    ];
+
+    let estimate = esi_client
+        .industry()
+        .estimate_job_cost(
+            30000142,
+            IndustryActivity::Manufacturing,
+            &materials,
+            2,
+            0.0,
+        )
+        .await;
+    let missing = esi_client
+        .industry()
+        .estimate_job_cost(
+            30002187,
+            IndustryActivity::Manufacturing,
+            &materials,
+            2,
+            0.0,
+        )
+        .await;
+
+    // Assert both endpoints were requested
+    mock_systems.assert();
+    mock_prices.assert();
+
+    // Assert the estimate uses the solar system's manufacturing cost index & adjusted prices
+    let estimate = estimate
+        .expect("Failed to estimate job cost")
+        .expect("Expected a job cost estimate");
+    assert_eq!(estimate.estimated_item_value, Isk::from(1_200));
+    assert_eq!(estimate.system_cost, Isk::from(600));
+    assert!(estimate.unpriced_type_ids.is_empty());
+
+    // Assert a solar system without cost indices returns no estimate
+    assert!(missing.expect("Failed to estimate job cost").is_none());
+}
@@ -0,0 +1,81 @@
+use eve_esi::model::enums::insurance::InsuranceLevel;
+use eve_esi::model::killmail::Killmail;
+use eve_esi::model::Isk;
+
+use crate::util::integration_test_setup;
+
+/// Creates a mock insurance prices response for a Rifter
+fn mock_insurance_prices() -> serde_json::Value {
+    serde_json::json!([
+      {
+        "levels": [
+          { "cost": 0.0, "name": "Basic", "payout": 40000.0 },
+          { "cost": 60000.0, "name": "Platinum", "payout": 200000.0 }
+        ],
+        "type_id": 587
+      }
+    ])
+}
+
+public_esi_request_test! {
+    list_insurance_levels,
+    insurance,
+    list_insurance_levels[],
+    request_type = "GET",
+    url = "/insurance/prices",
+    mock_response = mock_insurance_prices()
+}
+
+/// Tests the insurance payout is estimated for the victim's ship of a killmail
+///
+/// # Test Setup
+/// - Create a mock endpoint returning the insurance prices of a Rifter
+/// - Create a killmail of a lost Rifter
+///
+/// # Assertions
+/// - Assert the insurance prices were requested
+/// - Assert the estimate uses the payout & cost of the requested level
+/// - Assert no estimate is returned for an insurance level not available for the Rifter
+#[tokio::test]
+async fn test_estimate_insurance_payout() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock = mock_server
+        .mock("GET", "/insurance/prices")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_insurance_prices().to_string())
+        .expect(2)
+        .create();
+
+    let killmail: Killmail = serde_json::from_value(serde_json::json!({
+      "attackers": [],
+      "killmail_id": 81646519,
+      "killmail_time": "2018-02-23T14:31:32Z",
+      "solar_system_id": 30000142,
+      "victim": {
+        "character_id": 2117905894,
+        "damage_taken": 1500,
+        "ship_type_id": 587
+      }
+    }))
+    .unwrap();
+
+    let estimate = esi_client
+        .insurance()
+        .estimate_insurance_payout(&killmail, InsuranceLevel::Platinum)
+        .await
+        .unwrap()
+        .unwrap();
+    let unavailable = esi_client
+        .insurance()
+        .estimate_insurance_payout(&killmail, InsuranceLevel::Gold)
+        .await
+        .unwrap();
+
+    mock.assert();
+    assert_eq!(estimate.ship_type_id, 587);
+    assert_eq!(estimate.payout, Isk::from(200000));
+    assert_eq!(estimate.net_payout(), Isk::from(140000));
+    assert_eq!(unavailable, None);
+}
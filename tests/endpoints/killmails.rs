@@ -0,0 +1,251 @@
+use std::time::Duration;
+
+use eve_esi::{scope::KillmailsScopes, ScopeBuilder};
+use futures_util::StreamExt;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+use crate::util::integration_test_setup;
+
+/// Creates a mock killmail response with the provided killmail ID
+fn mock_killmail(killmail_id: i64) -> serde_json::Value {
+    serde_json::json!({
+      "attackers": [
+        {
+          "character_id": 2114794365,
+          "corporation_id": 98785281,
+          "damage_done": 1500,
+          "final_blow": true,
+          "security_status": 5.0,
+          "ship_type_id": 587,
+          "weapon_type_id": 2873
+        }
+      ],
+      "killmail_id": killmail_id,
+      "killmail_time": "2018-02-23T14:31:32Z",
+      "solar_system_id": 30000142,
+      "victim": {
+        "character_id": 2117905894,
+        "corporation_id": 98000001,
+        "damage_taken": 1500,
+        "items": [
+          {
+            "flag": 27,
+            "item_type_id": 2873,
+            "quantity_destroyed": 1,
+            "singleton": 0
+          }
+        ],
+        "position": {
+          "x": 0.0,
+          "y": 0.0,
+          "z": 0.0
+        },
+        "ship_type_id": 670
+      }
+    })
+}
+
+public_esi_request_test! {
+    get_killmail,
+    killmails,
+    get_killmail[81646519, "5d8d2d3d9f3ec8e5f2e2a7c2a6e3c6a8b0f3d2a1"],
+    request_type = "GET",
+    url = "/killmails/81646519/5d8d2d3d9f3ec8e5f2e2a7c2a6e3c6a8b0f3d2a1",
+    mock_response = mock_killmail(81646519)
+}
+
+authenticated_esi_request_test! {
+    get_character_recent_killmails,
+    killmails,
+    get_character_recent_killmails[2114794365, 1],
+    request_type = "GET",
+    url = "/characters/2114794365/killmails/recent?page=1",
+    required_scopes = ScopeBuilder::new()
+        .killmails(KillmailsScopes::new().read_killmails())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "killmail_hash": "string",
+        "killmail_id": 0
+      }
+    ]),
+}
+
+authenticated_esi_request_test! {
+    get_corporation_recent_killmails,
+    killmails,
+    get_corporation_recent_killmails[98785281, 1],
+    request_type = "GET",
+    url = "/corporations/98785281/killmails/recent?page=1",
+    required_scopes = ScopeBuilder::new()
+        .killmails(KillmailsScopes::new().read_corporation_killmails())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "killmail_hash": "string",
+        "killmail_id": 0
+      }
+    ]),
+}
+
+/// Tests that the corporation killmail feed yields only unseen killmails, oldest first
+///
+/// # Test Setup
+/// - Create a mock endpoint for recent killmails listing 3 killmails
+/// - Create mock endpoints for the details of the 2 killmails which were not seen
+///
+/// # Assertions
+/// - Assert the recent killmails & both unseen killmails were requested
+/// - Assert the seen killmail was not requested
+/// - Assert the killmails are yielded from oldest to newest
+#[tokio::test]
+async fn test_corporation_killmail_feed() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .killmails(KillmailsScopes::new().read_corporation_killmails())
+            .build(),
+    );
+
+    // Create a mock endpoint for recent killmails listing 3 killmails
+    let mock_recent = mock_server
+        .mock("GET", "/corporations/98785281/killmails/recent?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"recent\"")
+        .with_body(
+            serde_json::json!([
+                { "killmail_hash": "hash3", "killmail_id": 3 },
+                { "killmail_hash": "hash1", "killmail_id": 1 },
+                { "killmail_hash": "hash2", "killmail_id": 2 }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    // Create mock endpoints for the details of the unseen killmails
+    let mock_killmails: Vec<mockito::Mock> = [("1", "hash1"), ("3", "hash3")]
+        .iter()
+        .map(|(id, hash)| {
+            mock_server
+                .mock("GET", format!("/killmails/{}/{}", id, hash).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(mock_killmail(id.parse().unwrap()).to_string())
+                .expect(1)
+                .create()
+        })
+        .collect();
+    let mock_seen = mock_server
+        .mock("GET", "/killmails/2/hash2")
+        .expect(0)
+        .create();
+
+    let feed = esi_client.killmails().corporation_killmail_feed(
+        &access_token,
+        98785281,
+        Duration::from_secs(300),
+        [2],
+    );
+
+    let killmails: Vec<_> = feed.take(2).collect().await;
+
+    // Assert only the recent killmails & unseen killmails were requested
+    mock_jwt_key_endpoint.assert();
+    mock_recent.assert();
+    mock_seen.assert();
+    for mock in mock_killmails {
+        mock.assert();
+    }
+
+    // Assert the killmails are yielded from oldest to newest
+    let ids: Vec<i64> = killmails
+        .into_iter()
+        .map(|killmail| killmail.expect("Failed to retrieve killmail").killmail_id)
+        .collect();
+    assert_eq!(ids, vec![1, 3]);
+}
+
+/// Tests that a killmail feed using a store skips stored killmails & records yielded killmails
+///
+/// # Test Setup
+/// - Create a store containing 1 of the 2 recent killmails
+/// - Create a mock endpoint for recent killmails listing 2 killmails
+/// - Create a mock endpoint for the details of the killmail which was not stored
+///
+/// # Assertions
+/// - Assert the stored killmail was not requested
+/// - Assert the yielded killmail was recorded in the store
+#[tokio::test]
+async fn test_corporation_killmail_feed_with_store() {
+    use std::sync::Arc;
+
+    use eve_esi::endpoints::killmails::{MemorySeenStore, SeenStore};
+
+    let (esi_client, mut mock_server, _mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .killmails(KillmailsScopes::new().read_corporation_killmails())
+            .build(),
+    );
+
+    // Create a store containing 1 of the 2 recent killmails
+    let store = Arc::new(MemorySeenStore::from_ids([1]));
+
+    // Create a mock endpoint for recent killmails listing 2 killmails
+    let mock_recent = mock_server
+        .mock("GET", "/corporations/98785281/killmails/recent?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                { "killmail_hash": "hash1", "killmail_id": 1 },
+                { "killmail_hash": "hash2", "killmail_id": 2 }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    // Create a mock endpoint for the details of the killmail which was not stored
+    let mock_killmail = mock_server
+        .mock("GET", "/killmails/2/hash2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_killmail(2).to_string())
+        .expect(1)
+        .create();
+    let mock_stored = mock_server
+        .mock("GET", "/killmails/1/hash1")
+        .expect(0)
+        .create();
+
+    let feed = esi_client.killmails().corporation_killmail_feed_with_store(
+        &access_token,
+        98785281,
+        Duration::from_secs(300),
+        store.clone(),
+    );
+
+    let killmails: Vec<_> = feed.take(1).collect().await;
+
+    mock_recent.assert();
+    mock_killmail.assert();
+
+    // Assert the stored killmail was not requested
+    mock_stored.assert();
+    assert_eq!(
+        killmails[0]
+            .as_ref()
+            .expect("Failed to retrieve killmail")
+            .killmail_id,
+        2
+    );
+
+    // Assert the yielded killmail was recorded in the store
+    assert!(store.contains(2).await.unwrap());
+    assert_eq!(store.len(), 2);
+}
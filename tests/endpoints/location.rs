@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use eve_esi::model::location::OnlineStatusEvent;
+use eve_esi::{scope::LocationScopes, ScopeBuilder};
+use futures_util::StreamExt;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+/// Creates a mock online status response
+fn mock_online_status(online: bool) -> serde_json::Value {
+    serde_json::json!({
+      "last_login": "2025-10-14T18:00:00Z",
+      "last_logout": "2025-10-14T16:00:00Z",
+      "logins": 1000,
+      "online": online
+    })
+}
+
+authenticated_esi_request_test! {
+    get_character_location,
+    location,
+    get_character_location[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/location",
+    required_scopes = ScopeBuilder::new()
+        .location(LocationScopes::new().read_location())
+        .build();
+    mock_response = serde_json::json!({
+      "solar_system_id": 30000142,
+      "station_id": 60003760
+    }),
+}
+
+authenticated_esi_request_test! {
+    get_character_online,
+    location,
+    get_character_online[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/online",
+    required_scopes = ScopeBuilder::new()
+        .location(LocationScopes::new().read_online())
+        .build();
+    mock_response = mock_online_status(true),
+}
+
+authenticated_esi_request_test! {
+    get_character_ship,
+    location,
+    get_character_ship[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/ship",
+    required_scopes = ScopeBuilder::new()
+        .location(LocationScopes::new().read_ship_type())
+        .build();
+    mock_response = serde_json::json!({
+      "ship_item_id": 1000000016991_i64,
+      "ship_name": "Autumn's Rifter",
+      "ship_type_id": 587
+    }),
+}
+
+/// Tests retrieving a character's location, ship & online status with resolved names
+///
+/// # Test Setup
+/// - Create mock location, ship & online status endpoints for a character docked in a station
+/// - Create a mock names endpoint resolving the solar system, station & ship type
+///
+/// # Assertions
+/// - Assert each expected request was made once
+/// - Assert the location, ship & online status are returned with resolved names
+#[tokio::test]
+async fn test_get_character_presence() {
+    let (esi_client, mut mock_server, _mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token =
+        mock_access_token_with_scopes(ScopeBuilder::new().location(LocationScopes::all()).build());
+
+    // Create mock location, ship & online status endpoints for a character docked in a station
+    let mock_location = mock_server
+        .mock("GET", "/characters/2114794365/location")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({ "solar_system_id": 30000142, "station_id": 60003760 }).to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_ship = mock_server
+        .mock("GET", "/characters/2114794365/ship")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "ship_item_id": 1000000016991_i64,
+                "ship_name": "Autumn's Rifter",
+                "ship_type_id": 587
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_online = mock_server
+        .mock("GET", "/characters/2114794365/online")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_online_status(true).to_string())
+        .expect(1)
+        .create();
+
+    // Create a mock names endpoint resolving the solar system, station & ship type
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                { "category": "solar_system", "id": 30000142, "name": "Jita" },
+                {
+                    "category": "station",
+                    "id": 60003760,
+                    "name": "Jita IV - Moon 4 - Caldari Navy Assembly Plant"
+                },
+                { "category": "inventory_type", "id": 587, "name": "Rifter" }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let presence = esi_client
+        .location()
+        .get_character_presence(&access_token, 2114794365)
+        .await
+        .expect("Failed to retrieve character presence");
+
+    // Assert each expected request was made once
+    mock_location.assert();
+    mock_ship.assert();
+    mock_online.assert();
+    mock_names.assert();
+
+    // Assert the location, ship & online status are returned with resolved names
+    assert_eq!(presence.character_id, 2114794365);
+    assert_eq!(presence.solar_system_name, "Jita");
+    assert_eq!(
+        presence.station_name.as_deref(),
+        Some("Jita IV - Moon 4 - Caldari Navy Assembly Plant")
+    );
+    assert_eq!(presence.ship.ship_name, "Autumn's Rifter");
+    assert_eq!(presence.ship_type_name, "Rifter");
+    assert!(presence.is_online());
+    assert!(presence.is_docked());
+}
+
+/// Tests the online status feed yields status changes & polls offline characters less often
+///
+/// # Test Setup
+/// - Create mock endpoints for an online & an offline character
+/// - Poll both with a short online interval & a long offline interval
+/// - Change the online character's mock endpoint to offline after the initial events
+///
+/// # Assertions
+/// - Assert the initial status of both characters is yielded
+/// - Assert the online character logging out is yielded
+/// - Assert the offline character was only polled once
+#[tokio::test]
+async fn test_online_status_feed() {
+    let (esi_client, mut mock_server, _mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .location(LocationScopes::new().read_online())
+            .build(),
+    );
+
+    let mock_online = mock_server
+        .mock("GET", "/characters/1/online")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_online_status(true).to_string())
+        .create();
+    let mock_offline = mock_server
+        .mock("GET", "/characters/2/online")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_online_status(false).to_string())
+        .expect(1)
+        .create();
+
+    let feed = esi_client.location().online_status_feed(
+        [(1, &access_token), (2, &access_token)],
+        Duration::from_millis(10),
+        Duration::from_secs(3600),
+    );
+    let mut feed = Box::pin(feed);
+
+    // Assert the initial status of both characters is yielded
+    let first = feed.next().await.unwrap().unwrap();
+    let second = feed.next().await.unwrap().unwrap();
+    assert!(matches!(
+        first,
+        OnlineStatusEvent::Online {
+            character_id: 1,
+            ..
+        }
+    ));
+    assert!(matches!(
+        second,
+        OnlineStatusEvent::Offline {
+            character_id: 2,
+            ..
+        }
+    ));
+
+    // Log the online character out, creating the new mock first so no poll goes unanswered
+    let mock_logged_out = mock_server
+        .mock("GET", "/characters/1/online")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_online_status(false).to_string())
+        .expect(1)
+        .create();
+    mock_online.remove();
+
+    let logout = tokio::time::timeout(Duration::from_secs(5), feed.next())
+        .await
+        .expect("Logout was not yielded")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(logout.character_id(), 1);
+    assert!(!logout.status().online);
+    mock_logged_out.assert();
+    mock_offline.assert();
+}
@@ -0,0 +1,26 @@
+use eve_esi::model::enums::mail::MailRecipientType;
+use eve_esi::model::requests::{MailRecipient, NewMail};
+use eve_esi::scope::MailScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+authenticated_esi_request_test! {
+    send_mail,
+    mail,
+    send_mail[2114794365, NewMail {
+        approved_cost: 0,
+        body: "Fleet forming in Jita".to_string(),
+        recipients: vec![MailRecipient {
+            recipient_id: 98785281,
+            recipient_type: MailRecipientType::Corporation,
+        }],
+        subject: "Fleet".to_string(),
+    }],
+    request_type = "POST",
+    url = "/characters/2114794365/mail",
+    required_scopes = ScopeBuilder::new()
+        .mail(MailScopes::new().send_mail())
+        .build();
+    mock_response = serde_json::json!(13),
+}
@@ -215,7 +215,7 @@ public_esi_request_test! {
     market,
     list_orders_in_a_region[1, OrderType::All, 1],
     request_type = "GET",
-    url = "/markets/1/orders?order_type=%22all%22&page=1",
+    url = "/markets/1/orders?order_type=all&page=1",
     mock_response = serde_json::json!([
       {
         "duration": 0,
@@ -242,3 +242,195 @@ public_esi_request_test! {
     url = "/markets/1/types?page=1",
     mock_response = serde_json::json!([0])
 }
+
+/// Creates a mock market order of Tritanium or Pyerite at Jita 4-4
+fn mock_market_order(
+    order_id: i64,
+    type_id: i64,
+    is_buy_order: bool,
+    price: f64,
+) -> serde_json::Value {
+    serde_json::json!({
+        "duration": 90,
+        "is_buy_order": is_buy_order,
+        "is_corporation": false,
+        "issued": "2025-01-14T09:57:18Z",
+        "location_id": 60003760,
+        "min_volume": 1,
+        "order_id": order_id,
+        "price": price,
+        "range": "region",
+        "region_id": 10000002,
+        "system_id": 30000142,
+        "type_id": type_id,
+        "volume_remain": 1000,
+        "volume_total": 1000
+    })
+}
+
+/// Tests a character's open orders are compared against the current orders of their region & type
+///
+/// # Test Setup
+/// - Create a mock character orders endpoint with a Tritanium sell order & a Pyerite buy order
+/// - Create mock regional orders endpoints for each type, undercutting only the Tritanium order
+///
+/// # Assertions
+/// - Assert the regional orders of each type were requested once
+/// - Assert only the undercut Tritanium order is returned with the best competing price
+#[tokio::test]
+async fn test_get_undercut_character_orders() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .markets(MarketsScopes::new().read_character_orders())
+            .build(),
+    );
+
+    let mock_character_orders = mock_server
+        .mock("GET", "/characters/2114794365/orders")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                mock_market_order(1, 34, false, 5.0),
+                mock_market_order(2, 35, true, 10.0)
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_tritanium_orders = mock_server
+        .mock(
+            "GET",
+            "/markets/10000002/orders?order_type=all&type_id=34&page=1",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "1")
+        .with_body(
+            serde_json::json!([
+                mock_market_order(1, 34, false, 5.0),
+                mock_market_order(3, 34, false, 4.5),
+                mock_market_order(4, 34, false, 4.9)
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_pyerite_orders = mock_server
+        .mock(
+            "GET",
+            "/markets/10000002/orders?order_type=all&type_id=35&page=1",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "1")
+        .with_body(
+            serde_json::json!([
+                mock_market_order(2, 35, true, 10.0),
+                mock_market_order(5, 35, true, 9.0)
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .market()
+        .get_undercut_character_orders(&access_token, 2114794365)
+        .await;
+
+    // Assert the regional orders of each type were requested once
+    mock_jwt_key_endpoint.assert();
+    mock_character_orders.assert();
+    mock_tritanium_orders.assert();
+    mock_pyerite_orders.assert();
+
+    // Assert only the undercut Tritanium order is returned with the best competing price
+    let undercut = result.expect("Failed to get undercut orders");
+    assert_eq!(undercut.len(), 1);
+    assert_eq!(undercut[0].order_id, 1);
+    assert_eq!(undercut[0].competing_order_id, 3);
+}
+
+/// Tests the undercut orders found are kept when the regional orders of one type fail
+///
+/// # Test Setup
+/// - Create a mock character orders endpoint with a Tritanium sell order & a Pyerite buy order
+/// - Create a mock regional orders endpoint for Tritanium undercutting the Tritanium order
+/// - Create a mock regional orders endpoint for Pyerite returning an error
+///
+/// # Assertions
+/// - Assert the regional orders of each type were requested
+/// - Assert the undercut Tritanium order is kept
+/// - Assert the Pyerite order is returned as a failure with its order ID
+#[tokio::test]
+async fn test_get_undercut_character_orders_partial_failure() {
+    let (esi_client, mut mock_server, _mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .markets(MarketsScopes::new().read_character_orders())
+            .build(),
+    );
+
+    let _mock_character_orders = mock_server
+        .mock("GET", "/characters/2114794365/orders")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                mock_market_order(1, 34, false, 5.0),
+                mock_market_order(2, 35, true, 10.0)
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_tritanium_orders = mock_server
+        .mock(
+            "GET",
+            "/markets/10000002/orders?order_type=all&type_id=34&page=1",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "1")
+        .with_body(
+            serde_json::json!([
+                mock_market_order(1, 34, false, 5.0),
+                mock_market_order(3, 34, false, 4.5)
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_pyerite_orders = mock_server
+        .mock(
+            "GET",
+            "/markets/10000002/orders?order_type=all&type_id=35&page=1",
+        )
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Type not found"}"#)
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .market()
+        .get_undercut_character_orders(&access_token, 2114794365)
+        .await;
+
+    // Assert the regional orders of each type were requested
+    mock_tritanium_orders.assert();
+    mock_pyerite_orders.assert();
+
+    // Assert the undercut Tritanium order is kept
+    let partial = result.expect_err("Expected a partial failure");
+    assert_eq!(partial.successes().len(), 1);
+    assert_eq!(partial.successes()[0].order_id, 1);
+    assert_eq!(partial.attempted(), 2);
+
+    // Assert the Pyerite order is returned as a failure with its order ID
+    assert_eq!(partial.failures().len(), 1);
+    assert_eq!(partial.failures()[0].0, 2);
+}
@@ -9,6 +9,21 @@ mod clones;
 mod contacts;
 mod contract;
 mod corporation;
+mod corporation_projects;
+mod dogma;
+mod fittings;
+mod fleets;
+mod industry;
+mod insurance;
+mod killmails;
+mod location;
+mod mail;
 mod market;
+mod planetary_interaction;
+mod routes;
+mod search;
+mod skills;
+mod sovereignty;
 mod universe;
 mod util;
+mod wallet;
@@ -0,0 +1,71 @@
+use eve_esi::scope::PlanetsScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+authenticated_esi_request_test! {
+    get_colonies,
+    planetary_interaction,
+    get_colonies[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/planets",
+    required_scopes = ScopeBuilder::new()
+        .planets(PlanetsScopes::new().manage_planets())
+        .build();
+    mock_response = serde_json::json!([{
+        "last_update": "2018-12-20T16:11:54Z",
+        "num_pins": 12,
+        "owner_id": 2114794365,
+        "planet_id": 40161469,
+        "planet_type": "barren",
+        "solar_system_id": 30002541,
+        "upgrade_level": 5
+    }]),
+}
+
+authenticated_esi_request_test! {
+    get_colony_layout,
+    planetary_interaction,
+    get_colony_layout[2114794365, 40161469],
+    request_type = "GET",
+    url = "/characters/2114794365/planets/40161469",
+    required_scopes = ScopeBuilder::new()
+        .planets(PlanetsScopes::new().manage_planets())
+        .build();
+    mock_response = serde_json::json!({
+        "links": [{
+            "destination_pin_id": 2,
+            "link_level": 0,
+            "source_pin_id": 1
+        }],
+        "pins": [{
+            "expiry_time": "2018-12-21T16:11:54Z",
+            "extractor_details": {
+                "cycle_time": 7200,
+                "head_radius": 0.0138,
+                "heads": [{"head_id": 0, "latitude": 1.58, "longitude": 3.12}],
+                "product_type_id": 2268,
+                "qty_per_cycle": 6000
+            },
+            "install_time": "2018-12-20T16:11:54Z",
+            "last_cycle_start": "2018-12-20T16:11:54Z",
+            "latitude": 1.58,
+            "longitude": 3.12,
+            "pin_id": 1,
+            "type_id": 2848
+        }, {
+            "contents": [{"amount": 3000, "type_id": 2268}],
+            "latitude": 1.6,
+            "longitude": 3.1,
+            "pin_id": 2,
+            "type_id": 2541
+        }],
+        "routes": [{
+            "content_type_id": 2268,
+            "destination_pin_id": 2,
+            "quantity": 3000.0,
+            "route_id": 1,
+            "source_pin_id": 1
+        }]
+    }),
+}
@@ -0,0 +1,84 @@
+use eve_esi::model::enums::routes::RouteFlag;
+
+use crate::util::integration_test_setup;
+
+public_esi_request_test! {
+    get_route,
+    routes,
+    get_route[30000142, 30002187, RouteFlag::Secure],
+    request_type = "GET",
+    url = "/route/30000142/30002187?flag=secure",
+    mock_response = serde_json::json!([30000142, 30000144, 30002187])
+}
+
+/// Tests that a route report combines the route with system kills & sovereignty
+///
+/// # Test Setup
+/// - Setup a basic EVE ESI client & mock HTTP server
+/// - Create mock endpoints for the route, system kills, & sovereignty map
+///
+/// # Assertions
+/// - Assert each endpoint was requested once
+/// - Assert kills & sovereignty were attached to the systems along the route
+/// - Assert systems without kills or sovereignty have counts of zero & no holder
+#[tokio::test]
+async fn test_get_route_report() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_route = mock_server
+        .mock("GET", "/route/30002813/30004759?flag=insecure")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([30002813, 30002814, 30004759]).to_string())
+        .create();
+    let mock_kills = mock_server
+        .mock("GET", "/universe/system_kills")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"npc_kills": 0, "pod_kills": 2, "ship_kills": 7, "system_id": 30002813},
+                {"npc_kills": 40, "pod_kills": 0, "ship_kills": 1, "system_id": 30004759}
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_sovereignty = mock_server
+        .mock("GET", "/sovereignty/map")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"faction_id": 500003, "system_id": 30002813},
+                {"alliance_id": 99003214, "corporation_id": 98388312, "system_id": 30004759}
+            ])
+            .to_string(),
+        )
+        .create();
+
+    let result = esi_client
+        .routes()
+        .get_route_report(30002813, 30004759, RouteFlag::Insecure)
+        .await;
+
+    // Assert each endpoint was requested once
+    mock_route.assert();
+    mock_kills.assert();
+    mock_sovereignty.assert();
+
+    let report = result.expect("Failed to get route report");
+
+    // Assert kills & sovereignty were attached to the systems along the route
+    assert_eq!(report.jumps(), 2);
+    assert_eq!(report.total_kills(), 10);
+    assert_eq!(report.systems[0].ship_kills, 7);
+    assert_eq!(report.systems[0].faction_id, Some(500003));
+    assert_eq!(report.systems[2].alliance_id, Some(99003214));
+    assert_eq!(report.dangerous_systems(5).len(), 1);
+
+    // Assert systems without kills or sovereignty have counts of zero & no holder
+    assert_eq!(report.systems[1].ship_kills, 0);
+    assert_eq!(report.systems[1].pod_kills, 0);
+    assert_eq!(report.systems[1].alliance_id, None);
+    assert_eq!(report.systems[1].faction_id, None);
+}
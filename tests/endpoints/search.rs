@@ -0,0 +1,234 @@
+use eve_esi::model::enums::search::SearchCategory;
+use eve_esi::model::search::SearchResult;
+use eve_esi::{scope::SearchScopes, ScopeBuilder};
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+use crate::util::integration_test_setup;
+
+authenticated_esi_request_test! {
+    character_search,
+    search,
+    character_search[2114794365, "character,solar_system", "Jita", Some(true)],
+    request_type = "GET",
+    url = "/characters/2114794365/search?categories=character%2Csolar_system&search=Jita&strict=true",
+    required_scopes = ScopeBuilder::new()
+        .search(SearchScopes::new().search_structures())
+        .build();
+    mock_response = serde_json::json!({
+        "character": [2114794365],
+        "solar_system": [30000142]
+    }),
+}
+
+/// Tests resolving the characters, corporations & solar systems matched by a search
+///
+/// # Test Setup
+/// - Create a search result matching a character, corporation, solar system & region
+/// - Create mock endpoints for the character's name & affiliation
+/// - Create mock endpoints for the corporation & solar system information
+///
+/// # Assertions
+/// - Assert each expected request was made once
+/// - Assert the character is resolved with its name & affiliation
+/// - Assert the corporation & solar system are resolved by ID
+#[tokio::test]
+async fn test_resolve_search_result() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    // Create a search result matching a character, corporation, solar system & region
+    let result = SearchResult {
+        character: vec![2114794365],
+        corporation: vec![98785281],
+        solar_system: vec![30000142],
+        region: vec![10000002],
+        ..Default::default()
+    };
+
+    // Create mock endpoints for the character's name & affiliation
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                { "category": "character", "id": 2114794365, "name": "Hyziri" }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_affiliation = mock_server
+        .mock("POST", "/characters/affiliation")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {
+                    "alliance_id": 99013534,
+                    "character_id": 2114794365,
+                    "corporation_id": 98785281
+                }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    // Create mock endpoints for the corporation & solar system information
+    let mock_corporation = mock_server
+        .mock("GET", "/corporations/98785281")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "alliance_id": 99013534,
+                "ceo_id": 2114794365,
+                "creator_id": 2114794365,
+                "date_founded": "2024-10-07T21:43:09Z",
+                "description": "",
+                "home_station_id": 60003760,
+                "member_count": 21,
+                "name": "The Order of Autumn",
+                "shares": 1000,
+                "tax_rate": 0.0,
+                "ticker": "F4LL.",
+                "url": "https://autumn-order.com",
+                "war_eligible": true
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_system = mock_server
+        .mock("GET", "/universe/systems/30000142")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "constellation_id": 20000020,
+                "name": "Jita",
+                "position": { "x": 0.0, "y": 0.0, "z": 0.0 },
+                "security_class": "B",
+                "security_status": 0.945913,
+                "star_id": 40009076,
+                "system_id": 30000142
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let resolved = esi_client
+        .search()
+        .resolve_search_result(&result)
+        .await
+        .expect("Failed to resolve search result");
+
+    // Assert each expected request was made once
+    mock_names.assert();
+    mock_affiliation.assert();
+    mock_corporation.assert();
+    mock_system.assert();
+
+    // Assert the character is resolved with its name & affiliation
+    let character = &resolved.characters[&2114794365];
+    assert_eq!(character.name, "Hyziri");
+    assert_eq!(character.affiliation.corporation_id, 98785281);
+
+    // Assert the corporation & solar system are resolved by ID
+    assert_eq!(resolved.corporations[&98785281].name, "The Order of Autumn");
+    assert_eq!(resolved.solar_systems[&30000142].name, "Jita");
+}
+
+/// Tests the matches resolved are kept when the request of one corporation fails
+///
+/// # Test Setup
+/// - Create a search result matching 2 corporations & a solar system
+/// - Create a mock endpoint for the first corporation & the solar system
+/// - Create a mock endpoint returning an error for the second corporation
+///
+/// # Assertions
+/// - Assert every corporation & the solar system were requested
+/// - Assert the first corporation & the solar system are kept
+/// - Assert the failed corporation is returned with its category & ID
+#[tokio::test]
+async fn test_resolve_search_result_partial_failure() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    // Create a search result matching 2 corporations & a solar system
+    let result = SearchResult {
+        corporation: vec![98785281, 98000001],
+        solar_system: vec![30000142],
+        ..Default::default()
+    };
+
+    // Create a mock endpoint for the first corporation & the solar system
+    let mock_corporation = mock_server
+        .mock("GET", "/corporations/98785281")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "ceo_id": 2114794365,
+                "creator_id": 2114794365,
+                "description": "",
+                "member_count": 21,
+                "name": "The Order of Autumn",
+                "shares": 1000,
+                "tax_rate": 0.0,
+                "ticker": "F4LL."
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_system = mock_server
+        .mock("GET", "/universe/systems/30000142")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "constellation_id": 20000020,
+                "name": "Jita",
+                "position": { "x": 0.0, "y": 0.0, "z": 0.0 },
+                "security_status": 0.945913,
+                "star_id": 40009076,
+                "system_id": 30000142
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    // Create a mock endpoint returning an error for the second corporation
+    let mock_failed_corporation = mock_server
+        .mock("GET", "/corporations/98000001")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Corporation not found"}"#)
+        .expect(1)
+        .create();
+
+    let result = esi_client.search().resolve_search_result(&result).await;
+
+    // Assert every corporation & the solar system were requested
+    mock_corporation.assert();
+    mock_failed_corporation.assert();
+    mock_system.assert();
+
+    // Assert the first corporation & the solar system are kept
+    let partial = result.expect_err("Expected a partial failure");
+    assert_eq!(
+        partial.successes().corporations[&98785281].name,
+        "The Order of Autumn"
+    );
+    assert_eq!(partial.successes().solar_systems[&30000142].name, "Jita");
+    assert_eq!(partial.attempted(), 3);
+
+    // Assert the failed corporation is returned with its category & ID
+    assert_eq!(partial.failures().len(), 1);
+    assert_eq!(
+        partial.failures()[0].0,
+        (SearchCategory::Corporation, 98000001)
+    );
+}
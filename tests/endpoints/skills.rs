@@ -0,0 +1,46 @@
+use eve_esi::scope::SkillsScopes;
+use eve_esi::ScopeBuilder;
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+authenticated_esi_request_test! {
+    get_character_attributes,
+    skills,
+    get_character_attributes[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/attributes",
+    required_scopes = ScopeBuilder::new()
+        .skills(SkillsScopes::new().read_skills())
+        .build();
+    mock_response = serde_json::json!({
+        "accrued_remap_cooldown_date": "2018-12-20T16:11:54Z",
+        "bonus_remaps": 1,
+        "charisma": 19,
+        "intelligence": 20,
+        "last_remap_date": "2017-12-20T16:11:54Z",
+        "memory": 20,
+        "perception": 27,
+        "willpower": 21
+    }),
+}
+
+authenticated_esi_request_test! {
+    get_character_skill_queue,
+    skills,
+    get_character_skill_queue[2114794365],
+    request_type = "GET",
+    url = "/characters/2114794365/skillqueue",
+    required_scopes = ScopeBuilder::new()
+        .skills(SkillsScopes::new().read_skillqueue())
+        .build();
+    mock_response = serde_json::json!([{
+        "finish_date": "2018-12-20T16:11:54Z",
+        "finished_level": 5,
+        "level_end_sp": 256000,
+        "level_start_sp": 45255,
+        "queue_position": 0,
+        "skill_id": 3300,
+        "start_date": "2018-12-15T16:11:54Z",
+        "training_start_sp": 50000
+    }]),
+}
@@ -0,0 +1,156 @@
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+use crate::util::integration_test_setup;
+use eve_esi::model::corporation::StructureTimerStage;
+use eve_esi::model::sovereignty::TimerKind;
+use eve_esi::scope::CorporationsScopes;
+use eve_esi::ScopeBuilder;
+
+public_esi_request_test! {
+    list_sovereignty_of_systems,
+    sovereignty,
+    list_sovereignty_of_systems[],
+    request_type = "GET",
+    url = "/sovereignty/map",
+    mock_response = serde_json::json!([
+      {
+        "alliance_id": 99003214,
+        "corporation_id": 98388312,
+        "system_id": 30004759
+      },
+      {
+        "faction_id": 500001,
+        "system_id": 30000142
+      }
+    ])
+}
+
+public_esi_request_test! {
+    list_sovereignty_structures,
+    sovereignty,
+    list_sovereignty_structures[],
+    request_type = "GET",
+    url = "/sovereignty/structures",
+    mock_response = serde_json::json!([
+      {
+        "alliance_id": 498125261,
+        "solar_system_id": 30000570,
+        "structure_id": 1018253388776_i64,
+        "structure_type_id": 32226,
+        "vulnerability_occupancy_level": 2,
+        "vulnerable_end_time": "2016-10-29T05:30:00Z",
+        "vulnerable_start_time": "2016-10-28T20:30:00Z"
+      }
+    ])
+}
+
+/// Tests listing the upcoming sovereignty & structure timers of a corporation
+///
+/// # Test Setup
+/// - Create mock endpoints for the corporation, sovereignty structures & corporation structures
+/// - Return sovereignty structures of the corporation's alliance & another alliance
+///
+/// # Assertions
+/// - Assert the corporation, sovereignty structures & corporation structures were requested
+/// - Assert only the alliance's sovereignty structures are included, sorted chronologically
+#[tokio::test]
+async fn test_get_upcoming_timers() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .corporations(CorporationsScopes::new().read_structures())
+            .build(),
+    );
+
+    let sovereignty_structure = |alliance_id: i64, structure_id: i64| {
+        serde_json::json!({
+            "alliance_id": alliance_id,
+            "solar_system_id": 30000570,
+            "structure_id": structure_id,
+            "structure_type_id": 32226,
+            "vulnerable_end_time": "2999-01-02T04:00:00Z",
+            "vulnerable_start_time": "2999-01-02T00:00:00Z"
+        })
+    };
+
+    // Create mock endpoints for the corporation, sovereignty structures & corporation structures
+    let mock_corporation = mock_server
+        .mock("GET", "/corporations/98785281")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "alliance_id": 99013534,
+                "ceo_id": 2114794365,
+                "creator_id": 2114794365,
+                "member_count": 21,
+                "name": "The Order of Autumn",
+                "tax_rate": 0.0,
+                "ticker": "F4LL."
+            })
+            .to_string(),
+        )
+        .create();
+    let mock_sovereignty = mock_server
+        .mock("GET", "/sovereignty/structures")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                sovereignty_structure(99013534, 1),
+                sovereignty_structure(498125261, 2),
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_structures = mock_server
+        .mock("GET", "/corporations/98785281/structures?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "1")
+        .with_body(
+            serde_json::json!([{
+                "corporation_id": 98785281,
+                "name": "Test Structure",
+                "profile_id": 1,
+                "services": [],
+                "state": "armor_reinforce",
+                "state_timer_end": "2999-01-01T18:00:00Z",
+                "structure_id": 3,
+                "system_id": 30000142,
+                "type_id": 35832
+            }])
+            .to_string(),
+        )
+        .create();
+
+    let result = esi_client
+        .sovereignty()
+        .get_upcoming_timers(&access_token, 98785281)
+        .await;
+
+    // Assert the corporation, sovereignty structures & corporation structures were requested
+    mock_jwt_key_endpoint.assert();
+    mock_corporation.assert();
+    mock_sovereignty.assert();
+    mock_structures.assert();
+
+    let timers = result.expect("Failed to get upcoming timers");
+
+    // Assert only the alliance's sovereignty structures are included, sorted chronologically
+    let structure_ids: Vec<i64> = timers
+        .iter()
+        .map(|timer| timer.structure.structure_id)
+        .collect();
+    assert_eq!(structure_ids, vec![3, 1]);
+    assert_eq!(
+        timers[0].kind,
+        TimerKind::Reinforcement {
+            stage: StructureTimerStage::Armor
+        }
+    );
+    assert!(matches!(
+        timers[1].kind,
+        TimerKind::SovereigntyVulnerability { ends: Some(_) }
+    ));
+}
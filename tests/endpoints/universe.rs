@@ -1,3 +1,9 @@
+use eve_esi::model::asset::Asset;
+use eve_esi::model::enums::universe::UniverseNameCategory;
+use eve_esi::model::universe::{Entity, StructureName};
+use eve_esi::{scope::UniverseScopes, ScopeBuilder};
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
 use crate::util::integration_test_setup;
 
 public_esi_request_test! {
@@ -21,3 +27,822 @@ public_esi_request_test! {
       }
     ])
 }
+
+public_esi_request_test! {
+    get_names_and_categories,
+    universe,
+    get_names_and_categories[vec![30000142, 35832]],
+    request_type = "POST",
+    url = "/universe/names",
+    mock_response = serde_json::json!([
+      {
+        "category": "solar_system",
+        "id": 30000142,
+        "name": "Jita"
+      },
+      {
+        "category": "inventory_type",
+        "id": 35832,
+        "name": "Astrahus"
+      }
+    ])
+}
+
+public_esi_request_test! {
+    get_system_kills,
+    universe,
+    get_system_kills[],
+    request_type = "GET",
+    url = "/universe/system_kills",
+    mock_response = serde_json::json!([
+      {
+        "npc_kills": 120,
+        "pod_kills": 2,
+        "ship_kills": 5,
+        "system_id": 30000142
+      }
+    ])
+}
+
+public_esi_request_test! {
+    get_system_jumps,
+    universe,
+    get_system_jumps[],
+    request_type = "GET",
+    url = "/universe/system_jumps",
+    mock_response = serde_json::json!([
+      {
+        "ship_jumps": 3500,
+        "system_id": 30000142
+      }
+    ])
+}
+
+public_esi_request_test! {
+    get_solar_systems,
+    universe,
+    get_solar_systems[],
+    request_type = "GET",
+    url = "/universe/systems",
+    mock_response = serde_json::json!([30000142, 30000144])
+}
+
+public_esi_request_test! {
+    get_solar_system_information,
+    universe,
+    get_solar_system_information[30000142],
+    request_type = "GET",
+    url = "/universe/systems/30000142",
+    mock_response = serde_json::json!({
+        "constellation_id": 20000020,
+        "name": "Jita",
+        "planets": [{"planet_id": 40009077, "asteroid_belts": [40009078]}],
+        "position": {"x": -129064861735000000.0, "y": 60755306910000000.0, "z": 117469227060000000.0},
+        "security_class": "B",
+        "security_status": 0.945913,
+        "star_id": 40009076,
+        "stargates": [50001248],
+        "stations": [60003760],
+        "system_id": 30000142
+    })
+}
+
+public_esi_request_test! {
+    get_station_information,
+    universe,
+    get_station_information[60003760],
+    request_type = "GET",
+    url = "/universe/stations/60003760",
+    mock_response = serde_json::json!({
+        "max_dockable_ship_volume": 50000000.0,
+        "name": "Jita IV - Moon 4 - Caldari Navy Assembly Plant",
+        "office_rental_cost": 187400.0,
+        "owner": 1000035,
+        "position": {"x": -107302625280.0, "y": -18745221120.0, "z": 436489789440.0},
+        "race_id": 1,
+        "reprocessing_efficiency": 0.5,
+        "reprocessing_stations_take": 0.05,
+        "services": ["market", "reprocessing-plant"],
+        "station_id": 60003760,
+        "system_id": 30000142,
+        "type_id": 1531
+    })
+}
+
+public_esi_request_test! {
+    get_stargate_information,
+    universe,
+    get_stargate_information[50001248],
+    request_type = "GET",
+    url = "/universe/stargates/50001248",
+    mock_response = serde_json::json!({
+        "destination": {"stargate_id": 50000802, "system_id": 30000144},
+        "name": "Stargate (Perimeter)",
+        "position": {"x": 4517571747840.0, "y": 733253959680.0, "z": -1224443658240.0},
+        "stargate_id": 50001248,
+        "system_id": 30000142,
+        "type_id": 29635
+    })
+}
+
+public_esi_request_test! {
+    get_item_type_information,
+    universe,
+    get_item_type_information[587],
+    request_type = "GET",
+    url = "/universe/types/587",
+    mock_response = serde_json::json!({
+        "capacity": 135.0,
+        "description": "The Rifter is a very powerful combat frigate.",
+        "dogma_attributes": [{"attribute_id": 48, "value": 130.0}],
+        "dogma_effects": [{"effect_id": 1862, "is_default": false}],
+        "graphic_id": 46,
+        "group_id": 25,
+        "icon_id": 587,
+        "market_group_id": 64,
+        "mass": 1067000.0,
+        "name": "Rifter",
+        "packaged_volume": 2500.0,
+        "portion_size": 1,
+        "published": true,
+        "radius": 31.0,
+        "type_id": 587,
+        "volume": 27289.0
+    })
+}
+
+/// Tests retrieving system activity combined with system names & security status
+///
+/// # Test Setup
+/// - Create mock endpoints for system kills, system jumps, & information for 2 solar systems
+///
+/// # Assertions
+/// - Assert each endpoint was requested once
+/// - Assert activity was combined with system information
+/// - Assert systems without activity have counts of zero
+#[tokio::test]
+async fn test_get_system_activity() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let system = |system_id: i64, name: &str, security_status: f64| {
+        serde_json::json!({
+            "constellation_id": 20000020,
+            "name": name,
+            "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+            "security_status": security_status,
+            "system_id": system_id
+        })
+        .to_string()
+    };
+
+    // Create mock endpoints for system kills, system jumps, & information for 2 solar systems
+    let mock_kills = mock_server
+        .mock("GET", "/universe/system_kills")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"npc_kills": 0, "pod_kills": 3, "ship_kills": 4, "system_id": 30002187}
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_jumps = mock_server
+        .mock("GET", "/universe/system_jumps")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"ship_jumps": 250, "system_id": 30002187}
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_amarr = mock_server
+        .mock("GET", "/universe/systems/30002187")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(system(30002187, "Amarr", 1.0))
+        .create();
+    let mock_tama = mock_server
+        .mock("GET", "/universe/systems/30002813")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(system(30002813, "Tama", 0.3))
+        .create();
+
+    let result = esi_client
+        .universe()
+        .get_system_activity(&[30002187, 30002813])
+        .await;
+
+    // Assert each endpoint was requested once
+    mock_kills.assert();
+    mock_jumps.assert();
+    mock_amarr.assert();
+    mock_tama.assert();
+
+    let activity = result.expect("Failed to get system activity");
+
+    // Assert activity was combined with system information
+    assert_eq!(activity[0].name, "Amarr");
+    assert_eq!(activity[0].security_status, 1.0);
+    assert_eq!(activity[0].ship_kills, 4);
+    assert_eq!(activity[0].pod_kills, 3);
+    assert_eq!(activity[0].ship_jumps, 250);
+
+    // Assert systems without activity have counts of zero
+    assert_eq!(activity[1].name, "Tama");
+    assert_eq!(activity[1].ship_kills, 0);
+    assert_eq!(activity[1].ship_jumps, 0);
+}
+
+/// Tests resolved names are cached by the client & reused by later requests
+///
+/// # Test Setup
+/// - Create a mock universe names endpoint expecting to be requested once for each set of IDs
+///
+/// # Assertions
+/// - Assert the second resolution only requests the ID which was not yet cached
+/// - Assert the cached & newly resolved names are both returned
+/// - Assert the names are held by the client's name cache
+#[tokio::test]
+async fn test_resolve_names_cached() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_first = mock_server
+        .mock("POST", "/universe/names")
+        .match_body(mockito::Matcher::Json(serde_json::json!([30000142])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{"category": "solar_system", "id": 30000142, "name": "Jita"}])
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_second = mock_server
+        .mock("POST", "/universe/names")
+        .match_body(mockito::Matcher::Json(serde_json::json!([30002187])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{"category": "solar_system", "id": 30002187, "name": "Amarr"}])
+                .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let universe = esi_client.universe();
+    universe
+        .resolve_names(vec![30000142])
+        .await
+        .expect("Failed to resolve names");
+    let names = universe
+        .resolve_names(vec![30000142, 30002187])
+        .await
+        .expect("Failed to resolve names");
+
+    // Assert each set of uncached IDs was requested once
+    mock_first.assert();
+    mock_second.assert();
+
+    assert_eq!(names.get(&30000142).map(String::as_str), Some("Jita"));
+    assert_eq!(names.get(&30002187).map(String::as_str), Some("Amarr"));
+    assert_eq!(esi_client.name_cache().len(), 2);
+}
+
+/// Tests the faction lookup is requested once & cached on the client
+///
+/// # Test Setup
+/// - Create a mock factions endpoint expecting to be requested once
+/// - Retrieve the faction lookup & a faction from a clone of the client
+///
+/// # Assertions
+/// - Assert the factions endpoint was only requested once
+/// - Assert factions are mapped by ID & unknown IDs return `None`
+/// - Assert the faction names are held by the client's name cache
+#[tokio::test]
+async fn test_get_faction_lookup_cached() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_factions = mock_server
+        .mock("GET", "/universe/factions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{
+                "corporation_id": 1000035,
+                "description": "The Caldari State",
+                "faction_id": 500001,
+                "is_unique": true,
+                "militia_corporation_id": 1000180,
+                "name": "Caldari State",
+                "size_factor": 5.0,
+                "solar_system_id": 30000145,
+                "station_count": 1503,
+                "station_system_count": 503
+            }])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let factions = esi_client
+        .universe()
+        .get_faction_lookup()
+        .await
+        .expect("Failed to retrieve factions");
+    let faction = esi_client
+        .clone()
+        .universe()
+        .get_faction(500001)
+        .await
+        .expect("Failed to retrieve faction");
+    let unknown = esi_client
+        .universe()
+        .get_faction(1)
+        .await
+        .expect("Failed to retrieve faction");
+
+    // Assert the factions endpoint was only requested once
+    mock_factions.assert();
+
+    assert_eq!(factions.len(), 1);
+    assert_eq!(faction.unwrap().militia_corporation_id, Some(1000180));
+    assert!(unknown.is_none());
+    assert_eq!(
+        esi_client.name_cache().get(500001).map(|entry| entry.name),
+        Some("Caldari State".to_string())
+    );
+}
+
+/// Tests determining the category of IDs & retrieving their public information
+///
+/// # Test Setup
+/// - Create a mock universe names endpoint resolving a character & a solar system
+/// - Create a mock endpoint for the character's public information
+///
+/// # Assertions
+/// - Assert the character ID returns the character's information
+/// - Assert the solar system ID returns `None`
+#[tokio::test]
+async fn test_entity() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    // Create mock endpoints resolving the category of each ID
+    let mock_character_name = mock_server
+        .mock("POST", "/universe/names")
+        .match_body(mockito::Matcher::Json(serde_json::json!([2114794365])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{"category": "character", "id": 2114794365, "name": "Hyziri"}])
+                .to_string(),
+        )
+        .create();
+    let mock_system_name = mock_server
+        .mock("POST", "/universe/names")
+        .match_body(mockito::Matcher::Json(serde_json::json!([30000142])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{"category": "solar_system", "id": 30000142, "name": "Jita"}])
+                .to_string(),
+        )
+        .create();
+    let mock_character = mock_server
+        .mock("GET", "/characters/2114794365")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "alliance_id": 99013534,
+                "birthday": "2018-12-20T16:11:54Z",
+                "bloodline_id": 7,
+                "corporation_id": 98785281,
+                "description": "description",
+                "faction_id": null,
+                "gender": "male",
+                "name": "Hyziri",
+                "race_id": 8,
+                "security_status": -0.100373643,
+                "title": "Title",
+            })
+            .to_string(),
+        )
+        .create();
+
+    let character = esi_client.entity(2114794365).await;
+    let system = esi_client.entity(30000142).await;
+
+    // Assert each endpoint was requested once
+    mock_character_name.assert();
+    mock_system_name.assert();
+    mock_character.assert();
+
+    // Assert the character's information was retrieved
+    let character = character
+        .expect("Failed to get entity")
+        .expect("Character ID should be an entity");
+    assert!(matches!(character, Entity::Character { .. }));
+    assert_eq!(character.id(), 2114794365);
+    assert_eq!(character.name(), "Hyziri");
+
+    // Assert IDs of other categories are not entities
+    assert_eq!(system.expect("Failed to get entity"), None);
+}
+
+authenticated_esi_request_test! {
+    get_structure_information,
+    universe,
+    get_structure_information[1035466617946],
+    request_type = "GET",
+    url = "/universe/structures/1035466617946",
+    required_scopes = ScopeBuilder::new()
+        .universe(UniverseScopes::new().read_structures())
+        .build();
+    mock_response = serde_json::json!({
+      "name": "Perimeter - Tranquility Trading Tower",
+      "owner_id": 98785281,
+      "position": {
+        "x": 0.0,
+        "y": 0.0,
+        "z": 0.0
+      },
+      "solar_system_id": 30000144,
+      "type_id": 35834
+    }),
+}
+
+/// Tests resolving structure names where some structures are inaccessible
+///
+/// # Test Setup
+/// - Create a mock endpoint for an accessible structure
+/// - Create mock endpoints returning 403 & 404 for inaccessible structures
+///
+/// # Assertions
+/// - Assert each structure endpoint was requested once despite the duplicate ID
+/// - Assert the accessible structure's name is resolved
+/// - Assert the inaccessible structures are marked forbidden & unknown
+#[tokio::test]
+async fn test_resolve_structure_names() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .universe(UniverseScopes::new().read_structures())
+            .build(),
+    );
+
+    // Create a mock endpoint for an accessible structure
+    let mock_resolved = mock_server
+        .mock("GET", "/universe/structures/1035466617946")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "name": "Perimeter - Tranquility Trading Tower",
+                "owner_id": 98785281,
+                "solar_system_id": 30000144
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    // Create mock endpoints for inaccessible structures
+    let mock_forbidden = mock_server
+        .mock("GET", "/universe/structures/1035466617947")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Forbidden"}"#)
+        .expect(1)
+        .create();
+    let mock_unknown = mock_server
+        .mock("GET", "/universe/structures/1035466617948")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Structure not found"}"#)
+        .expect(1)
+        .create();
+
+    let result = esi_client
+        .universe()
+        .resolve_structure_names(
+            &access_token,
+            vec![1035466617946, 1035466617947, 1035466617948, 1035466617946],
+        )
+        .await;
+
+    // Assert each structure endpoint was requested once
+    mock_jwt_key_endpoint.assert();
+    mock_resolved.assert();
+    mock_forbidden.assert();
+    mock_unknown.assert();
+
+    let names = result.expect("Failed to resolve structure names");
+
+    // Assert accessible structures are resolved & inaccessible structures are marked
+    assert_eq!(
+        names[&1035466617946].name(),
+        Some("Perimeter - Tranquility Trading Tower")
+    );
+    assert_eq!(names[&1035466617947], StructureName::Forbidden);
+    assert_eq!(names[&1035466617948], StructureName::Unknown);
+}
+
+/// Tests crawling the universe graph, resuming from a partially crawled graph
+///
+/// # Test Setup
+/// - Create a graph already containing solar system 30000142
+/// - Create mock endpoints for 2 further solar systems connected by a stargate pair
+///
+/// # Assertions
+/// - Assert the already crawled solar system is not requested
+/// - Assert only one stargate of the pair is requested
+/// - Assert the crawled systems are connected in both directions
+#[cfg(feature = "universe-graph")]
+#[tokio::test]
+async fn test_crawl_universe_graph() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let system = |system_id: i64, stargates: Vec<i64>| {
+        serde_json::json!({
+            "constellation_id": 20000020,
+            "name": format!("System {}", system_id),
+            "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+            "security_status": 0.5,
+            "stargates": stargates,
+            "system_id": system_id
+        })
+        .to_string()
+    };
+
+    let mock_systems = mock_server
+        .mock("GET", "/universe/systems")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([30000142, 30000144, 30000145]).to_string())
+        .create();
+    let mock_crawled_system = mock_server
+        .mock("GET", "/universe/systems/30000142")
+        .expect(0)
+        .create();
+    let mock_system_1 = mock_server
+        .mock("GET", "/universe/systems/30000144")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(system(30000144, vec![50000001]))
+        .create();
+    let mock_system_2 = mock_server
+        .mock("GET", "/universe/systems/30000145")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(system(30000145, vec![50000002]))
+        .create();
+    let mock_stargate_1 = mock_server
+        .mock("GET", "/universe/stargates/50000001")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "destination": {"stargate_id": 50000002, "system_id": 30000145},
+                "name": "Stargate (System 30000145)",
+                "position": {"x": 0.0, "y": 0.0, "z": 0.0},
+                "stargate_id": 50000001,
+                "system_id": 30000144,
+                "type_id": 29635
+            })
+            .to_string(),
+        )
+        .create();
+    let mock_stargate_2 = mock_server
+        .mock("GET", "/universe/stargates/50000002")
+        .expect(0)
+        .create();
+
+    let mut graph = eve_esi::graph::UniverseGraph::new();
+    graph.insert_system(30000142, vec![]);
+
+    let result = esi_client.universe().crawl_universe_graph(&mut graph).await;
+
+    mock_systems.assert();
+    mock_crawled_system.assert();
+    mock_system_1.assert();
+    mock_system_2.assert();
+    mock_stargate_1.assert();
+    mock_stargate_2.assert();
+
+    assert!(result.is_ok());
+    assert_eq!(graph.len(), 3);
+    assert_eq!(graph.neighbors(30000144), &[30000145]);
+    assert_eq!(graph.neighbors(30000145), &[30000144]);
+    assert_eq!(graph.jumps(30000145, 30000144), Some(1));
+}
+
+/// Tests the distinct item types of a list of assets are retrieved & their names cached
+///
+/// # Test Setup
+/// - Create 3 assets of 2 distinct item types
+/// - Create mock item type endpoints for both types
+///
+/// # Assertions
+/// - Assert each item type is requested once
+/// - Assert both item types are returned
+/// - Assert the item type names were added to the name cache
+#[tokio::test]
+async fn test_get_item_types_for() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mocks: Vec<mockito::Mock> = [(34, "Tritanium"), (35, "Pyerite")]
+        .iter()
+        .map(|(type_id, name)| {
+            mock_server
+                .mock("GET", format!("/universe/types/{}", type_id).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "description": name,
+                        "group_id": 18,
+                        "name": name,
+                        "published": true,
+                        "type_id": type_id,
+                        "volume": 0.01
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create()
+        })
+        .collect();
+
+    let assets: Vec<Asset> = [(1, 34), (2, 35), (3, 34)]
+        .iter()
+        .map(|(item_id, type_id)| {
+            serde_json::from_value(serde_json::json!({
+                "is_singleton": false,
+                "item_id": item_id,
+                "location_flag": "Hangar",
+                "location_id": 60003760,
+                "location_type": "station",
+                "quantity": 100,
+                "type_id": type_id
+            }))
+            .unwrap()
+        })
+        .collect();
+
+    let types = esi_client
+        .universe()
+        .get_item_types_for(&assets)
+        .await
+        .expect("Failed to get item types");
+
+    for mock in mocks {
+        mock.assert();
+    }
+
+    assert_eq!(types.len(), 2);
+    assert_eq!(types[&34].name, "Tritanium");
+    assert_eq!(types[&35].name, "Pyerite");
+    assert_eq!(
+        esi_client
+            .name_cache()
+            .get_name(35, UniverseNameCategory::InventoryType)
+            .as_deref(),
+        Some("Pyerite")
+    );
+}
+
+/// Tests the item types retrieved are returned alongside the types which failed
+///
+/// # Test Setup
+/// - Create a mock item type endpoint for Tritanium & one returning 404 for Pyerite
+///
+/// # Assertions
+/// - Assert both item types are requested
+/// - Assert the retrieved item type is returned with the failed type ID
+/// - Assert the partial data is accepted below the failure threshold
+/// - Assert the name of the retrieved item type was added to the name cache
+#[tokio::test]
+async fn test_get_item_types_partial_failure() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_tritanium = mock_server
+        .mock("GET", "/universe/types/34")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "description": "Tritanium",
+                "group_id": 18,
+                "name": "Tritanium",
+                "published": true,
+                "type_id": 34,
+                "volume": 0.01
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+    let mock_pyerite = mock_server
+        .mock("GET", "/universe/types/35")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Type not found"}"#)
+        .expect(1)
+        .create();
+
+    let result = esi_client.universe().get_item_types(vec![34, 35]).await;
+
+    // Assert both item types are requested
+    mock_tritanium.assert();
+    mock_pyerite.assert();
+
+    // Assert the retrieved item type is returned with the failed type ID
+    let partial = result.expect_err("Expected a partial failure");
+    assert_eq!(partial.attempted(), 2);
+    assert_eq!(partial.failures().len(), 1);
+    assert_eq!(partial.failures()[0].0, 35);
+    assert_eq!(partial.successes()[&34].name, "Tritanium");
+
+    // Assert the partial data is accepted below the failure threshold
+    let types = partial.escalate(0.5).expect("Expected partial data");
+    assert_eq!(types.len(), 1);
+
+    // Assert the name of the retrieved item type was added to the name cache
+    assert_eq!(
+        esi_client
+            .name_cache()
+            .get_name(34, UniverseNameCategory::InventoryType)
+            .as_deref(),
+        Some("Tritanium")
+    );
+}
+
+/// Tests item types found in the SDE are not requested from ESI
+///
+/// # Test Setup
+/// - Add Tritanium to the static data & create mock item type endpoints for Tritanium & Pyerite
+///
+/// # Assertions
+/// - Assert only the item type missing from the static data is requested
+/// - Assert both item types are returned
+#[cfg(feature = "sde")]
+#[tokio::test]
+async fn test_get_static_types() {
+    use eve_esi::sde::{StaticData, StaticType};
+
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let mock_tritanium = mock_server
+        .mock("GET", "/universe/types/34")
+        .expect(0)
+        .create();
+    let mock_pyerite = mock_server
+        .mock("GET", "/universe/types/35")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "description": "Pyerite",
+                "group_id": 18,
+                "name": "Pyerite",
+                "published": true,
+                "type_id": 35,
+                "volume": 0.01
+            })
+            .to_string(),
+        )
+        .create();
+
+    let mut sde = StaticData::new();
+    sde.insert(StaticType {
+        type_id: 34,
+        name: "Tritanium".to_string(),
+        group_id: 18,
+        market_group_id: Some(1857),
+        volume: Some(0.01),
+        capacity: None,
+        mass: None,
+        portion_size: Some(1),
+        published: true,
+        dogma_attributes: Vec::new(),
+        dogma_effects: Vec::new(),
+    });
+
+    let types = esi_client
+        .universe()
+        .get_static_types(&sde, vec![34, 35, 35])
+        .await
+        .expect("Failed to get static types");
+
+    mock_tritanium.assert();
+    mock_pyerite.assert();
+
+    assert_eq!(types.len(), 2);
+    assert_eq!(types[&34].name, "Tritanium");
+    assert_eq!(types[&35].name, "Pyerite");
+}
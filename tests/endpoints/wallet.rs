@@ -0,0 +1,506 @@
+use eve_esi::model::wallet::WalletJournalEntry;
+use eve_esi::model::Isk;
+use eve_esi::{
+    scope::{CorporationsScopes, WalletScopes},
+    ScopeBuilder,
+};
+
+use crate::endpoints::util::{authenticated_endpoint_test_setup, mock_access_token_with_scopes};
+
+authenticated_esi_request_test! {
+    get_character_wallet_journal,
+    wallet,
+    get_character_wallet_journal[2114794365, 1],
+    request_type = "GET",
+    url = "/characters/2114794365/wallet/journal?page=1",
+    required_scopes = ScopeBuilder::new()
+        .wallet(WalletScopes::new().read_character_wallets())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "amount": -100000.0,
+        "balance": 1000000.0,
+        "context_id": 1,
+        "context_id_type": "market_transaction_id",
+        "date": "2018-02-23T14:31:32Z",
+        "description": "Market escrow release",
+        "first_party_id": 2114794365,
+        "id": 1,
+        "ref_type": "market_escrow",
+        "second_party_id": 1000132
+      }
+    ]),
+}
+
+authenticated_esi_request_test! {
+    get_corporation_wallet_journal,
+    wallet,
+    get_corporation_wallet_journal[98785281, 1, 1],
+    request_type = "GET",
+    url = "/corporations/98785281/wallets/1/journal?page=1",
+    required_scopes = ScopeBuilder::new()
+        .wallet(WalletScopes::new().read_corporation_wallets())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "amount": 5000000.0,
+        "balance": 25000000.0,
+        "date": "2018-02-23T14:31:32Z",
+        "description": "Bounty prizes",
+        "first_party_id": 1000125,
+        "id": 1,
+        "ref_type": "bounty_prizes",
+        "second_party_id": 98785281,
+        "tax": 500000.0,
+        "tax_receiver_id": 98785281
+      }
+    ]),
+}
+
+authenticated_esi_request_test! {
+    get_character_wallet_transactions,
+    wallet,
+    get_character_wallet_transactions[2114794365, None],
+    request_type = "GET",
+    url = "/characters/2114794365/wallet/transactions",
+    required_scopes = ScopeBuilder::new()
+        .wallet(WalletScopes::new().read_character_wallets())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "client_id": 1000132,
+        "date": "2018-02-23T14:31:32Z",
+        "is_buy": true,
+        "is_personal": true,
+        "journal_ref_id": 1,
+        "location_id": 60003760,
+        "quantity": 10,
+        "transaction_id": 5000,
+        "type_id": 34,
+        "unit_price": 5.5
+      }
+    ]),
+}
+
+authenticated_esi_request_test! {
+    get_corporation_wallet_transactions,
+    wallet,
+    get_corporation_wallet_transactions[98785281, 1, Some(5000)],
+    request_type = "GET",
+    url = "/corporations/98785281/wallets/1/transactions?from_id=5000",
+    required_scopes = ScopeBuilder::new()
+        .wallet(WalletScopes::new().read_corporation_wallets())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "client_id": 1000132,
+        "date": "2018-02-23T14:31:32Z",
+        "is_buy": false,
+        "journal_ref_id": 1,
+        "location_id": 60003760,
+        "quantity": 10,
+        "transaction_id": 4999,
+        "type_id": 34,
+        "unit_price": 5.5
+      }
+    ]),
+}
+
+authenticated_esi_request_test! {
+    get_corporation_wallets,
+    wallet,
+    get_corporation_wallets[98785281],
+    request_type = "GET",
+    url = "/corporations/98785281/wallets",
+    required_scopes = ScopeBuilder::new()
+        .wallet(WalletScopes::new().read_corporation_wallets())
+        .build();
+    mock_response = serde_json::json!([
+      {
+        "balance": 1000000.0,
+        "division": 1
+      }
+    ]),
+}
+
+/// Tests corporation wallet balances are returned with the names of their divisions
+///
+/// # Test Setup
+/// - Create a mock endpoint for the balances of 2 wallet divisions, listed out of order
+/// - Create a mock endpoint for the divisions with only the master wallet named
+///
+/// # Assertions
+/// - Assert the wallets & divisions were requested
+/// - Assert the wallets are ordered by division
+/// - Assert the named division keeps its name & the unnamed division is given a default name
+#[tokio::test]
+async fn test_get_named_corporation_wallets() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_corporation_wallets())
+            .corporations(CorporationsScopes::new().read_divisions())
+            .build(),
+    );
+
+    // Create a mock endpoint for the balances of 2 wallet divisions, listed out of order
+    let mock_wallets = mock_server
+        .mock("GET", "/corporations/98785281/wallets")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                { "balance": 2500.0, "division": 3 },
+                { "balance": 1000000.0, "division": 1 }
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    // Create a mock endpoint for the divisions with only the master wallet named
+    let mock_divisions = mock_server
+        .mock("GET", "/corporations/98785281/divisions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "hangar": [],
+                "wallet": [
+                    { "division": 1, "name": "Master Wallet" },
+                    { "division": 3 }
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let wallets = esi_client
+        .wallet()
+        .get_named_corporation_wallets(&access_token, 98785281)
+        .await
+        .expect("Failed to retrieve named corporation wallets");
+
+    // Assert the wallets & divisions were requested
+    mock_jwt_key_endpoint.assert();
+    mock_wallets.assert();
+    mock_divisions.assert();
+
+    // Assert the wallets are ordered by division & named
+    assert_eq!(wallets.len(), 2);
+    assert_eq!(wallets[0].division, 1);
+    assert_eq!(wallets[0].name, "Master Wallet");
+    assert_eq!(wallets[1].division, 3);
+    assert_eq!(wallets[1].name, "Wallet Division 3");
+}
+
+/// Creates a mock character wallet journal entry with the provided reference ID
+fn mock_journal_entry(id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "amount": 1000.0,
+        "date": "2018-02-23T14:31:32Z",
+        "description": "Player donation",
+        "id": id,
+        "ref_type": "player_donation"
+    })
+}
+
+/// Tests that syncing the wallet journal stops paging once known entries are reached
+///
+/// # Test Setup
+/// - Create mock endpoints for 3 pages of journal entries, the second page containing
+///   the last known entry
+///
+/// # Assertions
+/// - Assert only the first 2 pages are requested
+/// - Assert only entries newer than the last known entry are returned, newest first
+#[tokio::test]
+async fn test_sync_character_wallet_journal() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_character_wallets())
+            .build(),
+    );
+
+    // Create mock endpoints for 3 pages of journal entries
+    let mock_page_1 = mock_server
+        .mock("GET", "/characters/2114794365/wallet/journal?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "3")
+        .with_body(serde_json::json!([mock_journal_entry(6), mock_journal_entry(5)]).to_string())
+        .create();
+    let mock_page_2 = mock_server
+        .mock("GET", "/characters/2114794365/wallet/journal?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "3")
+        .with_body(serde_json::json!([mock_journal_entry(4), mock_journal_entry(3)]).to_string())
+        .create();
+    let mock_page_3 = mock_server
+        .mock("GET", "/characters/2114794365/wallet/journal?page=3")
+        .expect(0)
+        .create();
+
+    let result = esi_client
+        .wallet()
+        .sync_character_wallet_journal(&access_token, 2114794365, Some(3))
+        .await;
+
+    // Assert only the first 2 pages were requested
+    mock_jwt_key_endpoint.assert();
+    mock_page_1.assert();
+    mock_page_2.assert();
+    mock_page_3.assert();
+
+    let entries = result.expect("Failed to sync wallet journal");
+    let ids: Vec<i64> = entries.iter().map(|entry| entry.id).collect();
+
+    // Assert only new entries are returned, newest first
+    assert_eq!(ids, vec![6, 5, 4]);
+}
+
+/// Creates a mock wallet transaction referencing the provided journal entry
+fn mock_transaction(transaction_id: i64, journal_ref_id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "client_id": 1000132,
+        "date": "2018-02-23T14:31:32Z",
+        "is_buy": true,
+        "is_personal": true,
+        "journal_ref_id": journal_ref_id,
+        "location_id": 60003760,
+        "quantity": 10,
+        "transaction_id": transaction_id,
+        "type_id": 34,
+        "unit_price": 100.0
+    })
+}
+
+/// Tests that wallet transactions are linked with journal entries from only the pages required
+///
+/// # Test Setup
+/// - Create a mock transactions endpoint with transactions referencing journal entries 5 & 3
+/// - Create mock endpoints for 3 pages of journal entries, the second page containing an entry
+///   older than entry 3
+///
+/// # Assertions
+/// - Assert only the first 2 journal pages are requested
+/// - Assert each transaction is linked with the journal entry it references
+#[tokio::test]
+async fn test_get_linked_character_wallet_transactions() {
+    let (esi_client, mut mock_server, mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_character_wallets())
+            .build(),
+    );
+
+    let mock_transactions = mock_server
+        .mock("GET", "/characters/2114794365/wallet/transactions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([mock_transaction(2, 5), mock_transaction(1, 3)]).to_string())
+        .create();
+    let mock_page_1 = mock_server
+        .mock("GET", "/characters/2114794365/wallet/journal?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "3")
+        .with_body(serde_json::json!([mock_journal_entry(6), mock_journal_entry(5)]).to_string())
+        .create();
+    let mock_page_2 = mock_server
+        .mock("GET", "/characters/2114794365/wallet/journal?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "3")
+        .with_body(serde_json::json!([mock_journal_entry(3), mock_journal_entry(2)]).to_string())
+        .create();
+    let mock_page_3 = mock_server
+        .mock("GET", "/characters/2114794365/wallet/journal?page=3")
+        .expect(0)
+        .create();
+
+    let result = esi_client
+        .wallet()
+        .get_linked_character_wallet_transactions(&access_token, 2114794365)
+        .await;
+
+    // Assert only the journal pages containing the referenced entries were requested
+    mock_jwt_key_endpoint.assert();
+    mock_transactions.assert();
+    mock_page_1.assert();
+    mock_page_2.assert();
+    mock_page_3.assert();
+
+    let linked = result.expect("Failed to link wallet transactions");
+    let journal_ids: Vec<Option<i64>> = linked
+        .iter()
+        .map(|linked| linked.journal.as_ref().map(|entry| entry.id))
+        .collect();
+
+    // Assert each transaction is linked with its journal entry
+    assert_eq!(journal_ids, vec![Some(5), Some(3)]);
+}
+
+/// Tests auditing the bounty & mission income of members with each member's access token
+///
+/// # Test Setup
+/// - Create mock journal endpoints for 2 members, one with 2 pages of entries
+/// - Audit both members over a date range excluding one of the bounties
+///
+/// # Assertions
+/// - Assert every page of both journals was requested
+/// - Assert the income of each member is returned in the order provided
+/// - Assert entries outside of the date range & other reference types are ignored
+#[tokio::test]
+async fn test_audit_member_income() {
+    let (esi_client, mut mock_server, _mock_jwt_key_endpoint) =
+        authenticated_endpoint_test_setup().await;
+    let access_token = mock_access_token_with_scopes(
+        ScopeBuilder::new()
+            .wallet(WalletScopes::new().read_character_wallets())
+            .build(),
+    );
+
+    let entry = |id: i64, date: &str, ref_type: &str, amount: f64, tax: f64| {
+        serde_json::json!({
+            "amount": amount,
+            "date": date,
+            "description": "Income",
+            "id": id,
+            "ref_type": ref_type,
+            "tax": tax
+        })
+    };
+
+    let mock_first_page_1 = mock_server
+        .mock("GET", "/characters/1/wallet/journal?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                entry(4, "2025-10-05T12:00:00Z", "bounty_prizes", 900.0, 100.0),
+                entry(3, "2025-10-04T12:00:00Z", "player_donation", 5000.0, 0.0)
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_first_page_2 = mock_server
+        .mock("GET", "/characters/1/wallet/journal?page=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body(
+            serde_json::json!([
+                entry(
+                    2,
+                    "2025-10-03T12:00:00Z",
+                    "agent_mission_reward",
+                    450.0,
+                    50.0
+                ),
+                entry(1, "2025-09-01T12:00:00Z", "bounty_prizes", 9000.0, 1000.0)
+            ])
+            .to_string(),
+        )
+        .create();
+    let mock_second = mock_server
+        .mock("GET", "/characters/2/wallet/journal?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([entry(
+                10,
+                "2025-10-02T12:00:00Z",
+                "bounty_prizes",
+                1800.0,
+                200.0
+            )])
+            .to_string(),
+        )
+        .create();
+
+    let income = esi_client
+        .wallet()
+        .audit_member_income(
+            [(1, &access_token), (2, &access_token)],
+            "2025-10-01T00:00:00Z".parse().unwrap(),
+            "2025-10-08T00:00:00Z".parse().unwrap(),
+        )
+        .await
+        .expect("Failed to audit member income");
+
+    // Assert every page of both journals was requested
+    mock_first_page_1.assert();
+    mock_first_page_2.assert();
+    mock_second.assert();
+
+    // Assert the income of each member is returned in the order provided
+    assert_eq!(income.len(), 2);
+    assert_eq!(income[0].character_id, 1);
+    assert_eq!(income[0].bounties, Isk::from(900));
+    assert_eq!(income[0].missions, Isk::from(450));
+    assert_eq!(income[0].tax, Isk::from(150));
+    assert_eq!(income[0].entries, 2);
+    assert_eq!(income[1].character_id, 2);
+    assert_eq!(income[1].total(), Isk::from(1800));
+}
+
+/// Tests wallet journal entries are mapped to accounting records with their party names
+///
+/// # Test Setup
+/// - Create 2 journal entries between the same 2 parties, one without a second party
+/// - Create a mock universe names endpoint resolving both parties
+///
+/// # Assertions
+/// - Assert the party names are resolved in a single request
+/// - Assert each entry is mapped with its party names in the order provided
+#[tokio::test]
+async fn test_get_accounting_records() {
+    let (esi_client, mut mock_server) = crate::util::integration_test_setup().await;
+
+    let mut donation = mock_journal_entry(2);
+    donation["first_party_id"] = serde_json::json!(2114794365);
+    donation["second_party_id"] = serde_json::json!(2117053828);
+    let mut fee = mock_journal_entry(1);
+    fee["first_party_id"] = serde_json::json!(2117053828);
+    let journal: Vec<WalletJournalEntry> =
+        serde_json::from_value(serde_json::json!([donation, fee])).unwrap();
+
+    let mock_names = mock_server
+        .mock("POST", "/universe/names")
+        .match_body(mockito::Matcher::Json(serde_json::json!([
+            2114794365, 2117053828
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"category": "character", "id": 2114794365, "name": "Bob"},
+                {"category": "character", "id": 2117053828, "name": "Alice"}
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create();
+
+    let result = esi_client.wallet().get_accounting_records(&journal).await;
+
+    // Assert the party names are resolved in a single request
+    mock_names.assert();
+
+    // Assert each entry is mapped with its party names in the order provided
+    let records = result.expect("Failed to get accounting records");
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].id, 2);
+    assert_eq!(records[0].first_party.as_deref(), Some("Bob"));
+    assert_eq!(records[0].second_party.as_deref(), Some("Alice"));
+    assert_eq!(records[1].first_party.as_deref(), Some("Alice"));
+    assert_eq!(records[1].second_party, None);
+}
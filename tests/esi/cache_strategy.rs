@@ -1,5 +1,7 @@
 //! Integration tests for CacheStrategy and send_with_cache API
 
+use std::sync::{Arc, Mutex};
+
 use chrono::{DateTime, Utc};
 use eve_esi::CacheStrategy;
 use reqwest::Method;
@@ -327,3 +329,59 @@ async fn test_use_last_modified_for_next_request() -> Result<(), eve_esi::Error>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_on_etag_persists_etag_for_with_etag() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    // First request without an ETag returns fresh data & its ETag
+    let mock_fresh = server
+        .mock("GET", "/test")
+        .match_header("If-None-Match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("ETag", "\"etag-1\"")
+        .with_body(r#"{"value": "test data"}"#)
+        .create_async()
+        .await;
+
+    // Second request with the persisted ETag is not modified
+    let mock_not_modified = server
+        .mock("GET", "/test")
+        .match_header("If-None-Match", "\"etag-1\"")
+        .with_status(304)
+        .create_async()
+        .await;
+
+    let stored = Arc::new(Mutex::new(None));
+
+    let store = stored.clone();
+    let response = client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .with_etag(None)
+        .on_etag(move |etag| *store.lock().unwrap() = Some(etag.to_string()))
+        .send_conditional()
+        .await?;
+
+    assert!(response.is_fresh());
+    assert_eq!(stored.lock().unwrap().as_deref(), Some("\"etag-1\""));
+
+    let store = stored.clone();
+    let etag = stored.lock().unwrap().clone();
+    let response = client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .with_etag(etag)
+        .on_etag(move |_| *store.lock().unwrap() = None)
+        .send_conditional()
+        .await?;
+
+    // The 304 response does not invoke the callback, keeping the stored ETag
+    assert!(response.is_not_modified());
+    assert_eq!(stored.lock().unwrap().as_deref(), Some("\"etag-1\""));
+
+    mock_fresh.assert_async().await;
+    mock_not_modified.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Integration tests for recording & replaying ESI responses with a Cassette
+
+use eve_esi::Cassette;
+use mockito::Server;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestResponse {
+    value: String,
+}
+
+/// Builds a client using the provided ESI URL & cassette
+fn client_with_cassette(esi_url: &str, cassette: Cassette) -> eve_esi::Client {
+    let config = eve_esi::Config::builder()
+        .esi_url(esi_url)
+        .build()
+        .expect("Failed to build Config");
+
+    eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .cassette(cassette)
+        .build()
+        .expect("Failed to build Client")
+}
+
+/// Tests that a recorded response is replayed without sending a request
+///
+/// # Test Setup
+/// - Record a request to a mock endpoint to a cassette file
+/// - Replay the cassette file with a new client
+///
+/// # Assertions
+/// - Assert the mock endpoint was only requested while recording
+/// - Assert the replayed response matches the recorded response including headers
+/// - Assert a request which was not recorded returns an error
+#[tokio::test]
+async fn test_record_then_replay() -> Result<(), eve_esi::Error> {
+    let mut server = Server::new_async().await;
+    let path =
+        std::env::temp_dir().join(format!("eve_esi_cassette_test_{}.json", std::process::id()));
+
+    let mock = server
+        .mock("GET", "/test")
+        .with_status(200)
+        .with_header("ETag", "test-etag-123")
+        .with_body(r#"{"value": "test data"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = client_with_cassette(&server.url(), Cassette::record(&path));
+    let recorded = client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .send()
+        .await?;
+
+    let client = client_with_cassette(&server.url(), Cassette::replay(&path)?);
+    let replayed = client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .send()
+        .await;
+    let missing = client
+        .esi()
+        .new_request::<TestResponse>("/missing")
+        .send()
+        .await;
+
+    std::fs::remove_file(&path)?;
+    mock.assert_async().await;
+
+    let replayed = replayed?;
+    assert_eq!(replayed.data, recorded.data);
+    assert_eq!(replayed.cache.etag, "test-etag-123");
+    assert!(matches!(missing, Err(eve_esi::Error::ReplayMissing { .. })));
+
+    Ok(())
+}
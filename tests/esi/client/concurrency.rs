@@ -0,0 +1,69 @@
+//! Integration tests for the client's limit of ESI requests in flight.
+//!
+//! Tests that requests queue once `esi_max_concurrent_requests` requests are in flight, with the
+//! time spent queued reported as the request's queue wait, & that the in flight count is exposed.
+
+use std::time::Duration;
+
+use mockito::Server;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestData {
+    message: String,
+}
+
+/// Tests a request queues until the request in flight has read its response body.
+///
+/// Sends 2 requests at once with a limit of 1 request in flight, the mock server delaying each
+/// response body by 200 milliseconds after the headers are sent.
+///
+/// Expected: One request waits in the queue for the other's body, no request is left in flight
+#[tokio::test]
+async fn test_max_concurrent_requests() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/test")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|writer| {
+            std::thread::sleep(Duration::from_millis(200));
+            writer.write_all(br#"{"message": "ok"}"#)
+        })
+        .expect(2)
+        .create();
+
+    let config = eve_esi::Config::builder()
+        .esi_url(&server.url())
+        .esi_max_concurrent_requests(1)
+        .build()
+        .expect("Failed to build Config");
+    let client = eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .build()
+        .expect("Failed to build Client");
+
+    let first = client.esi().new_request::<TestData>("/test");
+    let second = client.esi().new_request::<TestData>("/test");
+
+    let (first, second) = tokio::join!(first.send_and_measure(), second.send_and_measure());
+    let first = first.expect("Failed to send first request");
+    let second = second.expect("Failed to send second request");
+
+    mock.assert();
+    assert_eq!(first.data.message, "ok");
+    assert_eq!(second.data.message, "ok");
+
+    // One request waited in the queue for the other's body
+    let queued = first.timing.queue_wait.max(second.timing.queue_wait);
+    assert!(
+        queued >= Duration::from_millis(150),
+        "Expected a request to queue, waited {:?}",
+        queued
+    );
+
+    // No request is left in flight
+    assert_eq!(client.in_flight_requests(), 0);
+}
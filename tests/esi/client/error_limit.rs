@@ -0,0 +1,320 @@
+//! Integration tests for ESI error limit (420) handling.
+//!
+//! Tests that 420 responses are returned as `Error::ErrorLimited` with the reset time from
+//! the `X-ESI-Error-Limit-Reset` header & that subsequent requests are parked until the
+//! reset when automatic cool-off is enabled. Also tests the simulated error limit enforced by the
+//! client when an `ErrorLimitSimulation` is configured.
+
+use std::time::{Duration, Instant};
+
+use eve_esi::esi::ErrorLimitSimulation;
+use eve_esi::Error;
+use mockito::Server;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestData {
+    message: String,
+}
+
+/// Creates a client for the mock server with automatic error limit cool-off enabled or disabled
+fn error_limit_test_client(server: &Server, cool_off: bool) -> eve_esi::Client {
+    let config = eve_esi::Config::builder()
+        .esi_url(&server.url())
+        .esi_error_limit_cool_off(cool_off)
+        .build()
+        .expect("Failed to build Config");
+
+    eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .build()
+        .expect("Failed to build Client")
+}
+
+/// Tests that a 420 response returns an error limited error with the reset time.
+///
+/// Verifies that the reset time is calculated from the `X-ESI-Error-Limit-Reset` header
+/// and the underlying ESI error is retained.
+///
+/// Expected: Error::ErrorLimited with a reset time ~30 seconds from now
+#[tokio::test]
+async fn test_error_limited_response() {
+    let mut server = Server::new_async().await;
+    let client = error_limit_test_client(&server, false);
+
+    let mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(420)
+        .with_header("x-esi-error-limit-reset", "30")
+        .with_body(r#"{"error": "This software has exceeded the error limit for ESI."}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let before = chrono::Utc::now();
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send()
+        .await;
+
+    match result {
+        Err(Error::ErrorLimited { reset_at, error }) => {
+            assert_eq!(error.status, 420);
+            assert!(reset_at >= before + chrono::TimeDelta::seconds(30));
+            assert!(reset_at <= chrono::Utc::now() + chrono::TimeDelta::seconds(30));
+        }
+        other => panic!("Expected ErrorLimited, got: {:?}", other),
+    }
+
+    mock.assert_async().await;
+}
+
+/// Tests that requests are parked until the error limit resets when cool-off is enabled.
+///
+/// Verifies that after a 420 response with a 1 second reset window, the next request
+/// is only sent once the window has passed.
+///
+/// Expected: Second request succeeds after waiting roughly 1 second
+#[tokio::test]
+async fn test_error_limit_cool_off() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let client = error_limit_test_client(&server, true);
+
+    let error_mock = server
+        .mock("GET", "/test/limited")
+        .with_status(420)
+        .with_header("x-esi-error-limit-reset", "1")
+        .with_body(r#"{"error": "This software has exceeded the error limit for ESI."}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let success_mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "ok"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/limited")
+        .send()
+        .await;
+    assert!(matches!(result, Err(Error::ErrorLimited { .. })));
+
+    let start = Instant::now();
+    let response = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send()
+        .await?;
+
+    assert_eq!(response.data.message, "ok");
+    assert!(start.elapsed() >= Duration::from_millis(900));
+
+    error_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests that requests are not parked after a 420 when cool-off is disabled.
+///
+/// Verifies the default behavior of sending subsequent requests immediately.
+///
+/// Expected: Second request is sent without waiting for the 60 second reset window
+#[tokio::test]
+async fn test_error_limit_no_cool_off() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let client = error_limit_test_client(&server, false);
+
+    let error_mock = server
+        .mock("GET", "/test/limited")
+        .with_status(420)
+        .with_header("x-esi-error-limit-reset", "60")
+        .with_body(r#"{"error": "This software has exceeded the error limit for ESI."}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let success_mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "ok"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/limited")
+        .send()
+        .await;
+    assert!(matches!(result, Err(Error::ErrorLimited { .. })));
+
+    let start = Instant::now();
+    client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send()
+        .await?;
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    error_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Creates a client for the mock server enforcing a simulated error limit
+fn error_limit_simulation_test_client(
+    server: &Server,
+    simulation: ErrorLimitSimulation,
+) -> eve_esi::Client {
+    let config = eve_esi::Config::builder()
+        .esi_url(&server.url())
+        .esi_error_limit_simulation(simulation)
+        .build()
+        .expect("Failed to build Config");
+
+    eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .build()
+        .expect("Failed to build Client")
+}
+
+/// Tests that error responses spend the simulated error limit's budget.
+///
+/// Verifies that responses carry the simulated error limit headers & that once the budget is
+/// spent requests fail with a simulated 420 without being sent until the window resets.
+///
+/// Expected: Error::ErrorLimited after two error responses, success once the window has passed
+#[tokio::test]
+async fn test_error_limit_simulation() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let client = error_limit_simulation_test_client(
+        &server,
+        ErrorLimitSimulation::new(2, Duration::from_secs(1)),
+    );
+
+    let error_mock = server
+        .mock("GET", "/test/missing")
+        .with_status(404)
+        .with_body(r#"{"error": "Not found"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let success_mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "ok"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    // Error responses spend the budget & responses carry the remaining simulated budget
+    let missing = || client.esi().new_request::<TestData>("/test/missing").send();
+    assert!(matches!(missing().await, Err(Error::EsiError(_))));
+
+    let response = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send_raw()
+        .await?;
+    assert_eq!(
+        response.headers.get("x-esi-error-limit-remain").unwrap(),
+        "1"
+    );
+    assert_eq!(
+        response.headers.get("x-esi-error-limit-reset").unwrap(),
+        "1"
+    );
+
+    assert!(matches!(missing().await, Err(Error::EsiError(_))));
+
+    // Once the budget is spent requests fail without being sent
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send()
+        .await;
+    match result {
+        Err(Error::ErrorLimited { reset_at, error }) => {
+            assert_eq!(error.status, 420);
+            assert!(reset_at <= chrono::Utc::now() + chrono::TimeDelta::seconds(1));
+        }
+        other => panic!("Expected ErrorLimited, got: {:?}", other),
+    }
+
+    // The budget is restored once the window has passed
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let response = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send()
+        .await?;
+    assert_eq!(response.data.message, "ok");
+
+    error_mock.assert_async().await;
+    success_mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests that bounded concurrency stops starting requests once the remaining errors fall below the threshold.
+///
+/// Verifies that the `X-ESI-Error-Limit-Remain` header of an earlier failed response is used to
+/// refuse further requests without sending them when cool-off is disabled.
+///
+/// Expected: Error::ErrorLimitThreshold & the batched endpoint is never requested
+#[tokio::test]
+async fn test_try_join_limited_error_limit_threshold() {
+    let mut server = Server::new_async().await;
+    let client = error_limit_test_client(&server, false);
+
+    let failing_mock = server
+        .mock("GET", "/failing/endpoint")
+        .with_status(404)
+        .with_header("x-esi-error-limit-remain", "5")
+        .with_header("x-esi-error-limit-reset", "30")
+        .with_body(r#"{"error": "Not found"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let batch_mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_body(r#"{"message": "ok"}"#)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestData>("/failing/endpoint")
+        .send()
+        .await;
+    assert!(result.is_err());
+
+    let result = eve_esi::esi::try_join_limited(&client, 0..3, 2, |_| {
+        let request = client.esi().new_request::<TestData>("/test/endpoint");
+
+        async move { request.send().await.map(|response| response.data) }
+    })
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::ErrorLimitThreshold { remaining: 5, .. })
+    ));
+
+    failing_mock.assert_async().await;
+    batch_mock.assert_async().await;
+}
@@ -1,11 +1,21 @@
 //! Integration tests for ESI client functionality.
 //!
 //! This module organizes client integration tests by public method:
+//! - `concurrency` - Tests for the limit of ESI requests in flight
+//! - `error_limit` - Tests for 420 error limited responses & automatic cool-off
 //! - `new_request` - Tests for creating EsiRequest instances
 //! - `request` - Tests for standard ESI requests
 //! - `request_cached` - Tests for cached ESI requests with 304 support
+//! - `request_raw` - Tests for raw ESI requests without typed deserialization
+//! - `response_cache` - Tests for requests reusing response bodies stored in a ResponseCache
+//! - `response_size` - Tests for maximum response sizes & streaming response bodies
 
+mod concurrency;
+mod error_limit;
 mod new_request;
 mod request;
 mod request_cached;
+mod request_raw;
+mod response_cache;
+mod response_size;
 mod retry_logic;
@@ -6,9 +6,11 @@
 use crate::oauth2::util::jwk_response::get_jwk_success_response;
 use crate::oauth2::util::jwt::create_mock_token;
 use crate::util::integration_test_setup;
+use eve_esi::esi::Idempotency;
 use oauth2::TokenResponse;
 use reqwest::Method;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 #[derive(Deserialize, Debug, PartialEq)]
 struct TestData {
@@ -301,6 +303,98 @@ async fn test_500_server_error_response() -> Result<(), eve_esi::Error> {
     Ok(())
 }
 
+/// Tests requests which may duplicate their effect are not retried.
+///
+/// Verifies that a `POST` request, which is classed as unsafe to retry by default,
+/// returns the first 500 error without retrying while the same request marked
+/// as safe is retried.
+///
+/// Expected: 1 request for the unsafe request & 3 for the safe request
+#[tokio::test]
+async fn test_unsafe_request_not_retried() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("POST", "/test/write")
+        .expect(1)
+        .with_status(500)
+        .with_body(r#"{"error": "Internal server error"}"#)
+        .create_async()
+        .await;
+
+    let request = client
+        .esi()
+        .new_request::<TestData>("/test/write")
+        .with_method(Method::POST);
+    assert_eq!(request.idempotency(), Idempotency::Unsafe);
+
+    let result = request.send().await;
+    assert!(matches!(result, Err(eve_esi::Error::EsiError(ref e)) if e.status == 500));
+
+    mock.assert_async().await;
+
+    let mock = server
+        .mock("POST", "/test/safe-write")
+        .expect(3) // 1 initial + 2 retries
+        .with_status(500)
+        .with_body(r#"{"error": "Internal server error"}"#)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/safe-write")
+        .with_method(Method::POST)
+        .with_idempotency(Idempotency::Safe)
+        .send()
+        .await;
+    assert!(result.is_err());
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests error response includes the Retry-After header.
+///
+/// Verifies that the Retry-After header is extracted from error responses other
+/// than 429 and that the error is classified as retryable.
+///
+/// Expected: Downtime error with a retry after of 30 seconds which is retryable
+#[tokio::test]
+async fn test_error_response_includes_retry_after() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", "/test/unavailable")
+        .expect(3) // 1 initial + 2 retries
+        .with_status(503)
+        .with_header("retry-after", "30")
+        .with_body(r#"{"error": "Service unavailable"}"#)
+        .create_async()
+        .await;
+
+    let request = client.esi().new_request::<TestData>("/test/unavailable");
+
+    let result = request.send().await;
+
+    if let Err(eve_esi::Error::Downtime { error: esi_err, .. }) = result {
+        assert_eq!(
+            esi_err.retry_after,
+            Some(std::time::Duration::from_secs(30))
+        );
+        assert!(esi_err.is_retryable());
+        assert!(!esi_err.is_client_error());
+        assert!(!esi_err.is_rate_limited());
+    } else {
+        panic!("Expected EsiResponseError, got: {:?}", result.err());
+    }
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
 /// Tests error response includes cache headers.
 ///
 /// Verifies that even when an error response is returned, the cache
@@ -460,11 +554,47 @@ async fn test_deserialization_failure() -> Result<(), eve_esi::Error> {
     let result = request.send().await;
 
     assert!(result.is_err());
-    // Should be a serde_json error
-    if let Err(eve_esi::Error::SerdeJsonError(_)) = result {
-        // Expected error type
+    // Should be a deserialization error including the raw body & endpoint
+    if let Err(eve_esi::Error::DeserializationError { body, endpoint, .. }) = result {
+        assert!(body.contains("wrong_field"));
+        assert!(endpoint.ends_with("/test/bad-format"));
+    } else {
+        panic!("Expected DeserializationError, got: {:?}", result);
+    }
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests deserialization failure reports the path of the invalid field.
+///
+/// Verifies that when a nested field has an unexpected type, the JSON path to
+/// the field is included in the error.
+///
+/// Expected: Deserialization error with the path `items[1]`
+#[tokio::test]
+async fn test_deserialization_failure_path() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    // Return a response where the second item is not a string
+    let mock = server
+        .mock("GET", "/test/bad-item")
+        .with_status(200)
+        .with_body(r#"{"items": ["item1", 2]}"#)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestArray>("/test/bad-item")
+        .send()
+        .await;
+
+    if let Err(eve_esi::Error::DeserializationError { path, .. }) = result {
+        assert_eq!(path, "items[1]");
     } else {
-        panic!("Expected SerdeJsonError, got: {:?}", result);
+        panic!("Expected DeserializationError, got: {:?}", result);
     }
 
     mock.assert_async().await;
@@ -539,3 +669,75 @@ async fn test_network_error() -> Result<(), eve_esi::Error> {
 
     Ok(())
 }
+
+/// Tests sending a request whose body fails to serialize.
+///
+/// Verifies that the serialization error of `with_body` is returned when the request is
+/// sent rather than sending the request with a `null` body.
+///
+/// Expected: SerdeJsonError without a request being made
+#[tokio::test]
+async fn test_body_serialization_error() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("PUT", "/test/update")
+        .expect(0)
+        .create_async()
+        .await;
+
+    // JSON object keys must be strings, tuple keys fail to serialize
+    let body = HashMap::from([((1, 2), true)]);
+
+    let request = client
+        .esi()
+        .new_request::<TestData>("/test/update")
+        .with_method(Method::PUT)
+        .with_body(body);
+
+    assert_eq!(request.body_json(), None);
+
+    let result = request.send().await;
+
+    assert!(matches!(result, Err(eve_esi::Error::SerdeJsonError(_))));
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests sending a request whose query parameter fails to serialize.
+///
+/// Verifies that the serialization error of `with_query` is returned when the request is
+/// sent rather than sending the request without the query parameter.
+///
+/// Expected: SerdeJsonError without a request being made
+#[tokio::test]
+async fn test_query_serialization_error() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    // JSON object keys must be strings, tuple keys fail to serialize
+    let value = HashMap::from([((1, 2), true)]);
+
+    let request = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .with_query("order_type", "sell")
+        .with_query("filter", value);
+
+    assert!(request
+        .endpoint()
+        .ends_with("/test/endpoint?order_type=sell"));
+
+    let result = request.send().await;
+
+    assert!(matches!(result, Err(eve_esi::Error::SerdeJsonError(_))));
+    mock.assert_async().await;
+
+    Ok(())
+}
@@ -430,11 +430,11 @@ async fn test_cached_request_deserialization_failure() -> Result<(), eve_esi::Er
         .await;
 
     assert!(result.is_err());
-    // Should be a serde_json error
-    if let Err(eve_esi::Error::SerdeJsonError(_)) = result {
-        // Expected error type
+    // Should be a deserialization error including the raw body
+    if let Err(eve_esi::Error::DeserializationError { body, .. }) = result {
+        assert!(body.contains("wrong_field"));
     } else {
-        panic!("Expected SerdeJsonError, got: {:?}", result);
+        panic!("Expected DeserializationError, got: {:?}", result);
     }
 
     mock.assert_async().await;
@@ -0,0 +1,74 @@
+//! Integration tests for the `request_raw` method.
+//!
+//! Tests that the `request_raw` method returns the status, headers, & body of a
+//! response without typed deserialization and still surfaces ESI error responses.
+
+use crate::util::integration_test_setup;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestData {
+    message: String,
+}
+
+/// Tests retrieving a raw response which doesn't match the typed model.
+///
+/// Verifies that a response which would fail typed deserialization can still be
+/// retrieved and parsed manually.
+///
+/// Expected: Raw response with status, headers, & JSON body
+#[tokio::test]
+async fn test_send_raw() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"raw-etag\"")
+        .with_body(r#"{"renamed_message": "success"}"#)
+        .create_async()
+        .await;
+
+    let response = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send_raw()
+        .await?;
+
+    assert_eq!(response.status, reqwest::StatusCode::OK);
+    assert_eq!(response.headers.get("etag").unwrap(), "\"raw-etag\"");
+    assert_eq!(response.json()?["renamed_message"], "success");
+    assert_eq!(response.text(), r#"{"renamed_message": "success"}"#);
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests error handling of raw requests.
+///
+/// Verifies that ESI error responses are still returned as errors.
+///
+/// Expected: ESI error with status 404
+#[tokio::test]
+async fn test_send_raw_error() {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", "/test/notfound")
+        .with_status(404)
+        .with_body(r#"{"error": "Resource not found"}"#)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/notfound")
+        .send_raw()
+        .await;
+
+    assert!(matches!(result, Err(eve_esi::Error::EsiError(ref e)) if e.status == 404));
+
+    mock.assert_async().await;
+}
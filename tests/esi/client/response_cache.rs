@@ -0,0 +1,109 @@
+//! Integration tests for the `send_with_cache` method.
+//!
+//! Tests that response bodies are stored in a [`ResponseCache`] & reused when ESI
+//! responds with 304 Not Modified.
+
+use eve_esi::esi::CacheEntry;
+use eve_esi::{MemoryResponseCache, ResponseCache};
+use serde::Deserialize;
+
+use crate::util::integration_test_setup;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestData {
+    message: String,
+}
+
+/// Tests that a 304 Not Modified response returns the body stored in the cache.
+///
+/// Verifies that the first request stores the response body & ETag, and that the
+/// second request sends the stored ETag & deserializes the cached body.
+///
+/// Expected: Both requests return the same data
+#[tokio::test]
+async fn test_send_with_cache_not_modified() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+    let cache = MemoryResponseCache::new();
+
+    let fresh_mock = server
+        .mock("GET", "/test/cached")
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("etag", "\"etag\"")
+        .with_body(r#"{"message": "cached data"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let not_modified_mock = server
+        .mock("GET", "/test/cached")
+        .match_header("if-none-match", "\"etag\"")
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let fresh = client
+        .esi()
+        .new_request::<TestData>("/test/cached")
+        .send_with_cache(&cache)
+        .await?;
+
+    let cached = client
+        .esi()
+        .new_request::<TestData>("/test/cached")
+        .send_with_cache(&cache)
+        .await?;
+
+    fresh_mock.assert_async().await;
+    not_modified_mock.assert_async().await;
+
+    assert_eq!(fresh.data.message, "cached data");
+    assert_eq!(cached.data, fresh.data);
+    assert_eq!(cached.cache.etag, "\"etag\"");
+    assert_eq!(cache.len(), 1);
+
+    Ok(())
+}
+
+/// Tests that a changed response replaces the cached entry.
+///
+/// Expected: Fresh data is returned & the cache holds the new ETag
+#[tokio::test]
+async fn test_send_with_cache_updates_entry() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+    let cache = MemoryResponseCache::new();
+
+    let mock = server
+        .mock("GET", "/test/cached")
+        .match_header("if-none-match", "\"old-etag\"")
+        .with_status(200)
+        .with_header("etag", "\"new-etag\"")
+        .with_body(r#"{"message": "new data"}"#)
+        .create_async()
+        .await;
+
+    let url = format!("{}/test/cached", server.url());
+    cache
+        .put(
+            &url,
+            CacheEntry {
+                etag: "\"old-etag\"".to_string(),
+                body: br#"{"message": "old data"}"#.to_vec(),
+            },
+        )
+        .await?;
+
+    let response = client
+        .esi()
+        .new_request::<TestData>("/test/cached")
+        .send_with_cache(&cache)
+        .await?;
+
+    mock.assert_async().await;
+
+    assert_eq!(response.data.message, "new data");
+    assert_eq!(cache.get(&url).await?.unwrap().etag, "\"new-etag\"");
+
+    Ok(())
+}
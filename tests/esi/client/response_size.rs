@@ -0,0 +1,158 @@
+//! Integration tests for response size limits & streaming response bodies.
+//!
+//! Tests that responses exceeding the configured or per-request maximum response size
+//! return an error, and that response bodies can be streamed to a writer.
+
+use eve_esi::Error;
+use mockito::Server;
+use serde::Deserialize;
+
+use crate::util::integration_test_setup;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestData {
+    message: String,
+}
+
+/// Creates a client for the mock server with the provided maximum response size
+fn response_size_test_client(server: &Server, max_response_size: u64) -> eve_esi::Client {
+    let config = eve_esi::Config::builder()
+        .esi_url(&server.url())
+        .esi_max_response_size(max_response_size)
+        .build()
+        .expect("Failed to build Config");
+
+    eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .build()
+        .expect("Failed to build Client")
+}
+
+/// Tests that a response exceeding the client's maximum response size returns an error.
+///
+/// Expected: Error::ResponseTooLarge with the configured limit
+#[tokio::test]
+async fn test_response_exceeds_client_limit() {
+    let mut server = Server::new_async().await;
+    let client = response_size_test_client(&server, 8);
+
+    let mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "success"}"#)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .send()
+        .await;
+
+    mock.assert_async().await;
+
+    assert!(matches!(
+        result,
+        Err(Error::ResponseTooLarge { limit: 8, .. })
+    ));
+}
+
+/// Tests that a per-request maximum response size overrides the client's setting.
+///
+/// Expected: Response within the per-request limit is deserialized successfully
+#[tokio::test]
+async fn test_request_overrides_client_limit() -> Result<(), Error> {
+    let mut server = Server::new_async().await;
+    let client = response_size_test_client(&server, 8);
+
+    let mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "success"}"#)
+        .create_async()
+        .await;
+
+    let response = client
+        .esi()
+        .new_request::<TestData>("/test/endpoint")
+        .with_max_response_size(1024)
+        .send()
+        .await?;
+
+    mock.assert_async().await;
+
+    assert_eq!(response.data.message, "success");
+
+    Ok(())
+}
+
+/// Tests streaming a response body to a writer.
+///
+/// Expected: The full body is written & the number of bytes written is returned
+#[tokio::test]
+async fn test_send_to_writer() -> Result<(), Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let body = r#"[{"order_id": 1}, {"order_id": 2}]"#;
+    let mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"stream-etag\"")
+        .with_body(body)
+        .create_async()
+        .await;
+
+    let mut writer: Vec<u8> = Vec::new();
+    let response = client
+        .esi()
+        .new_request::<serde_json::Value>("/test/endpoint")
+        .send_to_writer(&mut writer)
+        .await?;
+
+    mock.assert_async().await;
+
+    assert_eq!(response.data, body.len() as u64);
+    assert_eq!(response.cache.etag, "\"stream-etag\"");
+    assert_eq!(writer, body.as_bytes());
+
+    Ok(())
+}
+
+/// Tests streaming a response declared larger than the maximum response size to a writer.
+///
+/// The body spans many chunks so chunks within the limit would be written before it was exceeded
+/// if the `Content-Length` header was not checked first.
+///
+/// Expected: Error::ResponseTooLarge with nothing written to the writer
+#[tokio::test]
+async fn test_send_to_writer_exceeds_limit() {
+    let mut server = Server::new_async().await;
+    let client = response_size_test_client(&server, 512 * 1024);
+
+    let mock = server
+        .mock("GET", "/test/endpoint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(vec![b'0'; 1024 * 1024])
+        .create_async()
+        .await;
+
+    let mut writer: Vec<u8> = Vec::new();
+    let result = client
+        .esi()
+        .new_request::<serde_json::Value>("/test/endpoint")
+        .send_to_writer(&mut writer)
+        .await;
+
+    mock.assert_async().await;
+
+    assert!(matches!(
+        result,
+        Err(Error::ResponseTooLarge { limit: 524288, .. })
+    ));
+    assert!(writer.is_empty());
+}
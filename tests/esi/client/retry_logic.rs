@@ -170,3 +170,78 @@ async fn test_various_5xx_errors_retry() -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Tests that retries wait for the Retry-After header, capped to the configured maximum.
+///
+/// Verifies that a 503 response with a Retry-After header of an hour, as returned during
+/// downtime, is retried after the 10 millisecond maximum configured for tests.
+///
+/// Expected: Request succeeds on the retry without waiting for the full Retry-After duration
+#[tokio::test]
+async fn test_retry_after_capped() -> Result<(), Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let downtime_mock = server
+        .mock("GET", "/test/downtime")
+        .with_status(503)
+        .with_header("retry-after", "3600")
+        .with_body(r#"{"error": "Service unavailable"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let success_mock = server
+        .mock("GET", "/test/downtime")
+        .with_status(200)
+        .with_body(r#"{"message": "success"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let start = std::time::Instant::now();
+    let request = client.esi().new_request::<TestData>("/test/downtime");
+    let result = request.send().await?;
+
+    assert_eq!(result.data.message, "success");
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+
+    downtime_mock.assert_async().await;
+    success_mock.assert_async().await;
+    Ok(())
+}
+
+/// Tests that 503 responses with a Retry-After header return a downtime error.
+///
+/// Verifies that once retries are exhausted, downtime is distinguished from outages by
+/// the Retry-After header & reports when ESI expects to be available again.
+///
+/// Expected: Downtime error with a retry time about 15 minutes from now
+#[tokio::test]
+async fn test_downtime_error() -> Result<(), Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", "/test/downtime")
+        .with_status(503)
+        .with_header("retry-after", "900")
+        .with_body(r#"{"error": "The datasource tranquility is temporarily unavailable"}"#)
+        .expect(3) // 1 initial + 2 retries
+        .create_async()
+        .await;
+
+    let request = client.esi().new_request::<TestData>("/test/downtime");
+    let result = request.send().await;
+
+    match result {
+        Err(Error::Downtime { retry_at, error }) => {
+            let retry_in = retry_at - chrono::Utc::now();
+            assert!(retry_in > chrono::TimeDelta::minutes(14));
+            assert!(retry_in <= chrono::TimeDelta::minutes(15));
+            assert_eq!(error.status, 503);
+        }
+        result => panic!("Expected Downtime error, got: {:?}", result),
+    }
+
+    mock.assert_async().await;
+    Ok(())
+}
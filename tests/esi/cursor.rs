@@ -0,0 +1,254 @@
+//! Integration tests for cursor paginated ESI requests.
+//!
+//! Tests that a `Cursor` walks the pages of a route with the `before` & `after`
+//! tokens returned in each response body, stopping once a page is empty or no
+//! token is returned, & keeps the `after` token to resume from later, and that
+//! `send_all_cursor` follows tokens provided by the `Link` header.
+
+use eve_esi::esi::{Cursor, CursorPage, CursorTokens};
+use serde::Deserialize;
+
+use crate::util::integration_test_setup;
+
+/// Page of a cursor paginated test route
+#[derive(Deserialize)]
+struct TestPage {
+    cursor: Option<CursorTokens>,
+    records: Vec<i32>,
+}
+
+impl CursorPage for TestPage {
+    type Item = i32;
+
+    fn cursor(&self) -> Option<&CursorTokens> {
+        self.cursor.as_ref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.records
+    }
+}
+
+/// Creates a mock for a page of the cursor paginated test route
+async fn mock_page(
+    server: &mut mockito::ServerGuard,
+    path: &str,
+    body: serde_json::Value,
+) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+/// Tests retrieving every record of a cursor paginated route from the newest.
+///
+/// Verifies that the first page is requested without a token & each further page
+/// with the `before` token of the previous page until an empty page is returned,
+/// keeping the `after` token of the first page.
+///
+/// Expected: 3 requests are made, records of all pages are returned in order
+#[tokio::test]
+async fn test_cursor_before_tokens() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mocks = vec![
+        mock_page(
+            &mut server,
+            "/test/endpoint",
+            serde_json::json!({
+                "cursor": { "before": "b1", "after": "a1" },
+                "records": [3, 2]
+            }),
+        )
+        .await,
+        mock_page(
+            &mut server,
+            "/test/endpoint?before=b1",
+            serde_json::json!({
+                "cursor": { "before": "b2", "after": "a2" },
+                "records": [1]
+            }),
+        )
+        .await,
+        mock_page(
+            &mut server,
+            "/test/endpoint?before=b2",
+            serde_json::json!({
+                "cursor": { "before": "b3", "after": "a3" },
+                "records": []
+            }),
+        )
+        .await,
+    ];
+
+    let mut cursor = Cursor::new(client.esi().new_request::<TestPage>("/test/endpoint"));
+    let records = cursor.collect_all().await?;
+
+    assert_eq!(records, vec![3, 2, 1]);
+    assert_eq!(cursor.after_token(), Some("a1"));
+    assert!(cursor.is_finished());
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    Ok(())
+}
+
+/// Tests retrieving the records modified since a previous cursor.
+///
+/// Verifies that each page is requested with the `after` token of the previous
+/// page & the latest `after` token is kept once an empty page is returned.
+///
+/// Expected: 2 requests are made & the latest `after` token is returned
+#[tokio::test]
+async fn test_cursor_after_tokens() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mocks = vec![
+        mock_page(
+            &mut server,
+            "/test/endpoint?after=a1",
+            serde_json::json!({
+                "cursor": { "before": "b1", "after": "a2" },
+                "records": [4]
+            }),
+        )
+        .await,
+        mock_page(
+            &mut server,
+            "/test/endpoint?after=a2",
+            serde_json::json!({
+                "cursor": { "before": "b2", "after": "a3" },
+                "records": []
+            }),
+        )
+        .await,
+    ];
+
+    let mut cursor = Cursor::after(client.esi().new_request::<TestPage>("/test/endpoint"), "a1");
+
+    assert_eq!(cursor.next_page().await?, Some(vec![4]));
+    assert_eq!(cursor.next_page().await?, None);
+    assert_eq!(cursor.after_token(), Some("a3"));
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    Ok(())
+}
+
+/// Tests a cursor stops when a page is returned without a token for the next page.
+///
+/// Expected: 1 request is made & further pages return `None` without a request
+#[tokio::test]
+async fn test_cursor_without_token() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = mock_page(
+        &mut server,
+        "/test/endpoint",
+        serde_json::json!({ "records": [1, 2] }),
+    )
+    .await;
+
+    let mut cursor = Cursor::new(client.esi().new_request::<TestPage>("/test/endpoint"));
+
+    assert_eq!(cursor.next_page().await?, Some(vec![1, 2]));
+    assert_eq!(cursor.next_page().await?, None);
+    assert_eq!(cursor.after_token(), None);
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests retrieving every record of a route providing the cursor tokens in a `Link` header.
+///
+/// Verifies that `send_all_cursor` follows the `before` token of the `Link` header
+/// when records are returned as a plain list, until a page without a token is returned.
+///
+/// Expected: 2 requests are made, records of both pages are returned in order
+#[tokio::test]
+async fn test_send_all_cursor_link_header() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let first_page = server
+        .mock("GET", "/test/endpoint?limit=2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header(
+            "link",
+            "</test/endpoint?limit=2&before=b1>; rel=\"before\", </test/endpoint?limit=2&after=a1>; rel=\"after\"",
+        )
+        .with_body("[3, 2]")
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", "/test/endpoint?limit=2&before=b1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[1]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let records = client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint")
+        .with_query("limit", 2)
+        .send_all_cursor()
+        .await?;
+
+    assert_eq!(records, vec![3, 2, 1]);
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests error handling when a subsequent cursor page fails.
+///
+/// Verifies that an error on any page is returned rather than partial results.
+///
+/// Expected: ESI error with status 500 is returned
+#[tokio::test]
+async fn test_send_all_cursor_error() {
+    let (client, mut server) = integration_test_setup().await;
+
+    let _first_page = mock_page(
+        &mut server,
+        "/test/endpoint",
+        serde_json::json!({
+            "cursor": { "before": "b1" },
+            "records": [2]
+        }),
+    )
+    .await;
+    let _second_page = server
+        .mock("GET", "/test/endpoint?before=b1")
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Internal server error"}"#)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<TestPage>("/test/endpoint")
+        .send_all_cursor()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(eve_esi::Error::EsiError(ref error)) if error.status == 500
+    ));
+}
@@ -0,0 +1,60 @@
+//! Integration tests for default headers configured on the ClientBuilder
+
+use mockito::Server;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestResponse {
+    value: String,
+}
+
+/// Tests that default headers are sent alongside the configured user agent
+///
+/// # Test Setup
+/// - Create a client with a user agent & a default header
+/// - Create a mock endpoint expecting both headers
+///
+/// # Assertions
+/// - Assert the request succeeds & the mock endpoint received both headers
+#[tokio::test]
+async fn test_default_headers_sent_with_user_agent() -> Result<(), eve_esi::Error> {
+    let mut server = Server::new_async().await;
+
+    let config = eve_esi::Config::builder()
+        .esi_url(&server.url())
+        .build()
+        .expect("Failed to build Config");
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "X-Compatibility-Date",
+        HeaderValue::from_static("2025-08-26"),
+    );
+
+    let client = eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .default_headers(headers)
+        .config(config)
+        .build()?;
+
+    let mock = server
+        .mock("GET", "/test")
+        .match_header("user-agent", "MyApp/1.0 (contact@example.com)")
+        .match_header("x-compatibility-date", "2025-08-26")
+        .with_status(200)
+        .with_body(r#"{"value": "test data"}"#)
+        .create_async()
+        .await;
+
+    let response = client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .send()
+        .await?;
+
+    assert_eq!(response.data.value, "test data");
+    mock.assert_async().await;
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! Integration tests for endpoints defined outside of the crate with `define_esi_endpoint!`
+
+use eve_esi::esi::Idempotency;
+use eve_esi::{define_esi_endpoint, Client, EsiRequest, Method, ScopeBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::util::integration_test_setup;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Application {
+    application_id: i64,
+    character_id: i64,
+}
+
+#[derive(Serialize)]
+struct ApplicationResponse {
+    accepted: bool,
+}
+
+/// Endpoints for routes the crate does not provide, defined like the built-in endpoints
+struct RecruitmentEndpoints<'a> {
+    client: &'a Client,
+}
+
+impl RecruitmentEndpoints<'_> {
+    define_esi_endpoint! {
+        /// Public route with path & query parameters
+        pub fn get_recruitment_ads(
+            corporation_id: i64;
+            language: Option<String>,
+            active: bool
+        ) -> EsiRequest<Vec<i64>>
+        method = Method::GET;
+        path = "/corporations/{}/recruitment";
+        deprecated_since = "2025-01-01";
+    }
+
+    define_esi_endpoint! {
+        /// Authenticated route with a body
+        auth fn respond_to_application(
+            access_token: impl Into<AccessToken>,
+            corporation_id: i64,
+            application_id: i64
+        ) -> EsiRequest<Application>
+        method = Method::PUT;
+        path = "/corporations/{}/applications/{}";
+        required_scopes = ScopeBuilder::new()
+            .custom("esi-corporations.write_applications.v1")
+            .build();
+        body = response: ApplicationResponse;
+    }
+
+    define_esi_endpoint! {
+        /// Read-only route using `POST` which is safe to retry
+        pub fn get_recruitment_ad_names(
+        ) -> EsiRequest<Vec<String>>
+        method = Method::POST;
+        path = "/corporations/recruitment/names";
+        body = ad_ids: Vec<i64>;
+        idempotency = Idempotency::Safe;
+    }
+}
+
+/// Tests endpoints defined outside of the crate build requests like the built-in endpoints
+///
+/// # Test Setup
+/// - Define a public & an authenticated endpoint with `define_esi_endpoint!`
+/// - Create mock endpoints matching the expected query string & body
+///
+/// # Assertions
+/// - Assert `None` query parameters are omitted & the deprecated route is reported
+/// - Assert the authenticated request is sent with the body & scopes required by the definition
+#[tokio::test]
+async fn test_define_esi_endpoint_downstream() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+    let recruitment = RecruitmentEndpoints {
+        client: &esi_client,
+    };
+
+    let mock_ads = mock_server
+        .mock("GET", "/corporations/98785281/recruitment?active=true")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[1, 2]")
+        .create();
+    let mock_application = mock_server
+        .mock("PUT", "/corporations/98785281/applications/5")
+        .match_body(mockito::Matcher::Json(
+            serde_json::json!({ "accepted": true }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"application_id": 5, "character_id": 2114794365}"#)
+        .create();
+
+    // Assert `None` query parameters are omitted & the deprecated route is reported
+    let ads = recruitment
+        .get_recruitment_ads(98785281, None, true)
+        .send()
+        .await
+        .unwrap();
+    mock_ads.assert();
+    assert_eq!(ads.data, vec![1, 2]);
+
+    let deprecated_routes = esi_client.deprecated_routes();
+    assert_eq!(deprecated_routes.len(), 1);
+    assert_eq!(deprecated_routes[0].function, "get_recruitment_ads");
+
+    // Assert the authenticated request is sent with the body & scopes required by the definition
+    let request = recruitment
+        .respond_to_application(
+            "access_token",
+            98785281,
+            5,
+            ApplicationResponse { accepted: true },
+        )
+        .skip_token_validation();
+    assert_eq!(
+        request.required_scopes(),
+        &vec!["esi-corporations.write_applications.v1".to_string()]
+    );
+
+    let application = request.send().await.unwrap();
+    mock_application.assert();
+    assert_eq!(
+        application.data,
+        Application {
+            application_id: 5,
+            character_id: 2114794365
+        }
+    );
+}
+
+/// Tests the idempotency class declared by an endpoint definition controls retries
+///
+/// # Test Setup
+/// - Define a `POST` endpoint declared safe to retry with `define_esi_endpoint!`
+/// - Create a mock endpoint returning a 500 error
+///
+/// # Assertions
+/// - Assert the request is classed as safe despite using `POST`
+/// - Assert the request is retried the configured number of times
+#[tokio::test]
+async fn test_define_esi_endpoint_idempotency() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+    let recruitment = RecruitmentEndpoints {
+        client: &esi_client,
+    };
+
+    let mock_names = mock_server
+        .mock("POST", "/corporations/recruitment/names")
+        .with_status(500)
+        .with_body(r#"{"error": "Internal server error"}"#)
+        .expect(3) // 1 initial + 2 retries
+        .create();
+
+    // Assert the request is classed as safe despite using `POST`
+    let request = recruitment.get_recruitment_ad_names(vec![1, 2]);
+    assert_eq!(request.idempotency(), Idempotency::Safe);
+
+    // Assert the request is retried the configured number of times
+    let result = request.send().await;
+    assert!(matches!(result, Err(eve_esi::Error::EsiError(ref e)) if e.status == 500));
+    mock_names.assert();
+}
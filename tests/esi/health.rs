@@ -0,0 +1,65 @@
+//! Integration tests for the client health report
+
+use reqwest::Method;
+use serde::Deserialize;
+
+use crate::util::integration_test_setup;
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct TestResponse {
+    value: String,
+}
+
+/// Tests the health report reflects the client's configuration & observed rate limits
+///
+/// # Test Setup
+/// - Create a client configured with OAuth2 & a mock server
+/// - Send a request to an endpoint returning rate limit headers
+///
+/// # Assertions
+/// - Assert the report contains the configured ESI URL & OAuth2 configuration
+/// - Assert the rate limit headers of the response are reported
+/// - Assert no JWT keys are reported as cached since none were fetched
+/// - Assert the report can be serialized to JSON
+#[tokio::test]
+async fn test_client_health() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", "/test")
+        .with_status(200)
+        .with_header("X-Esi-Error-Limit-Group", "esi-search")
+        .with_header("X-Esi-Error-Limit-Limit", "150/15m")
+        .with_header("X-Esi-Error-Limit-Remain", "145")
+        .with_header("X-Esi-Error-Limit-Used", "5")
+        .with_body(r#"{"value": "test data"}"#)
+        .create_async()
+        .await;
+
+    client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .with_method(Method::GET)
+        .send()
+        .await?;
+
+    mock.assert_async().await;
+
+    let health = client.health().await;
+
+    assert_eq!(health.esi_url, server.url());
+    assert!(health.oauth2_configured);
+    assert_eq!(health.error_limit_reset_at, None);
+    assert_eq!(health.rate_limits.len(), 1);
+    assert_eq!(health.rate_limits[0].group, "esi-search");
+    assert_eq!(health.rate_limits[0].remaining, 145);
+    assert_eq!(health.in_flight_requests, 0);
+    assert_eq!(health.name_cache_size, 0);
+    assert!(!health.jwt_key_cache.cached);
+    assert_eq!(health.jwt_key_cache.age_secs, None);
+
+    let json = serde_json::to_value(&health)?;
+    assert_eq!(json["rate_limits"][0]["group"], "esi-search");
+
+    Ok(())
+}
@@ -1,4 +1,11 @@
 mod cache_strategy;
+#[cfg(feature = "record-replay")]
+mod cassette;
 mod client;
+mod cursor;
+mod default_headers;
+mod define_endpoint;
+mod health;
+mod pagination;
 mod response_headers;
 mod validate_token_before_request;
@@ -0,0 +1,208 @@
+//! Integration tests for paginated ESI requests.
+//!
+//! Tests that the `send_all_pages` method requests every page indicated by the
+//! `X-Pages` header and combines the entries of each page in order, and that the
+//! `page_count` method retrieves the `X-Pages` header without fetching entries.
+
+use std::sync::{Arc, Mutex};
+
+use crate::util::integration_test_setup;
+
+/// Tests retrieving all pages of a paginated endpoint.
+///
+/// Verifies that after requesting the first page, the remaining pages indicated
+/// by the `X-Pages` header are requested and their entries appended in order.
+///
+/// Expected: 3 requests are made & entries of all pages are returned in order
+#[tokio::test]
+async fn test_send_all_pages() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mut mocks = Vec::new();
+    for page in 1..=3 {
+        let mock = server
+            .mock("GET", format!("/test/endpoint?page={}", page).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("x-pages", "3")
+            .with_body(format!("[{}, {}]", page * 10, page * 10 + 1))
+            .create_async()
+            .await;
+
+        mocks.push(mock);
+    }
+
+    let response = client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint?page=1")
+        .send_all_pages()
+        .await?;
+
+    assert_eq!(response.data, vec![10, 11, 20, 21, 30, 31]);
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    Ok(())
+}
+
+/// Tests retrieving all pages of an endpoint without an `X-Pages` header.
+///
+/// Verifies that only a single request is made when the response does not
+/// indicate additional pages.
+///
+/// Expected: 1 request is made for the first page
+#[tokio::test]
+async fn test_send_all_pages_single_page() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("GET", "/test/endpoint?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[1, 2]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let response = client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint")
+        .send_all_pages()
+        .await?;
+
+    assert_eq!(response.data, vec![1, 2]);
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests error handling when a subsequent page fails.
+///
+/// Verifies that an error on any page is returned rather than partial results.
+///
+/// Expected: ESI error with status 500 is returned
+#[tokio::test]
+async fn test_send_all_pages_error() {
+    let (client, mut server) = integration_test_setup().await;
+
+    let _page_1 = server
+        .mock("GET", "/test/endpoint?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-pages", "2")
+        .with_body("[1]")
+        .create_async()
+        .await;
+    let _page_2 = server
+        .mock("GET", "/test/endpoint?page=2")
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error": "Internal Server Error"}"#)
+        .create_async()
+        .await;
+
+    let result = client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint?page=1")
+        .send_all_pages()
+        .await;
+
+    assert!(matches!(result, Err(eve_esi::Error::EsiError(ref e)) if e.status == 500));
+}
+
+/// Tests the progress callback registered with `on_page`.
+///
+/// Verifies that the callback is invoked after each page with the page number
+/// and total number of pages.
+///
+/// Expected: callback is invoked with (1, 2) then (2, 2)
+#[tokio::test]
+async fn test_send_all_pages_on_page() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    for page in 1..=2 {
+        server
+            .mock("GET", format!("/test/endpoint?page={}", page).as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("x-pages", "2")
+            .with_body(format!("[{}]", page))
+            .create_async()
+            .await;
+    }
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = progress.clone();
+
+    client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint")
+        .on_page(move |page, total| recorded.lock().unwrap().push((page, total)))
+        .send_all_pages()
+        .await?;
+
+    assert_eq!(*progress.lock().unwrap(), vec![(1, 2), (2, 2)]);
+
+    Ok(())
+}
+
+/// Tests retrieving the page count of a paginated endpoint.
+///
+/// Verifies that a HEAD request is made for the first page of GET requests and
+/// the `X-Pages` header value is returned.
+///
+/// Expected: 1 HEAD request is made & a page count of 5 is returned
+#[tokio::test]
+async fn test_page_count() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("HEAD", "/test/endpoint?page=1")
+        .with_status(200)
+        .with_header("x-pages", "5")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let pages = client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint")
+        .page_count()
+        .await?;
+
+    assert_eq!(pages, 5);
+
+    mock.assert_async().await;
+
+    Ok(())
+}
+
+/// Tests retrieving the page count of an endpoint without an `X-Pages` header.
+///
+/// Expected: a page count of 1 is returned
+#[tokio::test]
+async fn test_page_count_single_page() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    let mock = server
+        .mock("HEAD", "/test/endpoint?page=1")
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let pages = client
+        .esi()
+        .new_request::<Vec<i32>>("/test/endpoint")
+        .page_count()
+        .await?;
+
+    assert_eq!(pages, 1);
+
+    mock.assert_async().await;
+
+    Ok(())
+}
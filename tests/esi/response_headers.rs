@@ -184,3 +184,40 @@ async fn test_cached_response_with_esi_response() -> Result<(), eve_esi::Error>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_esi_response_send_and_measure() -> Result<(), eve_esi::Error> {
+    let (client, mut server) = integration_test_setup().await;
+
+    // Mock endpoint that returns the ESI request ID header
+    let mock = server
+        .mock("GET", "/test")
+        .with_status(200)
+        .with_header("X-Esi-Request-Id", "0d2f4b3e-1c7a-4e4a-9d0f-3b5c6a7e8f90")
+        .with_body(r#"{"value": "test data"}"#)
+        .create_async()
+        .await;
+
+    let request = client
+        .esi()
+        .new_request::<TestResponse>("/test")
+        .with_method(Method::GET);
+
+    let measured = request.send_and_measure().await?;
+
+    // Verify data is accessible through the measured response
+    assert_eq!(measured.data.value, "test data");
+
+    // Verify request ID & timing
+    assert_eq!(
+        measured.request_id.as_deref(),
+        Some("0d2f4b3e-1c7a-4e4a-9d0f-3b5c6a7e8f90")
+    );
+    assert_eq!(measured.timing.attempts, 1);
+    assert!(measured.timing.total >= measured.timing.time_to_headers);
+    assert!(measured.timing.total >= measured.timing.queue_wait);
+
+    mock.assert_async().await;
+
+    Ok(())
+}
@@ -37,3 +37,64 @@ async fn test_validate_token_before_request_disabled() -> Result<(), eve_esi::Er
 
     Ok(())
 }
+
+/// Validation is made despite ESI client config disabling it due to per-request override
+#[tokio::test]
+async fn test_validate_token_before_request_require_override() -> Result<(), eve_esi::Error> {
+    let (_, mut mock_server) = integration_test_setup().await;
+
+    let config = eve_esi::Config::builder()
+        .esi_validate_token_before_request(false)
+        .jwk_url(&format!("{}/oauth/jwks", mock_server.url()))
+        .build()
+        .expect("Failed to build ESI Config");
+
+    let esi_client = eve_esi::Client::builder()
+        .client_id("client_id")
+        .client_secret("client_secret")
+        .callback_url("http://localhost:8080/callback")
+        .config(config)
+        .build()?;
+
+    let expected_requests = 1;
+    let mock_jwt_key_endpoint = get_jwk_success_response(&mut mock_server, expected_requests);
+
+    let mock_token = create_mock_token(false);
+    let access_token = mock_token.access_token().secret().to_string();
+
+    let character_id = 123456789;
+    let character_endpoints = esi_client.character();
+    let request = character_endpoints
+        .get_agents_research(&access_token, character_id)
+        .require_token_validation();
+    let _ = request.send().await;
+
+    // Assert JWT keys were fetched to validate the token
+    mock_jwt_key_endpoint.assert();
+
+    Ok(())
+}
+
+/// No validation will be made despite ESI client config enabling it due to per-request override
+#[tokio::test]
+async fn test_validate_token_before_request_skip_override() -> Result<(), eve_esi::Error> {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+
+    let expected_requests = 0;
+    let mock_jwt_key_endpoint = get_jwk_success_response(&mut mock_server, expected_requests);
+
+    let mock_token = create_mock_token(false);
+    let access_token = mock_token.access_token().secret().to_string();
+
+    let character_id = 123456789;
+    let character_endpoints = esi_client.character();
+    let request = character_endpoints
+        .get_agents_research(&access_token, character_id)
+        .skip_token_validation();
+    let _ = request.send().await;
+
+    // Assert no requests were made due to validation being skipped
+    mock_jwt_key_endpoint.assert();
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+//! Golden-file tests asserting models round-trip responses recorded from ESI
+//!
+//! Re-record the golden files with `cargo run --example record_goldens --features golden`.
+
+use eve_esi::golden::assert_golden;
+
+/// Builds a client for building requests, requests are never sent
+fn golden_client() -> eve_esi::Client {
+    eve_esi::Client::new("MyApp/1.0 (contact@example.com)").expect("Failed to build Client")
+}
+
+/// Returns the path of a golden file in `tests/golden`
+fn golden(name: &str) -> String {
+    format!("{}/tests/golden/{}.json", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+/// Tests the Alliance model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_alliance_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.alliance().get_alliance_information(99013534),
+        golden("alliance"),
+    );
+}
+
+/// Tests the Character model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_character_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client
+            .character()
+            .get_character_public_information(2114794365),
+        golden("character"),
+    );
+}
+
+/// Tests the Corporation model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_corporation_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.corporation().get_corporation_information(98785281),
+        golden("corporation"),
+    );
+}
+
+/// Tests the SolarSystem model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_solar_system_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.universe().get_solar_system_information(30000142),
+        golden("solar_system"),
+    );
+}
+
+/// Tests the ItemType model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_item_type_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.universe().get_item_type_information(587),
+        golden("item_type"),
+    );
+}
+
+/// Tests the Stargate model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_stargate_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.universe().get_stargate_information(50001248),
+        golden("stargate"),
+    );
+}
+
+/// Tests the Station model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_station_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.universe().get_station_information(60003760),
+        golden("station"),
+    );
+}
+
+/// Tests the Faction model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_factions_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(&client.universe().get_factions(), golden("factions"));
+}
+
+/// Tests the MarketItemPrices model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_market_prices_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.market().list_market_prices(),
+        golden("market_prices"),
+    );
+}
+
+/// Tests the AllianceIcons model round-trips its golden file
+///
+/// # Assertions
+/// - Asserts the golden file deserializes & serializes without differences
+#[test]
+fn test_alliance_icons_golden() {
+    let client = golden_client();
+
+    // Assert the golden file deserializes & serializes without differences
+    assert_golden(
+        &client.alliance().get_alliance_icon(99013534),
+        golden("alliance_icons"),
+    );
+}
+
+/// Tests a golden file with a field the model does not have fails
+///
+/// # Assertions
+/// - Asserts the model not round-tripping the golden file panics
+#[test]
+#[should_panic(expected = "does not round-trip golden file")]
+fn test_golden_unknown_field() {
+    let client = golden_client();
+    let path = std::env::temp_dir().join("eve_esi_golden_unknown_field.json");
+    std::fs::write(
+        &path,
+        r#"{"creator_corporation_id": 1, "creator_id": 2, "date_founded": "2024-09-25T06:25:58Z", "name": "Alliance", "ticker": "ALLY", "new_field": 3}"#,
+    )
+    .unwrap();
+
+    // Assert the model not round-tripping the golden file panics
+    assert_golden(&client.alliance().get_alliance_information(1), &path);
+}
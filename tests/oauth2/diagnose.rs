@@ -0,0 +1,187 @@
+use eve_esi::oauth2::diagnose::OAuthIssue;
+use eve_esi::{Config, Environment, ScopeBuilder};
+use mockito::{Mock, Server, ServerGuard};
+
+use crate::oauth2::util::jwk_response::{
+    get_jwk_internal_server_error_response, get_jwk_success_response,
+};
+
+/// Client ID in the format issued by EVE Online's developer portal
+const VALID_CLIENT_ID: &str = "0123456789abcdef0123456789abcdef";
+
+/// Creates a mock server & a client with every SSO URL pointing to it
+async fn diagnose_test_setup(
+    client_id: &str,
+    callback_url: &str,
+) -> (eve_esi::Client, ServerGuard) {
+    let mock_server = Server::new_async().await;
+
+    let config = Config::builder()
+        .environment(Environment::Custom(mock_server.url()))
+        .build()
+        .expect("Failed to build Config");
+
+    let esi_client = eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .client_id(client_id)
+        .client_secret("client_secret")
+        .callback_url(callback_url)
+        .config(config)
+        .build()
+        .expect("Failed to build Client");
+
+    (esi_client, mock_server)
+}
+
+/// Adds a server metadata endpoint advertising the provided token endpoint
+fn mock_server_metadata(server: &mut ServerGuard, token_endpoint: &str) -> Mock {
+    let url = server.url();
+    let metadata = serde_json::json!({
+        "issuer": url,
+        "authorization_endpoint": format!("{}/v2/oauth/authorize", url),
+        "token_endpoint": token_endpoint,
+        "jwks_uri": format!("{}/oauth/jwks", url),
+        "response_types_supported": ["code", "token"],
+        "scopes_supported": ["publicData", "esi-assets.read_assets.v1"],
+        "code_challenge_methods_supported": ["S256"]
+    });
+
+    server
+        .mock("GET", "/.well-known/oauth-authorization-server")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(metadata.to_string())
+        .expect(1)
+        .create()
+}
+
+/// Tests a correctly configured client is diagnosed without issues
+///
+/// # Test Setup
+/// - Create a client with a valid client ID & a local callback URL
+/// - Create mock server metadata & JWT key endpoints matching the client's configuration
+///
+/// # Assertions
+/// - Assert the server metadata & JWT key endpoints were requested once each
+/// - Assert no issues were found & the server metadata was returned
+#[tokio::test]
+async fn test_diagnose_no_issues() {
+    let (esi_client, mut mock_server) =
+        diagnose_test_setup(VALID_CLIENT_ID, "http://localhost:8000/callback").await;
+    let token_endpoint = format!("{}/v2/oauth/token", mock_server.url());
+    let metadata_mock = mock_server_metadata(&mut mock_server, &token_endpoint);
+    let jwk_mock = get_jwk_success_response(&mut mock_server, 1);
+
+    let scopes = ScopeBuilder::new().public_data().build();
+    let diagnosis = esi_client.oauth2().diagnose(scopes).await;
+
+    // Assert the server metadata & JWT key endpoints were requested once each
+    metadata_mock.assert();
+    jwk_mock.assert();
+
+    // Assert no issues were found & the server metadata was returned
+    assert!(diagnosis.is_ok(), "{:?}", diagnosis.issues());
+    assert_eq!(diagnosis.metadata().unwrap().token_endpoint, token_endpoint);
+}
+
+/// Tests each misconfiguration of a client is reported in the order checked
+///
+/// # Test Setup
+/// - Create a client with a malformed client ID & an insecure callback URL with a fragment
+/// - Create a mock server metadata endpoint advertising a different token endpoint
+/// - Create a mock JWT key endpoint returning an error
+///
+/// # Assertions
+/// - Assert the client, endpoint, JWT key & scope issues are returned in order
+/// - Assert the server metadata was still returned
+#[tokio::test]
+async fn test_diagnose_misconfigured_client() {
+    let (esi_client, mut mock_server) =
+        diagnose_test_setup("client_id", "http://example.com/callback#login").await;
+    let _metadata_mock = mock_server_metadata(&mut mock_server, "https://example.com/token");
+    let _jwk_mock = get_jwk_internal_server_error_response(&mut mock_server, 1);
+
+    let scopes = vec![
+        "publicData".to_string(),
+        "esi-assets.read_assets.v1".to_string(),
+        "esi-skills.read_skills.v1".to_string(),
+    ];
+    let diagnosis = esi_client.oauth2().diagnose(scopes).await;
+
+    // Assert the client, endpoint, JWT key & scope issues are returned in order
+    let issues = diagnosis.issues();
+    assert_eq!(issues.len(), 6, "{:?}", issues);
+    assert_eq!(
+        issues[0],
+        OAuthIssue::MalformedClientId {
+            client_id: "client_id".to_string()
+        }
+    );
+    assert_eq!(
+        issues[1],
+        OAuthIssue::InsecureCallbackUrl {
+            callback_url: "http://example.com/callback#login".to_string()
+        }
+    );
+    assert_eq!(
+        issues[2],
+        OAuthIssue::CallbackUrlFragment {
+            callback_url: "http://example.com/callback#login".to_string()
+        }
+    );
+    assert_eq!(
+        issues[3],
+        OAuthIssue::TokenUrlMismatch {
+            configured: format!("{}/v2/oauth/token", mock_server.url()),
+            advertised: "https://example.com/token".to_string()
+        }
+    );
+    assert!(matches!(issues[4], OAuthIssue::JwkUnreachable { .. }));
+    assert_eq!(
+        issues[5],
+        OAuthIssue::UnsupportedScope {
+            scope: "esi-skills.read_skills.v1".to_string()
+        }
+    );
+
+    // Assert the server metadata was still returned
+    assert!(!diagnosis.is_ok());
+    assert!(diagnosis.metadata().is_some());
+}
+
+/// Tests an unreachable server metadata endpoint is reported without skipping further checks
+///
+/// # Test Setup
+/// - Create a client with a valid client ID & a local callback URL
+/// - Create a mock JWT key endpoint, without a server metadata endpoint
+///
+/// # Assertions
+/// - Assert the unreachable metadata & unknown scope are reported
+/// - Assert no server metadata was returned
+#[tokio::test]
+async fn test_diagnose_metadata_unreachable() {
+    let (esi_client, mut mock_server) =
+        diagnose_test_setup(VALID_CLIENT_ID, "http://localhost:8000/callback").await;
+    let _jwk_mock = get_jwk_success_response(&mut mock_server, 1);
+
+    let scopes = vec!["publicData".to_string(), "esi-typo.read.v1".to_string()];
+    let diagnosis = esi_client.oauth2().diagnose(scopes).await;
+
+    // Assert the unreachable metadata & unknown scope are reported
+    let issues = diagnosis.issues();
+    assert_eq!(issues.len(), 2, "{:?}", issues);
+    assert!(matches!(
+        issues[0],
+        OAuthIssue::MetadataUnreachable { ref url, .. }
+            if url == &format!("{}/.well-known/oauth-authorization-server", mock_server.url())
+    ));
+    assert_eq!(
+        issues[1],
+        OAuthIssue::UnknownScope {
+            scope: "esi-typo.read.v1".to_string()
+        }
+    );
+
+    // Assert no server metadata was returned
+    assert!(diagnosis.metadata().is_none());
+}
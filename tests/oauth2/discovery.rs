@@ -0,0 +1,202 @@
+use eve_esi::{Config, Environment, ScopeBuilder};
+use mockito::{Mock, Server, ServerGuard};
+
+use crate::constant::TEST_CLIENT_ID;
+use crate::oauth2::util::{
+    jwk_response::get_jwk_success_response,
+    jwt::{create_mock_token, create_mock_token_keys},
+};
+
+/// Creates a mock server & a client discovering its OAuth2 endpoints from it
+async fn discovery_test_setup() -> (eve_esi::Client, ServerGuard) {
+    let mock_server = Server::new_async().await;
+
+    let config = Config::builder()
+        .environment(Environment::Custom(mock_server.url()))
+        .oauth2_discovery(true)
+        .build()
+        .expect("Failed to build Config");
+
+    let esi_client = eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .client_id(TEST_CLIENT_ID)
+        .client_secret("client_secret")
+        .callback_url("http://localhost:8000/callback")
+        .config(config)
+        .build()
+        .expect("Failed to build Client");
+
+    (esi_client, mock_server)
+}
+
+/// Adds a server metadata endpoint advertising endpoints under `/sso` rather than the configured paths
+fn mock_server_metadata(server: &mut ServerGuard, expect: usize) -> Mock {
+    let url = server.url();
+    let metadata = serde_json::json!({
+        "issuer": url,
+        "authorization_endpoint": format!("{}/sso/authorize", url),
+        "token_endpoint": format!("{}/sso/token", url),
+        "jwks_uri": format!("{}/sso/jwks", url),
+        "response_types_supported": ["code"]
+    });
+
+    server
+        .mock("GET", "/.well-known/oauth-authorization-server")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(metadata.to_string())
+        .expect(expect)
+        .create()
+}
+
+/// Tests the endpoints advertised by the server metadata replace the configured endpoints
+///
+/// # Test Setup
+/// - Create a mock server metadata endpoint advertising endpoints under `/sso`
+/// - Create a mock JWT key endpoint at the advertised JWK URL
+///
+/// # Assertions
+/// - Assert the discovered endpoints are used
+/// - Assert login URLs use the discovered authorization URL
+/// - Assert JWT keys are fetched from the discovered JWK URL
+/// - Assert the server metadata was only requested once
+#[tokio::test]
+async fn test_discover_endpoints() {
+    let (esi_client, mut mock_server) = discovery_test_setup().await;
+    let metadata_mock = mock_server_metadata(&mut mock_server, 1);
+    let jwk_mock = mock_server
+        .mock("GET", "/sso/jwks")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token_keys(false)).unwrap())
+        .expect(1)
+        .create();
+
+    // Assert the discovered endpoints are used
+    assert!(esi_client.oauth2().discover_endpoints().await);
+
+    // Assert login URLs use the discovered authorization URL
+    let scopes = ScopeBuilder::new().public_data().build();
+    let login = esi_client.oauth2().login_url(scopes).unwrap();
+    assert!(login
+        .login_url
+        .starts_with(&format!("{}/sso/authorize", mock_server.url())));
+
+    // Assert JWT keys are fetched from the discovered JWK URL
+    let result = esi_client.oauth2().jwk().fetch_jwt_keys().await;
+    assert!(result.is_ok());
+    jwk_mock.assert();
+
+    // Assert the server metadata was only requested once
+    assert!(esi_client.oauth2().discover_endpoints().await);
+    metadata_mock.assert();
+}
+
+/// Tests endpoints are discovered on first use when requesting a token
+///
+/// # Test Setup
+/// - Create a mock server metadata endpoint advertising endpoints under `/sso`
+/// - Create a mock token endpoint at the advertised token URL
+///
+/// # Assertions
+/// - Assert the token was requested from the discovered token URL
+/// - Assert the server metadata was requested once
+#[tokio::test]
+async fn test_discover_endpoints_on_first_use() {
+    let (esi_client, mut mock_server) = discovery_test_setup().await;
+    let metadata_mock = mock_server_metadata(&mut mock_server, 1);
+    let token_mock = mock_server
+        .mock("POST", "/sso/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token(false)).unwrap())
+        .expect(1)
+        .create();
+
+    let result = esi_client.oauth2().get_token("authorization_code").await;
+
+    // Assert the token was requested from the discovered token URL
+    assert!(result.is_ok());
+    token_mock.assert();
+
+    // Assert the server metadata was requested once
+    metadata_mock.assert();
+}
+
+/// Tests the configured endpoints are used when the server metadata can't be retrieved
+///
+/// # Test Setup
+/// - Create a mock server metadata endpoint returning an error
+/// - Create a mock JWT key endpoint at the configured JWK URL
+///
+/// # Assertions
+/// - Assert the configured endpoints are used
+/// - Assert login URLs use the configured authorization URL
+/// - Assert JWT keys are fetched from the configured JWK URL
+/// - Assert discovery is reattempted by the JWT key request
+#[tokio::test]
+async fn test_discover_endpoints_fallback() {
+    let (esi_client, mut mock_server) = discovery_test_setup().await;
+    let metadata_mock = mock_server
+        .mock("GET", "/.well-known/oauth-authorization-server")
+        .with_status(503)
+        .expect(2)
+        .create();
+    let jwk_mock = get_jwk_success_response(&mut mock_server, 1);
+
+    // Assert the configured endpoints are used
+    assert!(!esi_client.oauth2().discover_endpoints().await);
+
+    // Assert login URLs use the configured authorization URL
+    let scopes = ScopeBuilder::new().public_data().build();
+    let login = esi_client.oauth2().login_url(scopes).unwrap();
+    assert!(login
+        .login_url
+        .starts_with(&format!("{}/v2/oauth/authorize", mock_server.url())));
+
+    // Assert JWT keys are fetched from the configured JWK URL
+    let result = esi_client.oauth2().jwk().fetch_jwt_keys().await;
+    assert!(result.is_ok());
+    jwk_mock.assert();
+
+    // Assert discovery is reattempted by the JWT key request
+    metadata_mock.assert();
+}
+
+/// Tests a failed discovery isn't cached & a later discovery succeeds
+///
+/// # Test Setup
+/// - Create a mock server metadata endpoint returning an error
+/// - Replace it with a mock server metadata endpoint advertising endpoints under `/sso`
+///
+/// # Assertions
+/// - Assert the configured endpoints are used while the metadata can't be retrieved
+/// - Assert the discovered endpoints are used once the metadata is retrieved
+/// - Assert login URLs use the discovered authorization URL
+#[tokio::test]
+async fn test_discover_endpoints_retry_after_failure() {
+    let (esi_client, mut mock_server) = discovery_test_setup().await;
+    let failed_mock = mock_server
+        .mock("GET", "/.well-known/oauth-authorization-server")
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    // Assert the configured endpoints are used while the metadata can't be retrieved
+    assert!(!esi_client.oauth2().discover_endpoints().await);
+    failed_mock.assert();
+    failed_mock.remove();
+
+    let metadata_mock = mock_server_metadata(&mut mock_server, 1);
+
+    // Assert the discovered endpoints are used once the metadata is retrieved
+    assert!(esi_client.oauth2().discover_endpoints().await);
+    metadata_mock.assert();
+
+    // Assert login URLs use the discovered authorization URL
+    let scopes = ScopeBuilder::new().public_data().build();
+    let login = esi_client.oauth2().login_url(scopes).unwrap();
+    assert!(login
+        .login_url
+        .starts_with(&format!("{}/sso/authorize", mock_server.url())));
+}
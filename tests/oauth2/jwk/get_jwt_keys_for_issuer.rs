@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use eve_esi::{Error, OAuthError};
+use futures_util::future::join_all;
+use mockito::{Server, ServerGuard};
+
+use crate::oauth2::util::jwt::create_mock_token_keys;
+
+/// Additional issuer configured with its own JWK URL on the mock server
+const ISSUER: &str = "https://sisilogin.testeveonline.com";
+
+/// Creates a client configured with an additional issuer whose JWK URL is on the mock server
+async fn issuer_test_setup() -> (eve_esi::Client, ServerGuard) {
+    let mock_server = Server::new_async().await;
+
+    let config = eve_esi::Config::builder()
+        .jwk_url(&format!("{}/oauth/jwks", mock_server.url()))
+        .jwt_issuer_jwk_url(ISSUER, &format!("{}/sisi/oauth/jwks", mock_server.url()))
+        .jwk_refresh_backoff(Duration::from_millis(1))
+        .jwk_refresh_timeout(Duration::from_secs(1))
+        .build()
+        .expect("Failed to build Config");
+    let esi_client = eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .config(config)
+        .build()
+        .expect("Failed to build Client");
+
+    (esi_client, mock_server)
+}
+
+/// Tests concurrent requests for an issuer's keys with an empty cache make a single fetch.
+///
+/// # Test Setup
+/// - Create a client configured with an additional issuer & a mock of the issuer's JWK URL
+/// - Request the issuer's keys 10 times concurrently
+///
+/// # Assertions
+/// - Assert the issuer's JWK URL was requested once
+/// - Assert every request returned the keys
+#[tokio::test]
+async fn get_jwt_keys_for_issuer_concurrent_single_fetch() {
+    let (esi_client, mut mock_server) = issuer_test_setup().await;
+
+    let mock = mock_server
+        .mock("GET", "/sisi/oauth/jwks")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token_keys(false)).unwrap())
+        .expect(1)
+        .create();
+
+    let oauth2 = esi_client.oauth2();
+    let jwk = oauth2.jwk();
+    let results = join_all((0..10).map(|_| jwk.get_jwt_keys_for_issuer(ISSUER))).await;
+
+    // Assert the issuer's JWK URL was requested once
+    mock.assert();
+
+    // Assert every request returned the keys
+    assert!(results.iter().all(Result::is_ok), "{:#?}", results);
+}
+
+/// Tests a failed refresh of an issuer's keys is followed by a cooldown.
+///
+/// # Test Setup
+/// - Create a client configured with an additional issuer & a mock of the issuer's JWK URL
+///   returning an error
+/// - Request the issuer's keys twice
+///
+/// # Assertions
+/// - Assert the issuer's JWK URL was only requested by the first request & its retries
+/// - Assert the first request returned the fetch error
+/// - Assert the second request returned a cooldown error without fetching
+#[tokio::test]
+async fn get_jwt_keys_for_issuer_failure_cooldown() {
+    let (esi_client, mut mock_server) = issuer_test_setup().await;
+
+    // 1 initial attempt & the default of 2 retries
+    let mock = mock_server
+        .mock("GET", "/sisi/oauth/jwks")
+        .with_status(500)
+        .expect(3)
+        .create();
+
+    let oauth2 = esi_client.oauth2();
+    let jwk = oauth2.jwk();
+    let first = jwk.get_jwt_keys_for_issuer(ISSUER).await;
+    let second = jwk.get_jwt_keys_for_issuer(ISSUER).await;
+
+    // Assert the issuer's JWK URL was only requested by the first request & its retries
+    mock.assert();
+
+    // Assert the first request returned the fetch error
+    assert!(first.is_err());
+    assert!(!matches!(
+        first,
+        Err(Error::OAuthError(OAuthError::JwtKeyRefreshCooldown(_)))
+    ));
+
+    // Assert the second request returned a cooldown error without fetching
+    assert!(matches!(
+        second,
+        Err(Error::OAuthError(OAuthError::JwtKeyRefreshCooldown(_)))
+    ));
+}
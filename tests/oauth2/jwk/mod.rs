@@ -1,3 +1,4 @@
 mod fetch_and_update_cache;
 mod fetch_jwt_keys;
 mod get_jwt_keys;
+mod get_jwt_keys_for_issuer;
@@ -1,3 +1,6 @@
+mod diagnose;
+mod discovery;
 pub mod jwk;
+mod session;
 mod token;
 pub mod util;
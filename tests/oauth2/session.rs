@@ -0,0 +1,111 @@
+use eve_esi::session::CharacterSession;
+use eve_esi::{scope::AssetsScopes, ScopeBuilder};
+use mockito::{Mock, ServerGuard};
+
+use crate::{
+    oauth2::util::{
+        jwk_response::get_jwk_success_response,
+        jwt::{create_mock_jwt_claims, create_mock_token_with_claims},
+    },
+    util::integration_test_setup,
+};
+
+/// Adds a token endpoint returning a token granted the provided scopes
+fn mock_token_with_scopes(server: &mut ServerGuard, scopes: Vec<String>) -> Mock {
+    let mut claims = create_mock_jwt_claims();
+    claims.scp = scopes;
+
+    server
+        .mock("POST", "/v2/oauth/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token_with_claims(false, claims)).unwrap())
+        .create()
+}
+
+/// Tests a session created from the OAuth2 callback sends bound requests with its token & character
+///
+/// # Test Setup
+/// - Create mock token & JWT key endpoints, the token being granted the assets scope
+/// - Create a mock character assets endpoint for the mock token's character
+/// - Create a session from an authorization code
+///
+/// # Assertions
+/// - Assert the session's character & scopes are read from the validated token
+/// - Assert the bound assets request was sent to the session character's assets endpoint
+#[tokio::test]
+async fn test_session_from_callback() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+    let scopes = ScopeBuilder::new()
+        .assets(AssetsScopes::new().read_assets())
+        .build();
+
+    let mock_token = mock_token_with_scopes(&mut mock_server, scopes.clone());
+    let _mock_jwt_key_endpoint = get_jwk_success_response(&mut mock_server, 1);
+    let mock_assets = mock_server
+        .mock("GET", "/characters/123456789/assets?page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create();
+
+    let session = CharacterSession::from_callback(esi_client, "authorization_code")
+        .await
+        .unwrap();
+
+    // Assert the session's character & scopes are read from the validated token
+    mock_token.assert();
+    assert_eq!(session.character_id(), 123456789);
+    assert_eq!(session.character_name(), "Test Character");
+    assert!(session.has_scopes(&scopes));
+    assert!(session.refresh_token().is_some());
+    assert!(!session.is_expired_within(chrono::Duration::seconds(60)));
+
+    // Assert the bound assets request was sent to the session character's assets endpoint
+    let assets = session
+        .assets()
+        .get_character_assets(1)
+        .send()
+        .await
+        .unwrap();
+    mock_assets.assert();
+    assert!(assets.data.is_empty());
+}
+
+/// Tests refreshing a session replaces its access token & granted scopes
+///
+/// # Test Setup
+/// - Create a session from a token granted the assets scope
+/// - Replace the token endpoint with one returning a token granted no scopes
+///
+/// # Assertions
+/// - Assert the session no longer has the assets scope after refreshing
+/// - Assert the access token was replaced
+#[tokio::test]
+async fn test_session_refresh() {
+    let (esi_client, mut mock_server) = integration_test_setup().await;
+    let scopes = ScopeBuilder::new()
+        .assets(AssetsScopes::new().read_assets())
+        .build();
+
+    let mock_token = mock_token_with_scopes(&mut mock_server, scopes.clone());
+    let _mock_jwt_key_endpoint = get_jwk_success_response(&mut mock_server, 1);
+
+    let mut session = CharacterSession::from_callback(esi_client, "authorization_code")
+        .await
+        .unwrap();
+    let access_token = session.access_token().clone();
+    assert!(session.has_scopes(&scopes));
+
+    mock_token.remove();
+    let _mock_refreshed_token = mock_token_with_scopes(&mut mock_server, Vec::new());
+
+    session.refresh().await.unwrap();
+
+    // Assert the session no longer has the assets scope after refreshing
+    assert!(!session.has_scopes(&scopes));
+    assert!(session.scopes().is_empty());
+
+    // Assert the access token was replaced
+    assert_ne!(session.access_token(), &access_token);
+}
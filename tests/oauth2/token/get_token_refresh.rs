@@ -31,7 +31,7 @@ pub async fn test_get_token_refresh_success() {
     let refresh_token = mock_token.refresh_token().unwrap().secret().to_string();
 
     // Call the get_token_refresh method
-    let result = client.oauth2().get_token_refresh(refresh_token).await;
+    let result = client.oauth2().get_token_refresh(refresh_token, None).await;
 
     // Assert only 1 fetch request was made
     mock.assert();
@@ -40,6 +40,49 @@ pub async fn test_get_token_refresh_success() {
     assert!(result.is_ok());
 }
 
+/// Tests that requested scopes which were not granted to the refreshed token are reported
+///
+/// # Setup
+/// - Create Client configured with OAuth2 & mock server
+/// - Create mock response with 200 success response & mock token without scopes
+/// - Create a mock refresh token
+///
+/// # Assertions
+/// - Assert only 1 fetch request was made
+/// - Assert the requested scope is reported as missing
+/// - Assert the rotated refresh token & expiration are returned
+#[tokio::test]
+pub async fn test_get_token_refresh_missing_scopes() {
+    // Create Client configured with OAuth2 & mock server
+    let (client, mut mock_server) = integration_test_setup().await;
+
+    // Create mock response with 200 success response & mock token without scopes
+    let mock = get_token_success_response(&mut mock_server, 1);
+
+    // Create a mock refresh token
+    let mock_token = create_mock_token(true);
+    let refresh_token = mock_token.refresh_token().unwrap().secret().to_string();
+
+    // Call the get_token_refresh method requesting a scope
+    let requested_scopes = vec!["publicData".to_string()];
+    let result = client
+        .oauth2()
+        .get_token_refresh(refresh_token, Some(requested_scopes.clone()))
+        .await;
+
+    // Assert only 1 fetch request was made
+    mock.assert();
+
+    // Assert the requested scope is reported as missing
+    let token = result.expect("Expected token refresh to succeed");
+    assert_eq!(token.missing_scopes, requested_scopes);
+    assert!(token.scopes.is_empty());
+
+    // Assert the rotated refresh token & expiration are returned
+    assert!(token.refresh_token.is_some());
+    assert!(token.expires_in.is_some());
+}
+
 /// Tests error handling when failing to refresh a JWT token
 ///
 /// # Setup
@@ -65,7 +108,7 @@ pub async fn test_get_token_refresh_error() {
     let refresh_token = mock_token.refresh_token().unwrap().secret().to_string();
 
     // Call the get_token_refresh method
-    let result = client.oauth2().get_token_refresh(refresh_token).await;
+    let result = client.oauth2().get_token_refresh(refresh_token, None).await;
 
     // Assert only 1 fetch request was made
     mock.assert();
@@ -117,7 +160,7 @@ pub async fn test_get_token_refresh_oauth_client_missing() {
     let refresh_token = mock_token.refresh_token().unwrap().secret().to_string();
 
     // Call the get_token_refresh method
-    let result = client.oauth2().get_token_refresh(refresh_token).await;
+    let result = client.oauth2().get_token_refresh(refresh_token, None).await;
 
     // Assert no fetch request was made
     mock.assert();
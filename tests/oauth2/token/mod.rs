@@ -1,4 +1,5 @@
 mod get_token;
 mod get_token_refresh;
+mod request_token;
 mod util;
 mod validate_token;
@@ -0,0 +1,97 @@
+use eve_esi::model::oauth2::TokenGrant;
+use mockito::Matcher;
+use oauth2::TokenResponse;
+
+use crate::{oauth2::util::jwt::create_mock_token, util::integration_test_setup};
+
+/// Tests requesting a token with the authorization code grant
+///
+/// # Setup
+/// - Create Client configured with OAuth2 & mock server
+/// - Create mock response expecting the authorization code grant type & code
+///
+/// # Assertions
+/// - Assert only 1 fetch request was made
+/// - Assert the token is returned without missing scopes
+#[tokio::test]
+pub async fn test_request_token_authorization_code() {
+    // Create Client configured with OAuth2 & mock server
+    let (client, mut mock_server) = integration_test_setup().await;
+
+    // Create mock response expecting the authorization code grant type & code
+    let mock = mock_server
+        .mock("POST", "/v2/oauth/token")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("grant_type".into(), "authorization_code".into()),
+            Matcher::UrlEncoded("code".into(), "authorization_code".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token(false)).unwrap())
+        .expect(1)
+        .create();
+
+    // Call the request_token method
+    let result = client
+        .oauth2()
+        .request_token(TokenGrant::authorization_code("authorization_code"))
+        .await;
+
+    // Assert only 1 fetch request was made
+    mock.assert();
+
+    // Assert the token is returned without missing scopes
+    let token = result.expect("Expected token request to succeed");
+    assert!(token.missing_scopes.is_empty());
+    assert!(token.expires_in.is_some());
+}
+
+/// Tests requesting a token with the refresh token grant
+///
+/// # Setup
+/// - Create Client configured with OAuth2 & mock server
+/// - Create a mock refresh token
+/// - Create mock response expecting the refresh token grant type & refresh token
+///
+/// # Assertions
+/// - Assert only 1 fetch request was made
+/// - Assert the requested scope is reported as missing
+#[tokio::test]
+pub async fn test_request_token_refresh_token() {
+    // Create Client configured with OAuth2 & mock server
+    let (client, mut mock_server) = integration_test_setup().await;
+
+    // Create a mock refresh token
+    let mock_token = create_mock_token(true);
+    let refresh_token = mock_token.refresh_token().unwrap().secret().to_string();
+
+    // Create mock response expecting the refresh token grant type & refresh token
+    let mock = mock_server
+        .mock("POST", "/v2/oauth/token")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+            Matcher::UrlEncoded("refresh_token".into(), refresh_token.clone()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token(false)).unwrap())
+        .expect(1)
+        .create();
+
+    // Call the request_token method requesting a scope
+    let requested_scopes = vec!["publicData".to_string()];
+    let result = client
+        .oauth2()
+        .request_token(TokenGrant::refresh_token(
+            refresh_token,
+            Some(requested_scopes.clone()),
+        ))
+        .await;
+
+    // Assert only 1 fetch request was made
+    mock.assert();
+
+    // Assert the requested scope is reported as missing
+    let token = result.expect("Expected token request to succeed");
+    assert_eq!(token.missing_scopes, requested_scopes);
+}
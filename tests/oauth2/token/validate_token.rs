@@ -7,7 +7,10 @@ use crate::constant::TEST_CLIENT_ID;
 use crate::oauth2::util::jwk_response::{
     get_jwk_internal_server_error_response, get_jwk_success_response,
 };
-use crate::oauth2::util::jwt::{create_mock_token, create_mock_token_keys, RSA_KEY_ID};
+use crate::oauth2::util::jwt::{
+    create_mock_jwt_claims, create_mock_token, create_mock_token_keys,
+    create_mock_token_with_claims, RSA_KEY_ID,
+};
 use crate::util::integration_test_setup;
 
 /// Tests successful validation of a JWT token
@@ -407,3 +410,62 @@ async fn test_validate_token_key_rotation() {
     // Assert token validation was successful
     assert!(result.is_ok(), "Token validation failed: {:#?}", result);
 }
+
+/// Tests validation of a token from an additional issuer using the issuer's own JWT keys
+///
+/// # Test Setup
+/// - Create an ESI Client configured with an additional issuer & JWK URL on the mock server
+/// - Create mock JWT key responses for both the default & additional issuer's JWK URLs
+/// - Create a mock token issued by the additional issuer
+///
+/// # Assertions
+/// - Assert JWT keys were fetched from the additional issuer's JWK URL only
+/// - Assert token validation was successful
+#[tokio::test]
+async fn test_validate_token_additional_issuer() {
+    let (_, mut mock_server) = integration_test_setup().await;
+    let issuer = "https://sisilogin.testeveonline.com";
+
+    // Create an ESI Client configured with an additional issuer & JWK URL
+    let config = eve_esi::Config::builder()
+        .jwk_url(&format!("{}/oauth/jwks", mock_server.url()))
+        .jwt_issuer_jwk_url(issuer, &format!("{}/sisi/oauth/jwks", mock_server.url()))
+        .build()
+        .expect("Failed to build Config");
+    let client = eve_esi::Client::builder()
+        .user_agent("MyApp/1.0 (contact@example.com)")
+        .client_id(TEST_CLIENT_ID)
+        .client_secret("client_secret")
+        .callback_url("http://localhost:8000/callback")
+        .config(config)
+        .build()
+        .expect("Failed to build Client");
+
+    // Create mock JWT key responses for both JWK URLs
+    let default_mock = get_jwk_success_response(&mut mock_server, 0);
+    let issuer_mock = mock_server
+        .mock("GET", "/sisi/oauth/jwks")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&create_mock_token_keys(false)).unwrap())
+        .expect(1)
+        .create();
+
+    // Create a mock token issued by the additional issuer
+    let mut claims = create_mock_jwt_claims();
+    claims.iss = issuer.to_string();
+    let token = create_mock_token_with_claims(false, claims);
+
+    // Validate the token
+    let result = client
+        .oauth2()
+        .validate_token(token.access_token().secret().to_string())
+        .await;
+
+    // Assert JWT keys were fetched from the additional issuer's JWK URL only
+    default_mock.assert();
+    issuer_mock.assert();
+
+    // Assert token validation was successful
+    assert!(result.is_ok(), "Token validation failed: {:#?}", result);
+}
@@ -1,5 +1,7 @@
 mod constant;
 mod endpoints;
 mod esi;
+#[cfg(feature = "golden")]
+mod golden;
 mod oauth2;
 mod util;
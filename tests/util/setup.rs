@@ -36,6 +36,8 @@ pub async fn integration_test_setup() -> (eve_esi::Client, ServerGuard) {
         .jwk_background_refresh_threshold(50) // 50% expiry for background refresh, 450 milliseconds
         // Reduce backoff for testing purposes
         .esi_retry_backoff(Duration::from_millis(10))
+        // Cap waiting for Retry-After headers for testing purposes
+        .esi_max_retry_after(Duration::from_millis(10))
         .build()
         .expect("Failed to build Config");
 